@@ -0,0 +1,30 @@
+#ifndef PASSGEN_H
+#define PASSGEN_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* status codes, tests/ffi.rs checks them against src/ffi.rs */
+#define PASSGEN_OK               0
+#define PASSGEN_INVALID_ARGUMENT 1
+#define PASSGEN_BUFFER_TOO_SMALL 2
+#define PASSGEN_INTERNAL_ERROR   3
+
+/* writes a NUL terminated password of count tokens from preset into out */
+int passgen_generate(const char *preset, uint32_t count, const char *sep, char *out, size_t out_len);
+
+/* stores the entropy of count tokens from preset in out */
+int passgen_entropy_bits(const char *preset, uint32_t count, double *out);
+
+/* message of the last failed call on this thread or NULL, valid until the next call */
+const char *passgen_last_error(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,31 @@
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "passgen.h"
+
+int main(void) {
+    char out[256];
+    double bits;
+
+    /* happy path */
+    assert(passgen_generate("number", 6, "", out, sizeof out) == PASSGEN_OK);
+    assert(strlen(out) == 6);
+    assert(passgen_last_error() == NULL);
+
+    assert(passgen_entropy_bits("ascii", 10, &bits) == PASSGEN_OK);
+    assert(bits > 65.0 && bits < 66.0);
+
+    /* error paths */
+    assert(passgen_generate("emoji", 6, "", out, sizeof out) == PASSGEN_INVALID_ARGUMENT);
+    assert(strcmp(passgen_last_error(), "invalid preset \"emoji\"") == 0);
+
+    assert(passgen_generate("number", 6, "", out, 6) == PASSGEN_BUFFER_TOO_SMALL);
+    assert(passgen_generate(NULL, 6, "", out, sizeof out) == PASSGEN_INVALID_ARGUMENT);
+    assert(passgen_generate("number", 0, "", out, sizeof out) == PASSGEN_INVALID_ARGUMENT);
+    assert(passgen_generate("\xff", 6, "", out, sizeof out) == PASSGEN_INVALID_ARGUMENT);
+    assert(passgen_entropy_bits("word", 1, NULL) == PASSGEN_INVALID_ARGUMENT);
+
+    puts("ok");
+    return 0;
+}
@@ -0,0 +1,130 @@
+use crate::{PassGenError, TokenSource};
+use std::fmt;
+
+/* -------------------- *
+ *       ACROSTIC       *
+ * -------------------- */
+/// One token per letter of a text, each starting with its letter.
+///
+/// Every position draws uniformly from the tokens starting with its letter, ignoring case,
+/// so a position is only worth `log2` of the size of that bucket.
+///
+/// ```
+/// use pass_gen::{preset, Acrostic, Generator};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let words = preset("word").unwrap().token_data;
+/// let acrostic = Acrostic::new("Berlin", &words).unwrap();
+/// let mut gen = Generator::new(&words, 6, " ", StdRng::seed_from_u64(1)).acrostic(&acrostic);
+///
+/// for _ in 0..1000 {
+///     let initials: String = gen.generate().split(' ').map(|word| &word[..1]).collect();
+///     assert_eq!(initials, "berlin");
+/// }
+///
+/// assert!(acrostic.bits(&words) < 6.0 * 14.0);
+/// assert!(Acrostic::new("b3rlin", &words).unwrap_err().to_string().contains("column 2"));
+/// ```
+#[derive(Debug)]
+pub struct Acrostic {
+    text: String,
+    // the bucket of every position
+    positions: Vec<usize>,
+    // indices of the tokens starting with each distinct letter
+    buckets: Vec<Vec<usize>>,
+}
+
+impl Acrostic {
+    pub fn new(text: &str, source: &dyn TokenSource) -> Result<Acrostic, PassGenError> {
+        let mut letters: Vec<char> = Vec::new();
+        let mut positions = Vec::new();
+
+        for (idx, c) in text.chars().enumerate() {
+            if !c.is_alphabetic() {
+                let msg = format!("invalid acrostic {:?} at column {}: {:?} is not a letter", text, idx + 1, c);
+                return Err(PassGenError::Usage(msg));
+            }
+
+            let letter = fold(c);
+
+            match letters.iter().position(|&l| l == letter) {
+                Some(bucket) => positions.push(bucket),
+                None => {
+                    positions.push(letters.len());
+                    letters.push(letter);
+                },
+            }
+        }
+
+        if positions.is_empty() {
+            return Err(PassGenError::Usage(String::from("acrostic is empty")));
+        }
+
+        let mut buckets = vec![Vec::new(); letters.len()];
+
+        for idx in source.range() {
+            let first = source.get(idx).chars().next().map(fold);
+
+            if let Some(bucket) = letters.iter().position(|&l| Some(l) == first) {
+                buckets[bucket].push(idx);
+            }
+        }
+
+        if let Some(empty) = buckets.iter().position(Vec::is_empty) {
+            let msg = format!("no token starts with {:?}, so the acrostic {:?} cannot be spelled", letters[empty], text);
+            return Err(PassGenError::Unsatisfiable(msg));
+        }
+
+        Ok(Acrostic { text: text.to_string(), positions, buckets })
+    }
+
+    /// Number of tokens, one per letter.
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// The tokens position `idx` draws from, `source` must be the one passed to [`Acrostic::new`].
+    pub fn bucket<'s>(&'s self, source: &'s dyn TokenSource, idx: usize) -> Bucket<'s> {
+        Bucket { source, indices: self.indices(idx) }
+    }
+
+    // indices into the token pool of the bucket of position idx
+    pub(crate) fn indices(&self, idx: usize) -> &[usize] {
+        &self.buckets[self.positions[idx]]
+    }
+
+    /// Entropy in bits, summed over the positions.
+    pub fn bits(&self, source: &dyn TokenSource) -> f64 {
+        (0..self.len()).map(|idx| self.bucket(source, idx).entropy_bits_per_token()).sum()
+    }
+}
+
+impl fmt::Display for Acrostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+/// The tokens starting with one letter of an [`Acrostic`].
+pub struct Bucket<'s> {
+    source: &'s dyn TokenSource,
+    indices: &'s [usize],
+}
+
+impl TokenSource for Bucket<'_> {
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn get(&self, idx: usize) -> &str {
+        self.source.get(self.indices[idx])
+    }
+}
+
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
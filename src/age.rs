@@ -0,0 +1,1065 @@
+use crate::{
+    derive::{hkdf_sha256, hmac_sha256},
+    wipe, PassGenError,
+};
+use rand::{CryptoRng, RngCore};
+use std::fmt;
+
+const VERSION_LINE: &str = "age-encryption.org/v1";
+const X25519_LABEL: &[u8] = b"age-encryption.org/v1/X25519";
+
+const ARMOR_BEGIN: &str = "-----BEGIN AGE ENCRYPTED FILE-----";
+const ARMOR_END: &str = "-----END AGE ENCRYPTED FILE-----";
+
+// plaintext bytes per payload chunk, each gets its own tag
+const CHUNK_SIZE: usize = 64 * 1024;
+const TAG_SIZE: usize = 16;
+const COLUMNS: usize = 64;
+
+const RECIPIENT_HRP: &str = "age";
+const IDENTITY_HRP: &str = "age-secret-key-";
+
+/* -------------------- *
+ *      RECIPIENTS      *
+ * -------------------- */
+/// An age X25519 recipient, the `age1...` public key.
+///
+/// ```
+/// use pass_gen::AgeRecipient;
+///
+/// let key = "age1ql3z7hjy54pw3hyww5ayyfg7zqgvc7w3j2elw8zmrj2kg5sfn9aqmcac8p";
+/// let recipient = AgeRecipient::parse(key).unwrap();
+///
+/// assert_eq!(recipient.to_string(), key);
+/// assert_eq!(AgeRecipient::parse(&key.to_uppercase()).unwrap(), recipient);
+///
+/// // a typo breaks the checksum
+/// let err = AgeRecipient::parse(&key.replace("ql3z", "ql3y")).unwrap_err();
+/// assert!(err.to_string().contains("invalid checksum"));
+///
+/// // recipients files take comments and blank lines
+/// let file = format!("# alice\n{}\n\n", key);
+/// assert_eq!(AgeRecipient::parse_file(&file).unwrap(), [recipient]);
+/// assert!(AgeRecipient::parse_file("# nobody\n").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AgeRecipient {
+    key: [u8; 32],
+}
+
+impl AgeRecipient {
+    pub fn parse(str: &str) -> Result<AgeRecipient, PassGenError> {
+        let invalid = |msg: &str| PassGenError::Usage(format!("invalid age recipient {:?}: {}", str, msg));
+
+        let mut key = bech32_decode(RECIPIENT_HRP, str.trim()).map_err(invalid)?;
+        let res = match <[u8; 32]>::try_from(&key[..]) {
+            Ok(key) => Ok(AgeRecipient { key }),
+            Err(_) => Err(invalid("expected a 32 byte key")),
+        };
+
+        wipe(&mut key);
+        res
+    }
+
+    /// One recipient per line, lines starting with `#` and blank ones are skipped.
+    pub fn parse_file(text: &str) -> Result<Vec<AgeRecipient>, PassGenError> {
+        let recipients = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(AgeRecipient::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if recipients.is_empty() {
+            return Err(PassGenError::InvalidData(String::from("no age recipients in the file")));
+        }
+
+        Ok(recipients)
+    }
+}
+
+impl fmt::Display for AgeRecipient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", bech32_encode(RECIPIENT_HRP, &self.key))
+    }
+}
+
+/// An age X25519 identity, the `AGE-SECRET-KEY-1...` secret key.
+///
+/// ```
+/// use pass_gen::{age_encrypt, AgeIdentity};
+/// use rand::rngs::OsRng;
+///
+/// let identity = AgeIdentity::generate(&mut OsRng);
+/// let other = AgeIdentity::generate(&mut OsRng);
+///
+/// let armor = age_encrypt(b"correct horse", &[other.to_recipient(), identity.to_recipient()], &mut OsRng).unwrap();
+///
+/// assert_eq!(identity.decrypt(&armor).unwrap(), b"correct horse");
+/// assert_eq!(other.decrypt(&armor).unwrap(), b"correct horse");
+///
+/// // the encoding round trips and is never shown by debug
+/// let encoded = identity.encode();
+/// assert!(encoded.starts_with("AGE-SECRET-KEY-1"));
+/// assert_eq!(AgeIdentity::parse(&encoded).unwrap().to_recipient(), identity.to_recipient());
+/// assert!(!format!("{:?}", identity).contains(&encoded[16..]));
+///
+/// // a third identity is not a recipient, a changed byte fails the header mac
+/// assert!(AgeIdentity::generate(&mut OsRng).decrypt(&armor).is_err());
+/// assert!(identity.decrypt(&armor.replacen('A', "B", 1)).is_err());
+/// ```
+#[derive(Clone)]
+pub struct AgeIdentity {
+    secret: [u8; 32],
+}
+
+impl AgeIdentity {
+    pub fn generate<R: RngCore + CryptoRng>(rng: &mut R) -> AgeIdentity {
+        let mut secret = [0; 32];
+        rng.fill_bytes(&mut secret);
+
+        AgeIdentity { secret }
+    }
+
+    pub fn parse(str: &str) -> Result<AgeIdentity, PassGenError> {
+        let mut secret = bech32_decode(IDENTITY_HRP, str.trim())
+            .map_err(|msg| PassGenError::InvalidData(format!("invalid age identity: {}", msg)))?;
+
+        let res = match <[u8; 32]>::try_from(&secret[..]) {
+            Ok(secret) => Ok(AgeIdentity { secret }),
+            Err(_) => Err(PassGenError::InvalidData(String::from("invalid age identity: expected a 32 byte key"))),
+        };
+
+        wipe(&mut secret);
+        res
+    }
+
+    /// The secret key in the uppercase form age writes to identity files.
+    pub fn encode(&self) -> String {
+        bech32_encode(IDENTITY_HRP, &self.secret).to_uppercase()
+    }
+
+    pub fn to_recipient(&self) -> AgeRecipient {
+        AgeRecipient { key: x25519(&self.secret, &BASEPOINT) }
+    }
+
+    /// Decrypts an age file, armored or not, encrypted to this identity.
+    pub fn decrypt(&self, file: &str) -> Result<Vec<u8>, PassGenError> {
+        let invalid = |msg: &str| PassGenError::InvalidData(format!("invalid age file: {}", msg));
+
+        let binary = match file.trim_start().starts_with(ARMOR_BEGIN) {
+            true => dearmor(file).ok_or_else(|| invalid("malformed armor"))?,
+            false => file.as_bytes().to_vec(),
+        };
+
+        let (header, mac, payload) = split_header(&binary).ok_or_else(|| invalid("malformed header"))?;
+        let recipient = self.to_recipient();
+
+        let mut file_key = None;
+
+        for (args, body) in stanzas(header).ok_or_else(|| invalid("malformed stanza"))? {
+            if let ["X25519", share] = args[..] {
+                let share: [u8; 32] = base64_decode(share)
+                    .and_then(|share| share.try_into().ok())
+                    .ok_or_else(|| invalid("malformed X25519 share"))?;
+
+                let mut wrap_key = wrap_key(&x25519(&self.secret, &share), &share, &recipient.key);
+                file_key = aead_open(&wrap_key, &[0; 12], &body);
+                wrap_key.fill(0);
+
+                if file_key.is_some() {
+                    break;
+                }
+            }
+        }
+
+        let mut file_key = file_key.ok_or_else(|| invalid("not encrypted to this identity"))?;
+
+        let res = match verify_tag(&header_mac(&file_key, header), &mac) {
+            true => decrypt_payload(&file_key, payload).ok_or_else(|| invalid("the payload failed authentication")),
+            false => Err(invalid("the header mac does not match")),
+        };
+
+        wipe(&mut file_key);
+        res
+    }
+}
+
+impl fmt::Debug for AgeIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AgeIdentity").field("recipient", &self.to_recipient().to_string()).finish()
+    }
+}
+
+impl Drop for AgeIdentity {
+    fn drop(&mut self) {
+        self.secret.fill(0);
+    }
+}
+
+
+/* -------------------- *
+ *      ENCRYPTION      *
+ * -------------------- */
+/// Encrypts `plaintext` to every recipient in the age v1 format, returned armored.
+///
+/// Any one of the matching identities decrypts it, with `age --decrypt` as well as
+/// [`AgeIdentity::decrypt`].
+pub fn age_encrypt<R: RngCore + CryptoRng>(
+    plaintext: &[u8],
+    recipients: &[AgeRecipient],
+    rng: &mut R,
+) -> Result<String, PassGenError> {
+    if recipients.is_empty() {
+        return Err(PassGenError::Usage(String::from("no age recipients to encrypt to")));
+    }
+
+    let mut file_key = vec![0; 16];
+    rng.fill_bytes(&mut file_key);
+
+    let mut header = format!("{}\n", VERSION_LINE);
+
+    for recipient in recipients {
+        let mut ephemeral = [0; 32];
+        rng.fill_bytes(&mut ephemeral);
+
+        let share = x25519(&ephemeral, &BASEPOINT);
+        let mut shared = x25519(&ephemeral, &recipient.key);
+
+        // a low order recipient key would make the shared secret known
+        if shared.iter().all(|&b| b == 0) {
+            wipe(&mut file_key);
+            return Err(PassGenError::Usage(format!("the age recipient {} is not a valid key", recipient)));
+        }
+
+        let mut wrap_key = wrap_key(&shared, &share, &recipient.key);
+        let body = aead_seal(&wrap_key, &[0; 12], &file_key);
+
+        header.push_str(&format!("-> X25519 {}\n{}\n", base64_encode(&share, false), base64_encode(&body, false)));
+
+        ephemeral.fill(0);
+        shared.fill(0);
+        wrap_key.fill(0);
+    }
+
+    header.push_str("---");
+
+    let mac = header_mac(&file_key, header.as_bytes());
+    let mut file = format!("{} {}\n", header, base64_encode(&mac, false)).into_bytes();
+
+    let mut nonce = [0; 16];
+    rng.fill_bytes(&mut nonce);
+    file.extend_from_slice(&nonce);
+
+    let mut payload_key = hkdf_sha256(&file_key, &nonce, b"payload", 32);
+    let chunks = plaintext.len().div_ceil(CHUNK_SIZE).max(1);
+
+    for counter in 0..chunks {
+        let chunk = &plaintext[counter * CHUNK_SIZE..plaintext.len().min((counter + 1) * CHUNK_SIZE)];
+        file.extend_from_slice(&aead_seal(&payload_key, &chunk_nonce(counter, counter + 1 == chunks), chunk));
+    }
+
+    wipe(&mut payload_key);
+    wipe(&mut file_key);
+
+    let mut armor = format!("{}\n", ARMOR_BEGIN);
+
+    for line in base64_encode(&file, true).as_bytes().chunks(COLUMNS) {
+        armor.push_str(std::str::from_utf8(line).unwrap());
+        armor.push('\n');
+    }
+
+    armor.push_str(ARMOR_END);
+    armor.push('\n');
+
+    Ok(armor)
+}
+
+fn wrap_key(shared: &[u8; 32], share: &[u8; 32], recipient: &[u8; 32]) -> Vec<u8> {
+    hkdf_sha256(shared, &[&share[..], &recipient[..]].concat(), X25519_LABEL, 32)
+}
+
+fn header_mac(file_key: &[u8], header: &[u8]) -> [u8; 32] {
+    let mut key = hkdf_sha256(file_key, &[], b"header", 32);
+    let mac = hmac_sha256(&key, &[header]);
+
+    wipe(&mut key);
+    mac
+}
+
+// an 11 byte big endian counter and a flag for the last chunk
+fn chunk_nonce(counter: usize, last: bool) -> [u8; 12] {
+    let mut nonce = [0; 12];
+
+    nonce[3..11].copy_from_slice(&(counter as u64).to_be_bytes());
+    nonce[11] = last as u8;
+
+    nonce
+}
+
+fn decrypt_payload(file_key: &[u8], payload: &[u8]) -> Option<Vec<u8>> {
+    let nonce = payload.get(..16)?;
+    let sealed: Vec<&[u8]> = payload[16..].chunks(CHUNK_SIZE + TAG_SIZE).collect();
+
+    let mut payload_key = hkdf_sha256(file_key, nonce, b"payload", 32);
+    let mut plaintext = Vec::with_capacity(payload.len());
+
+    // an empty chunk is only valid as the whole payload
+    let res = (!sealed.is_empty()).then_some(()).and_then(|_| {
+        for (counter, chunk) in sealed.iter().enumerate() {
+            let last = counter + 1 == sealed.len();
+            let mut opened = aead_open(&payload_key, &chunk_nonce(counter, last), chunk)?;
+
+            if opened.is_empty() && counter != 0 {
+                return None;
+            }
+
+            plaintext.extend_from_slice(&opened);
+            wipe(&mut opened);
+        }
+
+        Some(())
+    });
+
+    wipe(&mut payload_key);
+
+    match res {
+        Some(()) => Some(plaintext),
+        None => {
+            wipe(&mut plaintext);
+            None
+        },
+    }
+}
+
+// the header without the mac, the mac and the binary payload
+fn split_header(file: &[u8]) -> Option<(&[u8], [u8; 32], &[u8])> {
+    let start = file.windows(5).position(|window| window == b"\n--- ")? + 4;
+    let end = start + file[start..].iter().position(|&b| b == b'\n')?;
+
+    let mac = base64_decode(std::str::from_utf8(&file[start + 1..end]).ok()?)?.try_into().ok()?;
+
+    Some((&file[..start], mac, &file[end + 1..]))
+}
+
+// the arguments and body of every stanza, after checking the version line
+fn stanzas(header: &[u8]) -> Option<Vec<(Vec<&str>, Vec<u8>)>> {
+    let header = std::str::from_utf8(header).ok()?;
+    let mut lines = header.strip_suffix("---")?.split_terminator('\n');
+
+    if lines.next()? != VERSION_LINE {
+        return None;
+    }
+
+    let mut stanzas = Vec::new();
+    let mut lines = lines.peekable();
+
+    while let Some(line) = lines.next() {
+        let args: Vec<&str> = line.strip_prefix("-> ")?.split(' ').collect();
+        let mut body = Vec::new();
+
+        // body lines are full until the last one
+        loop {
+            let line = lines.next()?;
+
+            if line.len() > COLUMNS {
+                return None;
+            }
+
+            body.extend(base64_decode(line)?);
+
+            if line.len() < COLUMNS {
+                break;
+            }
+        }
+
+        stanzas.push((args, body));
+    }
+
+    Some(stanzas)
+}
+
+fn dearmor(file: &str) -> Option<Vec<u8>> {
+    let body = file.trim().strip_prefix(ARMOR_BEGIN)?.strip_suffix(ARMOR_END)?;
+    let lines: Vec<&str> = body.trim().lines().map(str::trim_end).collect();
+
+    if lines.iter().any(|line| line.len() > COLUMNS) {
+        return None;
+    }
+
+    base64_decode(lines.concat().trim_end_matches('='))
+}
+
+
+/* -------------------- *
+ *       X25519         *
+ * -------------------- */
+const BASEPOINT: [u8; 32] = {
+    let mut point = [0; 32];
+    point[0] = 9;
+    point
+};
+
+// an element of GF(2^255 - 19) in five 51 bit limbs, not always fully reduced
+#[derive(Clone, Copy)]
+struct Fe([u64; 5]);
+
+const MASK51: u64 = (1 << 51) - 1;
+
+impl Fe {
+    fn from_bytes(bytes: &[u8; 32]) -> Fe {
+        let w: Vec<u64> = bytes.chunks_exact(8).map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap())).collect();
+
+        // the top bit is ignored
+        Fe([
+            w[0] & MASK51,
+            (w[0] >> 51 | w[1] << 13) & MASK51,
+            (w[1] >> 38 | w[2] << 26) & MASK51,
+            (w[2] >> 25 | w[3] << 39) & MASK51,
+            (w[3] >> 12) & MASK51,
+        ])
+    }
+
+    fn to_bytes(self) -> [u8; 32] {
+        let mut l = self.carry().0;
+
+        // subtracts p once more if the value is still at least p
+        let mut q = (l[0] + 19) >> 51;
+
+        for limb in &l[1..] {
+            q = (limb + q) >> 51;
+        }
+
+        l[0] += 19 * q;
+
+        for i in 0..4 {
+            l[i + 1] += l[i] >> 51;
+            l[i] &= MASK51;
+        }
+
+        l[4] &= MASK51;
+
+        let mut bytes = [0; 32];
+        let (mut acc, mut bits, mut pos) = (0u128, 0, 0);
+
+        for limb in l {
+            acc |= (limb as u128) << bits;
+            bits += 51;
+
+            while bits >= 8 {
+                bytes[pos] = acc as u8;
+                acc >>= 8;
+                bits -= 8;
+                pos += 1;
+            }
+        }
+
+        bytes[pos] = acc as u8;
+        bytes
+    }
+
+    fn carry(self) -> Fe {
+        let mut l = self.0;
+
+        for i in 0..4 {
+            l[i + 1] += l[i] >> 51;
+            l[i] &= MASK51;
+        }
+
+        l[0] += 19 * (l[4] >> 51);
+        l[4] &= MASK51;
+
+        Fe(l)
+    }
+
+    fn add(self, other: Fe) -> Fe {
+        Fe(std::array::from_fn(|i| self.0[i] + other.0[i])).carry()
+    }
+
+    // adds 4p first so no limb goes below zero
+    fn sub(self, other: Fe) -> Fe {
+        let four_p = |i: usize| if i == 0 { (MASK51 - 18) * 4 } else { MASK51 * 4 };
+        let other = other.carry();
+
+        Fe(std::array::from_fn(|i| self.0[i] + four_p(i) - other.0[i])).carry()
+    }
+
+    fn mul(self, other: Fe) -> Fe {
+        let [a0, a1, a2, a3, a4] = self.0.map(|limb| limb as u128);
+        let [b0, b1, b2, b3, b4] = other.0.map(|limb| limb as u128);
+        let (b1_19, b2_19, b3_19, b4_19) = (b1 * 19, b2 * 19, b3 * 19, b4 * 19);
+
+        let r = [
+            a0 * b0 + a1 * b4_19 + a2 * b3_19 + a3 * b2_19 + a4 * b1_19,
+            a0 * b1 + a1 * b0 + a2 * b4_19 + a3 * b3_19 + a4 * b2_19,
+            a0 * b2 + a1 * b1 + a2 * b0 + a3 * b4_19 + a4 * b3_19,
+            a0 * b3 + a1 * b2 + a2 * b1 + a3 * b0 + a4 * b4_19,
+            a0 * b4 + a1 * b3 + a2 * b2 + a3 * b1 + a4 * b0,
+        ];
+
+        let mut l = [0u64; 5];
+        let mut carry = 0u128;
+
+        for i in 0..5 {
+            let value = r[i] + carry;
+            l[i] = value as u64 & MASK51;
+            carry = value >> 51;
+        }
+
+        let value = l[0] as u128 + carry * 19;
+        l[0] = value as u64 & MASK51;
+        l[1] += (value >> 51) as u64;
+
+        Fe(l)
+    }
+
+    fn square(self) -> Fe {
+        self.mul(self)
+    }
+
+    // z^(p - 2), the exponent has every bit from 254 down set except bits 2 and 4
+    fn invert(self) -> Fe {
+        let mut result = Fe([1, 0, 0, 0, 0]);
+
+        for bit in (0..255).rev() {
+            result = result.square();
+
+            if bit != 2 && bit != 4 {
+                result = result.mul(self);
+            }
+        }
+
+        result
+    }
+
+    fn swap(a: &mut Fe, b: &mut Fe, swap: u64) {
+        let mask = 0u64.wrapping_sub(swap);
+
+        for (a, b) in a.0.iter_mut().zip(&mut b.0) {
+            let t = mask & (*a ^ *b);
+            *a ^= t;
+            *b ^= t;
+        }
+    }
+}
+
+// the montgomery ladder of RFC 7748, in constant time for the scalar
+fn x25519(scalar: &[u8; 32], point: &[u8; 32]) -> [u8; 32] {
+    let mut k = *scalar;
+    k[0] &= 248;
+    k[31] &= 127;
+    k[31] |= 64;
+
+    let x1 = Fe::from_bytes(point);
+    let a24 = Fe([121665, 0, 0, 0, 0]);
+
+    let (mut x2, mut z2, mut x3, mut z3) = (Fe([1, 0, 0, 0, 0]), Fe([0; 5]), x1, Fe([1, 0, 0, 0, 0]));
+    let mut swap = 0;
+
+    for t in (0..255).rev() {
+        let bit = (k[t / 8] >> (t % 8)) as u64 & 1;
+        swap ^= bit;
+        Fe::swap(&mut x2, &mut x3, swap);
+        Fe::swap(&mut z2, &mut z3, swap);
+        swap = bit;
+
+        let a = x2.add(z2);
+        let aa = a.square();
+        let b = x2.sub(z2);
+        let bb = b.square();
+        let e = aa.sub(bb);
+        let c = x3.add(z3);
+        let d = x3.sub(z3);
+        let da = d.mul(a);
+        let cb = c.mul(b);
+
+        x3 = da.add(cb).square();
+        z3 = x1.mul(da.sub(cb).square());
+        x2 = aa.mul(bb);
+        z2 = e.mul(aa.add(a24.mul(e)));
+    }
+
+    Fe::swap(&mut x2, &mut x3, swap);
+    Fe::swap(&mut z2, &mut z3, swap);
+
+    k.fill(0);
+    x2.mul(z2.invert()).to_bytes()
+}
+
+
+/* -------------------- *
+ *  CHACHA20-POLY1305   *
+ * -------------------- */
+fn chacha20_block(key: &[u8], counter: u32, nonce: &[u8; 12]) -> [u8; 64] {
+    let le = |bytes: &[u8]| u32::from_le_bytes(bytes.try_into().unwrap());
+
+    let mut state = [0u32; 16];
+    state[..4].copy_from_slice(&[0x61707865, 0x3320646e, 0x79622d32, 0x6b206574]);
+
+    for (word, chunk) in state[4..12].iter_mut().zip(key.chunks_exact(4)) {
+        *word = le(chunk);
+    }
+
+    state[12] = counter;
+
+    for (word, chunk) in state[13..].iter_mut().zip(nonce.chunks_exact(4)) {
+        *word = le(chunk);
+    }
+
+    let mut x = state;
+
+    let quarter = |x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize| {
+        x[a] = x[a].wrapping_add(x[b]);
+        x[d] = (x[d] ^ x[a]).rotate_left(16);
+        x[c] = x[c].wrapping_add(x[d]);
+        x[b] = (x[b] ^ x[c]).rotate_left(12);
+        x[a] = x[a].wrapping_add(x[b]);
+        x[d] = (x[d] ^ x[a]).rotate_left(8);
+        x[c] = x[c].wrapping_add(x[d]);
+        x[b] = (x[b] ^ x[c]).rotate_left(7);
+    };
+
+    for _ in 0..10 {
+        quarter(&mut x, 0, 4, 8, 12);
+        quarter(&mut x, 1, 5, 9, 13);
+        quarter(&mut x, 2, 6, 10, 14);
+        quarter(&mut x, 3, 7, 11, 15);
+        quarter(&mut x, 0, 5, 10, 15);
+        quarter(&mut x, 1, 6, 11, 12);
+        quarter(&mut x, 2, 7, 8, 13);
+        quarter(&mut x, 3, 4, 9, 14);
+    }
+
+    let mut block = [0; 64];
+
+    for (chunk, (x, s)) in block.chunks_exact_mut(4).zip(x.iter().zip(state)) {
+        chunk.copy_from_slice(&x.wrapping_add(s).to_le_bytes());
+    }
+
+    block
+}
+
+fn chacha20_xor(key: &[u8], nonce: &[u8; 12], data: &mut [u8]) {
+    for (counter, chunk) in data.chunks_mut(64).enumerate() {
+        let mut block = chacha20_block(key, counter as u32 + 1, nonce);
+
+        for (byte, key) in chunk.iter_mut().zip(block) {
+            *byte ^= key;
+        }
+
+        block.fill(0);
+    }
+}
+
+// poly1305 with 44 bit limbs
+fn poly1305(key: &[u8; 32], message: &[u8]) -> [u8; 16] {
+    const M44: u64 = (1 << 44) - 1;
+    const M42: u64 = (1 << 42) - 1;
+
+    let le = |bytes: &[u8]| u64::from_le_bytes(bytes.try_into().unwrap());
+
+    let (t0, t1) = (le(&key[..8]), le(&key[8..16]));
+    let r0 = t0 & 0xffc0fffffff;
+    let r1 = ((t0 >> 44) | (t1 << 20)) & 0xfffffc0ffff;
+    let r2 = (t1 >> 24) & 0x00ffffffc0f;
+    let (s1, s2) = (r1 * 20, r2 * 20);
+
+    let (mut h0, mut h1, mut h2) = (0u64, 0u64, 0u64);
+
+    for chunk in message.chunks(16) {
+        // a short last block ends in a one byte instead of the bit above a full one
+        let mut block = [0; 16];
+        block[..chunk.len()].copy_from_slice(chunk);
+
+        let high = match chunk.len() {
+            16 => 1 << 40,
+            len => {
+                block[len] = 1;
+                0
+            },
+        };
+
+        let (t0, t1) = (le(&block[..8]), le(&block[8..]));
+
+        h0 += t0 & M44;
+        h1 += ((t0 >> 44) | (t1 << 20)) & M44;
+        h2 += ((t1 >> 24) & M42) | high;
+
+        let wide = |x: u64, y: u64| x as u128 * y as u128;
+        let d0 = wide(h0, r0) + wide(h1, s2) + wide(h2, s1);
+        let mut d1 = wide(h0, r1) + wide(h1, r0) + wide(h2, s2);
+        let mut d2 = wide(h0, r2) + wide(h1, r1) + wide(h2, r0);
+
+        h0 = d0 as u64 & M44;
+        d1 += d0 >> 44;
+        h1 = d1 as u64 & M44;
+        d2 += d1 >> 44;
+        h2 = d2 as u64 & M42;
+        h0 += (d2 >> 42) as u64 * 5;
+        h1 += h0 >> 44;
+        h0 &= M44;
+    }
+
+    for _ in 0..2 {
+        h2 += h1 >> 44;
+        h1 &= M44;
+        h0 += (h2 >> 42) * 5;
+        h2 &= M42;
+        h1 += h0 >> 44;
+        h0 &= M44;
+    }
+
+    // h - p, kept only when h is at least p
+    let mut g0 = h0 + 5;
+    let mut g1 = h1 + (g0 >> 44);
+    g0 &= M44;
+    let g2 = (h2 + (g1 >> 44)).wrapping_sub(1 << 42);
+    g1 &= M44;
+
+    let mask = (g2 >> 63).wrapping_sub(1);
+    h0 = (h0 & !mask) | (g0 & mask);
+    h1 = (h1 & !mask) | (g1 & mask);
+    h2 = (h2 & !mask) | (g2 & mask);
+
+    let (t0, t1) = (le(&key[16..24]), le(&key[24..]));
+
+    h0 += t0 & M44;
+    h1 += (((t0 >> 44) | (t1 << 20)) & M44) + (h0 >> 44);
+    h0 &= M44;
+    h2 += (t1 >> 24) + (h1 >> 44);
+    h1 &= M44;
+
+    let mut tag = [0; 16];
+    tag[..8].copy_from_slice(&(h0 | h1 << 44).to_le_bytes());
+    tag[8..].copy_from_slice(&(h1 >> 20 | h2 << 24).to_le_bytes());
+
+    tag
+}
+
+// the tag of RFC 8439 over the additional data, the ciphertext and their lengths, age has no additional data
+fn aead_tag(key: &[u8], nonce: &[u8; 12], aad: &[u8], ciphertext: &[u8]) -> [u8; 16] {
+    let mut block = chacha20_block(key, 0, nonce);
+    let poly_key: [u8; 32] = block[..32].try_into().unwrap();
+
+    let mut message = aad.to_vec();
+    message.resize(aad.len().next_multiple_of(16), 0);
+    message.extend_from_slice(ciphertext);
+    message.resize(message.len() + ciphertext.len().next_multiple_of(16) - ciphertext.len(), 0);
+    message.extend_from_slice(&(aad.len() as u64).to_le_bytes());
+    message.extend_from_slice(&(ciphertext.len() as u64).to_le_bytes());
+
+    block.fill(0);
+    poly1305(&poly_key, &message)
+}
+
+fn aead_seal(key: &[u8], nonce: &[u8; 12], plaintext: &[u8]) -> Vec<u8> {
+    let mut sealed = Vec::with_capacity(plaintext.len() + TAG_SIZE);
+    sealed.extend_from_slice(plaintext);
+
+    chacha20_xor(key, nonce, &mut sealed);
+    let tag = aead_tag(key, nonce, &[], &sealed);
+    sealed.extend_from_slice(&tag);
+
+    sealed
+}
+
+fn aead_open(key: &[u8], nonce: &[u8; 12], sealed: &[u8]) -> Option<Vec<u8>> {
+    let (ciphertext, tag) = sealed.split_at(sealed.len().checked_sub(TAG_SIZE)?);
+
+    if !verify_tag(&aead_tag(key, nonce, &[], ciphertext), tag) {
+        return None;
+    }
+
+    let mut plaintext = ciphertext.to_vec();
+    chacha20_xor(key, nonce, &mut plaintext);
+
+    Some(plaintext)
+}
+
+// compares without stopping at the first difference
+fn verify_tag(expected: &[u8], tag: &[u8]) -> bool {
+    expected.len() == tag.len() && expected.iter().zip(tag).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+
+/* -------------------- *
+ *       ENCODING       *
+ * -------------------- */
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BECH32: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+fn base64_encode(data: &[u8], padding: bool) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let bits = chunk.iter().enumerate().fold(0u32, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64[(bits >> (18 - 6 * i) & 63) as usize] as char);
+            } else if padding {
+                out.push('=');
+            }
+        }
+    }
+
+    out
+}
+
+// unpadded and canonical, unused trailing bits must be zero
+fn base64_decode(str: &str) -> Option<Vec<u8>> {
+    if str.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(str.len() * 3 / 4);
+    let (mut acc, mut bits) = (0u32, 0);
+
+    for c in str.bytes() {
+        acc = acc << 6 | BASE64.iter().position(|&b| b == c)? as u32;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+
+    (acc == 0).then_some(out)
+}
+
+fn bech32_polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATORS: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+    values.fold(1, |chk, value| {
+        let top = chk >> 25;
+        let chk = (chk & 0x1ffffff) << 5 ^ value as u32;
+
+        (0..5).filter(|i| top >> i & 1 == 1).fold(chk, |chk, i| chk ^ GENERATORS[i])
+    })
+}
+
+fn hrp_expand(hrp: &str) -> impl Iterator<Item = u8> + '_ {
+    hrp.bytes().map(|c| c >> 5).chain([0]).chain(hrp.bytes().map(|c| c & 31))
+}
+
+fn bech32_encode(hrp: &str, data: &[u8]) -> String {
+    let mut values = Vec::with_capacity(data.len() * 8 / 5 + 7);
+    let (mut acc, mut bits) = (0u32, 0);
+
+    for &byte in data {
+        acc = acc << 8 | byte as u32;
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            values.push((acc >> bits & 31) as u8);
+        }
+    }
+
+    if bits > 0 {
+        values.push((acc << (5 - bits) & 31) as u8);
+    }
+
+    let checksum = bech32_polymod(hrp_expand(hrp).chain(values.iter().copied()).chain([0; 6])) ^ 1;
+    values.extend((0..6).map(|i| (checksum >> (5 * (5 - i)) & 31) as u8));
+
+    let encoded: String = values.iter().map(|&value| BECH32[value as usize] as char).collect();
+    format!("{}1{}", hrp, encoded)
+}
+
+fn bech32_decode(hrp: &str, str: &str) -> Result<Vec<u8>, &'static str> {
+    if str.chars().any(|c| c.is_ascii_lowercase()) && str.chars().any(|c| c.is_ascii_uppercase()) {
+        return Err("mixed case");
+    }
+
+    let str = str.to_ascii_lowercase();
+    let (prefix, data) = str.rsplit_once('1').ok_or("not a bech32 string")?;
+
+    if prefix != hrp {
+        return Err(match hrp {
+            RECIPIENT_HRP => "expected a key starting with age1",
+            _ => "expected a key starting with AGE-SECRET-KEY-1",
+        });
+    }
+
+    let values = data
+        .bytes()
+        .map(|c| BECH32.iter().position(|&b| b == c).map(|value| value as u8))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or("invalid bech32 character")?;
+
+    if values.len() < 6 || bech32_polymod(hrp_expand(hrp).chain(values.iter().copied())) != 1 {
+        return Err("invalid checksum");
+    }
+
+    let mut out = Vec::with_capacity(values.len() * 5 / 8);
+    let (mut acc, mut bits) = (0u32, 0);
+
+    for &value in &values[..values.len() - 6] {
+        acc = (acc << 5 | value as u32) & 0xfff;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+        }
+    }
+
+    if bits >= 5 || acc & ((1 << bits) - 1) != 0 {
+        wipe(&mut out);
+        return Err("invalid padding");
+    }
+
+    Ok(out)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(str: &str) -> Vec<u8> {
+        let digits: Vec<u8> = str.bytes().filter(u8::is_ascii_hexdigit).collect();
+        digits.chunks(2).map(|pair| u8::from_str_radix(std::str::from_utf8(pair).unwrap(), 16).unwrap()).collect()
+    }
+
+    fn hex32(str: &str) -> [u8; 32] {
+        hex(str).try_into().unwrap()
+    }
+
+    const SUNSCREEN: &[u8] = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the \
+        future, sunscreen would be it.";
+
+    #[test]
+    fn x25519_rfc7748() {
+        // section 5.2
+        let cases = [
+            (
+                "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+                "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+                "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+            ),
+            (
+                "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+                "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+                "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+            ),
+        ];
+
+        for (scalar, point, expected) in cases {
+            assert_eq!(x25519(&hex32(scalar), &hex32(point)), hex32(expected));
+        }
+
+        // the iterated ladder, starting from the base point
+        let (mut k, mut u) = (BASEPOINT, BASEPOINT);
+
+        for round in 1..=1000 {
+            (k, u) = (x25519(&k, &u), k);
+
+            if round == 1 {
+                assert_eq!(k, hex32("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079"));
+            }
+        }
+
+        assert_eq!(k, hex32("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51"));
+
+        // section 6.1
+        let alice = hex32("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob = hex32("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+        let (alice_public, bob_public) = (x25519(&alice, &BASEPOINT), x25519(&bob, &BASEPOINT));
+
+        assert_eq!(alice_public, hex32("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"));
+        assert_eq!(bob_public, hex32("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f"));
+
+        let shared = hex32("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+        assert_eq!((x25519(&alice, &bob_public), x25519(&bob, &alice_public)), (shared, shared));
+    }
+
+    #[test]
+    fn chacha20_rfc8439() {
+        let key = hex("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+
+        // section 2.3.2
+        let nonce = hex("000000090000004a00000000").try_into().unwrap();
+        let block = hex(
+            "10f1e7e4d13b5915500fdd1fa32071c4c7d1f4c733c068030422aa9ac3d46c4e\
+             d2826446079faa0914c2d705d98b02a2b5129cd1de164eb9cbd083e8a2503c4e",
+        );
+
+        assert_eq!(chacha20_block(&key, 1, &nonce).to_vec(), block);
+
+        // section 2.4.2, the keystream starts at block 1
+        let nonce = hex("000000000000004a00000000").try_into().unwrap();
+        let mut text = SUNSCREEN.to_vec();
+        chacha20_xor(&key, &nonce, &mut text);
+
+        let ciphertext = hex(
+            "6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0b\
+             f91b65c5524733ab8f593dabcd62b3571639d624e65152ab8f530c359f0861d8\
+             07ca0dbf500d6a6156a38e088a22b65e52bc514d16ccf806818ce91ab7793736\
+             5af90bbf74a35be6b40b8eedf2785e42874d",
+        );
+
+        assert_eq!(text, ciphertext);
+    }
+
+    #[test]
+    fn poly1305_rfc8439() {
+        // section 2.5.2
+        let key = hex32("85d6be7857556d337f4452fe42d506a80103808afb0db2fd4abff6af4149f51b");
+        let tag = poly1305(&key, b"Cryptographic Forum Research Group");
+
+        assert_eq!(tag.to_vec(), hex("a8061dc1305136c6c22b8baf0c0127a9"));
+    }
+
+    #[test]
+    fn aead_rfc8439() {
+        // section 2.8.2
+        let key = hex("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f");
+        let nonce = hex("070000004041424344454647").try_into().unwrap();
+        let aad = hex("50515253c0c1c2c3c4c5c6c7");
+
+        let mut text = SUNSCREEN.to_vec();
+        chacha20_xor(&key, &nonce, &mut text);
+
+        let ciphertext = hex(
+            "d31a8d34648e60db7b86afbc53ef7ec2a4aded51296e08fea9e2b5a736ee62d6\
+             3dbea45e8ca9671282fafb69da92728b1a71de0a9e060b2905d6a5b67ecd3b36\
+             92ddbd7f2d778b8c9803aee328091b58fab324e4fad675945585808b4831d7bc\
+             3ff4def08e4b7a9de576d26586cec64b6116",
+        );
+
+        assert_eq!(text, ciphertext);
+        assert_eq!(aead_tag(&key, &nonce, &aad, &text).to_vec(), hex("1ae10b594f09e26a7e902ecbd0600691"));
+
+        // without additional data, as age seals its chunks
+        let sealed = aead_seal(&key, &nonce, SUNSCREEN);
+
+        assert_eq!(sealed[..SUNSCREEN.len()], ciphertext);
+        assert_eq!(aead_open(&key, &nonce, &sealed).as_deref(), Some(SUNSCREEN));
+    }
+
+    #[test]
+    fn encodings() {
+        // RFC 4648 section 10
+        let cases = [("", ""), ("f", "Zg"), ("fo", "Zm8"), ("foo", "Zm9v"), ("foob", "Zm9vYg"), ("foobar", "Zm9vYmFy")];
+
+        for (data, encoded) in cases {
+            assert_eq!(base64_encode(data.as_bytes(), false), encoded);
+            assert_eq!(base64_decode(encoded).as_deref(), Some(data.as_bytes()));
+        }
+
+        assert_eq!(base64_encode(b"fooba", true), "Zm9vYmE=");
+
+        // BIP 173, the 32 values in order pack into 20 bytes
+        let data = hex("00443214c74254b635cf84653a56d7c675be77df");
+
+        assert_eq!(bech32_encode("a", &[]), "a12uel5l");
+        assert_eq!(bech32_decode("a", "A12UEL5L"), Ok(Vec::new()));
+        assert_eq!(bech32_encode("abcdef", &data), "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw");
+        assert_eq!(bech32_decode("abcdef", "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw"), Ok(data));
+        assert_eq!(bech32_decode("a", "a12uel5m"), Err("invalid checksum"));
+    }
+}
@@ -0,0 +1,265 @@
+use crate::PassGenError;
+use rand::Rng;
+
+/// Random bits of the payload of an API token when no other amount is asked for.
+pub const API_TOKEN_BITS: f64 = 160.0;
+
+/// Characters of the checksum at the end of an API token.
+pub const API_TOKEN_CHECKSUM_LEN: usize = 6;
+
+/* -------------------- *
+ *       ALPHABET       *
+ * -------------------- */
+/// Alphabet of the payload and checksum of an API token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenAlphabet {
+    /// Digits, upper and lower case letters.
+    Base62,
+    /// Crockford's base32, digits and upper case letters without I, L, O and U.
+    Crockford32,
+}
+
+impl TokenAlphabet {
+    pub const ALL: [TokenAlphabet; 2] = [TokenAlphabet::Base62, TokenAlphabet::Crockford32];
+
+    pub fn parse(name: &str) -> Result<TokenAlphabet, PassGenError> {
+        match TokenAlphabet::ALL.into_iter().find(|alphabet| alphabet.name().eq_ignore_ascii_case(name)) {
+            Some(alphabet) => Ok(alphabet),
+            None => Err(PassGenError::Usage(format!(
+                "invalid token alphabet {:?}, expected one of {}",
+                name,
+                TokenAlphabet::ALL.map(TokenAlphabet::name).join(", ")
+            ))),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            TokenAlphabet::Base62 => "base62",
+            TokenAlphabet::Crockford32 => "crockford32",
+        }
+    }
+
+    pub fn chars(self) -> &'static [u8] {
+        match self {
+            TokenAlphabet::Base62 => b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+            TokenAlphabet::Crockford32 => crate::CROCKFORD_BASE32,
+        }
+    }
+
+    /// Bits of one character drawn uniformly from the alphabet.
+    pub fn bits(self) -> f64 {
+        (self.chars().len() as f64).log2()
+    }
+
+    pub fn contains(self, c: char) -> bool {
+        c.is_ascii() && self.chars().contains(&(c as u8))
+    }
+
+    /// `value` in exactly `len` characters, most significant first; higher digits that do
+    /// not fit are dropped.
+    pub fn encode(self, mut value: u64, len: usize) -> String {
+        let chars = self.chars();
+        let mut encoded = vec![chars[0]; len];
+
+        for c in encoded.iter_mut().rev() {
+            *c = chars[(value % chars.len() as u64) as usize];
+            value /= chars.len() as u64;
+        }
+
+        String::from_utf8(encoded).expect("alphabets are ascii")
+    }
+}
+
+
+/* -------------------- *
+ *       CHECKSUM       *
+ * -------------------- */
+/// The CRC-32 of `data`, as used by zip and ethernet.
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for &byte in data {
+        crc ^= byte as u32;
+
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb88320 & (crc & 1).wrapping_neg());
+        }
+    }
+
+    !crc
+}
+
+/// The checksum of an API token payload, its CRC-32 in [`API_TOKEN_CHECKSUM_LEN`] characters
+/// of `alphabet`.
+///
+/// Six base62 characters hold all 32 bits, six of Crockford's base32 the lowest 30.
+///
+/// ```
+/// use pass_gen::{token_checksum, TokenAlphabet};
+///
+/// assert_eq!(token_checksum("123456789", TokenAlphabet::Base62), "3jZRME");
+/// assert_eq!(token_checksum("123456789", TokenAlphabet::Crockford32), "5Z8E96");
+/// ```
+pub fn token_checksum(payload: &str, alphabet: TokenAlphabet) -> String {
+    alphabet.encode(crc32(payload.as_bytes()) as u64, API_TOKEN_CHECKSUM_LEN)
+}
+
+
+/* -------------------- *
+ *      API TOKEN       *
+ * -------------------- */
+/// The shape of an API token, `<prefix>_<payload><checksum>`.
+///
+/// The payload is drawn uniformly from the alphabet and the checksum is derived from it, so
+/// scanners can recognize leaked tokens by their prefix and rule out lookalikes offline.
+///
+/// ```
+/// use pass_gen::{verify_api_token, ApiToken, TokenAlphabet};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let shape = ApiToken::new("pg", TokenAlphabet::Base62, 160.0).unwrap();
+/// let mut rng = StdRng::seed_from_u64(1);
+///
+/// assert_eq!(shape.payload_len(), 27);
+/// assert!(shape.bits() >= 160.0);
+///
+/// for _ in 0..1000 {
+///     let token = shape.generate(&mut rng);
+///
+///     assert!(token.starts_with("pg_"));
+///     assert_eq!(token.len(), shape.length());
+///     assert_eq!(verify_api_token(&token).unwrap(), shape);
+/// }
+///
+/// // crockford payloads are longer for the same bits
+/// let shape = ApiToken::new("acme_live", TokenAlphabet::Crockford32, 160.0).unwrap();
+/// let token = shape.generate(&mut rng);
+///
+/// assert_eq!(shape.payload_len(), 32);
+/// assert_eq!(verify_api_token(&token).unwrap(), shape);
+///
+/// let error = |prefix| ApiToken::new(prefix, TokenAlphabet::Base62, 160.0).unwrap_err().to_string();
+///
+/// assert!(error("").contains("empty"));
+/// assert!(error("pg-live").contains("letters, digits and _"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiToken {
+    prefix: String,
+    alphabet: TokenAlphabet,
+    payload_len: usize,
+}
+
+impl ApiToken {
+    /// Tokens starting with `prefix` and an underscore, with a payload of at least `bits`.
+    pub fn new(prefix: &str, alphabet: TokenAlphabet, bits: f64) -> Result<ApiToken, PassGenError> {
+        check_prefix(prefix).map_err(PassGenError::Usage)?;
+
+        let payload_len = (bits / alphabet.bits()).ceil().max(1.0) as usize;
+
+        Ok(ApiToken { prefix: prefix.to_string(), alphabet, payload_len })
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn alphabet(&self) -> TokenAlphabet {
+        self.alphabet
+    }
+
+    pub fn payload_len(&self) -> usize {
+        self.payload_len
+    }
+
+    /// Random bits of the payload, the prefix and checksum add none.
+    pub fn bits(&self) -> f64 {
+        self.payload_len as f64 * self.alphabet.bits()
+    }
+
+    /// Characters of a whole token.
+    pub fn length(&self) -> usize {
+        self.prefix.len() + 1 + self.payload_len + API_TOKEN_CHECKSUM_LEN
+    }
+
+    pub fn generate(&self, rng: &mut impl Rng) -> String {
+        let chars = self.alphabet.chars();
+        let payload: String = (0..self.payload_len).map(|_| chars[rng.gen_range(0..chars.len())] as char).collect();
+
+        format!("{}_{}{}", self.prefix, payload, token_checksum(&payload, self.alphabet))
+    }
+}
+
+fn check_prefix(prefix: &str) -> Result<(), String> {
+    if prefix.is_empty() {
+        return Err(String::from("token prefix is empty"));
+    }
+
+    if let Some(c) = prefix.chars().find(|c| !(c.is_ascii_alphanumeric() || *c == '_')) {
+        return Err(format!("invalid token prefix {:?}, {:?} is not one of letters, digits and _", prefix, c));
+    }
+
+    Ok(())
+}
+
+/// Checks the structure and checksum of `token`, without any network access, and returns its
+/// shape.
+///
+/// The payload is whatever follows the last underscore, minus the checksum. A payload that
+/// could be either alphabet is accepted if the checksum matches one of them.
+///
+/// ```
+/// use pass_gen::{token_checksum, verify_api_token, TokenAlphabet};
+///
+/// let token = format!("pg_123456789{}", token_checksum("123456789", TokenAlphabet::Base62));
+/// assert_eq!(verify_api_token(&token).unwrap().alphabet(), TokenAlphabet::Base62);
+///
+/// let error = |token: &str| verify_api_token(token).unwrap_err().to_string();
+///
+/// // a single changed character of the payload or the checksum is caught
+/// assert!(error(&token.replace("pg_1", "pg_2")).contains("checksum"));
+/// assert!(error(&token.replace("RME", "RMe")).contains("checksum"));
+///
+/// assert!(error("123456789abcdef").contains("prefix"));
+/// assert!(error("pg_3jZRME").contains("payload"));
+/// assert!(error("pg_12345-6789abc").contains("'-'"));
+/// ```
+pub fn verify_api_token(token: &str) -> Result<ApiToken, PassGenError> {
+    let invalid = |msg: String| PassGenError::InvalidData(msg);
+
+    let (prefix, body) = match token.trim().rsplit_once('_') {
+        Some((prefix, body)) => (prefix, body),
+        None => return Err(invalid(String::from("token has no prefix, expected <prefix>_<payload><checksum>"))),
+    };
+
+    check_prefix(prefix).map_err(invalid)?;
+
+    if let Some(c) = body.chars().find(|&c| TokenAlphabet::ALL.iter().all(|alphabet| !alphabet.contains(c))) {
+        return Err(invalid(format!("token contains {:?}, which is in no token alphabet", c)));
+    }
+
+    if body.len() <= API_TOKEN_CHECKSUM_LEN {
+        return Err(invalid(format!("token payload is missing, only {} characters follow the prefix", body.len())));
+    }
+
+    let (payload, checksum) = body.split_at(body.len() - API_TOKEN_CHECKSUM_LEN);
+
+    TokenAlphabet::ALL
+        .into_iter()
+        .filter(|alphabet| body.chars().all(|c| alphabet.contains(c)))
+        .find(|alphabet| token_checksum(payload, *alphabet) == checksum)
+        .map(|alphabet| ApiToken { prefix: prefix.to_string(), alphabet, payload_len: payload.len() })
+        .ok_or_else(|| invalid(format!("token checksum {:?} does not match its payload", checksum)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32_matches_the_check_value() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+    }
+}
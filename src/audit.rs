@@ -0,0 +1,84 @@
+use crate::{history::unix_time, json_number, json_quote, Config, PassGenError};
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+};
+
+/* -------------------- *
+ *      AUDIT LOG       *
+ * -------------------- */
+/// A log of every run, opened for appending so lines of concurrent runs never overwrite each other.
+#[derive(Debug)]
+pub struct AuditLog {
+    pub path: String,
+    pub file: File,
+}
+
+impl AuditLog {
+    /// Opens `path` for appending, created readable only by the user.
+    pub fn open(path: String) -> Result<Self, PassGenError> {
+        let mut options = OpenOptions::new();
+        options.append(true).create(true);
+
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        match options.open(&path) {
+            Ok(file) => Ok(AuditLog { path, file }),
+            Err(source) => Err(PassGenError::Io { action: "opening audit log", path: Some(path), source }),
+        }
+    }
+
+    /// Appends a line about the run of `config`.
+    ///
+    /// Only metadata of the run, the passwords, their tokens and the seed never reach this line.
+    pub fn record(&self, config: &Config) -> Result<(), PassGenError> {
+        // templates, --length and sentences vary the tokens per password
+        let token_count = match (&config.template, &config.length, &config.sentence) {
+            (None, None, None) => (config.token_count + config.checksum as u32).to_string(),
+            _ => String::from("null"),
+        };
+
+        let line = format!(
+            "{{\"time\": {}, \"user\": {}, \"source\": {}, \"token_count\": {}, \"entropy_bits\": {}, \
+            \"passwords\": {}}}\n",
+            unix_time(),
+            json_quote(&username()),
+            json_quote(&config.source()),
+            token_count,
+            json_number(config.min_bits().unwrap_or_else(|| config.entropy())),
+            config.number
+        );
+
+        // a single write, appended whole even when other runs write at the same time
+        (&self.file).write_all(line.as_bytes()).map_err(|source| PassGenError::Io {
+            action: "writing audit log",
+            path: Some(self.path.clone()),
+            source,
+        })
+    }
+}
+
+// the login name, from the password database where there is one
+fn username() -> String {
+    #[cfg(unix)]
+    {
+        let mut passwd: libc::passwd = unsafe { std::mem::zeroed() };
+        let mut result = std::ptr::null_mut();
+        let mut buf = vec![0 as libc::c_char; 4096];
+
+        // SAFETY: getpwuid_r only writes into passwd and buf, whose size it is given
+        let res = unsafe { libc::getpwuid_r(libc::getuid(), &mut passwd, buf.as_mut_ptr(), buf.len(), &mut result) };
+
+        if res == 0 && !result.is_null() {
+            // SAFETY: pw_name points to a nul terminated string inside buf
+            let name = unsafe { std::ffi::CStr::from_ptr(passwd.pw_name) };
+            return name.to_string_lossy().into_owned();
+        }
+    }
+
+    ["USER", "USERNAME"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|name| !name.is_empty()))
+        .unwrap_or_else(|| String::from("unknown"))
+}
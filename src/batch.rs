@@ -0,0 +1,551 @@
+#[cfg(feature = "age")]
+use crate::{age_encrypt, wipe};
+use crate::{
+    grapheme_len, wipe_string, write_columns, write_footer, write_header, write_password, write_stats, Config, Format,
+    Generator, Identifier, PassGenError, Rejections, Retry, SecretSharing, Secrets, Sentence, Ulids,
+};
+use rand::{
+    rngs::{OsRng, StdRng},
+    Rng, SeedableRng,
+};
+use rand_chacha::ChaCha20Rng;
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+    thread,
+};
+
+/// Passwords drawn from one rng, a batch is split into chunks of this size across the jobs.
+pub(crate) const CHUNK_SIZE: u32 = 4096;
+
+/// Reason a candidate longer than `--max-length` is drawn again.
+pub(crate) const TOO_LONG: &str = "too long";
+/// Reason a candidate seen before in a `--unique` batch is drawn again.
+pub(crate) const REPEATED: &str = "repeated in the batch";
+/// Reason a candidate containing a dictionary word is drawn again.
+pub(crate) const DICTIONARY_HIT: &str = "containing a dictionary word";
+/// Reason a candidate found in the history is drawn again.
+pub(crate) const ISSUED: &str = "issued before";
+/// Reason a candidate found in the breach filter is drawn again.
+pub(crate) const BREACHED: &str = "in the breach filter";
+
+/* -------------------- *
+ *        BATCH         *
+ * -------------------- */
+/// Every check a candidate of `config` has to pass, cheapest first.
+///
+/// `seen` holds the passwords of the batch so far, it is only consulted with `--unique`.
+pub(crate) fn checks<'c>(config: &'c Config, seen: &'c HashSet<String>) -> Retry<'c, String> {
+    let mut retry = Retry::new(config.retry_limit);
+
+    if let Some(max) = config.max_length {
+        retry.check(TOO_LONG, move |password: &String| grapheme_len(password) <= max);
+    }
+
+    if config.start_with_letter {
+        retry.check("not starting with a letter", |password: &String| password.starts_with(char::is_alphabetic));
+    }
+
+    for class in config.require.iter() {
+        retry.check(format!("missing {}", class.name()), move |password: &String| {
+            password.chars().any(|c| class.matches(c))
+        });
+    }
+
+    if let Some((_, policy)) = &config.policy {
+        retry.check("against the policy", |password: &String| policy.allows(password));
+    }
+
+    if let Some(dictionary) = &config.dictionary {
+        retry.check(DICTIONARY_HIT, |password: &String| !dictionary.contains_word(password));
+    }
+
+    if config.unique {
+        retry.check(REPEATED, |password: &String| !seen.contains(password));
+    }
+
+    if let Some(history) = &config.history {
+        retry.check(ISSUED, |password: &String| !history.history.contains(password));
+    }
+
+    if let Some((_, filter)) = &config.breach_filter {
+        retry.check(BREACHED, |password: &String| !filter.contains(password));
+    }
+
+    retry
+}
+
+/// Draws `password` again from `gen` until every one of [`checks`] passes.
+///
+/// Rejected candidates are counted into `rejected`. Giving up is an
+/// [`Unsatisfiable`](PassGenError::Unsatisfiable) error with a hint for the most common reason.
+pub(crate) fn redraw<R: Rng>(
+    config: &Config,
+    gen: &mut Generator<R>,
+    password: &mut String,
+    seen: &HashSet<String>,
+    rejected: &mut Rejections,
+) -> Result<(), PassGenError> {
+    let redraw = |password: &mut String| {
+        password.clear();
+        gen.push_password(password);
+    };
+
+    checks(config, seen).until_accepted(password, redraw, rejected).map_err(|rejections| {
+        let hint = match rejections.most_common() {
+            Some(TOO_LONG) => "use fewer or shorter tokens",
+            Some(REPEATED) => "the batch size exceeds what the configuration can support",
+            Some(ISSUED) => "prune the history or use a larger pool",
+            _ => "raise --retry-limit or relax the constraints",
+        };
+
+        PassGenError::Unsatisfiable(format!("{}, {}", rejections, hint))
+    })
+}
+
+/// The passwords of chunk number `chunk` of a batch and their usernames, drawn after them
+/// from an rng seeded with `seed`.
+///
+/// The passwords are not checked yet, see [`redraw`].
+pub(crate) fn generate_chunk(config: &Config, chunk: u32, seed: [u8; 32]) -> (Vec<String>, Vec<String>) {
+    let start = chunk * CHUNK_SIZE;
+    let end = config.number.min(start + CHUNK_SIZE);
+
+    let mut gen = config.generator(StdRng::from_seed(seed));
+    let passwords = gen.generate_many((end - start) as usize);
+
+    let usernames = match &config.username {
+        Some(username) => passwords.iter().map(|_| username.generate(gen.rng())).collect(),
+        None => Vec::new(),
+    };
+
+    (passwords, usernames)
+}
+
+/// One checked password of `config` and its username, drawn after the password from the
+/// same rng.
+pub(crate) fn single_password<R: Rng>(
+    config: &Config,
+    rng: R,
+    rejected: &mut Rejections,
+) -> Result<(String, Option<String>), PassGenError> {
+    let mut gen = config.generator(rng);
+    let mut password = gen.generate();
+
+    redraw(config, &mut gen, &mut password, &HashSet::new(), rejected)?;
+
+    let username = config.username.as_ref().map(|username| username.generate(gen.rng()));
+
+    Ok((password, username))
+}
+
+/// Writes the same password as [`single_password`] without ever holding all of it.
+///
+/// The tokens are borrowed from the pool, so only the separators need wiping.
+pub(crate) fn stream_password<R: Rng>(config: &Config, rng: R, out: &mut impl Write) -> io::Result<()> {
+    let mut gen = config.generator(rng);
+    let mut tokens = gen.tokens();
+    let mut sep = String::new();
+
+    while let Some(token) = tokens.next() {
+        out.write_all(token.as_bytes())?;
+
+        if tokens.len() != 0 {
+            tokens.push_separator(&mut sep);
+
+            let res = out.write_all(sep.as_bytes());
+            wipe_string(&mut sep);
+            res?;
+        }
+    }
+
+    Ok(())
+}
+
+/// How many different passwords `config` can draw, checked against the batch size of
+/// `--unique`.
+pub(crate) fn unique_combinations(config: &Config) -> f64 {
+    match (&config.template, config.length_bits(), &config.acrostic) {
+        _ if config.sentence.is_some() => config.sentence.as_ref().map_or(0.0, Sentence::combinations),
+        _ if config.packing.is_some() => config.packing.map_or(0.0, |packing| (packing.bits() as f64).exp2()),
+        (Some(template), _, _) => template.bits(config.token_data.as_ref()).exp2(),
+        (None, Some((min, _)), _) => min.exp2(),
+        (None, None, Some(acrostic)) => acrostic.bits(config.token_data.as_ref()).exp2(),
+        (None, None, None) => (config.token_data.len() as f64).powf(config.token_count as f64),
+    }
+}
+
+
+/* -------------------- *
+ *        WRITE         *
+ * -------------------- */
+/// Writes the passwords of `config` to `out` in its output format, with the stats, the
+/// `--tty-echo` copy and the history of the batch.
+///
+/// The seed of `secrets` takes precedence over `--seed`, its pepper is appended. A batch laid out
+/// in [`columns`](Config::columns) fills `width` columns of the terminal.
+///
+/// ```
+/// use pass_gen::{write_passwords, ConfigBuilder, Secrets};
+///
+/// let config = ConfigBuilder::new().preset("number").count(4).number(3).seed(1).no_config(true).build().unwrap();
+/// let mut out = Vec::new();
+///
+/// write_passwords(&config, &Secrets::default(), 80, &mut out).unwrap();
+///
+/// let out = String::from_utf8(out).unwrap();
+/// assert_eq!(out.lines().count(), 3);
+/// assert!(out.lines().all(|line| line.len() == 4 && line.bytes().all(|byte| byte.is_ascii_digit())));
+/// ```
+pub fn write_passwords(
+    config: &Config,
+    secrets: &Secrets,
+    width: usize,
+    out: &mut impl Write,
+) -> Result<(), PassGenError> {
+    let derived = secrets.seed;
+
+    write_header(config, out)?;
+
+    // identifiers never touch the token pool
+    if let Some(identifier) = &config.identifier {
+        return match (derived, config.seed) {
+            (Some(seed), _) => write_identifiers(config, identifier, ChaCha20Rng::from_seed(seed), out),
+            (None, Some(seed)) => write_identifiers(config, identifier, StdRng::seed_from_u64(seed), out),
+            (None, None) => write_identifiers(config, identifier, rand::thread_rng(), out),
+        };
+    }
+
+    let mut rejected = Rejections::default();
+    let mut issued = Vec::new();
+
+    // huge passwords go to the writer token by token
+    if config.streams() {
+        match (derived, config.seed) {
+            (Some(seed), _) => stream_password(config, ChaCha20Rng::from_seed(seed), out)?,
+            (None, Some(seed)) => stream_password(config, StdRng::seed_from_u64(seed), out)?,
+            (None, None) => stream_password(config, rand::thread_rng(), out)?,
+        }
+
+        return Ok(write_footer(config, out)?);
+    }
+
+    // fast path for the common single password case
+    if config.number == 1 {
+        let (mut password, username) = match (derived, config.seed) {
+            (Some(seed), _) => single_password(config, ChaCha20Rng::from_seed(seed), &mut rejected)?,
+            (None, Some(seed)) => single_password(config, StdRng::seed_from_u64(seed), &mut rejected)?,
+            (None, None) => single_password(config, rand::thread_rng(), &mut rejected)?,
+        };
+
+        if let Some(history) = &config.history {
+            issued.push(history.history.hash(&password));
+        }
+
+        match &config.split {
+            Some(sharing) => write_shares(config, sharing, out, &password)?,
+            None => write_entry(config, secrets, out, 0, &password, username.as_deref())?,
+        }
+
+        wipe_string(&mut password);
+        report_discarded(config, &rejected);
+        write_footer(config, out)?;
+
+        return record_issued(config, &issued);
+    }
+
+    // every chunk gets its own rng seeded from the master rng in index order,
+    // so the output only depends on the seed and not on the number of jobs
+    let mut master = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    // rejected passwords are re-drawn in index order from a dedicated rng for the same reason
+    let retry_seed = if config.redraws() { master.gen() } else { [0; 32] };
+    let mut retry = config.generator(StdRng::from_seed(retry_seed));
+    let mut seen = HashSet::new();
+
+    if config.unique {
+        let combinations = unique_combinations(config);
+
+        if combinations < config.number as f64 {
+            return Err(PassGenError::Unsatisfiable(format!(
+                "cannot generate {} unique passwords, the configuration only supports {}",
+                config.number, combinations
+            )));
+        }
+    }
+
+    // a terminal gets the whole batch at once, laid out in columns like ls
+    let in_columns = config.columns();
+    let mut grid = Vec::new();
+
+    let chunk_count = config.number.div_ceil(CHUNK_SIZE);
+    let mut chunk = 0;
+
+    while chunk < chunk_count {
+        let round: Vec<(u32, [u8; 32])> = (chunk..chunk_count.min(chunk + config.jobs))
+            .map(|c| (c, master.gen()))
+            .collect();
+
+        let chunks: Vec<(Vec<String>, Vec<String>)> = thread::scope(|scope| {
+            let handles: Vec<_> = round
+                .iter()
+                .map(|&(c, seed)| scope.spawn(move || generate_chunk(config, c, seed)))
+                .collect();
+
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        let (passwords, usernames): (Vec<_>, Vec<_>) = chunks.into_iter().unzip();
+        let usernames: Vec<String> = usernames.into_iter().flatten().collect();
+
+        for (i, mut password) in passwords.into_iter().flatten().enumerate() {
+            let index = chunk * CHUNK_SIZE + i as u32;
+
+            redraw(config, &mut retry, &mut password, &seen, &mut rejected)?;
+
+            if config.unique {
+                seen.insert(password.clone());
+            }
+
+            if let Some(history) = &config.history {
+                issued.push(history.history.hash(&password));
+            }
+
+            if in_columns {
+                write_stats(config, index, &password, &mut io::stderr().lock())?;
+
+                if let Some(peppered) = secrets.peppered(config, &password) {
+                    wipe_string(&mut password);
+                    password = peppered;
+                }
+
+                echo_tty(config, &password)?;
+                grid.push(password);
+                continue;
+            }
+
+            write_entry(config, secrets, out, index, &password, usernames.get(i).map(String::as_str))?;
+            wipe_string(&mut password);
+        }
+
+        chunk += round.len() as u32;
+    }
+
+    if in_columns {
+        write_columns(out, &grid, width)?;
+    }
+
+    for mut password in seen.drain().chain(grid) {
+        wipe_string(&mut password);
+    }
+
+    report_discarded(config, &rejected);
+    write_footer(config, out)?;
+
+    record_issued(config, &issued)
+}
+
+/// Writes the output of [`write_passwords`] encrypted to the age recipients of `config` and
+/// armored, after the plaintext with `--also-plain`.
+#[cfg(feature = "age")]
+pub fn write_age(
+    config: &Config,
+    secrets: &Secrets,
+    width: usize,
+    out: &mut impl Write,
+) -> Result<(), PassGenError> {
+    let mut plaintext = Vec::with_capacity(64 * 1024);
+
+    let res = write_passwords(config, secrets, width, &mut plaintext)
+        .and_then(|_| age_encrypt(&plaintext, &config.age_recipients, &mut OsRng))
+        .and_then(|armor| {
+            if config.also_plain {
+                out.write_all(&plaintext)?;
+                out.write_all(b"\n\n")?;
+            }
+
+            Ok(out.write_all(armor.as_bytes())?)
+        });
+
+    wipe(&mut plaintext);
+    res
+}
+
+/// Writes the password number `index` of a batch like [`write_passwords`] does, its stats to
+/// stderr, the pepper appended and a copy to the terminal.
+///
+/// ```
+/// use pass_gen::{write_entry, ConfigBuilder, Secrets};
+///
+/// let config = ConfigBuilder::new().number(2).one_per_line(true).no_config(true).build().unwrap();
+/// let mut out = Vec::new();
+///
+/// let secrets = Secrets::default();
+///
+/// write_entry(&config, &secrets, &mut out, 0, "correct horse", None).unwrap();
+/// write_entry(&config, &secrets, &mut out, 1, "battery staple", None).unwrap();
+/// assert_eq!(out, b"correct horse\nbattery staple");
+/// ```
+pub fn write_entry(
+    config: &Config,
+    secrets: &Secrets,
+    out: &mut impl Write,
+    index: u32,
+    password: &str,
+    username: Option<&str>,
+) -> io::Result<()> {
+    if config.format != Format::Json {
+        write_stats(config, index, password, &mut io::stderr().lock())?;
+    }
+
+    // the pepper is part of what is written, never of the stats
+    let mut peppered = secrets.peppered(config, password);
+    let password = peppered.as_deref().unwrap_or(password);
+    let res = echo_tty(config, password).and_then(|_| write_password(config, out, index, password, username));
+
+    if let Some(peppered) = &mut peppered {
+        wipe_string(peppered);
+    }
+
+    res
+}
+
+// one per line, ulids count up within the millisecond so the batch stays in order
+fn write_identifiers<R: Rng>(
+    config: &Config,
+    identifier: &Identifier,
+    mut rng: R,
+    out: &mut impl Write,
+) -> Result<(), PassGenError> {
+    let mut ulids = Ulids::default();
+
+    for index in 0..config.number {
+        if index != 0 {
+            out.write_all(b"\n")?;
+        }
+
+        let value = identifier.generate(&mut ulids, &mut rng)?;
+
+        echo_tty(config, &value)?;
+        out.write_all(value.as_bytes())?;
+    }
+
+    Ok(())
+}
+
+// every share under a header with its index, the password only with --also-plain
+fn write_shares(config: &Config, sharing: &SecretSharing, out: &mut impl Write, password: &str) -> io::Result<()> {
+    write_stats(config, 0, password, &mut io::stderr().lock())?;
+
+    if config.also_plain {
+        write!(out, "{}\n\n", password)?;
+    }
+
+    // the coefficients never come from --seed, a seeded password stays reproducible but its shares do not
+    for share in sharing.split(password.as_bytes(), &mut OsRng) {
+        let mut hex = share.encode();
+        let res = write!(
+            out,
+            "{}share {} of {}, any {} reconstruct the password\n{}",
+            if share.index() != 1 { "\n\n" } else { "" },
+            share.index(),
+            sharing.shares(),
+            sharing.threshold(),
+            hex
+        );
+
+        wipe_string(&mut hex);
+        res?;
+    }
+
+    Ok(())
+}
+
+fn report_discarded(config: &Config, rejected: &Rejections) {
+    if config.breach_filter.is_some() {
+        eprintln!(
+            "pass-gen: discarded {} candidate(s) found in the breach filter \
+            (false positives only cause a harmless regeneration)",
+            rejected.count(BREACHED)
+        );
+    }
+
+    if config.dictionary.is_some() {
+        eprintln!("pass-gen: discarded {} candidate(s) containing a dictionary word", rejected.count(DICTIONARY_HIT));
+    }
+
+    if config.history.is_some() {
+        eprintln!("pass-gen: discarded {} candidate(s) issued before", rejected.count(ISSUED));
+    }
+}
+
+// a copy on the terminal that leaves stdout as it is, one password per line
+fn echo_tty(config: &Config, password: &str) -> io::Result<()> {
+    let mut tty = match &config.tty {
+        Some(tty) => tty,
+        None => return Ok(()),
+    };
+
+    match std::env::var_os("NO_COLOR") {
+        Some(_) => writeln!(tty, "{}", password),
+        None => writeln!(tty, "\x1b[1m{}\x1b[0m", password),
+    }
+}
+
+// written once the passwords are out, a failed write afterwards only wastes them
+fn record_issued(config: &Config, issued: &[[u8; 32]]) -> Result<(), PassGenError> {
+    match &config.history {
+        Some(history) => history.append(issued).map_err(|source| PassGenError::Io {
+            action: "writing history file",
+            path: Some(history.path.clone()),
+            source,
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConfigBuilder;
+
+    #[test]
+    fn redraw_gives_up_with_a_hint() {
+        // every digit is taken already
+        let config = ConfigBuilder::new().preset("number").count(1).unique(true).retry_limit(100).no_config(true);
+        let config = config.build().unwrap();
+        let seen: HashSet<String> = (0..10).map(|digit| digit.to_string()).collect();
+
+        let mut gen = config.generator(StdRng::seed_from_u64(1));
+        let mut password = gen.generate();
+        let mut rejected = Rejections::default();
+
+        let err = redraw(&config, &mut gen, &mut password, &seen, &mut rejected).unwrap_err();
+
+        assert!(matches!(err, PassGenError::Unsatisfiable(msg) if msg.ends_with("what the configuration can support")));
+        assert_eq!(rejected.total(), 100);
+    }
+
+    #[test]
+    fn chunks_are_reproducible_from_their_seed() {
+        let config = ConfigBuilder::new().preset("number").count(6).number(5000).no_config(true).build().unwrap();
+
+        let (first, usernames) = generate_chunk(&config, 0, [7; 32]);
+        let (last, _) = generate_chunk(&config, 1, [7; 32]);
+
+        assert_eq!((first.len(), last.len()), (CHUNK_SIZE as usize, 5000 - CHUNK_SIZE as usize));
+        assert!(usernames.is_empty());
+
+        // the same seed draws the same passwords
+        assert_eq!(first, generate_chunk(&config, 0, [7; 32]).0);
+    }
+
+    #[test]
+    fn combinations_of_a_token_pool() {
+        let config = ConfigBuilder::new().preset("number").count(2).no_config(true).build().unwrap();
+        assert_eq!(unique_combinations(&config), 100.0);
+    }
+}
@@ -0,0 +1,285 @@
+use crate::PassGenError;
+use std::io::{self, BufRead, ErrorKind, Read, Write};
+
+/* -------------------- *
+ *        SHA-1         *
+ * -------------------- */
+/// SHA-1 digest of `data`, the hash breach corpora are published with.
+///
+/// ```
+/// let digest = pass_gen::sha1(b"abc");
+///
+/// assert_eq!(digest[..4], [0xa9, 0x99, 0x3e, 0x36]);
+/// ```
+pub fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut state: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+    let mut message = data.to_vec();
+    message.push(0x80);
+
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+
+    message.extend_from_slice(&(data.len() as u64 * 8).to_be_bytes());
+
+    for block in message.chunks_exact(64) {
+        let mut w = [0u32; 80];
+
+        for (i, word) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+
+        for (i, &word) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5a827999),
+                20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+                _ => (b ^ c ^ d, 0xca62c1d6),
+            };
+
+            let temp = a.rotate_left(5).wrapping_add(f).wrapping_add(e).wrapping_add(k).wrapping_add(word);
+
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+
+        for (s, x) in state.iter_mut().zip([a, b, c, d, e]) {
+            *s = s.wrapping_add(x);
+        }
+    }
+
+    let mut digest = [0; 20];
+
+    for (chunk, s) in digest.chunks_exact_mut(4).zip(state) {
+        chunk.copy_from_slice(&s.to_be_bytes());
+    }
+
+    digest
+}
+
+
+/* -------------------- *
+ *    BREACH FILTER     *
+ * -------------------- */
+const MAGIC: &[u8; 8] = b"PASSGENF";
+const VERSION: u32 = 1;
+
+/// A bloom filter over the SHA-1 hashes of breached passwords.
+///
+/// False positives are possible, so a hit means the password is probably
+/// breached, while a miss means it is certainly not in the corpus.
+///
+/// ```
+/// use pass_gen::{sha1, BreachFilter};
+///
+/// let mut filter = BreachFilter::new(2, 0.001);
+/// filter.insert(&sha1(b"password"));
+/// filter.insert(&sha1(b"123456"));
+///
+/// let mut file = Vec::new();
+/// filter.write_to(&mut file).unwrap();
+/// let filter = BreachFilter::read_from(file.as_slice()).unwrap();
+///
+/// assert!(filter.contains("password"));
+/// assert!(!filter.contains("correct horse battery staple"));
+/// ```
+#[derive(Debug)]
+pub struct BreachFilter {
+    hashes: u32,
+    bits: u64,
+    items: u64,
+    fp_rate: f64,
+    words: Vec<u64>,
+}
+
+impl BreachFilter {
+    /// An empty filter sized for `items` hashes at the given false positive rate.
+    pub fn new(items: u64, fp_rate: f64) -> Self {
+        let ln2 = std::f64::consts::LN_2;
+
+        let bits = ((-(items.max(1) as f64) * fp_rate.ln() / (ln2 * ln2)).ceil() as u64).max(64);
+        let hashes = ((bits as f64 / items.max(1) as f64 * ln2).round() as u32).clamp(1, 64);
+
+        Self {
+            hashes,
+            bits,
+            items,
+            fp_rate,
+            words: vec![0; bits.div_ceil(64) as usize],
+        }
+    }
+
+    /// Number of hashes the filter was sized for.
+    pub fn items(&self) -> u64 {
+        self.items
+    }
+
+    /// Configured false positive rate.
+    pub fn fp_rate(&self) -> f64 {
+        self.fp_rate
+    }
+
+    pub fn insert(&mut self, digest: &[u8; 20]) {
+        for bit in self.positions(digest) {
+            self.words[(bit / 64) as usize] |= 1 << (bit % 64);
+        }
+    }
+
+    pub fn contains_hash(&self, digest: &[u8; 20]) -> bool {
+        self.positions(digest).all(|bit| self.words[(bit / 64) as usize] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Whether `password` is probably in the breach corpus.
+    pub fn contains(&self, password: &str) -> bool {
+        self.contains_hash(&sha1(password.as_bytes()))
+    }
+
+    // the digest is already uniform, so two halves give all positions by double hashing
+    fn positions(&self, digest: &[u8; 20]) -> impl Iterator<Item = u64> {
+        let h1 = u64::from_le_bytes(digest[0..8].try_into().unwrap());
+        let h2 = u64::from_le_bytes(digest[8..16].try_into().unwrap()) | 1;
+        let bits = self.bits;
+
+        (0..self.hashes as u64).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % bits)
+    }
+
+    pub fn write_to(&self, out: &mut impl Write) -> io::Result<()> {
+        out.write_all(MAGIC)?;
+        out.write_all(&VERSION.to_le_bytes())?;
+        out.write_all(&self.hashes.to_le_bytes())?;
+        out.write_all(&self.bits.to_le_bytes())?;
+        out.write_all(&self.items.to_le_bytes())?;
+        out.write_all(&self.fp_rate.to_le_bytes())?;
+
+        for word in &self.words {
+            out.write_all(&word.to_le_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    pub fn read_from(reader: impl Read) -> Result<BreachFilter, PassGenError> {
+        let mut reader = reader;
+        let mut header = [0; 40];
+
+        read_exact(&mut reader, &mut header)?;
+
+        if &header[0..8] != MAGIC {
+            return Err(invalid("not a pass-gen filter file"));
+        }
+
+        let field = |range: std::ops::Range<usize>| {
+            let mut bytes = [0; 8];
+            bytes[..range.len()].copy_from_slice(&header[range]);
+            u64::from_le_bytes(bytes)
+        };
+
+        let version = field(8..12) as u32;
+
+        if version != VERSION {
+            return Err(invalid(&format!("unsupported version {}, expected {}", version, VERSION)));
+        }
+
+        let hashes = field(12..16) as u32;
+        let bits = field(16..24);
+        let items = field(24..32);
+        let fp_rate = f64::from_bits(field(32..40));
+
+        if !(1..=64).contains(&hashes) || bits == 0 || !(fp_rate > 0.0 && fp_rate < 1.0) {
+            return Err(invalid("corrupt header"));
+        }
+
+        let len = usize::try_from(bits.div_ceil(64)).map_err(|_| invalid("corrupt header"))?;
+        let mut words = Vec::with_capacity(len);
+        let mut word = [0; 8];
+
+        for _ in 0..len {
+            read_exact(&mut reader, &mut word)?;
+            words.push(u64::from_le_bytes(word));
+        }
+
+        match reader.read(&mut word) {
+            Ok(0) => {},
+            Ok(_) => return Err(invalid("trailing data after the filter")),
+            Err(source) => return Err(PassGenError::Io { action: "reading filter file", path: None, source }),
+        }
+
+        Ok(BreachFilter { hashes, bits, items, fp_rate, words })
+    }
+
+    /// Builds a filter from a hash list such as the ordered HIBP SHA-1 download.
+    ///
+    /// Every non-empty line has to start with 40 hex digits, anything after
+    /// them (like HIBP's `:count`) is ignored. `open` is called twice, once to
+    /// count the hashes and once to insert them.
+    pub fn from_hash_list<R: BufRead>(
+        mut open: impl FnMut() -> io::Result<R>,
+        fp_rate: f64,
+    ) -> Result<BreachFilter, PassGenError> {
+        let io_error = |source| PassGenError::Io { action: "reading hash list", path: None, source };
+
+        let mut items = 0;
+
+        for line in open().map_err(io_error)?.lines() {
+            if !line.map_err(io_error)?.trim().is_empty() {
+                items += 1;
+            }
+        }
+
+        let mut filter = BreachFilter::new(items, fp_rate);
+
+        for (nr, line) in open().map_err(io_error)?.lines().enumerate() {
+            let line = line.map_err(io_error)?;
+            let line = line.trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            match parse_hex(line.get(..40).unwrap_or("")) {
+                Some(digest) => filter.insert(&digest),
+                None => {
+                    let msg = format!("invalid sha-1 hash on line {} of the hash list", nr + 1);
+                    return Err(PassGenError::InvalidData(msg));
+                },
+            }
+        }
+
+        Ok(filter)
+    }
+}
+
+fn parse_hex(str: &str) -> Option<[u8; 20]> {
+    let mut digest = [0; 20];
+
+    if str.len() != 40 {
+        return None;
+    }
+
+    for (byte, pair) in digest.iter_mut().zip(str.as_bytes().chunks_exact(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+
+    Some(digest)
+}
+
+fn read_exact(reader: &mut impl Read, buf: &mut [u8]) -> Result<(), PassGenError> {
+    reader.read_exact(buf).map_err(|source| match source.kind() {
+        ErrorKind::UnexpectedEof => invalid("file is truncated"),
+        _ => PassGenError::Io { action: "reading filter file", path: None, source },
+    })
+}
+
+fn invalid(msg: &str) -> PassGenError {
+    PassGenError::InvalidData(format!("invalid filter file: {}", msg))
+}
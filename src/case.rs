@@ -0,0 +1,209 @@
+use crate::{CharClass, ClassSet, PassGenError, TokenSource};
+use rand::Rng;
+use std::{collections::HashMap, ops::Range};
+
+// groups of tokens differing only in case are enumerated exactly up to this many letters
+const MAX_EXACT_LETTERS: usize = 20;
+
+/* -------------------- *
+ *     RANDOM CASE      *
+ * -------------------- */
+/// Uppercases each letter of the tokens independently with a probability.
+///
+/// Only lowercase letters with a single uppercase form of the same encoded length are
+/// changed, separators, digits and symbols never are.
+///
+/// ```
+/// use pass_gen::{preset, Generator, RandomCase, TokenSource};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let words = preset("word").unwrap().token_data;
+/// let mut plain = Generator::new(&words, 4, "-", StdRng::seed_from_u64(1));
+///
+/// // certainty draws nothing, so the tokens stay those of the seed
+/// let upper = RandomCase::new(1.0).unwrap();
+/// let mut gen = Generator::new(&words, 4, "-", StdRng::seed_from_u64(1)).random_case(&upper);
+///
+/// assert_eq!(gen.generate(), plain.generate().to_uppercase());
+/// assert_eq!(upper.bits(&words), 0.0);
+///
+/// // a coin flip per letter is one bit for each, the hyphen of buy-in is none
+/// let half = RandomCase::new(0.5).unwrap();
+/// let letters = words.range().map(|idx| words.get(idx).matches(char::is_lowercase).count()).sum::<usize>() as f64;
+///
+/// assert!((half.bits(&words) - letters / words.len() as f64).abs() < 1e-9);
+/// assert!(RandomCase::new(0.0).is_err() && RandomCase::new(1.5).is_err());
+/// ```
+///
+/// Tokens that only differ in case can turn into each other, so their outcomes are merged
+/// and shared ones count once. For pools that already hold both cases the change costs entropy:
+///
+/// ```
+/// use pass_gen::{preset, RandomCase, TokenData};
+///
+/// let case = RandomCase::new(0.5).unwrap();
+/// let pair = TokenData::from_reader("a\nA\nb\n".as_bytes()).unwrap();
+///
+/// // a stays a with 1/2 and becomes A with 1/2, A always stays
+/// let merged = -(0.5f64 * 0.5f64.log2() + 1.5 * 1.5f64.log2());
+/// assert!((case.bits(&pair) - (merged + 1.0) / 3.0).abs() < 1e-9);
+///
+/// assert!(case.bits(&preset("ascii").unwrap().token_data) < 0.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RandomCase {
+    probability: f64,
+}
+
+impl RandomCase {
+    pub const DEFAULT_PROBABILITY: f64 = 0.5;
+
+    /// `probability` is the chance of uppercasing each letter.
+    pub fn new(probability: f64) -> Result<RandomCase, PassGenError> {
+        if !(probability > 0.0 && probability <= 1.0) {
+            let msg = format!("random case probability must be above 0 and at most 1, got {}", probability);
+            return Err(PassGenError::Usage(msg));
+        }
+
+        Ok(RandomCase { probability })
+    }
+
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+
+    /// Uppercases letters of `password[range]` at random, the range stays valid.
+    pub fn apply<R: Rng>(&self, password: &mut String, range: Range<usize>, rng: &mut R) {
+        let mut buf = [0; 4];
+        let flips: Vec<(usize, char)> = password[range.clone()]
+            .char_indices()
+            .filter_map(|(idx, c)| upper(c).map(|upper| (range.start + idx, upper)))
+            .collect();
+
+        for (idx, upper) in flips {
+            if rng.gen_bool(self.probability) {
+                password.replace_range(idx..idx + upper.len_utf8(), upper.encode_utf8(&mut buf));
+            }
+        }
+    }
+
+    /// Average entropy in bits the case changes add to a token drawn uniformly from `source`.
+    ///
+    /// Each letter adds the binary entropy of the probability, except where tokens differing only
+    /// in case share outcomes.
+    pub fn bits(&self, source: &dyn TokenSource) -> f64 {
+        let mut groups: HashMap<String, Vec<&str>> = HashMap::new();
+
+        for idx in source.range() {
+            let token = source.get(idx);
+            groups.entry(token.chars().map(fold).collect()).or_default().push(token);
+        }
+
+        let total: f64 = groups.values().map(|tokens| self.group_bits(tokens)).sum();
+
+        total / source.len().max(1) as f64
+    }
+
+    // entropy of the outcomes of tokens with the same folded form, less that of the tokens themselves,
+    // summed rather than averaged
+    fn group_bits(&self, tokens: &[&str]) -> f64 {
+        let p = self.probability;
+
+        // per letter position of the group, whether each token already has it uppercase
+        let letters: Vec<Vec<bool>> = tokens
+            .iter()
+            .map(|token| {
+                token.chars().filter(|&c| fold(c) != c || upper(c).is_some()).map(|c| fold(c) != c).collect()
+            })
+            .collect();
+
+        let len = letters[0].len();
+        let lowercase = letters.iter().map(|token| token.iter().filter(|&&up| !up).count() as f64).sum::<f64>();
+
+        if tokens.len() == 1 || len > MAX_EXACT_LETTERS {
+            return lowercase * binary_entropy(p);
+        }
+
+        // every set of uppercase positions, weighted by how likely each token turns into it
+        let outcomes = (0..1usize << len).map(|set| {
+            letters
+                .iter()
+                .filter(|token| token.iter().enumerate().all(|(pos, &up)| !up || set & (1 << pos) != 0))
+                .map(|token| {
+                    let lowercase = token.iter().enumerate().filter(|(_, &up)| !up);
+
+                    lowercase.fold(1.0, |weight, (pos, _)| match set & (1 << pos) {
+                        0 => weight * (1.0 - p),
+                        _ => weight * p,
+                    })
+                })
+                .sum::<f64>()
+        });
+
+        outcomes.filter(|&weight| weight > 0.0).map(|weight| -weight * weight.log2()).sum()
+    }
+
+    /// The class weights of the tokens, with the letters uppercased at random.
+    pub fn source_weights(&self, source: &dyn TokenSource) -> [f64; 16] {
+        let p = self.probability;
+        let mut weights = [0.0; 16];
+
+        for idx in source.range() {
+            let token = source.get(idx);
+            let fixed: String = token.chars().filter(|&c| upper(c).is_none()).collect();
+            let base = ClassSet::of(&fixed);
+            let count = token.chars().count() - fixed.chars().count();
+
+            if count == 0 {
+                weights[base.bits()] += 1.0;
+                continue;
+            }
+
+            let (lower, upper) = ((1.0 - p).powi(count as i32), p.powi(count as i32));
+
+            weights[base.with(CharClass::Lower).bits()] += lower;
+            weights[base.with(CharClass::Upper).bits()] += upper;
+            weights[base.with(CharClass::Lower).with(CharClass::Upper).bits()] += 1.0 - lower - upper;
+        }
+
+        weights
+    }
+}
+
+/// Entropy in bits of an event of probability `p`, one bit at 0.5 and none at 0 or 1.
+pub(crate) fn binary_entropy(p: f64) -> f64 {
+    [p, 1.0 - p].iter().filter(|&&q| q > 0.0).map(|q| -q * q.log2()).sum()
+}
+
+// the uppercase form of a letter random case may produce
+fn upper(c: char) -> Option<char> {
+    let mut upper = c.to_uppercase();
+
+    match (upper.next(), upper.next()) {
+        (Some(u), None) if c.is_lowercase() && u != c && u.len_utf8() == c.len_utf8() => Some(u),
+        _ => None,
+    }
+}
+
+// letters random case could have produced map back to their lowercase form
+fn fold(c: char) -> char {
+    let mut lower = c.to_lowercase();
+
+    match (lower.next(), lower.next()) {
+        (Some(l), None) if upper(l) == Some(c) => l,
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binary_entropy_is_symmetric() {
+        assert_eq!(binary_entropy(0.5), 1.0);
+        assert_eq!(binary_entropy(1.0), 0.0);
+        assert!((binary_entropy(0.1) - 0.4689955935892812).abs() < 1e-12);
+        assert_eq!(binary_entropy(0.3), binary_entropy(0.7));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::PassGenError;
+use std::collections::HashSet;
+
+/* -------------------- *
+ *       CHARSET        *
+ * -------------------- */
+/// Expands a character list like `a-zA-Z0-9!@#` into its characters, in order and without repeats.
+///
+/// `x-y` is an inclusive range, `\` makes the next character literal and a dash at either end is
+/// a literal dash. Characters outside ascii are rejected unless `unicode` is set.
+pub(crate) fn parse_charset(spec: &str, unicode: bool) -> Result<String, PassGenError> {
+    let invalid = |column: usize, msg: &str| {
+        PassGenError::Usage(format!("invalid charset {:?} at column {}: {}", spec, column, msg))
+    };
+
+    // each character with its column, escapes resolved and marked as literal
+    let mut items = Vec::new();
+    let mut chars = spec.chars().enumerate();
+
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some((_, escaped)) => items.push((idx + 1, escaped, true)),
+                None => return Err(invalid(idx + 1, "nothing left to escape")),
+            },
+            _ => items.push((idx + 1, c, false)),
+        }
+    }
+
+    for &(column, c, _) in &items {
+        if !unicode && !c.is_ascii() {
+            return Err(invalid(column, &format!("{:?} is not ascii, pass --allow-unicode to use it", c)));
+        }
+
+        if c.is_control() {
+            return Err(invalid(column, &format!("{:?} is a control character", c)));
+        }
+    }
+
+    let mut charset = String::new();
+    let mut seen = HashSet::new();
+    let mut idx = 0;
+
+    while idx < items.len() {
+        let (column, first, _) = items[idx];
+
+        let range = match items.get(idx + 1..idx + 3) {
+            Some(&[(_, '-', false), (_, last, _)]) => Some(last),
+            _ => None,
+        };
+
+        let last = match range {
+            Some(last) if last < first => {
+                return Err(invalid(column, &format!("range {:?}-{:?} is descending", first, last)));
+            },
+            Some(last) => last,
+            None => first,
+        };
+
+        charset.extend((first..=last).filter(|&c| seen.insert(c)));
+        idx += if range.is_some() { 3 } else { 1 };
+    }
+
+    if charset.is_empty() {
+        return Err(PassGenError::Usage(String::from("charset is empty")));
+    }
+
+    Ok(charset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges_escapes_and_errors() {
+        assert_eq!(parse_charset("a-dx", false).unwrap(), "abcdx");
+
+        // duplicates across ranges are dropped
+        assert_eq!(parse_charset("a-cb-e", false).unwrap(), "abcde");
+
+        // a trailing or escaped dash is literal
+        assert_eq!(parse_charset("0-2-", false).unwrap(), "012-");
+        assert_eq!(parse_charset("a\\-c", false).unwrap(), "a-c");
+
+        // ranges must ascend
+        let err = parse_charset("az-a", false).unwrap_err().to_string();
+        assert!(err.contains("column 2") && err.contains("descending"));
+
+        // multibyte characters need to be allowed explicitly
+        assert!(parse_charset("α-γ", false).unwrap_err().to_string().contains("column 1"));
+        assert_eq!(parse_charset("α-γé", true).unwrap(), "αβγé");
+
+        assert!(parse_charset("\\", false).is_err());
+        assert!(parse_charset("", false).is_err());
+    }
+}
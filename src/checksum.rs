@@ -0,0 +1,77 @@
+use crate::{sha256, wipe_string, TokenSource};
+
+/// Number of hash bits that pick the checksum token.
+pub(crate) const CHECKSUM_BITS: u32 = 11;
+
+/* -------------------- *
+ *       CHECKSUM       *
+ * -------------------- */
+/// Index into a pool of `pool_len` tokens of the checksum token for `tokens`.
+///
+/// The first [`CHECKSUM_BITS`] bits of the SHA-256 of the lowercased tokens, joined by
+/// newlines, pick the token, wrapped around smaller pools. Case and separators are ignored,
+/// so a passphrase copied by hand still verifies.
+pub(crate) fn checksum_index<'t>(tokens: impl IntoIterator<Item = &'t str>, pool_len: usize) -> usize {
+    let mut normalized = String::new();
+
+    for (idx, token) in tokens.into_iter().enumerate() {
+        if idx != 0 {
+            normalized.push('\n');
+        }
+
+        normalized.extend(token.chars().flat_map(char::to_lowercase));
+    }
+
+    let digest = sha256(normalized.as_bytes());
+    wipe_string(&mut normalized);
+
+    let value = u16::from_be_bytes([digest[0], digest[1]]) >> (16 - CHECKSUM_BITS);
+
+    value as usize % pool_len.max(1)
+}
+
+/// The checksum token for `tokens`, drawn from `source`.
+pub(crate) fn checksum_token<'s, 't>(
+    tokens: impl IntoIterator<Item = &'t str>,
+    source: &'s dyn TokenSource,
+) -> &'s str {
+    source.get(checksum_index(tokens, source.len()))
+}
+
+/// Whether the last of `tokens` is the checksum of the ones before it, ignoring case.
+///
+/// ```
+/// use pass_gen::{preset, verify_checksum, Generator};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let words = preset("word").unwrap().token_data;
+/// let mut gen = Generator::new(&words, 5, " ", StdRng::seed_from_u64(1)).checksum();
+///
+/// for _ in 0..1000 {
+///     let password = gen.generate();
+///     let tokens: Vec<&str> = password.split(' ').collect();
+///
+///     assert_eq!(tokens.len(), 6);
+///     assert!(verify_checksum(&tokens, &words));
+///
+///     // copied by hand, in capitals and with other separators
+///     let copied = password.to_uppercase().replace(' ', "-");
+///     assert!(verify_checksum(&copied.split('-').collect::<Vec<_>>(), &words));
+/// }
+///
+/// let password = gen.generate();
+/// let mut tokens: Vec<&str> = password.split(' ').collect();
+///
+/// // a swapped word is caught
+/// tokens.swap(0, 1);
+/// assert!(!verify_checksum(&tokens, &words));
+/// assert!(!verify_checksum(&tokens[..1], &words));
+/// ```
+pub fn verify_checksum(tokens: &[&str], source: &dyn TokenSource) -> bool {
+    match tokens.split_last() {
+        Some((last, rest)) if !rest.is_empty() => {
+            checksum_token(rest.iter().copied(), source).to_lowercase() == last.to_lowercase()
+        },
+        _ => false,
+    }
+}
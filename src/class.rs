@@ -0,0 +1,226 @@
+use crate::TokenSource;
+use std::fmt;
+
+/* -------------------- *
+ *      CHAR CLASS      *
+ * -------------------- */
+/// A class of characters a site can require in a password.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharClass {
+    Lower,
+    Upper,
+    Digit,
+    Symbol,
+}
+
+impl CharClass {
+    pub const ALL: [CharClass; 4] = [CharClass::Lower, CharClass::Upper, CharClass::Digit, CharClass::Symbol];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            CharClass::Lower => "lower",
+            CharClass::Upper => "upper",
+            CharClass::Digit => "digit",
+            CharClass::Symbol => "symbol",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|class| class.name() == name)
+    }
+
+    /// Symbols are everything printable that is neither alphanumeric nor whitespace.
+    pub fn matches(self, c: char) -> bool {
+        match self {
+            CharClass::Lower => c.is_lowercase(),
+            CharClass::Upper => c.is_uppercase(),
+            CharClass::Digit => c.is_ascii_digit(),
+            CharClass::Symbol => !c.is_alphanumeric() && !c.is_whitespace() && !c.is_control(),
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+
+/* -------------------- *
+ *      CLASS SET       *
+ * -------------------- */
+/// A set of [`CharClass`]es.
+///
+/// ```
+/// use pass_gen::{CharClass, ClassSet};
+///
+/// let required = ClassSet::parse("upper,digit").unwrap();
+///
+/// assert!(ClassSet::of("Tr0ub4dor").contains_all(required));
+/// assert!(!ClassSet::of("troubador").contains(CharClass::Upper));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClassSet(u8);
+
+impl ClassSet {
+    /// Parses a comma separated list of class names.
+    pub fn parse(list: &str) -> Option<Self> {
+        list.split(',').map(|name| CharClass::from_name(name.trim())).try_fold(Self::default(), |set, class| {
+            class.map(|class| set.with(class))
+        })
+    }
+
+    /// Classes present in `str`.
+    pub fn of(str: &str) -> Self {
+        let mut set = Self::default();
+
+        for c in str.chars() {
+            for class in CharClass::ALL {
+                if class.matches(c) {
+                    set.insert(class);
+                }
+            }
+        }
+
+        set
+    }
+
+    pub fn union(self, other: ClassSet) -> Self {
+        ClassSet(self.0 | other.0)
+    }
+
+    pub fn with(mut self, class: CharClass) -> Self {
+        self.insert(class);
+        self
+    }
+
+    pub fn insert(&mut self, class: CharClass) {
+        self.0 |= class.bit();
+    }
+
+    pub fn contains(self, class: CharClass) -> bool {
+        self.0 & class.bit() != 0
+    }
+
+    pub fn contains_all(self, other: ClassSet) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = CharClass> {
+        CharClass::ALL.into_iter().filter(move |&class| self.contains(class))
+    }
+
+    // index into tables with one entry per set
+    pub(crate) fn bits(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl fmt::Display for ClassSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names: Vec<&str> = self.iter().map(CharClass::name).collect();
+
+        write!(f, "{}", names.join(","))
+    }
+}
+
+
+/* -------------------- *
+ *     PROBABILITY      *
+ * -------------------- */
+/// Probability that a password of `count` tokens from `source` contains every class in `required`.
+///
+/// Tokens are drawn uniformly and joined by `count - 1` separators drawn uniformly from `seps`,
+/// a fixed separator is a single entry. Re-drawing until the classes are present keeps the
+/// password uniform over the accepted ones, so its entropy drops by `-log2` of this probability.
+///
+/// ```
+/// use pass_gen::{preset, require_probability, ClassSet, Generator};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let ascii = preset("ascii").unwrap();
+///
+/// for name in ["lower", "upper", "digit", "symbol"] {
+///     let required = ClassSet::parse(name).unwrap();
+///     let mut gen = Generator::new(&ascii.token_data, 4, "", StdRng::seed_from_u64(7));
+///
+///     assert!(require_probability(required, &ascii.token_data, 4, &[""]) > 0.0);
+///     assert!((0..1000).map(|_| gen.generate()).any(|p| ClassSet::of(&p).contains_all(required)));
+/// }
+///
+/// let digit = ClassSet::parse("digit").unwrap();
+/// let words = preset("word").unwrap();
+///
+/// assert_eq!(require_probability(digit, &words.token_data, 6, &[" "]), 0.0);
+/// ```
+pub fn require_probability(required: ClassSet, source: &dyn TokenSource, count: u32, seps: &[&str]) -> f64 {
+    let separators = class_weights(seps.iter().copied());
+
+    draws_require_probability(required, &[(source_weights(source), count), (separators, count.saturating_sub(1))])
+}
+
+/// Probability that independent draws together contain every class in `required`.
+///
+/// Each draw is a table of weights per set of classes, see [`source_weights`], with the
+/// number of times it is drawn.
+pub(crate) fn draws_require_probability(required: ClassSet, draws: &[([f64; 16], u32)]) -> f64 {
+    // inclusion-exclusion over the required classes missing from every draw
+    let mut probability = 0.0;
+    let mut subset = required.0;
+
+    loop {
+        let avoid = |weights: &[f64; 16]| {
+            let total: f64 = weights.iter().sum();
+            let avoiding: f64 = (0..16).filter(|mask| mask & subset == 0).map(|mask| weights[mask as usize]).sum();
+
+            if total > 0.0 { avoiding / total } else { 0.0 }
+        };
+
+        let term: f64 = draws.iter().map(|(weights, count)| avoid(weights).powi(*count as i32)).product();
+
+        probability += if subset.count_ones().is_multiple_of(2) { term } else { -term };
+
+        if subset == 0 {
+            break;
+        }
+
+        subset = (subset - 1) & required.0;
+    }
+
+    probability.clamp(0.0, 1.0)
+}
+
+/// Number of tokens of `source` per set of classes they contain.
+pub(crate) fn source_weights(source: &dyn TokenSource) -> [f64; 16] {
+    class_weights(source.range().map(|idx| source.get(idx)))
+}
+
+/// Number of strings per set of classes they contain.
+pub(crate) fn class_weights<'a>(strs: impl Iterator<Item = &'a str>) -> [f64; 16] {
+    let mut counts = [0.0; 16];
+
+    for str in strs {
+        counts[ClassSet::of(str).0 as usize] += 1.0;
+    }
+
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preset;
+
+    #[test]
+    fn probability_over_several_draws() {
+        let words = preset("word").unwrap().token_data;
+        let digits = source_weights(&preset("number").unwrap().token_data);
+        let digit = ClassSet::parse("digit").unwrap();
+
+        assert_eq!(draws_require_probability(digit, &[(source_weights(&words), 4)]), 0.0);
+        assert_eq!(draws_require_probability(digit, &[(source_weights(&words), 4), (digits, 1)]), 1.0);
+    }
+}
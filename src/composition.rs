@@ -0,0 +1,77 @@
+use crate::{graphemes, CharClass, ClassSet};
+
+/* -------------------- *
+ *     COMPOSITION      *
+ * -------------------- */
+/// What a password is made of, counted in user-perceived characters, see [`graphemes`].
+///
+/// An emoji counts once. Each character is classified by its first code point like
+/// [`CharClass::matches`] does, anything in none of the classes, like whitespace or letters
+/// without case, is other.
+///
+/// ```
+/// use pass_gen::{CharClass, Composition};
+///
+/// let stats = Composition::of("Tr0ub4dor&3");
+///
+/// assert_eq!((stats.characters, stats.lower, stats.upper, stats.digits, stats.symbols), (11, 6, 1, 3, 1));
+/// assert_eq!(stats.other, 0);
+/// assert_eq!(stats.classes().iter().count(), 4);
+///
+/// let stats = Composition::of("correct horse");
+///
+/// assert_eq!((stats.characters, stats.lower, stats.other), (13, 12, 1));
+/// assert!(!stats.classes().contains(CharClass::Symbol));
+///
+/// // family, thumbs up with a skin tone, a flag and a keycap are one character each
+/// let emoji = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}-\u{1F44D}\u{1F3FD}";
+/// let stats = Composition::of(&format!("{}\u{1F1E9}\u{1F1EA}7\u{FE0F}\u{20E3}", emoji));
+///
+/// assert_eq!((stats.characters, stats.symbols, stats.digits), (5, 4, 1));
+///
+/// // accents stay on their letter, caseless letters are other
+/// let stats = Composition::of("e\u{301}t\u{E9}\u{6F22}\u{5B57}");
+///
+/// assert_eq!((stats.characters, stats.lower, stats.other), (5, 3, 2));
+/// assert_eq!(Composition::of("").characters, 0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Composition {
+    pub characters: usize,
+    pub lower: usize,
+    pub upper: usize,
+    pub digits: usize,
+    pub symbols: usize,
+    pub other: usize,
+}
+
+impl Composition {
+    pub fn of(password: &str) -> Self {
+        let mut stats = Composition::default();
+
+        for c in graphemes(password).filter_map(|grapheme| grapheme.chars().next()) {
+            stats.characters += 1;
+
+            match CharClass::ALL.into_iter().find(|class| class.matches(c)) {
+                Some(CharClass::Lower) => stats.lower += 1,
+                Some(CharClass::Upper) => stats.upper += 1,
+                Some(CharClass::Digit) => stats.digits += 1,
+                Some(CharClass::Symbol) => stats.symbols += 1,
+                None => stats.other += 1,
+            }
+        }
+
+        stats
+    }
+
+    /// Classes with at least one character.
+    pub fn classes(&self) -> ClassSet {
+        let counts = [self.lower, self.upper, self.digits, self.symbols];
+
+        CharClass::ALL
+            .into_iter()
+            .zip(counts)
+            .filter(|&(_, count)| count > 0)
+            .fold(ClassSet::default(), |set, (class, _)| set.with(class))
+    }
+}
@@ -0,0 +1,3038 @@
+#[cfg(feature = "age")]
+use crate::AgeRecipient;
+use crate::{
+    class_weights, config_dir, draws_require_probability, format_unit, grapheme_len, layer, length_bits,
+    length_require_probability, locale_preset, max_length_probability, source_weights, wipe_string, Acrostic, ApiToken,
+    AuditLog, BitPacking, BreachFilter, CharClass, ClassSet, ConfigFile, Dictionary, Generator, HistoryFile,
+    Identifier, Join, KdfParams, Layer, Leet, LicenseKey, Memorability, PartOfSpeech, PassGenError, Policy, Pool,
+    PresetSection, RandomCase, ReportFormat, SecretSharing, Sentence, TargetHash, Template, TokenAlphabet, TokenData,
+    TokenSource, Username, UsernameStyle, Whitespace, WordlistHash, API_TOKEN_BITS, BAR_TARGET, DEFAULT_RATES,
+    LEET_TABLE, PRESETS,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{
+    fs::File,
+    io::{self, BufReader, IsTerminal},
+    path::Path,
+};
+
+/// Bits a `--charset` pool is sized for when no count is given.
+pub const CHARSET_BITS: f64 = 128.0;
+
+/// Tokens per password allowed without `--force`.
+pub const MAX_TOKENS: u32 = 1_000_000;
+
+/// Alphabet of recovery codes, digits and lower case letters without 0, 1, i, l and o.
+pub(crate) const RECOVERY_ALPHABET: &str = "23456789abcdefghjkmnpqrstuvwxyz";
+
+/// Characters of a recovery code.
+pub(crate) const RECOVERY_LENGTH: u32 = 8;
+
+/// Characters per group of a recovery code.
+pub(crate) const RECOVERY_GROUP: u32 = 4;
+
+/// Groups of a license key.
+pub const LICENSE_GROUPS: u32 = 4;
+
+/// The controlling terminal, where `--tty-echo` and `--confirm-reveal` write to.
+#[cfg(not(windows))]
+pub(crate) const TTY_PATH: &str = "/dev/tty";
+#[cfg(windows)]
+pub(crate) const TTY_PATH: &str = "CONOUT$";
+
+const MAX_BITS: f64 = 65536.0;
+const RETRY_LIMIT: u32 = 1000;
+const DICTIONARY_MIN_LEN: usize = 4;
+const MEMORABILITY_SAMPLES: usize = 200;
+const MEMORABILITY_TOKENS: usize = 200_000;
+const STREAM_TOKENS: u32 = 100_000;
+const LICENSE_GROUP: u32 = 4;
+
+/// Tokens removed from a pool, by the filter that removed them.
+pub type PoolFilters = Vec<(&'static str, usize)>;
+
+macro_rules! usage {
+    ($($x:expr),*) => {
+        PassGenError::Usage(format!($($x,)*))
+    }
+}
+
+/* -------------------- *
+ *        CONFIG        *
+ * -------------------- */
+/// How the passwords are written.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Format {
+    Plain,
+    Csv,
+    Json,
+    KeepassCsv,
+}
+
+/// Quoting of plain output for `--quote` and `--export`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Quote {
+    None,
+    Shell,
+    Env,
+}
+
+/// Where the token pool comes from.
+#[derive(Debug, PartialEq)]
+pub enum PoolSource {
+    Preset(String),
+    File(String),
+    Charset(String),
+    Policy(String),
+    From(String),
+    Size(usize),
+}
+
+/// What decided the number of tokens per password.
+#[derive(Debug, PartialEq)]
+pub enum CountSource {
+    Default,
+    Config,
+    Flag,
+    Bits,
+    CrackTime,
+    Length,
+    Template,
+    Acrostic,
+    Sentence,
+    ExactBits,
+    Join,
+    From,
+}
+
+/// The settings of `--derive`, the master passphrase is only read when the seed is derived.
+#[derive(Debug)]
+pub struct Derivation {
+    pub label: String,
+    pub salt: Option<String>,
+    pub master_fd: Option<u32>,
+    pub params: KdfParams,
+}
+
+/// Everything a run does, checked and resolved by [`ConfigBuilder::build`].
+#[derive(Debug)]
+pub struct Config {
+    pub(crate) verbose: bool,
+    pub(crate) stats: bool,
+    pub(crate) report: bool,
+    pub(crate) report_only: bool,
+    pub(crate) report_format: ReportFormat,
+    pub(crate) guess_rates: Vec<(String, f64)>,
+    pub(crate) target_hash: Option<TargetHash>,
+    pub(crate) format: Format,
+    pub(crate) header: bool,
+    pub(crate) title_template: Option<String>,
+    pub(crate) username_template: Option<String>,
+    pub(crate) keepass_group: Option<String>,
+    pub(crate) username: Option<Username>,
+    pub(crate) one_per_line: bool,
+    pub(crate) recovery_codes: bool,
+    pub(crate) identifier: Option<Identifier>,
+    pub(crate) vertical: bool,
+    pub(crate) numbers: bool,
+    pub(crate) split: Option<SecretSharing>,
+    pub(crate) also_plain: bool,
+    pub(crate) encrypt_to: Vec<String>,
+    #[cfg(feature = "age")]
+    pub(crate) age_recipients: Vec<AgeRecipient>,
+    pub(crate) tty_echo: bool,
+    pub(crate) tty: Option<File>,
+    pub(crate) confirm_reveal: bool,
+    pub(crate) pepper: bool,
+    pub(crate) pepper_fd: Option<u32>,
+    pub(crate) output: Option<String>,
+    pub(crate) quote: Quote,
+    pub(crate) export: Option<String>,
+    pub(crate) unique: bool,
+    pub(crate) start_with_letter: bool,
+    pub(crate) checksum: bool,
+    pub(crate) first_pool: Option<TokenData>,
+    pub(crate) leet: Option<Leet>,
+    pub(crate) random_case: Option<RandomCase>,
+    pub(crate) mutate: Option<(u32, TokenData)>,
+    pub(crate) paranoid: bool,
+    pub(crate) derive: Option<Derivation>,
+    pub(crate) number: u32,
+    pub(crate) bits: Option<f64>,
+    pub(crate) min_entropy: Option<f64>,
+    pub(crate) bar_target: f64,
+    pub(crate) crack_time: Option<f64>,
+    pub(crate) jobs: u32,
+    pub(crate) seed: Option<u64>,
+    pub(crate) token_count: u32,
+    pub(crate) count_source: CountSource,
+    pub(crate) length: Option<(u32, TokenData)>,
+    pub(crate) max_length: Option<usize>,
+    pub(crate) retry_limit: u32,
+    pub(crate) template: Option<Template>,
+    pub(crate) acrostic: Option<Acrostic>,
+    pub(crate) sentence: Option<Sentence>,
+    pub(crate) packing: Option<BitPacking>,
+    pub(crate) join: Option<Join>,
+    pub(crate) decode: bool,
+    pub(crate) token_sep: String,
+    pub(crate) sep_layer: Layer,
+    pub(crate) sep_set: Vec<char>,
+    pub(crate) require: ClassSet,
+    pub(crate) policy: Option<(String, Policy)>,
+    pub(crate) pool_source: PoolSource,
+    pub(crate) locale: Option<String>,
+    pub(crate) config_file: Option<String>,
+    pub(crate) token_data: Box<dyn TokenSource>,
+    pub(crate) pool_filters: PoolFilters,
+    /// The pools of `--from` in order, `token_data` is all of them together.
+    pub(crate) sources: Vec<Box<dyn TokenSource>>,
+    pub(crate) breach_filter: Option<(String, BreachFilter)>,
+    pub(crate) history: Option<HistoryFile>,
+    pub(crate) history_prune: Option<u32>,
+    pub(crate) audit_log: Option<AuditLog>,
+    pub(crate) dictionary: Option<Dictionary>,
+    pub(crate) compare: Option<String>,
+    pub(crate) estimate: Option<String>,
+    pub(crate) batch: bool,
+    pub(crate) batch_strict: bool,
+    pub(crate) spec: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let word = crate::preset("word").expect("word is a builtin preset");
+
+        Config {
+            verbose: false,
+            stats: false,
+            report: false,
+            report_only: false,
+            report_format: ReportFormat::Human,
+            guess_rates: Vec::new(),
+            target_hash: None,
+            format: Format::Plain,
+            header: true,
+            title_template: None,
+            username_template: None,
+            keepass_group: None,
+            username: None,
+            one_per_line: false,
+            recovery_codes: false,
+            identifier: None,
+            vertical: false,
+            numbers: true,
+            split: None,
+            also_plain: false,
+            encrypt_to: Vec::new(),
+            #[cfg(feature = "age")]
+            age_recipients: Vec::new(),
+            tty_echo: false,
+            tty: None,
+            confirm_reveal: false,
+            pepper: false,
+            pepper_fd: None,
+            output: None,
+            quote: Quote::None,
+            export: None,
+            unique: false,
+            start_with_letter: false,
+            checksum: false,
+            first_pool: None,
+            leet: None,
+            random_case: None,
+            mutate: None,
+            paranoid: false,
+            derive: None,
+            number: 1,
+            bits: None,
+            min_entropy: None,
+            bar_target: BAR_TARGET,
+            crack_time: None,
+            jobs: 1,
+            seed: None,
+            token_count: word.token_count,
+            count_source: CountSource::Default,
+            length: None,
+            max_length: None,
+            retry_limit: RETRY_LIMIT,
+            template: None,
+            acrostic: None,
+            sentence: None,
+            packing: None,
+            join: None,
+            decode: false,
+            token_sep: word.token_sep.to_string(),
+            sep_layer: Layer::Default,
+            sep_set: Vec::new(),
+            require: ClassSet::default(),
+            policy: None,
+            pool_source: PoolSource::Preset(String::from("word")),
+            locale: None,
+            config_file: None,
+            token_data: Box::new(word.token_data),
+            pool_filters: Vec::new(),
+            sources: Vec::new(),
+            breach_filter: None,
+            history: None,
+            history_prune: None,
+            audit_log: None,
+            dictionary: None,
+            compare: None,
+            estimate: None,
+            batch: false,
+            batch_strict: false,
+            spec: None,
+        }
+    }
+}
+
+// the filters of the command line, every pool goes through them on its own
+#[derive(Debug, Default)]
+struct TokenFilters {
+    exclude_chars: Option<String>,
+    word_lengths: (Option<u32>, Option<u32>),
+    no_confusable: bool,
+}
+
+impl TokenFilters {
+    // applies the filters in order
+    fn apply(
+        &self,
+        mut token_data: Box<dyn TokenSource>,
+        filters: &mut PoolFilters,
+    ) -> Result<Box<dyn TokenSource>, PassGenError> {
+        if let Some(chars) = &self.exclude_chars {
+            let (filtered, removed) = TokenData::without_chars(token_data.as_ref(), chars)
+                .map_err(|_| usage!("--exclude-chars only applies to pools of single characters, not words"))?;
+
+            if filtered.is_empty() {
+                return Err(usage!("--exclude-chars {:?} removes every character of the pool", chars));
+            }
+
+            token_data = Box::new(filtered);
+            filters.push(("excluded characters", removed));
+        }
+
+        if self.word_lengths != (None, None) {
+            let (min, max) = (self.word_lengths.0.unwrap_or(0) as usize, self.word_lengths.1.map(|max| max as usize));
+            let (filtered, removed) = TokenData::with_lengths(token_data.as_ref(), min, max)?;
+
+            if filtered.is_empty() {
+                let msg = "no token is within --min-word-length and --max-word-length".to_string();
+                return Err(PassGenError::Unsatisfiable(msg));
+            }
+
+            token_data = Box::new(filtered);
+            filters.push(("word length", removed));
+        }
+
+        if self.no_confusable {
+            match token_data.length_stats() {
+                (_, _, 1) => {
+                    eprintln!("pass-gen: warning: --no-confusable has no effect on a pool of single characters")
+                },
+                _ => {
+                    let (filtered, removed) = TokenData::without_confusable(token_data.as_ref())?;
+
+                    token_data = Box::new(filtered);
+                    filters.push(("confusable words", removed));
+                },
+            }
+        }
+
+        Ok(token_data)
+    }
+}
+
+/// Builds a [`Config`], one setter per command line flag.
+///
+/// Nothing is checked until [`build`](ConfigBuilder::build), which rejects conflicting settings
+/// and resolves the ones that depend on the final pool.
+///
+/// ```
+/// use pass_gen::ConfigBuilder;
+///
+/// let config = ConfigBuilder::new().preset("number").count(6).no_config(true).build().unwrap();
+///
+/// assert_eq!(config.token_count(), 6);
+/// assert_eq!(config.token_data().len(), 10);
+///
+/// let err = ConfigBuilder::new().preset("word").count(4).bits(64.0).no_config(true).build().unwrap_err();
+/// assert_eq!(err.to_string(), "--bits conflicts with --count");
+/// ```
+#[derive(Debug, Default)]
+pub struct ConfigBuilder {
+    config: Config,
+    length: Option<u32>,
+    acrostic: Option<String>,
+    sentence: bool,
+    sentence_template: Option<String>,
+    mutate: Option<u32>,
+    mutate_pool: Option<String>,
+    exact_bits: Option<u32>,
+    decode_bits: Option<u32>,
+    template: Option<String>,
+    join: Option<String>,
+    quote: Option<Quote>,
+    preset: Option<String>,
+    file: Option<String>,
+    from: Option<String>,
+    wordlist_hashes: Vec<WordlistHash>,
+    charset: Option<String>,
+    unicode: bool,
+    filters: TokenFilters,
+    policy: Option<String>,
+    count: Option<u32>,
+    separator: Option<String>,
+    sep_set: Option<String>,
+    check_offline: Option<String>,
+    history: Option<String>,
+    audit_log: Option<String>,
+    no_locale: bool,
+    no_config: bool,
+    no_dictionary: bool,
+    derive: Option<String>,
+    salt: Option<String>,
+    master_fd: Option<u32>,
+    kdf: (Option<u32>, Option<u32>, Option<u32>),
+    pool_size: Option<u32>,
+    with_username: bool,
+    username_style: Option<UsernameStyle>,
+    username_max_len: Option<u32>,
+    force: bool,
+    whitespace: Option<Whitespace>,
+    whitespace_join: Option<String>,
+    recovery_codes: Option<u32>,
+    group: Option<u32>,
+    api_token: bool,
+    token_prefix: Option<String>,
+    token_alphabet: Option<TokenAlphabet>,
+    license_key: bool,
+    key_groups: Option<u32>,
+}
+
+impl ConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.config.verbose = verbose;
+        self
+    }
+
+    pub fn stats(mut self, stats: bool) -> Self {
+        self.config.stats = stats;
+        self
+    }
+
+    pub fn report(mut self, report: bool) -> Self {
+        self.config.report = report;
+        self
+    }
+
+    pub fn report_only(mut self, report_only: bool) -> Self {
+        self.config.report = self.config.report || report_only;
+        self.config.report_only = report_only;
+        self
+    }
+
+    pub fn report_format(mut self, format: ReportFormat) -> Self {
+        self.config.report = true;
+        self.config.report_format = format;
+        self
+    }
+
+    pub fn guess_rate(mut self, label: &str, rate: f64) -> Self {
+        self.config.guess_rates.push((label.to_string(), rate));
+        self
+    }
+
+    pub fn target_hash(mut self, hash: TargetHash) -> Self {
+        self.config.target_hash = Some(hash);
+        self
+    }
+
+    pub fn format(mut self, format: Format) -> Self {
+        self.config.format = format;
+        self
+    }
+
+    pub fn header(mut self, header: bool) -> Self {
+        self.config.header = header;
+        self
+    }
+
+    pub fn title_template(mut self, template: &str) -> Self {
+        self.config.title_template = Some(template.to_string());
+        self
+    }
+
+    pub fn username_template(mut self, template: &str) -> Self {
+        self.config.username_template = Some(template.to_string());
+        self
+    }
+
+    pub fn keepass_group(mut self, group: &str) -> Self {
+        self.config.keepass_group = Some(group.to_string());
+        self
+    }
+
+    pub fn with_username(mut self, with_username: bool) -> Self {
+        self.with_username = with_username;
+        self
+    }
+
+    pub fn username_style(mut self, style: UsernameStyle) -> Self {
+        self.username_style = Some(style);
+        self
+    }
+
+    pub fn username_max_len(mut self, max_len: u32) -> Self {
+        self.username_max_len = Some(max_len);
+        self
+    }
+
+    pub fn output(mut self, path: &str) -> Self {
+        self.config.output = Some(path.to_string());
+        self
+    }
+
+    pub fn quote(mut self, quote: Quote) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+
+    pub fn export(mut self, name: &str) -> Self {
+        self.config.export = Some(name.to_string());
+        self
+    }
+
+    pub fn derive(mut self, label: &str) -> Self {
+        self.derive = Some(label.to_string());
+        self
+    }
+
+    pub fn salt(mut self, salt: &str) -> Self {
+        self.salt = Some(salt.to_string());
+        self
+    }
+
+    pub fn master_fd(mut self, fd: u32) -> Self {
+        self.master_fd = Some(fd);
+        self
+    }
+
+    pub fn kdf_memory(mut self, kib: u32) -> Self {
+        self.kdf.0 = Some(kib);
+        self
+    }
+
+    pub fn kdf_iterations(mut self, iterations: u32) -> Self {
+        self.kdf.1 = Some(iterations);
+        self
+    }
+
+    pub fn kdf_lanes(mut self, lanes: u32) -> Self {
+        self.kdf.2 = Some(lanes);
+        self
+    }
+
+    pub fn paranoid(mut self, paranoid: bool) -> Self {
+        self.config.paranoid = paranoid;
+        self
+    }
+
+    pub fn one_per_line(mut self, one_per_line: bool) -> Self {
+        self.config.one_per_line = one_per_line;
+        self
+    }
+
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.config.vertical = vertical;
+        self
+    }
+
+    pub fn numbers(mut self, numbers: bool) -> Self {
+        self.config.numbers = numbers;
+        self
+    }
+
+    pub fn split(mut self, sharing: SecretSharing) -> Self {
+        self.config.split = Some(sharing);
+        self
+    }
+
+    pub fn also_plain(mut self, also_plain: bool) -> Self {
+        self.config.also_plain = also_plain;
+        self
+    }
+
+    pub fn encrypt_to(mut self, recipient: &str) -> Self {
+        self.config.encrypt_to.push(recipient.to_string());
+        self
+    }
+
+    #[cfg(feature = "age")]
+    pub fn age_recipients(mut self, recipients: Vec<AgeRecipient>) -> Self {
+        self.config.age_recipients.extend(recipients);
+        self
+    }
+
+    pub fn tty_echo(mut self, tty_echo: bool) -> Self {
+        self.config.tty_echo = tty_echo;
+        self
+    }
+
+    pub fn confirm_reveal(mut self, confirm_reveal: bool) -> Self {
+        self.config.confirm_reveal = confirm_reveal;
+        self
+    }
+
+    pub fn pepper(mut self, pepper: bool) -> Self {
+        self.config.pepper = pepper;
+        self
+    }
+
+    pub fn pepper_fd(mut self, fd: u32) -> Self {
+        self.config.pepper = true;
+        self.config.pepper_fd = Some(fd);
+        self
+    }
+
+    pub fn unique(mut self, unique: bool) -> Self {
+        self.config.unique = unique;
+        self
+    }
+
+    pub fn recovery_codes(mut self, codes: u32) -> Self {
+        self.recovery_codes = Some(codes);
+        self
+    }
+
+    pub fn group(mut self, size: u32) -> Self {
+        self.group = Some(size);
+        self
+    }
+
+    pub fn identifier(mut self, identifier: Identifier) -> Self {
+        self.config.identifier = Some(identifier);
+        self
+    }
+
+    pub fn api_token(mut self, api_token: bool) -> Self {
+        self.api_token = api_token;
+        self
+    }
+
+    pub fn token_prefix(mut self, prefix: &str) -> Self {
+        self.token_prefix = Some(prefix.to_string());
+        self
+    }
+
+    pub fn token_alphabet(mut self, alphabet: TokenAlphabet) -> Self {
+        self.token_alphabet = Some(alphabet);
+        self
+    }
+
+    pub fn license_key(mut self, license_key: bool) -> Self {
+        self.license_key = license_key;
+        self
+    }
+
+    pub fn key_groups(mut self, groups: u32) -> Self {
+        self.key_groups = Some(groups);
+        self
+    }
+
+    pub fn mutate(mut self, count: u32) -> Self {
+        self.mutate = Some(count);
+        self
+    }
+
+    pub fn mutate_pool(mut self, spec: &str) -> Self {
+        self.mutate_pool = Some(spec.to_string());
+        self
+    }
+
+    pub fn leet(mut self, leet: Leet) -> Self {
+        self.config.leet = Some(leet);
+        self
+    }
+
+    pub fn random_case(mut self, random_case: RandomCase) -> Self {
+        self.config.random_case = Some(random_case);
+        self
+    }
+
+    pub fn start_with_letter(mut self, start: bool) -> Self {
+        self.config.start_with_letter = start;
+        self
+    }
+
+    pub fn checksum(mut self, checksum: bool) -> Self {
+        self.config.checksum = checksum;
+        self
+    }
+
+    pub fn require(mut self, classes: ClassSet) -> Self {
+        self.config.require = classes;
+        self
+    }
+
+    pub fn number(mut self, number: u32) -> Self {
+        self.config.number = number;
+        self
+    }
+
+    pub fn jobs(mut self, jobs: u32) -> Self {
+        self.config.jobs = jobs;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    pub fn length(mut self, length: u32) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    pub fn acrostic(mut self, text: &str) -> Self {
+        self.acrostic = Some(text.to_string());
+        self
+    }
+
+    pub fn template(mut self, pattern: &str) -> Self {
+        self.template = Some(pattern.to_string());
+        self
+    }
+
+    pub fn join(mut self, layout: &str) -> Self {
+        self.join = Some(layout.to_string());
+        self
+    }
+
+    pub fn sentence(mut self, sentence: bool) -> Self {
+        self.sentence = sentence;
+        self
+    }
+
+    /// A fixed layout implies --sentence.
+    pub fn sentence_template(mut self, pattern: &str) -> Self {
+        self.sentence = true;
+        self.sentence_template = Some(pattern.to_string());
+        self
+    }
+
+    pub fn max_length(mut self, max: u32) -> Self {
+        self.config.max_length = Some(max as usize);
+        self
+    }
+
+    pub fn retry_limit(mut self, limit: u32) -> Self {
+        self.config.retry_limit = limit;
+        self
+    }
+
+    pub fn bits(mut self, bits: f64) -> Self {
+        self.config.bits = Some(bits);
+        self
+    }
+
+    pub fn exact_bits(mut self, bits: u32) -> Self {
+        self.exact_bits = Some(bits);
+        self
+    }
+
+    pub fn decode_bits(mut self, bits: u32) -> Self {
+        self.decode_bits = Some(bits);
+        self
+    }
+
+    pub fn min_entropy(mut self, bits: f64) -> Self {
+        self.config.min_entropy = Some(bits);
+        self
+    }
+
+    pub fn bar_target(mut self, bits: f64) -> Self {
+        self.config.bar_target = bits;
+        self
+    }
+
+    pub fn crack_time(mut self, seconds: f64) -> Self {
+        self.config.crack_time = Some(seconds);
+        self
+    }
+
+    pub fn separator(mut self, sep: &str) -> Self {
+        self.separator = Some(sep.to_string());
+        self
+    }
+
+    pub fn sep_set(mut self, set: &str) -> Self {
+        self.sep_set = Some(set.to_string());
+        self
+    }
+
+    /// A preset supplies the tokens as well as the default count and separator.
+    pub fn preset(mut self, name: &str) -> Self {
+        self.preset = Some(name.to_string());
+        self.file = None;
+        self
+    }
+
+    /// Replaces the tokens but keeps the defaults of the active preset.
+    pub fn wordlist_file(mut self, path: &str) -> Self {
+        self.file = Some(path.to_string());
+        self
+    }
+
+    pub fn from(mut self, spec: &str) -> Self {
+        self.from = Some(spec.to_string());
+        self
+    }
+
+    /// One per word list file, matched in order.
+    pub fn wordlist_hash(mut self, hash: WordlistHash) -> Self {
+        self.wordlist_hashes.push(hash);
+        self
+    }
+
+    pub fn whitespace(mut self, whitespace: Whitespace) -> Self {
+        self.whitespace = Some(whitespace);
+        self
+    }
+
+    pub fn whitespace_join(mut self, join: &str) -> Self {
+        self.whitespace_join = Some(join.to_string());
+        self
+    }
+
+    pub fn charset(mut self, spec: &str) -> Self {
+        self.charset = Some(spec.to_string());
+        self
+    }
+
+    pub fn allow_unicode(mut self, unicode: bool) -> Self {
+        self.unicode = unicode;
+        self
+    }
+
+    pub fn exclude_chars(mut self, chars: &str) -> Self {
+        self.filters.exclude_chars = Some(chars.to_string());
+        self
+    }
+
+    pub fn min_word_length(mut self, min: u32) -> Self {
+        self.filters.word_lengths.0 = Some(min);
+        self
+    }
+
+    pub fn max_word_length(mut self, max: u32) -> Self {
+        self.filters.word_lengths.1 = Some(max);
+        self
+    }
+
+    pub fn policy(mut self, path: &str) -> Self {
+        self.policy = Some(path.to_string());
+        self
+    }
+
+    pub fn check_offline(mut self, path: &str) -> Self {
+        self.check_offline = Some(path.to_string());
+        self
+    }
+
+    pub fn no_config(mut self, no_config: bool) -> Self {
+        self.no_config = no_config;
+        self
+    }
+
+    pub fn no_locale(mut self, no_locale: bool) -> Self {
+        self.no_locale = no_locale;
+        self
+    }
+
+    pub fn history(mut self, path: &str) -> Self {
+        self.history = Some(path.to_string());
+        self
+    }
+
+    pub fn history_prune(mut self, days: u32) -> Self {
+        self.config.history_prune = Some(days);
+        self
+    }
+
+    pub fn audit_log(mut self, path: &str) -> Self {
+        self.audit_log = Some(path.to_string());
+        self
+    }
+
+    pub fn no_dictionary(mut self, no_dictionary: bool) -> Self {
+        self.no_dictionary = no_dictionary;
+        self
+    }
+
+    pub fn no_confusable(mut self, no_confusable: bool) -> Self {
+        self.filters.no_confusable = no_confusable;
+        self
+    }
+
+    pub fn compare(mut self, spec: &str) -> Self {
+        self.config.compare = Some(spec.to_string());
+        self
+    }
+
+    pub fn estimate(mut self, phrase: &str) -> Self {
+        self.config.estimate = Some(phrase.to_string());
+        self
+    }
+
+    pub fn batch(mut self, batch: bool) -> Self {
+        self.config.batch = batch;
+        self
+    }
+
+    pub fn batch_strict(mut self, batch_strict: bool) -> Self {
+        self.config.batch_strict = batch_strict;
+        self
+    }
+
+    pub fn spec(mut self, path: &str) -> Self {
+        self.config.spec = Some(path.to_string());
+        self
+    }
+
+    pub fn pool_size(mut self, size: u32) -> Self {
+        self.pool_size = Some(size);
+        self
+    }
+
+    /// Checks the settings against each other and resolves the ones that depend on the final pool.
+    pub fn build(mut self) -> Result<Config, PassGenError> {
+        // the rate of the hash stands in for the generic ones everywhere
+        if let Some(hash) = &self.config.target_hash {
+            if !self.config.guess_rates.is_empty() {
+                return Err(usage!("--target-hash conflicts with --guesses-per-second"));
+            }
+
+            let rate = hash.guesses_per_second();
+            self.config.guess_rates = vec![(format_unit(rate, "").trim_end().to_string(), rate)];
+        }
+
+        // recovery codes are single characters of an unambiguous alphabet, laid out in groups
+        if let Some(codes) = self.recovery_codes {
+            let pool = self.preset.is_some() || self.file.is_some() || self.from.is_some() || self.charset.is_some();
+
+            if pool || self.join.is_some() || self.pool_size.is_some() {
+                return Err(usage!(
+                    "--recovery-codes conflicts with --preset, --file, --from, --charset, --join and --pool-size"
+                ));
+            }
+
+            if self.config.number != 1 {
+                return Err(usage!("--recovery-codes=<n> sets the number of codes, it conflicts with --number"));
+            }
+
+            let length = *self.count.get_or_insert(RECOVERY_LENGTH);
+            let sep = self.separator.take().unwrap_or_else(|| String::from("-"));
+
+            self.join = Some(recovery_layout(length, self.group.unwrap_or(RECOVERY_GROUP), &sep));
+            self.charset = Some(String::from(RECOVERY_ALPHABET));
+            self.config.number = codes;
+            self.config.unique = true;
+            self.config.one_per_line = true;
+            self.config.recovery_codes = true;
+        } else if self.group.is_some() && !self.license_key {
+            return Err(usage!("--group requires --recovery-codes or --license-key"));
+        }
+
+        // an api token is an identifier with a payload of --bits
+        if self.api_token {
+            if let Some(identifier) = &self.config.identifier {
+                return Err(usage!("--api-token conflicts with --{}", identifier.name()));
+            }
+
+            let prefix = match &self.token_prefix {
+                Some(prefix) => prefix,
+                None => return Err(usage!("--api-token requires --token-prefix")),
+            };
+
+            let alphabet = self.token_alphabet.unwrap_or(TokenAlphabet::Base62);
+            let bits = self.config.bits.take().unwrap_or(API_TOKEN_BITS);
+
+            self.config.identifier = Some(Identifier::ApiToken(ApiToken::new(prefix, alphabet, bits)?));
+        } else if self.token_prefix.is_some() || self.token_alphabet.is_some() {
+            return Err(usage!("--token-prefix and --token-alphabet require --api-token"));
+        }
+
+        // so is a license key, in groups of characters ending in a check character
+        if self.license_key {
+            if let Some(identifier) = &self.config.identifier {
+                return Err(usage!("--license-key conflicts with --{}", identifier.name()));
+            }
+
+            let group = self.group.unwrap_or(LICENSE_GROUP) as usize;
+            let groups = self.key_groups.unwrap_or(LICENSE_GROUPS) as usize;
+
+            self.config.identifier = Some(Identifier::LicenseKey(LicenseKey::new(group, groups)?));
+        } else if self.key_groups.is_some() {
+            return Err(usage!("--key-groups requires --license-key"));
+        }
+
+        // identifiers have a fixed shape and draw no tokens
+        if let Some(identifier) = &self.config.identifier {
+            let config = &self.config;
+            let pool = self.preset.is_some() || self.file.is_some() || self.from.is_some() || self.charset.is_some();
+            let shaped = self.count.is_some()
+                || self.separator.is_some()
+                || self.template.is_some()
+                || self.length.is_some()
+                || self.join.is_some()
+                || self.sentence
+                || self.acrostic.is_some()
+                || self.exact_bits.is_some()
+                || self.recovery_codes.is_some();
+            let altered =
+                config.leet.is_some() || config.random_case.is_some() || self.mutate.is_some() || config.checksum;
+            let checked = self.policy.is_some()
+                || config.max_length.is_some()
+                || !config.require.is_empty()
+                || config.start_with_letter
+                || config.unique;
+            let output =
+                config.format != Format::Plain || config.split.is_some() || config.vertical || self.with_username;
+
+            if pool || shaped || altered || checked || output {
+                return Err(usage!(
+                    "--{} conflicts with --preset, --file, --from, --charset, --count, --sep, --template, --length, \
+                    --join, --sentence, --acrostic, --exact-bits, --recovery-codes, --leet, --random-case, --mutate, \
+                    --checksum, --policy, --max-length, --require, --start-with-letter, --unique, --csv, --json, \
+                    --keepass-csv, --split, --vertical and --with-username",
+                    identifier.name()
+                ));
+            }
+        }
+
+        if let Some(size) = self.pool_size {
+            return self.build_hypothetical(size as usize);
+        }
+
+        let mut config = self.config;
+
+        let policy = match &self.policy {
+            Some(path) => Some(Policy::from_path(Path::new(path))?),
+            None => None,
+        };
+
+        let inline = match &self.charset {
+            Some(_) if self.preset.is_some() || self.file.is_some() => {
+                return Err(usage!("--charset conflicts with --preset and --file"));
+            },
+            Some(spec) => Some(crate::parse_charset(spec, self.unicode)?),
+            None if self.unicode => return Err(usage!("--allow-unicode requires --charset")),
+            None => None,
+        };
+
+        // a policy charset replaces the default pool, with the defaults of the ascii preset
+        let charset = match (&policy, &self.preset, &self.file, &inline) {
+            (Some(policy), None, None, None) => policy.charset(),
+            _ => None,
+        };
+
+        let name = match (&charset, &inline, &self.preset) {
+            (Some(_), _, _) | (_, Some(_), _) => "ascii",
+            (None, None, Some(name)) => name,
+            (None, None, None) if self.no_locale || self.file.is_some() || self.from.is_some() => "word",
+            (None, None, None) => {
+                let (name, reason) = locale_preset();
+                config.locale = Some(reason);
+                name
+            },
+        };
+
+        let preset = match crate::preset(name) {
+            Some(preset) => preset,
+            None => return Err(usage!("invalid preset {:?}, expected one of {}", name, PRESETS.join(", "))),
+        };
+
+        // preset defaults, then the preset's section of the config file, then flags
+        let section = match (&self.file, &charset, &inline) {
+            (None, None, None) if !self.no_config && self.from.is_none() => Config::load_section(&mut config, name)?,
+            _ => Default::default(),
+        };
+
+        let (count, count_layer) = layer(preset.token_count, section.count, self.count);
+        let separated = self.separator.is_some();
+
+        config.token_count = count;
+        config.count_source = match count_layer {
+            Layer::Default => CountSource::Default,
+            Layer::Config => CountSource::Config,
+            Layer::Flag => CountSource::Flag,
+        };
+        (config.token_sep, config.sep_layer) = layer(preset.token_sep.to_string(), section.sep, self.separator);
+
+        let whitespace = Config::whitespace_policy(self.whitespace, self.whitespace_join)?;
+
+        if whitespace.is_some() && self.file.is_none() && self.from.is_none() {
+            return Err(usage!("--whitespace and --whitespace-join only apply to --file and --from"));
+        }
+
+        let whitespace = whitespace.unwrap_or_default();
+
+        // the word list files in the order they are read
+        let files: Vec<&str> = match (&self.file, &self.from) {
+            (Some(path), _) => vec![path],
+            (None, Some(spec)) => spec.split(',').map(str::trim).filter(|src| !src.starts_with("preset:")).collect(),
+            (None, None) => Vec::new(),
+        };
+
+        if !self.wordlist_hashes.is_empty() && self.wordlist_hashes.len() != files.len() {
+            return Err(usage!(
+                "--wordlist-hash is given {} time(s) for {} word list file(s), \
+                repeat it for every --file or --from file in order",
+                self.wordlist_hashes.len(),
+                files.len()
+            ));
+        }
+
+        // every line or entry parses the command line again
+        if files.contains(&"-") && (config.batch || config.spec.is_some()) {
+            return Err(usage!("a word list read from stdin conflicts with --batch and --spec"));
+        }
+
+        match self.file {
+            Some(path) => {
+                let (token_data, pool_filters) = Config::load_file(&path, &whitespace, self.wordlist_hashes.first())?;
+
+                config.token_data = Box::new(token_data);
+                config.pool_filters = pool_filters;
+                config.pool_source = PoolSource::File(path);
+            },
+            None => {
+                config.token_data = Box::new(preset.token_data);
+                config.pool_filters = Vec::new();
+                config.pool_source = PoolSource::Preset(name.to_string());
+            },
+        }
+
+        if let (Some(chars), Some(spec)) = (inline, &self.charset) {
+            config.token_data = Box::new(TokenData::from_strings(chars.chars().map(String::from).collect())?);
+            config.pool_source = PoolSource::Charset(spec.clone());
+        }
+
+        if let (Some(policy), Some(path)) = (&policy, &self.policy) {
+            match charset {
+                Some(chars) => {
+                    let mut token_data = TokenData::from_strings(chars.chars().map(String::from).collect())?;
+                    let removed = token_data.dedup();
+
+                    config.token_data = Box::new(token_data);
+                    config.pool_filters = vec![("duplicates", removed)];
+                    config.pool_source = PoolSource::Policy(path.clone());
+                },
+                None => {
+                    let (token_data, removed) = policy.restrict(config.token_data.as_ref())?;
+
+                    config.token_data = Box::new(token_data);
+                    config.pool_filters.push(("policy", removed));
+                },
+            }
+        }
+
+        match &self.from {
+            Some(spec) => {
+                let pool = !matches!(config.pool_source, PoolSource::Preset(_)) || self.preset.is_some();
+                let shaped = self.template.is_some()
+                    || self.length.is_some()
+                    || self.sentence
+                    || self.acrostic.is_some()
+                    || self.exact_bits.is_some()
+                    || self.decode_bits.is_some();
+                let sized = config.bits.is_some() || config.crack_time.is_some();
+                let altered =
+                    config.leet.is_some() || config.random_case.is_some() || self.mutate.is_some() || config.checksum;
+                let checked = config.max_length.is_some() || !config.require.is_empty() || config.start_with_letter;
+
+                if pool || policy.is_some() || shaped || sized || altered || checked {
+                    return Err(usage!(
+                        "--from conflicts with --preset, --file, --charset, --policy, --template, --length, \
+                        --sentence, --acrostic, --exact-bits, --decode-bits, --bits, --crack-time, --leet, \
+                        --random-case, --mutate, --checksum, --max-length, --require and --start-with-letter"
+                    ));
+                }
+
+                let (sources, filters) = Config::load_sources(spec, &whitespace, &self.filters, &self.wordlist_hashes)?;
+                let tokens = sources.iter().flat_map(|source| source.range().map(|idx| source.get(idx).to_string()));
+                let mut token_data = TokenData::from_strings(tokens.collect())?;
+
+                token_data.dedup();
+
+                config.token_count = (sources.len() as u32).saturating_mul(self.count.unwrap_or(1));
+                config.count_source = if self.count.is_some() { CountSource::Flag } else { CountSource::From };
+                config.token_data = Box::new(token_data);
+                config.pool_filters = filters;
+                config.pool_source = PoolSource::From(spec.clone());
+                config.sources = sources;
+            },
+            None => {
+                let mut filters = std::mem::take(&mut config.pool_filters);
+
+                config.token_data = self.filters.apply(config.token_data, &mut filters)?;
+                config.pool_filters = filters;
+            },
+        }
+
+        // a word pool would have every candidate rejected
+        if self.no_dictionary {
+            match config.token_data.length_stats() {
+                (_, _, 1) => {
+                    let words = crate::preset("word").map(|preset| preset.token_data);
+                    config.dictionary = words.map(|words| Dictionary::from_source(&words, DICTIONARY_MIN_LEN));
+                },
+                _ => eprintln!("pass-gen: warning: --no-dictionary-substrings has no effect on a pool of words"),
+            }
+        }
+
+        match self.derive {
+            Some(label) => {
+                let default = KdfParams::default();
+                let params = KdfParams {
+                    memory: self.kdf.0.unwrap_or(default.memory),
+                    iterations: self.kdf.1.unwrap_or(default.iterations),
+                    lanes: self.kdf.2.unwrap_or(default.lanes),
+                };
+
+                params.validate()?;
+
+                if config.seed.is_some() || config.number > 1 {
+                    return Err(usage!("--derive conflicts with --seed and --number"));
+                }
+
+                config.derive = Some(Derivation { label, salt: self.salt, master_fd: self.master_fd, params });
+            },
+            None if self.salt.is_some() || self.master_fd.is_some() || self.kdf != (None, None, None) => {
+                return Err(usage!("--salt, --master-fd and --kdf-* require --derive"));
+            },
+            None => {},
+        }
+
+        if let Some(path) = self.check_offline {
+            let filter = Config::load_filter(&path)?;
+            config.breach_filter = Some((path, filter));
+        }
+
+        // every password issued with a history is new, within the batch too
+        match self.history {
+            // checked before the file is created, nothing is issued to record
+            Some(_) if config.report_only => return Err(usage!("--report-only cannot be combined with --history")),
+            Some(path) => {
+                config.history = Some(HistoryFile::open(path)?);
+                config.unique = true;
+            },
+            None if config.history_prune.is_some() => return Err(usage!("--history-prune requires --history")),
+            None => {},
+        }
+
+        if let Some(path) = self.audit_log {
+            config.audit_log = Some(AuditLog::open(path)?);
+        }
+
+        if config.batch_strict && !config.batch {
+            return Err(usage!("--batch-strict requires --batch"));
+        }
+
+        if config.spec.is_some() && config.batch {
+            return Err(usage!("--spec conflicts with --batch"));
+        }
+
+        if !config.numbers && !config.vertical {
+            return Err(usage!("--no-numbers requires --vertical"));
+        }
+
+        if let Some(set) = self.sep_set {
+            config.sep_set = set.chars().collect();
+            config.sep_set.sort_unstable();
+            config.sep_set.dedup();
+
+            if config.sep_set.is_empty() {
+                return Err(usage!("invalid argument to {:?}, expected at least one character", "--sep-set"));
+            }
+        }
+
+        for (name, value) in [("count", config.token_count), ("number", config.number), ("jobs", config.jobs)] {
+            if value == 0 {
+                return Err(usage!("{} must be a positive number", name));
+            }
+        }
+
+        Config::check_pool(config.token_data.len())?;
+
+        // nothing is printed for the output flags to shape
+        let output = config.output.is_some() || config.format != Format::Plain;
+
+        let layout = self.quote.is_some() || config.export.is_some() || config.vertical || config.split.is_some();
+
+        let encrypted = !config.encrypt_to.is_empty() || config.age();
+
+        if config.report_only && (output || layout || config.tty_echo || encrypted) {
+            return Err(usage!(
+                "--report-only cannot be combined with --output, --csv, --json, --quote, --export, --vertical, --split, \
+                --tty-echo, --encrypt-to or --age-recipient"
+            ));
+        }
+
+        // the file, history and audit log always get the raw password
+        config.quote = match (self.quote, &config.export) {
+            (Some(Quote::None), Some(_)) => return Err(usage!("--export needs --quote shell or env")),
+            (Some(quote), _) => quote,
+            (None, Some(_)) => Quote::Env,
+            (None, None) => Quote::None,
+        };
+
+        if config.quote != Quote::None && (config.output.is_some() || config.format != Format::Plain) {
+            let msg = "--quote and --export only apply to plain output on stdout, not --output, --csv or --json";
+            return Err(PassGenError::Usage(String::from(msg)));
+        }
+
+        let keepass = [&config.title_template, &config.username_template, &config.keepass_group];
+
+        if config.format != Format::KeepassCsv && keepass.iter().any(|field| field.is_some()) {
+            return Err(usage!("--title-template, --username-template and --keepass-group require --keepass-csv"));
+        }
+
+        if self.with_username {
+            let style = self.username_style.unwrap_or(UsernameStyle::Words);
+            let max_len = self.username_max_len.map_or(Username::DEFAULT_MAX_LEN, |max_len| max_len as usize);
+
+            config.username = Some(Username::new(style, max_len)?);
+        } else if self.username_style.is_some() || self.username_max_len.is_some() {
+            return Err(usage!("--username-style and --username-max-len require --with-username"));
+        }
+
+        if config.username.is_some() {
+            if config.quote != Quote::None || config.vertical || config.split.is_some() {
+                return Err(usage!("--with-username conflicts with --quote, --export, --vertical and --split"));
+            }
+
+            if config.username_template.is_some() {
+                return Err(usage!("--with-username conflicts with --username-template"));
+            }
+        }
+
+        if let Some(name) = &config.export {
+            let mut chars = name.chars();
+            let valid = chars.next().is_some_and(|c| c == '_' || c.is_ascii_alphabetic())
+                && chars.all(|c| c == '_' || c.is_ascii_alphanumeric());
+
+            if !valid {
+                return Err(usage!("invalid variable name {:?} for --export", name));
+            }
+
+            if config.number != 1 {
+                return Err(usage!("--export prints a single assignment, it conflicts with --number"));
+            }
+        }
+
+        // the token count can only be derived once the pool is final
+        if self.charset.is_some() && config.count_source == CountSource::Default {
+            config.token_count = Config::count_for_bits(CHARSET_BITS, config.token_data.len())?;
+        }
+
+        if let Some(seconds) = config.crack_time {
+            if config.count_source == CountSource::Flag || config.bits.is_some() {
+                return Err(usage!("--crack-time conflicts with --count and --bits"));
+            }
+
+            // expected crack time is pool^count / 2 / rate
+            config.bits = Some((seconds * config.attack_rate() / 0.5).log2().max(1.0));
+        }
+
+        if let Some(bits) = config.bits {
+            if config.count_source == CountSource::Flag {
+                return Err(usage!("--bits conflicts with --count"));
+            }
+
+            config.token_count = Config::count_for_bits(bits, config.token_data.len())?;
+            config.count_source = match config.crack_time {
+                Some(_) => CountSource::CrackTime,
+                None => CountSource::Bits,
+            };
+        }
+
+        // decoding needs the same pool and packing the passphrase was generated with
+        let packed = match (self.exact_bits, self.decode_bits) {
+            (Some(_), Some(_)) => return Err(usage!("--exact-bits conflicts with --decode-bits")),
+            (Some(bits), None) => Some(("--exact-bits", bits)),
+            (None, Some(bits)) => Some(("--decode-bits", bits)),
+            (None, None) => None,
+        };
+
+        if let Some((flag, bits)) = packed {
+            if config.count_source == CountSource::Flag || config.bits.is_some() {
+                return Err(usage!("{} conflicts with --count, --bits and --crack-time", flag));
+            }
+
+            if bits as f64 > MAX_BITS {
+                let msg = format!("target of {} bits exceeds the limit of {} bits", bits, MAX_BITS);
+                return Err(PassGenError::Unsatisfiable(msg));
+            }
+
+            let packing = BitPacking::new(bits, config.token_data.len())?;
+
+            config.token_count = packing.count();
+            config.count_source = CountSource::ExactBits;
+            config.packing = Some(packing);
+            config.decode = self.decode_bits.is_some();
+        }
+
+        if let Some(pattern) = &self.template {
+            let sizing = config.count_source == CountSource::Flag || config.bits.is_some() || self.length.is_some();
+
+            if sizing || separated || !config.sep_set.is_empty() {
+                return Err(usage!(
+                    "--template conflicts with --count, --sep, --sep-set, --bits, --crack-time and --length"
+                ));
+            }
+
+            config.template = Some(Template::parse(pattern)?);
+            config.count_source = CountSource::Template;
+        }
+
+        if let Some(text) = &self.acrostic {
+            let sizing = config.count_source == CountSource::Flag || config.bits.is_some() || self.length.is_some();
+
+            if sizing || config.template.is_some() {
+                return Err(usage!("--acrostic conflicts with --count, --bits, --crack-time, --length and --template"));
+            }
+
+            let acrostic = Acrostic::new(text, config.token_data.as_ref())?;
+
+            config.token_count = acrostic.len() as u32;
+            config.count_source = CountSource::Acrostic;
+            config.acrostic = Some(acrostic);
+        }
+
+        if self.sentence {
+            let sizing = config.count_source == CountSource::Flag || config.bits.is_some() || self.length.is_some();
+            let custom = !matches!(config.pool_source, PoolSource::Preset(_));
+            let pool = custom || self.preset.is_some() || policy.is_some();
+
+            if sizing || pool || config.template.is_some() || config.acrostic.is_some() {
+                return Err(usage!(
+                    "--sentence conflicts with --count, --bits, --crack-time, --length, --template, --acrostic, \
+                    --preset, --file, --charset and --policy"
+                ));
+            }
+
+            if self.filters.no_confusable {
+                return Err(usage!("--no-confusable only filters the token pool, not the word lists of --sentence"));
+            }
+
+            let sentence = match &self.sentence_template {
+                Some(pattern) => Sentence::parse(pattern)?,
+                None => Sentence::new()?,
+            };
+
+            if !separated {
+                config.token_sep = String::from("-");
+                config.sep_layer = Layer::Default;
+            }
+
+            config.token_count = sentence.templates().iter().map(Vec::len).max().unwrap_or(0) as u32;
+            config.count_source = CountSource::Sentence;
+            config.sentence = Some(sentence);
+        }
+
+        if let Some(layout) = &self.join {
+            let shaped =
+                config.template.is_some() || self.length.is_some() || config.sentence.is_some() || packed.is_some();
+            let altered =
+                config.leet.is_some() || config.random_case.is_some() || self.mutate.is_some() || config.checksum;
+            let checked = config.max_length.is_some()
+                || !config.require.is_empty()
+                || config.start_with_letter
+                || policy.is_some();
+
+            if shaped || altered || checked || !config.sep_set.is_empty() || config.vertical {
+                return Err(usage!(
+                    "--join conflicts with --template, --length, --sentence, --exact-bits, --decode-bits, --leet, \
+                    --random-case, --mutate, --checksum, --max-length, --require, --start-with-letter, --policy, \
+                    --sep-set and --vertical"
+                ));
+            }
+
+            let join = Join::parse(layout)?;
+            let sizing = config.count_source == CountSource::Flag
+                || config.bits.is_some()
+                || config.acrostic.is_some()
+                || !config.sources.is_empty();
+
+            // without a place for the rest, the placeholders are the tokens
+            if !join.has_rest() && !sizing {
+                config.token_count = join.highest() as u32;
+                config.count_source = CountSource::Join;
+            }
+
+            join.check_count(config.token_count)?;
+            config.join = Some(join);
+        }
+
+        let reshaped = config.template.is_some() || self.length.is_some() || config.sentence.is_some();
+
+        // packed tokens only decode when they appear as drawn
+        if let Some((flag, _)) = packed {
+            let altered = config.acrostic.is_some()
+                || config.leet.is_some()
+                || config.random_case.is_some()
+                || self.mutate.is_some()
+                || config.checksum;
+
+            if reshaped || altered || !config.sep_set.is_empty() {
+                return Err(usage!(
+                    "{} conflicts with --template, --length, --sentence, --acrostic, --leet, --random-case, --mutate, \
+                    --checksum and --sep-set",
+                    flag
+                ));
+            }
+        }
+
+        if config.leet.is_some() && reshaped {
+            return Err(usage!("--leet conflicts with --template, --length and --sentence"));
+        }
+
+        // template capitals are fixed, and substituted letters would make the case entropy depend on them
+        if config.random_case.is_some() && (reshaped || config.leet.is_some()) {
+            return Err(usage!("--random-case conflicts with --template, --length, --sentence and --leet"));
+        }
+
+        match (self.mutate, &self.mutate_pool) {
+            (Some(_), _) if reshaped => {
+                return Err(usage!("--mutate conflicts with --template, --length and --sentence"));
+            },
+            (Some(count), spec) => {
+                let chars = match spec {
+                    Some(spec) => crate::parse_charset(spec, self.unicode)?.chars().map(String::from).collect(),
+                    None => Config::digits_and_symbols(policy.as_ref()),
+                };
+
+                let (shortest, _, _) = config.token_data.length_stats();
+                let positions = shortest * config.token_count as usize;
+
+                if count as usize > positions {
+                    return Err(PassGenError::Unsatisfiable(format!(
+                        "--mutate {} exceeds the {} token characters of the shortest password",
+                        count, positions
+                    )));
+                }
+
+                if chars.is_empty() {
+                    let msg = "the policy allows no digit or symbol to mutate with".to_string();
+                    return Err(PassGenError::Unsatisfiable(msg));
+                }
+
+                config.mutate = Some((count, TokenData::from_strings(chars)?));
+            },
+            (None, Some(_)) => return Err(usage!("--mutate-pool requires --mutate")),
+            (None, None) => {},
+        }
+
+        if config.vertical {
+            if config.quote != Quote::None || config.format != Format::Plain {
+                return Err(usage!("--vertical conflicts with --csv, --json, --quote and --export"));
+            }
+
+            // the separators are left out, so their entropy would be too
+            if reshaped || !config.sep_set.is_empty() {
+                return Err(usage!("--vertical conflicts with --template, --length, --sentence and --sep-set"));
+            }
+
+            // a line break is never part of a token, so the lines split the tokens apart whatever they hold
+            config.token_sep = String::from("\n");
+        }
+
+        if config.split.is_some() {
+            if config.number != 1 {
+                return Err(usage!("--split shares a single password, it conflicts with --number"));
+            }
+
+            if config.quote != Quote::None || config.format != Format::Plain || config.vertical {
+                return Err(usage!("--split conflicts with --csv, --json, --quote and --vertical"));
+            }
+
+            if !config.encrypt_to.is_empty() {
+                return Err(usage!("--encrypt-to conflicts with --split"));
+            }
+
+            if config.age() {
+                return Err(usage!("--age-recipient conflicts with --split"));
+            }
+        } else if config.also_plain && config.encrypt_to.is_empty() && !config.age() {
+            return Err(usage!("--also-plain requires --split, --encrypt-to or --age-recipient"));
+        }
+
+        if config.age() && !config.encrypt_to.is_empty() {
+            return Err(usage!("--age-recipient conflicts with --encrypt-to"));
+        }
+
+        // the stats of csv and json would have to leave out part of the entry
+        if config.pepper && (config.format != Format::Plain || config.split.is_some()) {
+            return Err(usage!("--pepper conflicts with --csv, --json and --split"));
+        }
+
+        if config.tty_echo {
+            if config.split.is_some() {
+                return Err(usage!("--tty-echo conflicts with --split, the shares keep the password off screen"));
+            }
+
+            // without a controlling terminal there is nobody to show it to
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                config.tty = std::fs::OpenOptions::new().write(true).open(TTY_PATH).ok();
+            }
+        }
+
+        if config.checksum {
+            if reshaped || !config.sep_set.is_empty() {
+                return Err(usage!("--checksum conflicts with --template, --length, --sentence and --sep-set"));
+            }
+
+            // verification splits the passphrase on the separator
+            let words = config.token_data.as_ref();
+            let sep = config.token_sep.as_str();
+
+            if sep.is_empty() {
+                return Err(usage!("--checksum needs a separator to tell the tokens apart, set one with --sep"));
+            }
+
+            if let Some(token) = words.range().map(|idx| words.get(idx)).find(|token| token.contains(sep)) {
+                return Err(PassGenError::Unsatisfiable(format!(
+                    "the token {:?} contains the separator {:?}, so the checksum could not be verified",
+                    token, sep
+                )));
+            }
+
+            // nor may substitutions, mutations and case changes produce it
+            let leet = |c: char| config.leet.is_some() && LEET_TABLE.iter().any(|(_, subs)| subs.contains(&c));
+            let mutate = |c: char| {
+                config.mutate.as_ref().is_some_and(|(_, pool)| pool.range().any(|idx| pool.get(idx).contains(c)))
+            };
+            let case = |c: char| config.random_case.is_some() && c.is_uppercase();
+
+            if let Some(c) = sep.chars().find(|&c| leet(c) || mutate(c) || case(c)) {
+                return Err(usage!(
+                    "--checksum needs a separator --leet, --mutate and --random-case never produce, {:?} is one \
+                    they can",
+                    c
+                ));
+            }
+        }
+
+
+        if let Some(length) = self.length {
+            if config.count_source == CountSource::Flag || config.bits.is_some() {
+                return Err(usage!("--length conflicts with --count, --bits and --crack-time"));
+            }
+
+            let (shortest, _, _) = config.token_data.length_stats();
+
+            if shortest > length as usize {
+                return Err(PassGenError::Unsatisfiable(format!(
+                    "the shortest token is {} characters long, it cannot fit --length {}",
+                    shortest, length
+                )));
+            }
+
+            let padding = Config::digits_and_symbols(policy.as_ref());
+
+            if padding.is_empty() {
+                let msg = "the policy allows no digit or symbol to pad --length with".to_string();
+                return Err(PassGenError::Unsatisfiable(msg));
+            }
+
+            config.length = Some((length, TokenData::from_strings(padding)?));
+            config.count_source = CountSource::Length;
+        }
+
+        if let Some(policy) = &policy {
+            for class in policy.require().iter() {
+                config.require.insert(class);
+            }
+        }
+
+        for class in config.require.iter() {
+            if config.require_probability(ClassSet::default().with(class)) == 0.0 {
+                return Err(PassGenError::Unsatisfiable(format!(
+                    "the token pool and separators never produce {} characters, so they cannot be required",
+                    class.name()
+                )));
+            }
+        }
+
+        if config.start_with_letter {
+            if config.letter_probability() == 0.0 {
+                let msg = "no password can start with a letter, the pool has no tokens starting with one";
+                return Err(PassGenError::Unsatisfiable(msg.to_string()));
+            }
+
+            // character pools draw the first position from their letters, other pools re-draw
+            let words = config.token_data.as_ref();
+
+            let drawn = config.template.is_none() && config.length.is_none() && config.packing.is_none();
+
+            if drawn && words.length_stats().2 == 1 {
+                let letters = words.range().map(|idx| words.get(idx)).filter(|t| t.starts_with(char::is_alphabetic));
+                config.first_pool = Some(TokenData::from_strings(letters.map(String::from).collect())?);
+            }
+        }
+
+        if let (Some(policy), Some(path)) = (policy, self.policy) {
+            if config.sep_set.is_empty() && !config.token_sep.chars().all(|c| policy.allows_char(c)) {
+                return Err(PassGenError::Unsatisfiable(format!(
+                    "the separator {:?} is not allowed by the policy, choose another with --sep",
+                    config.token_sep
+                )));
+            }
+
+            let random = !config.sep_set.is_empty();
+            config.sep_set.retain(|&c| policy.allows_char(c));
+
+            if random && config.sep_set.is_empty() {
+                let msg = "no character of --sep-set is allowed by the policy".to_string();
+                return Err(PassGenError::Unsatisfiable(msg));
+            }
+
+            if let (_, Some(max)) = policy.length_bounds() {
+                config.max_length = Some(config.max_length.map_or(max, |limit| limit.min(max)));
+            }
+
+            config.fit_length(&policy)?;
+            config.policy = Some((path, policy));
+        }
+
+        if let Some(max) = config.max_length {
+            let (shortest, _, _) = config.length();
+
+            if shortest > max {
+                return Err(PassGenError::Unsatisfiable(format!(
+                    "the shortest possible password is {} characters, it cannot fit in {}, \
+                    use fewer or shorter tokens",
+                    shortest, max
+                )));
+            }
+
+            // re-drawing would rarely succeed within the retry cap
+            let fit = config.max_length_probability();
+
+            if fit * (config.retry_limit as f64) < 1.0 {
+                return Err(PassGenError::Unsatisfiable(format!(
+                    "only {:.3}% of candidates fit in {} characters, use fewer or shorter tokens",
+                    fit * 100.0,
+                    max
+                )));
+            }
+        }
+
+        // a typo like --count 4000000000 would print gigabytes
+        if config.token_count > MAX_TOKENS && !self.force {
+            return Err(usage!(
+                "{} tokens per password exceed the limit of {}, pass --force to generate them anyway",
+                config.token_count,
+                MAX_TOKENS
+            ));
+        }
+
+        Ok(config)
+    }
+
+    // a pool that only has a size can be reported on, never drawn from
+    fn build_hypothetical(self, size: usize) -> Result<Config, PassGenError> {
+        let pool = self.preset.is_some() || self.file.is_some() || self.from.is_some() || self.charset.is_some();
+
+        if pool || self.policy.is_some() {
+            return Err(usage!("--pool-size conflicts with --preset, --file, --from, --charset and --policy"));
+        }
+
+        let mut config = self.config;
+
+        let shaped = self.length.is_some()
+            || self.acrostic.is_some()
+            || self.sentence
+            || self.template.is_some()
+            || self.mutate.is_some()
+            || self.exact_bits.is_some()
+            || self.decode_bits.is_some()
+            || self.sep_set.is_some()
+            || self.derive.is_some()
+            || config.leet.is_some()
+            || config.checksum
+            || config.start_with_letter
+            || config.max_length.is_some()
+            || !config.require.is_empty()
+            || config.estimate.is_some()
+            || config.compare.is_some();
+
+        if shaped {
+            return Err(usage!("--pool-size only goes with --count, --bits, --crack-time and the report flags"));
+        }
+
+        if config.output.is_some() || config.format != Format::Plain {
+            return Err(usage!("--report-only cannot be combined with --output, --csv or --json"));
+        }
+
+        // there is nothing to generate from
+        config.pool_source = PoolSource::Size(size);
+        config.report = true;
+        config.report_only = true;
+
+        if let Some(seconds) = config.crack_time {
+            if self.count.is_some() || config.bits.is_some() {
+                return Err(usage!("--crack-time conflicts with --count and --bits"));
+            }
+
+            config.bits = Some((seconds * config.attack_rate() / 0.5).log2().max(1.0));
+        }
+
+        (config.token_count, config.count_source) = match (self.count, config.bits) {
+            (Some(_), Some(_)) => return Err(usage!("--bits conflicts with --count")),
+            (Some(count), None) => (count, CountSource::Flag),
+            (None, Some(bits)) => (
+                Config::count_for_bits(bits, size)?,
+                if config.crack_time.is_some() { CountSource::CrackTime } else { CountSource::Bits },
+            ),
+            (None, None) => return Err(usage!("--pool-size needs --count, --bits or --crack-time")),
+        };
+
+        Ok(config)
+    }
+}
+
+// the join layout of a recovery code, its characters in groups joined by the separator
+fn recovery_layout(length: u32, group: u32, sep: &str) -> String {
+    let sep = sep.replace('{', "{{").replace('}', "}}");
+
+    (0..length)
+        .map(|pos| match pos != 0 && pos % group == 0 {
+            true => format!("{}{{{}}}", sep, pos + 1),
+            false => format!("{{{}}}", pos + 1),
+        })
+        .collect()
+}
+
+
+// read access for callers of the library, a built configuration only changes through the builder
+impl Config {
+    pub fn verbose(&self) -> bool {
+        self.verbose
+    }
+
+    pub fn stats(&self) -> bool {
+        self.stats
+    }
+
+    pub fn report(&self) -> bool {
+        self.report
+    }
+
+    pub fn report_only(&self) -> bool {
+        self.report_only
+    }
+
+    pub fn report_format(&self) -> ReportFormat {
+        self.report_format
+    }
+
+    pub fn guess_rates(&self) -> &[(String, f64)] {
+        &self.guess_rates
+    }
+
+    pub fn target_hash(&self) -> Option<&TargetHash> {
+        self.target_hash.as_ref()
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    pub fn header(&self) -> bool {
+        self.header
+    }
+
+    pub fn title_template(&self) -> Option<&str> {
+        self.title_template.as_deref()
+    }
+
+    pub fn username_template(&self) -> Option<&str> {
+        self.username_template.as_deref()
+    }
+
+    pub fn keepass_group(&self) -> Option<&str> {
+        self.keepass_group.as_deref()
+    }
+
+    pub fn username(&self) -> Option<&Username> {
+        self.username.as_ref()
+    }
+
+    pub fn one_per_line(&self) -> bool {
+        self.one_per_line
+    }
+
+    pub fn recovery_codes(&self) -> bool {
+        self.recovery_codes
+    }
+
+    pub fn identifier(&self) -> Option<&Identifier> {
+        self.identifier.as_ref()
+    }
+
+    pub fn vertical(&self) -> bool {
+        self.vertical
+    }
+
+    pub fn numbers(&self) -> bool {
+        self.numbers
+    }
+
+    pub fn split(&self) -> Option<SecretSharing> {
+        self.split
+    }
+
+    pub fn also_plain(&self) -> bool {
+        self.also_plain
+    }
+
+    pub fn encrypt_to(&self) -> &[String] {
+        &self.encrypt_to
+    }
+
+    #[cfg(feature = "age")]
+    pub fn age_recipients(&self) -> &[AgeRecipient] {
+        &self.age_recipients
+    }
+
+    pub fn tty_echo(&self) -> bool {
+        self.tty_echo
+    }
+
+    pub fn confirm_reveal(&self) -> bool {
+        self.confirm_reveal
+    }
+
+    pub fn pepper(&self) -> bool {
+        self.pepper
+    }
+
+    pub fn pepper_fd(&self) -> Option<u32> {
+        self.pepper_fd
+    }
+
+    pub fn output(&self) -> Option<&str> {
+        self.output.as_deref()
+    }
+
+    pub fn quote(&self) -> Quote {
+        self.quote
+    }
+
+    pub fn export(&self) -> Option<&str> {
+        self.export.as_deref()
+    }
+
+    pub fn unique(&self) -> bool {
+        self.unique
+    }
+
+    pub fn start_with_letter(&self) -> bool {
+        self.start_with_letter
+    }
+
+    pub fn checksum(&self) -> bool {
+        self.checksum
+    }
+
+    pub fn first_pool(&self) -> Option<&TokenData> {
+        self.first_pool.as_ref()
+    }
+
+    pub fn leet(&self) -> Option<Leet> {
+        self.leet
+    }
+
+    pub fn random_case(&self) -> Option<RandomCase> {
+        self.random_case
+    }
+
+    pub fn mutate(&self) -> Option<&(u32, TokenData)> {
+        self.mutate.as_ref()
+    }
+
+    pub fn paranoid(&self) -> bool {
+        self.paranoid
+    }
+
+    pub fn derive(&self) -> Option<&Derivation> {
+        self.derive.as_ref()
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn bits(&self) -> Option<f64> {
+        self.bits
+    }
+
+    pub fn min_entropy(&self) -> Option<f64> {
+        self.min_entropy
+    }
+
+    pub fn bar_target(&self) -> f64 {
+        self.bar_target
+    }
+
+    pub fn crack_time(&self) -> Option<f64> {
+        self.crack_time
+    }
+
+    pub fn jobs(&self) -> u32 {
+        self.jobs
+    }
+
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    pub fn token_count(&self) -> u32 {
+        self.token_count
+    }
+
+    pub fn count_source(&self) -> &CountSource {
+        &self.count_source
+    }
+
+    pub fn fixed_length(&self) -> Option<&(u32, TokenData)> {
+        self.length.as_ref()
+    }
+
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    pub fn retry_limit(&self) -> u32 {
+        self.retry_limit
+    }
+
+    pub fn template(&self) -> Option<&Template> {
+        self.template.as_ref()
+    }
+
+    pub fn acrostic(&self) -> Option<&Acrostic> {
+        self.acrostic.as_ref()
+    }
+
+    pub fn sentence(&self) -> Option<&Sentence> {
+        self.sentence.as_ref()
+    }
+
+    pub fn packing(&self) -> Option<BitPacking> {
+        self.packing
+    }
+
+    pub fn join(&self) -> Option<&Join> {
+        self.join.as_ref()
+    }
+
+    pub fn decode(&self) -> bool {
+        self.decode
+    }
+
+    pub fn token_sep(&self) -> &str {
+        &self.token_sep
+    }
+
+    pub fn sep_set(&self) -> &[char] {
+        &self.sep_set
+    }
+
+    pub fn require(&self) -> ClassSet {
+        self.require
+    }
+
+    pub fn policy(&self) -> Option<&(String, Policy)> {
+        self.policy.as_ref()
+    }
+
+    pub fn pool_source(&self) -> &PoolSource {
+        &self.pool_source
+    }
+
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    pub fn config_file(&self) -> Option<&str> {
+        self.config_file.as_deref()
+    }
+
+    pub fn token_data(&self) -> &dyn TokenSource {
+        self.token_data.as_ref()
+    }
+
+    pub fn pool_filters(&self) -> &[(&'static str, usize)] {
+        &self.pool_filters
+    }
+
+    pub fn sources(&self) -> &[Box<dyn TokenSource>] {
+        &self.sources
+    }
+
+    pub fn breach_filter(&self) -> Option<&(String, BreachFilter)> {
+        self.breach_filter.as_ref()
+    }
+
+    pub fn history(&self) -> Option<&HistoryFile> {
+        self.history.as_ref()
+    }
+
+    /// The locked history file, for maintenance that ends the run.
+    pub fn into_history(self) -> Option<HistoryFile> {
+        self.history
+    }
+
+    pub fn history_prune(&self) -> Option<u32> {
+        self.history_prune
+    }
+
+    pub fn audit_log(&self) -> Option<&AuditLog> {
+        self.audit_log.as_ref()
+    }
+
+    pub fn compare(&self) -> Option<&str> {
+        self.compare.as_deref()
+    }
+
+    pub fn estimate(&self) -> Option<&str> {
+        self.estimate.as_deref()
+    }
+
+    pub fn batch(&self) -> bool {
+        self.batch
+    }
+
+    pub fn batch_strict(&self) -> bool {
+        self.batch_strict
+    }
+
+    pub fn spec(&self) -> Option<&str> {
+        self.spec.as_deref()
+    }
+}
+
+impl Config {
+    pub fn check_min_entropy(&self) -> Result<(), PassGenError> {
+        let min = match self.min_entropy {
+            Some(min) => min,
+            None => return Ok(()),
+        };
+
+        // only the guaranteed minimum counts when the entropy varies
+        let total = self.min_bits().unwrap_or_else(|| self.entropy());
+
+        if let (Some(identifier), true) = (&self.identifier, total < min) {
+            return Err(PassGenError::Unsatisfiable(match identifier {
+                Identifier::ApiToken(_) => format!(
+                    "an api token of {:.1} random bits is below the minimum of {} bits, raise --bits",
+                    total, min
+                ),
+                Identifier::LicenseKey(_) => format!(
+                    "a license key of {:.1} random bits is below the minimum of {} bits, raise --group or --key-groups",
+                    total, min
+                ),
+                _ => format!(
+                    "a {} has {} random bits, below the minimum of {} bits, and cannot be made longer",
+                    identifier.name(),
+                    total,
+                    min
+                ),
+            }));
+        }
+
+        if total < min {
+            let per_token = total / self.token_count as f64;
+            let missing = ((min - total) / per_token).ceil();
+
+            return Err(PassGenError::Weak { total, min, missing });
+        }
+
+        Ok(())
+    }
+
+    fn check_pool(size: usize) -> Result<(), PassGenError> {
+        if size < 2 {
+            return Err(PassGenError::InvalidData(format!(
+                "token pool has {} tokens, generated passwords would be predictable; \
+                check the token file and filters",
+                size
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn attack_rate(&self) -> f64 {
+        let rates = if self.guess_rates.is_empty() { DEFAULT_RATES } else { &self.rates() };
+
+        rates.iter().map(|&(_, rate)| rate).fold(0.0, f64::max)
+    }
+
+    pub fn rates(&self) -> Vec<(&str, f64)> {
+        self.guess_rates.iter().map(|(label, rate)| (label.as_str(), *rate)).collect()
+    }
+
+    fn count_for_bits(bits: f64, pool_size: usize) -> Result<u32, PassGenError> {
+        if pool_size < 2 {
+            let msg = format!("cannot reach {} bits with a pool of {} tokens", bits, pool_size);
+            return Err(PassGenError::Unsatisfiable(msg));
+        }
+
+        if bits > MAX_BITS {
+            let msg = format!("target of {} bits exceeds the limit of {} bits", bits, MAX_BITS);
+            return Err(PassGenError::Unsatisfiable(msg));
+        }
+
+        let count = (bits / (pool_size as f64).log2() - 1e-9).ceil();
+
+        Ok(count.max(1.0) as u32)
+    }
+
+    /// The raw bytes are checked against the hash before any of them is parsed.
+    pub fn load_file(
+        path: &str,
+        whitespace: &Whitespace,
+        hash: Option<&WordlistHash>,
+    ) -> Result<(TokenData, PoolFilters), PassGenError> {
+        let io_error = |source| PassGenError::Io { action: "reading token file", path: Some(path.to_string()), source };
+
+        let mut data = Vec::new();
+        let read = match path {
+            "-" => io::Read::read_to_end(&mut io::stdin().lock(), &mut data),
+            path => File::open(path).and_then(|mut file| io::Read::read_to_end(&mut file, &mut data)),
+        };
+
+        read.map_err(io_error)?;
+
+        if let Some(hash) = hash {
+            hash.check(if path == "-" { "<stdin>" } else { path }, &data)?;
+        }
+
+        let loaded = match TokenData::from_reader(&data[..]) {
+            Err(PassGenError::Io { source, .. }) => Err(io_error(source)),
+            loaded => loaded,
+        };
+
+        let (mut token_data, spaced) = TokenData::with_whitespace(&loaded?, whitespace)?;
+        let mut filters = Vec::new();
+
+        if *whitespace == Whitespace::Skip && spaced > 0 {
+            eprintln!(
+                "pass-gen: warning: skipped {} line(s) of {} with whitespace inside, \
+                --whitespace keeps, splits or joins them",
+                spaced, path
+            );
+
+            filters.push(("whitespace", spaced));
+        }
+
+        let removed = token_data.dedup();
+        filters.push(("duplicates", removed));
+
+        Ok((token_data, filters))
+    }
+
+    // every source of --from loaded and filtered on its own, with the filters of all of them summed
+    fn load_sources(
+        spec: &str,
+        whitespace: &Whitespace,
+        token_filters: &TokenFilters,
+        hashes: &[WordlistHash],
+    ) -> Result<(Vec<Box<dyn TokenSource>>, PoolFilters), PassGenError> {
+        let mut sources = Vec::new();
+        let mut filters: PoolFilters = Vec::new();
+        let mut hashes = hashes.iter();
+
+        for (idx, source) in spec.split(',').enumerate() {
+            let in_source = |err: PassGenError| {
+                let context = format!("source {} of --from ({:?})", idx + 1, source);
+
+                match err {
+                    PassGenError::Usage(msg) => PassGenError::Usage(format!("{}: {}", context, msg)),
+                    PassGenError::Unsatisfiable(msg) => PassGenError::Unsatisfiable(format!("{}: {}", context, msg)),
+                    err => PassGenError::InvalidData(format!("{}: {}", context, err)),
+                }
+            };
+
+            let (token_data, loaded): (Box<dyn TokenSource>, PoolFilters) = match source.trim() {
+                "" => return Err(in_source(usage!("empty source, expected a file or preset:<name>"))),
+                name if name.starts_with("preset:") => match crate::preset(&name["preset:".len()..]) {
+                    Some(preset) => (Box::new(preset.token_data), Vec::new()),
+                    None => return Err(in_source(usage!("invalid preset, expected one of {}", PRESETS.join(", ")))),
+                },
+                path => {
+                    let (token_data, loaded) = Config::load_file(path, whitespace, hashes.next()).map_err(in_source)?;
+                    (Box::new(token_data), loaded)
+                },
+            };
+
+            let mut source_filters = loaded;
+            let token_data = token_filters.apply(token_data, &mut source_filters).map_err(in_source)?;
+
+            Config::check_pool(token_data.len()).map_err(in_source)?;
+
+            for (name, removed) in source_filters {
+                match filters.iter_mut().find(|(seen, _)| *seen == name) {
+                    Some((_, total)) => *total += removed,
+                    None => filters.push((name, removed)),
+                }
+            }
+
+            sources.push(token_data);
+        }
+
+        Ok((sources, filters))
+    }
+
+    /// The string to join with only goes with the join policy.
+    pub fn whitespace_policy(
+        whitespace: Option<Whitespace>,
+        join: Option<String>,
+    ) -> Result<Option<Whitespace>, PassGenError> {
+        match (whitespace, join) {
+            (Some(Whitespace::Join(_)), Some(join)) => Ok(Some(Whitespace::Join(join))),
+            (_, Some(_)) => Err(usage!("--whitespace-join requires --whitespace join")),
+            (whitespace, None) => Ok(whitespace),
+        }
+    }
+
+    // the config file's section for the preset, its warnings go to stderr
+    fn load_section(config: &mut Config, preset: &str) -> Result<PresetSection, PassGenError> {
+        let path = match config_dir() {
+            Some(dir) => dir.join("config.toml"),
+            None => return Ok(PresetSection::default()),
+        };
+
+        let file = ConfigFile::from_path(&path).map_err(|err| match err {
+            PassGenError::InvalidData(msg) => PassGenError::InvalidData(format!("{}: {}", path.display(), msg)),
+            err => err,
+        })?;
+
+        for warning in file.warnings() {
+            eprintln!("pass-gen: warning: {}: {}", path.display(), warning);
+        }
+
+        match file.preset(preset) {
+            Some(section) => {
+                config.config_file = Some(format!("{} [preset.{}]", path.display(), preset));
+                Ok(section.clone())
+            },
+            None => Ok(PresetSection::default()),
+        }
+    }
+
+    fn load_filter(path: &str) -> Result<BreachFilter, PassGenError> {
+        let loaded = File::open(path)
+            .map_err(|source| PassGenError::Io { action: "reading filter file", path: None, source })
+            .and_then(|file| BreachFilter::read_from(BufReader::new(file)));
+
+        match loaded {
+            Err(PassGenError::Io { action, source, .. }) => {
+                Err(PassGenError::Io { action, path: Some(path.to_string()), source })
+            },
+            loaded => loaded,
+        }
+    }
+
+    pub fn pool(&self) -> Pool {
+        // an identifier is one draw of its random bits
+        if let Some(identifier) = &self.identifier {
+            let length = identifier.length();
+
+            return Pool {
+                size: identifier.bits().exp2() as usize,
+                per_token_bits: identifier.bits(),
+                filters: Vec::new(),
+                length: Some((length, length as f64, length)),
+                components: self.entropy_components(),
+            };
+        }
+
+        // the words of a sentence come from one list per part of speech
+        if let Some(sentence) = &self.sentence {
+            return Pool {
+                size: PartOfSpeech::ALL.into_iter().map(|pos| sentence.words(pos).len()).sum(),
+                per_token_bits: sentence.bits() / sentence.avg_words(),
+                filters: Vec::new(),
+                length: Some(self.length()),
+                components: self.entropy_components(),
+            };
+        }
+
+        // a hypothetical pool has no tokens to measure
+        if let PoolSource::Size(size) = self.pool_source {
+            let per_token_bits = (size as f64).log2();
+
+            return Pool {
+                size,
+                per_token_bits,
+                filters: Vec::new(),
+                length: None,
+                components: vec![("tokens", per_token_bits * self.token_count as f64)],
+            };
+        }
+
+        let per_token_bits = match self.sources.is_empty() {
+            true => self.token_data.entropy_bits_per_token(),
+            false => self.sources_bits() / self.token_count as f64,
+        };
+
+        Pool {
+            size: self.token_data.len(),
+            per_token_bits,
+            filters: self.pool_filters.clone(),
+            length: Some(self.length()),
+            components: self.entropy_components(),
+        }
+    }
+
+    /// Minimum, mean and maximum password length in characters.
+    pub fn length(&self) -> (usize, f64, usize) {
+        if let Some((length, _)) = &self.length {
+            return (*length as usize, *length as f64, *length as usize);
+        }
+
+        let (min, avg, max) = match (&self.template, &self.sentence) {
+            (Some(template), _) => template.length_stats(self.token_data.as_ref()),
+            (None, Some(sentence)) => sentence.length_stats(self.sep_len()),
+            (None, None) => {
+                let count = self.token_count as usize + self.checksum as usize;
+                let seps = match &self.join {
+                    Some(join) => self.sep_len() * join.separators(count) + join.literal_len(),
+                    None => self.sep_len() * (count - 1),
+                };
+
+                // each position of --from has the lengths of its own source
+                let (min, avg, max) = match self.sources.is_empty() {
+                    true => {
+                        let (min, avg, max) = self.token_data.length_stats();
+                        (min * count, avg * count as f64, max * count)
+                    },
+                    false => (0..count)
+                        .map(|position| self.sources[position % self.sources.len()].length_stats())
+                        .fold((0, 0.0, 0), |(min, avg, max), stats| (min + stats.0, avg + stats.1, max + stats.2)),
+                };
+
+                (min + seps, avg + seps as f64, max + seps)
+            },
+        };
+
+        // longer passwords are re-drawn, the average is only capped
+        match self.max_length {
+            Some(limit) => (min, avg.min(limit as f64), max.min(limit)),
+            None => (min, avg, max),
+        }
+    }
+
+    /// Where the tokens come from, for --verbose and the audit log.
+    pub fn source(&self) -> String {
+        match &self.identifier {
+            Some(Identifier::ApiToken(token)) => return format!("api token with prefix {:?}", token.prefix()),
+            Some(Identifier::LicenseKey(_)) => return String::from("license key"),
+            Some(identifier) => return format!("random {}", identifier.name()),
+            None => {},
+        }
+
+        match &self.pool_source {
+            _ if self.sentence.is_some() => String::from("builtin sentence word lists"),
+            PoolSource::Preset(name) => format!("preset {}", name),
+            PoolSource::File(path) => format!("file {:?}", path),
+            PoolSource::Charset(spec) => format!("charset {:?}", spec),
+            PoolSource::Policy(path) => format!("charset of policy {:?}", path),
+            PoolSource::From(spec) => format!("a token of each of {:?} in turn", spec),
+            PoolSource::Size(size) => format!("hypothetical pool of {} tokens", size),
+        }
+    }
+
+    /// How the passwords are put together, for the notes of --keepass-csv.
+    pub fn parameters(&self) -> String {
+        let shape = match (&self.sentence, &self.template, &self.length) {
+            (Some(sentence), _, _) => format!("sentence {}", sentence),
+            (_, Some(template), _) => format!("template {:?}", template.to_string()),
+            (_, _, Some((length, _))) => format!("{} characters", length),
+            (None, None, None) => match &self.join {
+                Some(join) => format!("{} tokens joined as {:?}", self.token_count, join.to_string()),
+                None => format!("{} tokens separated by {:?}", self.token_count, self.token_sep),
+            },
+        };
+
+        format!("{}, {}", self.source(), shape)
+    }
+
+    fn sep_len(&self) -> usize {
+        if self.sep_set.is_empty() { grapheme_len(&self.token_sep) } else { 1 }
+    }
+
+    // a default count grows until the average password is long enough
+    fn fit_length(&mut self, policy: &Policy) -> Result<(), PassGenError> {
+        let (min, max) = policy.length_bounds();
+
+        if self.count_source == CountSource::Default {
+            let (_, avg, _) = self.token_data.length_stats();
+            let sep_len = self.sep_len() as f64;
+
+            let count = ((min as f64 + sep_len) / (avg + sep_len)).ceil();
+            self.token_count = self.token_count.max(count as u32);
+        }
+
+        let (shortest, _, longest) = self.length();
+
+        if longest < min || max.is_some_and(|max| shortest > max) {
+            let bounds = match max {
+                Some(max) => format!("{}–{}", min, max),
+                None => format!("at least {}", min),
+            };
+
+            if let Some((length, _)) = &self.length {
+                let msg = format!("--length {} is outside the policy's {} characters", length, bounds);
+                return Err(PassGenError::Unsatisfiable(msg));
+            }
+
+            if let Some(template) = &self.template {
+                let msg = format!(
+                    "passwords from template {:?} are {}–{} characters long, the policy requires {}",
+                    template.to_string(), shortest, longest, bounds
+                );
+                return Err(PassGenError::Unsatisfiable(msg));
+            }
+
+            return Err(PassGenError::Unsatisfiable(format!(
+                "passwords of {} tokens are {}–{} characters long, the policy requires {}",
+                self.token_count, shortest, longest, bounds
+            )));
+        }
+
+        Ok(())
+    }
+
+    // every source of randomness registers its contribution here
+    fn entropy_components(&self) -> Vec<(&'static str, f64)> {
+        if let Some(identifier) = &self.identifier {
+            // the check character is derived from the others
+            if let Identifier::LicenseKey(key) = identifier {
+                return vec![("random characters", key.bits()), ("check character", 0.0)];
+            }
+
+            return vec![("random bits", identifier.bits())];
+        }
+
+        let count = self.sentence.as_ref().map_or(self.token_count as f64, Sentence::avg_words);
+        let mut components = match (&self.template, self.length_bits(), &self.sentence) {
+            (Some(template), _, _) => template.components(self.token_data.as_ref()),
+            (None, Some((_, avg)), _) => vec![("tokens, separators and padding", avg)],
+            (None, None, Some(sentence)) => sentence.components(),
+            (None, None, None) => match (&self.acrostic, &self.packing) {
+                (Some(acrostic), _) => vec![("tokens", acrostic.bits(self.token_data.as_ref()))],
+                (None, Some(packing)) => vec![("packed tokens", packing.bits() as f64)],
+                (None, None) if !self.sources.is_empty() => vec![("tokens", self.sources_bits())],
+                (None, None) => vec![("tokens", self.token_data.entropy_bits_per_token() * count)],
+            },
+        };
+
+        if !self.sep_set.is_empty() && self.length.is_none() && self.template.is_none() {
+            components.push(("separators", (self.sep_set.len() as f64).log2() * (count - 1.0)));
+        }
+
+        // only the choice of substitutions counts, attackers try the substituted words anyway
+        if let Some(leet) = &self.leet {
+            components.push(("leet substitutions", leet.bits(self.token_data.as_ref()) * count));
+        }
+
+        if let Some(case) = &self.random_case {
+            components.push(("random case", case.bits(self.token_data.as_ref()) * count));
+        }
+
+        // the replaced positions are a set, their order adds nothing
+        if let Some((mutations, pool)) = &self.mutate {
+            let positions = self.token_data.length_stats().0 * self.token_count as usize;
+            let choose: f64 = (0..*mutations as usize).map(|i| ((positions - i) as f64 / (i + 1) as f64).log2()).sum();
+
+            components.push(("mutations", choose + pool.entropy_bits_per_token() * *mutations as f64));
+        }
+
+        // re-drawing long passwords and missing classes discards part of the keyspace
+        if self.max_length.is_some() && self.max_length_probability() < 1.0 {
+            components.push(("max length", self.max_length_probability().log2()));
+        }
+
+        if !self.require.is_empty() {
+            components.push(("required classes", self.require_probability(self.require).log2()));
+        }
+
+        if self.start_with_letter && self.letter_probability() < 1.0 {
+            components.push(("first letter", self.letter_probability().log2()));
+        }
+
+        // derived from the other tokens, so it adds nothing an attacker has to guess
+        if self.checksum {
+            components.push(("checksum", 0.0));
+        }
+
+        components
+    }
+
+    // every position adds the entropy of the source it is drawn from
+    fn sources_bits(&self) -> f64 {
+        (0..self.token_count as usize)
+            .map(|position| self.sources[position % self.sources.len()].entropy_bits_per_token())
+            .sum()
+    }
+
+    pub fn require_probability(&self, classes: ClassSet) -> f64 {
+        let sep_set: Vec<String> = self.sep_set.iter().map(char::to_string).collect();
+        let seps: Vec<&str> = if sep_set.is_empty() {
+            vec![self.token_sep.as_str()]
+        } else {
+            sep_set.iter().map(String::as_str).collect()
+        };
+
+        if let Some(sentence) = &self.sentence {
+            return sentence.require_probability(classes, &seps);
+        }
+
+        match (&self.template, &self.length) {
+            (Some(template), _) => template.require_probability(classes, self.token_data.as_ref()),
+            (None, Some((length, padding))) => {
+                length_require_probability(classes, self.token_data.as_ref(), *length as usize, &seps, padding)
+            },
+            (None, None) => {
+                let weights = |source: &dyn TokenSource| match (&self.leet, &self.random_case) {
+                    (Some(leet), _) => leet.source_weights(source),
+                    (None, Some(case)) => case.source_weights(source),
+                    (None, None) => source_weights(source),
+                };
+
+                let words = self.token_data.as_ref();
+                let mut draws = match &self.acrostic {
+                    Some(acrostic) => {
+                        (0..acrostic.len()).map(|idx| (weights(&acrostic.bucket(words, idx)), 1)).collect()
+                    },
+                    None => vec![(weights(words), self.token_count)],
+                };
+
+                // the checksum token is treated as one more draw from the pool
+                let checksum = self.checksum as u32;
+
+                if self.checksum {
+                    draws.push((source_weights(words), checksum));
+                }
+
+                // mutated characters count as extra draws, the ones they replace are kept
+                draws.push((class_weights(seps.into_iter()), self.token_count - 1 + checksum));
+
+                if let Some((count, pool)) = &self.mutate {
+                    draws.push((source_weights(pool), *count));
+                }
+
+                draws_require_probability(classes, &draws)
+            },
+        }
+    }
+
+    // the printable ascii digits and symbols the policy allows
+    fn digits_and_symbols(policy: Option<&Policy>) -> Vec<String> {
+        (' '..='~')
+            .filter(|&c| CharClass::Digit.matches(c) || CharClass::Symbol.matches(c))
+            .filter(|&c| policy.is_none_or(|policy| policy.allows_char(c)))
+            .map(String::from)
+            .collect()
+    }
+
+    /// Whether any candidate can be rejected and drawn again.
+    pub fn redraws(&self) -> bool {
+        self.unique
+            || self.breach_filter.is_some()
+            || self.history.is_some()
+            || self.dictionary.is_some()
+            || self.policy.is_some()
+            || self.max_length.is_some()
+            || self.start_with_letter
+            || !self.require.is_empty()
+    }
+
+    /// Whether the password is written token by token instead of being put together first.
+    pub fn streams(&self) -> bool {
+        self.token_count > STREAM_TOKENS
+            && self.number == 1
+            && self.format == Format::Plain
+            && self.template.is_none()
+            && self.length.is_none()
+            && self.sentence.is_none()
+            && self.packing.is_none()
+            && self.join.is_none()
+            && self.leet.is_none()
+            && self.random_case.is_none()
+            && self.mutate.is_none()
+            && !self.checksum
+            && !self.redraws()
+            && self.split.is_none()
+            && !self.pepper
+            && self.quote == Quote::None
+            && !self.vertical
+            && self.username.is_none()
+            && !self.stats
+            && self.tty.is_none()
+    }
+
+    /// Whether the output is encrypted with age.
+    pub fn age(&self) -> bool {
+        #[cfg(feature = "age")]
+        return !self.age_recipients.is_empty();
+
+        #[cfg(not(feature = "age"))]
+        false
+    }
+
+    /// Tokens in a generated password, None where padding or a template hides them.
+    pub fn tokens_in(&self, password: &str) -> Option<usize> {
+        match (&self.sentence, &self.length, &self.template) {
+            (Some(_), _, _) if !self.sep_set.is_empty() => Some(password.split(|c| self.sep_set.contains(&c)).count()),
+            (Some(_), _, _) if !self.token_sep.is_empty() => Some(password.split(self.token_sep.as_str()).count()),
+            (Some(_), _, _) | (_, Some(_), _) | (_, _, Some(_)) => None,
+            (None, None, None) => Some(self.token_count as usize + self.checksum as usize),
+        }
+    }
+
+    /// Whether a batch is laid out in columns, only plain output to a terminal.
+    pub fn columns(&self) -> bool {
+        self.format == Format::Plain
+            && self.output.is_none()
+            && self.quote == Quote::None
+            && !self.vertical
+            && !self.one_per_line
+            && self.username.is_none()
+            && self.number > 1
+            && !cfg!(target_arch = "wasm32")
+            && io::stdout().is_terminal()
+    }
+
+    /// Share of candidates starting with a letter, before any first position pool.
+    pub fn letter_probability(&self) -> f64 {
+        let words = self.token_data.as_ref();
+
+        match (&self.template, &self.sentence) {
+            (Some(template), _) => template.starts_with_probability(words, char::is_alphabetic),
+            (None, Some(sentence)) => {
+                let starts = sentence.templates().iter().map(|template| {
+                    let first = sentence.words(template[0]);
+                    first.range().filter(|&idx| first.get(idx).starts_with(char::is_alphabetic)).count() as f64
+                        / first.len() as f64
+                });
+
+                starts.sum::<f64>() / sentence.templates().len() as f64
+            },
+            (None, None) => {
+                let letters = words.range().filter(|&idx| words.get(idx).starts_with(char::is_alphabetic)).count();
+                letters as f64 / words.len() as f64
+            },
+        }
+    }
+
+    pub fn max_length_probability(&self) -> f64 {
+        if let (Some(max), Some(sentence)) = (self.max_length, &self.sentence) {
+            return sentence.max_length_probability(self.sep_len(), max);
+        }
+
+        match (self.max_length, &self.template, &self.length) {
+            (Some(max), Some(template), _) => template.max_length_probability(self.token_data.as_ref(), max),
+            (Some(max), None, None) => {
+                let count = self.token_count + self.checksum as u32;
+                max_length_probability(self.token_data.as_ref(), count, self.sep_len(), max)
+            },
+            _ => 1.0,
+        }
+    }
+
+    /// Minimum and average entropy with --length, which varies with the tokens drawn.
+    pub fn length_bits(&self) -> Option<(f64, f64)> {
+        let (length, padding) = self.length.as_ref()?;
+
+        Some(length_bits(
+            self.token_data.as_ref(),
+            *length as usize,
+            self.sep_len(),
+            self.sep_set.len().max(1),
+            padding.len(),
+        ))
+    }
+
+    /// Guaranteed entropy when it varies between passwords.
+    pub fn min_bits(&self) -> Option<f64> {
+        if let Some((lowest, _)) = self.length_bits() {
+            return Some(lowest);
+        }
+
+        // the weakest template, with everything else that adds or discards entropy
+        let sentence = self.sentence.as_ref().filter(|sentence| sentence.templates().len() > 1)?;
+
+        Some(self.entropy() - sentence.bits() + sentence.min_bits())
+    }
+
+    /// Average over passwords from a fixed seed, so the score is the same on every run.
+    pub fn memorability(&self) -> Memorability {
+        let mut gen = self.generator(StdRng::seed_from_u64(0));
+
+        let samples: Vec<Memorability> = (0..self.memorability_samples())
+            .map(|_| {
+                let mut password = gen.generate();
+                let memorability = Memorability::of(&password);
+
+                wipe_string(&mut password);
+                memorability
+            })
+            .collect();
+
+        Memorability::mean(&samples)
+    }
+
+    /// Fewer samples of huge passwords, they hardly differ from each other.
+    pub fn memorability_samples(&self) -> usize {
+        (MEMORABILITY_TOKENS / self.token_count.max(1) as usize).clamp(1, MEMORABILITY_SAMPLES)
+    }
+
+    pub fn entropy(&self) -> f64 {
+        self.entropy_components().iter().map(|(_, bits)| bits).sum()
+    }
+
+    /// A generator of passwords as configured, drawing from `rng`.
+    pub fn generator<R: Rng>(&self, rng: R) -> Generator<'_, R> {
+        let mut gen =
+            Generator::new(self.token_data.as_ref(), self.token_count, &self.token_sep, rng).sep_set(&self.sep_set);
+
+        if !self.sources.is_empty() {
+            gen = gen.sources(&self.sources);
+        }
+
+        if let Some(leet) = &self.leet {
+            gen = gen.leet(leet);
+        }
+
+        if let Some(case) = &self.random_case {
+            gen = gen.random_case(case);
+        }
+
+        if let Some((count, pool)) = &self.mutate {
+            gen = gen.mutate(*count as usize, pool);
+        }
+
+        if let Some(acrostic) = &self.acrostic {
+            gen = gen.acrostic(acrostic);
+        }
+
+        if let Some(join) = &self.join {
+            gen = gen.join(join);
+        }
+
+        if let Some(sentence) = &self.sentence {
+            gen = gen.sentence(sentence);
+        }
+
+        if let Some(packing) = &self.packing {
+            gen = gen.packing(packing);
+        }
+
+        if self.checksum {
+            gen = gen.checksum();
+        }
+
+        match (&self.template, &self.length, &self.first_pool) {
+            (Some(template), _, _) => gen.template(template),
+            (None, Some((length, padding)), _) => gen.length(*length as usize, padding),
+            (None, None, Some(first)) => gen.first(first),
+            (None, None, None) => gen,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // without the locale and the config file of whoever runs the tests
+    fn builder() -> ConfigBuilder {
+        ConfigBuilder::new().no_locale(true).no_config(true)
+    }
+
+    fn error(builder: ConfigBuilder) -> PassGenError {
+        builder.build().unwrap_err()
+    }
+
+    #[test]
+    fn builds_the_defaults() {
+        let config = builder().build().unwrap();
+
+        assert_eq!(config.pool_source, PoolSource::Preset(String::from("word")));
+        assert_eq!(config.count_source, CountSource::Default);
+        assert_eq!((config.number, config.jobs), (1, 1));
+    }
+
+    #[test]
+    fn conflicting_modes() {
+        let cases = [
+            (builder().count(4).bits(64.0), "--bits conflicts with --count"),
+            (builder().count(4).crack_time(1e9), "--crack-time conflicts with --count and --bits"),
+            (builder().count(4).length(20), "--length conflicts with --count, --bits and --crack-time"),
+            (builder().exact_bits(64).decode_bits(64), "--exact-bits conflicts with --decode-bits"),
+            (builder().count(4).template("Wwdd"), "--template conflicts with --count"),
+            (builder().template("Wwdd").acrostic("cat"), "--acrostic conflicts with"),
+            (builder().sentence(true).preset("word"), "--sentence conflicts with"),
+            (builder().charset("abc").preset("word"), "--charset conflicts with --preset and --file"),
+            (builder().from("preset:word,preset:number").preset("word"), "--from conflicts with"),
+            (builder().pool_size(1000).preset("word"), "--pool-size conflicts with"),
+            (builder().template("Wwdd").leet(Leet::new(0.5).unwrap()), "--leet conflicts with"),
+            (
+                builder().leet(Leet::new(0.5).unwrap()).random_case(RandomCase::new(0.5).unwrap()),
+                "--random-case conflicts with",
+            ),
+            (builder().template("Wwdd").mutate(1), "--mutate conflicts with"),
+            (builder().join("{1}-{2}").vertical(true), "--join conflicts with"),
+            (builder().identifier(Identifier::Uuid).preset("word"), "--uuid conflicts with --preset"),
+            (builder().api_token(true).token_prefix("pg").identifier(Identifier::Ulid), "--api-token conflicts with"),
+            (builder().license_key(true).identifier(Identifier::Uuid), "--license-key conflicts with --uuid"),
+            (builder().recovery_codes(10).preset("word"), "--recovery-codes conflicts with --preset"),
+            (builder().recovery_codes(10).number(2), "it conflicts with --number"),
+            (builder().format(Format::Csv).vertical(true), "--vertical conflicts with"),
+            (builder().split(SecretSharing::new(2, 3).unwrap()).number(2), "it conflicts with --number"),
+            (builder().split(SecretSharing::new(2, 3).unwrap()).encrypt_to("a@b"), "--encrypt-to conflicts with"),
+            (builder().format(Format::Json).pepper(true), "--pepper conflicts with"),
+            (builder().report_only(true).output("out.txt"), "--report-only cannot be combined"),
+            (builder().report_only(true).quote(Quote::Shell), "--report-only cannot be combined"),
+            (builder().report_only(true).export("PASS"), "--report-only cannot be combined"),
+            (builder().report_only(true).vertical(true), "--report-only cannot be combined"),
+            (builder().report_only(true).split(SecretSharing::new(2, 3).unwrap()), "--report-only cannot be combined"),
+            (builder().report_only(true).tty_echo(true), "--report-only cannot be combined"),
+            (builder().report_only(true).encrypt_to("a@b"), "--report-only cannot be combined"),
+            (builder().report_only(true).history("history.txt"), "--report-only cannot be combined with --history"),
+            (builder().format(Format::Csv).quote(Quote::Shell), "--quote and --export only apply to plain output"),
+            (builder().export("PASS").number(2), "it conflicts with --number"),
+            (builder().spec("spec.txt").batch(true), "--spec conflicts with --batch"),
+            (builder().derive("site").seed(1), "--derive conflicts with --seed and --number"),
+            (builder().with_username(true).vertical(true), "--with-username conflicts with"),
+        ];
+
+        for (builder, expected) in cases {
+            match error(builder) {
+                PassGenError::Usage(msg) => assert!(msg.contains(expected), "{:?} lacks {:?}", msg, expected),
+                err => panic!("{:?} instead of a usage error {:?}", err, expected),
+            }
+        }
+    }
+
+    #[test]
+    fn settings_without_the_mode_they_belong_to() {
+        let cases = [
+            (builder().group(4), "--group requires --recovery-codes or --license-key"),
+            (builder().token_prefix("pg"), "--token-prefix and --token-alphabet require --api-token"),
+            (builder().api_token(true), "--api-token requires --token-prefix"),
+            (builder().key_groups(3), "--key-groups requires --license-key"),
+            (builder().history_prune(30), "--history-prune requires --history"),
+            (builder().batch_strict(true), "--batch-strict requires --batch"),
+            (builder().numbers(false), "--no-numbers requires --vertical"),
+            (builder().also_plain(true), "--also-plain requires --split"),
+            (builder().keepass_group("web"), "require --keepass-csv"),
+            (builder().username_style(UsernameStyle::Words), "require --with-username"),
+            (builder().mutate_pool("!@#"), "--mutate-pool requires --mutate"),
+            (builder().salt("pepper"), "--salt, --master-fd and --kdf-* require --derive"),
+            (builder().allow_unicode(true), "--allow-unicode requires --charset"),
+            (builder().whitespace(Whitespace::Split), "only apply to --file and --from"),
+            (builder().pool_size(1000), "--pool-size needs --count, --bits or --crack-time"),
+            (builder().export("PASS").quote(Quote::None), "--export needs --quote shell or env"),
+        ];
+
+        for (builder, expected) in cases {
+            match error(builder) {
+                PassGenError::Usage(msg) => assert!(msg.contains(expected), "{:?} lacks {:?}", msg, expected),
+                err => panic!("{:?} instead of a usage error {:?}", err, expected),
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_values() {
+        let cases = [
+            (builder().count(0), "count must be a positive number"),
+            (builder().number(0), "number must be a positive number"),
+            (builder().jobs(0), "jobs must be a positive number"),
+            (builder().sep_set(""), "expected at least one character"),
+            (builder().preset("hex"), "invalid preset \"hex\""),
+            (builder().export("1PASS"), "invalid variable name \"1PASS\""),
+            (builder().count(MAX_TOKENS + 1), "pass --force to generate them anyway"),
+            (builder().checksum(true).separator(""), "--checksum needs a separator"),
+            (builder().charset("abc").exclude_chars("abc"), "removes every character of the pool"),
+        ];
+
+        for (builder, expected) in cases {
+            match error(builder) {
+                PassGenError::Usage(msg) => assert!(msg.contains(expected), "{:?} lacks {:?}", msg, expected),
+                err => panic!("{:?} instead of a usage error {:?}", err, expected),
+            }
+        }
+
+        // far beyond the limit is fine once forced
+        assert!(builder().preset("number").count(MAX_TOKENS + 1).force(true).build().is_ok());
+    }
+
+    #[test]
+    fn unsatisfiable_settings() {
+        let cases = [
+            (builder().bits(MAX_BITS * 2.0), "exceeds the limit of 65536 bits"),
+            (builder().preset("number").max_length(3), "it cannot fit in 3"),
+            (builder().preset("number").length(0), "it cannot fit --length 0"),
+            (builder().preset("number").mutate(100), "--mutate 100 exceeds"),
+            (builder().preset("number").require(ClassSet::default().with(CharClass::Upper)), "cannot be required"),
+            (builder().charset("0-9").start_with_letter(true), "no password can start with a letter"),
+            (builder().charset("ab-").separator("-").checksum(true), "contains the separator"),
+        ];
+
+        for (builder, expected) in cases {
+            match error(builder) {
+                PassGenError::Unsatisfiable(msg) => assert!(msg.contains(expected), "{:?} lacks {:?}", msg, expected),
+                err => panic!("{:?} instead of an unsatisfiable error {:?}", err, expected),
+            }
+        }
+    }
+
+    #[test]
+    fn predictable_pools() {
+        for charset in ["a", "aaaa"] {
+            match error(builder().charset(charset)) {
+                PassGenError::InvalidData(msg) => assert!(msg.contains("token pool has 1 tokens"), "{}", msg),
+                err => panic!("{:?} instead of an invalid data error", err),
+            }
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use crate::policy::{strip_comment, Value};
+use crate::{preset, PassGenError};
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+/* -------------------- *
+ *        LAYERS        *
+ * -------------------- */
+/// Where an effective setting came from, later layers win.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Layer {
+    /// The preset's own default.
+    Default,
+    /// A `[preset.<name>]` section of the config file.
+    Config,
+    /// A command line flag.
+    Flag,
+}
+
+impl Layer {
+    pub fn name(self) -> &'static str {
+        match self {
+            Layer::Default => "default",
+            Layer::Config => "config file",
+            Layer::Flag => "flag",
+        }
+    }
+}
+
+/// Picks the value of the highest layer that has one.
+pub(crate) fn layer<T>(default: T, config: Option<T>, flag: Option<T>) -> (T, Layer) {
+    match (config, flag) {
+        (_, Some(flag)) => (flag, Layer::Flag),
+        (Some(config), None) => (config, Layer::Config),
+        (None, None) => (default, Layer::Default),
+    }
+}
+
+
+/* -------------------- *
+ *     CONFIG FILE      *
+ * -------------------- */
+/// Keys of a `[preset.<name>]` section, with what they set.
+pub const CONFIG_KEYS: &[(&str, &str)] = &[
+    ("count", "number of tokens per password, like --count"),
+    ("sep", "separator between tokens, a quoted string, like --sep"),
+];
+
+/// Settings of a `[preset.<name>]` section.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct PresetSection {
+    pub count: Option<u32>,
+    pub sep: Option<String>,
+}
+
+/// The config file, a toml subset with one section per preset.
+///
+/// ```toml
+/// [preset.word]
+/// count = 5
+/// sep = "-"
+///
+/// [preset.ascii]
+/// count = 24
+/// ```
+///
+/// Sections of presets that are not built in are ignored with a warning, they may belong to
+/// a build with other features.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ConfigFile {
+    presets: Vec<(String, PresetSection)>,
+    warnings: Vec<String>,
+}
+
+impl ConfigFile {
+    pub fn parse(text: &str) -> Result<ConfigFile, PassGenError> {
+        let mut file = ConfigFile::default();
+        let mut seen = Vec::new();
+        // index of the current section, None inside a section that is ignored
+        let mut current: Option<Option<usize>> = None;
+
+        for (nr, line) in text.lines().enumerate() {
+            let invalid = |msg: &str| PassGenError::InvalidData(format!("invalid config on line {}: {}", nr + 1, msg));
+
+            let line = strip_comment(line).trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+                let name = match table.trim().strip_prefix("preset.") {
+                    Some(name) => name.trim().trim_matches('"'),
+                    None => return Err(invalid("only [preset.<name>] sections are supported")),
+                };
+
+                if seen.contains(&name) {
+                    return Err(invalid(&format!("duplicate section for preset {:?}", name)));
+                }
+
+                seen.push(name);
+
+                current = Some(match preset(name) {
+                    Some(_) => {
+                        file.presets.push((name.to_string(), PresetSection::default()));
+                        Some(file.presets.len() - 1)
+                    },
+                    None => {
+                        file.warnings.push(format!("line {}: unknown preset {:?}, section ignored", nr + 1, name));
+                        None
+                    },
+                });
+
+                continue;
+            }
+
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => return Err(invalid("expected key = value")),
+            };
+
+            let section = match current {
+                Some(Some(idx)) => &mut file.presets[idx].1,
+                Some(None) => continue,
+                None => return Err(invalid(&format!("{:?} must be inside a [preset.<name>] section", key))),
+            };
+
+            let duplicate = match key {
+                "count" => section.count.is_some(),
+                "sep" => section.sep.is_some(),
+                _ => false,
+            };
+
+            if duplicate {
+                return Err(invalid(&format!("duplicate key {:?}", key)));
+            }
+
+            match (key, Value::parse(value)) {
+                ("count", Some(Value::Int(count))) if (1..=u32::MAX as usize).contains(&count) => {
+                    section.count = Some(count as u32);
+                },
+                ("sep", Some(Value::Str(sep))) => section.sep = Some(sep),
+                ("count", _) => return Err(invalid("count must be a positive number")),
+                ("sep", _) => return Err(invalid("sep must be a string")),
+                _ => {
+                    let keys: Vec<&str> = CONFIG_KEYS.iter().map(|(key, _)| *key).collect();
+                    return Err(invalid(&format!("unknown key {:?}, expected one of {}", key, keys.join(", "))));
+                },
+            }
+        }
+
+        Ok(file)
+    }
+
+    /// Reads the file at `path`, a missing file is an empty config.
+    pub fn from_path(path: &Path) -> Result<ConfigFile, PassGenError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(source) if source.kind() == ErrorKind::NotFound => Ok(ConfigFile::default()),
+            Err(source) => Err(PassGenError::Io {
+                action: "reading config file",
+                path: Some(path.display().to_string()),
+                source,
+            }),
+        }
+    }
+
+    pub fn preset(&self, name: &str) -> Option<&PresetSection> {
+        self.presets.iter().find(|(preset, _)| preset == name).map(|(_, section)| section)
+    }
+
+    /// Problems that did not stop the file from loading.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+}
+
+/// Where the salt and the config file live, `None` when no home directory is known.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn config_dir() -> Option<PathBuf> {
+    #[cfg(windows)]
+    let home = std::env::var_os("APPDATA").map(|appdata| Path::new(&appdata).join("pass-gen"));
+
+    #[cfg(not(windows))]
+    let home = std::env::var_os("HOME").map(|home| Path::new(&home).join(".config/pass-gen"));
+
+    match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(config) => Some(Path::new(&config).join("pass-gen")),
+        None => home,
+    }
+}
+
+/// WebAssembly has no environment to find a home directory in.
+#[cfg(target_arch = "wasm32")]
+pub fn config_dir() -> Option<PathBuf> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_last_layer_wins() {
+        assert_eq!(layer(6, None, None), (6, Layer::Default));
+        assert_eq!(layer(6, Some(5), None), (5, Layer::Config));
+        assert_eq!(layer(6, None, Some(4)), (4, Layer::Flag));
+        assert_eq!(layer(6, Some(5), Some(4)), (4, Layer::Flag));
+
+        // a layer that repeats the default still counts as set
+        assert_eq!(layer("-", Some("-"), None), ("-", Layer::Config));
+    }
+
+    #[test]
+    fn presets_are_parsed_and_checked() {
+        let text = "[preset.word]\ncount = 5\nsep = \"-\"\n\n[preset.ascii] # comment\ncount = 24";
+        let file = ConfigFile::parse(text).unwrap();
+
+        assert_eq!(file.preset("word"), Some(&PresetSection { count: Some(5), sep: Some(String::from("-")) }));
+        assert_eq!(file.preset("ascii").and_then(|section| section.sep.as_deref()), None);
+        assert_eq!(file.preset("number"), None);
+
+        let file = ConfigFile::parse("[preset.klingon]\ncount = 3").unwrap();
+
+        assert_eq!(file.preset("klingon"), None);
+        assert!(file.warnings()[0].contains("\"klingon\""));
+
+        let invalid = ["count = 5", "[other]", "[preset.word]\ncount = 0", "[preset.word]\nlength = 5"];
+        let duplicate = ["[preset.word]\n[preset.word]", "[preset.word]\nsep = \"\"\nsep = \"-\""];
+
+        for text in invalid.iter().chain(&duplicate) {
+            assert!(ConfigFile::parse(text).is_err(), "{}", text);
+        }
+    }
+}
@@ -0,0 +1,407 @@
+pub static TOKEN_TEXT: &str = concat!(
+    "agile",
+    "airy",
+    "amber",
+    "ancient",
+    "angry",
+    "arctic",
+    "arid",
+    "awkward",
+    "azure",
+    "balmy",
+    "bashful",
+    "bitter",
+    "blazing",
+    "blunt",
+    "bold",
+    "bouncy",
+    "brave",
+    "breezy",
+    "bright",
+    "brisk",
+    "brittle",
+    "broad",
+    "bronze",
+    "bubbly",
+    "bumpy",
+    "busy",
+    "calm",
+    "candid",
+    "careful",
+    "cheerful",
+    "chilly",
+    "chubby",
+    "clever",
+    "cloudy",
+    "clumsy",
+    "cobalt",
+    "colossal",
+    "cosmic",
+    "cozy",
+    "crafty",
+    "cranky",
+    "creaky",
+    "crimson",
+    "crisp",
+    "crooked",
+    "cruel",
+    "cunning",
+    "curious",
+    "curly",
+    "dainty",
+    "damp",
+    "dapper",
+    "daring",
+    "dazzling",
+    "deep",
+    "distant",
+    "dizzy",
+    "dreamy",
+    "drowsy",
+    "dull",
+    "dusty",
+    "eager",
+    "earnest",
+    "electric",
+    "elegant",
+    "empty",
+    "endless",
+    "exotic",
+    "faded",
+    "faint",
+    "famous",
+    "fancy",
+    "fearless",
+    "feisty",
+    "feral",
+    "fickle",
+    "fierce",
+    "fiery",
+    "filthy",
+    "fizzy",
+    "flaky",
+    "flat",
+    "floppy",
+    "fluffy",
+    "foggy",
+    "fond",
+    "fragile",
+    "frantic",
+    "fresh",
+    "friendly",
+    "frosty",
+    "frozen",
+    "funny",
+    "fuzzy",
+    "gaudy",
+    "gentle",
+    "giant",
+    "giddy",
+    "gifted",
+    "gigantic",
+    "glad",
+    "gleaming",
+    "gloomy",
+    "glossy",
+    "glowing",
+    "golden",
+    "graceful",
+    "grand",
+    "grassy",
+    "greasy",
+    "great",
+    "greedy",
+    "green",
+    "grim",
+    "gritty",
+    "groovy",
+    "grumpy",
+    "gusty",
+    "hairy",
+    "handsome",
+    "happy",
+    "hardy",
+    "hasty",
+    "hazy",
+    "heavy",
+    "hefty",
+    "hidden",
+    "hollow",
+    "honest",
+    "hopeful",
+    "huge",
+    "hulking",
+    "humble",
+    "humid",
+    "hungry",
+    "husky",
+    "icy",
+    "idle",
+    "ivory",
+    "jagged",
+    "jaunty",
+    "jolly",
+    "jovial",
+    "joyful",
+    "jumbo",
+    "keen",
+    "lanky",
+    "large",
+    "lavish",
+    "lazy",
+    "lean",
+    "little",
+    "lively",
+    "lofty",
+    "lonely",
+    "loud",
+    "lovely",
+    "loyal",
+    "lucid",
+    "lucky",
+    "lumpy",
+    "lunar",
+    "lush",
+    "magenta",
+    "majestic",
+    "meek",
+    "mellow",
+    "merry",
+    "mighty",
+    "misty",
+    "modern",
+    "modest",
+    "moody",
+    "mossy",
+    "muddy",
+    "murky",
+    "musty",
+    "mystic",
+    "narrow",
+    "naughty",
+    "neat",
+    "nervous",
+    "nifty",
+    "nimble",
+    "noble",
+    "noisy",
+    "nutty",
+    "oblong",
+    "odd",
+    "opaque",
+    "orange",
+    "ornate",
+    "pale",
+    "pastel",
+    "patient",
+    "peaceful",
+    "peppy",
+    "perfect",
+    "perky",
+    "petite",
+    "plucky",
+    "plump",
+    "plush",
+    "polar",
+    "polite",
+    "portly",
+    "prickly",
+    "prim",
+    "proud",
+    "puffy",
+    "purple",
+    "quaint",
+    "quick",
+    "quiet",
+    "quirky",
+    "radiant",
+    "ragged",
+    "rainy",
+    "rapid",
+    "rare",
+    "restless",
+    "rich",
+    "rickety",
+    "rigid",
+    "ripe",
+    "robust",
+    "rocky",
+    "rosy",
+    "rough",
+    "round",
+    "rowdy",
+    "royal",
+    "rubbery",
+    "rugged",
+    "rustic",
+    "rusty",
+    "sad",
+    "salty",
+    "sandy",
+    "sassy",
+    "savage",
+    "scaly",
+    "scarlet",
+    "scrawny",
+    "secret",
+    "serene",
+    "shabby",
+    "shaggy",
+    "sharp",
+    "shiny",
+    "short",
+    "shrewd",
+    "shy",
+    "silent",
+    "silky",
+    "silly",
+    "silver",
+    "simple",
+    "skinny",
+    "sleek",
+    "sleepless",
+    "sleepy",
+    "slender",
+    "slim",
+    "slimy",
+    "slow",
+    "sly",
+    "small",
+    "smart",
+    "smoky",
+    "smooth",
+    "snowy",
+    "snug",
+    "soaring",
+    "soft",
+    "soggy",
+    "solar",
+    "solemn",
+    "somber",
+    "sour",
+    "sparkling",
+    "speedy",
+    "spicy",
+    "spiky",
+    "spotted",
+    "spry",
+    "squeaky",
+    "stale",
+    "stark",
+    "starry",
+    "steady",
+    "stealthy",
+    "steep",
+    "sticky",
+    "stiff",
+    "stony",
+    "stormy",
+    "stout",
+    "strange",
+    "striped",
+    "strong",
+    "stubborn",
+    "sturdy",
+    "sublime",
+    "sudden",
+    "sulky",
+    "sunny",
+    "superb",
+    "sweet",
+    "swift",
+    "tall",
+    "tame",
+    "tangy",
+    "tawny",
+    "tender",
+    "tense",
+    "tepid",
+    "thirsty",
+    "thorny",
+    "tidy",
+    "timid",
+    "tiny",
+    "tired",
+    "toasty",
+    "tough",
+    "towering",
+    "tranquil",
+    "tricky",
+    "trusty",
+    "turquoise",
+    "twisted",
+    "ugly",
+    "upbeat",
+    "urban",
+    "vain",
+    "valiant",
+    "vast",
+    "velvet",
+    "vibrant",
+    "vintage",
+    "violet",
+    "vivid",
+    "wacky",
+    "warm",
+    "wary",
+    "wavy",
+    "wealthy",
+    "weary",
+    "whimsical",
+    "wicked",
+    "wide",
+    "wild",
+    "windy",
+    "wiry",
+    "wise",
+    "wistful",
+    "witty",
+    "wobbly",
+    "wooden",
+    "woolly",
+    "wrinkled",
+    "yellow",
+    "young",
+    "zany",
+    "zealous",
+    "zesty",
+);
+pub static TOKEN_OFFSETS: [u32; 367] = [
+    0, 5, 9, 14, 21, 26, 32, 36, 43, 48,
+    53, 60, 66, 73, 78, 82, 88, 93, 99, 105,
+    110, 117, 122, 128, 134, 139, 143, 147, 153, 160,
+    168, 174, 180, 186, 192, 198, 204, 212, 218, 222,
+    228, 234, 240, 247, 252, 259, 264, 271, 278, 283,
+    289, 293, 299, 305, 313, 317, 324, 329, 335, 341,
+    345, 350, 355, 362, 370, 377, 382, 389, 395, 400,
+    405, 411, 416, 424, 430, 435, 441, 447, 452, 458,
+    463, 468, 472, 478, 484, 489, 493, 500, 507, 512,
+    520, 526, 532, 537, 542, 547, 553, 558, 563, 569,
+    577, 581, 589, 595, 601, 608, 614, 622, 627, 633,
+    639, 644, 650, 655, 659, 665, 671, 677, 682, 687,
+    695, 700, 705, 710, 714, 719, 724, 730, 736, 742,
+    749, 753, 760, 766, 771, 777, 782, 785, 789, 794,
+    800, 806, 811, 817, 823, 828, 832, 837, 842, 848,
+    852, 856, 862, 868, 873, 879, 883, 889, 894, 899,
+    904, 909, 914, 918, 925, 933, 937, 943, 948, 954,
+    959, 965, 971, 976, 981, 986, 991, 996, 1002, 1008,
+    1015, 1019, 1026, 1031, 1037, 1042, 1047, 1052, 1058, 1061,
+    1067, 1073, 1079, 1083, 1089, 1096, 1104, 1109, 1116, 1121,
+    1127, 1133, 1138, 1143, 1148, 1154, 1160, 1167, 1171, 1176,
+    1181, 1187, 1193, 1198, 1203, 1209, 1216, 1222, 1227, 1232,
+    1236, 1244, 1248, 1255, 1260, 1264, 1270, 1275, 1279, 1284,
+    1289, 1294, 1299, 1306, 1312, 1318, 1323, 1326, 1331, 1336,
+    1341, 1347, 1352, 1359, 1366, 1372, 1378, 1384, 1390, 1395,
+    1400, 1405, 1411, 1414, 1420, 1425, 1430, 1436, 1442, 1448,
+    1453, 1462, 1468, 1475, 1479, 1484, 1488, 1491, 1496, 1501,
+    1506, 1512, 1517, 1521, 1528, 1532, 1537, 1542, 1548, 1554,
+    1558, 1567, 1573, 1578, 1583, 1590, 1594, 1601, 1606, 1611,
+    1617, 1623, 1631, 1636, 1642, 1647, 1652, 1658, 1663, 1670,
+    1677, 1683, 1691, 1697, 1704, 1710, 1715, 1720, 1726, 1731,
+    1736, 1740, 1744, 1749, 1754, 1760, 1765, 1770, 1777, 1783,
+    1787, 1792, 1796, 1801, 1807, 1812, 1820, 1828, 1834, 1840,
+    1849, 1856, 1860, 1866, 1871, 1875, 1882, 1886, 1892, 1899,
+    1906, 1912, 1917, 1922, 1926, 1930, 1934, 1941, 1946, 1955,
+    1961, 1965, 1969, 1974, 1978, 1982, 1989, 1994, 2000, 2006,
+    2012, 2020, 2026, 2031, 2035, 2042, 2047,
+];
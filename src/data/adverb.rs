@@ -0,0 +1,199 @@
+pub static TOKEN_TEXT: &str = concat!(
+    "abruptly",
+    "absently",
+    "always",
+    "angrily",
+    "anxiously",
+    "awkwardly",
+    "badly",
+    "bitterly",
+    "blindly",
+    "blissfully",
+    "boastfully",
+    "boldly",
+    "bravely",
+    "breathlessly",
+    "briefly",
+    "brightly",
+    "briskly",
+    "busily",
+    "calmly",
+    "carefully",
+    "casually",
+    "cautiously",
+    "cheaply",
+    "cheerfully",
+    "cleverly",
+    "closely",
+    "clumsily",
+    "coolly",
+    "correctly",
+    "coyly",
+    "crossly",
+    "cruelly",
+    "curiously",
+    "daintily",
+    "daringly",
+    "deeply",
+    "defiantly",
+    "deliberately",
+    "delightfully",
+    "dimly",
+    "doubtfully",
+    "dreamily",
+    "eagerly",
+    "easily",
+    "elegantly",
+    "energetically",
+    "enormously",
+    "evenly",
+    "fairly",
+    "faithfully",
+    "famously",
+    "fervently",
+    "fiercely",
+    "fondly",
+    "foolishly",
+    "frankly",
+    "frantically",
+    "freely",
+    "furiously",
+    "generously",
+    "gently",
+    "gladly",
+    "gleefully",
+    "gloomily",
+    "gracefully",
+    "greedily",
+    "grimly",
+    "happily",
+    "hastily",
+    "heavily",
+    "helpfully",
+    "honestly",
+    "hopelessly",
+    "hungrily",
+    "innocently",
+    "intensely",
+    "jealously",
+    "jovially",
+    "joyfully",
+    "keenly",
+    "kindly",
+    "lazily",
+    "lightly",
+    "loudly",
+    "lovingly",
+    "loyally",
+    "madly",
+    "meekly",
+    "merrily",
+    "miserably",
+    "mockingly",
+    "mysteriously",
+    "neatly",
+    "nervously",
+    "never",
+    "nimbly",
+    "noisily",
+    "obediently",
+    "oddly",
+    "often",
+    "openly",
+    "painfully",
+    "patiently",
+    "perfectly",
+    "playfully",
+    "politely",
+    "poorly",
+    "promptly",
+    "proudly",
+    "quickly",
+    "quietly",
+    "quirkily",
+    "rapidly",
+    "rarely",
+    "rashly",
+    "readily",
+    "recklessly",
+    "restfully",
+    "righteously",
+    "rigidly",
+    "roughly",
+    "rudely",
+    "sadly",
+    "safely",
+    "secretly",
+    "seldom",
+    "sharply",
+    "sheepishly",
+    "shrilly",
+    "shyly",
+    "silently",
+    "sleepily",
+    "sloppily",
+    "slowly",
+    "smoothly",
+    "sneakily",
+    "snugly",
+    "softly",
+    "solemnly",
+    "sometimes",
+    "soon",
+    "sparingly",
+    "speedily",
+    "steadily",
+    "stealthily",
+    "sternly",
+    "strangely",
+    "stubbornly",
+    "suddenly",
+    "sweetly",
+    "swiftly",
+    "tenderly",
+    "tensely",
+    "thankfully",
+    "thoughtfully",
+    "tightly",
+    "timidly",
+    "tirelessly",
+    "triumphantly",
+    "truthfully",
+    "twice",
+    "unevenly",
+    "urgently",
+    "vaguely",
+    "valiantly",
+    "victoriously",
+    "violently",
+    "vivaciously",
+    "warmly",
+    "wearily",
+    "wildly",
+    "willfully",
+    "wisely",
+    "wistfully",
+    "wrongly",
+    "zealously",
+    "zestfully",
+);
+pub static TOKEN_OFFSETS: [u32; 178] = [
+    0, 8, 16, 22, 29, 38, 47, 52, 60, 67,
+    77, 87, 93, 100, 112, 119, 127, 134, 140, 146,
+    155, 163, 173, 180, 190, 198, 205, 213, 219, 228,
+    233, 240, 247, 256, 264, 272, 278, 287, 299, 311,
+    316, 326, 334, 341, 347, 356, 369, 379, 385, 391,
+    401, 409, 418, 426, 432, 441, 448, 459, 465, 474,
+    484, 490, 496, 505, 513, 523, 531, 537, 544, 551,
+    558, 567, 575, 585, 593, 603, 612, 621, 629, 637,
+    643, 649, 655, 662, 668, 676, 683, 688, 694, 701,
+    710, 719, 731, 737, 746, 751, 757, 764, 774, 779,
+    784, 790, 799, 808, 817, 826, 834, 840, 848, 855,
+    862, 869, 877, 884, 890, 896, 903, 913, 922, 933,
+    940, 947, 953, 958, 964, 972, 978, 985, 995, 1002,
+    1007, 1015, 1023, 1031, 1037, 1045, 1053, 1059, 1065, 1073,
+    1082, 1086, 1095, 1103, 1111, 1121, 1128, 1137, 1147, 1155,
+    1162, 1169, 1177, 1184, 1194, 1206, 1213, 1220, 1230, 1242,
+    1252, 1257, 1265, 1273, 1280, 1289, 1301, 1310, 1321, 1327,
+    1334, 1340, 1349, 1355, 1364, 1371, 1380, 1389,
+];
@@ -0,0 +1,329 @@
+// words that read aloud or spell so alike that a passphrase is easily taken down wrong,
+// the first word of a set in a pool is the one kept
+pub static CONFUSABLE_SETS: &[&[&str]] = &[
+    // homophones
+    &["to", "too", "two"],
+    &["there", "their", "they're"],
+    &["your", "you're", "yore"],
+    &["its", "it's"],
+    &["right", "write", "rite", "wright"],
+    &["here", "hear"],
+    &["know", "no"],
+    &["knew", "new"],
+    &["knight", "night"],
+    &["knot", "not"],
+    &["one", "won"],
+    &["four", "for", "fore"],
+    &["eight", "ate"],
+    &["by", "buy", "bye"],
+    &["sea", "see"],
+    &["son", "sun"],
+    &["road", "rode", "rowed"],
+    &["made", "maid"],
+    &["mail", "male"],
+    &["meat", "meet", "mete"],
+    &["peace", "piece"],
+    &["plain", "plane"],
+    &["principal", "principle"],
+    &["rain", "reign", "rein"],
+    &["scene", "seen"],
+    &["sole", "soul"],
+    &["some", "sum"],
+    &["stair", "stare"],
+    &["stake", "steak"],
+    &["stationary", "stationery"],
+    &["tail", "tale"],
+    &["threw", "through"],
+    &["waist", "waste"],
+    &["wait", "weight"],
+    &["way", "weigh", "whey"],
+    &["weak", "week"],
+    &["wear", "where", "ware"],
+    &["weather", "whether"],
+    &["which", "witch"],
+    &["whole", "hole"],
+    &["wood", "would"],
+    &["allowed", "aloud"],
+    &["ant", "aunt"],
+    &["bare", "bear"],
+    &["be", "bee"],
+    &["berry", "bury"],
+    &["blew", "blue"],
+    &["board", "bored"],
+    &["brake", "break"],
+    &["bread", "bred"],
+    &["buoy", "boy"],
+    &["ceiling", "sealing"],
+    &["cell", "sell"],
+    &["cent", "scent", "sent"],
+    &["cereal", "serial"],
+    &["cheap", "cheep"],
+    &["chews", "choose"],
+    &["coarse", "course"],
+    &["colonel", "kernel"],
+    &["council", "counsel"],
+    &["creak", "creek"],
+    &["dear", "deer"],
+    &["dew", "due", "do"],
+    &["die", "dye"],
+    &["doe", "dough"],
+    &["dual", "duel"],
+    &["ewe", "you", "yew"],
+    &["fair", "fare"],
+    &["feat", "feet"],
+    &["find", "fined"],
+    &["flea", "flee"],
+    &["flew", "flu", "flue"],
+    &["flour", "flower"],
+    &["foul", "fowl"],
+    &["gait", "gate"],
+    &["genes", "jeans"],
+    &["grate", "great"],
+    &["groan", "grown"],
+    &["guessed", "guest"],
+    &["hair", "hare"],
+    &["hall", "haul"],
+    &["heal", "heel", "he'll"],
+    &["heard", "herd"],
+    &["hi", "high"],
+    &["him", "hymn"],
+    &["hoarse", "horse"],
+    &["hour", "our"],
+    &["idle", "idol"],
+    &["in", "inn"],
+    &["jam", "jamb"],
+    &["lead", "led"],
+    &["leak", "leek"],
+    &["lessen", "lesson"],
+    &["loan", "lone"],
+    &["main", "mane"],
+    &["maize", "maze"],
+    &["marshal", "martial"],
+    &["medal", "meddle", "metal", "mettle"],
+    &["might", "mite"],
+    &["mind", "mined"],
+    &["miner", "minor"],
+    &["missed", "mist"],
+    &["moose", "mousse"],
+    &["morning", "mourning"],
+    &["muscle", "mussel"],
+    &["naval", "navel"],
+    &["none", "nun"],
+    &["oar", "or", "ore"],
+    &["overdo", "overdue"],
+    &["pail", "pale"],
+    &["pain", "pane"],
+    &["pair", "pare", "pear"],
+    &["passed", "past"],
+    &["pause", "paws"],
+    &["peak", "peek", "pique"],
+    &["peal", "peel"],
+    &["pedal", "peddle"],
+    &["peer", "pier"],
+    &["plum", "plumb"],
+    &["pole", "poll"],
+    &["pray", "prey"],
+    &["presence", "presents"],
+    &["profit", "prophet"],
+    &["rap", "wrap"],
+    &["read", "reed"],
+    &["real", "reel"],
+    &["ring", "wring"],
+    &["role", "roll"],
+    &["root", "route"],
+    &["rose", "rows"],
+    &["rung", "wrung"],
+    &["sail", "sale"],
+    &["seam", "seem"],
+    &["sew", "so", "sow"],
+    &["shoe", "shoo"],
+    &["sight", "site", "cite"],
+    &["slay", "sleigh"],
+    &["soar", "sore"],
+    &["steal", "steel"],
+    &["suite", "sweet"],
+    &["tacks", "tax"],
+    &["taught", "taut"],
+    &["tea", "tee"],
+    &["team", "teem"],
+    &["tide", "tied"],
+    &["toe", "tow"],
+    &["vain", "vane", "vein"],
+    &["vary", "very"],
+    &["wade", "weighed"],
+    &["wail", "whale"],
+    &["war", "wore"],
+    &["warn", "worn"],
+    &["wave", "waive"],
+    &["whine", "wine"],
+    &["holey", "holy", "wholly"],
+    &["aisle", "isle"],
+    &["altar", "alter"],
+    &["assent", "ascent"],
+    &["bail", "bale"],
+    &["band", "banned"],
+    &["baron", "barren"],
+    &["base", "bass"],
+    &["beach", "beech"],
+    &["beat", "beet"],
+    &["bolder", "boulder"],
+    &["bough", "bow"],
+    &["boarder", "border"],
+    &["bridal", "bridle"],
+    &["broach", "brooch"],
+    &["callous", "callus"],
+    &["canvas", "canvass"],
+    &["carat", "carrot", "caret"],
+    &["cash", "cache"],
+    &["chord", "cord"],
+    &["claws", "clause"],
+    &["cue", "queue"],
+    &["currant", "current"],
+    &["days", "daze"],
+    &["discreet", "discrete"],
+    &["faint", "feint"],
+    &["fir", "fur"],
+    &["flair", "flare"],
+    &["forth", "fourth"],
+    &["frees", "freeze", "frieze"],
+    &["gilt", "guilt"],
+    &["gorilla", "guerrilla"],
+    &["grisly", "grizzly"],
+    &["hangar", "hanger"],
+    &["heroin", "heroine"],
+    &["hoard", "horde"],
+    &["lade", "laid"],
+    &["lain", "lane"],
+    &["lapse", "laps"],
+    &["liar", "lyre"],
+    &["links", "lynx"],
+    &["lumbar", "lumber"],
+    &["manner", "manor"],
+    &["mantel", "mantle"],
+    &["mince", "mints"],
+    &["moan", "mown"],
+    &["mode", "mowed"],
+    &["muse", "mews"],
+    &["nay", "neigh"],
+    &["nose", "knows"],
+    &["ode", "owed"],
+    &["packed", "pact"],
+    &["patience", "patients"],
+    &["pearl", "purl"],
+    &["plaice", "place"],
+    &["pleas", "please"],
+    &["pore", "pour", "poor"],
+    &["pros", "prose"],
+    &["quarts", "quartz"],
+    &["rapped", "rapt", "wrapped"],
+    &["raise", "rays", "raze"],
+    &["reek", "wreak"],
+    &["rest", "wrest"],
+    &["retch", "wretch"],
+    &["rigger", "rigor"],
+    &["rote", "wrote"],
+    &["rude", "rued"],
+    &["rye", "wry"],
+    &["sac", "sack"],
+    &["sauce", "source"],
+    &["scull", "skull"],
+    &["seas", "sees", "seize"],
+    &["sects", "sex"],
+    &["serf", "surf"],
+    &["shear", "sheer"],
+    &["shone", "shown"],
+    &["sighed", "side"],
+    &["sloe", "slow"],
+    &["soared", "sword"],
+    &["staid", "stayed"],
+    &["stile", "style"],
+    &["storey", "story"],
+    &["straight", "strait"],
+    &["sundae", "sunday"],
+    &["symbol", "cymbal"],
+    &["tare", "tear"],
+    &["tern", "turn"],
+    &["throne", "thrown"],
+    &["thyme", "time"],
+    &["toad", "towed"],
+    &["troop", "troupe"],
+    &["vial", "vile"],
+    &["vice", "vise"],
+    &["weave", "we've"],
+    &["wed", "we'd"],
+    // easily confused spellings
+    &["accept", "except"],
+    &["affect", "effect"],
+    &["advice", "advise"],
+    &["lose", "loose"],
+    &["than", "then"],
+    &["quiet", "quite"],
+    &["breath", "breathe"],
+    &["loath", "loathe"],
+    &["cloth", "clothe"],
+    &["later", "latter"],
+    &["personal", "personnel"],
+    &["device", "devise"],
+    &["conscience", "conscious"],
+    &["emigrate", "immigrate"],
+    &["eminent", "imminent"],
+    &["ensure", "insure"],
+    &["precede", "proceed"],
+    &["adverse", "averse"],
+    &["allusion", "illusion"],
+    &["appraise", "apprise"],
+    &["envelop", "envelope"],
+    &["farther", "further"],
+    &["morale", "moral"],
+    &["dinner", "diner"],
+    &["angel", "angle"],
+    &["bazaar", "bizarre"],
+    &["casual", "causal"],
+    &["costume", "custom"],
+    &["decent", "descent", "dissent"],
+    &["desert", "dessert"],
+    &["complement", "compliment"],
+    &["elude", "allude"],
+    &["expand", "expend"],
+    &["formally", "formerly"],
+    &["human", "humane"],
+    &["lightening", "lightning"],
+    &["marital", "martial"],
+    &["prescribe", "proscribe"],
+    &["recent", "resent"],
+    &["respectfully", "respectively"],
+    &["statue", "stature", "statute"],
+    &["through", "thorough", "though"],
+    &["track", "tract"],
+    &["wander", "wonder"],
+    &["wreath", "wreathe"],
+    &["elicit", "illicit"],
+    &["cooperation", "corporation"],
+    &["accent", "ascent"],
+    &["addition", "edition"],
+    &["bellow", "below"],
+    &["carton", "cartoon"],
+    &["collision", "collusion"],
+    &["comma", "coma"],
+    &["dairy", "diary"],
+    &["deference", "difference"],
+    &["exercise", "exorcise"],
+    &["finally", "finely"],
+    &["hardy", "hearty"],
+    &["incite", "insight"],
+    &["lay", "lie"],
+    &["loyal", "royal"],
+    &["medal", "meddle"],
+    &["pasture", "pastor"],
+    &["perspective", "prospective"],
+    &["picture", "pitcher"],
+    &["pigeon", "pidgin"],
+    &["quay", "key"],
+    &["sculptor", "sculpture"],
+    &["sense", "since"],
+    &["sink", "zinc"],
+    &["tenet", "tenant"],
+    &["vocation", "vacation"],
+    &["weary", "wary"],
+    &["were", "where", "wear"],
+];
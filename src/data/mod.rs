@@ -1,3 +1,18 @@
 pub mod ascii;
+pub mod base58;
+pub mod grapheme;
 pub mod number;
 pub mod word;
+pub mod zbase32;
+
+#[cfg(feature = "sentence")]
+pub mod adjective;
+#[cfg(feature = "sentence")]
+pub mod adverb;
+#[cfg(feature = "sentence")]
+pub mod noun;
+#[cfg(feature = "sentence")]
+pub mod verb;
+
+#[cfg(feature = "confusable")]
+pub mod confusable;
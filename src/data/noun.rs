@@ -0,0 +1,648 @@
+pub static TOKEN_TEXT: &str = concat!(
+    "accordion",
+    "acorn",
+    "airship",
+    "alligator",
+    "almond",
+    "anchor",
+    "ant",
+    "anvil",
+    "apple",
+    "apricot",
+    "apron",
+    "armadillo",
+    "arrow",
+    "asteroid",
+    "attic",
+    "avalanche",
+    "axe",
+    "backpack",
+    "badger",
+    "bagel",
+    "balloon",
+    "bamboo",
+    "banana",
+    "bandana",
+    "banjo",
+    "barge",
+    "barn",
+    "barrel",
+    "basket",
+    "bat",
+    "bathtub",
+    "battery",
+    "bay",
+    "beacon",
+    "bead",
+    "bean",
+    "bear",
+    "beard",
+    "beaver",
+    "beetle",
+    "bell",
+    "bench",
+    "berry",
+    "bicycle",
+    "biscuit",
+    "bison",
+    "blanket",
+    "blender",
+    "blimp",
+    "blizzard",
+    "blossom",
+    "blueberry",
+    "boat",
+    "bobcat",
+    "bonfire",
+    "bookcase",
+    "boot",
+    "bottle",
+    "boulder",
+    "bouquet",
+    "bowl",
+    "box",
+    "bracelet",
+    "brick",
+    "bridge",
+    "broccoli",
+    "brook",
+    "broom",
+    "bubble",
+    "bucket",
+    "buffalo",
+    "bugle",
+    "bull",
+    "bumblebee",
+    "bunny",
+    "burrito",
+    "bus",
+    "bush",
+    "butterfly",
+    "button",
+    "cabbage",
+    "cabin",
+    "cable",
+    "cactus",
+    "cake",
+    "calculator",
+    "camel",
+    "camera",
+    "camp",
+    "canal",
+    "candle",
+    "cannon",
+    "canoe",
+    "canyon",
+    "cape",
+    "car",
+    "card",
+    "carpet",
+    "carrot",
+    "cart",
+    "castle",
+    "cat",
+    "caterpillar",
+    "cathedral",
+    "cauldron",
+    "cave",
+    "cedar",
+    "cello",
+    "chair",
+    "chalk",
+    "chariot",
+    "cheese",
+    "cheetah",
+    "cherry",
+    "chestnut",
+    "chicken",
+    "chimney",
+    "chipmunk",
+    "chocolate",
+    "cinnamon",
+    "circus",
+    "city",
+    "clarinet",
+    "cliff",
+    "clock",
+    "cloud",
+    "clover",
+    "cobra",
+    "cocoa",
+    "coconut",
+    "coffee",
+    "coin",
+    "collar",
+    "comet",
+    "compass",
+    "cone",
+    "cookie",
+    "coral",
+    "cork",
+    "corn",
+    "cottage",
+    "couch",
+    "cow",
+    "coyote",
+    "crab",
+    "crane",
+    "crater",
+    "crayon",
+    "cricket",
+    "crocodile",
+    "crow",
+    "crown",
+    "crystal",
+    "cucumber",
+    "cup",
+    "cupcake",
+    "curtain",
+    "cushion",
+    "dagger",
+    "daisy",
+    "dandelion",
+    "deer",
+    "den",
+    "desert",
+    "diamond",
+    "dice",
+    "dinosaur",
+    "dish",
+    "dolphin",
+    "donkey",
+    "doorbell",
+    "dove",
+    "dragon",
+    "dragonfly",
+    "drum",
+    "duck",
+    "dumpling",
+    "dune",
+    "eagle",
+    "easel",
+    "eel",
+    "eggplant",
+    "elephant",
+    "elk",
+    "engine",
+    "envelope",
+    "falcon",
+    "feather",
+    "fence",
+    "ferret",
+    "ferry",
+    "fiddle",
+    "field",
+    "fig",
+    "firefly",
+    "flag",
+    "flamingo",
+    "flashlight",
+    "flower",
+    "flute",
+    "football",
+    "forest",
+    "fork",
+    "fountain",
+    "fox",
+    "fridge",
+    "frog",
+    "galaxy",
+    "garden",
+    "garlic",
+    "gate",
+    "gazelle",
+    "gem",
+    "geyser",
+    "ghost",
+    "giraffe",
+    "glacier",
+    "glove",
+    "gnome",
+    "goat",
+    "goblet",
+    "gondola",
+    "goose",
+    "gorilla",
+    "grape",
+    "grapefruit",
+    "grasshopper",
+    "gravel",
+    "guitar",
+    "hammock",
+    "hamster",
+    "harbor",
+    "harmonica",
+    "harp",
+    "hat",
+    "hawk",
+    "hay",
+    "hedgehog",
+    "helicopter",
+    "helmet",
+    "heron",
+    "hill",
+    "hippo",
+    "hive",
+    "honey",
+    "horn",
+    "hornet",
+    "horse",
+    "hourglass",
+    "hurricane",
+    "hut",
+    "iceberg",
+    "igloo",
+    "iguana",
+    "island",
+    "jackal",
+    "jaguar",
+    "jar",
+    "jeep",
+    "jellyfish",
+    "jewel",
+    "jungle",
+    "kangaroo",
+    "kayak",
+    "kettle",
+    "key",
+    "kite",
+    "kitten",
+    "kiwi",
+    "knight",
+    "knot",
+    "koala",
+    "ladder",
+    "ladle",
+    "lagoon",
+    "lake",
+    "lamp",
+    "lantern",
+    "leaf",
+    "lemon",
+    "lemur",
+    "leopard",
+    "lettuce",
+    "library",
+    "lighthouse",
+    "lily",
+    "lion",
+    "lizard",
+    "llama",
+    "lobster",
+    "locket",
+    "locomotive",
+    "log",
+    "lollipop",
+    "lute",
+    "magnet",
+    "mailbox",
+    "mammoth",
+    "mandolin",
+    "mango",
+    "map",
+    "maple",
+    "marble",
+    "marsh",
+    "mask",
+    "mattress",
+    "meadow",
+    "melon",
+    "mermaid",
+    "meteor",
+    "microscope",
+    "mirror",
+    "mitten",
+    "moat",
+    "mole",
+    "monkey",
+    "monument",
+    "moon",
+    "moose",
+    "mosquito",
+    "moth",
+    "motorcycle",
+    "mountain",
+    "mouse",
+    "muffin",
+    "mule",
+    "mushroom",
+    "napkin",
+    "narwhal",
+    "necklace",
+    "needle",
+    "nest",
+    "noodle",
+    "notebook",
+    "nugget",
+    "nutmeg",
+    "oak",
+    "oar",
+    "oasis",
+    "ocean",
+    "octopus",
+    "onion",
+    "opal",
+    "orchard",
+    "orchid",
+    "ostrich",
+    "otter",
+    "oven",
+    "owl",
+    "oyster",
+    "paddle",
+    "pagoda",
+    "pail",
+    "paintbrush",
+    "palace",
+    "pan",
+    "pancake",
+    "panda",
+    "panther",
+    "parachute",
+    "parrot",
+    "parsnip",
+    "pasta",
+    "path",
+    "peach",
+    "peacock",
+    "peak",
+    "peanut",
+    "pear",
+    "pearl",
+    "pebble",
+    "pelican",
+    "pencil",
+    "penguin",
+    "pepper",
+    "piano",
+    "pickle",
+    "pie",
+    "pig",
+    "pigeon",
+    "pillow",
+    "pine",
+    "pineapple",
+    "pipe",
+    "pirate",
+    "pistachio",
+    "pitcher",
+    "pizza",
+    "planet",
+    "plank",
+    "plate",
+    "plum",
+    "pond",
+    "pony",
+    "poodle",
+    "popcorn",
+    "porch",
+    "porcupine",
+    "pot",
+    "potato",
+    "pretzel",
+    "puddle",
+    "puffin",
+    "pumpkin",
+    "puppet",
+    "puppy",
+    "pyramid",
+    "python",
+    "quail",
+    "quilt",
+    "rabbit",
+    "raccoon",
+    "radish",
+    "raft",
+    "rainbow",
+    "rake",
+    "ranch",
+    "raven",
+    "reef",
+    "reindeer",
+    "rhino",
+    "rhubarb",
+    "ribbon",
+    "ring",
+    "river",
+    "robin",
+    "robot",
+    "rock",
+    "rocket",
+    "rooster",
+    "rope",
+    "rose",
+    "ruby",
+    "rug",
+    "saddle",
+    "sailboat",
+    "salmon",
+    "sandal",
+    "sandwich",
+    "satellite",
+    "saucer",
+    "sausage",
+    "saxophone",
+    "scarecrow",
+    "scarf",
+    "scooter",
+    "scroll",
+    "seahorse",
+    "seal",
+    "shark",
+    "sheep",
+    "shell",
+    "ship",
+    "shovel",
+    "shrimp",
+    "skateboard",
+    "skunk",
+    "sky",
+    "skyscraper",
+    "sled",
+    "slipper",
+    "sloth",
+    "snail",
+    "snowflake",
+    "snowman",
+    "sock",
+    "sofa",
+    "spaceship",
+    "sparrow",
+    "spatula",
+    "sphinx",
+    "spider",
+    "spinach",
+    "sponge",
+    "spoon",
+    "spruce",
+    "squid",
+    "squirrel",
+    "stable",
+    "stadium",
+    "stagecoach",
+    "stapler",
+    "star",
+    "starfish",
+    "statue",
+    "stool",
+    "stove",
+    "strawberry",
+    "stream",
+    "submarine",
+    "suitcase",
+    "sundae",
+    "sunflower",
+    "swan",
+    "swing",
+    "sword",
+    "sycamore",
+    "table",
+    "taco",
+    "tadpole",
+    "tambourine",
+    "tangerine",
+    "tapir",
+    "teacup",
+    "teapot",
+    "telescope",
+    "temple",
+    "tent",
+    "thimble",
+    "thistle",
+    "throne",
+    "thunderstorm",
+    "tiara",
+    "tiger",
+    "toad",
+    "toaster",
+    "tomato",
+    "torch",
+    "tortoise",
+    "totem",
+    "toucan",
+    "tower",
+    "tractor",
+    "trail",
+    "train",
+    "trampoline",
+    "treasure",
+    "tree",
+    "trolley",
+    "trombone",
+    "truck",
+    "trumpet",
+    "trunk",
+    "tuba",
+    "tulip",
+    "tuna",
+    "tunnel",
+    "turkey",
+    "turnip",
+    "turtle",
+    "umbrella",
+    "unicorn",
+    "unicycle",
+    "valley",
+    "van",
+    "vase",
+    "vest",
+    "village",
+    "vine",
+    "violin",
+    "volcano",
+    "vulture",
+    "waffle",
+    "wagon",
+    "wallet",
+    "walnut",
+    "walrus",
+    "wand",
+    "wardrobe",
+    "warthog",
+    "wasp",
+    "waterfall",
+    "watermelon",
+    "weasel",
+    "well",
+    "whale",
+    "wharf",
+    "wheel",
+    "wheelbarrow",
+    "whistle",
+    "willow",
+    "windmill",
+    "window",
+    "wizard",
+    "wolf",
+    "wombat",
+    "woodpecker",
+    "worm",
+    "wren",
+    "yacht",
+    "yak",
+    "yogurt",
+    "zebra",
+    "zeppelin",
+    "zucchini",
+);
+pub static TOKEN_OFFSETS: [u32; 586] = [
+    0, 9, 14, 21, 30, 36, 42, 45, 50, 55,
+    62, 67, 76, 81, 89, 94, 103, 106, 114, 120,
+    125, 132, 138, 144, 151, 156, 161, 165, 171, 177,
+    180, 187, 194, 197, 203, 207, 211, 215, 220, 226,
+    232, 236, 241, 246, 253, 260, 265, 272, 279, 284,
+    292, 299, 308, 312, 318, 325, 333, 337, 343, 350,
+    357, 361, 364, 372, 377, 383, 391, 396, 401, 407,
+    413, 420, 425, 429, 438, 443, 450, 453, 457, 466,
+    472, 479, 484, 489, 495, 499, 509, 514, 520, 524,
+    529, 535, 541, 546, 552, 556, 559, 563, 569, 575,
+    579, 585, 588, 599, 608, 616, 620, 625, 630, 635,
+    640, 647, 653, 660, 666, 674, 681, 688, 696, 705,
+    713, 719, 723, 731, 736, 741, 746, 752, 757, 762,
+    769, 775, 779, 785, 790, 797, 801, 807, 812, 816,
+    820, 827, 832, 835, 841, 845, 850, 856, 862, 869,
+    878, 882, 887, 894, 902, 905, 912, 919, 926, 932,
+    937, 946, 950, 953, 959, 966, 970, 978, 982, 989,
+    995, 1003, 1007, 1013, 1022, 1026, 1030, 1038, 1042, 1047,
+    1052, 1055, 1063, 1071, 1074, 1080, 1088, 1094, 1101, 1106,
+    1112, 1117, 1123, 1128, 1131, 1138, 1142, 1150, 1160, 1166,
+    1171, 1179, 1185, 1189, 1197, 1200, 1206, 1210, 1216, 1222,
+    1228, 1232, 1239, 1242, 1248, 1253, 1260, 1267, 1272, 1277,
+    1281, 1287, 1294, 1299, 1306, 1311, 1321, 1332, 1338, 1344,
+    1351, 1358, 1364, 1373, 1377, 1380, 1384, 1387, 1395, 1405,
+    1411, 1416, 1420, 1425, 1429, 1434, 1438, 1444, 1449, 1458,
+    1467, 1470, 1477, 1482, 1488, 1494, 1500, 1506, 1509, 1513,
+    1522, 1527, 1533, 1541, 1546, 1552, 1555, 1559, 1565, 1569,
+    1575, 1579, 1584, 1590, 1595, 1601, 1605, 1609, 1616, 1620,
+    1625, 1630, 1637, 1644, 1651, 1661, 1665, 1669, 1675, 1680,
+    1687, 1693, 1703, 1706, 1714, 1718, 1724, 1731, 1738, 1746,
+    1751, 1754, 1759, 1765, 1770, 1774, 1782, 1788, 1793, 1800,
+    1806, 1816, 1822, 1828, 1832, 1836, 1842, 1850, 1854, 1859,
+    1867, 1871, 1881, 1889, 1894, 1900, 1904, 1912, 1918, 1925,
+    1933, 1939, 1943, 1949, 1957, 1963, 1969, 1972, 1975, 1980,
+    1985, 1992, 1997, 2001, 2008, 2014, 2021, 2026, 2030, 2033,
+    2039, 2045, 2051, 2055, 2065, 2071, 2074, 2081, 2086, 2093,
+    2102, 2108, 2115, 2120, 2124, 2129, 2136, 2140, 2146, 2150,
+    2155, 2161, 2168, 2174, 2181, 2187, 2192, 2198, 2201, 2204,
+    2210, 2216, 2220, 2229, 2233, 2239, 2248, 2255, 2260, 2266,
+    2271, 2276, 2280, 2284, 2288, 2294, 2301, 2306, 2315, 2318,
+    2324, 2331, 2337, 2343, 2350, 2356, 2361, 2368, 2374, 2379,
+    2384, 2390, 2397, 2403, 2407, 2414, 2418, 2423, 2428, 2432,
+    2440, 2445, 2452, 2458, 2462, 2467, 2472, 2477, 2481, 2487,
+    2494, 2498, 2502, 2506, 2509, 2515, 2523, 2529, 2535, 2543,
+    2552, 2558, 2565, 2574, 2583, 2588, 2595, 2601, 2609, 2613,
+    2618, 2623, 2628, 2632, 2638, 2644, 2654, 2659, 2662, 2672,
+    2676, 2683, 2688, 2693, 2702, 2709, 2713, 2717, 2726, 2733,
+    2740, 2746, 2752, 2759, 2765, 2770, 2776, 2781, 2789, 2795,
+    2802, 2812, 2819, 2823, 2831, 2837, 2842, 2847, 2857, 2863,
+    2872, 2880, 2886, 2895, 2899, 2904, 2909, 2917, 2922, 2926,
+    2933, 2943, 2952, 2957, 2963, 2969, 2978, 2984, 2988, 2995,
+    3002, 3008, 3020, 3025, 3030, 3034, 3041, 3047, 3052, 3060,
+    3065, 3071, 3076, 3083, 3088, 3093, 3103, 3111, 3115, 3122,
+    3130, 3135, 3142, 3147, 3151, 3156, 3160, 3166, 3172, 3178,
+    3184, 3192, 3199, 3207, 3213, 3216, 3220, 3224, 3231, 3235,
+    3241, 3248, 3255, 3261, 3266, 3272, 3278, 3284, 3288, 3296,
+    3303, 3307, 3316, 3326, 3332, 3336, 3341, 3346, 3351, 3362,
+    3369, 3375, 3383, 3389, 3395, 3399, 3405, 3415, 3419, 3423,
+    3428, 3431, 3437, 3442, 3450, 3458,
+];
@@ -1,6 +1,6 @@
 pub static TOKEN_COUNT: u32 = 6;
 pub static TOKEN_SEP: &str = "";
-pub static TOKEN_DATA: [&str; 10] = [
+pub static TOKEN_TEXT: &str = concat!(
     "0",
     "1",
     "2",
@@ -11,4 +11,8 @@ pub static TOKEN_DATA: [&str; 10] = [
     "7",
     "8",
     "9",
+);
+pub static TOKEN_OFFSETS: [u32; 11] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+    10,
 ];
@@ -0,0 +1,291 @@
+pub static TOKEN_TEXT: &str = concat!(
+    "admires",
+    "adopts",
+    "balances",
+    "befriends",
+    "bends",
+    "bewitches",
+    "blesses",
+    "boards",
+    "borrows",
+    "bounces",
+    "breaks",
+    "brews",
+    "bribes",
+    "brushes",
+    "builds",
+    "bumps",
+    "buries",
+    "buys",
+    "calms",
+    "captures",
+    "carries",
+    "carves",
+    "catches",
+    "celebrates",
+    "charms",
+    "chases",
+    "chews",
+    "chisels",
+    "chops",
+    "circles",
+    "clasps",
+    "cleans",
+    "climbs",
+    "clutches",
+    "coaxes",
+    "collects",
+    "combs",
+    "confuses",
+    "conquers",
+    "cooks",
+    "copies",
+    "counts",
+    "cradles",
+    "crafts",
+    "crowns",
+    "crumples",
+    "crushes",
+    "cuddles",
+    "dangles",
+    "dazzles",
+    "decorates",
+    "defends",
+    "delivers",
+    "devours",
+    "digs",
+    "discovers",
+    "dodges",
+    "drags",
+    "draws",
+    "drops",
+    "dunks",
+    "dusts",
+    "echoes",
+    "embraces",
+    "enchants",
+    "engraves",
+    "escorts",
+    "examines",
+    "fans",
+    "feeds",
+    "fetches",
+    "finds",
+    "fixes",
+    "flings",
+    "flips",
+    "folds",
+    "follows",
+    "forges",
+    "frames",
+    "fries",
+    "frightens",
+    "gathers",
+    "gnaws",
+    "grabs",
+    "greets",
+    "grills",
+    "grips",
+    "grooms",
+    "guards",
+    "guides",
+    "hammers",
+    "hatches",
+    "hauls",
+    "heals",
+    "hides",
+    "hoists",
+    "honors",
+    "hooks",
+    "hugs",
+    "hunts",
+    "ignites",
+    "inflates",
+    "inspects",
+    "invents",
+    "irons",
+    "jolts",
+    "juggles",
+    "kicks",
+    "kisses",
+    "kneads",
+    "knits",
+    "lassos",
+    "launches",
+    "licks",
+    "lifts",
+    "loves",
+    "lures",
+    "marries",
+    "mashes",
+    "measures",
+    "melts",
+    "mends",
+    "mimics",
+    "mocks",
+    "molds",
+    "munches",
+    "nibbles",
+    "nudges",
+    "obeys",
+    "opens",
+    "orders",
+    "outwits",
+    "packs",
+    "paints",
+    "pampers",
+    "pardons",
+    "pats",
+    "peels",
+    "pesters",
+    "photographs",
+    "pickles",
+    "pilots",
+    "pinches",
+    "plants",
+    "plucks",
+    "plunders",
+    "pokes",
+    "polishes",
+    "pounds",
+    "praises",
+    "presses",
+    "prods",
+    "protects",
+    "pulls",
+    "punches",
+    "punts",
+    "purchases",
+    "pushes",
+    "questions",
+    "quizzes",
+    "raises",
+    "ransacks",
+    "rattles",
+    "recycles",
+    "repairs",
+    "rescues",
+    "rides",
+    "roasts",
+    "rolls",
+    "ropes",
+    "rumples",
+    "salutes",
+    "saves",
+    "scares",
+    "scolds",
+    "scoops",
+    "scrubs",
+    "sculpts",
+    "seizes",
+    "serenades",
+    "shakes",
+    "shapes",
+    "shears",
+    "shields",
+    "shovels",
+    "shoves",
+    "shreds",
+    "signs",
+    "sips",
+    "slices",
+    "smashes",
+    "smells",
+    "smuggles",
+    "snaps",
+    "snatches",
+    "sniffs",
+    "soothes",
+    "spins",
+    "splashes",
+    "sprinkles",
+    "squeezes",
+    "stacks",
+    "stamps",
+    "startles",
+    "steals",
+    "steers",
+    "stirs",
+    "stitches",
+    "strokes",
+    "studies",
+    "stuffs",
+    "summons",
+    "swallows",
+    "swats",
+    "sweeps",
+    "swings",
+    "tackles",
+    "tames",
+    "tangles",
+    "taps",
+    "tastes",
+    "teases",
+    "thanks",
+    "throws",
+    "tickles",
+    "toasts",
+    "topples",
+    "tosses",
+    "tows",
+    "traces",
+    "trains",
+    "traps",
+    "tricks",
+    "trims",
+    "tugs",
+    "twists",
+    "unfolds",
+    "unlocks",
+    "untangles",
+    "unwraps",
+    "upgrades",
+    "vacuums",
+    "visits",
+    "wakes",
+    "warms",
+    "washes",
+    "watches",
+    "weaves",
+    "weighs",
+    "whacks",
+    "whisks",
+    "wiggles",
+    "wins",
+    "wrangles",
+    "wraps",
+    "wrestles",
+    "writes",
+    "yanks",
+    "zaps",
+    "zips",
+);
+pub static TOKEN_OFFSETS: [u32; 261] = [
+    0, 7, 13, 21, 30, 35, 44, 51, 57, 64,
+    71, 77, 82, 88, 95, 101, 106, 112, 116, 121,
+    129, 136, 142, 149, 159, 165, 171, 176, 183, 188,
+    195, 201, 207, 213, 221, 227, 235, 240, 248, 256,
+    261, 267, 273, 280, 286, 292, 300, 307, 314, 321,
+    328, 337, 344, 352, 359, 363, 372, 378, 383, 388,
+    393, 398, 403, 409, 417, 425, 433, 440, 448, 452,
+    457, 464, 469, 474, 480, 485, 490, 497, 503, 509,
+    514, 523, 530, 535, 540, 546, 552, 557, 563, 569,
+    575, 582, 589, 594, 599, 604, 610, 616, 621, 625,
+    630, 637, 645, 653, 660, 665, 670, 677, 682, 688,
+    694, 699, 705, 713, 718, 723, 728, 733, 740, 746,
+    754, 759, 764, 770, 775, 780, 787, 794, 800, 805,
+    810, 816, 823, 828, 834, 841, 848, 852, 857, 864,
+    875, 882, 888, 895, 901, 907, 915, 920, 928, 934,
+    941, 948, 953, 961, 966, 973, 978, 987, 993, 1002,
+    1009, 1015, 1023, 1030, 1038, 1045, 1052, 1057, 1063, 1068,
+    1073, 1080, 1087, 1092, 1098, 1104, 1110, 1116, 1123, 1129,
+    1138, 1144, 1150, 1156, 1163, 1170, 1176, 1182, 1187, 1191,
+    1197, 1204, 1210, 1218, 1223, 1231, 1237, 1244, 1249, 1257,
+    1266, 1274, 1280, 1286, 1294, 1300, 1306, 1311, 1319, 1326,
+    1333, 1339, 1346, 1354, 1359, 1365, 1371, 1378, 1383, 1390,
+    1394, 1400, 1406, 1412, 1418, 1425, 1431, 1438, 1444, 1448,
+    1454, 1460, 1465, 1471, 1476, 1480, 1486, 1493, 1500, 1509,
+    1516, 1524, 1531, 1537, 1542, 1547, 1553, 1560, 1566, 1572,
+    1578, 1584, 1591, 1595, 1603, 1608, 1616, 1622, 1627, 1631,
+    1635,
+];
@@ -1,6 +1,6 @@
 pub static TOKEN_COUNT: u32 = 6;
 pub static TOKEN_SEP: &str = " ";
-pub static TOKEN_DATA: [&str; 26578] = [
+pub static TOKEN_TEXT: &str = concat!(
     "aback",
     "abandon",
     "abandoned",
@@ -26579,4 +26579,2664 @@ pub static TOKEN_DATA: [&str; 26578] = [
     "zooming",
     "zoos",
     "zucchini",
+);
+pub static TOKEN_OFFSETS: [u32; 26579] = [
+    0, 5, 12, 21, 31, 42, 50, 59, 65, 70,
+    75, 86, 98, 106, 115, 125, 132, 141, 149, 158,
+    166, 174, 184, 195, 200, 208, 213, 220, 229, 236,
+    242, 248, 252, 256, 264, 275, 285, 291, 296, 303,
+    312, 322, 331, 343, 353, 363, 368, 374, 379, 387,
+    395, 402, 409, 417, 423, 429, 437, 444, 452, 459,
+    467, 473, 481, 492, 500, 508, 518, 525, 533, 539,
+    547, 556, 563, 573, 580, 590, 598, 608, 619, 630,
+    642, 651, 657, 666, 674, 683, 691, 701, 707, 714,
+    720, 727, 734, 739, 745, 753, 761, 773, 784, 793,
+    802, 809, 819, 830, 842, 854, 865, 878, 884, 892,
+    899, 910, 916, 926, 936, 944, 953, 960, 966, 974,
+    984, 993, 1002, 1013, 1022, 1030, 1040, 1052, 1061, 1068,
+    1077, 1085, 1094, 1105, 1117, 1128, 1139, 1148, 1160, 1170,
+    1181, 1191, 1203, 1209, 1219, 1227, 1236, 1247, 1256, 1263,
+    1271, 1278, 1289, 1300, 1310, 1321, 1330, 1340, 1348, 1358,
+    1367, 1374, 1380, 1387, 1394, 1402, 1412, 1423, 1434, 1446,
+    1458, 1466, 1474, 1484, 1492, 1502, 1513, 1519, 1526, 1533,
+    1541, 1548, 1556, 1566, 1569, 1573, 1580, 1586, 1593, 1597,
+    1602, 1607, 1617, 1624, 1632, 1643, 1655, 1663, 1672, 1678,
+    1688, 1692, 1698, 1705, 1710, 1721, 1733, 1745, 1749, 1753,
+    1758, 1764, 1772, 1781, 1789, 1801, 1811, 1823, 1830, 1838,
+    1846, 1855, 1866, 1878, 1884, 1893, 1902, 1906, 1913, 1918,
+    1923, 1930, 1939, 1946, 1954, 1960, 1967, 1970, 1975, 1981,
+    1987, 1994, 2002, 2011, 2020, 2030, 2040, 2046, 2054, 2062,
+    2070, 2079, 2089, 2097, 2102, 2108, 2115, 2124, 2128, 2134,
+    2143, 2152, 2160, 2167, 2173, 2179, 2190, 2195, 2202, 2209,
+    2214, 2220, 2227, 2232, 2244, 2253, 2263, 2274, 2281, 2288,
+    2296, 2304, 2312, 2318, 2321, 2326, 2334, 2342, 2351, 2360,
+    2366, 2374, 2384, 2396, 2405, 2413, 2422, 2429, 2438, 2447,
+    2456, 2466, 2470, 2477, 2482, 2490, 2498, 2508, 2514, 2521,
+    2530, 2538, 2547, 2554, 2562, 2570, 2578, 2587, 2594, 2602,
+    2611, 2621, 2630, 2637, 2646, 2658, 2666, 2673, 2681, 2687,
+    2697, 2705, 2713, 2722, 2732, 2743, 2750, 2755, 2760, 2770,
+    2782, 2793, 2806, 2815, 2824, 2831, 2839, 2848, 2858, 2864,
+    2871, 2878, 2886, 2893, 2901, 2911, 2920, 2930, 2935, 2941,
+    2949, 2959, 2968, 2978, 2981, 2986, 2997, 3007, 3018, 3023,
+    3030, 3038, 3046, 3054, 3060, 3068, 3077, 3082, 3088, 3095,
+    3100, 3107, 3114, 3124, 3130, 3136, 3139, 3144, 3152, 3161,
+    3167, 3174, 3182, 3193, 3201, 3210, 3219, 3231, 3241, 3250,
+    3256, 3265, 3275, 3286, 3296, 3307, 3313, 3320, 3331, 3342,
+    3351, 3358, 3367, 3376, 3385, 3395, 3405, 3416, 3427, 3433,
+    3442, 3448, 3455, 3462, 3470, 3477, 3485, 3492, 3500, 3508,
+    3516, 3524, 3533, 3542, 3552, 3557, 3563, 3570, 3578, 3589,
+    3600, 3607, 3615, 3624, 3634, 3638, 3645, 3651, 3658, 3664,
+    3672, 3681, 3690, 3702, 3712, 3719, 3728, 3737, 3747, 3757,
+    3768, 3780, 3790, 3798, 3804, 3815, 3826, 3834, 3843, 3850,
+    3855, 3862, 3869, 3876, 3885, 3895, 3904, 3912, 3918, 3928,
+    3936, 3943, 3950, 3956, 3963, 3969, 3974, 3980, 3985, 3991,
+    3997, 4000, 4009, 4018, 4027, 4037, 4046, 4056, 4061, 4068,
+    4073, 4076, 4080, 4088, 4094, 4100, 4107, 4112, 4118, 4122,
+    4132, 4141, 4151, 4161, 4172, 4182, 4192, 4204, 4213, 4219,
+    4224, 4231, 4236, 4243, 4251, 4260, 4268, 4271, 4278, 4286,
+    4295, 4300, 4308, 4313, 4322, 4331, 4337, 4345, 4354, 4364,
+    4370, 4382, 4393, 4400, 4405, 4408, 4412, 4417, 4422, 4428,
+    4432, 4435, 4441, 4448, 4456, 4459, 4464, 4470, 4477, 4481,
+    4484, 4490, 4496, 4504, 4512, 4520, 4525, 4533, 4542, 4549,
+    4555, 4562, 4569, 4577, 4585, 4591, 4597, 4605, 4614, 4621,
+    4629, 4633, 4640, 4648, 4656, 4664, 4671, 4679, 4685, 4692,
+    4696, 4701, 4707, 4711, 4715, 4723, 4728, 4735, 4743, 4751,
+    4757, 4761, 4770, 4776, 4782, 4788, 4793, 4800, 4806, 4815,
+    4822, 4829, 4838, 4848, 4858, 4866, 4872, 4877, 4885, 4893,
+    4896, 4901, 4908, 4916, 4925, 4931, 4938, 4942, 4947, 4954,
+    4963, 4971, 4980, 4990, 4995, 5002, 5010, 5015, 5020, 5028,
+    5037, 5047, 5053, 5059, 5067, 5072, 5079, 5087, 5096, 5102,
+    5107, 5114, 5119, 5125, 5133, 5141, 5144, 5149, 5159, 5170,
+    5176, 5183, 5192, 5199, 5209, 5217, 5228, 5236, 5243, 5251,
+    5259, 5268, 5275, 5284, 5289, 5295, 5303, 5312, 5318, 5324,
+    5333, 5341, 5350, 5360, 5370, 5381, 5390, 5400, 5408, 5413,
+    5422, 5431, 5441, 5448, 5456, 5462, 5467, 5475, 5481, 5487,
+    5494, 5501, 5509, 5515, 5523, 5531, 5540, 5548, 5552, 5559,
+    5567, 5573, 5580, 5586, 5590, 5594, 5599, 5604, 5609, 5618,
+    5623, 5628, 5633, 5641, 5653, 5659, 5666, 5673, 5676, 5681,
+    5687, 5692, 5702, 5713, 5724, 5731, 5739, 5748, 5758, 5769,
+    5779, 5790, 5801, 5812, 5824, 5830, 5838, 5846, 5855, 5859,
+    5869, 5874, 5882, 5892, 5896, 5904, 5910, 5916, 5923, 5934,
+    5946, 5951, 5958, 5966, 5973, 5981, 5986, 5992, 6001, 6008,
+    6017, 6023, 6030, 6040, 6051, 6056, 6064, 6072, 6079, 6090,
+    6099, 6108, 6116, 6125, 6134, 6145, 6155, 6161, 6170, 6180,
+    6190, 6196, 6204, 6208, 6216, 6221, 6228, 6236, 6245, 6255,
+    6261, 6270, 6277, 6284, 6292, 6300, 6304, 6313, 6319, 6324,
+    6329, 6334, 6338, 6345, 6355, 6362, 6369, 6374, 6381, 6387,
+    6394, 6403, 6415, 6424, 6430, 6438, 6447, 6454, 6459, 6462,
+    6468, 6477, 6487, 6497, 6502, 6511, 6520, 6530, 6537, 6546,
+    6556, 6561, 6565, 6573, 6583, 6589, 6596, 6601, 6607, 6616,
+    6623, 6631, 6640, 6651, 6658, 6667, 6676, 6686, 6692, 6701,
+    6710, 6717, 6726, 6733, 6741, 6749, 6756, 6764, 6772, 6782,
+    6794, 6801, 6809, 6817, 6825, 6834, 6842, 6851, 6860, 6870,
+    6877, 6885, 6893, 6903, 6910, 6918, 6927, 6936, 6944, 6950,
+    6959, 6967, 6976, 6983, 6990, 6998, 7007, 7010, 7017, 7025,
+    7032, 7041, 7049, 7058, 7064, 7070, 7077, 7087, 7097, 7105,
+    7114, 7118, 7123, 7130, 7136, 7141, 7148, 7154, 7160, 7171,
+    7176, 7182, 7188, 7195, 7201, 7208, 7215, 7220, 7225, 7232,
+    7239, 7245, 7252, 7259, 7267, 7275, 7284, 7294, 7301, 7309,
+    7318, 7323, 7330, 7339, 7345, 7350, 7355, 7361, 7367, 7375,
+    7384, 7389, 7399, 7406, 7413, 7425, 7436, 7445, 7455, 7466,
+    7474, 7483, 7495, 7504, 7514, 7523, 7533, 7538, 7547, 7554,
+    7562, 7568, 7576, 7583, 7592, 7599, 7604, 7611, 7619, 7624,
+    7632, 7641, 7650, 7657, 7661, 7670, 7679, 7690, 7698, 7705,
+    7711, 7719, 7728, 7735, 7738, 7746, 7756, 7766, 7778, 7789,
+    7798, 7802, 7812, 7822, 7833, 7841, 7848, 7856, 7864, 7872,
+    7878, 7885, 7896, 7905, 7912, 7924, 7928, 7938, 7949, 7959,
+    7967, 7977, 7988, 7999, 8011, 8023, 8035, 8041, 8049, 8056,
+    8064, 8072, 8081, 8092, 8099, 8107, 8118, 8127, 8138, 8148,
+    8158, 8167, 8174, 8180, 8187, 8191, 8196, 8205, 8212, 8219,
+    8228, 8235, 8241, 8248, 8254, 8262, 8269, 8275, 8283, 8288,
+    8293, 8298, 8307, 8316, 8326, 8332, 8335, 8343, 8347, 8351,
+    8358, 8365, 8370, 8378, 8384, 8394, 8405, 8411, 8421, 8429,
+    8438, 8447, 8457, 8467, 8474, 8482, 8490, 8497, 8505, 8514,
+    8517, 8525, 8534, 8543, 8550, 8558, 8568, 8578, 8584, 8592,
+    8601, 8608, 8614, 8624, 8635, 8643, 8652, 8659, 8666, 8674,
+    8683, 8692, 8703, 8709, 8719, 8727, 8737, 8745, 8753, 8762,
+    8769, 8778, 8786, 8791, 8797, 8806, 8816, 8826, 8835, 8845,
+    8856, 8868, 8875, 8882, 8887, 8895, 8902, 8911, 8921, 8931,
+    8942, 8954, 8962, 8971, 8981, 8989, 8998, 9009, 9020, 9030,
+    9041, 9053, 9065, 9077, 9086, 9097, 9109, 9121, 9131, 9142,
+    9153, 9161, 9169, 9179, 9189, 9200, 9211, 9222, 9234, 9242,
+    9249, 9257, 9265, 9274, 9285, 9297, 9301, 9308, 9316, 9321,
+    9326, 9332, 9339, 9343, 9346, 9354, 9359, 9363, 9374, 9384,
+    9392, 9399, 9407, 9415, 9422, 9429, 9435, 9442, 9453, 9462,
+    9473, 9483, 9494, 9499, 9507, 9516, 9519, 9525, 9532, 9538,
+    9542, 9549, 9558, 9568, 9579, 9589, 9600, 9608, 9614, 9624,
+    9630, 9637, 9644, 9650, 9660, 9669, 9676, 9687, 9696, 9706,
+    9718, 9725, 9733, 9741, 9750, 9757, 9761, 9767, 9773, 9781,
+    9786, 9793, 9797, 9802, 9807, 9813, 9818, 9826, 9834, 9839,
+    9845, 9851, 9858, 9866, 9875, 9881, 9885, 9889, 9895, 9900,
+    9906, 9912, 9919, 9930, 9940, 9952, 9963, 9973, 9976, 9979,
+    9985, 9993, 10002, 10010, 10018, 10023, 10029, 10035, 10044, 10049,
+    10056, 10062, 10068, 10075, 10079, 10083, 10088, 10094, 10102, 10107,
+    10113, 10120, 10126, 10133, 10141, 10148, 10156, 10167, 10179, 10187,
+    10195, 10204, 10209, 10216, 10222, 10229, 10235, 10243, 10252, 10259,
+    10266, 10274, 10280, 10287, 10294, 10302, 10311, 10319, 10324, 10333,
+    10342, 10348, 10354, 10361, 10369, 10376, 10381, 10384, 10392, 10400,
+    10406, 10412, 10420, 10429, 10439, 10446, 10454, 10464, 10475, 10487,
+    10499, 10507, 10516, 10524, 10534, 10546, 10555, 10562, 10570, 10576,
+    10584, 10592, 10599, 10603, 10610, 10618, 10626, 10632, 10642, 10650,
+    10658, 10667, 10674, 10683, 10689, 10698, 10709, 10721, 10728, 10738,
+    10743, 10750, 10758, 10766, 10773, 10780, 10783, 10790, 10795, 10800,
+    10806, 10812, 10819, 10823, 10828, 10831, 10836, 10841, 10847, 10851,
+    10857, 10860, 10869, 10875, 10882, 10887, 10894, 10902, 10910, 10918,
+    10927, 10937, 10948, 10954, 10961, 10968, 10975, 10983, 10992, 11000,
+    11008, 11020, 11029, 11036, 11045, 11055, 11063, 11068, 11075, 11081,
+    11091, 11102, 11110, 11119, 11129, 11139, 11147, 11158, 11164, 11172,
+    11178, 11186, 11195, 11204, 11214, 11223, 11230, 11236, 11244, 11252,
+    11261, 11271, 11282, 11290, 11295, 11301, 11307, 11315, 11324, 11334,
+    11345, 11352, 11362, 11373, 11385, 11391, 11401, 11410, 11420, 11428,
+    11437, 11444, 11453, 11463, 11473, 11484, 11495, 11507, 11518, 11526,
+    11536, 11543, 11549, 11556, 11563, 11571, 11581, 11592, 11601, 11611,
+    11617, 11624, 11633, 11640, 11648, 11653, 11661, 11667, 11675, 11684,
+    11690, 11698, 11708, 11719, 11731, 11741, 11747, 11753, 11760, 11769,
+    11778, 11788, 11798, 11809, 11821, 11830, 11836, 11843, 11849, 11856,
+    11866, 11878, 11887, 11897, 11909, 11912, 11919, 11926, 11933, 11941,
+    11948, 11956, 11964, 11973, 11978, 11985, 11995, 12006, 12011, 12015,
+    12021, 12026, 12031, 12040, 12044, 12049, 12055, 12065, 12073, 12080,
+    12086, 12093, 12101, 12110, 12120, 12131, 12137, 12145, 12153, 12162,
+    12171, 12178, 12184, 12194, 12202, 12211, 12221, 12228, 12235, 12244,
+    12254, 12262, 12268, 12278, 12289, 12298, 12308, 12316, 12325, 12332,
+    12341, 12351, 12360, 12370, 12381, 12387, 12395, 12402, 12407, 12413,
+    12420, 12428, 12437, 12445, 12454, 12461, 12470, 12480, 12490, 12501,
+    12511, 12519, 12531, 12540, 12550, 12560, 12571, 12582, 12594, 12603,
+    12610, 12618, 12624, 12631, 12640, 12648, 12656, 12663, 12671, 12680,
+    12685, 12696, 12701, 12708, 12716, 12724, 12734, 12743, 12750, 12760,
+    12768, 12776, 12782, 12787, 12792, 12799, 12811, 12820, 12830, 12838,
+    12844, 12848, 12853, 12857, 12862, 12870, 12880, 12887, 12896, 12905,
+    12917, 12923, 12931, 12942, 12951, 12960, 12970, 12980, 12991, 12998,
+    13008, 13014, 13022, 13026, 13039, 13047, 13057, 13066, 13076, 13084,
+    13092, 13101, 13110, 13120, 13130, 13141, 13151, 13161, 13169, 13176,
+    13181, 13187, 13195, 13206, 13215, 13220, 13232, 13241, 13248, 13257,
+    13264, 13270, 13276, 13283, 13290, 13298, 13306, 13312, 13319, 13326,
+    13334, 13342, 13351, 13357, 13365, 13370, 13377, 13385, 13390, 13398,
+    13405, 13413, 13417, 13423, 13430, 13435, 13444, 13451, 13459, 13465,
+    13469, 13475, 13480, 13487, 13495, 13501, 13506, 13512, 13520, 13529,
+    13536, 13541, 13548, 13556, 13562, 13567, 13576, 13581, 13585, 13588,
+    13592, 13599, 13604, 13611, 13617, 13624, 13633, 13636, 13642, 13647,
+    13651, 13654, 13658, 13663, 13668, 13674, 13678, 13682, 13687, 13691,
+    13696, 13705, 13708, 13715, 13720, 13726, 13734, 13738, 13743, 13749,
+    13755, 13762, 13766, 13773, 13781, 13790, 13794, 13802, 13812, 13820,
+    13826, 13832, 13839, 13847, 13857, 13868, 13875, 13883, 13891, 13898,
+    13906, 13911, 13919, 13927, 13936, 13944, 13953, 13963, 13969, 13976,
+    13984, 13996, 14005, 14013, 14018, 14026, 14035, 14044, 14047, 14051,
+    14056, 14062, 14069, 14075, 14080, 14089, 14096, 14102, 14109, 14117,
+    14120, 14125, 14132, 14138, 14145, 14150, 14157, 14161, 14164, 14168,
+    14174, 14181, 14188, 14192, 14198, 14205, 14209, 14214, 14219, 14225,
+    14231, 14237, 14244, 14252, 14260, 14269, 14278, 14285, 14289, 14296,
+    14300, 14305, 14309, 14315, 14319, 14325, 14332, 14339, 14348, 14354,
+    14361, 14370, 14377, 14385, 14391, 14398, 14406, 14414, 14418, 14423,
+    14429, 14439, 14445, 14448, 14453, 14461, 14467, 14474, 14478, 14485,
+    14493, 14501, 14507, 14514, 14520, 14527, 14532, 14541, 14550, 14554,
+    14558, 14564, 14569, 14573, 14579, 14587, 14595, 14600, 14604, 14610,
+    14616, 14623, 14630, 14638, 14647, 14655, 14663, 14673, 14678, 14684,
+    14690, 14697, 14704, 14711, 14719, 14723, 14729, 14735, 14742, 14751,
+    14759, 14767, 14774, 14782, 14785, 14789, 14798, 14808, 14816, 14825,
+    14834, 14842, 14848, 14855, 14861, 14868, 14876, 14881, 14885, 14890,
+    14894, 14902, 14908, 14914, 14921, 14931, 14939, 14944, 14950, 14958,
+    14964, 14968, 14974, 14980, 14987, 14992, 14998, 15002, 15010, 15019,
+    15025, 15030, 15038, 15043, 15051, 15058, 15066, 15072, 15079, 15086,
+    15094, 15101, 15107, 15113, 15120, 15126, 15133, 15141, 15148, 15157,
+    15163, 15170, 15174, 15183, 15189, 15195, 15200, 15206, 15210, 15218,
+    15223, 15231, 15239, 15246, 15254, 15259, 15263, 15270, 15275, 15284,
+    15290, 15295, 15303, 15308, 15314, 15320, 15325, 15329, 15335, 15345,
+    15352, 15359, 15363, 15370, 15377, 15384, 15387, 15392, 15399, 15403,
+    15408, 15412, 15417, 15423, 15430, 15438, 15446, 15455, 15460, 15467,
+    15472, 15476, 15483, 15492, 15502, 15508, 15514, 15520, 15528, 15537,
+    15544, 15551, 15558, 15564, 15571, 15582, 15589, 15599, 15610, 15618,
+    15622, 15629, 15632, 15637, 15644, 15652, 15657, 15661, 15667, 15674,
+    15679, 15686, 15692, 15699, 15703, 15709, 15714, 15720, 15724, 15730,
+    15735, 15739, 15745, 15752, 15757, 15761, 15766, 15770, 15778, 15783,
+    15790, 15796, 15802, 15809, 15816, 15824, 15829, 15834, 15841, 15847,
+    15851, 15857, 15864, 15872, 15877, 15881, 15889, 15898, 15909, 15915,
+    15920, 15926, 15933, 15939, 15943, 15951, 15957, 15964, 15972, 15975,
+    15981, 15987, 15994, 16000, 16007, 16014, 16022, 16026, 16033, 16040,
+    16043, 16048, 16052, 16057, 16064, 16068, 16072, 16077, 16081, 16085,
+    16091, 16098, 16103, 16109, 16117, 16123, 16129, 16135, 16145, 16155,
+    16164, 16167, 16172, 16177, 16183, 16189, 16196, 16202, 16209, 16214,
+    16222, 16231, 16240, 16250, 16256, 16264, 16268, 16273, 16279, 16285,
+    16292, 16299, 16307, 16315, 16325, 16333, 16339, 16345, 16351, 16357,
+    16365, 16373, 16378, 16383, 16389, 16396, 16401, 16407, 16413, 16419,
+    16426, 16432, 16439, 16447, 16455, 16464, 16472, 16481, 16489, 16493,
+    16498, 16504, 16511, 16518, 16529, 16535, 16543, 16550, 16555, 16560,
+    16566, 16574, 16583, 16593, 16600, 16607, 16612, 16616, 16622, 16627,
+    16634, 16641, 16646, 16653, 16662, 16672, 16676, 16682, 16689, 16694,
+    16701, 16711, 16721, 16732, 16739, 16748, 16758, 16766, 16777, 16787,
+    16793, 16797, 16804, 16812, 16822, 16829, 16837, 16843, 16851, 16862,
+    16868, 16873, 16877, 16884, 16889, 16896, 16901, 16907, 16912, 16919,
+    16924, 16930, 16937, 16945, 16954, 16958, 16964, 16972, 16979, 16984,
+    16994, 16997, 17001, 17007, 17015, 17023, 17032, 17039, 17048, 17052,
+    17058, 17065, 17072, 17079, 17084, 17091, 17099, 17108, 17114, 17124,
+    17135, 17147, 17153, 17159, 17163, 17169, 17175, 17182, 17190, 17193,
+    17198, 17204, 17212, 17224, 17230, 17237, 17245, 17248, 17254, 17261,
+    17268, 17272, 17280, 17284, 17291, 17302, 17305, 17311, 17318, 17325,
+    17330, 17337, 17344, 17348, 17353, 17358, 17364, 17370, 17379, 17383,
+    17388, 17397, 17401, 17410, 17420, 17426, 17432, 17441, 17448, 17456,
+    17463, 17474, 17482, 17489, 17494, 17499, 17502, 17510, 17516, 17520,
+    17526, 17533, 17540, 17548, 17553, 17558, 17563, 17573, 17581, 17585,
+    17596, 17608, 17620, 17631, 17641, 17652, 17664, 17675, 17684, 17694,
+    17706, 17715, 17725, 17732, 17742, 17750, 17756, 17765, 17775, 17784,
+    17789, 17796, 17803, 17808, 17812, 17818, 17823, 17828, 17836, 17844,
+    17854, 17860, 17867, 17875, 17881, 17889, 17897, 17903, 17912, 17919,
+    17926, 17931, 17937, 17943, 17946, 17953, 17957, 17962, 17968, 17974,
+    17978, 17984, 17990, 17998, 18008, 18016, 18023, 18028, 18038, 18047,
+    18057, 18066, 18073, 18081, 18090, 18099, 18107, 18117, 18124, 18129,
+    18135, 18139, 18144, 18150, 18156, 18163, 18171, 18176, 18181, 18188,
+    18196, 18203, 18209, 18216, 18225, 18230, 18237, 18245, 18253, 18259,
+    18266, 18273, 18278, 18284, 18290, 18297, 18303, 18310, 18316, 18324,
+    18329, 18333, 18338, 18346, 18352, 18359, 18364, 18371, 18378, 18386,
+    18392, 18397, 18404, 18411, 18419, 18428, 18433, 18437, 18443, 18451,
+    18456, 18461, 18468, 18476, 18483, 18492, 18498, 18503, 18510, 18518,
+    18522, 18527, 18535, 18542, 18552, 18560, 18568, 18573, 18581, 18586,
+    18593, 18597, 18601, 18606, 18614, 18622, 18633, 18640, 18648, 18654,
+    18660, 18665, 18669, 18676, 18684, 18689, 18694, 18700, 18705, 18712,
+    18720, 18726, 18735, 18746, 18752, 18757, 18764, 18772, 18778, 18785,
+    18793, 18797, 18802, 18809, 18817, 18823, 18830, 18834, 18840, 18847,
+    18852, 18859, 18864, 18867, 18871, 18880, 18889, 18894, 18899, 18905,
+    18911, 18918, 18926, 18931, 18938, 18945, 18949, 18956, 18964, 18970,
+    18975, 18980, 18987, 18992, 18999, 19007, 19014, 19017, 19021, 19026,
+    19033, 19041, 19049, 19055, 19064, 19068, 19073, 19080, 19088, 19094,
+    19098, 19105, 19112, 19119, 19124, 19132, 19135, 19141, 19148, 19153,
+    19160, 19167, 19171, 19177, 19183, 19189, 19193, 19202, 19212, 19220,
+    19223, 19228, 19234, 19239, 19245, 19249, 19254, 19262, 19266, 19272,
+    19278, 19289, 19296, 19303, 19308, 19312, 19318, 19326, 19332, 19340,
+    19347, 19354, 19363, 19367, 19373, 19380, 19385, 19389, 19396, 19405,
+    19415, 19426, 19432, 19438, 19446, 19451, 19458, 19462, 19468, 19475,
+    19480, 19484, 19489, 19496, 19502, 19507, 19512, 19519, 19523, 19526,
+    19532, 19536, 19544, 19550, 19557, 19564, 19572, 19579, 19586, 19594,
+    19602, 19607, 19617, 19626, 19634, 19643, 19650, 19654, 19660, 19669,
+    19676, 19681, 19689, 19693, 19703, 19708, 19715, 19722, 19730, 19738,
+    19742, 19748, 19753, 19759, 19766, 19771, 19774, 19782, 19788, 19796,
+    19805, 19815, 19822, 19826, 19831, 19838, 19843, 19848, 19854, 19858,
+    19863, 19868, 19875, 19883, 19889, 19897, 19905, 19914, 19923, 19930,
+    19934, 19940, 19945, 19950, 19953, 19962, 19970, 19979, 19985, 19989,
+    19995, 20003, 20012, 20019, 20025, 20032, 20039, 20047, 20053, 20061,
+    20068, 20076, 20081, 20087, 20093, 20101, 20108, 20116, 20125, 20131,
+    20138, 20145, 20153, 20158, 20168, 20176, 20183, 20191, 20200, 20206,
+    20214, 20220, 20227, 20235, 20242, 20251, 20262, 20266, 20274, 20279,
+    20285, 20288, 20293, 20298, 20304, 20308, 20314, 20320, 20327, 20334,
+    20339, 20345, 20349, 20352, 20357, 20362, 20368, 20373, 20379, 20383,
+    20386, 20393, 20402, 20410, 20419, 20426, 20432, 20436, 20439, 20444,
+    20450, 20458, 20464, 20471, 20478, 20485, 20493, 20501, 20507, 20511,
+    20518, 20526, 20531, 20538, 20544, 20551, 20556, 20562, 20568, 20575,
+    20580, 20586, 20593, 20600, 20604, 20610, 20618, 20626, 20635, 20640,
+    20647, 20655, 20661, 20667, 20671, 20676, 20681, 20685, 20692, 20697,
+    20704, 20711, 20717, 20724, 20729, 20734, 20738, 20744, 20750, 20758,
+    20766, 20775, 20780, 20786, 20793, 20798, 20806, 20815, 20822, 20830,
+    20839, 20847, 20855, 20861, 20873, 20880, 20890, 20898, 20904, 20911,
+    20919, 20927, 20936, 20946, 20953, 20957, 20963, 20971, 20976, 20983,
+    20991, 20999, 21005, 21011, 21018, 21024, 21032, 21039, 21043, 21049,
+    21055, 21064, 21071, 21078, 21085, 21089, 21094, 21100, 21107, 21113,
+    21118, 21124, 21130, 21135, 21145, 21151, 21157, 21164, 21174, 21181,
+    21189, 21195, 21200, 21209, 21216, 21223, 21231, 21239, 21246, 21252,
+    21256, 21263, 21271, 21279, 21285, 21293, 21301, 21310, 21318, 21328,
+    21338, 21347, 21358, 21362, 21369, 21377, 21382, 21387, 21395, 21401,
+    21406, 21411, 21416, 21423, 21430, 21437, 21445, 21453, 21460, 21466,
+    21474, 21479, 21488, 21497, 21508, 21520, 21532, 21542, 21549, 21558,
+    21568, 21576, 21583, 21591, 21598, 21607, 21614, 21622, 21630, 21639,
+    21644, 21651, 21658, 21663, 21669, 21675, 21684, 21691, 21698, 21705,
+    21712, 21721, 21731, 21739, 21746, 21752, 21759, 21765, 21770, 21777,
+    21785, 21791, 21796, 21802, 21807, 21812, 21820, 21827, 21838, 21846,
+    21853, 21857, 21862, 21869, 21877, 21884, 21892, 21900, 21908, 21914,
+    21924, 21929, 21935, 21942, 21950, 21958, 21964, 21970, 21977, 21984,
+    21992, 21998, 22006, 22011, 22016, 22023, 22030, 22038, 22045, 22051,
+    22060, 22068, 22073, 22079, 22086, 22092, 22099, 22106, 22114, 22120,
+    22130, 22134, 22140, 22147, 22155, 22162, 22168, 22175, 22182, 22190,
+    22195, 22203, 22206, 22213, 22220, 22225, 22230, 22236, 22245, 22253,
+    22262, 22269, 22273, 22277, 22284, 22293, 22299, 22307, 22314, 22320,
+    22328, 22335, 22338, 22343, 22348, 22352, 22357, 22364, 22372, 22380,
+    22389, 22395, 22402, 22407, 22411, 22418, 22423, 22428, 22434, 22441,
+    22448, 22452, 22460, 22465, 22469, 22476, 22484, 22490, 22498, 22507,
+    22514, 22521, 22528, 22535, 22542, 22550, 22557, 22562, 22567, 22575,
+    22582, 22588, 22592, 22598, 22604, 22611, 22618, 22623, 22628, 22632,
+    22635, 22640, 22647, 22654, 22660, 22667, 22674, 22682, 22686, 22692,
+    22699, 22704, 22709, 22713, 22720, 22724, 22732, 22739, 22745, 22750,
+    22753, 22759, 22767, 22774, 22784, 22790, 22801, 22813, 22824, 22831,
+    22835, 22845, 22851, 22858, 22866, 22873, 22881, 22889, 22897, 22903,
+    22910, 22916, 22925, 22930, 22934, 22940, 22946, 22953, 22960, 22967,
+    22972, 22977, 22984, 22990, 22999, 23006, 23011, 23019, 23025, 23029,
+    23036, 23039, 23045, 23050, 23054, 23060, 23067, 23073, 23080, 23087,
+    23092, 23098, 23105, 23111, 23119, 23129, 23140, 23151, 23157, 23161,
+    23165, 23171, 23177, 23184, 23190, 23198, 23203, 23207, 23213, 23218,
+    23225, 23233, 23239, 23244, 23250, 23258, 23269, 23278, 23285, 23291,
+    23299, 23306, 23314, 23324, 23329, 23332, 23337, 23343, 23349, 23355,
+    23361, 23365, 23369, 23376, 23382, 23388, 23395, 23403, 23406, 23412,
+    23418, 23424, 23430, 23438, 23446, 23455, 23459, 23464, 23467, 23472,
+    23479, 23486, 23494, 23499, 23506, 23514, 23520, 23525, 23531, 23537,
+    23544, 23548, 23553, 23558, 23564, 23570, 23577, 23583, 23588, 23594,
+    23597, 23604, 23609, 23616, 23624, 23629, 23635, 23642, 23647, 23653,
+    23662, 23670, 23674, 23679, 23684, 23689, 23693, 23698, 23703, 23711,
+    23718, 23727, 23737, 23747, 23758, 23769, 23781, 23791, 23798, 23806,
+    23814, 23823, 23827, 23834, 23844, 23855, 23861, 23868, 23874, 23883,
+    23887, 23895, 23903, 23909, 23915, 23922, 23933, 23940, 23947, 23952,
+    23958, 23962, 23968, 23974, 23981, 23987, 23995, 24002, 24009, 24017,
+    24023, 24030, 24033, 24037, 24042, 24048, 24053, 24059, 24065, 24074,
+    24081, 24091, 24095, 24103, 24113, 24123, 24134, 24143, 24149, 24155,
+    24162, 24170, 24180, 24187, 24194, 24199, 24207, 24216, 24222, 24230,
+    24234, 24242, 24245, 24250, 24256, 24264, 24270, 24276, 24284, 24296,
+    24305, 24315, 24322, 24328, 24335, 24342, 24348, 24357, 24366, 24376,
+    24384, 24391, 24398, 24404, 24411, 24417, 24422, 24426, 24431, 24437,
+    24444, 24452, 24460, 24466, 24473, 24481, 24492, 24499, 24505, 24515,
+    24522, 24528, 24533, 24538, 24546, 24554, 24560, 24565, 24574, 24583,
+    24589, 24597, 24603, 24607, 24617, 24624, 24632, 24639, 24645, 24655,
+    24660, 24666, 24672, 24680, 24687, 24693, 24700, 24703, 24715, 24725,
+    24732, 24743, 24753, 24762, 24772, 24780, 24784, 24790, 24795, 24806,
+    24815, 24822, 24832, 24842, 24853, 24863, 24874, 24882, 24889, 24895,
+    24902, 24909, 24919, 24923, 24931, 24938, 24946, 24953, 24961, 24968,
+    24977, 24986, 24996, 25004, 25011, 25019, 25026, 25034, 25043, 25050,
+    25057, 25065, 25073, 25082, 25085, 25092, 25103, 25111, 25118, 25126,
+    25133, 25145, 25151, 25160, 25167, 25176, 25186, 25190, 25199, 25206,
+    25214, 25222, 25233, 25242, 25252, 25257, 25261, 25266, 25272, 25279,
+    25287, 25294, 25303, 25312, 25322, 25330, 25340, 25352, 25357, 25363,
+    25371, 25379, 25388, 25393, 25400, 25407, 25417, 25428, 25434, 25440,
+    25447, 25454, 25460, 25468, 25479, 25484, 25492, 25496, 25505, 25515,
+    25524, 25530, 25538, 25545, 25553, 25562, 25573, 25580, 25587, 25595,
+    25602, 25609, 25615, 25622, 25627, 25635, 25639, 25643, 25649, 25656,
+    25665, 25671, 25678, 25685, 25695, 25706, 25714, 25723, 25733, 25738,
+    25743, 25749, 25755, 25762, 25770, 25777, 25785, 25793, 25797, 25805,
+    25813, 25818, 25826, 25830, 25836, 25842, 25849, 25857, 25863, 25870,
+    25876, 25883, 25887, 25893, 25898, 25905, 25913, 25922, 25926, 25931,
+    25937, 25944, 25952, 25958, 25965, 25971, 25981, 25986, 25992, 26000,
+    26010, 26018, 26021, 26028, 26038, 26046, 26056, 26066, 26077, 26086,
+    26094, 26103, 26112, 26120, 26129, 26138, 26146, 26154, 26165, 26177,
+    26182, 26189, 26196, 26204, 26213, 26224, 26230, 26239, 26250, 26260,
+    26270, 26281, 26289, 26294, 26301, 26309, 26320, 26326, 26333, 26342,
+    26352, 26360, 26369, 26376, 26382, 26386, 26392, 26398, 26406, 26412,
+    26420, 26426, 26435, 26443, 26452, 26461, 26466, 26472, 26478, 26486,
+    26493, 26500, 26507, 26516, 26524, 26532, 26542, 26550, 26559, 26566,
+    26570, 26576, 26583, 26589, 26598, 26605, 26610, 26616, 26624, 26630,
+    26637, 26642, 26648, 26657, 26663, 26670, 26675, 26681, 26685, 26690,
+    26697, 26705, 26714, 26724, 26734, 26745, 26756, 26768, 26779, 26788,
+    26794, 26801, 26810, 26818, 26826, 26830, 26836, 26843, 26850, 26855,
+    26860, 26868, 26877, 26883, 26891, 26898, 26908, 26916, 26922, 26930,
+    26937, 26947, 26954, 26962, 26968, 26976, 26980, 26987, 26996, 27006,
+    27012, 27020, 27029, 27040, 27047, 27058, 27065, 27076, 27085, 27096,
+    27106, 27114, 27122, 27127, 27136, 27145, 27152, 27159, 27167, 27173,
+    27180, 27190, 27198, 27206, 27216, 27226, 27234, 27241, 27250, 27259,
+    27270, 27282, 27291, 27298, 27306, 27314, 27322, 27331, 27338, 27344,
+    27349, 27354, 27361, 27366, 27373, 27381, 27387, 27392, 27399, 27407,
+    27415, 27426, 27432, 27442, 27448, 27454, 27461, 27466, 27472, 27481,
+    27491, 27501, 27512, 27522, 27533, 27540, 27551, 27559, 27568, 27577,
+    27582, 27591, 27599, 27609, 27618, 27630, 27636, 27642, 27649, 27656,
+    27666, 27674, 27681, 27689, 27695, 27702, 27709, 27717, 27724, 27733,
+    27741, 27746, 27753, 27761, 27767, 27772, 27779, 27783, 27792, 27798,
+    27805, 27813, 27822, 27827, 27834, 27842, 27846, 27855, 27867, 27877,
+    27884, 27892, 27898, 27905, 27912, 27920, 27927, 27935, 27942, 27950,
+    27958, 27969, 27979, 27988, 27995, 28002, 28007, 28014, 28022, 28028,
+    28035, 28040, 28047, 28054, 28063, 28071, 28079, 28089, 28095, 28100,
+    28106, 28112, 28118, 28125, 28130, 28137, 28143, 28151, 28155, 28162,
+    28167, 28174, 28181, 28189, 28196, 28204, 28213, 28218, 28225, 28233,
+    28240, 28245, 28252, 28260, 28265, 28272, 28279, 28287, 28295, 28304,
+    28312, 28322, 28333, 28339, 28346, 28353, 28358, 28364, 28369, 28376,
+    28384, 28394, 28402, 28408, 28414, 28420, 28432, 28439, 28450, 28456,
+    28463, 28467, 28472, 28476, 28484, 28494, 28503, 28510, 28519, 28527,
+    28539, 28546, 28555, 28563, 28569, 28574, 28579, 28587, 28593, 28600,
+    28604, 28610, 28617, 28622, 28625, 28629, 28634, 28641, 28649, 28655,
+    28661, 28666, 28673, 28679, 28688, 28698, 28703, 28715, 28725, 28734,
+    28743, 28751, 28760, 28769, 28777, 28782, 28788, 28793, 28798, 28805,
+    28813, 28819, 28825, 28830, 28836, 28843, 28849, 28860, 28867, 28875,
+    28880, 28891, 28895, 28900, 28904, 28912, 28919, 28927, 28932, 28937,
+    28945, 28951, 28959, 28963, 28971, 28977, 28985, 28993, 29003, 29014,
+    29019, 29028, 29037, 29043, 29050, 29058, 29063, 29069, 29074, 29080,
+    29087, 29094, 29105, 29111, 29118, 29126, 29130, 29137, 29144, 29152,
+    29158, 29163, 29169, 29176, 29181, 29187, 29192, 29198, 29204, 29212,
+    29217, 29223, 29227, 29234, 29244, 29253, 29259, 29267, 29277, 29288,
+    29295, 29306, 29315, 29325, 29335, 29345, 29355, 29361, 29366, 29373,
+    29381, 29385, 29390, 29396, 29402, 29408, 29416, 29426, 29431, 29438,
+    29446, 29451, 29456, 29461, 29470, 29480, 29486, 29494, 29499, 29505,
+    29512, 29518, 29525, 29534, 29542, 29548, 29555, 29560, 29569, 29575,
+    29582, 29589, 29597, 29604, 29613, 29621, 29629, 29638, 29648, 29659,
+    29670, 29681, 29693, 29705, 29715, 29721, 29730, 29737, 29745, 29752,
+    29760, 29769, 29773, 29778, 29783, 29789, 29795, 29802, 29811, 29819,
+    29830, 29836, 29842, 29848, 29852, 29857, 29863, 29868, 29876, 29885,
+    29893, 29905, 29913, 29922, 29926, 29931, 29939, 29948, 29955, 29963,
+    29969, 29973, 29979, 29985, 29990, 29997, 30005, 30010, 30014, 30025,
+    30030, 30035, 30039, 30046, 30053, 30061, 30067, 30076, 30083, 30093,
+    30101, 30108, 30115, 30120, 30127, 30134, 30142, 30147, 30154, 30162,
+    30167, 30174, 30181, 30188, 30197, 30208, 30216, 30226, 30236, 30246,
+    30254, 30265, 30274, 30284, 30293, 30302, 30312, 30318, 30325, 30331,
+    30338, 30346, 30350, 30356, 30363, 30368, 30372, 30377, 30384, 30391,
+    30399, 30407, 30418, 30425, 30431, 30438, 30446, 30455, 30462, 30467,
+    30476, 30483, 30490, 30498, 30506, 30513, 30519, 30524, 30532, 30538,
+    30545, 30552, 30559, 30564, 30570, 30578, 30585, 30591, 30599, 30605,
+    30614, 30623, 30629, 30637, 30644, 30649, 30655, 30661, 30669, 30675,
+    30680, 30687, 30695, 30701, 30707, 30714, 30719, 30725, 30732, 30740,
+    30748, 30754, 30759, 30766, 30773, 30781, 30789, 30795, 30801, 30809,
+    30814, 30822, 30828, 30834, 30842, 30852, 30861, 30871, 30878, 30882,
+    30891, 30898, 30905, 30913, 30921, 30926, 30932, 30939, 30944, 30951,
+    30957, 30962, 30968, 30977, 30981, 30988, 30996, 31004, 31009, 31015,
+    31021, 31028, 31033, 31039, 31046, 31055, 31061, 31067, 31074, 31080,
+    31087, 31094, 31101, 31109, 31113, 31118, 31124, 31131, 31138, 31146,
+    31152, 31157, 31165, 31170, 31177, 31183, 31189, 31194, 31199, 31205,
+    31211, 31216, 31222, 31229, 31233, 31242, 31247, 31251, 31256, 31261,
+    31267, 31275, 31281, 31286, 31293, 31302, 31312, 31320, 31326, 31334,
+    31342, 31351, 31358, 31363, 31370, 31377, 31385, 31393, 31404, 31408,
+    31416, 31425, 31435, 31440, 31446, 31454, 31461, 31466, 31473, 31480,
+    31488, 31495, 31504, 31510, 31514, 31520, 31527, 31535, 31540, 31548,
+    31552, 31558, 31565, 31571, 31577, 31584, 31591, 31598, 31603, 31610,
+    31617, 31623, 31630, 31637, 31642, 31649, 31657, 31663, 31666, 31670,
+    31674, 31679, 31684, 31691, 31696, 31701, 31709, 31715, 31721, 31725,
+    31736, 31748, 31754, 31761, 31769, 31777, 31784, 31795, 31801, 31807,
+    31814, 31820, 31827, 31830, 31836, 31842, 31849, 31858, 31867, 31876,
+    31884, 31892, 31900, 31906, 31913, 31917, 31923, 31930, 31935, 31939,
+    31946, 31954, 31962, 31971, 31982, 31992, 32001, 32011, 32017, 32024,
+    32029, 32033, 32037, 32041, 32047, 32054, 32060, 32068, 32073, 32078,
+    32086, 32093, 32104, 32116, 32123, 32131, 32140, 32149, 32159, 32165,
+    32173, 32180, 32188, 32198, 32207, 32217, 32224, 32233, 32244, 32254,
+    32264, 32275, 32285, 32297, 32306, 32316, 32324, 32331, 32338, 32346,
+    32356, 32363, 32371, 32377, 32384, 32394, 32402, 32411, 32420, 32430,
+    32437, 32445, 32455, 32464, 32471, 32476, 32483, 32491, 32499, 32510,
+    32518, 32526, 32535, 32547, 32555, 32564, 32570, 32575, 32585, 32593,
+    32601, 32610, 32616, 32624, 32632, 32636, 32641, 32647, 32656, 32663,
+    32667, 32675, 32679, 32685, 32694, 32704, 32713, 32720, 32726, 32737,
+    32749, 32756, 32764, 32772, 32779, 32788, 32793, 32798, 32808, 32812,
+    32820, 32828, 32837, 32844, 32852, 32858, 32864, 32869, 32875, 32880,
+    32885, 32891, 32898, 32909, 32920, 32929, 32939, 32947, 32952, 32959,
+    32965, 32971, 32978, 32982, 32987, 32994, 33004, 33013, 33022, 33032,
+    33042, 33053, 33065, 33073, 33082, 33090, 33096, 33107, 33115, 33124,
+    33136, 33145, 33155, 33162, 33171, 33183, 33190, 33202, 33212, 33223,
+    33235, 33244, 33254, 33262, 33270, 33280, 33292, 33303, 33312, 33322,
+    33334, 33346, 33357, 33363, 33373, 33384, 33391, 33400, 33409, 33419,
+    33429, 33440, 33449, 33458, 33468, 33474, 33482, 33491, 33500, 33508,
+    33519, 33526, 33538, 33547, 33555, 33562, 33570, 33581, 33593, 33605,
+    33617, 33626, 33635, 33644, 33654, 33665, 33674, 33685, 33692, 33700,
+    33708, 33717, 33726, 33733, 33743, 33751, 33760, 33769, 33779, 33786,
+    33796, 33807, 33814, 33822, 33830, 33839, 33849, 33860, 33871, 33883,
+    33890, 33900, 33910, 33920, 33931, 33937, 33946, 33956, 33963, 33973,
+    33983, 33994, 34006, 34018, 34030, 34037, 34045, 34055, 34067, 34077,
+    34086, 34094, 34103, 34114, 34126, 34137, 34147, 34158, 34169, 34176,
+    34184, 34192, 34201, 34210, 34221, 34229, 34240, 34250, 34261, 34270,
+    34279, 34289, 34299, 34311, 34322, 34330, 34339, 34349, 34361, 34370,
+    34380, 34390, 34397, 34406, 34416, 34428, 34438, 34448, 34457, 34467,
+    34478, 34490, 34502, 34512, 34520, 34528, 34538, 34549, 34555, 34564,
+    34573, 34583, 34590, 34598, 34606, 34615, 34623, 34632, 34641, 34651,
+    34662, 34674, 34681, 34690, 34698, 34708, 34719, 34728, 34738, 34750,
+    34758, 34769, 34779, 34790, 34800, 34808, 34817, 34826, 34836, 34846,
+    34857, 34868, 34880, 34891, 34901, 34911, 34921, 34932, 34944, 34951,
+    34959, 34967, 34979, 34988, 34996, 35005, 35012, 35020, 35023, 35030,
+    35037, 35046, 35056, 35067, 35075, 35082, 35090, 35098, 35107, 35114,
+    35125, 35136, 35144, 35153, 35163, 35174, 35186, 35198, 35211, 35221,
+    35228, 35238, 35249, 35257, 35267, 35279, 35286, 35295, 35305, 35313,
+    35320, 35329, 35337, 35346, 35354, 35364, 35375, 35380, 35389, 35401,
+    35413, 35420, 35428, 35436, 35445, 35454, 35464, 35474, 35485, 35495,
+    35507, 35518, 35525, 35536, 35545, 35553, 35562, 35568, 35577, 35588,
+    35599, 35609, 35621, 35631, 35638, 35648, 35655, 35667, 35676, 35686,
+    35694, 35706, 35714, 35723, 35732, 35741, 35752, 35763, 35775, 35785,
+    35790, 35801, 35807, 35818, 35825, 35832, 35840, 35846, 35855, 35862,
+    35871, 35881, 35891, 35901, 35913, 35922, 35932, 35940, 35947, 35955,
+    35959, 35964, 35975, 35986, 35998, 36004, 36014, 36025, 36034, 36044,
+    36051, 36058, 36067, 36076, 36086, 36096, 36108, 36119, 36128, 36137,
+    36144, 36152, 36162, 36171, 36183, 36194, 36203, 36213, 36224, 36231,
+    36239, 36250, 36258, 36267, 36274, 36286, 36295, 36305, 36313, 36324,
+    36336, 36344, 36354, 36365, 36374, 36384, 36391, 36403, 36412, 36422,
+    36432, 36440, 36448, 36458, 36469, 36477, 36487, 36498, 36507, 36514,
+    36522, 36530, 36539, 36548, 36557, 36567, 36576, 36586, 36596, 36608,
+    36620, 36632, 36640, 36650, 36661, 36672, 36682, 36691, 36696, 36703,
+    36710, 36720, 36728, 36738, 36746, 36754, 36763, 36773, 36784, 36795,
+    36802, 36810, 36818, 36825, 36834, 36844, 36854, 36865, 36875, 36887,
+    36896, 36906, 36914, 36921, 36932, 36944, 36951, 36959, 36966, 36975,
+    36985, 36994, 37004, 37012, 37020, 37029, 37033, 37043, 37052, 37063,
+    37074, 37086, 37096, 37107, 37119, 37130, 37140, 37149, 37156, 37165,
+    37173, 37184, 37196, 37206, 37218, 37230, 37242, 37254, 37266, 37274,
+    37283, 37293, 37301, 37313, 37325, 37336, 37346, 37357, 37366, 37379,
+    37386, 37395, 37406, 37416, 37428, 37438, 37446, 37457, 37464, 37472,
+    37480, 37491, 37503, 37512, 37522, 37529, 37539, 37547, 37558, 37568,
+    37580, 37588, 37597, 37607, 37616, 37626, 37635, 37643, 37653, 37662,
+    37674, 37686, 37697, 37709, 37719, 37730, 37741, 37753, 37765, 37774,
+    37785, 37795, 37806, 37818, 37827, 37838, 37850, 37862, 37874, 37885,
+    37895, 37903, 37912, 37918, 37926, 37935, 37942, 37949, 37960, 37970,
+    37981, 37993, 38005, 38014, 38024, 38032, 38039, 38047, 38055, 38066,
+    38075, 38083, 38092, 38102, 38113, 38125, 38136, 38140, 38147, 38156,
+    38166, 38174, 38183, 38193, 38200, 38209, 38218, 38228, 38238, 38249,
+    38257, 38268, 38280, 38292, 38303, 38315, 38327, 38335, 38347, 38354,
+    38363, 38372, 38382, 38392, 38400, 38407, 38416, 38426, 38437, 38448,
+    38456, 38463, 38473, 38484, 38493, 38503, 38511, 38518, 38526, 38536,
+    38546, 38555, 38566, 38576, 38587, 38597, 38605, 38614, 38625, 38636,
+    38648, 38656, 38665, 38674, 38684, 38694, 38704, 38716, 38725, 38732,
+    38740, 38748, 38758, 38769, 38780, 38792, 38802, 38813, 38822, 38833,
+    38843, 38855, 38866, 38874, 38882, 38892, 38903, 38912, 38922, 38933,
+    38944, 38956, 38968, 38979, 38991, 39003, 39011, 39019, 39028, 39035,
+    39047, 39057, 39067, 39078, 39089, 39097, 39108, 39118, 39125, 39133,
+    39144, 39154, 39166, 39173, 39183, 39195, 39206, 39214, 39222, 39231,
+    39242, 39252, 39261, 39271, 39283, 39291, 39301, 39311, 39322, 39329,
+    39338, 39347, 39358, 39368, 39376, 39382, 39388, 39398, 39406, 39415,
+    39423, 39430, 39437, 39446, 39456, 39467, 39475, 39483, 39492, 39501,
+    39511, 39523, 39534, 39544, 39555, 39561, 39568, 39579, 39583, 39591,
+    39597, 39603, 39610, 39616, 39623, 39630, 39635, 39639, 39646, 39652,
+    39658, 39665, 39671, 39679, 39684, 39688, 39694, 39703, 39713, 39724,
+    39735, 39746, 39758, 39765, 39776, 39786, 39797, 39808, 39820, 39832,
+    39843, 39846, 39850, 39855, 39861, 39867, 39873, 39879, 39886, 39894,
+    39900, 39904, 39910, 39914, 39921, 39930, 39941, 39946, 39952, 39956,
+    39963, 39969, 39974, 39978, 39983, 39988, 39997, 40001, 40005, 40011,
+    40018, 40024, 40032, 40039, 40050, 40056, 40063, 40070, 40078, 40083,
+    40090, 40099, 40105, 40113, 40123, 40134, 40141, 40148, 40155, 40163,
+    40172, 40183, 40195, 40204, 40209, 40215, 40222, 40228, 40234, 40241,
+    40250, 40260, 40270, 40282, 40293, 40303, 40312, 40323, 40331, 40340,
+    40350, 40360, 40371, 40383, 40393, 40405, 40416, 40424, 40433, 40445,
+    40453, 40462, 40471, 40481, 40488, 40497, 40507, 40514, 40520, 40526,
+    40532, 40540, 40549, 40555, 40567, 40576, 40588, 40594, 40598, 40605,
+    40611, 40616, 40623, 40631, 40634, 40638, 40645, 40649, 40656, 40664,
+    40670, 40676, 40686, 40691, 40698, 40705, 40711, 40718, 40723, 40730,
+    40738, 40744, 40749, 40756, 40763, 40773, 40784, 40794, 40803, 40813,
+    40821, 40828, 40838, 40848, 40857, 40867, 40875, 40880, 40889, 40896,
+    40907, 40914, 40924, 40933, 40944, 40954, 40965, 40977, 40989, 40997,
+    41005, 41013, 41021, 41030, 41039, 41046, 41056, 41067, 41073, 41079,
+    41083, 41088, 41094, 41101, 41108, 41115, 41123, 41131, 41137, 41144,
+    41149, 41156, 41166, 41173, 41181, 41187, 41194, 41202, 41207, 41214,
+    41223, 41231, 41241, 41249, 41258, 41266, 41273, 41282, 41288, 41297,
+    41306, 41316, 41322, 41329, 41339, 41343, 41348, 41356, 41365, 41370,
+    41378, 41385, 41393, 41399, 41405, 41413, 41418, 41425, 41428, 41434,
+    41443, 41451, 41458, 41464, 41471, 41476, 41484, 41493, 41497, 41500,
+    41506, 41513, 41517, 41521, 41527, 41532, 41541, 41548, 41556, 41564,
+    41571, 41579, 41585, 41591, 41598, 41606, 41611, 41618, 41626, 41632,
+    41641, 41650, 41656, 41660, 41666, 41671, 41675, 41682, 41687, 41694,
+    41702, 41708, 41713, 41719, 41726, 41733, 41738, 41748, 41754, 41759,
+    41766, 41773, 41781, 41786, 41791, 41797, 41804, 41810, 41815, 41821,
+    41827, 41834, 41842, 41847, 41854, 41862, 41870, 41876, 41883, 41888,
+    41894, 41899, 41904, 41911, 41919, 41924, 41930, 41936, 41942, 41949,
+    41956, 41962, 41969, 41976, 41984, 41992, 42001, 42009, 42019, 42029,
+    42036, 42044, 42052, 42061, 42069, 42080, 42088, 42099, 42107, 42113,
+    42121, 42129, 42138, 42145, 42150, 42155, 42161, 42166, 42172, 42177,
+    42185, 42191, 42197, 42205, 42214, 42225, 42231, 42238, 42243, 42248,
+    42256, 42261, 42268, 42274, 42281, 42289, 42293, 42299, 42306, 42311,
+    42315, 42320, 42327, 42336, 42346, 42354, 42359, 42364, 42369, 42375,
+    42383, 42392, 42403, 42410, 42416, 42423, 42430, 42438, 42444, 42450,
+    42455, 42462, 42468, 42476, 42485, 42491, 42499, 42509, 42519, 42528,
+    42538, 42548, 42559, 42566, 42574, 42583, 42591, 42600, 42610, 42616,
+    42625, 42630, 42637, 42642, 42649, 42655, 42660, 42664, 42671, 42679,
+    42684, 42689, 42697, 42704, 42711, 42720, 42728, 42737, 42746, 42756,
+    42762, 42770, 42775, 42782, 42786, 42793, 42798, 42805, 42813, 42819,
+    42824, 42831, 42839, 42845, 42850, 42857, 42864, 42873, 42881, 42890,
+    42898, 42909, 42918, 42923, 42930, 42935, 42942, 42949, 42955, 42962,
+    42969, 42977, 42984, 42992, 42997, 43004, 43012, 43021, 43027, 43033,
+    43041, 43047, 43054, 43061, 43069, 43078, 43086, 43091, 43098, 43106,
+    43111, 43122, 43129, 43135, 43141, 43147, 43155, 43159, 43162, 43168,
+    43173, 43180, 43187, 43198, 43210, 43218, 43221, 43225, 43230, 43235,
+    43242, 43248, 43254, 43258, 43264, 43272, 43275, 43279, 43283, 43287,
+    43292, 43299, 43307, 43315, 43319, 43325, 43335, 43345, 43356, 43367,
+    43375, 43382, 43390, 43394, 43403, 43413, 43424, 43435, 43446, 43451,
+    43459, 43469, 43476, 43484, 43492, 43502, 43506, 43511, 43521, 43528,
+    43537, 43544, 43547, 43555, 43562, 43567, 43573, 43579, 43586, 43590,
+    43593, 43600, 43606, 43614, 43621, 43629, 43633, 43639, 43646, 43651,
+    43655, 43659, 43664, 43669, 43675, 43680, 43686, 43695, 43702, 43711,
+    43715, 43721, 43727, 43734, 43739, 43744, 43751, 43761, 43769, 43776,
+    43785, 43793, 43802, 43812, 43817, 43822, 43828, 43834, 43841, 43848,
+    43854, 43861, 43865, 43872, 43881, 43888, 43896, 43902, 43911, 43916,
+    43922, 43928, 43935, 43942, 43950, 43954, 43959, 43966, 43975, 43984,
+    43991, 43997, 44008, 44017, 44025, 44034, 44043, 44053, 44060, 44063,
+    44071, 44075, 44082, 44089, 44095, 44099, 44105, 44112, 44119, 44127,
+    44131, 44138, 44148, 44153, 44159, 44165, 44171, 44179, 44186, 44193,
+    44201, 44208, 44216, 44225, 44233, 44242, 44253, 44260, 44265, 44272,
+    44280, 44286, 44293, 44297, 44303, 44308, 44317, 44321, 44324, 44330,
+    44333, 44338, 44342, 44348, 44356, 44360, 44366, 44373, 44380, 44385,
+    44391, 44396, 44400, 44407, 44412, 44416, 44425, 44428, 44434, 44441,
+    44448, 44456, 44462, 44466, 44470, 44476, 44482, 44490, 44494, 44500,
+    44505, 44511, 44517, 44524, 44530, 44537, 44542, 44548, 44557, 44568,
+    44575, 44581, 44588, 44596, 44602, 44606, 44613, 44617, 44622, 44631,
+    44636, 44642, 44646, 44652, 44660, 44666, 44673, 44679, 44687, 44695,
+    44702, 44706, 44710, 44716, 44723, 44728, 44732, 44741, 44747, 44753,
+    44760, 44764, 44772, 44781, 44790, 44797, 44801, 44806, 44811, 44817,
+    44823, 44828, 44832, 44840, 44849, 44857, 44866, 44871, 44875, 44881,
+    44888, 44893, 44896, 44904, 44912, 44920, 44924, 44931, 44935, 44940,
+    44946, 44953, 44961, 44967, 44974, 44985, 44989, 44997, 45006, 45014,
+    45023, 45031, 45037, 45041, 45047, 45056, 45064, 45068, 45074, 45081,
+    45091, 45098, 45106, 45111, 45116, 45120, 45125, 45129, 45136, 45142,
+    45148, 45153, 45161, 45168, 45174, 45181, 45188, 45197, 45203, 45210,
+    45217, 45225, 45237, 45242, 45248, 45255, 45261, 45265, 45271, 45278,
+    45283, 45288, 45296, 45305, 45311, 45316, 45322, 45328, 45337, 45345,
+    45352, 45361, 45366, 45373, 45381, 45387, 45395, 45403, 45409, 45416,
+    45424, 45431, 45437, 45445, 45454, 45463, 45470, 45478, 45487, 45493,
+    45500, 45509, 45516, 45524, 45533, 45540, 45548, 45556, 45565, 45573,
+    45581, 45590, 45598, 45608, 45612, 45618, 45623, 45634, 45646, 45657,
+    45664, 45672, 45680, 45689, 45696, 45704, 45712, 45721, 45727, 45734,
+    45741, 45749, 45759, 45764, 45772, 45781, 45791, 45801, 45812, 45822,
+    45831, 45838, 45843, 45851, 45860, 45869, 45879, 45885, 45892, 45899,
+    45907, 45914, 45921, 45929, 45938, 45948, 45954, 45961, 45967, 45975,
+    45985, 45994, 46004, 46013, 46017, 46022, 46026, 46032, 46037, 46041,
+    46047, 46055, 46064, 46071, 46077, 46084, 46090, 46094, 46104, 46111,
+    46119, 46125, 46133, 46142, 46149, 46155, 46163, 46172, 46181, 46188,
+    46194, 46203, 46213, 46221, 46229, 46238, 46247, 46254, 46261, 46269,
+    46278, 46283, 46292, 46300, 46308, 46315, 46327, 46337, 46346, 46353,
+    46361, 46367, 46373, 46381, 46387, 46394, 46400, 46407, 46414, 46422,
+    46430, 46440, 46450, 46461, 46471, 46483, 46490, 46498, 46505, 46514,
+    46524, 46530, 46541, 46553, 46561, 46572, 46581, 46588, 46594, 46598,
+    46604, 46611, 46617, 46621, 46628, 46638, 46650, 46662, 46673, 46680,
+    46688, 46696, 46705, 46711, 46718, 46729, 46736, 46743, 46748, 46756,
+    46760, 46765, 46772, 46780, 46786, 46794, 46803, 46812, 46822, 46833,
+    46839, 46846, 46857, 46864, 46872, 46880, 46889, 46893, 46903, 46915,
+    46927, 46935, 46943, 46953, 46962, 46969, 46978, 46988, 46996, 47003,
+    47012, 47022, 47033, 47044, 47054, 47062, 47069, 47080, 47091, 47100,
+    47110, 47120, 47128, 47136, 47141, 47147, 47154, 47160, 47168, 47177,
+    47183, 47188, 47195, 47201, 47209, 47218, 47225, 47236, 47244, 47252,
+    47260, 47266, 47270, 47281, 47290, 47298, 47308, 47317, 47328, 47340,
+    47350, 47358, 47368, 47378, 47383, 47390, 47396, 47408, 47419, 47431,
+    47443, 47448, 47454, 47461, 47464, 47470, 47477, 47483, 47489, 47494,
+    47502, 47514, 47525, 47535, 47541, 47548, 47555, 47563, 47573, 47581,
+    47590, 47600, 47604, 47609, 47616, 47622, 47631, 47638, 47642, 47648,
+    47654, 47661, 47670, 47678, 47683, 47690, 47698, 47705, 47717, 47721,
+    47728, 47734, 47742, 47751, 47761, 47773, 47784, 47791, 47800, 47810,
+    47816, 47826, 47834, 47844, 47856, 47866, 47875, 47885, 47894, 47901,
+    47907, 47915, 47924, 47933, 47943, 47950, 47957, 47965, 47974, 47984,
+    47991, 47999, 48005, 48013, 48022, 48032, 48043, 48049, 48060, 48068,
+    48075, 48082, 48091, 48101, 48112, 48122, 48130, 48135, 48141, 48148,
+    48156, 48168, 48175, 48184, 48194, 48204, 48215, 48225, 48236, 48243,
+    48251, 48259, 48268, 48272, 48277, 48283, 48291, 48297, 48302, 48308,
+    48316, 48324, 48329, 48341, 48349, 48356, 48364, 48372, 48382, 48392,
+    48403, 48409, 48416, 48423, 48431, 48441, 48451, 48458, 48465, 48475,
+    48486, 48495, 48505, 48513, 48520, 48528, 48537, 48546, 48556, 48567,
+    48579, 48590, 48600, 48611, 48617, 48625, 48634, 48641, 48648, 48656,
+    48664, 48673, 48679, 48688, 48698, 48708, 48719, 48730, 48742, 48750,
+    48758, 48767, 48776, 48783, 48795, 48804, 48810, 48817, 48824, 48832,
+    48840, 48846, 48850, 48855, 48862, 48870, 48880, 48887, 48895, 48905,
+    48914, 48925, 48936, 48943, 48951, 48958, 48964, 48972, 48981, 48988,
+    48996, 49007, 49019, 49027, 49034, 49043, 49050, 49059, 49068, 49078,
+    49088, 49096, 49104, 49115, 49126, 49132, 49140, 49150, 49161, 49167,
+    49175, 49184, 49191, 49197, 49205, 49214, 49220, 49230, 49238, 49247,
+    49256, 49265, 49275, 49283, 49292, 49299, 49308, 49313, 49322, 49333,
+    49345, 49356, 49368, 49379, 49388, 49398, 49408, 49419, 49430, 49443,
+    49451, 49461, 49470, 49476, 49484, 49492, 49501, 49511, 49517, 49525,
+    49532, 49541, 49552, 49563, 49570, 49578, 49588, 49599, 49610, 49617,
+    49626, 49635, 49645, 49655, 49666, 49678, 49686, 49694, 49701, 49709,
+    49716, 49724, 49732, 49741, 49751, 49757, 49764, 49769, 49777, 49783,
+    49790, 49796, 49803, 49811, 49817, 49827, 49835, 49841, 49848, 49855,
+    49863, 49870, 49878, 49886, 49896, 49902, 49910, 49916, 49924, 49927,
+    49936, 49944, 49950, 49958, 49966, 49976, 49984, 49993, 50002, 50012,
+    50021, 50031, 50042, 50050, 50060, 50067, 50075, 50079, 50086, 50095,
+    50103, 50109, 50116, 50122, 50130, 50139, 50144, 50152, 50160, 50169,
+    50176, 50184, 50190, 50197, 50206, 50213, 50222, 50227, 50236, 50244,
+    50248, 50253, 50258, 50268, 50277, 50283, 50288, 50295, 50303, 50311,
+    50319, 50331, 50338, 50350, 50360, 50366, 50374, 50377, 50381, 50385,
+    50391, 50395, 50402, 50408, 50416, 50423, 50428, 50434, 50442, 50452,
+    50463, 50472, 50484, 50495, 50504, 50511, 50520, 50532, 50542, 50553,
+    50560, 50568, 50576, 50585, 50588, 50594, 50602, 50611, 50620, 50627,
+    50633, 50640, 50647, 50652, 50659, 50668, 50677, 50683, 50692, 50703,
+    50710, 50714, 50725, 50731, 50738, 50746, 50753, 50761, 50770, 50778,
+    50788, 50792, 50798, 50805, 50813, 50821, 50824, 50828, 50837, 50848,
+    50858, 50863, 50871, 50881, 50891, 50902, 50912, 50922, 50927, 50933,
+    50939, 50945, 50948, 50952, 50957, 50962, 50968, 50972, 50978, 50983,
+    50989, 50995, 51002, 51010, 51019, 51023, 51030, 51035, 51041, 51048,
+    51055, 51058, 51065, 51074, 51082, 51090, 51100, 51109, 51115, 51121,
+    51127, 51134, 51138, 51146, 51150, 51156, 51164, 51173, 51182, 51193,
+    51203, 51212, 51222, 51230, 51238, 51249, 51260, 51271, 51280, 51289,
+    51296, 51300, 51304, 51309, 51321, 51331, 51338, 51346, 51355, 51367,
+    51375, 51387, 51396, 51408, 51418, 51427, 51438, 51450, 51460, 51472,
+    51483, 51494, 51500, 51511, 51519, 51527, 51535, 51544, 51554, 51561,
+    51568, 51577, 51587, 51596, 51600, 51607, 51616, 51624, 51631, 51640,
+    51651, 51661, 51672, 51681, 51691, 51701, 51712, 51720, 51729, 51741,
+    51751, 51762, 51773, 51781, 51791, 51799, 51808, 51818, 51828, 51839,
+    51844, 51854, 51864, 51876, 51886, 51897, 51909, 51916, 51924, 51934,
+    51945, 51954, 51964, 51975, 51987, 51996, 52006, 52014, 52024, 52034,
+    52045, 52056, 52065, 52074, 52083, 52094, 52102, 52113, 52121, 52131,
+    52143, 52155, 52168, 52173, 52183, 52190, 52199, 52208, 52218, 52228,
+    52239, 52246, 52253, 52261, 52269, 52280, 52288, 52299, 52307, 52316,
+    52323, 52332, 52342, 52346, 52352, 52361, 52371, 52379, 52387, 52391,
+    52396, 52403, 52411, 52419, 52430, 52442, 52450, 52458, 52464, 52474,
+    52485, 52491, 52499, 52506, 52515, 52524, 52533, 52543, 52551, 52561,
+    52573, 52580, 52588, 52598, 52608, 52617, 52629, 52637, 52646, 52657,
+    52666, 52674, 52684, 52694, 52700, 52710, 52722, 52730, 52739, 52749,
+    52758, 52766, 52775, 52785, 52793, 52802, 52814, 52824, 52831, 52840,
+    52850, 52858, 52868, 52879, 52889, 52897, 52906, 52913, 52921, 52929,
+    52938, 52949, 52961, 52969, 52976, 52984, 52992, 53004, 53012, 53021,
+    53032, 53041, 53048, 53057, 53067, 53077, 53088, 53098, 53106, 53110,
+    53122, 53129, 53138, 53148, 53159, 53171, 53178, 53188, 53198, 53210,
+    53219, 53229, 53239, 53249, 53260, 53271, 53283, 53294, 53302, 53311,
+    53320, 53330, 53340, 53348, 53356, 53365, 53374, 53384, 53391, 53400,
+    53408, 53415, 53427, 53436, 53446, 53454, 53465, 53477, 53488, 53498,
+    53509, 53516, 53525, 53535, 53545, 53556, 53564, 53572, 53582, 53593,
+    53604, 53616, 53626, 53634, 53644, 53655, 53665, 53676, 53687, 53699,
+    53711, 53723, 53734, 53746, 53754, 53763, 53771, 53778, 53789, 53801,
+    53810, 53820, 53828, 53836, 53842, 53849, 53854, 53861, 53867, 53872,
+    53880, 53889, 53896, 53900, 53905, 53910, 53914, 53919, 53924, 53931,
+    53939, 53949, 53958, 53966, 53972, 53979, 53990, 53999, 54008, 54017,
+    54023, 54031, 54040, 54045, 54051, 54059, 54065, 54072, 54080, 54089,
+    54096, 54104, 54111, 54119, 54129, 54135, 54143, 54150, 54157, 54163,
+    54171, 54179, 54189, 54198, 54206, 54213, 54220, 54228, 54236, 54243,
+    54252, 54257, 54265, 54271, 54275, 54281, 54287, 54293, 54300, 54305,
+    54313, 54319, 54327, 54336, 54346, 54353, 54362, 54370, 54379, 54387,
+    54398, 54408, 54419, 54428, 54437, 54442, 54448, 54454, 54461, 54468,
+    54471, 54475, 54480, 54484, 54487, 54495, 54501, 54509, 54514, 54520,
+    54528, 54532, 54539, 54544, 54550, 54554, 54558, 54564, 54571, 54576,
+    54581, 54588, 54596, 54602, 54609, 54613, 54618, 54623, 54631, 54643,
+    54655, 54663, 54672, 54680, 54688, 54697, 54706, 54716, 54726, 54734,
+    54743, 54749, 54757, 54760, 54766, 54773, 54781, 54789, 54798, 54802,
+    54808, 54815, 54821, 54826, 54832, 54836, 54841, 54847, 54851, 54857,
+    54865, 54869, 54877, 54885, 54893, 54900, 54905, 54913, 54920, 54928,
+    54932, 54937, 54943, 54947, 54955, 54962, 54973, 54982, 54988, 54994,
+    55001, 55005, 55010, 55016, 55023, 55026, 55030, 55034, 55040, 55046,
+    55053, 55060, 55067, 55075, 55081, 55086, 55093, 55101, 55109, 55118,
+    55124, 55129, 55137, 55144, 55150, 55154, 55158, 55163, 55171, 55178,
+    55183, 55187, 55195, 55203, 55209, 55217, 55227, 55235, 55242, 55250,
+    55260, 55269, 55277, 55285, 55294, 55299, 55307, 55315, 55325, 55335,
+    55343, 55351, 55359, 55367, 55376, 55384, 55389, 55394, 55399, 55403,
+    55408, 55413, 55419, 55425, 55429, 55434, 55441, 55449, 55455, 55459,
+    55466, 55474, 55480, 55489, 55496, 55504, 55509, 55514, 55522, 55529,
+    55537, 55543, 55547, 55552, 55558, 55566, 55578, 55587, 55592, 55597,
+    55603, 55610, 55616, 55623, 55634, 55638, 55646, 55655, 55665, 55671,
+    55678, 55685, 55693, 55698, 55705, 55710, 55715, 55720, 55727, 55735,
+    55743, 55748, 55755, 55762, 55770, 55778, 55786, 55792, 55798, 55804,
+    55810, 55817, 55825, 55830, 55836, 55844, 55849, 55857, 55864, 55871,
+    55878, 55886, 55895, 55899, 55906, 55911, 55916, 55921, 55927, 55932,
+    55939, 55947, 55953, 55958, 55965, 55973, 55979, 55984, 55991, 55999,
+    56007, 56013, 56017, 56024, 56032, 56037, 56042, 56048, 56054, 56061,
+    56067, 56075, 56082, 56089, 56094, 56100, 56105, 56110, 56117, 56125,
+    56131, 56135, 56142, 56150, 56157, 56165, 56172, 56180, 56185, 56192,
+    56200, 56205, 56211, 56216, 56223, 56231, 56237, 56245, 56249, 56256,
+    56261, 56266, 56272, 56276, 56284, 56291, 56298, 56306, 56314, 56319,
+    56324, 56332, 56339, 56350, 56356, 56359, 56364, 56370, 56376, 56381,
+    56388, 56392, 56399, 56406, 56409, 56415, 56422, 56429, 56434, 56440,
+    56447, 56452, 56456, 56462, 56469, 56474, 56478, 56485, 56490, 56494,
+    56497, 56501, 56506, 56510, 56514, 56519, 56523, 56529, 56532, 56538,
+    56542, 56547, 56551, 56557, 56565, 56569, 56573, 56581, 56588, 56593,
+    56597, 56603, 56610, 56615, 56618, 56622, 56627, 56631, 56638, 56646,
+    56650, 56657, 56662, 56665, 56669, 56674, 56680, 56689, 56699, 56710,
+    56720, 56727, 56735, 56741, 56747, 56751, 56756, 56760, 56767, 56773,
+    56780, 56787, 56792, 56797, 56803, 56810, 56814, 56819, 56826, 56832,
+    56837, 56844, 56852, 56860, 56869, 56875, 56880, 56888, 56897, 56903,
+    56906, 56910, 56916, 56920, 56924, 56929, 56936, 56945, 56956, 56964,
+    56972, 56980, 56986, 56994, 57003, 57010, 57021, 57029, 57037, 57041,
+    57046, 57053, 57062, 57067, 57073, 57076, 57083, 57087, 57094, 57102,
+    57109, 57114, 57119, 57128, 57132, 57138, 57144, 57151, 57158, 57167,
+    57174, 57182, 57187, 57194, 57202, 57206, 57213, 57218, 57225, 57232,
+    57242, 57253, 57259, 57269, 57275, 57279, 57284, 57289, 57297, 57302,
+    57308, 57315, 57321, 57327, 57331, 57340, 57348, 57355, 57366, 57374,
+    57383, 57387, 57390, 57395, 57400, 57406, 57412, 57416, 57421, 57424,
+    57429, 57438, 57445, 57453, 57457, 57463, 57469, 57476, 57484, 57491,
+    57499, 57507, 57517, 57529, 57536, 57547, 57555, 57565, 57577, 57586,
+    57595, 57604, 57614, 57621, 57630, 57640, 57644, 57651, 57659, 57669,
+    57675, 57681, 57685, 57689, 57694, 57699, 57705, 57709, 57715, 57720,
+    57726, 57733, 57741, 57749, 57753, 57759, 57766, 57773, 57781, 57787,
+    57796, 57806, 57813, 57823, 57828, 57835, 57843, 57852, 57861, 57872,
+    57880, 57889, 57892, 57896, 57901, 57907, 57914, 57922, 57928, 57936,
+    57945, 57954, 57965, 57972, 57983, 57991, 58003, 58013, 58022, 58033,
+    58039, 58045, 58052, 58060, 58071, 58074, 58082, 58086, 58089, 58095,
+    58103, 58107, 58114, 58120, 58131, 58136, 58144, 58154, 58160, 58168,
+    58174, 58180, 58186, 58191, 58198, 58206, 58215, 58225, 58236, 58247,
+    58253, 58260, 58267, 58277, 58283, 58290, 58295, 58301, 58306, 58313,
+    58319, 58325, 58330, 58337, 58345, 58353, 58362, 58370, 58377, 58386,
+    58396, 58407, 58415, 58423, 58433, 58445, 58456, 58467, 58476, 58486,
+    58498, 58509, 58521, 58529, 58539, 58550, 58561, 58570, 58576, 58584,
+    58591, 58600, 58607, 58614, 58619, 58626, 58635, 58645, 58653, 58661,
+    58670, 58677, 58685, 58693, 58702, 58712, 58720, 58729, 58735, 58743,
+    58746, 58752, 58760, 58769, 58776, 58787, 58795, 58804, 58814, 58824,
+    58835, 58846, 58851, 58857, 58864, 58871, 58877, 58880, 58887, 58895,
+    58903, 58911, 58921, 58924, 58928, 58936, 58945, 58955, 58964, 58972,
+    58982, 58986, 58995, 59004, 59009, 59015, 59021, 59028, 59033, 59035,
+    59040, 59046, 59053, 59061, 59069, 59078, 59090, 59100, 59107, 59113,
+    59121, 59130, 59139, 59150, 59162, 59171, 59178, 59183, 59191, 59200,
+    59211, 59216, 59222, 59230, 59236, 59246, 59253, 59261, 59269, 59279,
+    59285, 59294, 59302, 59308, 59316, 59323, 59331, 59339, 59348, 59359,
+    59369, 59378, 59384, 59393, 59400, 59407, 59415, 59421, 59428, 59437,
+    59448, 59457, 59465, 59472, 59480, 59488, 59493, 59501, 59510, 59518,
+    59527, 59537, 59547, 59555, 59562, 59571, 59575, 59583, 59591, 59599,
+    59608, 59612, 59617, 59624, 59632, 59635, 59642, 59651, 59662, 59670,
+    59677, 59685, 59691, 59698, 59705, 59713, 59721, 59729, 59738, 59748,
+    59758, 59769, 59777, 59789, 59798, 59804, 59811, 59820, 59831, 59841,
+    59847, 59855, 59863, 59872, 59880, 59889, 59898, 59908, 59915, 59923,
+    59930, 59939, 59949, 59960, 59968, 59975, 59982, 59989, 59998, 60003,
+    60011, 60019, 60022, 60029, 60037, 60046, 60054, 60056, 60062, 60069,
+    60076, 60084, 60089, 60096, 60104, 60113, 60119, 60125, 60133, 60141,
+    60151, 60163, 60170, 60182, 60191, 60200, 60208, 60217, 60224, 60232,
+    60239, 60247, 60255, 60264, 60273, 60283, 60289, 60296, 60303, 60310,
+    60318, 60327, 60338, 60349, 60361, 60367, 60376, 60387, 60399, 60409,
+    60418, 60428, 60438, 60449, 60457, 60469, 60476, 60485, 60495, 60506,
+    60518, 60521, 60529, 60539, 60545, 60553, 60561, 60570, 60575, 60582,
+    60589, 60595, 60602, 60609, 60618, 60627, 60634, 60642, 60653, 60661,
+    60670, 60681, 60686, 60693, 60702, 60712, 60716, 60725, 60731, 60738,
+    60745, 60753, 60760, 60765, 60774, 60782, 60790, 60796, 60804, 60811,
+    60822, 60830, 60841, 60849, 60858, 60864, 60871, 60881, 60892, 60899,
+    60907, 60915, 60925, 60931, 60939, 60949, 60960, 60969, 60976, 60983,
+    60991, 60999, 61008, 61018, 61024, 61032, 61039, 61047, 61058, 61070,
+    61078, 61086, 61095, 61101, 61110, 61116, 61124, 61129, 61138, 61145,
+    61153, 61162, 61168, 61175, 61183, 61194, 61202, 61211, 61222, 61228,
+    61236, 61245, 61255, 61262, 61270, 61276, 61281, 61289, 61297, 61307,
+    61313, 61320, 61328, 61337, 61344, 61350, 61357, 61363, 61371, 61379,
+    61389, 61395, 61403, 61412, 61422, 61430, 61439, 61448, 61454, 61461,
+    61469, 61476, 61481, 61487, 61493, 61500, 61506, 61513, 61520, 61528,
+    61534, 61542, 61549, 61557, 61566, 61571, 61578, 61586, 61596, 61607,
+    61619, 61625, 61634, 61645, 61656, 61668, 61676, 61685, 61693, 61703,
+    61713, 61725, 61736, 61742, 61749, 61757, 61763, 61771, 61779, 61787,
+    61794, 61802, 61813, 61825, 61833, 61839, 61849, 61858, 61866, 61874,
+    61883, 61890, 61898, 61904, 61914, 61921, 61927, 61935, 61945, 61957,
+    61964, 61971, 61978, 61987, 61992, 62000, 62010, 62021, 62028, 62036,
+    62045, 62054, 62062, 62068, 62075, 62086, 62098, 62106, 62114, 62123,
+    62131, 62141, 62146, 62152, 62156, 62162, 62169, 62178, 62182, 62191,
+    62196, 62204, 62213, 62225, 62234, 62243, 62250, 62258, 62266, 62274,
+    62283, 62291, 62299, 62308, 62318, 62325, 62333, 62341, 62353, 62360,
+    62368, 62375, 62382, 62390, 62397, 62402, 62408, 62413, 62420, 62425,
+    62432, 62440, 62452, 62460, 62468, 62475, 62481, 62487, 62494, 62501,
+    62509, 62517, 62526, 62533, 62543, 62553, 62564, 62575, 62586, 62592,
+    62599, 62604, 62613, 62621, 62630, 62638, 62644, 62655, 62665, 62676,
+    62685, 62688, 62697, 62708, 62712, 62717, 62723, 62729, 62736, 62743,
+    62750, 62758, 62762, 62767, 62773, 62780, 62787, 62794, 62797, 62803,
+    62810, 62816, 62823, 62828, 62834, 62843, 62854, 62859, 62865, 62874,
+    62881, 62886, 62893, 62901, 62909, 62918, 62924, 62935, 62947, 62955,
+    62964, 62974, 62984, 62992, 62998, 63005, 63012, 63020, 63028, 63038,
+    63044, 63052, 63060, 63069, 63076, 63082, 63091, 63099, 63107, 63117,
+    63126, 63135, 63142, 63150, 63160, 63168, 63175, 63180, 63188, 63194,
+    63201, 63209, 63218, 63229, 63239, 63248, 63259, 63270, 63282, 63288,
+    63295, 63301, 63309, 63314, 63322, 63330, 63339, 63348, 63358, 63368,
+    63377, 63387, 63395, 63404, 63412, 63421, 63428, 63432, 63438, 63445,
+    63453, 63460, 63469, 63477, 63482, 63490, 63495, 63502, 63511, 63517,
+    63527, 63536, 63545, 63550, 63558, 63567, 63576, 63586, 63594, 63600,
+    63606, 63613, 63622, 63630, 63638, 63644, 63648, 63653, 63663, 63671,
+    63680, 63690, 63695, 63701, 63708, 63716, 63725, 63734, 63744, 63754,
+    63765, 63776, 63788, 63798, 63808, 63818, 63828, 63839, 63846, 63853,
+    63856, 63860, 63867, 63875, 63881, 63886, 63894, 63900, 63908, 63918,
+    63922, 63932, 63941, 63952, 63960, 63965, 63974, 63982, 63990, 64000,
+    64010, 64015, 64022, 64030, 64038, 64047, 64056, 64063, 64072, 64076,
+    64081, 64087, 64094, 64103, 64108, 64114, 64120, 64127, 64136, 64148,
+    64154, 64161, 64168, 64176, 64179, 64189, 64200, 64205, 64212, 64220,
+    64227, 64237, 64248, 64260, 64265, 64272, 64276, 64287, 64299, 64306,
+    64314, 64322, 64331, 64339, 64348, 64355, 64363, 64368, 64379, 64387,
+    64396, 64406, 64417, 64423, 64431, 64440, 64451, 64458, 64463, 64471,
+    64481, 64490, 64496, 64502, 64510, 64519, 64528, 64539, 64549, 64556,
+    64564, 64570, 64578, 64587, 64598, 64606, 64615, 64624, 64634, 64643,
+    64649, 64656, 64664, 64674, 64680, 64687, 64696, 64706, 64713, 64721,
+    64728, 64737, 64745, 64756, 64763, 64771, 64779, 64788, 64797, 64806,
+    64817, 64824, 64832, 64841, 64850, 64860, 64866, 64873, 64880, 64884,
+    64894, 64901, 64909, 64918, 64928, 64937, 64947, 64955, 64963, 64971,
+    64980, 64991, 65002, 65011, 65017, 65025, 65034, 65044, 65051, 65059,
+    65068, 65077, 65087, 65092, 65099, 65107, 65115, 65121, 65127, 65134,
+    65141, 65150, 65160, 65170, 65180, 65188, 65195, 65204, 65214, 65224,
+    65235, 65243, 65249, 65260, 65268, 65275, 65285, 65290, 65296, 65302,
+    65307, 65314, 65323, 65331, 65342, 65350, 65356, 65360, 65366, 65373,
+    65378, 65384, 65394, 65402, 65412, 65418, 65424, 65432, 65441, 65448,
+    65455, 65463, 65472, 65482, 65491, 65501, 65512, 65518, 65528, 65537,
+    65548, 65560, 65568, 65577, 65584, 65594, 65603, 65611, 65621, 65632,
+    65637, 65645, 65651, 65659, 65670, 65682, 65689, 65697, 65706, 65716,
+    65727, 65738, 65750, 65760, 65772, 65784, 65796, 65807, 65813, 65822,
+    65830, 65837, 65847, 65853, 65860, 65867, 65875, 65881, 65888, 65897,
+    65907, 65915, 65926, 65938, 65949, 65957, 65967, 65974, 65982, 65990,
+    65999, 66006, 66018, 66027, 66037, 66045, 66056, 66068, 66079, 66086,
+    66094, 66102, 66111, 66119, 66128, 66137, 66147, 66156, 66166, 66170,
+    66178, 66189, 66198, 66204, 66212, 66220, 66229, 66238, 66245, 66250,
+    66256, 66263, 66270, 66278, 66288, 66296, 66305, 66312, 66321, 66328,
+    66337, 66346, 66356, 66366, 66377, 66387, 66396, 66406, 66415, 66424,
+    66430, 66436, 66444, 66453, 66460, 66469, 66479, 66488, 66499, 66505,
+    66512, 66520, 66528, 66538, 66545, 66555, 66567, 66575, 66584, 66589,
+    66596, 66605, 66615, 66625, 66633, 66643, 66654, 66664, 66670, 66682,
+    66693, 66701, 66708, 66717, 66725, 66734, 66743, 66753, 66764, 66773,
+    66782, 66789, 66797, 66806, 66815, 66821, 66824, 66831, 66839, 66846,
+    66854, 66858, 66864, 66871, 66877, 66884, 66892, 66896, 66904, 66914,
+    66919, 66925, 66931, 66937, 66947, 66958, 66965, 66973, 66979, 66986,
+    66990, 66995, 67003, 67011, 67016, 67021, 67028, 67034, 67040, 67046,
+    67056, 67067, 67078, 67090, 67102, 67113, 67123, 67131, 67137, 67146,
+    67150, 67157, 67165, 67171, 67179, 67188, 67197, 67204, 67211, 67216,
+    67223, 67232, 67239, 67242, 67246, 67251, 67258, 67263, 67269, 67273,
+    67277, 67283, 67290, 67298, 67303, 67310, 67318, 67322, 67327, 67334,
+    67341, 67349, 67357, 67364, 67368, 67374, 67381, 67392, 67399, 67405,
+    67413, 67418, 67425, 67430, 67435, 67443, 67453, 67465, 67471, 67475,
+    67480, 67486, 67492, 67500, 67507, 67511, 67518, 67524, 67532, 67539,
+    67546, 67553, 67559, 67564, 67569, 67578, 67585, 67592, 67599, 67605,
+    67613, 67617, 67622, 67630, 67638, 67649, 67657, 67663, 67669, 67676,
+    67684, 67690, 67698, 67701, 67708, 67716, 67723, 67730, 67738, 67743,
+    67749, 67756, 67760, 67765, 67771, 67778, 67782, 67789, 67798, 67807,
+    67814, 67817, 67822, 67829, 67834, 67842, 67846, 67851, 67856, 67864,
+    67868, 67874, 67880, 67887, 67896, 67903, 67911, 67916, 67923, 67931,
+    67939, 67949, 67960, 67971, 67978, 67985, 67993, 68000, 68011, 68020,
+    68028, 68032, 68038, 68044, 68052, 68058, 68065, 68072, 68077, 68080,
+    68085, 68093, 68103, 68111, 68118, 68122, 68127, 68134, 68139, 68145,
+    68153, 68163, 68171, 68178, 68184, 68191, 68198, 68206, 68214, 68218,
+    68224, 68229, 68235, 68240, 68247, 68255, 68261, 68267, 68272, 68278,
+    68283, 68292, 68301, 68308, 68316, 68324, 68333, 68339, 68343, 68346,
+    68351, 68355, 68361, 68368, 68375, 68383, 68388, 68396, 68407, 68415,
+    68420, 68427, 68435, 68441, 68445, 68452, 68460, 68468, 68473, 68480,
+    68488, 68496, 68505, 68512, 68515, 68522, 68532, 68542, 68553, 68562,
+    68570, 68578, 68587, 68597, 68608, 68611, 68617, 68623, 68627, 68635,
+    68641, 68648, 68655, 68663, 68668, 68672, 68679, 68687, 68692, 68696,
+    68700, 68707, 68715, 68723, 68731, 68737, 68741, 68747, 68754, 68760,
+    68767, 68777, 68788, 68793, 68801, 68807, 68813, 68817, 68823, 68830,
+    68838, 68848, 68856, 68864, 68873, 68880, 68885, 68890, 68896, 68902,
+    68908, 68915, 68919, 68925, 68931, 68936, 68943, 68955, 68964, 68968,
+    68973, 68982, 68990, 68996, 69002, 69009, 69015, 69022, 69027, 69034,
+    69043, 69053, 69063, 69074, 69081, 69087, 69091, 69099, 69108, 69115,
+    69126, 69131, 69136, 69143, 69151, 69157, 69164, 69169, 69173, 69179,
+    69188, 69195, 69200, 69205, 69213, 69219, 69222, 69227, 69233, 69239,
+    69243, 69248, 69258, 69264, 69271, 69277, 69283, 69290, 69299, 69307,
+    69317, 69323, 69330, 69338, 69346, 69352, 69361, 69365, 69370, 69377,
+    69384, 69392, 69398, 69407, 69412, 69418, 69426, 69434, 69439, 69445,
+    69449, 69456, 69465, 69470, 69476, 69483, 69491, 69496, 69499, 69504,
+    69511, 69519, 69527, 69533, 69538, 69545, 69549, 69559, 69565, 69572,
+    69579, 69587, 69596, 69604, 69612, 69621, 69625, 69630, 69635, 69640,
+    69646, 69652, 69659, 69663, 69669, 69675, 69682, 69689, 69696, 69703,
+    69711, 69716, 69720, 69726, 69733, 69742, 69752, 69757, 69763, 69771,
+    69780, 69787, 69792, 69798, 69806, 69816, 69819, 69824, 69830, 69838,
+    69847, 69855, 69863, 69872, 69879, 69885, 69892, 69900, 69908, 69917,
+    69928, 69937, 69946, 69951, 69958, 69962, 69968, 69975, 69983, 69988,
+    69992, 69997, 70003, 70011, 70016, 70022, 70027, 70034, 70040, 70046,
+    70057, 70068, 70080, 70087, 70097, 70103, 70111, 70119, 70128, 70136,
+    70145, 70151, 70155, 70159, 70162, 70166, 70173, 70181, 70189, 70194,
+    70205, 70212, 70219, 70226, 70235, 70245, 70254, 70259, 70267, 70275,
+    70283, 70292, 70298, 70302, 70308, 70314, 70322, 70327, 70335, 70340,
+    70349, 70356, 70361, 70367, 70371, 70377, 70383, 70392, 70401, 70410,
+    70417, 70424, 70430, 70437, 70442, 70449, 70456, 70464, 70468, 70473,
+    70476, 70482, 70489, 70493, 70499, 70505, 70512, 70519, 70527, 70531,
+    70536, 70544, 70547, 70553, 70560, 70568, 70576, 70581, 70586, 70592,
+    70598, 70605, 70613, 70617, 70623, 70628, 70634, 70641, 70645, 70652,
+    70660, 70668, 70676, 70681, 70689, 70698, 70703, 70711, 70716, 70720,
+    70725, 70731, 70737, 70744, 70754, 70759, 70767, 70773, 70780, 70788,
+    70795, 70800, 70807, 70815, 70821, 70828, 70832, 70839, 70847, 70852,
+    70857, 70863, 70869, 70876, 70881, 70890, 70900, 70907, 70914, 70922,
+    70932, 70938, 70943, 70949, 70953, 70959, 70967, 70972, 70979, 70988,
+    70995, 71004, 71014, 71022, 71028, 71036, 71045, 71052, 71056, 71062,
+    71070, 71075, 71079, 71083, 71088, 71094, 71098, 71105, 71114, 71118,
+    71124, 71131, 71136, 71141, 71149, 71155, 71160, 71166, 71170, 71174,
+    71185, 71193, 71201, 71206, 71213, 71220, 71230, 71238, 71243, 71249,
+    71254, 71260, 71267, 71273, 71279, 71287, 71292, 71300, 71305, 71309,
+    71317, 71325, 71332, 71339, 71347, 71352, 71357, 71364, 71372, 71379,
+    71387, 71392, 71399, 71407, 71413, 71418, 71425, 71431, 71438, 71446,
+    71451, 71458, 71466, 71472, 71477, 71484, 71492, 71498, 71502, 71509,
+    71515, 71520, 71525, 71531, 71537, 71544, 71549, 71558, 71566, 71574,
+    71579, 71587, 71597, 71608, 71612, 71618, 71624, 71632, 71641, 71648,
+    71655, 71662, 71667, 71672, 71675, 71679, 71690, 71701, 71713, 71717,
+    71724, 71730, 71738, 71743, 71749, 71754, 71762, 71768, 71773, 71778,
+    71784, 71796, 71807, 71815, 71823, 71829, 71834, 71841, 71849, 71854,
+    71860, 71866, 71873, 71882, 71892, 71896, 71902, 71905, 71915, 71920,
+    71926, 71932, 71939, 71948, 71956, 71958, 71962, 71968, 71973, 71977,
+    71984, 71989, 71994, 71999, 72006, 72013, 72021, 72029, 72035, 72038,
+    72042, 72045, 72050, 72057, 72064, 72068, 72074, 72079, 72084, 72088,
+    72094, 72100, 72107, 72114, 72119, 72124, 72131, 72136, 72142, 72146,
+    72154, 72159, 72167, 72175, 72184, 72191, 72197, 72205, 72213, 72222,
+    72231, 72238, 72243, 72247, 72253, 72259, 72267, 72271, 72276, 72279,
+    72283, 72288, 72298, 72302, 72308, 72315, 72322, 72329, 72340, 72345,
+    72349, 72356, 72364, 72374, 72385, 72395, 72401, 72409, 72418, 72426,
+    72433, 72441, 72448, 72456, 72465, 72473, 72482, 72491, 72501, 72510,
+    72518, 72521, 72527, 72535, 72543, 72548, 72554, 72561, 72568, 72574,
+    72583, 72593, 72600, 72605, 72611, 72619, 72629, 72636, 72642, 72650,
+    72658, 72665, 72669, 72674, 72681, 72689, 72697, 72708, 72717, 72728,
+    72739, 72748, 72754, 72763, 72771, 72781, 72789, 72796, 72805, 72815,
+    72822, 72830, 72837, 72845, 72853, 72861, 72870, 72880, 72887, 72894,
+    72905, 72913, 72922, 72928, 72936, 72944, 72952, 72959, 72967, 72975,
+    72982, 72990, 72997, 73006, 73016, 73023, 73028, 73034, 73041, 73047,
+    73053, 73060, 73070, 73077, 73084, 73092, 73103, 73111, 73120, 73125,
+    73131, 73140, 73144, 73150, 73158, 73163, 73170, 73174, 73180, 73192,
+    73201, 73212, 73221, 73231, 73239, 73245, 73254, 73264, 73273, 73280,
+    73289, 73299, 73305, 73311, 73319, 73329, 73336, 73344, 73349, 73356,
+    73364, 73373, 73383, 73394, 73405, 73417, 73424, 73432, 73436, 73441,
+    73446, 73457, 73466, 73473, 73481, 73490, 73497, 73506, 73515, 73523,
+    73533, 73538, 73547, 73558, 73565, 73573, 73578, 73583, 73589, 73596,
+    73605, 73615, 73623, 73629, 73636, 73642, 73650, 73659, 73666, 73672,
+    73676, 73682, 73689, 73694, 73704, 73715, 73722, 73729, 73738, 73746,
+    73754, 73761, 73769, 73778, 73782, 73790, 73798, 73808, 73814, 73818,
+    73823, 73826, 73831, 73836, 73843, 73851, 73861, 73870, 73878, 73887,
+    73896, 73906, 73913, 73922, 73930, 73941, 73951, 73960, 73969, 73977,
+    73982, 73989, 73994, 74000, 74007, 74013, 74022, 74032, 74039, 74048,
+    74058, 74063, 74074, 74081, 74087, 74094, 74105, 74114, 74124, 74129,
+    74135, 74145, 74152, 74156, 74162, 74167, 74173, 74180, 74188, 74196,
+    74200, 74205, 74213, 74221, 74228, 74236, 74244, 74252, 74259, 74268,
+    74274, 74281, 74288, 74293, 74298, 74307, 74314, 74320, 74327, 74334,
+    74342, 74349, 74358, 74364, 74372, 74380, 74386, 74397, 74406, 74414,
+    74424, 74434, 74440, 74448, 74453, 74460, 74467, 74475, 74483, 74492,
+    74502, 74506, 74513, 74521, 74528, 74533, 74539, 74547, 74553, 74559,
+    74564, 74570, 74580, 74592, 74600, 74607, 74617, 74628, 74633, 74639,
+    74646, 74654, 74660, 74668, 74678, 74689, 74698, 74704, 74710, 74716,
+    74723, 74730, 74735, 74741, 74750, 74755, 74759, 74766, 74771, 74777,
+    74781, 74787, 74792, 74800, 74807, 74814, 74822, 74831, 74839, 74848,
+    74854, 74859, 74866, 74874, 74880, 74886, 74891, 74896, 74903, 74911,
+    74917, 74922, 74928, 74936, 74942, 74947, 74955, 74963, 74971, 74980,
+    74986, 74992, 75002, 75013, 75024, 75036, 75039, 75044, 75050, 75057,
+    75062, 75066, 75072, 75079, 75084, 75092, 75101, 75106, 75113, 75120,
+    75129, 75139, 75150, 75158, 75168, 75175, 75179, 75185, 75192, 75200,
+    75205, 75212, 75218, 75225, 75232, 75240, 75244, 75249, 75254, 75260,
+    75263, 75271, 75281, 75294, 75306, 75316, 75327, 75336, 75340, 75351,
+    75363, 75369, 75376, 75381, 75388, 75396, 75402, 75407, 75411, 75416,
+    75422, 75427, 75430, 75437, 75446, 75450, 75458, 75466, 75473, 75481,
+    75488, 75497, 75506, 75516, 75527, 75536, 75541, 75547, 75555, 75562,
+    75567, 75571, 75578, 75587, 75597, 75608, 75616, 75623, 75627, 75631,
+    75636, 75644, 75649, 75655, 75661, 75668, 75672, 75679, 75684, 75690,
+    75698, 75704, 75711, 75721, 75725, 75730, 75733, 75738, 75744, 75747,
+    75753, 75760, 75766, 75769, 75773, 75779, 75785, 75789, 75795, 75800,
+    75804, 75810, 75817, 75824, 75829, 75833, 75837, 75845, 75853, 75859,
+    75863, 75868, 75872, 75879, 75888, 75899, 75908, 75915, 75921, 75928,
+    75935, 75941, 75948, 75954, 75962, 75969, 75977, 75983, 75989, 75996,
+    76003, 76011, 76019, 76023, 76028, 76033, 76039, 76045, 76050, 76055,
+    76059, 76067, 76072, 76080, 76089, 76092, 76097, 76103, 76107, 76113,
+    76120, 76124, 76131, 76138, 76144, 76152, 76161, 76170, 76177, 76183,
+    76190, 76198, 76204, 76211, 76219, 76225, 76233, 76242, 76248, 76255,
+    76261, 76269, 76279, 76288, 76294, 76297, 76304, 76309, 76313, 76319,
+    76327, 76335, 76339, 76345, 76352, 76357, 76364, 76368, 76373, 76383,
+    76388, 76395, 76403, 76409, 76417, 76425, 76434, 76444, 76451, 76457,
+    76462, 76468, 76473, 76478, 76484, 76490, 76497, 76502, 76510, 76515,
+    76519, 76524, 76527, 76531, 76536, 76543, 76548, 76555, 76563, 76569,
+    76573, 76580, 76586, 76593, 76598, 76603, 76607, 76612, 76615, 76622,
+    76626, 76629, 76633, 76641, 76647, 76654, 76658, 76670, 76679, 76685,
+    76692, 76702, 76712, 76723, 76732, 76740, 76748, 76757, 76766, 76776,
+    76786, 76797, 76807, 76816, 76826, 76833, 76843, 76851, 76861, 76866,
+    76873, 76880, 76891, 76899, 76905, 76910, 76919, 76927, 76933, 76941,
+    76949, 76955, 76962, 76967, 76973, 76977, 76984, 76991, 76997, 77006,
+    77015, 77025, 77032, 77038, 77044, 77051, 77060, 77065, 77075, 77086,
+    77096, 77108, 77117, 77125, 77135, 77144, 77154, 77161, 77170, 77181,
+    77191, 77199, 77210, 77222, 77232, 77241, 77245, 77252, 77260, 77271,
+    77276, 77287, 77296, 77303, 77311, 77319, 77322, 77329, 77333, 77340,
+    77346, 77353, 77359, 77366, 77371, 77378, 77384, 77389, 77394, 77400,
+    77406, 77413, 77418, 77422, 77428, 77433, 77436, 77444, 77450, 77457,
+    77464, 77472, 77476, 77482, 77489, 77493, 77498, 77502, 77509, 77512,
+    77518, 77526, 77533, 77540, 77546, 77553, 77559, 77563, 77573, 77581,
+    77588, 77593, 77598, 77602, 77605, 77611, 77615, 77620, 77626, 77631,
+    77637, 77642, 77649, 77656, 77664, 77668, 77673, 77682, 77692, 77698,
+    77706, 77715, 77722, 77728, 77735, 77742, 77750, 77755, 77761, 77770,
+    77775, 77781, 77788, 77793, 77802, 77809, 77815, 77823, 77828, 77834,
+    77840, 77847, 77852, 77859, 77867, 77873, 77878, 77885, 77889, 77893,
+    77897, 77902, 77908, 77914, 77921, 77927, 77934, 77941, 77948, 77956,
+    77964, 77969, 77977, 77987, 77993, 78000, 78010, 78015, 78021, 78029,
+    78034, 78040, 78048, 78056, 78061, 78069, 78075, 78082, 78091, 78098,
+    78106, 78111, 78116, 78124, 78131, 78138, 78144, 78149, 78155, 78161,
+    78165, 78171, 78178, 78183, 78190, 78194, 78199, 78205, 78209, 78213,
+    78219, 78224, 78231, 78237, 78244, 78249, 78253, 78259, 78263, 78269,
+    78279, 78290, 78295, 78305, 78316, 78320, 78325, 78331, 78337, 78343,
+    78346, 78353, 78362, 78371, 78378, 78385, 78390, 78394, 78398, 78403,
+    78410, 78415, 78421, 78427, 78431, 78442, 78448, 78456, 78461, 78470,
+    78474, 78480, 78487, 78494, 78498, 78502, 78505, 78509, 78516, 78523,
+    78529, 78537, 78542, 78550, 78555, 78559, 78564, 78569, 78575, 78582,
+    78586, 78591, 78599, 78605, 78612, 78620, 78626, 78630, 78634, 78640,
+    78647, 78655, 78661, 78664, 78670, 78676, 78681, 78686, 78693, 78697,
+    78703, 78713, 78721, 78730, 78739, 78749, 78761, 78772, 78780, 78789,
+    78796, 78800, 78805, 78809, 78816, 78824, 78829, 78834, 78840, 78848,
+    78858, 78864, 78872, 78882, 78886, 78891, 78897, 78903, 78910, 78916,
+    78924, 78933, 78940, 78947, 78956, 78964, 78973, 78982, 78992, 79002,
+    79010, 79015, 79022, 79030, 79036, 79041, 79046, 79052, 79058, 79062,
+    79069, 79077, 79088, 79098, 79103, 79110, 79115, 79122, 79130, 79138,
+    79149, 79158, 79167, 79174, 79181, 79192, 79199, 79211, 79217, 79225,
+    79234, 79244, 79250, 79257, 79263, 79268, 79275, 79283, 79290, 79296,
+    79304, 79311, 79319, 79324, 79330, 79335, 79342, 79351, 79362, 79370,
+    79378, 79386, 79392, 79399, 79406, 79414, 79423, 79428, 79435, 79443,
+    79449, 79454, 79461, 79472, 79481, 79487, 79492, 79498, 79506, 79516,
+    79525, 79532, 79542, 79549, 79557, 79566, 79574, 79579, 79585, 79593,
+    79600, 79607, 79613, 79619, 79626, 79635, 79648, 79655, 79660, 79664,
+    79671, 79678, 79683, 79688, 79694, 79701, 79707, 79714, 79720, 79725,
+    79732, 79740, 79747, 79756, 79762, 79767, 79775, 79781, 79786, 79793,
+    79801, 79811, 79819, 79827, 79833, 79838, 79845, 79853, 79862, 79868,
+    79875, 79883, 79892, 79896, 79905, 79912, 79916, 79923, 79931, 79936,
+    79941, 79950, 79960, 79966, 79973, 79981, 79989, 79996, 80001, 80007,
+    80014, 80022, 80026, 80033, 80041, 80046, 80052, 80058, 80063, 80067,
+    80072, 80079, 80087, 80094, 80102, 80107, 80111, 80118, 80126, 80131,
+    80137, 80142, 80146, 80151, 80158, 80164, 80172, 80181, 80188, 80193,
+    80200, 80208, 80214, 80220, 80229, 80236, 80243, 80248, 80253, 80260,
+    80268, 80274, 80280, 80287, 80294, 80300, 80307, 80312, 80319, 80327,
+    80334, 80343, 80349, 80355, 80362, 80368, 80376, 80385, 80392, 80402,
+    80407, 80414, 80422, 80431, 80437, 80443, 80448, 80453, 80459, 80463,
+    80469, 80476, 80483, 80488, 80495, 80503, 80508, 80515, 80520, 80526,
+    80533, 80537, 80543, 80549, 80557, 80562, 80570, 80578, 80583, 80590,
+    80597, 80605, 80611, 80617, 80622, 80629, 80637, 80643, 80652, 80662,
+    80674, 80684, 80693, 80701, 80706, 80713, 80721, 80730, 80742, 80750,
+    80756, 80761, 80769, 80778, 80788, 80793, 80800, 80807, 80815, 80820,
+    80826, 80834, 80839, 80845, 80854, 80864, 80870, 80877, 80882, 80890,
+    80896, 80901, 80906, 80912, 80918, 80923, 80929, 80935, 80944, 80954,
+    80961, 80966, 80971, 80975, 80979, 80987, 80994, 80999, 81004, 81008,
+    81014, 81017, 81022, 81026, 81029, 81036, 81044, 81052, 81059, 81063,
+    81069, 81075, 81081, 81087, 81094, 81101, 81108, 81116, 81125, 81129,
+    81136, 81144, 81151, 81159, 81165, 81169, 81174, 81178, 81184, 81191,
+    81195, 81200, 81205, 81208, 81212, 81218, 81224, 81231, 81239, 81242,
+    81246, 81249, 81258, 81265, 81275, 81283, 81290, 81296, 81301, 81306,
+    81315, 81322, 81332, 81340, 81346, 81354, 81364, 81372, 81376, 81382,
+    81388, 81395, 81402, 81409, 81416, 81423, 81428, 81432, 81438, 81445,
+    81450, 81454, 81461, 81469, 81477, 81484, 81489, 81494, 81501, 81505,
+    81510, 81514, 81522, 81530, 81538, 81545, 81552, 81556, 81564, 81572,
+    81577, 81584, 81592, 81596, 81603, 81607, 81613, 81619, 81626, 81631,
+    81637, 81643, 81646, 81655, 81661, 81668, 81674, 81682, 81689, 81696,
+    81702, 81710, 81714, 81721, 81729, 81738, 81742, 81749, 81757, 81765,
+    81771, 81778, 81786, 81793, 81801, 81809, 81817, 81826, 81837, 81844,
+    81856, 81862, 81869, 81876, 81884, 81891, 81899, 81907, 81915, 81922,
+    81930, 81935, 81942, 81950, 81961, 81972, 81977, 81985, 81989, 81995,
+    82002, 82008, 82014, 82021, 82028, 82036, 82043, 82050, 82058, 82063,
+    82069, 82073, 82082, 82089, 82095, 82103, 82112, 82122, 82129, 82136,
+    82144, 82151, 82160, 82165, 82171, 82179, 82188, 82198, 82204, 82212,
+    82219, 82223, 82231, 82240, 82246, 82254, 82263, 82269, 82276, 82282,
+    82290, 82298, 82307, 82315, 82323, 82328, 82332, 82337, 82341, 82350,
+    82356, 82360, 82366, 82373, 82380, 82388, 82396, 82405, 82414, 82423,
+    82433, 82442, 82449, 82454, 82461, 82465, 82472, 82483, 82490, 82496,
+    82501, 82506, 82513, 82520, 82524, 82531, 82540, 82549, 82559, 82567,
+    82571, 82578, 82585, 82592, 82598, 82605, 82610, 82616, 82624, 82631,
+    82636, 82639, 82644, 82653, 82660, 82668, 82675, 82682, 82690, 82694,
+    82699, 82706, 82711, 82717, 82723, 82727, 82734, 82738, 82744, 82751,
+    82759, 82764, 82771, 82779, 82785, 82790, 82796, 82801, 82807, 82812,
+    82815, 82819, 82825, 82832, 82837, 82845, 82848, 82852, 82858, 82867,
+    82874, 82878, 82883, 82891, 82895, 82899, 82907, 82916, 82924, 82930,
+    82936, 82943, 82951, 82958, 82966, 82974, 82982, 82991, 83000, 83010,
+    83018, 83028, 83038, 83049, 83061, 83069, 83074, 83081, 83091, 83098,
+    83103, 83107, 83113, 83119, 83126, 83133, 83138, 83144, 83154, 83163,
+    83170, 83174, 83180, 83187, 83192, 83196, 83201, 83207, 83214, 83221,
+    83229, 83234, 83241, 83247, 83252, 83261, 83271, 83282, 83291, 83297,
+    83304, 83312, 83321, 83330, 83336, 83342, 83346, 83352, 83358, 83365,
+    83370, 83377, 83385, 83392, 83399, 83404, 83409, 83415, 83421, 83429,
+    83436, 83443, 83451, 83458, 83465, 83470, 83481, 83485, 83492, 83500,
+    83506, 83512, 83517, 83523, 83531, 83537, 83544, 83552, 83556, 83562,
+    83570, 83574, 83580, 83585, 83589, 83594, 83602, 83608, 83614, 83622,
+    83632, 83639, 83646, 83650, 83657, 83665, 83670, 83675, 83679, 83689,
+    83700, 83706, 83711, 83718, 83723, 83727, 83733, 83740, 83745, 83749,
+    83753, 83759, 83765, 83772, 83779, 83786, 83794, 83804, 83816, 83821,
+    83824, 83834, 83845, 83852, 83858, 83868, 83878, 83882, 83885, 83890,
+    83900, 83908, 83913, 83917, 83924, 83933, 83939, 83947, 83955, 83962,
+    83966, 83976, 83983, 83989, 83999, 84004, 84008, 84014, 84020, 84027,
+    84034, 84039, 84043, 84052, 84058, 84068, 84076, 84082, 84088, 84096,
+    84102, 84109, 84118, 84126, 84132, 84142, 84150, 84158, 84166, 84172,
+    84181, 84188, 84194, 84201, 84205, 84211, 84217, 84223, 84230, 84237,
+    84242, 84248, 84255, 84259, 84266, 84271, 84279, 84287, 84296, 84306,
+    84318, 84327, 84337, 84340, 84344, 84347, 84354, 84363, 84366, 84372,
+    84378, 84386, 84392, 84396, 84402, 84409, 84414, 84417, 84423, 84427,
+    84434, 84441, 84446, 84452, 84464, 84475, 84484, 84488, 84494, 84501,
+    84509, 84518, 84527, 84538, 84550, 84560, 84568, 84574, 84582, 84587,
+    84594, 84602, 84608, 84616, 84625, 84629, 84634, 84640, 84645, 84651,
+    84660, 84664, 84669, 84677, 84684, 84689, 84693, 84700, 84704, 84710,
+    84718, 84725, 84734, 84739, 84748, 84753, 84759, 84765, 84769, 84775,
+    84785, 84792, 84797, 84800, 84806, 84812, 84819, 84824, 84828, 84835,
+    84839, 84844, 84849, 84855, 84859, 84865, 84872, 84881, 84890, 84899,
+    84909, 84917, 84927, 84939, 84948, 84955, 84958, 84963, 84970, 84978,
+    84984, 84992, 84996, 85002, 85009, 85016, 85020, 85025, 85031, 85036,
+    85044, 85050, 85056, 85064, 85069, 85073, 85080, 85086, 85093, 85098,
+    85102, 85108, 85111, 85116, 85120, 85125, 85132, 85140, 85144, 85150,
+    85157, 85164, 85172, 85177, 85181, 85186, 85191, 85198, 85206, 85213,
+    85221, 85229, 85235, 85243, 85249, 85257, 85264, 85269, 85277, 85284,
+    85288, 85294, 85298, 85308, 85316, 85324, 85336, 85342, 85350, 85360,
+    85368, 85373, 85378, 85386, 85394, 85403, 85411, 85419, 85427, 85435,
+    85441, 85447, 85458, 85469, 85479, 85489, 85500, 85504, 85509, 85515,
+    85523, 85530, 85535, 85544, 85553, 85557, 85562, 85571, 85579, 85586,
+    85595, 85603, 85609, 85613, 85619, 85624, 85632, 85636, 85641, 85645,
+    85651, 85658, 85663, 85667, 85672, 85676, 85682, 85685, 85689, 85694,
+    85701, 85710, 85718, 85728, 85740, 85745, 85751, 85757, 85763, 85770,
+    85774, 85779, 85785, 85792, 85800, 85810, 85822, 85830, 85837, 85845,
+    85849, 85855, 85861, 85868, 85873, 85881, 85889, 85895, 85903, 85912,
+    85918, 85925, 85930, 85939, 85947, 85955, 85963, 85973, 85979, 85988,
+    86000, 86004, 86009, 86016, 86026, 86034, 86045, 86057, 86066, 86070,
+    86077, 86085, 86091, 86097, 86104, 86111, 86118, 86129, 86138, 86145,
+    86150, 86153, 86158, 86164, 86169, 86175, 86182, 86187, 86194, 86200,
+    86204, 86210, 86215, 86220, 86226, 86235, 86246, 86256, 86267, 86279,
+    86285, 86294, 86304, 86313, 86320, 86325, 86332, 86340, 86346, 86353,
+    86361, 86370, 86374, 86380, 86387, 86392, 86395, 86401, 86405, 86408,
+    86414, 86421, 86424, 86428, 86432, 86435, 86439, 86445, 86451, 86458,
+    86462, 86465, 86469, 86473, 86477, 86482, 86485, 86490, 86496, 86504,
+    86512, 86522, 86534, 86544, 86552, 86560, 86569, 86576, 86584, 86590,
+    86596, 86603, 86611, 86617, 86623, 86630, 86635, 86643, 86653, 86664,
+    86675, 86683, 86689, 86696, 86707, 86716, 86721, 86729, 86734, 86739,
+    86746, 86753, 86761, 86765, 86771, 86779, 86785, 86789, 86795, 86799,
+    86805, 86811, 86818, 86825, 86830, 86838, 86844, 86851, 86855, 86861,
+    86867, 86874, 86880, 86889, 86899, 86906, 86915, 86920, 86928, 86932,
+    86939, 86946, 86952, 86960, 86965, 86972, 86981, 86989, 86993, 86999,
+    87003, 87010, 87015, 87020, 87027, 87033, 87040, 87043, 87048, 87052,
+    87060, 87066, 87073, 87078, 87085, 87093, 87102, 87113, 87125, 87133,
+    87142, 87152, 87157, 87164, 87172, 87176, 87182, 87187, 87191, 87196,
+    87206, 87220, 87232, 87241, 87247, 87255, 87263, 87267, 87276, 87288,
+    87298, 87310, 87321, 87331, 87341, 87353, 87365, 87373, 87381, 87391,
+    87394, 87401, 87409, 87415, 87419, 87423, 87428, 87432, 87437, 87440,
+    87444, 87449, 87457, 87465, 87475, 87484, 87491, 87497, 87502, 87510,
+    87515, 87524, 87536, 87546, 87556, 87567, 87577, 87585, 87596, 87606,
+    87614, 87625, 87635, 87643, 87648, 87654, 87661, 87667, 87671, 87679,
+    87685, 87689, 87693, 87701, 87708, 87713, 87720, 87725, 87732, 87738,
+    87745, 87753, 87760, 87769, 87777, 87783, 87790, 87797, 87805, 87808,
+    87811, 87818, 87827, 87839, 87846, 87856, 87866, 87873, 87882, 87891,
+    87895, 87905, 87916, 87928, 87940, 87948, 87957, 87965, 87975, 87986,
+    87997, 88009, 88021, 88033, 88044, 88055, 88060, 88066, 88073, 88079,
+    88089, 88098, 88109, 88121, 88132, 88139, 88147, 88155, 88162, 88171,
+    88175, 88180, 88189, 88199, 88207, 88214, 88220, 88227, 88235, 88243,
+    88252, 88261, 88271, 88279, 88289, 88297, 88306, 88315, 88326, 88333,
+    88342, 88349, 88357, 88366, 88375, 88384, 88394, 88404, 88415, 88423,
+    88431, 88442, 88449, 88459, 88467, 88478, 88487, 88493, 88501, 88513,
+    88523, 88532, 88538, 88546, 88555, 88562, 88568, 88576, 88586, 88597,
+    88604, 88610, 88618, 88627, 88639, 88646, 88653, 88663, 88672, 88683,
+    88690, 88701, 88710, 88716, 88723, 88730, 88740, 88751, 88759, 88767,
+    88776, 88788, 88798, 88809, 88818, 88826, 88837, 88848, 88858, 88868,
+    88875, 88882, 88889, 88896, 88908, 88917, 88925, 88934, 88945, 88957,
+    88967, 88977, 88988, 89000, 89008, 89018, 89025, 89032, 89039, 89047,
+    89052, 89060, 89066, 89076, 89085, 89096, 89107, 89115, 89123, 89132,
+    89141, 89148, 89154, 89161, 89168, 89176, 89186, 89196, 89204, 89213,
+    89221, 89233, 89244, 89253, 89260, 89269, 89279, 89289, 89300, 89310,
+    89317, 89325, 89333, 89343, 89355, 89365, 89374, 89382, 89392, 89399,
+    89407, 89418, 89430, 89438, 89447, 89456, 89466, 89474, 89481, 89489,
+    89498, 89506, 89512, 89522, 89530, 89536, 89543, 89552, 89564, 89574,
+    89582, 89590, 89600, 89612, 89622, 89632, 89644, 89653, 89661, 89670,
+    89681, 89693, 89699, 89706, 89715, 89725, 89737, 89746, 89757, 89767,
+    89774, 89782, 89791, 89801, 89810, 89819, 89830, 89834, 89840, 89846,
+    89854, 89863, 89871, 89881, 89893, 89902, 89911, 89919, 89926, 89934,
+    89942, 89949, 89957, 89963, 89970, 89978, 89989, 90001, 90008, 90016,
+    90024, 90031, 90039, 90047, 90056, 90065, 90075, 90084, 90094, 90100,
+    90107, 90115, 90127, 90139, 90150, 90160, 90172, 90184, 90196, 90208,
+    90219, 90231, 90243, 90252, 90263, 90271, 90280, 90289, 90299, 90311,
+    90321, 90331, 90340, 90351, 90361, 90369, 90378, 90388, 90397, 90406,
+    90416, 90421, 90429, 90435, 90444, 90454, 90462, 90474, 90482, 90488,
+    90498, 90510, 90519, 90525, 90533, 90543, 90555, 90566, 90578, 90583,
+    90590, 90597, 90605, 90613, 90622, 90631, 90641, 90651, 90662, 90672,
+    90681, 90691, 90698, 90706, 90716, 90728, 90739, 90749, 90757, 90766,
+    90777, 90783, 90791, 90801, 90811, 90821, 90833, 90844, 90855, 90863,
+    90869, 90876, 90882, 90889, 90899, 90906, 90914, 90924, 90932, 90940,
+    90949, 90958, 90967, 90974, 90982, 90992, 91001, 91010, 91020, 91030,
+    91041, 91049, 91060, 91071, 91083, 91094, 91103, 91113, 91118, 91130,
+    91140, 91150, 91158, 91163, 91170, 91178, 91189, 91199, 91209, 91216,
+    91226, 91237, 91249, 91261, 91271, 91279, 91285, 91292, 91298, 91307,
+    91315, 91322, 91332, 91338, 91346, 91355, 91365, 91375, 91382, 91387,
+    91396, 91406, 91417, 91425, 91436, 91444, 91451, 91459, 91465, 91476,
+    91487, 91495, 91502, 91512, 91520, 91527, 91536, 91546, 91557, 91569,
+    91577, 91587, 91597, 91605, 91611, 91620, 91628, 91639, 91651, 91663,
+    91673, 91680, 91688, 91697, 91709, 91719, 91729, 91736, 91745, 91755,
+    91761, 91770, 91780, 91790, 91801, 91812, 91821, 91827, 91831, 91842,
+    91848, 91856, 91866, 91875, 91885, 91896, 91907, 91915, 91923, 91932,
+    91939, 91947, 91961, 91971, 91983, 91991, 92003, 92013, 92019, 92026,
+    92034, 92043, 92052, 92058, 92066, 92075, 92080, 92086, 92093, 92102,
+    92112, 92123, 92130, 92137, 92148, 92157, 92167, 92175, 92185, 92191,
+    92198, 92205, 92213, 92221, 92231, 92238, 92249, 92258, 92268, 92276,
+    92283, 92292, 92302, 92312, 92320, 92327, 92335, 92343, 92351, 92355,
+    92362, 92372, 92381, 92389, 92397, 92406, 92415, 92425, 92435, 92445,
+    92456, 92465, 92471, 92479, 92488, 92497, 92507, 92515, 92525, 92536,
+    92542, 92549, 92557, 92565, 92574, 92580, 92589, 92599, 92602, 92609,
+    92613, 92617, 92623, 92629, 92634, 92639, 92645, 92651, 92657, 92664,
+    92667, 92673, 92681, 92686, 92695, 92701, 92708, 92717, 92725, 92734,
+    92742, 92752, 92763, 92773, 92782, 92786, 92794, 92805, 92815, 92826,
+    92835, 92844, 92849, 92855, 92862, 92869, 92877, 92885, 92893, 92902,
+    92911, 92918, 92929, 92936, 92942, 92950, 92960, 92968, 92977, 92988,
+    93000, 93006, 93018, 93025, 93033, 93043, 93054, 93065, 93071, 93079,
+    93088, 93097, 93104, 93109, 93116, 93122, 93129, 93137, 93144, 93153,
+    93160, 93170, 93178, 93186, 93193, 93199, 93207, 93217, 93226, 93235,
+    93242, 93249, 93257, 93266, 93276, 93284, 93292, 93299, 93308, 93318,
+    93328, 93339, 93348, 93358, 93369, 93376, 93384, 93392, 93401, 93412,
+    93421, 93428, 93440, 93449, 93459, 93470, 93478, 93486, 93495, 93502,
+    93511, 93519, 93526, 93536, 93547, 93554, 93562, 93573, 93582, 93591,
+    93601, 93611, 93622, 93634, 93642, 93652, 93663, 93674, 93686, 93697,
+    93707, 93718, 93727, 93737, 93749, 93760, 93772, 93779, 93787, 93796,
+    93806, 93816, 93823, 93829, 93837, 93846, 93853, 93862, 93868, 93875,
+    93882, 93890, 93897, 93907, 93916, 93926, 93934, 93946, 93949, 93955,
+    93961, 93968, 93978, 93985, 93993, 94001, 94010, 94019, 94029, 94039,
+    94050, 94061, 94071, 94080, 94085, 94094, 94106, 94118, 94129, 94141,
+    94147, 94155, 94164, 94171, 94178, 94187, 94198, 94207, 94218, 94227,
+    94236, 94247, 94253, 94262, 94273, 94283, 94291, 94298, 94306, 94316,
+    94327, 94338, 94350, 94361, 94370, 94379, 94390, 94401, 94412, 94420,
+    94428, 94438, 94449, 94458, 94467, 94477, 94487, 94496, 94506, 94518,
+    94528, 94539, 94549, 94556, 94564, 94573, 94584, 94596, 94605, 94617,
+    94629, 94641, 94647, 94655, 94667, 94677, 94690, 94698, 94706, 94716,
+    94723, 94733, 94742, 94751, 94762, 94773, 94785, 94797, 94809, 94819,
+    94827, 94839, 94848, 94859, 94871, 94883, 94893, 94902, 94914, 94926,
+    94936, 94948, 94958, 94970, 94981, 94991, 94999, 95008, 95017, 95027,
+    95037, 95048, 95060, 95069, 95080, 95091, 95103, 95114, 95126, 95138,
+    95148, 95158, 95168, 95177, 95187, 95195, 95203, 95213, 95223, 95234,
+    95246, 95258, 95269, 95280, 95290, 95297, 95308, 95320, 95331, 95339,
+    95350, 95358, 95367, 95375, 95384, 95393, 95403, 95412, 95417, 95426,
+    95436, 95446, 95457, 95469, 95481, 95488, 95496, 95504, 95513, 95523,
+    95532, 95538, 95547, 95557, 95566, 95577, 95586, 95592, 95599, 95606,
+    95614, 95621, 95629, 95636, 95646, 95656, 95665, 95673, 95682, 95690,
+    95696, 95704, 95713, 95722, 95732, 95741, 95749, 95760, 95769, 95778,
+    95785, 95792, 95801, 95810, 95816, 95824, 95830, 95838, 95849, 95861,
+    95873, 95885, 95894, 95905, 95915, 95926, 95934, 95943, 95950, 95960,
+    95969, 95979, 95990, 95996, 96003, 96010, 96018, 96028, 96035, 96043,
+    96049, 96056, 96063, 96071, 96082, 96090, 96097, 96105, 96116, 96124,
+    96133, 96139, 96147, 96154, 96160, 96163, 96173, 96180, 96188, 96192,
+    96196, 96201, 96204, 96211, 96215, 96222, 96226, 96234, 96240, 96246,
+    96254, 96264, 96271, 96278, 96283, 96288, 96298, 96309, 96319, 96328,
+    96340, 96351, 96361, 96373, 96385, 96397, 96408, 96416, 96425, 96435,
+    96447, 96456, 96464, 96472, 96481, 96491, 96501, 96507, 96515, 96524,
+    96531, 96535, 96540, 96545, 96551, 96559, 96568, 96575, 96583, 96591,
+    96600, 96609, 96618, 96628, 96638, 96645, 96653, 96661, 96670, 96678,
+    96683, 96689, 96695, 96702, 96709, 96715, 96722, 96726, 96733, 96738,
+    96742, 96749, 96757, 96762, 96769, 96777, 96786, 96796, 96805, 96813,
+    96822, 96831, 96837, 96842, 96845, 96848, 96854, 96858, 96864, 96870,
+    96877, 96884, 96889, 96893, 96898, 96901, 96907, 96913, 96920, 96924,
+    96930, 96936, 96941, 96944, 96952, 96958, 96965, 96969, 96975, 96982,
+    96987, 96990, 96996, 97003, 97009, 97016, 97020, 97027, 97033, 97041,
+    97048, 97051, 97058, 97062, 97066, 97070, 97077, 97085, 97090, 97094,
+    97099, 97106, 97111, 97120, 97130, 97138, 97145, 97150, 97155, 97161,
+    97168, 97172, 97178, 97181, 97185, 97191, 97199, 97204, 97209, 97216,
+    97223, 97229, 97232, 97237, 97240, 97246, 97251, 97257, 97261, 97268,
+    97272, 97275, 97282, 97286, 97290, 97296, 97303, 97306, 97312, 97319,
+    97323, 97329, 97335, 97342, 97347, 97352, 97359, 97366, 97372, 97377,
+    97383, 97387, 97392, 97397, 97402, 97408, 97416, 97421, 97425, 97431,
+    97435, 97442, 97450, 97453, 97459, 97464, 97471, 97481, 97491, 97503,
+    97514, 97522, 97529, 97537, 97543, 97546, 97552, 97560, 97566, 97574,
+    97581, 97585, 97593, 97601, 97608, 97613, 97619, 97629, 97635, 97642,
+    97650, 97659, 97667, 97676, 97685, 97689, 97693, 97696, 97702, 97710,
+    97717, 97722, 97728, 97733, 97740, 97746, 97753, 97758, 97762, 97768,
+    97774, 97781, 97788, 97793, 97798, 97806, 97815, 97823, 97829, 97836,
+    97842, 97849, 97856, 97860, 97867, 97872, 97881, 97887, 97899, 97905,
+    97912, 97917, 97923, 97927, 97931, 97938, 97946, 97957, 97966, 97975,
+    97982, 97992, 97998, 98005, 98009, 98017, 98026, 98030, 98038, 98046,
+    98055, 98061, 98066, 98073, 98079, 98084, 98089, 98097, 98101, 98109,
+    98113, 98119, 98125, 98129, 98135, 98142, 98149, 98154, 98162, 98165,
+    98169, 98172, 98178, 98182, 98186, 98192, 98199, 98207, 98214, 98220,
+    98227, 98230, 98238, 98247, 98252, 98260, 98267, 98273, 98280, 98284,
+    98292, 98299, 98307, 98312, 98316, 98323, 98327, 98333, 98339, 98346,
+    98353, 98360, 98365, 98368, 98374, 98381, 98387, 98396, 98406, 98413,
+    98419, 98426, 98430, 98434, 98440, 98446, 98453, 98460, 98468, 98473,
+    98477, 98482, 98486, 98494, 98503, 98512, 98522, 98527, 98535, 98541,
+    98544, 98548, 98554, 98566, 98573, 98579, 98586, 98594, 98600, 98608,
+    98615, 98620, 98629, 98636, 98640, 98647, 98655, 98665, 98671, 98676,
+    98684, 98689, 98696, 98703, 98710, 98715, 98719, 98725, 98731, 98738,
+    98741, 98748, 98756, 98760, 98765, 98769, 98775, 98782, 98787, 98791,
+    98796, 98804, 98809, 98814, 98822, 98826, 98833, 98838, 98846, 98852,
+    98857, 98862, 98867, 98871, 98876, 98882, 98890, 98900, 98908, 98915,
+    98919, 98926, 98934, 98940, 98945, 98952, 98960, 98968, 98974, 98979,
+    98983, 98988, 98995, 98999, 99007, 99014, 99023, 99032, 99037, 99042,
+    99049, 99057, 99064, 99069, 99075, 99081, 99086, 99089, 99094, 99101,
+    99109, 99117, 99123, 99128, 99140, 99150, 99157, 99164, 99172, 99180,
+    99189, 99195, 99199, 99208, 99212, 99217, 99222, 99226, 99232, 99239,
+    99244, 99251, 99258, 99266, 99275, 99281, 99288, 99295, 99299, 99302,
+    99308, 99315, 99320, 99326, 99332, 99337, 99341, 99345, 99352, 99360,
+    99363, 99368, 99374, 99381, 99387, 99394, 99398, 99402, 99406, 99410,
+    99414, 99419, 99422, 99426, 99432, 99437, 99441, 99447, 99455, 99462,
+    99470, 99479, 99483, 99488, 99493, 99500, 99506, 99512, 99516, 99522,
+    99528, 99536, 99544, 99552, 99559, 99567, 99575, 99583, 99592, 99602,
+    99610, 99619, 99628, 99638, 99643, 99652, 99662, 99672, 99683, 99692,
+    99702, 99706, 99711, 99719, 99728, 99735, 99740, 99747, 99755, 99762,
+    99765, 99770, 99776, 99783, 99787, 99792, 99798, 99804, 99810, 99817,
+    99824, 99828, 99833, 99840, 99846, 99854, 99861, 99866, 99870, 99875,
+    99881, 99887, 99893, 99900, 99905, 99911, 99915, 99921, 99927, 99934,
+    99938, 99943, 99947, 99953, 99960, 99966, 99971, 99976, 99983, 99990,
+    99994, 100000, 100007, 100015, 100021, 100026, 100033, 100042, 100048, 100053,
+    100058, 100064, 100069, 100077, 100086, 100093, 100101, 100107, 100115, 100122,
+    100130, 100134, 100142, 100148, 100153, 100160, 100168, 100174, 100182, 100188,
+    100196, 100204, 100213, 100221, 100230, 100240, 100247, 100255, 100264, 100270,
+    100277, 100281, 100289, 100297, 100303, 100311, 100319, 100322, 100328, 100336,
+    100344, 100351, 100359, 100368, 100372, 100377, 100381, 100388, 100396, 100402,
+    100409, 100412, 100420, 100426, 100429, 100434, 100441, 100449, 100455, 100461,
+    100467, 100473, 100479, 100486, 100492, 100499, 100506, 100510, 100516, 100524,
+    100528, 100531, 100536, 100543, 100551, 100555, 100561, 100567, 100573, 100580,
+    100590, 100597, 100602, 100610, 100614, 100620, 100627, 100635, 100640, 100645,
+    100651, 100658, 100662, 100669, 100675, 100682, 100687, 100692, 100696, 100702,
+    100709, 100717, 100722, 100726, 100732, 100739, 100744, 100749, 100754, 100761,
+    100768, 100776, 100784, 100790, 100796, 100801, 100807, 100813, 100818, 100825,
+    100830, 100837, 100845, 100850, 100856, 100862, 100868, 100875, 100882, 100890,
+    100898, 100907, 100915, 100924, 100927, 100932, 100938, 100945, 100951, 100954,
+    100959, 100966, 100970, 100975, 100979, 100983, 100990, 100996, 101000, 101007,
+    101015, 101023, 101031, 101036, 101039, 101047, 101053, 101058, 101066, 101074,
+    101083, 101090, 101096, 101102, 101111, 101118, 101124, 101132, 101139, 101145,
+    101152, 101163, 101174, 101184, 101195, 101206, 101218, 101228, 101238, 101250,
+    101254, 101260, 101267, 101274, 101283, 101288, 101293, 101301, 101307, 101312,
+    101316, 101322, 101329, 101336, 101341, 101347, 101355, 101365, 101376, 101383,
+    101390, 101398, 101405, 101409, 101415, 101419, 101426, 101433, 101441, 101447,
+    101454, 101458, 101465, 101473, 101479, 101486, 101490, 101496, 101502, 101510,
+    101517, 101523, 101529, 101536, 101542, 101546, 101549, 101555, 101563, 101567,
+    101573, 101581, 101590, 101597, 101604, 101611, 101619, 101628, 101638, 101643,
+    101649, 101654, 101661, 101669, 101677, 101683, 101688, 101696, 101702, 101708,
+    101714, 101722, 101728, 101732, 101739, 101743, 101750, 101757, 101768, 101777,
+    101783, 101790, 101798, 101802, 101807, 101813, 101818, 101825, 101835, 101844,
+    101852, 101860, 101869, 101879, 101889, 101898, 101909, 101918, 101925, 101934,
+    101944, 101953, 101960, 101968, 101972, 101980, 101987, 101995, 102003, 102011,
+    102020, 102030, 102036, 102043, 102047, 102053, 102058, 102066, 102069, 102073,
+    102076, 102080, 102084, 102088, 102092, 102102, 102113, 102117, 102125, 102134,
+    102142, 102150, 102158, 102166, 102171, 102179, 102188, 102198, 102206, 102215,
+    102219, 102225, 102232, 102237, 102245, 102254, 102262, 102267, 102274, 102281,
+    102288, 102296, 102306, 102317, 102325, 102332, 102341, 102350, 102356, 102365,
+    102376, 102383, 102388, 102398, 102404, 102411, 102419, 102425, 102430, 102438,
+    102444, 102449, 102455, 102459, 102463, 102468, 102473, 102477, 102486, 102494,
+    102499, 102508, 102513, 102523, 102534, 102541, 102549, 102558, 102564, 102568,
+    102577, 102581, 102587, 102594, 102602, 102606, 102613, 102621, 102627, 102633,
+    102641, 102651, 102662, 102667, 102672, 102678, 102683, 102689, 102694, 102700,
+    102706, 102714, 102723, 102730, 102735, 102742, 102750, 102760, 102771, 102780,
+    102786, 102793, 102797, 102804, 102812, 102818, 102824, 102831, 102836, 102844,
+    102851, 102855, 102861, 102865, 102870, 102873, 102878, 102884, 102888, 102896,
+    102903, 102909, 102919, 102930, 102939, 102946, 102952, 102959, 102963, 102967,
+    102971, 102975, 102981, 102987, 102995, 103003, 103012, 103021, 103028, 103035,
+    103043, 103051, 103056, 103059, 103065, 103069, 103074, 103082, 103089, 103098,
+    103106, 103114, 103122, 103130, 103140, 103146, 103151, 103158, 103169, 103177,
+    103187, 103193, 103199, 103207, 103214, 103220, 103228, 103238, 103245, 103249,
+    103254, 103264, 103270, 103275, 103281, 103287, 103292, 103301, 103306, 103312,
+    103318, 103325, 103330, 103334, 103340, 103346, 103353, 103358, 103362, 103366,
+    103370, 103376, 103381, 103390, 103395, 103401, 103408, 103416, 103422, 103425,
+    103432, 103439, 103444, 103452, 103460, 103464, 103469, 103474, 103481, 103489,
+    103494, 103500, 103507, 103517, 103525, 103537, 103545, 103554, 103561, 103567,
+    103573, 103580, 103587, 103595, 103603, 103612, 103616, 103622, 103628, 103635,
+    103642, 103649, 103654, 103658, 103668, 103678, 103689, 103694, 103700, 103707,
+    103711, 103716, 103722, 103728, 103735, 103743, 103747, 103752, 103755, 103764,
+    103775, 103781, 103788, 103795, 103800, 103807, 103816, 103821, 103829, 103839,
+    103848, 103852, 103857, 103861, 103869, 103873, 103883, 103889, 103894, 103902,
+    103906, 103912, 103918, 103925, 103934, 103942, 103951, 103958, 103966, 103975,
+    103987, 103992, 104004, 104012, 104016, 104022, 104029, 104036, 104041, 104047,
+    104051, 104057, 104064, 104069, 104073, 104077, 104083, 104091, 104098, 104103,
+    104108, 104114, 104121, 104127, 104135, 104144, 104148, 104154, 104161, 104166,
+    104174, 104178, 104183, 104191, 104195, 104202, 104207, 104211, 104216, 104222,
+    104227, 104233, 104237, 104243, 104247, 104250, 104254, 104260, 104264, 104271,
+    104276, 104280, 104286, 104293, 104299, 104307, 104313, 104320, 104328, 104333,
+    104338, 104345, 104349, 104354, 104360, 104365, 104371, 104376, 104382, 104390,
+    104393, 104398, 104405, 104413, 104419, 104425, 104432, 104440, 104445, 104449,
+    104454, 104462, 104471, 104478, 104487, 104494, 104503, 104514, 104519, 104527,
+    104531, 104538, 104543, 104552, 104561, 104564, 104571, 104579, 104583, 104590,
+    104596, 104602, 104608, 104613, 104623, 104631, 104635, 104641, 104646, 104651,
+    104657, 104662, 104669, 104677, 104682, 104690, 104697, 104701, 104706, 104712,
+    104717, 104722, 104727, 104734, 104738, 104743, 104748, 104753, 104759, 104763,
+    104769, 104776, 104781, 104789, 104793, 104797, 104803, 104810, 104818, 104823,
+    104827, 104835, 104844, 104850, 104856, 104859, 104863, 104868, 104873, 104882,
+    104890, 104898, 104902, 104906, 104911, 104918, 104927, 104935, 104941, 104948,
+    104956, 104960, 104967, 104974, 104982, 104991, 104999, 105008, 105016, 105021,
+    105030, 105038, 105048, 105053, 105059, 105070, 105073, 105078, 105084, 105090,
+    105102, 105107, 105113, 105119, 105126, 105133, 105140, 105148, 105157, 105164,
+    105171, 105176, 105183, 105191, 105200, 105210, 105221, 105226, 105233, 105241,
+    105249, 105258, 105264, 105272, 105281, 105288, 105295, 105308, 105320, 105331,
+    105340, 105347, 105356, 105366, 105374, 105380, 105386, 105393, 105401, 105408,
+    105416, 105420, 105426, 105433, 105438, 105442, 105449, 105455, 105461, 105468,
+    105476, 105483, 105488, 105494, 105498, 105507, 105515, 105523, 105529, 105537,
+    105542, 105550, 105560, 105568, 105578, 105589, 105598, 105609, 105614, 105622,
+    105629, 105634, 105641, 105649, 105659, 105667, 105673, 105677, 105682, 105688,
+    105693, 105702, 105708, 105714, 105722, 105728, 105735, 105742, 105750, 105754,
+    105762, 105767, 105778, 105784, 105793, 105799, 105809, 105818, 105826, 105830,
+    105837, 105843, 105848, 105860, 105871, 105875, 105882, 105886, 105891, 105897,
+    105906, 105913, 105920, 105927, 105930, 105936, 105946, 105953, 105963, 105970,
+    105980, 105988, 105995, 106003, 106011, 106018, 106026, 106034, 106043, 106051,
+    106059, 106063, 106068, 106076, 106087, 106096, 106101, 106110, 106116, 106123,
+    106128, 106135, 106143, 106152, 106159, 106164, 106170, 106175, 106183, 106193,
+    106204, 106214, 106223, 106232, 106240, 106249, 106259, 106270, 106282, 106294,
+    106306, 106313, 106318, 106324, 106330, 106338, 106345, 106352, 106357, 106365,
+    106371, 106379, 106386, 106394, 106400, 106406, 106412, 106418, 106425, 106431,
+    106439, 106446, 106457, 106469, 106481, 106493, 106499, 106509, 106520, 106524,
+    106527, 106532, 106538, 106544, 106551, 106559, 106563, 106566, 106574, 106580,
+    106587, 106592, 106599, 106607, 106614, 106622, 106626, 106631, 106640, 106649,
+    106655, 106663, 106673, 106680, 106685, 106693, 106702, 106708, 106716, 106724,
+    106733, 106739, 106746, 106754, 106761, 106768, 106776, 106780, 106786, 106794,
+    106800, 106807, 106813, 106823, 106831, 106839, 106848, 106857, 106868, 106875,
+    106882, 106890, 106895, 106903, 106909, 106915, 106922, 106928, 106935, 106942,
+    106950, 106957, 106965, 106971, 106979, 106988, 106995, 107002, 107008, 107013,
+    107021, 107025, 107030, 107037, 107045, 107052, 107058, 107062, 107069, 107076,
+    107082, 107089, 107096, 107102, 107111, 107119, 107126, 107132, 107140, 107149,
+    107156, 107163, 107169, 107176, 107185, 107196, 107201, 107205, 107211, 107215,
+    107221, 107228, 107233, 107238, 107245, 107252, 107258, 107264, 107274, 107278,
+    107286, 107295, 107304, 107311, 107319, 107325, 107331, 107338, 107345, 107354,
+    107358, 107364, 107372, 107381, 107389, 107399, 107410, 107422, 107429, 107436,
+    107444, 107451, 107456, 107459, 107466, 107471, 107478, 107485, 107493, 107497,
+    107502, 107510, 107521, 107532, 107543, 107555, 107565, 107574, 107582, 107591,
+    107596, 107600, 107612, 107623, 107629, 107637, 107648, 107654, 107660, 107667,
+    107671, 107676, 107682, 107688, 107696, 107703, 107710, 107718, 107728, 107734,
+    107741, 107748, 107756, 107764, 107773, 107778, 107786, 107795, 107798, 107801,
+    107808, 107817, 107822, 107828, 107835, 107847, 107855, 107865, 107871, 107878,
+    107883, 107892, 107898, 107902, 107912, 107917, 107924, 107933, 107939, 107943,
+    107947, 107953, 107960, 107966, 107970, 107975, 107979, 107986, 107993, 108000,
+    108010, 108021, 108029, 108037, 108042, 108047, 108055, 108064, 108071, 108077,
+    108087, 108094, 108102, 108113, 108125, 108133, 108142, 108146, 108154, 108159,
+    108164, 108172, 108182, 108194, 108203, 108212, 108222, 108233, 108243, 108248,
+    108257, 108266, 108272, 108278, 108286, 108291, 108297, 108303, 108310, 108318,
+    108326, 108335, 108344, 108352, 108361, 108366, 108373, 108382, 108392, 108403,
+    108412, 108420, 108429, 108437, 108445, 108453, 108463, 108474, 108484, 108490,
+    108497, 108503, 108507, 108513, 108521, 108525, 108532, 108540, 108545, 108548,
+    108558, 108565, 108572, 108580, 108585, 108591, 108598, 108606, 108615, 108621,
+    108626, 108632, 108636, 108644, 108650, 108657, 108662, 108668, 108675, 108685,
+    108696, 108704, 108713, 108717, 108724, 108728, 108734, 108741, 108752, 108761,
+    108770, 108780, 108788, 108797, 108805, 108813, 108819, 108824, 108827, 108833,
+    108840, 108848, 108852, 108858, 108865, 108871, 108881, 108890, 108899, 108905,
+    108914, 108922, 108929, 108938, 108948, 108956, 108962, 108970, 108979, 108986,
+    108990, 108995, 109005, 109016, 109025, 109036, 109044, 109053, 109060, 109068,
+    109077, 109084, 109089, 109093, 109099, 109105, 109110, 109116, 109122, 109129,
+    109135, 109142, 109150, 109155, 109162, 109173, 109179, 109186, 109193, 109198,
+    109202, 109206, 109212, 109218, 109226, 109230, 109237, 109245, 109251, 109260,
+    109270, 109277, 109284, 109289, 109296, 109299, 109303, 109311, 109320, 109330,
+    109335, 109343, 109353, 109359, 109370, 109378, 109390, 109399, 109411, 109422,
+    109432, 109442, 109447, 109453, 109461, 109470, 109481, 109488, 109493, 109501,
+    109507, 109514, 109522, 109528, 109539, 109546, 109556, 109568, 109574, 109582,
+    109589, 109594, 109604, 109616, 109622, 109631, 109635, 109639, 109645, 109650,
+    109657, 109665, 109677, 109686, 109695, 109701, 109708, 109718, 109729, 109739,
+    109750, 109760, 109769, 109772, 109778, 109784, 109790, 109798, 109803, 109809,
+    109816, 109824, 109832, 109840, 109850, 109860, 109865, 109874, 109881, 109888,
+    109894, 109901, 109909, 109913, 109918, 109926, 109932, 109940, 109947, 109955,
+    109962, 109970, 109978, 109987, 109996, 110006, 110015, 110019, 110025, 110031,
+    110037, 110041, 110048, 110053, 110062, 110072, 110078, 110087, 110095, 110104,
+    110114, 110124, 110132, 110139, 110149, 110157, 110161, 110167, 110174, 110179,
+    110183, 110189, 110198, 110208, 110214, 110221, 110227, 110237, 110247, 110258,
+    110265, 110272, 110283, 110291, 110296, 110300, 110307, 110312, 110320, 110327,
+    110333, 110339, 110344, 110350, 110354, 110360, 110367, 110374, 110382, 110387,
+    110396, 110400, 110405, 110410, 110417, 110427, 110435, 110441, 110446, 110457,
+    110463, 110470, 110478, 110482, 110491, 110501, 110508, 110518, 110527, 110539,
+    110547, 110556, 110565, 110575, 110582, 110588, 110598, 110606, 110617, 110626,
+    110636, 110644, 110648, 110653, 110663, 110671, 110677, 110684, 110692, 110696,
+    110702, 110707, 110712, 110717, 110726, 110732, 110740, 110747, 110755, 110762,
+    110770, 110780, 110792, 110798, 110802, 110807, 110813, 110821, 110830, 110837,
+    110842, 110853, 110861, 110872, 110882, 110890, 110898, 110909, 110914, 110923,
+    110932, 110940, 110946, 110952, 110962, 110973, 110983, 110992, 110998, 111005,
+    111012, 111022, 111028, 111036, 111044, 111052, 111060, 111069, 111076, 111080,
+    111086, 111092, 111099, 111107, 111114, 111121, 111133, 111143, 111151, 111155,
+    111162, 111170, 111180, 111188, 111194, 111201, 111209, 111217, 111225, 111233,
+    111238, 111243, 111249, 111256, 111260, 111265, 111273, 111283, 111290, 111294,
+    111300, 111307, 111310, 111315, 111320, 111326, 111331, 111337, 111344, 111352,
+    111360, 111364, 111370, 111377, 111382, 111386, 111389, 111395, 111403, 111415,
+    111423, 111432, 111442, 111446, 111454, 111458, 111464, 111471, 111478, 111483,
+    111486, 111491, 111495, 111500, 111507, 111515, 111523, 111529, 111534, 111540,
+    111548, 111557, 111567, 111576, 111586, 111594, 111603, 111609, 111618, 111627,
+    111636, 111645, 111655, 111662, 111667, 111673, 111681, 111688, 111695, 111707,
+    111715, 111723, 111732, 111740, 111746, 111755, 111762, 111770, 111779, 111789,
+    111795, 111802, 111809, 111814, 111820, 111828, 111834, 111839, 111847, 111855,
+    111860, 111866, 111874, 111878, 111884, 111891, 111899, 111904, 111908, 111917,
+    111925, 111934, 111942, 111946, 111953, 111960, 111968, 111972, 111978, 111983,
+    111993, 111996, 112002, 112013, 112022, 112029, 112038, 112045, 112053, 112058,
+    112063, 112067, 112074, 112082, 112090, 112101, 112110, 112118, 112126, 112131,
+    112137, 112144, 112151, 112160, 112170, 112178, 112182, 112188, 112195, 112200,
+    112204, 112214, 112222, 112230, 112239, 112249, 112255, 112263, 112273, 112283,
+    112290, 112300, 112312, 112320, 112328, 112336, 112346, 112354, 112362, 112370,
+    112379, 112386, 112393, 112401, 112410, 112417, 112422, 112429, 112435, 112443,
+    112453, 112462, 112466, 112471, 112476, 112480, 112489, 112496, 112501, 112507,
+    112514, 112522, 112527, 112532, 112536, 112539, 112546, 112553, 112558, 112564,
+    112572, 112580, 112587, 112593, 112599, 112609, 112614, 112620, 112629, 112637,
+    112642, 112648, 112656, 112660, 112667, 112675, 112682, 112688, 112696, 112706,
+    112712, 112718, 112724, 112733, 112741, 112748, 112754, 112761, 112769, 112778,
+    112786, 112792, 112799, 112805, 112812, 112820, 112830, 112834, 112840, 112845,
+    112851, 112855, 112860, 112866, 112870, 112876, 112886, 112895, 112903, 112910,
+    112915, 112920, 112926, 112932, 112938, 112946, 112956, 112963, 112971, 112980,
+    112989, 112999, 113009, 113021, 113032, 113038, 113045, 113051, 113060, 113065,
+    113075, 113086, 113094, 113102, 113111, 113120, 113126, 113134, 113143, 113150,
+    113155, 113160, 113166, 113171, 113179, 113190, 113201, 113210, 113217, 113225,
+    113231, 113236, 113243, 113250, 113258, 113266, 113274, 113279, 113285, 113290,
+    113297, 113305, 113311, 113318, 113322, 113330, 113335, 113343, 113352, 113357,
+    113363, 113368, 113373, 113379, 113385, 113388, 113393, 113398, 113404, 113408,
+    113412, 113418, 113423, 113426, 113432, 113439, 113444, 113450, 113457, 113464,
+    113471, 113474, 113478, 113486, 113491, 113495, 113500, 113504, 113509, 113521,
+    113533, 113543, 113554, 113562, 113571, 113580, 113592, 113602, 113612, 113620,
+    113631, 113640, 113650, 113662, 113672, 113678, 113685, 113693, 113700, 113705,
+    113710, 113715, 113723, 113730, 113739, 113751, 113760, 113765, 113771, 113777,
+    113785, 113793, 113802, 113811, 113820, 113827, 113832, 113838, 113846, 113853,
+    113859, 113866, 113873, 113881, 113885, 113890, 113895, 113901, 113908, 113912,
+    113920, 113929, 113934, 113941, 113950, 113958, 113966, 113975, 113985, 113991,
+    113998, 114002, 114008, 114018, 114025, 114031, 114037, 114044, 114048, 114056,
+    114063, 114071, 114078, 114084, 114092, 114097, 114104, 114110, 114117, 114123,
+    114130, 114138, 114147, 114151, 114156, 114165, 114175, 114181, 114185, 114191,
+    114199, 114208, 114214, 114220, 114228, 114234, 114240, 114246, 114252, 114259,
+    114265, 114274, 114284, 114296, 114303, 114309, 114317, 114326, 114333, 114340,
+    114348, 114352, 114358, 114366, 114378, 114387, 114392, 114397, 114401, 114407,
+    114412, 114417, 114422, 114426, 114431, 114437, 114442, 114450, 114456, 114461,
+    114464, 114470, 114476, 114485, 114492, 114500, 114508, 114517, 114526, 114536,
+    114544, 114550, 114558, 114566, 114575, 114583, 114590, 114595, 114600, 114605,
+    114611, 114619, 114630, 114641, 114652, 114662, 114671, 114678, 114688, 114694,
+    114701, 114709, 114716, 114726, 114737, 114746, 114752, 114759, 114765, 114773,
+    114778, 114784, 114793, 114801, 114811, 114821, 114830, 114834, 114840, 114846,
+    114853, 114860, 114866, 114870, 114876, 114884, 114895, 114904, 114915, 114924,
+    114928, 114936, 114941, 114949, 114955, 114964, 114968, 114974, 114981, 114987,
+    114994, 114999, 115004, 115010, 115018, 115026, 115036, 115045, 115052, 115061,
+    115071, 115081, 115090, 115100, 115111, 115122, 115132, 115140, 115151, 115160,
+    115167, 115174, 115178, 115186, 115192, 115199, 115207, 115214, 115219, 115225,
+    115232, 115236, 115242, 115249, 115254, 115257, 115261, 115267, 115274, 115281,
+    115290, 115300, 115308, 115317, 115323, 115330, 115336, 115343, 115353, 115361,
+    115368, 115376, 115381, 115384, 115391, 115399, 115408, 115413, 115419, 115424,
+    115432, 115436, 115444, 115453, 115463, 115472, 115482, 115490, 115496, 115500,
+    115508, 115514, 115518, 115524, 115529, 115535, 115541, 115552, 115560, 115569,
+    115578, 115586, 115591, 115596, 115604, 115613, 115623, 115632, 115643, 115652,
+    115659, 115668, 115678, 115684, 115693, 115699, 115707, 115717, 115725, 115731,
+    115736, 115741, 115746, 115753, 115760, 115768, 115776, 115781, 115789, 115797,
+    115803, 115813, 115819, 115828, 115831, 115835, 115840, 115845, 115851, 115856,
+    115861, 115868, 115874, 115881, 115890, 115898, 115907, 115917, 115927, 115938,
+    115948, 115955, 115963, 115979, 115983, 115994, 116004, 116013, 116024, 116033,
+    116041, 116048, 116058, 116064, 116071, 116075, 116078, 116082, 116088, 116097,
+    116107, 116115, 116124, 116129, 116134, 116144, 116153, 116162, 116170, 116179,
+    116189, 116198, 116202, 116211, 116220, 116227, 116234, 116242, 116249, 116255,
+    116263, 116268, 116275, 116279, 116287, 116296, 116301, 116306, 116316, 116323,
+    116329, 116339, 116349, 116356, 116363, 116371, 116379, 116385, 116391, 116397,
+    116404, 116413, 116422, 116433, 116437, 116442, 116447, 116455, 116464, 116471,
+    116477, 116484, 116492, 116498, 116501, 116509, 116516, 116522, 116529, 116536,
+    116542, 116549, 116556, 116560, 116565, 116571, 116577, 116584, 116588, 116595,
+    116599, 116605, 116613, 116622, 116629, 116636, 116644, 116651, 116660, 116671,
+    116679, 116689, 116692, 116696, 116701, 116710, 116717, 116723, 116730, 116737,
+    116740, 116746, 116754, 116763, 116772, 116783, 116793, 116797, 116802, 116807,
+    116813, 116816, 116820, 116823, 116827, 116832, 116837, 116840, 116844, 116849,
+    116854, 116861, 116865, 116874, 116882, 116889, 116894, 116901, 116905, 116911,
+    116918, 116923, 116931, 116937, 116945, 116954, 116964, 116973, 116984, 116994,
+    117005, 117012, 117020, 117029, 117039, 117050, 117060, 117066, 117073, 117080,
+    117088, 117095, 117104, 117115, 117123, 117132, 117138, 117147, 117151, 117158,
+    117167, 117174, 117182, 117190, 117199, 117209, 117219, 117230, 117242, 117253,
+    117260, 117268, 117276, 117285, 117293, 117302, 117308, 117316, 117325, 117334,
+    117342, 117350, 117358, 117367, 117377, 117386, 117394, 117404, 117415, 117426,
+    117432, 117442, 117450, 117459, 117466, 117472, 117479, 117488, 117496, 117506,
+    117518, 117528, 117537, 117547, 117555, 117564, 117570, 117579, 117587, 117596,
+    117606, 117618, 117629, 117637, 117645, 117651, 117660, 117665, 117673, 117683,
+    117694, 117703, 117709, 117714, 117721, 117727, 117732, 117739, 117748, 117753,
+    117759, 117765, 117772, 117777, 117784, 117791, 117797, 117803, 117806, 117813,
+    117821, 117827, 117832, 117836, 117839, 117843, 117849, 117853, 117858, 117865,
+    117868, 117875, 117881, 117889, 117897, 117906, 117915, 117922, 117929, 117937,
+    117946, 117956, 117961, 117968, 117976, 117985, 117991, 117998, 118004, 118011,
+    118019, 118026, 118034, 118044, 118053, 118063, 118074, 118081, 118087, 118094,
+    118102, 118110, 118119, 118127, 118130, 118135, 118142, 118146, 118149, 118153,
+    118156, 118161, 118165, 118169, 118177, 118181, 118185, 118188, 118193, 118198,
+    118204, 118210, 118219, 118227, 118236, 118241, 118247, 118255, 118264, 118269,
+    118277, 118281, 118286, 118293, 118301, 118310, 118314, 118319, 118326, 118334,
+    118341, 118351, 118358, 118362, 118370, 118378, 118381, 118388, 118395, 118399,
+    118406, 118413, 118420, 118425, 118431, 118437, 118442, 118449, 118458, 118462,
+    118466, 118472, 118479, 118483, 118487, 118493, 118500, 118504, 118510, 118514,
+    118520, 118526, 118533, 118541, 118547, 118555, 118560, 118565, 118572, 118580,
+    118586, 118593, 118601, 118609, 118617, 118626, 118635, 118646, 118656, 118665,
+    118675, 118683, 118692, 118702, 118709, 118715, 118722, 118730, 118736, 118741,
+    118749, 118758, 118769, 118775, 118782, 118789, 118797, 118805, 118814, 118824,
+    118831, 118840, 118850, 118860, 118863, 118866, 118871, 118876, 118883, 118891,
+    118897, 118904, 118912, 118920, 118930, 118942, 118950, 118959, 118969, 118976,
+    118982, 118988, 118996, 119006, 119014, 119021, 119029, 119036, 119040, 119046,
+    119053, 119057, 119063, 119070, 119077, 119085, 119091, 119099, 119106, 119113,
+    119116, 119121, 119128, 119136, 119144, 119150, 119157, 119165, 119174, 119184,
+    119194, 119205, 119217, 119229, 119235, 119242, 119248, 119256, 119262, 119267,
+    119274, 119282, 119289, 119295, 119302, 119311, 119321, 119331, 119339, 119349,
+    119357, 119360, 119367, 119372, 119379, 119387, 119395, 119404, 119412, 119424,
+    119432, 119441, 119450, 119460, 119469, 119479, 119485, 119491, 119499, 119510,
+    119522, 119530, 119538, 119545, 119551, 119559, 119570, 119580, 119589, 119598,
+    119608, 119618, 119629, 119639, 119646, 119653, 119661, 119671, 119681, 119690,
+    119696, 119702, 119708, 119717, 119725, 119732, 119740, 119749, 119759, 119770,
+    119780, 119791, 119802, 119814, 119824, 119831, 119838, 119848, 119860, 119867,
+    119872, 119878, 119887, 119892, 119898, 119905, 119913, 119917, 119922, 119927,
+    119933, 119937, 119946, 119950, 119956, 119959, 119965, 119972, 119979, 119987,
+    119995, 120004, 120012, 120021, 120028, 120036, 120043, 120051, 120058, 120066,
+    120072, 120080, 120087, 120095, 120100, 120108, 120118, 120124, 120131, 120140,
+    120148, 120155, 120163, 120172, 120180, 120186, 120193, 120199, 120207, 120214,
+    120220, 120227, 120233, 120240, 120247, 120254, 120262, 120270, 120279, 120287,
+    120294, 120302, 120310, 120318, 120329, 120339, 120346, 120354, 120360, 120367,
+    120374, 120382, 120392, 120400, 120408, 120416, 120422, 120426, 120432, 120439,
+    120447, 120456, 120465, 120476, 120485, 120496, 120508, 120515, 120524, 120532,
+    120540, 120547, 120551, 120558, 120565, 120570, 120577, 120581, 120586, 120593,
+    120601, 120610, 120618, 120626, 120634, 120642, 120652, 120663, 120671, 120678,
+    120686, 120697, 120706, 120714, 120722, 120732, 120740, 120748, 120757, 120766,
+    120774, 120782, 120790, 120797, 120807, 120818, 120826, 120833, 120841, 120849,
+    120859, 120867, 120877, 120888, 120897, 120905, 120911, 120920, 120929, 120937,
+    120949, 120956, 120964, 120974, 120982, 120991, 120998, 121003, 121010, 121018,
+    121025, 121035, 121043, 121051, 121059, 121071, 121080, 121088, 121097, 121102,
+    121110, 121119, 121129, 121138, 121147, 121157, 121165, 121172, 121181, 121189,
+    121197, 121205, 121215, 121222, 121230, 121240, 121249, 121260, 121272, 121280,
+    121289, 121292, 121296, 121300, 121305, 121308, 121312, 121315, 121320, 121325,
+    121331, 121340, 121346, 121350, 121354, 121360, 121369, 121374, 121380, 121387,
+    121395, 121401, 121407, 121414, 121419, 121423, 121428, 121437, 121442, 121449,
+    121457, 121465, 121473, 121481, 121487, 121493, 121497, 121504, 121512, 121520,
+    121529, 121535, 121541, 121548, 121554, 121561, 121568, 121573, 121577, 121582,
+    121585, 121591, 121598, 121604, 121611, 121618, 121626, 121633, 121638, 121644,
+    121648, 121653, 121661, 121667, 121671, 121678, 121686, 121691, 121696, 121702,
+    121708, 121712, 121716, 121720, 121726, 121733, 121742, 121750, 121755, 121760,
+    121767, 121774, 121782, 121790, 121799, 121805, 121809, 121815, 121822, 121827,
+    121835, 121842, 121849, 121852, 121858, 121865, 121871, 121879, 121886, 121890,
+    121895, 121900, 121907, 121915, 121923, 121932, 121936, 121945, 121951, 121958,
+    121968, 121974, 121980, 121984, 121989, 121997, 122004, 122012, 122021, 122025,
+    122030, 122036, 122042, 122050, 122058, 122067, 122070, 122077, 122084, 122092,
+    122100, 122110, 122115, 122123, 122129, 122133, 122138, 122145, 122153, 122161,
+    122167, 122172, 122176, 122181, 122190, 122195, 122203, 122210, 122216, 122222,
+    122228, 122235, 122242, 122250, 122259, 122263, 122267, 122273, 122281, 122288,
+    122296, 122303, 122312, 122319, 122325, 122330, 122333, 122337, 122343, 122348,
+    122354, 122359, 122368, 122374, 122383, 122390, 122396, 122403, 122406, 122413,
+    122421, 122429, 122438, 122447, 122453, 122460, 122467, 122475, 122487, 122496,
+    122504, 122512, 122519, 122528, 122539, 122547, 122554, 122563, 122573, 122581,
+    122589, 122599, 122610, 122621, 122630, 122639, 122648, 122658, 122667, 122677,
+    122686, 122694, 122702, 122712, 122719, 122729, 122737, 122746, 122755, 122762,
+    122768, 122775, 122780, 122787, 122796, 122802, 122810, 122817, 122821, 122826,
+    122832, 122841, 122849, 122860, 122870, 122879, 122886, 122892, 122898, 122906,
+    122918, 122924, 122928, 122934, 122941, 122946, 122953, 122961, 122967, 122977,
+    122988, 122994, 123001, 123010, 123018, 123026, 123032, 123038, 123044, 123051,
+    123056, 123060, 123065, 123071, 123078, 123085, 123091, 123098, 123102, 123109,
+    123117, 123123, 123130, 123139, 123150, 123162, 123173, 123185, 123197, 123207,
+    123215, 123224, 123234, 123246, 123257, 123268, 123275, 123282, 123290, 123299,
+    123308, 123319, 123331, 123341, 123349, 123355, 123362, 123371, 123381, 123389,
+    123400, 123412, 123421, 123426, 123431, 123439, 123445, 123452, 123456, 123464,
+    123471, 123479, 123489, 123495, 123504, 123514, 123520, 123526, 123533, 123540,
+    123550, 123562, 123570, 123577, 123586, 123595, 123603, 123612, 123620, 123629,
+    123633, 123638, 123643, 123655, 123661, 123667, 123674, 123680, 123688, 123695,
+    123703, 123710, 123716, 123724, 123731, 123739, 123745, 123750, 123757, 123765,
+    123768, 123773, 123780, 123787, 123795, 123801, 123808, 123814, 123822, 123830,
+    123837, 123845, 123856, 123865, 123869, 123877, 123885, 123895, 123904, 123916,
+    123927, 123936, 123942, 123947, 123954, 123962, 123970, 123977, 123986, 123994,
+    124000, 124005, 124012, 124021, 124032, 124042, 124052, 124061, 124068, 124077,
+    124087, 124095, 124101, 124110, 124120, 124127, 124133, 124142, 124149, 124154,
+    124160, 124166, 124173, 124182, 124192, 124200, 124207, 124212, 124219, 124225,
+    124232, 124237, 124243, 124249, 124256, 124260, 124265, 124273, 124281, 124290,
+    124296, 124299, 124304, 124308, 124311, 124318, 124326, 124331, 124336, 124342,
+    124348, 124355, 124363, 124370, 124378, 124384, 124391, 124398, 124402, 124405,
+    124410, 124418, 124428, 124440, 124449, 124454, 124461, 124468, 124476, 124480,
+    124486, 124493, 124498, 124502, 124508, 124515, 124519, 124524, 124530, 124536,
+    124541, 124545, 124552, 124561, 124569, 124574, 124578, 124584, 124591, 124596,
+    124602, 124606, 124613, 124619, 124627, 124635, 124646, 124656, 124665, 124676,
+    124684, 124689, 124695, 124703, 124710, 124718, 124726, 124734, 124744, 124755,
+    124764, 124774, 124782, 124790, 124794, 124800, 124807, 124811, 124817, 124824,
+    124829, 124833, 124839, 124846, 124850, 124857, 124863, 124870, 124878, 124883,
+    124886, 124892, 124896, 124903, 124910, 124918, 124924, 124931, 124938, 124942,
+    124947, 124953, 124959, 124962, 124967, 124975, 124984, 124991, 124998, 125003,
+    125011, 125017, 125024, 125031, 125039, 125046, 125054, 125063, 125073, 125083,
+    125094, 125105, 125112, 125120, 125130, 125139, 125149, 125157, 125164, 125170,
+    125177, 125182, 125186, 125193, 125201, 125208, 125212, 125220, 125230, 125241,
+    125247, 125254, 125261, 125264, 125270, 125278, 125285, 125288, 125296, 125305,
+    125314, 125324, 125331, 125341, 125352, 125362, 125373, 125383, 125394, 125404,
+    125414, 125419, 125426, 125433, 125443, 125452, 125459, 125468, 125478, 125487,
+    125497, 125508, 125516, 125523, 125534, 125546, 125555, 125564, 125574, 125584,
+    125592, 125599, 125607, 125615, 125622, 125627, 125635, 125641, 125650, 125656,
+    125664, 125674, 125686, 125697, 125708, 125719, 125726, 125736, 125745, 125751,
+    125759, 125766, 125770, 125775, 125779, 125789, 125798, 125809, 125821, 125830,
+    125838, 125847, 125858, 125868, 125879, 125889, 125895, 125902, 125911, 125921,
+    125932, 125942, 125950, 125963, 125974, 125985, 125997, 126006, 126017, 126027,
+    126038, 126050, 126059, 126069, 126080, 126092, 126099, 126108, 126119, 126129,
+    126141, 126151, 126159, 126165, 126172, 126180, 126188, 126199, 126211, 126221,
+    126232, 126241, 126248, 126259, 126271, 126283, 126291, 126300, 126309, 126319,
+    126329, 126339, 126343, 126350, 126360, 126368, 126377, 126389, 126396, 126402,
+    126409, 126417, 126425, 126434, 126442, 126447, 126451, 126456, 126465, 126476,
+    126480, 126489, 126499, 126505, 126510, 126513, 126518, 126524, 126529, 126535,
+    126541, 126549, 126559, 126569, 126580, 126589, 126596, 126602, 126611, 126615,
+    126622, 126627, 126630, 126634, 126640, 126645, 126652, 126660, 126668, 126675,
+    126683, 126690, 126698, 126708, 126718, 126730, 126738, 126748, 126755, 126760,
+    126775, 126781, 126787, 126794, 126802, 126811, 126821, 126831, 126840, 126852,
+    126862, 126868, 126879, 126888, 126899, 126911, 126922, 126934, 126944, 126950,
+    126956, 126962, 126969, 126975, 126981, 126988, 126993, 126999, 127006, 127014,
+    127022, 127028, 127036, 127043, 127055, 127064, 127069, 127078, 127086, 127096,
+    127101, 127110, 127122, 127132, 127144, 127156, 127168, 127179, 127190, 127196,
+    127203, 127209, 127215, 127222, 127229, 127237, 127243, 127251, 127261, 127270,
+    127280, 127289, 127299, 127306, 127316, 127324, 127331, 127339, 127344, 127350,
+    127356, 127360, 127367, 127371, 127377, 127383, 127390, 127396, 127403, 127410,
+    127416, 127423, 127430, 127435, 127441, 127448, 127454, 127461, 127470, 127477,
+    127485, 127493, 127504, 127507, 127512, 127518, 127527, 127533, 127537, 127541,
+    127547, 127554, 127562, 127567, 127571, 127576, 127579, 127585, 127592, 127597,
+    127604, 127612, 127616, 127620, 127625, 127634, 127638, 127643, 127648, 127655,
+    127665, 127673, 127679, 127683, 127690, 127696, 127703, 127709, 127716, 127721,
+    127726, 127733, 127741, 127747, 127753, 127756, 127763, 127769, 127774, 127781,
+    127789, 127793, 127802, 127807, 127811, 127818, 127824, 127828, 127834, 127841,
+    127846, 127854, 127860, 127867, 127875, 127879, 127883, 127888, 127893, 127900,
+    127909, 127919, 127927, 127932, 127935, 127939, 127944, 127952, 127961, 127966,
+    127971, 127978, 127984, 127991, 127996, 128002, 128008, 128014, 128021, 128027,
+    128034, 128040, 128047, 128050, 128054, 128059, 128066, 128073, 128081, 128088,
+    128097, 128105, 128112, 128120, 128124, 128132, 128138, 128145, 128153, 128157,
+    128163, 128170, 128179, 128183, 128190, 128195, 128202, 128209, 128214, 128220,
+    128225, 128231, 128240, 128248, 128255, 128260, 128267, 128273, 128282, 128292,
+    128300, 128309, 128315, 128321, 128327, 128334, 128344, 128350, 128357, 128364,
+    128369, 128374, 128381, 128387, 128396, 128406, 128413, 128417, 128423, 128428,
+    128434, 128440, 128451, 128460, 128467, 128474, 128479, 128485, 128493, 128500,
+    128507, 128515, 128523, 128528, 128533, 128541, 128551, 128562, 128569, 128576,
+    128584, 128592, 128601, 128607, 128613, 128620, 128626, 128633, 128640, 128650,
+    128658, 128663, 128670, 128678, 128684, 128692, 128701, 128707, 128715, 128724,
+    128731, 128739, 128747, 128754, 128762, 128769, 128777, 128789, 128798, 128802,
+    128810, 128818, 128824, 128830, 128837, 128844, 128854, 128865, 128874, 128881,
+    128889, 128897, 128904, 128912, 128917, 128927, 128938, 128943, 128947, 128952,
+    128959, 128967, 128973, 128978, 128986, 128996, 129002, 129009, 129016, 129024,
+    129035, 129043, 129052, 129059, 129067, 129077, 129083, 129090, 129097, 129105,
+    129112, 129121, 129127, 129135, 129141, 129148, 129153, 129159, 129165, 129171,
+    129179, 129186, 129190, 129195, 129202, 129209, 129217, 129225, 129233, 129237,
+    129243, 129250, 129255, 129259, 129264, 129271, 129279, 129283, 129290, 129298,
+    129303, 129307, 129314, 129319, 129326, 129334, 129342, 129347, 129353, 129358,
+    129363, 129370, 129379, 129385, 129392, 129399, 129406, 129414, 129420, 129429,
+    129440, 129449, 129456, 129460, 129465, 129474, 129477, 129483, 129490, 129499,
+    129508, 129513, 129520, 129528, 129534, 129542, 129549, 129552, 129559, 129567,
+    129573, 129580, 129584, 129588, 129593, 129597, 129603, 129611, 129617, 129622,
+    129630, 129635, 129642, 129651, 129658, 129666, 129674, 129683, 129689, 129694,
+    129700, 129706, 129714, 129723, 129732, 129739, 129743, 129748, 129753, 129759,
+    129763, 129766, 129771, 129779, 129791, 129799, 129808, 129812, 129819, 129828,
+    129836, 129841, 129847, 129856, 129865, 129873, 129881, 129887, 129892, 129898,
+    129906, 129915, 129921, 129929, 129939, 129946, 129955, 129966, 129976, 129987,
+    129995, 130003, 130011, 130017, 130022, 130026, 130032, 130038, 130049, 130056,
+    130061, 130069, 130078, 130088, 130095, 130103, 130112, 130116, 130127, 130136,
+    130148, 130156, 130163, 130172, 130180, 130187, 130196, 130204, 130214, 130225,
+    130236, 130247, 130251, 130258, 130262, 130268, 130276, 130281, 130287, 130294,
+    130304, 130311, 130315, 130323, 130329, 130333, 130337, 130343, 130350, 130355,
+    130359, 130365, 130372, 130378, 130381, 130388, 130392, 130397, 130403, 130410,
+    130416, 130423, 130430, 130435, 130439, 130447, 130454, 130464, 130475, 130484,
+    130492, 130501, 130511, 130522, 130530, 130538, 130546, 130551, 130560, 130565,
+    130572, 130579, 130588, 130592, 130597, 130601, 130609, 130615, 130623, 130631,
+    130635, 130643, 130650, 130656, 130663, 130669, 130676, 130682, 130689, 130698,
+    130708, 130715, 130722, 130730, 130741, 130749, 130758, 130769, 130776, 130785,
+    130795, 130804, 130814, 130822, 130827, 130831, 130836, 130841, 130846, 130850,
+    130856, 130861, 130868, 130876, 130884, 130894, 130904, 130915, 130924, 130932,
+    130942, 130952, 130960, 130966, 130971, 130978, 130987, 130996, 131006, 131016,
+    131027, 131037, 131046, 131057, 131065, 131073, 131079, 131083, 131090, 131096,
+    131104, 131113, 131121, 131127, 131133, 131142, 131151, 131158, 131170, 131180,
+    131187, 131195, 131202, 131212, 131222, 131231, 131241, 131249, 131258, 131263,
+    131273, 131282, 131292, 131302, 131309, 131317, 131324, 131327, 131334, 131340,
+    131349, 131355, 131363, 131370, 131376, 131385, 131397, 131408, 131418, 131424,
+    131431, 131435, 131441, 131448, 131455, 131462, 131467, 131474, 131481, 131487,
+    131494, 131499, 131506, 131514, 131520, 131524, 131530, 131537, 131542, 131546,
+    131553, 131560, 131566, 131574, 131581, 131588, 131593, 131600, 131608, 131618,
+    131628, 131637, 131643, 131651, 131654, 131665, 131674, 131685, 131693, 131702,
+    131711, 131721, 131733, 131742, 131752, 131759, 131767, 131773, 131780, 131787,
+    131795, 131800, 131806, 131812, 131816, 131822, 131828, 131835, 131842, 131847,
+    131853, 131861, 131869, 131878, 131886, 131895, 131903, 131913, 131923, 131934,
+    131941, 131949, 131959, 131968, 131978, 131986, 131995, 132002, 132010, 132018,
+    132027, 132035, 132046, 132058, 132070, 132077, 132086, 132095, 132103, 132112,
+    132121, 132133, 132142, 132152, 132160, 132168, 132176, 132185, 132194, 132205,
+    132216, 132228, 132238, 132249, 132258, 132268, 132278, 132285, 132296, 132307,
+    132316, 132326, 132336, 132347, 132357, 132366, 132374, 132386, 132397, 132408,
+    132415, 132426, 132433, 132441, 132449, 132456, 132466, 132477, 132483, 132493,
+    132503, 132513, 132524, 132536, 132545, 132555, 132562, 132568, 132576, 132584,
+    132595, 132604, 132612, 132619, 132630, 132640, 132649, 132659, 132669, 132680,
+    132687, 132695, 132706, 132713, 132724, 132731, 132739, 132748, 132757, 132767,
+    132775, 132782, 132790, 132798, 132805, 132812, 132820, 132828, 132839, 132843,
+    132850, 132861, 132873, 132884, 132891, 132899, 132911, 132919, 132928, 132939,
+    132946, 132958, 132969, 132981, 132990, 132999, 133009, 133019, 133030, 133042,
+    133054, 133062, 133069, 133081, 133090, 133099, 133109, 133118, 133126, 133138,
+    133146, 133155, 133164, 133174, 133180, 133187, 133195, 133205, 133214, 133226,
+    133236, 133244, 133253, 133258, 133265, 133272, 133280, 133288, 133297, 133306,
+    133317, 133325, 133336, 133342, 133352, 133359, 133367, 133375, 133386, 133396,
+    133407, 133414, 133423, 133432, 133442, 133450, 133458, 133469, 133476, 133484,
+    133490, 133497, 133504, 133513, 133523, 133531, 133541, 133550, 133557, 133566,
+    133576, 133586, 133596, 133604, 133611, 133619, 133627, 133637, 133643, 133647,
+    133652, 133658, 133667, 133673, 133681, 133688, 133695, 133702, 133707, 133713,
+    133718, 133724, 133733, 133743, 133751, 133758, 133762, 133769, 133775, 133784,
+    133793, 133800, 133807, 133815, 133820, 133826, 133832, 133839, 133845, 133853,
+    133860, 133869, 133879, 133889, 133897, 133903, 133911, 133918, 133926, 133936,
+    133945, 133956, 133966, 133975, 133985, 133995, 134000, 134007, 134014, 134022,
+    134030, 134038, 134044, 134049, 134059, 134067, 134073, 134078, 134084, 134090,
+    134098, 134107, 134114, 134122, 134129, 134136, 134145, 134155, 134164, 134172,
+    134181, 134191, 134201, 134206, 134211, 134217, 134223, 134226, 134235, 134246,
+    134254, 134262, 134269, 134278, 134283, 134289, 134295, 134302, 134309, 134316,
+    134327, 134335, 134345, 134354, 134364, 134371, 134380, 134390, 134401, 134409,
+    134416, 134425, 134434, 134444, 134454, 134465, 134474, 134484, 134492, 134502,
+    134513, 134522, 134534, 134545, 134552, 134559, 134567, 134578, 134582, 134589,
+    134597, 134605, 134615, 134622, 134627, 134634, 134642, 134650, 134659, 134667,
+    134676, 134683, 134693, 134704, 134714, 134726, 134736, 134744, 134748, 134755,
+    134762, 134771, 134781, 134793, 134804, 134813, 134823, 134834, 134844, 134851,
+    134859, 134867, 134876, 134882, 134892, 134902, 134910, 134917, 134925, 134935,
+    134942, 134951, 134961, 134968, 134980, 134989, 134999, 135006, 135018, 135028,
+    135038, 135049, 135060, 135068, 135076, 135086, 135096, 135107, 135118, 135129,
+    135141, 135149, 135159, 135170, 135181, 135193, 135202, 135209, 135218, 135228,
+    135239, 135249, 135259, 135270, 135279, 135289, 135297, 135308, 135319, 135327,
+    135333, 135341, 135348, 135357, 135361, 135370, 135380, 135389, 135400, 135407,
+    135415, 135423, 135432, 135442, 135447, 135457, 135464, 135472, 135480, 135489,
+    135497, 135506, 135515, 135526, 135536, 135542, 135550, 135559, 135567, 135574,
+    135585, 135590, 135595, 135602, 135609, 135618, 135628, 135636, 135641, 135649,
+    135655, 135659, 135669, 135678, 135688, 135699, 135710, 135717, 135723, 135733,
+    135742, 135751, 135761, 135771, 135777, 135785, 135795, 135803, 135813, 135821,
+    135829, 135836, 135845, 135853, 135865, 135876, 135885, 135895, 135905, 135917,
+    135928, 135936, 135945, 135952, 135960, 135968, 135977, 135988, 136000, 136007,
+    136018, 136028, 136039, 136048, 136053, 136063, 136070, 136075, 136084, 136094,
+    136105, 136116, 136128, 136138, 136149, 136156, 136164, 136175, 136184, 136194,
+    136201, 136210, 136220, 136230, 136238, 136248, 136258, 136270, 136281, 136293,
+    136300, 136307, 136316, 136326, 136336, 136347, 136357, 136366, 136378, 136386,
+    136393, 136401, 136408, 136418, 136429, 136438, 136448, 136458, 136468, 136476,
+    136484, 136493, 136499, 136506, 136515, 136525, 136533, 136543, 136551, 136561,
+    136566, 136573, 136578, 136584, 136590, 136600, 136607, 136617, 136625, 136631,
+    136638, 136646, 136656, 136664, 136673, 136681, 136690, 136698, 136707, 136717,
+    136724, 136733, 136744, 136754, 136761, 136772, 136783, 136790, 136798, 136806,
+    136815, 136822, 136826, 136833, 136838, 136846, 136853, 136862, 136867, 136872,
+    136880, 136887, 136897, 136902, 136908, 136914, 136921, 136924, 136930, 136935,
+    136941, 136947, 136956, 136966, 136971, 136977, 136988, 136999, 137011, 137021,
+    137028, 137037, 137049, 137059, 137068, 137077, 137080, 137087, 137093, 137104,
+    137116, 137125, 137134, 137144, 137152, 137159, 137168, 137177, 137187, 137196,
+    137206, 137210, 137214, 137221, 137227, 137234, 137240, 137247, 137251, 137257,
+    137264, 137269, 137274, 137278, 137284, 137290, 137297, 137304, 137312, 137317,
+    137326, 137330, 137336, 137341, 137347, 137353, 137360, 137364, 137370, 137374,
+    137380, 137387, 137394, 137402, 137407, 137410, 137415, 137422, 137429, 137437,
+    137445, 137455, 137466, 137474, 137480, 137487, 137494, 137500, 137510, 137518,
+    137526, 137535, 137545, 137556, 137564, 137568, 137572, 137576, 137582, 137587,
+    137591, 137594, 137599, 137605, 137611, 137618, 137625, 137630, 137634, 137642,
+    137651, 137660, 137670, 137679, 137689, 137693, 137698, 137704, 137710, 137715,
+    137721, 137730, 137735, 137741, 137747, 137754, 137766, 137774, 137780, 137787,
+    137795, 137801, 137807, 137815, 137822, 137831, 137842, 137850, 137857, 137867,
+    137876, 137884, 137889, 137895, 137901, 137909, 137915, 137922, 137930, 137937,
+    137945, 137952, 137960, 137968, 137975, 137979, 137987, 137993, 137999, 138005,
+    138012, 138017, 138020, 138028, 138034, 138038, 138042, 138049, 138054, 138060,
+    138067, 138074, 138082, 138087, 138094, 138103, 138111, 138115, 138121, 138126,
+    138130, 138140, 138148, 138157, 138166, 138174, 138182, 138187, 138193, 138198,
+    138207, 138216, 138226, 138235, 138242, 138252, 138263, 138272, 138279, 138285,
+    138293, 138299, 138309, 138317, 138329, 138339, 138347, 138355, 138362, 138372,
+    138377, 138383, 138390, 138398, 138406, 138414, 138420, 138429, 138434, 138441,
+    138452, 138461, 138469, 138476, 138484, 138490, 138496, 138502, 138509, 138514,
+    138518, 138524, 138529, 138535, 138540, 138545, 138551, 138559, 138568, 138575,
+    138582, 138587, 138595, 138600, 138605, 138613, 138625, 138635, 138646, 138655,
+    138661, 138666, 138672, 138679, 138685, 138690, 138697, 138704, 138712, 138719,
+    138723, 138728, 138735, 138742, 138749, 138754, 138759, 138766, 138774, 138780,
+    138786, 138791, 138798, 138805, 138810, 138816, 138820, 138825, 138830, 138837,
+    138845, 138851, 138859, 138868, 138872, 138879, 138885, 138890, 138896, 138905,
+    138915, 138920, 138926, 138932, 138940, 138947, 138957, 138963, 138969, 138976,
+    138982, 138987, 138993, 139000, 139004, 139014, 139019, 139028, 139033, 139039,
+    139044, 139053, 139061, 139067, 139073, 139080, 139084, 139090, 139096, 139103,
+    139108, 139116, 139121, 139127, 139133, 139139, 139147, 139154, 139161, 139169,
+    139177, 139186, 139195, 139203, 139210, 139220, 139229, 139234, 139245, 139252,
+    139261, 139267, 139276, 139288, 139294, 139302, 139308, 139314, 139319, 139326,
+    139330, 139336, 139343, 139350, 139355, 139358, 139365, 139369, 139374, 139379,
+    139385, 139391, 139395, 139402, 139409, 139413, 139419, 139425, 139432, 139439,
+    139444, 139448, 139454, 139461, 139469, 139477, 139486, 139491, 139498, 139506,
+    139510, 139517, 139525, 139533, 139541, 139547, 139555, 139565, 139572, 139579,
+    139584, 139593, 139598, 139603, 139609, 139615, 139621, 139628, 139635, 139639,
+    139644, 139650, 139657, 139664, 139669, 139677, 139680, 139686, 139693, 139701,
+    139707, 139711, 139718, 139725, 139732, 139740, 139745, 139749, 139752, 139757,
+    139764, 139772, 139779, 139787, 139793, 139799, 139809, 139817, 139821, 139826,
+    139832, 139838, 139845, 139851, 139858, 139862, 139868, 139875, 139883, 139888,
+    139894, 139898, 139901, 139906, 139914, 139921, 139927, 139933, 139939, 139946,
+    139953, 139960, 139968, 139972, 139979, 139983, 139991, 139997, 140002, 140008,
+    140016, 140022, 140028, 140035, 140039, 140045, 140049, 140058, 140064, 140067,
+    140071, 140076, 140081, 140087, 140099, 140107, 140113, 140119, 140126, 140131,
+    140137, 140145, 140154, 140165, 140176, 140187, 140198, 140210, 140220, 140228,
+    140237, 140244, 140250, 140254, 140260, 140266, 140273, 140280, 140291, 140299,
+    140306, 140313, 140319, 140326, 140333, 140337, 140342, 140349, 140354, 140362,
+    140368, 140374, 140381, 140387, 140390, 140393, 140398, 140402, 140407, 140412,
+    140417, 140424, 140431, 140439, 140444, 140451, 140459, 140467, 140478, 140487,
+    140498, 140506, 140513, 140521, 140527, 140531, 140539, 140545, 140552, 140562,
+    140569, 140576, 140585, 140592, 140600, 140607, 140612, 140617, 140625, 140635,
+    140642, 140650, 140654, 140661, 140668, 140677, 140685, 140694, 140701, 140712,
+    140719, 140727, 140735, 140744, 140750, 140755, 140761, 140768, 140776, 140782,
+    140786, 140792, 140798, 140805, 140813, 140823, 140832, 140843, 140847, 140853,
+    140860, 140870, 140876, 140886, 140896, 140904, 140913, 140920, 140928, 140936,
+    140948, 140958, 140969, 140977, 140986, 140996, 141002, 141008, 141015, 141020,
+    141028, 141037, 141047, 141057, 141063, 141070, 141076, 141083, 141092, 141100,
+    141106, 141114, 141121, 141131, 141138, 141144, 141151, 141156, 141164, 141170,
+    141178, 141187, 141194, 141199, 141208, 141218, 141224, 141230, 141237, 141244,
+    141252, 141259, 141267, 141277, 141284, 141292, 141300, 141309, 141317, 141326,
+    141332, 141340, 141350, 141359, 141371, 141381, 141390, 141398, 141407, 141413,
+    141421, 141429, 141438, 141447, 141455, 141461, 141468, 141477, 141487, 141497,
+    141508, 141515, 141523, 141533, 141539, 141546, 141553, 141561, 141569, 141575,
+    141583, 141592, 141599, 141608, 141619, 141626, 141637, 141649, 141658, 141668,
+    141678, 141689, 141695, 141703, 141712, 141724, 141733, 141744, 141756, 141766,
+    141771, 141780, 141790, 141801, 141811, 141823, 141834, 141840, 141848, 141856,
+    141865, 141874, 141884, 141893, 141900, 141907, 141916, 141926, 141934, 141940,
+    141948, 141955, 141966, 141975, 141985, 141995, 142003, 142011, 142019, 142028,
+    142038, 142050, 142057, 142066, 142076, 142087, 142095, 142104, 142114, 142125,
+    142132, 142143, 142149, 142156, 142161, 142169, 142179, 142188, 142197, 142203,
+    142212, 142219, 142227, 142236, 142239, 142245, 142253, 142260, 142266, 142274,
+    142282, 142290, 142299, 142311, 142321, 142329, 142339, 142348, 142359, 142366,
+    142374, 142384, 142391, 142395, 142403, 142410, 142417, 142424, 142428, 142434,
+    142441, 142448, 142456, 142465, 142475, 142485, 142494, 142501, 142505, 142510,
+    142514, 142519, 142523, 142532, 142542, 142548, 142555, 142560, 142567, 142574,
+    142579, 142582, 142587, 142594, 142602, 142610, 142619, 142629, 142639, 142650,
+    142660, 142671, 142679, 142690, 142698, 142707, 142715, 142724, 142730, 142736,
+    142744, 142750, 142757, 142767, 142778, 142788, 142796, 142804, 142812, 142819,
+    142828, 142838, 142848, 142859, 142869, 142878, 142886, 142892, 142900, 142909,
+    142915, 142926, 142934, 142942, 142951, 142960, 142967, 142977, 142987, 142994,
+    143003, 143011, 143018, 143027, 143037, 143049, 143053, 143062, 143068, 143075,
+    143083, 143090, 143096, 143103, 143114, 143121, 143129, 143135, 143142, 143149,
+    143157, 143167, 143173, 143180, 143187, 143195, 143201, 143209, 143218, 143225,
+    143230, 143237, 143243, 143251, 143260, 143270, 143277, 143284, 143291, 143303,
+    143309, 143316, 143322, 143328, 143335, 143343, 143351, 143361, 143370, 143377,
+    143383, 143391, 143402, 143412, 143419, 143427, 143437, 143448, 143457, 143466,
+    143478, 143486, 143493, 143503, 143514, 143524, 143530, 143537, 143546, 143553,
+    143562, 143569, 143579, 143588, 143596, 143604, 143613, 143622, 143632, 143642,
+    143653, 143662, 143672, 143682, 143687, 143693, 143702, 143712, 143720, 143729,
+    143734, 143741, 143749, 143755, 143759, 143767, 143773, 143782, 143792, 143802,
+    143813, 143818, 143827, 143837, 143845, 143852, 143860, 143868, 143878, 143884,
+    143892, 143901, 143910, 143917, 143924, 143932, 143940, 143949, 143955, 143963,
+    143972, 143979, 143987, 143995, 144001, 144008, 144015, 144023, 144031, 144041,
+    144050, 144062, 144070, 144080, 144089, 144101, 144111, 144116, 144126, 144133,
+    144140, 144148, 144153, 144160, 144168, 144174, 144181, 144189, 144197, 144206,
+    144215, 144225, 144233, 144243, 144255, 144264, 144272, 144283, 144291, 144299,
+    144307, 144314, 144319, 144325, 144331, 144337, 144344, 144350, 144357, 144365,
+    144373, 144382, 144390, 144399, 144408, 144419, 144429, 144435, 144443, 144449,
+    144457, 144463, 144471, 144480, 144490, 144500, 144510, 144519, 144530, 144534,
+    144541, 144547, 144553, 144562, 144570, 144579, 144586, 144592, 144600, 144606,
+    144614, 144620, 144630, 144640, 144648, 144657, 144664, 144674, 144683, 144690,
+    144697, 144705, 144713, 144721, 144727, 144735, 144745, 144756, 144765, 144776,
+    144782, 144790, 144798, 144807, 144816, 144823, 144834, 144843, 144848, 144859,
+    144867, 144874, 144882, 144889, 144898, 144908, 144918, 144925, 144931, 144939,
+    144947, 144956, 144963, 144971, 144977, 144984, 144991, 144999, 145011, 145022,
+    145027, 145033, 145040, 145048, 145054, 145062, 145071, 145078, 145088, 145095,
+    145104, 145114, 145122, 145131, 145136, 145145, 145152, 145160, 145167, 145175,
+    145183, 145192, 145200, 145209, 145219, 145229, 145240, 145246, 145254, 145258,
+    145264, 145271, 145277, 145284, 145291, 145296, 145306, 145317, 145329, 145335,
+    145343, 145352, 145359, 145369, 145380, 145383, 145389, 145398, 145404, 145412,
+    145421, 145428, 145439, 145445, 145456, 145468, 145473, 145482, 145488, 145496,
+    145502, 145510, 145520, 145528, 145537, 145544, 145549, 145557, 145563, 145573,
+    145581, 145591, 145600, 145610, 145621, 145631, 145638, 145646, 145657, 145669,
+    145677, 145686, 145692, 145699, 145706, 145713, 145721, 145730, 145740, 145751,
+    145758, 145765, 145770, 145778, 145784, 145792, 145802, 145810, 145819, 145828,
+    145835, 145841, 145851, 145860, 145874, 145885, 145897, 145907, 145914, 145923,
+    145933, 145943, 145951, 145962, 145969, 145978, 145986, 145994, 146003, 146010,
+    146019, 146027, 146036, 146046, 146056, 146068, 146079, 146091, 146103, 146110,
+    146114, 146121, 146129, 146137, 146147, 146158, 146167, 146178, 146188, 146199,
+    146206, 146214, 146223, 146232, 146241, 146251, 146262, 146268, 146275, 146284,
+    146291, 146300, 146310, 146318, 146325, 146332, 146340, 146351, 146363, 146371,
+    146380, 146389, 146395, 146403, 146409, 146415, 146426, 146433, 146442, 146448,
+    146455, 146462, 146470, 146477, 146485, 146493, 146503, 146513, 146524, 146534,
+    146545, 146551, 146562, 146570, 146579, 146588, 146598, 146604, 146612, 146621,
+    146631, 146642, 146654, 146661, 146669, 146677, 146687, 146696, 146706, 146711,
+    146720, 146729, 146735, 146742, 146751, 146761, 146772, 146781, 146789, 146800,
+    146809, 146816, 146824, 146832, 146841, 146848, 146856, 146862, 146873, 146881,
+    146890, 146897, 146907, 146916, 146921, 146927, 146933, 146943, 146954, 146963,
+    146971, 146980, 146991, 146999, 147008, 147015, 147021, 147027, 147035, 147045,
+    147055, 147066, 147073, 147081, 147089, 147098, 147107, 147117, 147125, 147133,
+    147140, 147146, 147154, 147163, 147170, 147178, 147187, 147194, 147205, 147214,
+    147224, 147236, 147246, 147256, 147268, 147276, 147287, 147298, 147305, 147312,
+    147321, 147331, 147342, 147352, 147360, 147368, 147377, 147388, 147398, 147402,
+    147409, 147418, 147425, 147433, 147444, 147454, 147465, 147471, 147478, 147485,
+    147496, 147503, 147511, 147523, 147534, 147545, 147552, 147560, 147568, 147577,
+    147585, 147595, 147606, 147615, 147625, 147633, 147643, 147654, 147665, 147677,
+    147688, 147697, 147705, 147714, 147725, 147730, 147736, 147745, 147753, 147762,
+    147769, 147775, 147782, 147789, 147797, 147807, 147815, 147825, 147835, 147846,
+    147858, 147864, 147872, 147881, 147890, 147896, 147904, 147912, 147921, 147928,
+    147934, 147944, 147955, 147966, 147975, 147984, 147991, 148001, 148009, 148015,
+    148022, 148029, 148035, 148042, 148049, 148057, 148067, 148074, 148082, 148088,
+    148094, 148100, 148108, 148115, 148123, 148130, 148141, 148150, 148160, 148167,
+    148176, 148186, 148194, 148205, 148214, 148222, 148231, 148241, 148251, 148261,
+    148267, 148275, 148284, 148291, 148297, 148304, 148312, 148319, 148327, 148335,
+    148344, 148352, 148357, 148363, 148366, 148372, 148380, 148386, 148394, 148403,
+    148410, 148415, 148425, 148436, 148442, 148449, 148456, 148464, 148470, 148477,
+    148486, 148494, 148502, 148509, 148517, 148526, 148533, 148541, 148549, 148559,
+    148568, 148577, 148583, 148591, 148600, 148607, 148613, 148621, 148629, 148638,
+    148647, 148654, 148661, 148667, 148674, 148682, 148690, 148699, 148706, 148715,
+    148725, 148732, 148740, 148746, 148753, 148761, 148771, 148777, 148784, 148790,
+    148798, 148805, 148815, 148826, 148833, 148841, 148849, 148857, 148866, 148872,
+    148880, 148889, 148896, 148902, 148910, 148919, 148926, 148935, 148944, 148952,
+    148960, 148970, 148979, 148984, 148994, 149000, 149007, 149014, 149021, 149026,
+    149032, 149038, 149045, 149051, 149059, 149066, 149069, 149075, 149082, 149086,
+    149090, 149094, 149100, 149106, 149113, 149119, 149127, 149131, 149138, 149145,
+    149153, 149156, 149162, 149169, 149175, 149182, 149189, 149193, 149198, 149204,
+    149209, 149214, 149220, 149228, 149237, 149247, 149253, 149257, 149261, 149266,
+    149271, 149279, 149285, 149289, 149292, 149298, 149305, 149310, 149319, 149327,
+    149335, 149343, 149350, 149356, 149361, 149369, 149376, 149381, 149389, 149399,
+    149405, 149409, 149412, 149416, 149422, 149426, 149430, 149434, 149440, 149446,
+    149453, 149458, 149466, 149470, 149475, 149481, 149488, 149492, 149499, 149506,
+    149513, 149518, 149521, 149525, 149530, 149537, 149545, 149551, 149557, 149564,
+    149570, 149577, 149584, 149592, 149596, 149600, 149605, 149610, 149615, 149621,
+    149625, 149631, 149638, 149643, 149648, 149652, 149657, 149663, 149671, 149678,
+    149683, 149692, 149699, 149705, 149710, 149720, 149726, 149735, 149740, 149747,
+    149755, 149761, 149766, 149770, 149775, 149783, 149790, 149798, 149802, 149808,
+    149815, 149819, 149823, 149829, 149836, 149841, 149846, 149853, 149861, 149864,
+    149870, 149876, 149885, 149892, 149899, 149906, 149910, 149915, 149920, 149925,
+    149934, 149940, 149945, 149952, 149960, 149966, 149970, 149976, 149986, 149990,
+    149996, 150002, 150009, 150015, 150022, 150029, 150034, 150039, 150045, 150048,
+    150052, 150058, 150065, 150070, 150074, 150077, 150084, 150089, 150095, 150100,
+    150106, 150110, 150115, 150119, 150127, 150133, 150139, 150146, 150153, 150158,
+    150165, 150170, 150177, 150185, 150193, 150204, 150208, 150214, 150218, 150222,
+    150228, 150235, 150240, 150247, 150255, 150259, 150265, 150272, 150276, 150283,
+    150291, 150296, 150301, 150308, 150316, 150320, 150326, 150333, 150338, 150342,
+    150347, 150353, 150357, 150364, 150372, 150377, 150384, 150392, 150400, 150406,
+    150413, 150420, 150424, 150427, 150433, 150439, 150446, 150453, 150461, 150469,
+    150479, 150488, 150492, 150497, 150503, 150509, 150515, 150522, 150529, 150534,
+    150539, 150546, 150553, 150562, 150570, 150575, 150585, 150592, 150602, 150610,
+    150617, 150623, 150630, 150635, 150641, 150648, 150652, 150657, 150663, 150669,
+    150675, 150682, 150691, 150699, 150706, 150711, 150717, 150723, 150726, 150733,
+    150738, 150743, 150748, 150756, 150762, 150766, 150771, 150779, 150788, 150794,
+    150803, 150810, 150813, 150819, 150825, 150832, 150839, 150846, 150853, 150859,
+    150866, 150872, 150877, 150883, 150889, 150896, 150900, 150904, 150910, 150915,
+    150919, 150925, 150933, 150944, 150947, 150953, 150961, 150965, 150972, 150975,
+    150980, 150986, 150990, 150994, 151000, 151007, 151014, 151019, 151023, 151028,
+    151033, 151039, 151044, 151050, 151057, 151060, 151066, 151073, 151081, 151089,
+    151094, 151101, 151107, 151114, 151117, 151124, 151132, 151139, 151143, 151148,
+    151152, 151157, 151163, 151170, 151177, 151183, 151187, 151193, 151200, 151205,
+    151211, 151218, 151226, 151234, 151239, 151243, 151247, 151253, 151259, 151266,
+    151270, 151276, 151280, 151286, 151292, 151299, 151305, 151312, 151320, 151325,
+    151328, 151336, 151340, 151343, 151353, 151358, 151363, 151371, 151376, 151382,
+    151386, 151392, 151399, 151404, 151413, 151424, 151434, 151440, 151449, 151459,
+    151469, 151480, 151491, 151495, 151498, 151504, 151512, 151519, 151525, 151532,
+    151539, 151547, 151552, 151559, 151565, 151569, 151578, 151590, 151600, 151606,
+    151611, 151617, 151623, 151630, 151633, 151637, 151645, 151650, 151654, 151659,
+    151665, 151672, 151676, 151684, 151690, 151697, 151703, 151710, 151715, 151720,
+    151727, 151733, 151738, 151742, 151747, 151753, 151763, 151771, 151779, 151785,
+    151789, 151794, 151802, 151810, 151821, 151832, 151840, 151847, 151853, 151861,
+    151867, 151872, 151878, 151888, 151893, 151899, 151905, 151912, 151917, 151921,
+    151927, 151933, 151938, 151947, 151952, 151960, 151966, 151973, 151981, 151988,
+    151996, 152005, 152010, 152015, 152020, 152024, 152032, 152038, 152045, 152052,
+    152059, 152067, 152074, 152084, 152094, 152102, 152110, 152120, 152129, 152137,
+    152148, 152157, 152164, 152168, 152174, 152181, 152188, 152196, 152202, 152209,
+    152216, 152223, 152228, 152237, 152245, 152255, 152260, 152264, 152268, 152276,
+    152284, 152294, 152302, 152308, 152312, 152316, 152319, 152326, 152334, 152342,
+    152346, 152353, 152362, 152369, 152380, 152388, 152396, 152400, 152404, 152407,
+    152414, 152421, 152430, 152440, 152445, 152451, 152458, 152467, 152475, 152487,
+    152499, 152508, 152517, 152524, 152534, 152542, 152551, 152561, 152567, 152572,
+    152580, 152586, 152593, 152599, 152604, 152609, 152616, 152624, 152629, 152635,
+    152643, 152651, 152658, 152665, 152673, 152677, 152682, 152687, 152693, 152698,
+    152704, 152711, 152717, 152724, 152729, 152736, 152744, 152750, 152755, 152758,
+    152765, 152770, 152776, 152783, 152791, 152795, 152799, 152805, 152808, 152817,
+    152828, 152831, 152837, 152844, 152848, 152852, 152860, 152868, 152879, 152885,
+    152890, 152898, 152903, 152909, 152915, 152922, 152929, 152937, 152942, 152949,
+    152954, 152958, 152965, 152969, 152976, 152986, 152994, 153001, 153008, 153016,
+    153024, 153029, 153034, 153040, 153047, 153051, 153057, 153065, 153073, 153078,
+    153084, 153090, 153095, 153102, 153109, 153116, 153124, 153129, 153136, 153141,
+    153149, 153156, 153165, 153175, 153183, 153191, 153200, 153205, 153212, 153218,
+    153224, 153229, 153236, 153242, 153250, 153259, 153268, 153278, 153284, 153293,
+    153299, 153306, 153314, 153320, 153328, 153335, 153344, 153352, 153363, 153375,
+    153385, 153391, 153400, 153408, 153419, 153428, 153435, 153443, 153450, 153457,
+    153465, 153475, 153484, 153494, 153499, 153506, 153514, 153523, 153530, 153535,
+    153542, 153550, 153555, 153562, 153569, 153574, 153580, 153586, 153594, 153599,
+    153609, 153615, 153624, 153630, 153637, 153643, 153650, 153655, 153662, 153670,
+    153678, 153687, 153693, 153698, 153705, 153712, 153720, 153725, 153732, 153740,
+    153746, 153751, 153758, 153766, 153774, 153783, 153793, 153798, 153804, 153811,
+    153818, 153826, 153833, 153841, 153849, 153858, 153864, 153871, 153880, 153889,
+    153899, 153907, 153914, 153920, 153928, 153937, 153944, 153951, 153957, 153963,
+    153971, 153980, 153990, 154000, 154011, 154018, 154023, 154029, 154035, 154042,
+    154051, 154057, 154065, 154074, 154081, 154091, 154100, 154110, 154116, 154125,
+    154132, 154139, 154144, 154152, 154159, 154167, 154178, 154186, 154191, 154198,
+    154204, 154210, 154218, 154226, 154235, 154245, 154254, 154264, 154272, 154278,
+    154286, 154292, 154295, 154301, 154309, 154317, 154324, 154328, 154335, 154341,
+    154348, 154353, 154357, 154365, 154370, 154378, 154385, 154389, 154395, 154403,
+    154411, 154419, 154428, 154439, 154445, 154452, 154457, 154461, 154469, 154476,
+    154482, 154490, 154500, 154508, 154517, 154524, 154528, 154534, 154541, 154546,
+    154553, 154560, 154566, 154572, 154581, 154589, 154598, 154604, 154613, 154621,
+    154629, 154636, 154643, 154649, 154660, 154671, 154680, 154687, 154695, 154703,
+    154712, 154722, 154731, 154739, 154746, 154750, 154759, 154766, 154775, 154783,
+    154789, 154796, 154801, 154808, 154818, 154824, 154831, 154839, 154846, 154854,
+    154864, 154872, 154877, 154883, 154891, 154899, 154908, 154913, 154921, 154932,
+    154941, 154949, 154955, 154962, 154970, 154979, 154988, 154992, 154998, 155005,
+    155013, 155022, 155027, 155032, 155038, 155042, 155048, 155055, 155062, 155067,
+    155071, 155077, 155084, 155093, 155098, 155102, 155106, 155113, 155119, 155126,
+    155130, 155135, 155139, 155145, 155153, 155160, 155172, 155181, 155189, 155199,
+    155210, 155215, 155222, 155227, 155233, 155239, 155246, 155253, 155261, 155267,
+    155273, 155281, 155290, 155299, 155309, 155318, 155329, 155340, 155348, 155357,
+    155364, 155372, 155376, 155383, 155394, 155402, 155406, 155412, 155419, 155426,
+    155431, 155438, 155444, 155452, 155464, 155473, 155482, 155491, 155499, 155508,
+    155512, 155521, 155531, 155538, 155545, 155555, 155563, 155571, 155580, 155586,
+    155593, 155603, 155611, 155615, 155621, 155628, 155633, 155639, 155645, 155654,
+    155661, 155670, 155681, 155691, 155696, 155702, 155711, 155717, 155728, 155736,
+    155744, 155751, 155760, 155771, 155777, 155784, 155791, 155798, 155808, 155816,
+    155820, 155828, 155837, 155846, 155856, 155864, 155873, 155884, 155894, 155902,
+    155910, 155916, 155922, 155931, 155939, 155948, 155958, 155967, 155977, 155987,
+    155998, 156008, 156019, 156028, 156033, 156039, 156044, 156050, 156056, 156062,
+    156069, 156077, 156086, 156095, 156105, 156115, 156127, 156134, 156142, 156148,
+    156156, 156164, 156168, 156175, 156180, 156185, 156193, 156202, 156208, 156218,
+    156226, 156233, 156239, 156244, 156251, 156260, 156271, 156277, 156284, 156290,
+    156297, 156307, 156315, 156323, 156328, 156335, 156343, 156348, 156354, 156360,
+    156367, 156373, 156380, 156388, 156398, 156406, 156415, 156422, 156429, 156437,
+    156446, 156452, 156459, 156467, 156470, 156477, 156485, 156489, 156495, 156502,
+    156510, 156516, 156523, 156533, 156544, 156551, 156559, 156566, 156574, 156579,
+    156585, 156590, 156596, 156605, 156616, 156623, 156632, 156639, 156644, 156651,
+    156660, 156666, 156673, 156681, 156689, 156697, 156705, 156708, 156714, 156719,
+    156724, 156732, 156738, 156744, 156748, 156751, 156756, 156762, 156768, 156774,
+    156780, 156789, 156793, 156799, 156804, 156811, 156819, 156827, 156833, 156837,
+    156842, 156848, 156854, 156860, 156867, 156873, 156881, 156888, 156895, 156900,
+    156905, 156911, 156917, 156921, 156926, 156935, 156941, 156947, 156954, 156960,
+    156967, 156972, 156977, 156982, 156990, 156997, 157005, 157010, 157014, 157020,
+    157027, 157035, 157040, 157046, 157054, 157061, 157069, 157077, 157082, 157088,
+    157094, 157099, 157105, 157112, 157118, 157125, 157131, 157136, 157142, 157153,
+    157165, 157171, 157177, 157184, 157189, 157195, 157200, 157207, 157216, 157223,
+    157231, 157238, 157245, 157254, 157264, 157269, 157275, 157281, 157287, 157294,
+    157302, 157307, 157313, 157319, 157324, 157329, 157336, 157343, 157351, 157357,
+    157363, 157371, 157378, 157384, 157391, 157395, 157403, 157408, 157413, 157418,
+    157423, 157428, 157436, 157442, 157447, 157453, 157458, 157463, 157470, 157479,
+    157487, 157493, 157500, 157509, 157517, 157524, 157531, 157539, 157547, 157556,
+    157563, 157571, 157577, 157582, 157588, 157596, 157605, 157612, 157617, 157624,
+    157632, 157638, 157644, 157652, 157661, 157665, 157672, 157682, 157686, 157691,
+    157697, 157703, 157710, 157718, 157725, 157730, 157734, 157746, 157754, 157763,
+    157770, 157777, 157785, 157793, 157798, 157807, 157817, 157825, 157834, 157839,
+    157844, 157849, 157855, 157861, 157869, 157878, 157885, 157890, 157896, 157901,
+    157908, 157916, 157922, 157926, 157932, 157936, 157945, 157950, 157955, 157960,
+    157965, 157972, 157980, 157988, 157997, 158005, 158011, 158015, 158022, 158030,
+    158038, 158043, 158048, 158057, 158063, 158068, 158073, 158081, 158090, 158102,
+    158110, 158119, 158126, 158135, 158145, 158153, 158160, 158168, 158177, 158186,
+    158193, 158199, 158208, 158212, 158219, 158227, 158232, 158240, 158249, 158254,
+    158261, 158269, 158275, 158280, 158286, 158292, 158299, 158306, 158310, 158318,
+    158326, 158335, 158344, 158354, 158362, 158368, 158374, 158382, 158389, 158396,
+    158401, 158409, 158414, 158419, 158425, 158433, 158438, 158446, 158452, 158457,
+    158463, 158471, 158477, 158485, 158492, 158498, 158504, 158511, 158517, 158524,
+    158530, 158539, 158548, 158555, 158562, 158568, 158576, 158583, 158588, 158594,
+    158599, 158607, 158613, 158619, 158627, 158634, 158643, 158650, 158658, 158667,
+    158671, 158678, 158683, 158690, 158698, 158704, 158708, 158716, 158723, 158731,
+    158736, 158743, 158751, 158759, 158766, 158769, 158774, 158781, 158788, 158796,
+    158801, 158805, 158812, 158818, 158826, 158832, 158838, 158846, 158850, 158857,
+    158866, 158874, 158879, 158887, 158895, 158904, 158913, 158918, 158926, 158935,
+    158943, 158949, 158956, 158961, 158967, 158973, 158979, 158984, 158988, 158994,
+    159001, 159005, 159011, 159018, 159023, 159028, 159035, 159043, 159052, 159058,
+    159069, 159074, 159078, 159084, 159092, 159101, 159110, 159120, 159127, 159138,
+    159147, 159156, 159166, 159172, 159178, 159185, 159191, 159203, 159214, 159223,
+    159232, 159239, 159249, 159256, 159264, 159269, 159275, 159282, 159290, 159298,
+    159306, 159312, 159320, 159330, 159336, 159344, 159351, 159359, 159363, 159369,
+    159374, 159379, 159383, 159388, 159393, 159397, 159402, 159406, 159412, 159422,
+    159428, 159435, 159447, 159457, 159466, 159472, 159479, 159485, 159493, 159499,
+    159506, 159514, 159521, 159531, 159541, 159551, 159559, 159570, 159580, 159586,
+    159590, 159598, 159607, 159617, 159627, 159638, 159647, 159656, 159668, 159671,
+    159676, 159683, 159692, 159701, 159705, 159711, 159717, 159721, 159726, 159732,
+    159739, 159746, 159752, 159759, 159766, 159775, 159781, 159786, 159794, 159805,
+    159815, 159823, 159827, 159835, 159842, 159847, 159853, 159859, 159866, 159873,
+    159877, 159884, 159889, 159896, 159906, 159909, 159915, 159921, 159928, 159932,
+    159935, 159939, 159944, 159949, 159955, 159959, 159962, 159968, 159975, 159978,
+    159984, 159991, 159995, 160000, 160005, 160011, 160017, 160021, 160027, 160034,
+    160041, 160049, 160056, 160064, 160072, 160081, 160091, 160094, 160102, 160109,
+    160118, 160123, 160130, 160135, 160142, 160146, 160154, 160159, 160164, 160170,
+    160176, 160184, 160189, 160195, 160202, 160208, 160215, 160223, 160231, 160240,
+    160247, 160256, 160266, 160274, 160280, 160288, 160296, 160303, 160307, 160313,
+    160319, 160322, 160326, 160333, 160338, 160344, 160349, 160354, 160360, 160365,
+    160372, 160379, 160387, 160397, 160403, 160411, 160415, 160422, 160430, 160434,
+    160441, 160447, 160452, 160456, 160463, 160470, 160478, 160483, 160491, 160501,
+    160506, 160513, 160521, 160527, 160531, 160535, 160540, 160546, 160551, 160554,
+    160562, 160569, 160579, 160590, 160597, 160603, 160607, 160612, 160617, 160623,
+    160628, 160632, 160639, 160647, 160654, 160659, 160664, 160671, 160679, 160683,
+    160690, 160698, 160703, 160708, 160715, 160723, 160728, 160734, 160740, 160745,
+    160754, 160765, 160772, 160778, 160785, 160789, 160795, 160802, 160806, 160811,
+    160816, 160823, 160831, 160839, 160845, 160851, 160856, 160862, 160869, 160875,
+    160882, 160887, 160891, 160896, 160902, 160908, 160915, 160920, 160924, 160929,
+    160935, 160941, 160948, 160954, 160962, 160971, 160979, 160983, 160988, 160993,
+    160998, 161004, 161008, 161016, 161023, 161030, 161038, 161046, 161054, 161059,
+    161063, 161070, 161075, 161081, 161085, 161089, 161095, 161102, 161107, 161111,
+    161116, 161122, 161129, 161135, 161139, 161144, 161149, 161156, 161162, 161166,
+    161174, 161180, 161186, 161193, 161200, 161206, 161214, 161219, 161225, 161229,
+    161237, 161245, 161250, 161254, 161261, 161266, 161273, 161278, 161283, 161287,
+    161294, 161299, 161302, 161307, 161314, 161322, 161328, 161333, 161340, 161348,
+    161356, 161361, 161368, 161376, 161383, 161393, 161404, 161409, 161416, 161424,
+    161429, 161436, 161444, 161450, 161455, 161462, 161470, 161476, 161482, 161487,
+    161494, 161502, 161507, 161513, 161519, 161525, 161532, 161537, 161544, 161549,
+    161555, 161562, 161567, 161571, 161577, 161582, 161588, 161595, 161601, 161608,
+    161613, 161620, 161626, 161634, 161642, 161651, 161659, 161664, 161671, 161677,
+    161681, 161688, 161696, 161705, 161714, 161719, 161725, 161729, 161734, 161740,
+    161745, 161751, 161755, 161762, 161769, 161777, 161782, 161790, 161795, 161801,
+    161806, 161811, 161818, 161826, 161834, 161842, 161847, 161854, 161861, 161869,
+    161877, 161883, 161889, 161894, 161901, 161907, 161915, 161920, 161927, 161934,
+    161942, 161947, 161954, 161961, 161967, 161971, 161979, 161986, 161991, 161997,
+    162002, 162009, 162016, 162021, 162028, 162036, 162041, 162045, 162053, 162062,
+    162070, 162077, 162082, 162087, 162091, 162095, 162102, 162110, 162116, 162120,
+    162126, 162133, 162137, 162142, 162147, 162151, 162157, 162164, 162167, 162173,
+    162180, 162185, 162192, 162200, 162207, 162215, 162219, 162225, 162233, 162239,
+    162248, 162257, 162267, 162276, 162286, 162297, 162305, 162313, 162322, 162329,
+    162341, 162352, 162364, 162373, 162377, 162383, 162390, 162395, 162398, 162402,
+    162408, 162414, 162418, 162423, 162427, 162435, 162441, 162449, 162458, 162465,
+    162471, 162477, 162485, 162493, 162501, 162506, 162510, 162516, 162521, 162528,
+    162534, 162539, 162543, 162549, 162557, 162564, 162572, 162576, 162582, 162588,
+    162596, 162604, 162609, 162616, 162628, 162637, 162646, 162656, 162661, 162671,
+    162681, 162689, 162697, 162704, 162710, 162718, 162726, 162730, 162737, 162745,
+    162750, 162758, 162768, 162775, 162781, 162789, 162798, 162803, 162809, 162817,
+    162824, 162832, 162838, 162845, 162851, 162858, 162864, 162868, 162876, 162883,
+    162890, 162897, 162906, 162915, 162923, 162932, 162940, 162949, 162952, 162957,
+    162963, 162970, 162974, 162979, 162989, 163000, 163005, 163011, 163018, 163023,
+    163031, 163035, 163039, 163045, 163049, 163055, 163062, 163070, 163073, 163083,
+    163090, 163103, 163112, 163119, 163127, 163134, 163140, 163144, 163150, 163158,
+    163163, 163170, 163178, 163184, 163190, 163197, 163202, 163206, 163212, 163218,
+    163225, 163232, 163237, 163243, 163247, 163254, 163259, 163264, 163271, 163278,
+    163286, 163293, 163299, 163309, 163320, 163324, 163329, 163333, 163339, 163346,
+    163353, 163361, 163367, 163372, 163382, 163391, 163403, 163412, 163420, 163431,
+    163440, 163450, 163459, 163471, 163479, 163488, 163497, 163508, 163514, 163521,
+    163524, 163529, 163535, 163539, 163543, 163546, 163550, 163557, 163565, 163568,
+    163573, 163583, 163589, 163600, 163606, 163615, 163621, 163628, 163636, 163641,
+    163650, 163655, 163659, 163663, 163671, 163678, 163683, 163691, 163698, 163706,
+    163711, 163715, 163720, 163726, 163732, 163739, 163748, 163753, 163760, 163768,
+    163775, 163783, 163792, 163798, 163805, 163813, 163819, 163827, 163834, 163838,
+    163843, 163849, 163856, 163860, 163865, 163872, 163881, 163888, 163893, 163900,
+    163908, 163913, 163920, 163928, 163936, 163942, 163947, 163958, 163964, 163968,
+    163975, 163985, 163996, 164006, 164017, 164028, 164040, 164049, 164057, 164068,
+    164077, 164083, 164090, 164098, 164110, 164119, 164128, 164137, 164144, 164154,
+    164162, 164171, 164179, 164184, 164191, 164199, 164205, 164210, 164219, 164229,
+    164240, 164249, 164259, 164266, 164273, 164281, 164293, 164301, 164309, 164318,
+    164328, 164339, 164351, 164362, 164373, 164377, 164383, 164391, 164401, 164406,
+    164413, 164421, 164429, 164435, 164443, 164449, 164454, 164461, 164469, 164478,
+    164484, 164489, 164494, 164501, 164509, 164515, 164520, 164524, 164530, 164537,
+    164546, 164555, 164561, 164566, 164572, 164578, 164583, 164589, 164596, 164601,
+    164606, 164611, 164617, 164623, 164628, 164635, 164643, 164649, 164657, 164662,
+    164666, 164673, 164679, 164686, 164694, 164699, 164705, 164712, 164720, 164728,
+    164733, 164741, 164746, 164752, 164759, 164764, 164770, 164776, 164784, 164791,
+    164800, 164812, 164823, 164827, 164832, 164840, 164848, 164857, 164862, 164870,
+    164876, 164884, 164892, 164899, 164905, 164913, 164921, 164927, 164934, 164942,
+    164948, 164955, 164961, 164969, 164976, 164981, 164987, 164996, 165003, 165008,
+    165016, 165023, 165030, 165038, 165044, 165050, 165055, 165061, 165067, 165076,
+    165085, 165091, 165098, 165104, 165111, 165120, 165130, 165138, 165149, 165160,
+    165171, 165176, 165181, 165187, 165192, 165197, 165205, 165211, 165219, 165224,
+    165230, 165235, 165242, 165250, 165258, 165264, 165273, 165277, 165285, 165294,
+    165299, 165306, 165314, 165320, 165326, 165333, 165338, 165344, 165350, 165358,
+    165367, 165372, 165379, 165387, 165393, 165399, 165408, 165415, 165426, 165431,
+    165436, 165442, 165448, 165459, 165468, 165475, 165483, 165492, 165498, 165506,
+    165514, 165521, 165527, 165533, 165541, 165548, 165554, 165560, 165564, 165568,
+    165572, 165580, 165587, 165594, 165599, 165604, 165610, 165617, 165623, 165626,
+    165632, 165637, 165645, 165654, 165660, 165667, 165673, 165680, 165686, 165693,
+    165701, 165708, 165716, 165722, 165730, 165735, 165743, 165751, 165760, 165766,
+    165772, 165780, 165787, 165793, 165801, 165808, 165816, 165824, 165833, 165838,
+    165843, 165849, 165857, 165865, 165871, 165878, 165884, 165892, 165900, 165909,
+    165916, 165920, 165927, 165935, 165944, 165953, 165963, 165974, 165980, 165987,
+    165992, 166000, 166005, 166012, 166020, 166026, 166033, 166041, 166046, 166053,
+    166061, 166069, 166075, 166079, 166084, 166094, 166100, 166106, 166112, 166119,
+    166128, 166138, 166145, 166153, 166163, 166168, 166173, 166180, 166188, 166197,
+    166203, 166208, 166217, 166227, 166233, 166241, 166246, 166252, 166258, 166265,
+    166270, 166279, 166284, 166291, 166298, 166306, 166312, 166317, 166324, 166332,
+    166340, 166349, 166355, 166363, 166370, 166377, 166384, 166389, 166396, 166404,
+    166412, 166418, 166424, 166431, 166436, 166444, 166456, 166465, 166472, 166480,
+    166489, 166497, 166505, 166515, 166521, 166527, 166534, 166540, 166547, 166551,
+    166560, 166566, 166574, 166582, 166589, 166594, 166600, 166606, 166614, 166621,
+    166626, 166633, 166642, 166650, 166657, 166665, 166671, 166676, 166681, 166688,
+    166695, 166703, 166711, 166718, 166726, 166735, 166741, 166748, 166756, 166766,
+    166772, 166779, 166787, 166792, 166799, 166805, 166809, 166814, 166820, 166829,
+    166836, 166845, 166855, 166861, 166870, 166879, 166888, 166894, 166901, 166908,
+    166918, 166927, 166937, 166945, 166954, 166965, 166975, 166980, 166988, 166994,
+    167001, 167008, 167014, 167022, 167029, 167037, 167046, 167053, 167058, 167062,
+    167068, 167075, 167080, 167085, 167094, 167102, 167110, 167116, 167121, 167127,
+    167132, 167140, 167146, 167153, 167158, 167167, 167174, 167181, 167189, 167197,
+    167206, 167211, 167216, 167222, 167228, 167233, 167240, 167247, 167255, 167262,
+    167269, 167274, 167281, 167289, 167295, 167300, 167307, 167311, 167318, 167326,
+    167331, 167337, 167344, 167349, 167353, 167363, 167373, 167379, 167386, 167394,
+    167399, 167406, 167412, 167422, 167433, 167444, 167456, 167463, 167472, 167480,
+    167485, 167492, 167499, 167506, 167514, 167518, 167525, 167533, 167544, 167550,
+    167555, 167562, 167570, 167578, 167584, 167590, 167595, 167602, 167611, 167618,
+    167625, 167634, 167640, 167647, 167655, 167661, 167666, 167672, 167677, 167684,
+    167693, 167699, 167706, 167712, 167721, 167731, 167740, 167750, 167760, 167771,
+    167782, 167789, 167797, 167802, 167810, 167818, 167824, 167830, 167835, 167843,
+    167848, 167854, 167861, 167871, 167882, 167886, 167893, 167901, 167908, 167916,
+    167921, 167927, 167935, 167943, 167953, 167958, 167966, 167973, 167984, 167996,
+    168004, 168013, 168022, 168028, 168034, 168040, 168045, 168053, 168059, 168064,
+    168070, 168076, 168081, 168087, 168093, 168100, 168108, 168113, 168120, 168128,
+    168133, 168139, 168145, 168154, 168163, 168169, 168174, 168179, 168186, 168191,
+    168197, 168202, 168209, 168217, 168221, 168229, 168236, 168243, 168251, 168256,
+    168263, 168268, 168274, 168280, 168287, 168294, 168301, 168306, 168311, 168318,
+    168326, 168332, 168338, 168343, 168353, 168364, 168369, 168376, 168381, 168387,
+    168391, 168397, 168405, 168413, 168423, 168435, 168441, 168449, 168457, 168466,
+    168473, 168479, 168486, 168492, 168500, 168507, 168514, 168523, 168531, 168540,
+    168548, 168557, 168562, 168570, 168576, 168582, 168591, 168601, 168611, 168619,
+    168629, 168636, 168641, 168653, 168663, 168669, 168674, 168681, 168689, 168695,
+    168703, 168710, 168716, 168724, 168733, 168743, 168754, 168761, 168767, 168776,
+    168786, 168793, 168801, 168811, 168823, 168834, 168843, 168852, 168858, 168866,
+    168874, 168883, 168892, 168899, 168908, 168917, 168926, 168936, 168942, 168950,
+    168958, 168964, 168972, 168980, 168990, 168996, 169004, 169011, 169019, 169025,
+    169031, 169041, 169048, 169056, 169063, 169071, 169081, 169087, 169095, 169104,
+    169111, 169118, 169123, 169129, 169136, 169143, 169151, 169159, 169168, 169174,
+    169180, 169187, 169194, 169202, 169208, 169214, 169220, 169227, 169234, 169240,
+    169248, 169257, 169263, 169271, 169280, 169290, 169301, 169309, 169315, 169321,
+    169331, 169343, 169352, 169362, 169372, 169383, 169391, 169400, 169409, 169419,
+    169424, 169430, 169435, 169441, 169445, 169452, 169460, 169470, 169476, 169481,
+    169487, 169492, 169496, 169503, 169510, 169518, 169525, 169532, 169538, 169545,
+    169553, 169558, 169563, 169571, 169576, 169583, 169591, 169597, 169603, 169610,
+    169618, 169626, 169635, 169640, 169646, 169650, 169655, 169662, 169670, 169675,
+    169682, 169688, 169697, 169705, 169711, 169717, 169725, 169732, 169742, 169747,
+    169753, 169759, 169766, 169773, 169780, 169789, 169797, 169803, 169810, 169815,
+    169818, 169826, 169838, 169850, 169862, 169872, 169884, 169893, 169903, 169914,
+    169926, 169932, 169939, 169947, 169955, 169964, 169971, 169980, 169990, 170000,
+    170012, 170020, 170031, 170037, 170044, 170053, 170063, 170071, 170080, 170090,
+    170101, 170111, 170117, 170124, 170133, 170143, 170154, 170166, 170178, 170186,
+    170196, 170205, 170215, 170225, 170236, 170245, 170257, 170267, 170278, 170288,
+    170300, 170311, 170317, 170324, 170331, 170339, 170349, 170357, 170369, 170379,
+    170388, 170398, 170405, 170412, 170423, 170430, 170438, 170447, 170457, 170468,
+    170480, 170491, 170501, 170511, 170522, 170533, 170545, 170557, 170566, 170574,
+    170583, 170593, 170605, 170613, 170622, 170631, 170637, 170644, 170652, 170658,
+    170666, 170674, 170684, 170695, 170706, 170717, 170723, 170731, 170739, 170746,
+    170756, 170766, 170773, 170779, 170786, 170795, 170805, 170813, 170820, 170829,
+    170839, 170851, 170861, 170871, 170883, 170892, 170902, 170910, 170920, 170929,
+    170936, 170945, 170952, 170960, 170967, 170974, 170980, 170988, 170992, 170995,
+    170999, 171004, 171008, 171014, 171022, 171030, 171039, 171048, 171058, 171065,
+    171072, 171080, 171088, 171099, 171109, 171121, 171127, 171135, 171144, 171152,
+    171160, 171165, 171174, 171180, 171186, 171193, 171202, 171212, 171222, 171233,
+    171243, 171251, 171259, 171266, 171274, 171279, 171283, 171294, 171302, 171310,
+    171318, 171323, 171329, 171335, 171341, 171348, 171353, 171360, 171367, 171375,
+    171381, 171389, 171393, 171399, 171407, 171412, 171418, 171427, 171434, 171437,
+    171446, 171455, 171465, 171475, 171486, 171493, 171502, 171508, 171514, 171521,
+    171531, 171538, 171544, 171551, 171557, 171565, 171572, 171576, 171580, 171584,
+    171587, 171594, 171602, 171608, 171615, 171621, 171630, 171634, 171644, 171648,
+    171654, 171662, 171668, 171673, 171680, 171684, 171690, 171697, 171705, 171712,
+    171715, 171720, 171726, 171734, 171742, 171753, 171764, 171774, 171786, 171794,
+    171805, 171814, 171822, 171833, 171845, 171857, 171866, 171876, 171887, 171897,
+    171905, 171915, 171924, 171934, 171946, 171955, 171965, 171976, 171987, 171997,
+    172008, 172019, 172025, 172031, 172039, 172049, 172055, 172065, 172077, 172089,
+    172100, 172108, 172116, 172125, 172133, 172139, 172148, 172155, 172164, 172173,
+    172183, 172193, 172203, 172211, 172218, 172226, 172236, 172244, 172253, 172264,
+    172272, 172282, 172293, 172304, 172313, 172320, 172324, 172330, 172335, 172341,
+    172345, 172352, 172360, 172368, 172374, 172381, 172388, 172393, 172399, 172406,
+    172414, 172423, 172430, 172436, 172444, 172454, 172461, 172466, 172473, 172481,
+    172489, 172499, 172506, 172514, 172521, 172530, 172540, 172547, 172556, 172564,
+    172573, 172582, 172592, 172604, 172611, 172621, 172631, 172640, 172651, 172657,
+    172666, 172674, 172684, 172695, 172707, 172716, 172723, 172735, 172741, 172752,
+    172760, 172769, 172777, 172786, 172793, 172801, 172808, 172816, 172824, 172833,
+    172841, 172850, 172861, 172866, 172873, 172882, 172890, 172897, 172906, 172916,
+    172924, 172934, 172945, 172954, 172964, 172974, 172986, 172993, 173004, 173013,
+    173023, 173031, 173041, 173047, 173054, 173061, 173069, 173073, 173078, 173085,
+    173089, 173096, 173101, 173108, 173117, 173127, 173135, 173139, 173144, 173151,
+    173157, 173163, 173167, 173172, 173176, 173183, 173191, 173196, 173201, 173208,
+    173216, 173222, 173229, 173233, 173239, 173244, 173248, 173254, 173261, 173266,
+    173274, 173280, 173285, 173292, 173300, 173308, 173314, 173320, 173325, 173332,
+    173340, 173346, 173351, 173358, 173366, 173376, 173383, 173390, 173396, 173401,
+    173408, 173416, 173422, 173429, 173434, 173440, 173447, 173452, 173459, 173465,
+    173469, 173476, 173484, 173492, 173497, 173505, 173512, 173517, 173522, 173530,
+    173536, 173541, 173546, 173553, 173561, 173567, 173572, 173578, 173586, 173594,
+    173603, 173609, 173616, 173621, 173626, 173633, 173638, 173644, 173649, 173654,
+    173659, 173667, 173675, 173683, 173692, 173700, 173709, 173715, 173723, 173735,
+    173744, 173753, 173763, 173773, 173784, 173791, 173800, 173811, 173819, 173830,
+    173840, 173848, 173857, 173867, 173875, 173883, 173892, 173899, 173910, 173918,
+    173927, 173937, 173944, 173952, 173956, 173966, 173978, 173989, 173998, 174008,
+    174019, 174027, 174036, 174043, 174048, 174055, 174065, 174073, 174081, 174090,
+    174096, 174105, 174115, 174126, 174137, 174146, 174153, 174161, 174166, 174172,
+    174178, 174188, 174196, 174203, 174206, 174211, 174221, 174226, 174232, 174238,
+    174248, 174259, 174265, 174272, 174279, 174287, 174292, 174298, 174302, 174309,
+    174317, 174326, 174331, 174338, 174346, 174350, 174356, 174362, 174369, 174376,
+    174384, 174389, 174394, 174398, 174402, 174409, 174415, 174423, 174430, 174437,
+    174440, 174448, 174451, 174457, 174464, 174468, 174472, 174478, 174486, 174493,
+    174499, 174507, 174514, 174519, 174524, 174531, 174535, 174540, 174547, 174555,
+    174560, 174566, 174571, 174577, 174584, 174588, 174594, 174602, 174609, 174614,
+    174622, 174626, 174632, 174638, 174645, 174650, 174654, 174660, 174667, 174674,
+    174680, 174685, 174691, 174695, 174700, 174706, 174712, 174720, 174723, 174729,
+    174736, 174746, 174755, 174763, 174769, 174776, 174783, 174788, 174793, 174797,
+    174803, 174810, 174815, 174821, 174827, 174833, 174840, 174850, 174857, 174865,
+    174868, 174872, 174877, 174882, 174889, 174897, 174903, 174908, 174918, 174926,
+    174932, 174938, 174945, 174949, 174956, 174959, 174964, 174970, 174978, 174987,
+    174994, 175000, 175007, 175013, 175020, 175025, 175033, 175038, 175042, 175048,
+    175052, 175058, 175063, 175070, 175075, 175081, 175089, 175095, 175102, 175107,
+    175115, 175121, 175129, 175136, 175142, 175147, 175154, 175162, 175168, 175172,
+    175178, 175185, 175190, 175193, 175200, 175208, 175213, 175218, 175222, 175228,
+    175233, 175242, 175250, 175258, 175267, 175270, 175275, 175282, 175290, 175297,
+    175305, 175314, 175318, 175322, 175326, 175332, 175339, 175347, 175356, 175361,
+    175369, 175375, 175379, 175386, 175393, 175398, 175402, 175407, 175413, 175419,
+    175426, 175434, 175443, 175452, 175463, 175473, 175484, 175493, 175503, 175509,
+    175521, 175531, 175539, 175546, 175552, 175555, 175562, 175566, 175573, 175581,
+    175590, 175595, 175600, 175604, 175609, 175618, 175626, 175635, 175644, 175653,
+    175663, 175673, 175682, 175691, 175701, 175709, 175718, 175728, 175739, 175744,
+    175748, 175754, 175761, 175768, 175773, 175781, 175785, 175791, 175798, 175809,
+    175819, 175828, 175839, 175851, 175859, 175866, 175873, 175881, 175890, 175896,
+    175903, 175908, 175916, 175926, 175937, 175946, 175951, 175956, 175966, 175977,
+    175984, 175992, 175995, 176002, 176010, 176016, 176023, 176027, 176033, 176043,
+    176051, 176057, 176065, 176073, 176083, 176090, 176097, 176103, 176110, 176118,
+    176123, 176131, 176136, 176142, 176149, 176155, 176160, 176164, 176169, 176175,
+    176181, 176188, 176195, 176203, 176209, 176213, 176221, 176230, 176239, 176250,
+    176255, 176261, 176266, 176273, 176279, 176286, 176291, 176298, 176302, 176308,
+    176316, 176325, 176334, 176344, 176354, 176365, 176376, 176386, 176393, 176404,
+    176412, 176419, 176427, 176432, 176436, 176443, 176448, 176455, 176463, 176471,
+    176478, 176489, 176497, 176505, 176512, 176520, 176528, 176537, 176544, 176554,
+    176565, 176576, 176585, 176591, 176600, 176610, 176617, 176622, 176627, 176635,
+    176640, 176652, 176656, 176664, 176673, 176679, 176685, 176692, 176701, 176710,
+    176717, 176727, 176738, 176749, 176758, 176765, 176777, 176782, 176789, 176795,
+    176803, 176809, 176813, 176821, 176830, 176837, 176845, 176850, 176857, 176865,
+    176872, 176880, 176888, 176893, 176900, 176908, 176916, 176922, 176934, 176940,
+    176948, 176956, 176960, 176966, 176973, 176980, 176988, 176996, 177006, 177011,
+    177017, 177023, 177031, 177039, 177044, 177050, 177056, 177066, 177077, 177088,
+    177096, 177103, 177111, 177120, 177131, 177139, 177147, 177156, 177164, 177173,
+    177183, 177189, 177200, 177209, 177218, 177228, 177235, 177242, 177251, 177258,
+    177265, 177274, 177281, 177290, 177301, 177308, 177314, 177320, 177325, 177332,
+    177341, 177351, 177359, 177366, 177374, 177381, 177389, 177396, 177405, 177416,
+    177421, 177428, 177433, 177439, 177446, 177450, 177455, 177461, 177466, 177473,
+    177481, 177489, 177495, 177501, 177508, 177515, 177523, 177531, 177536, 177543,
+    177549, 177555, 177562, 177570, 177580, 177588, 177594, 177601, 177606, 177612,
+    177618, 177626, 177636, 177640, 177646, 177653, 177663, 177675, 177683, 177691,
+    177700, 177706, 177712, 177720, 177726, 177736, 177744, 177751, 177757, 177765,
+    177775, 177786, 177795, 177802, 177807, 177816, 177822, 177831, 177841, 177846,
+    177852, 177858, 177865, 177871, 177879, 177887, 177896, 177903, 177909, 177916,
+    177923, 177931, 177937, 177944, 177949, 177958, 177964, 177974, 177980, 177987,
+    177993, 178001, 178008, 178016, 178026, 178036, 178041, 178048, 178056, 178062,
+    178068, 178074, 178080, 178089, 178096, 178100, 178104, 178109, 178114, 178123,
+    178129, 178134, 178141, 178149, 178156, 178167, 178177, 178185, 178189, 178195,
+    178203, 178206, 178211, 178217, 178224, 178231, 178236, 178239, 178243, 178249,
+    178255, 178264, 178271, 178278, 178284, 178291, 178299, 178307, 178312, 178316,
+    178321, 178325, 178330, 178337, 178341, 178344, 178354, 178358, 178362, 178367,
+    178371, 178375, 178380, 178386, 178391, 178398, 178407, 178417, 178424, 178431,
+    178440, 178446, 178450, 178455, 178460, 178466, 178470, 178477, 178483, 178489,
+    178496, 178500, 178506, 178513, 178518, 178524, 178531, 178537, 178541, 178546,
+    178554, 178562, 178568, 178573, 178579, 178584, 178593, 178598, 178606, 178613,
+    178619, 178622, 178628, 178636, 178642, 178646, 178653, 178658, 178664, 178670,
+    178678, 178685, 178691, 178699, 178703, 178707, 178713, 178718, 178722, 178725,
+    178731, 178738, 178743, 178747, 178752, 178758, 178765, 178771, 178775, 178780,
+    178788, 178793, 178801, 178807, 178813, 178820, 178825, 178832, 178840, 178846,
+    178851, 178856, 178862, 178868, 178875, 178879, 178884, 178889, 178896, 178903,
+    178909, 178916, 178921, 178928, 178936, 178939, 178943, 178947, 178953, 178957,
+    178961, 178969, 178975, 178979, 178985, 178991, 178998, 179005, 179010, 179015,
+    179021, 179025, 179034, 179043, 179053, 179061, 179069, 179078, 179088, 179092,
+    179097, 179100, 179108, 179114, 179122, 179126, 179131, 179140, 179146, 179150,
+    179158, 179161, 179166, 179174, 179178, 179183, 179188, 179193, 179197, 179202,
+    179208, 179215, 179220, 179227, 179234, 179238, 179245, 179249, 179252, 179256,
+    179260, 179267, 179275, 179282, 179289, 179296, 179301, 179306, 179313, 179316,
+    179321, 179326, 179333, 179339, 179346, 179357, 179367, 179378, 179386, 179392,
+    179399, 179405, 179412, 179420, 179424, 179431, 179436, 179443, 179447, 179454,
+    179463, 179471, 179475, 179482, 179491, 179498, 179507, 179516, 179522, 179528,
+    179535, 179542, 179552, 179560, 179567, 179572, 179576, 179584, 179592, 179597,
+    179605, 179613, 179621, 179626, 179630, 179636, 179642, 179649, 179656, 179659,
+    179664, 179671, 179679, 179691, 179699, 179707, 179716, 179723, 179729, 179733,
+    179738, 179743, 179752, 179762, 179769, 179776, 179784, 179790, 179795, 179802,
+    179810, 179819, 179823, 179829, 179836, 179843, 179850, 179858, 179868, 179879,
+    179884, 179888, 179894, 179897, 179903, 179910, 179915, 179920, 179926, 179931,
+    179938, 179946, 179952, 179958, 179962, 179971, 179976, 179984, 179993, 180001,
+    180012, 180017, 180025, 180035, 180040, 180046, 180049, 180054, 180058, 180063,
+    180069, 180075, 180082, 180088, 180095, 180102, 180110, 180115, 180122, 180129,
+    180137, 180143, 180148, 180157, 180165, 180172, 180180, 180186, 180191, 180197,
+    180206, 180216, 180222, 180229, 180235, 180242, 180251, 180262, 180272, 180279,
+    180289, 180300, 180311, 180320, 180327, 180333, 180338, 180345, 180352, 180360,
+    180368, 180374, 180379, 180386, 180393, 180401, 180408, 180416, 180424, 180430,
+    180435, 180442, 180450, 180456, 180468, 180478, 180482, 180490, 180497, 180505,
+    180511, 180516, 180522, 180530, 180542, 180550, 180561, 180573, 180582, 180593,
+    180605, 180617, 180627, 180638, 180648, 180659, 180669, 180677, 180685, 180697,
+    180709, 180720, 180732, 180741, 180750, 180764, 180775, 180786, 180798, 180810,
+    180820, 180831, 180840, 180850, 180861, 180868, 180878, 180890, 180901, 180911,
+    180921, 180930, 180940, 180950, 180961, 180972, 180984, 180994, 181005, 181016,
+    181028, 181036, 181049, 181058, 181069, 181080, 181092, 181104, 181116, 181127,
+    181137, 181147, 181159, 181170, 181179, 181190, 181201, 181213, 181223, 181232,
+    181242, 181246, 181253, 181260, 181268, 181276, 181285, 181290, 181295, 181301,
+    181308, 181317, 181324, 181330, 181338, 181346, 181355, 181364, 181371, 181379,
+    181388, 181397, 181407, 181415, 181422, 181426, 181431, 181442, 181451, 181456,
+    181464, 181470, 181477, 181485, 181494, 181503, 181512, 181520, 181525, 181532,
+    181540, 181548, 181556, 181565, 181574, 181584, 181590, 181596, 181602, 181606,
+    181611, 181619, 181623, 181631, 181638, 181645, 181653, 181662, 181672, 181684,
+    181690, 181697, 181703, 181711, 181716, 181724, 181730, 181736, 181744, 181751,
+    181756, 181762, 181768, 181773, 181779, 181787, 181796, 181806, 181817, 181826,
+    181832, 181837, 181843, 181852, 181860, 181869, 181876, 181887, 181896, 181903,
+    181911, 181918, 181923, 181930, 181938, 181945, 181953, 181959, 181965, 181973,
+    181980, 181984, 181989, 181994, 182000, 182007, 182015, 182022, 182031, 182041,
+    182049, 182054, 182062, 182070, 182077, 182081, 182090, 182097, 182105, 182112,
+    182119, 182127, 182131, 182136, 182140, 182150, 182156, 182163, 182170, 182177,
+    182185, 182191, 182198, 182206, 182211, 182219, 182224, 182231, 182240, 182250,
+    182262, 182270, 182276, 182283, 182287, 182294, 182299, 182306, 182314, 182319,
+    182326, 182334, 182340, 182345, 182351, 182359, 182365, 182371, 182379, 182386,
+    182390, 182397, 182404, 182412, 182419, 182427, 182435, 182446, 182455, 182461,
+    182468, 182474, 182481, 182488, 182496, 182501, 182505, 182512, 182517, 182522,
+    182530, 182536, 182543, 182547, 182552, 182558, 182564, 182569, 182574, 182581,
+    182590, 182598, 182606, 182615, 182620, 182626, 182631, 182638, 182643, 182650,
+    182657, 182665, 182673, 182679, 182690, 182696, 182701, 182709, 182715, 182718,
+    182724, 182730, 182734, 182741, 182744, 182748, 182752, 182757, 182766, 182778,
+    182784, 182789, 182795, 182799, 182806, 182810, 182816, 182822, 182829, 182833,
+    182839, 182844, 182847, 182853, 182860, 182864, 182871, 182876, 182882, 182888,
+    182895, 182902, 182910, 182915, 182920, 182926, 182932, 182942, 182946, 182952,
+    182956, 182961, 182966, 182974, 182979, 182985, 182991, 183000, 183007, 183012,
+    183018, 183024, 183031, 183039, 183049, 183058, 183062, 183068, 183075, 183083,
+    183090, 183094, 183104, 183110, 183116, 183123, 183129, 183136, 183143, 183150,
+    183158, 183167, 183175, 183180, 183189, 183198, 183204, 183211, 183217, 183224,
+    183229, 183237, 183242, 183249, 183257, 183265, 183271, 183275, 183281, 183286,
+    183291, 183296, 183303, 183309, 183316, 183322, 183330, 183339, 183345, 183350,
+    183354, 183359, 183367, 183373, 183378, 183382, 183387, 183393, 183400, 183407,
+    183415, 183422, 183430, 183435, 183442, 183450, 183455, 183462, 183470, 183476,
+    183482, 183490, 183497, 183504, 183508, 183514, 183519, 183523, 183528, 183536,
+    183541, 183551, 183558, 183568, 183575, 183582, 183588, 183595, 183604, 183612,
+    183620, 183626, 183632, 183638, 183645, 183651, 183658, 183662, 183672, 183680,
+    183688, 183692, 183697, 183707, 183713, 183721, 183729, 183739, 183748, 183753,
+    183763, 183773, 183784, 183793, 183801, 183807, 183814, 183822, 183828, 183840,
+    183850, 183858, 183865, 183876, 183885, 183894, 183905, 183915, 183922, 183927,
+    183937, 183949, 183961, 183972, 183983, 183990, 183998, 184008, 184018, 184026,
+    184037, 184045, 184057, 184065, 184071, 184078, 184087, 184095, 184102, 184110,
+    184119, 184130, 184139, 184149, 184158, 184163, 184170, 184177, 184183, 184191,
+    184202, 184213, 184225, 184233, 184240, 184247, 184256, 184266, 184274, 184281,
+    184286, 184293, 184304, 184314, 184323, 184333, 184338, 184346, 184356, 184364,
+    184372, 184379, 184388, 184398, 184407, 184418, 184426, 184434, 184443, 184452,
+    184462, 184472, 184482, 184491, 184501, 184511, 184522, 184532, 184540, 184550,
+    184561, 184572, 184580, 184589, 184599, 184610, 184620, 184629, 184639, 184649,
+    184659, 184670, 184682, 184691, 184701, 184710, 184719, 184729, 184740, 184752,
+    184763, 184774, 184779, 184789, 184800, 184810, 184821, 184830, 184834, 184841,
+    184847, 184858, 184865, 184870, 184880, 184886, 184893, 184901, 184910, 184916,
+    184924, 184934, 184940, 184950, 184960, 184972, 184980, 184987, 184998, 185006,
+    185015, 185021, 185029, 185039, 185051, 185062, 185068, 185076, 185086, 185097,
+    185105, 185115, 185120, 185126, 185134, 185143, 185150, 185160, 185171, 185180,
+    185190, 185201, 185208, 185219, 185226, 185234, 185243, 185250, 185258, 185264,
+    185274, 185281, 185293, 185304, 185311, 185318, 185327, 185337, 185346, 185354,
+    185359, 185367, 185377, 185389, 185400, 185411, 185420, 185430, 185435, 185443,
+    185449, 185455, 185463, 185473, 185479, 185483, 185490, 185495, 185501, 185507,
+    185514, 185521, 185526, 185531, 185540, 185552, 185563, 185573, 185581, 185590,
+    185602, 185612, 185618, 185629, 185637, 185644, 185650, 185661, 185668, 185676,
+    185684, 185691, 185700, 185706, 185716, 185722, 185730, 185739, 185745, 185753,
+    185762, 185768, 185776, 185783, 185791, 185799, 185808, 185816, 185828, 185835,
+    185842, 185851, 185862, 185870, 185879, 185889, 185899, 185907, 185916, 185927,
+    185937, 185943, 185951, 185957, 185969, 185976, 185986, 185995, 186004, 186014,
+    186026, 186037, 186045, 186056, 186067, 186074, 186080, 186091, 186103, 186115,
+    186124, 186134, 186144, 186154, 186166, 186172, 186184, 186196, 186202, 186208,
+    186214, 186222, 186233, 186241, 186253, 186261, 186267, 186276, 186286, 186294,
+    186303, 186309, 186319, 186327, 186335, 186342, 186353, 186364, 186372, 186380,
+    186388, 186396, 186408, 186420, 186430, 186436, 186442, 186449, 186457, 186466,
+    186474, 186485, 186491, 186496, 186502, 186507, 186515, 186523, 186527, 186533,
+    186542, 186550, 186559, 186568, 186575, 186581, 186588, 186596, 186602, 186609,
+    186618, 186626, 186635, 186643, 186654, 186662, 186671, 186677, 186685, 186694,
+    186700, 186706, 186717, 186725, 186734, 186739, 186745, 186755, 186763, 186769,
+    186776, 186783, 186791, 186798, 186806, 186814, 186823, 186831, 186837, 186843,
+    186849, 186858, 186865, 186871, 186877, 186884, 186890, 186898, 186907, 186913,
+    186921, 186925, 186930, 186938, 186947, 186954, 186962, 186971, 186977, 186985,
+    186992, 186997, 187003, 187012, 187018, 187024, 187032, 187039, 187046, 187054,
+    187061, 187067, 187073, 187081, 187087, 187095, 187102, 187109, 187114, 187120,
+    187132, 187141, 187147, 187154, 187158, 187162, 187167, 187174, 187180, 187188,
+    187193, 187199, 187203, 187206, 187209, 187213, 187220, 187229, 187235, 187240,
+    187246, 187249, 187253, 187259, 187267, 187277, 187284, 187288, 187296, 187301,
+    187305, 187310, 187317, 187325, 187331, 187336, 187341, 187348, 187353, 187360,
+    187367, 187372, 187380, 187387, 187398, 187407, 187414, 187425, 187432, 187440,
+    187448, 187457, 187463, 187469, 187476, 187483, 187488, 187497, 187507, 187514,
+    187522, 187529, 187535, 187544, 187551, 187557, 187563, 187570, 187578, 187587,
+    187597, 187608, 187615, 187623, 187630, 187636, 187644, 187652, 187660, 187667,
+    187675, 187680, 187687, 187696, 187700, 187706, 187710, 187719, 187724, 187731,
+    187736, 187744, 187753, 187763, 187771, 187778, 187784, 187791, 187796, 187804,
+    187813, 187818, 187824, 187830, 187837, 187842, 187848, 187855, 187863, 187866,
+    187875, 187882, 187886, 187891, 187899, 187906, 187912, 187920, 187928, 187937,
+    187945, 187951, 187961, 187965, 187972, 187977, 187985, 187991, 188002, 188010,
+    188019, 188027, 188036, 188043, 188051, 188060, 188070, 188078, 188084, 188090,
+    188099, 188106, 188113, 188122, 188129, 188136, 188140, 188147, 188155, 188159,
+    188164, 188170, 188177, 188181, 188187, 188195, 188198, 188202, 188212, 188217,
+    188224, 188232, 188238, 188245, 188249, 188255, 188262, 188266, 188272, 188277,
+    188286, 188296, 188306, 188316, 188326, 188334, 188344, 188351, 188359, 188368,
+    188372, 188378, 188385, 188390, 188394, 188399, 188405, 188415, 188423, 188429,
+    188436, 188444, 188451, 188457, 188464, 188470, 188479, 188488, 188498, 188506,
+    188515, 188523, 188530, 188535, 188543, 188549, 188553, 188559, 188570, 188577,
+    188584, 188593, 188603, 188614, 188619, 188626, 188634, 188642, 188647, 188653,
+    188661, 188668, 188672, 188678, 188686, 188694, 188702, 188707, 188714, 188721,
+    188729, 188734, 188746, 188754, 188762, 188768, 188777, 188783, 188792, 188798,
+    188807, 188813, 188823, 188829, 188838, 188849, 188854, 188860, 188866, 188873,
+    188881, 188887, 188895, 188904, 188913, 188923, 188934, 188940, 188948, 188958,
+    188966, 188973, 188978, 188985, 188991, 188998, 189002, 189008, 189017, 189024,
+    189032, 189039, 189044, 189047, 189054, 189062, 189072, 189076, 189082, 189090,
+    189095, 189101, 189104, 189113, 189119, 189126, 189130, 189139, 189144, 189148,
+    189155, 189162, 189170, 189179, 189188, 189198, 189205, 189210, 189219, 189223,
+    189230, 189235, 189243, 189250, 189262, 189268, 189278, 189285, 189291, 189300,
+    189310, 189317, 189324, 189329, 189335, 189344, 189347, 189351, 189355, 189362,
+    189368, 189374, 189381, 189388, 189397, 189407, 189412, 189417, 189426, 189434,
+    189443, 189451, 189456, 189464, 189474, 189478, 189483, 189490, 189498, 189507,
+    189515, 189522, 189532, 189540, 189546, 189557, 189561, 189568, 189573, 189581,
+    189590, 189597, 189602, 189607, 189614, 189622, 189630, 189639, 189648, 189658,
+    189666, 189673, 189682, 189688, 189695, 189701, 189710, 189717, 189722, 189728,
+    189733, 189741, 189750, 189757, 189763, 189771, 189779, 189786, 189795, 189801,
+    189808, 189816, 189824, 189833, 189841, 189846, 189853, 189857, 189863, 189868,
+    189875, 189883, 189892, 189900, 189907, 189911, 189921, 189928, 189935, 189941,
+    189950, 189957, 189962, 189972, 189979, 189987, 189994, 190002, 190008, 190013,
+    190018, 190024, 190030, 190039, 190049, 190057, 190064, 190069, 190077, 190084,
+    190091, 190099, 190107, 190112, 190119, 190129, 190134, 190142, 190151, 190159,
+    190165, 190173, 190183, 190188, 190193, 190198, 190204, 190213, 190219, 190226,
+    190230, 190236, 190243, 190248, 190256, 190266, 190274, 190281, 190290, 190298,
+    190304, 190314, 190318, 190322, 190329, 190337, 190342, 190348, 190355, 190365,
+    190375, 190386, 190395, 190404, 190415, 190425, 190432, 190440, 190446, 190452,
+    190460, 190464, 190469, 190474, 190480, 190485, 190491, 190497, 190502, 190509,
+    190517, 190520, 190525, 190530, 190536, 190540, 190545, 190551, 190558, 190565,
+    190571, 190581, 190588, 190596, 190601, 190605, 190610, 190616, 190621, 190627,
+    190633, 190639, 190642, 190646, 190651, 190656, 190661, 190668, 190674, 190679,
+    190685, 190689, 190695, 190702, 190707, 190711, 190717, 190723, 190730, 190737,
+    190745, 190750, 190755, 190761, 190767, 190774, 190778, 190785, 190790, 190796,
+    190801, 190806, 190810, 190816, 190822, 190829, 190836, 190843, 190848, 190855,
+    190863, 190867, 190873, 190879, 190886, 190895, 190900, 190906, 190913, 190919,
+    190924, 190927, 190931, 190937, 190945, 190953, 190962, 190971, 190978, 190982,
+    190987, 190993, 190997, 191003, 191010, 191016, 191021, 191024, 191031, 191039,
+    191043, 191049, 191056, 191064, 191069, 191073, 191082, 191092, 191097, 191104,
+    191111, 191119, 191125, 191133, 191140, 191147, 191155, 191159, 191165, 191171,
+    191178, 191185, 191191, 191196, 191202, 191206, 191212, 191219, 191227, 191232,
+    191236, 191242, 191249, 191256, 191265, 191275, 191283, 191291, 191297, 191304,
+    191311, 191319, 191323, 191330, 191338, 191342, 191349, 191354, 191358, 191362,
+    191370, 191376, 191382, 191389, 191395, 191402, 191409, 191413, 191418, 191425,
+    191430, 191436, 191444, 191453, 191459, 191469, 191476, 191481, 191489, 191496,
+    191503, 191511, 191518, 191526, 191534, 191542, 191550, 191560, 191565, 191575,
+    191586, 191593, 191602, 191612, 191621, 191631, 191639, 191648, 191654, 191663,
+    191671, 191680, 191690, 191696, 191700, 191706, 191711, 191715, 191720, 191728,
+    191738, 191749, 191756, 191764, 191769, 191775, 191779, 191782, 191787, 191792,
+    191798, 191802, 191806, 191813, 191820, 191824, 191830, 191838, 191847, 191854,
+    191860, 191867, 191873, 191881, 191891, 191897, 191906, 191916, 191923, 191927,
+    191933, 191940, 191946, 191960, 191968, 191975, 191979, 191985, 191992, 191999,
+    192008, 192015, 192020, 192025, 192031, 192038, 192047, 192057, 192062, 192068,
+    192075, 192081, 192088, 192091, 192098, 192102, 192109, 192117, 192120, 192126,
+    192133, 192141, 192146, 192152, 192158, 192165, 192169, 192176, 192181, 192185,
+    192192, 192200, 192207, 192215, 192221, 192226, 192230, 192237, 192242, 192247,
+    192251, 192256, 192263, 192271, 192277, 192283, 192291, 192300, 192307, 192314,
+    192319, 192326, 192334, 192342, 192351, 192355, 192361, 192368, 192373, 192383,
+    192388, 192393, 192397, 192403, 192407, 192411, 192419, 192423, 192432, 192440,
+    192447, 192457, 192468, 192476, 192484, 192493, 192496, 192503, 192511, 192518,
+    192525, 192531, 192537, 192544, 192551, 192557, 192562, 192567, 192574, 192580,
+    192587, 192592, 192599, 192607, 192617, 192622, 192627, 192636, 192646, 192653,
+    192660, 192668, 192674, 192682, 192688, 192696, 192707, 192714, 192721, 192730,
+    192737, 192744, 192753, 192761, 192768, 192772, 192781, 192786, 192791, 192797,
+    192801, 192808, 192813, 192818, 192824, 192831, 192835, 192842, 192850, 192855,
+    192860, 192867, 192875, 192884, 192889, 192896, 192904, 192911, 192918, 192927,
+    192937, 192945, 192952, 192960, 192968, 192976, 192985, 192990, 193000, 193009,
+    193018, 193025, 193032, 193039, 193046, 193050, 193057, 193062, 193071, 193077,
+    193086, 193097, 193106, 193112, 193116, 193124, 193129, 193137, 193142, 193147,
+    193150, 193154, 193160, 193168, 193178, 193184, 193190, 193197, 193202, 193206,
+    193212, 193217, 193224, 193232, 193238, 193243, 193253, 193263, 193269, 193274,
+    193281, 193288, 193294, 193299, 193305, 193310, 193317, 193325, 193329, 193332,
+    193338, 193343, 193347, 193351, 193355, 193362, 193370, 193376, 193386, 193393,
+    193401, 193410, 193418, 193424, 193432, 193437, 193442, 193448, 193456, 193462,
+    193469, 193476, 193485, 193496, 193502, 193509, 193514, 193518, 193524, 193528,
+    193531, 193536, 193542, 193547, 193551, 193557, 193565, 193572, 193580, 193586,
+    193593, 193601, 193606, 193616, 193624, 193629, 193633, 193641, 193647, 193652,
+    193656, 193662, 193668, 193675, 193680, 193688, 193692, 193698, 193705, 193711,
+    193718, 193725, 193733, 193737, 193743, 193752, 193759, 193765, 193769, 193774,
+    193779, 193785, 193789, 193794, 193802, 193807, 193813, 193817, 193823, 193827,
+    193833, 193838, 193844, 193848, 193854, 193860, 193867, 193874, 193878, 193883,
+    193890, 193893, 193898, 193908, 193915, 193921, 193929, 193939, 193955, 193966,
+    193977, 193986, 193994, 194000, 194008, 194015, 194023, 194031, 194042, 194048,
+    194055, 194064, 194071, 194080, 194089, 194099, 194103, 194108, 194113, 194119,
+    194126, 194132, 194138, 194141, 194149, 194153, 194156, 194160, 194165, 194169,
+    194176, 194185, 194195, 194201, 194206, 194215, 194222, 194226, 194232, 194237,
+    194240, 194246, 194254, 194263, 194274, 194283, 194293, 194300, 194308, 194311,
+    194315, 194322, 194330, 194336, 194342, 194350, 194359, 194366, 194376, 194381,
+    194389, 194395, 194399, 194405, 194411, 194415, 194421, 194428, 194433, 194437,
+    194441, 194449, 194459, 194467, 194474, 194480, 194486, 194493, 194501, 194510,
+    194519, 194526, 194534, 194542, 194549, 194560, 194567, 194574, 194582, 194591,
+    194601, 194606, 194615, 194625, 194633, 194642, 194653, 194665, 194670, 194683,
+    194690, 194696, 194705, 194709, 194714, 194718, 194725, 194732, 194737, 194745,
+    194750, 194756, 194764, 194773, 194780, 194787, 194796, 194806, 194817, 194825,
+    194830, 194837, 194842, 194851, 194861, 194867, 194872, 194879, 194887, 194893,
+    194897, 194902, 194905, 194911, 194915, 194922, 194929, 194937, 194945, 194950,
+    194955, 194960, 194966, 194973, 194978, 194986, 194993, 195001, 195007, 195011,
+    195017, 195025, 195030, 195037, 195044, 195052, 195060, 195069, 195078, 195084,
+    195092, 195100, 195108, 195114, 195119, 195127, 195134, 195142, 195150, 195155,
+    195161, 195165, 195170, 195176, 195188, 195195, 195201, 195208, 195216, 195223,
+    195228, 195238, 195245, 195253, 195260, 195266, 195271, 195278, 195283, 195286,
+    195291, 195296, 195302, 195307, 195310, 195317, 195320, 195324, 195330, 195335,
+    195338, 195342, 195355, 195360, 195364, 195369, 195372, 195376, 195382, 195389,
+    195393, 195397, 195405, 195411, 195416, 195423, 195431, 195436, 195441, 195447,
+    195451, 195457, 195464, 195470, 195479, 195486, 195491, 195494, 195500, 195509,
+    195512, 195515, 195524, 195527, 195530, 195535, 195542, 195550, 195556, 195561,
+    195565, 195569, 195575, 195579, 195583, 195588, 195594, 195598, 195603, 195610,
+    195618, 195627, 195637, 195642, 195650, 195655, 195663, 195669, 195674, 195678,
+    195683, 195687, 195693, 195700, 195707, 195712, 195715, 195721, 195729, 195733,
+    195738, 195742, 195747, 195751, 195757, 195761, 195766, 195769, 195775, 195781,
+    195787, 195793, 195799, 195806, 195811, 195815, 195820, 195825, 195831, 195840,
+    195843, 195853, 195862, 195869, 195873, 195879, 195886, 195890, 195898,
 ];
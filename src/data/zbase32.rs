@@ -0,0 +1,42 @@
+pub static TOKEN_COUNT: u32 = 26;
+pub static TOKEN_SEP: &str = "";
+pub static TOKEN_TEXT: &str = concat!(
+    "y",
+    "b",
+    "n",
+    "d",
+    "r",
+    "f",
+    "g",
+    "8",
+    "e",
+    "j",
+    "k",
+    "m",
+    "c",
+    "p",
+    "q",
+    "x",
+    "o",
+    "t",
+    "1",
+    "u",
+    "w",
+    "i",
+    "s",
+    "z",
+    "a",
+    "3",
+    "4",
+    "5",
+    "h",
+    "7",
+    "6",
+    "9",
+);
+pub static TOKEN_OFFSETS: [u32; 33] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+    10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+    20, 21, 22, 23, 24, 25, 26, 27, 28, 29,
+    30, 31, 32,
+];
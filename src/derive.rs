@@ -0,0 +1,513 @@
+use crate::{wipe, PassGenError};
+
+/* -------------------- *
+ *       BLAKE2B        *
+ * -------------------- */
+const BLAKE2B_IV: [u64; 8] = [
+    0x6a09e667f3bcc908, 0xbb67ae8584caa73b, 0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
+    0x510e527fade682d1, 0x9b05688c2b3e6c1f, 0x1f83d9abfb41bd6b, 0x5be0cd19137e2179,
+];
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+fn blake2b_compress(h: &mut [u64; 8], block: &[u8; 128], t: u128, last: bool) {
+    let mut m = [0u64; 16];
+
+    for (word, bytes) in m.iter_mut().zip(block.chunks_exact(8)) {
+        *word = u64::from_le_bytes(bytes.try_into().unwrap());
+    }
+
+    let mut v = [0u64; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&BLAKE2B_IV);
+    v[12] ^= t as u64;
+    v[13] ^= (t >> 64) as u64;
+
+    if last {
+        v[14] = !v[14];
+    }
+
+    let mut g = |a: usize, b: usize, c: usize, d: usize, x: u64, y: u64| {
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+        v[d] = (v[d] ^ v[a]).rotate_right(32);
+        v[c] = v[c].wrapping_add(v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(24);
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+        v[d] = (v[d] ^ v[a]).rotate_right(16);
+        v[c] = v[c].wrapping_add(v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(63);
+    };
+
+    for round in 0..12 {
+        let s = &SIGMA[round % 10];
+
+        g(0, 4, 8, 12, m[s[0]], m[s[1]]);
+        g(1, 5, 9, 13, m[s[2]], m[s[3]]);
+        g(2, 6, 10, 14, m[s[4]], m[s[5]]);
+        g(3, 7, 11, 15, m[s[6]], m[s[7]]);
+        g(0, 5, 10, 15, m[s[8]], m[s[9]]);
+        g(1, 6, 11, 12, m[s[10]], m[s[11]]);
+        g(2, 7, 8, 13, m[s[12]], m[s[13]]);
+        g(3, 4, 9, 14, m[s[14]], m[s[15]]);
+    }
+
+    for i in 0..8 {
+        h[i] ^= v[i] ^ v[i + 8];
+    }
+}
+
+// unkeyed blake2b of the concatenated parts with an output of 1 to 64 bytes
+fn blake2b(out_len: usize, parts: &[&[u8]]) -> Vec<u8> {
+    let data = parts.concat();
+
+    let mut h = BLAKE2B_IV;
+    h[0] ^= 0x01010000 ^ out_len as u64;
+
+    let blocks = data.len().div_ceil(128).max(1);
+
+    for i in 0..blocks {
+        let chunk = &data[i * 128..data.len().min((i + 1) * 128)];
+        let mut block = [0; 128];
+        block[..chunk.len()].copy_from_slice(chunk);
+
+        let last = i == blocks - 1;
+        let t = if last { data.len() } else { (i + 1) * 128 };
+
+        blake2b_compress(&mut h, &block, t as u128, last);
+    }
+
+    h.iter().flat_map(|w| w.to_le_bytes()).take(out_len).collect()
+}
+
+// the variable length hash H' from the argon2 spec
+fn blake2b_long(out_len: usize, input: &[u8]) -> Vec<u8> {
+    let len = (out_len as u32).to_le_bytes();
+
+    if out_len <= 64 {
+        return blake2b(out_len, &[&len, input]);
+    }
+
+    let mut out = Vec::with_capacity(out_len);
+    let mut v = blake2b(64, &[&len, input]);
+
+    while out_len - out.len() > 64 {
+        out.extend_from_slice(&v[..32]);
+        v = blake2b(64.min(out_len - out.len()), &[&v]);
+    }
+
+    out.extend_from_slice(&v);
+    out
+}
+
+
+/* -------------------- *
+ *       ARGON2ID       *
+ * -------------------- */
+const SYNC_POINTS: usize = 4;
+
+type Block = [u64; 128];
+
+/// Cost parameters of the key derivation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KdfParams {
+    /// Memory in KiB.
+    pub memory: u32,
+    pub iterations: u32,
+    pub lanes: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self { memory: 64 * 1024, iterations: 3, lanes: 1 }
+    }
+}
+
+impl KdfParams {
+    pub fn validate(&self) -> Result<(), PassGenError> {
+        let err = |msg: &str| Err(PassGenError::Usage(String::from(msg)));
+
+        if self.iterations == 0 || self.lanes == 0 {
+            return err("argon2 iterations and lanes must be positive");
+        }
+
+        if self.lanes >= 1 << 24 || (self.memory as u64) < 8 * self.lanes as u64 {
+            return err("argon2 memory must be at least 8 KiB per lane");
+        }
+
+        Ok(())
+    }
+}
+
+fn mix(v: &mut Block, idx: [usize; 16]) {
+    let mut g = |a: usize, b: usize, c: usize, d: usize| {
+        let bla = |x: u64, y: u64| x.wrapping_add(y).wrapping_add(2u64.wrapping_mul(x & 0xffffffff).wrapping_mul(y & 0xffffffff));
+
+        v[a] = bla(v[a], v[b]);
+        v[d] = (v[d] ^ v[a]).rotate_right(32);
+        v[c] = bla(v[c], v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(24);
+        v[a] = bla(v[a], v[b]);
+        v[d] = (v[d] ^ v[a]).rotate_right(16);
+        v[c] = bla(v[c], v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(63);
+    };
+
+    g(idx[0], idx[4], idx[8], idx[12]);
+    g(idx[1], idx[5], idx[9], idx[13]);
+    g(idx[2], idx[6], idx[10], idx[14]);
+    g(idx[3], idx[7], idx[11], idx[15]);
+    g(idx[0], idx[5], idx[10], idx[15]);
+    g(idx[1], idx[6], idx[11], idx[12]);
+    g(idx[2], idx[7], idx[8], idx[13]);
+    g(idx[3], idx[4], idx[9], idx[14]);
+}
+
+// the compression function G, xor-ing into the old contents after the first pass
+fn fill_block(prev: &Block, reference: &Block, next: &mut Block, with_xor: bool) {
+    let mut r = [0u64; 128];
+
+    for i in 0..128 {
+        r[i] = prev[i] ^ reference[i];
+    }
+
+    let mut tmp = r;
+
+    if with_xor {
+        for i in 0..128 {
+            tmp[i] ^= next[i];
+        }
+    }
+
+    for row in 0..8 {
+        mix(&mut r, std::array::from_fn(|j| 16 * row + j));
+    }
+
+    for col in 0..8 {
+        mix(&mut r, std::array::from_fn(|j| 2 * col + (j / 2) * 16 + j % 2));
+    }
+
+    for i in 0..128 {
+        next[i] = tmp[i] ^ r[i];
+    }
+}
+
+fn to_block(bytes: &[u8]) -> Block {
+    let mut block = [0; 128];
+
+    for (word, chunk) in block.iter_mut().zip(bytes.chunks_exact(8)) {
+        *word = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+
+    block
+}
+
+/// Argon2id (version 0x13) as specified in RFC 9106.
+pub(crate) fn argon2id(
+    password: &[u8],
+    salt: &[u8],
+    secret: &[u8],
+    data: &[u8],
+    params: &KdfParams,
+    out_len: usize,
+) -> Result<Vec<u8>, PassGenError> {
+    params.validate()?;
+
+    if salt.len() < 8 {
+        return Err(PassGenError::Usage(String::from("argon2 salt must be at least 8 bytes")));
+    }
+
+    let lanes = params.lanes as usize;
+    let segment_len = params.memory as usize / (lanes * SYNC_POINTS);
+    let lane_len = segment_len * SYNC_POINTS;
+    let blocks = lane_len * lanes;
+
+    let le = |x: usize| (x as u32).to_le_bytes();
+
+    let h0 = blake2b(
+        64,
+        &[
+            &le(lanes),
+            &le(out_len),
+            &le(params.memory as usize),
+            &le(params.iterations as usize),
+            &le(0x13),
+            &le(2),
+            &le(password.len()),
+            password,
+            &le(salt.len()),
+            salt,
+            &le(secret.len()),
+            secret,
+            &le(data.len()),
+            data,
+        ],
+    );
+
+    let mut memory: Vec<Block> = vec![[0; 128]; blocks];
+
+    for lane in 0..lanes {
+        for i in 0..2 {
+            let input = [&h0[..], &le(i), &le(lane)].concat();
+            memory[lane * lane_len + i] = to_block(&blake2b_long(1024, &input));
+        }
+    }
+
+    for pass in 0..params.iterations as usize {
+        for slice in 0..SYNC_POINTS {
+            for lane in 0..lanes {
+                // argon2id only uses data independent addressing in the first half of the first pass
+                let independent = pass == 0 && slice < SYNC_POINTS / 2;
+
+                let mut input: Block = [0; 128];
+                let mut addresses: Block = [0; 128];
+                let zero: Block = [0; 128];
+
+                let next_addresses = |input: &mut Block, addresses: &mut Block| {
+                    input[6] += 1;
+                    let mut tmp = [0; 128];
+                    fill_block(&zero, input, &mut tmp, false);
+                    fill_block(&zero, &tmp, addresses, false);
+                };
+
+                if independent {
+                    input[..6].copy_from_slice(&[
+                        pass as u64,
+                        lane as u64,
+                        slice as u64,
+                        blocks as u64,
+                        params.iterations as u64,
+                        2,
+                    ]);
+                }
+
+                let start = if pass == 0 && slice == 0 { 2 } else { 0 };
+
+                if independent && start == 2 {
+                    next_addresses(&mut input, &mut addresses);
+                }
+
+                for index in start..segment_len {
+                    let column = slice * segment_len + index;
+                    let curr = lane * lane_len + column;
+                    let prev = if column == 0 { curr + lane_len - 1 } else { curr - 1 };
+
+                    let pseudo_rand = if independent {
+                        if index % 128 == 0 {
+                            next_addresses(&mut input, &mut addresses);
+                        }
+
+                        addresses[index % 128]
+                    } else {
+                        memory[prev][0]
+                    };
+
+                    let ref_lane = if pass == 0 && slice == 0 { lane } else { (pseudo_rand >> 32) as usize % lanes };
+                    let same_lane = ref_lane == lane;
+
+                    let area = match (pass, same_lane) {
+                        (0, _) if slice == 0 => index - 1,
+                        (0, true) => slice * segment_len + index - 1,
+                        (0, false) => slice * segment_len - (index == 0) as usize,
+                        (_, true) => lane_len - segment_len + index - 1,
+                        (_, false) => lane_len - segment_len - (index == 0) as usize,
+                    } as u64;
+
+                    let x = pseudo_rand & 0xffffffff;
+                    let x = (x * x) >> 32;
+                    let relative = area - 1 - ((area * x) >> 32);
+
+                    let start_pos = if pass == 0 || slice == SYNC_POINTS - 1 { 0 } else { (slice + 1) * segment_len };
+                    let ref_index = (start_pos + relative as usize) % lane_len;
+
+                    let reference = memory[ref_lane * lane_len + ref_index];
+                    let previous = memory[prev];
+
+                    fill_block(&previous, &reference, &mut memory[curr], pass != 0);
+                }
+            }
+        }
+    }
+
+    let mut last = memory[lane_len - 1];
+
+    for lane in 1..lanes {
+        for (a, b) in last.iter_mut().zip(memory[lane * lane_len + lane_len - 1]) {
+            *a ^= b;
+        }
+    }
+
+    let bytes: Vec<u8> = last.iter().flat_map(|w| w.to_le_bytes()).collect();
+
+    for block in memory.iter_mut() {
+        block.fill(0);
+    }
+
+    Ok(blake2b_long(out_len, &bytes))
+}
+
+
+/* -------------------- *
+ *     HKDF-SHA256      *
+ * -------------------- */
+const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// SHA-256 digest of `data`.
+pub(crate) fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut state: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+    ];
+
+    let mut message = data.to_vec();
+    message.push(0x80);
+
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+
+    message.extend_from_slice(&(data.len() as u64 * 8).to_be_bytes());
+
+    for block in message.chunks_exact(64) {
+        let mut w = [0u32; 64];
+
+        for (i, word) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes(word.try_into().unwrap());
+        }
+
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(SHA256_K[i]).wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+
+        for (s, x) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *s = s.wrapping_add(x);
+        }
+    }
+
+    let mut digest = [0; 32];
+
+    for (chunk, s) in digest.chunks_exact_mut(4).zip(state) {
+        chunk.copy_from_slice(&s.to_be_bytes());
+    }
+
+    digest
+}
+
+pub(crate) fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let mut block = [0u8; 64];
+
+    if key.len() > 64 {
+        block[..32].copy_from_slice(&sha256(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    // the parts are often secrets, the copy of them is wiped
+    let mut inner: Vec<u8> = block.iter().map(|b| b ^ 0x36).collect();
+    parts.iter().for_each(|part| inner.extend_from_slice(part));
+
+    let mut outer: Vec<u8> = block.iter().map(|b| b ^ 0x5c).chain(sha256(&inner)).collect();
+    let mac = sha256(&outer);
+
+    wipe(&mut inner);
+    wipe(&mut outer);
+
+    mac
+}
+
+/// HKDF with SHA-256 as specified in RFC 5869, `out_len` is at most 8160 bytes.
+pub(crate) fn hkdf_sha256(ikm: &[u8], salt: &[u8], info: &[u8], out_len: usize) -> Vec<u8> {
+    let prk = hmac_sha256(salt, &[ikm]);
+
+    let mut out = Vec::with_capacity(out_len);
+    let mut t = Vec::new();
+
+    for counter in 1..=out_len.div_ceil(32) as u8 {
+        t = hmac_sha256(&prk, &[&t, info, &[counter]]).to_vec();
+        out.extend_from_slice(&t);
+    }
+
+    out.truncate(out_len);
+    out
+}
+
+
+/* -------------------- *
+ *      DERIVATION      *
+ * -------------------- */
+/// Derives the 32 byte rng seed for `label` from a master secret.
+///
+/// The master secret goes through Argon2id with `salt`, the result is
+/// expanded per label with HKDF-SHA256, so every label gets an independent
+/// seed. Changing anything here changes every derived password.
+///
+/// ```
+/// use pass_gen::{derive_seed, KdfParams};
+///
+/// let params = KdfParams { memory: 64, iterations: 1, lanes: 1 };
+/// let seed = derive_seed(b"master", b"saltsalt", "example.com", &params).unwrap();
+///
+/// assert_eq!(seed[..8], [0x50, 0xbd, 0xe2, 0xc8, 0x83, 0x62, 0x1d, 0xf8]);
+/// ```
+pub fn derive_seed(master: &[u8], salt: &[u8], label: &str, params: &KdfParams) -> Result<[u8; 32], PassGenError> {
+    let mut key = argon2id(master, salt, &[], &[], params, 32)?;
+    let seed = hkdf_sha256(&key, b"pass-gen derive v1", label.as_bytes(), 32);
+
+    crate::wipe(&mut key);
+
+    Ok(seed.try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn argon2id_rfc9106() {
+        // test vector from RFC 9106 section 5.3
+        let params = KdfParams { memory: 32, iterations: 3, lanes: 4 };
+        let tag = argon2id(&[1; 32], &[2; 16], &[3; 8], &[4; 12], &params, 32).unwrap();
+
+        assert_eq!(tag[..8], [0x0d, 0x64, 0x0d, 0xf5, 0x8d, 0x78, 0x76, 0x6c]);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::TokenSource;
+use std::{collections::VecDeque, ops::Range};
+
+const LETTERS: usize = 26;
+
+/* -------------------- *
+ *      DICTIONARY      *
+ * -------------------- */
+/// Finds dictionary words inside a text, ignoring ascii case.
+///
+/// The words are compiled once into an Aho-Corasick automaton, so a text is scanned in a
+/// single pass however many words there are. Only words made of ascii letters are kept.
+#[derive(Debug)]
+pub(crate) struct Dictionary {
+    // complete transitions per state, state 0 is the root
+    goto: Vec<[u32; LETTERS]>,
+    // length of the longest word ending in each state, 0 for none
+    output: Vec<u32>,
+}
+
+impl Dictionary {
+    pub fn new<'w>(words: impl IntoIterator<Item = &'w str>, min_len: usize) -> Self {
+        let mut goto = vec![[0; LETTERS]];
+        let mut output = vec![0];
+
+        for word in words {
+            if word.len() < min_len.max(1) || !word.bytes().all(|b| b.is_ascii_alphabetic()) {
+                continue;
+            }
+
+            let mut state = 0;
+
+            for idx in word.bytes().map(letter) {
+                if goto[state][idx] == 0 {
+                    goto[state][idx] = goto.len() as u32;
+                    goto.push([0; LETTERS]);
+                    output.push(0);
+                }
+
+                state = goto[state][idx] as usize;
+            }
+
+            output[state] = word.len() as u32;
+        }
+
+        // breadth first, so the failure state of every state is complete before its children
+        let mut fail = vec![0; goto.len()];
+        let mut queue: VecDeque<usize> = goto[0].iter().filter(|&&s| s != 0).map(|&s| s as usize).collect();
+
+        while let Some(state) = queue.pop_front() {
+            let failure = fail[state];
+
+            if output[state] == 0 {
+                output[state] = output[failure];
+            }
+
+            let fallback = goto[failure];
+
+            for (next, &other) in goto[state].iter_mut().zip(&fallback) {
+                match *next as usize {
+                    0 => *next = other,
+                    child => {
+                        fail[child] = other as usize;
+                        queue.push_back(child);
+                    },
+                }
+            }
+        }
+
+        Dictionary { goto, output }
+    }
+
+    /// Builds a dictionary from every token of `source`.
+    pub fn from_source(source: &dyn TokenSource, min_len: usize) -> Self {
+        Self::new(source.range().map(|idx| source.get(idx)), min_len)
+    }
+
+    /// Byte range of the first word found in `text`, by where it ends.
+    pub fn find(&self, text: &str) -> Option<Range<usize>> {
+        let mut state = 0;
+
+        for (idx, byte) in text.bytes().enumerate() {
+            state = match byte.is_ascii_alphabetic() {
+                true => self.goto[state][letter(byte)] as usize,
+                false => 0,
+            };
+
+            if self.output[state] != 0 {
+                return Some(idx + 1 - self.output[state] as usize..idx + 1);
+            }
+        }
+
+        None
+    }
+
+    pub fn contains_word(&self, text: &str) -> bool {
+        self.find(text).is_some()
+    }
+}
+
+fn letter(byte: u8) -> usize {
+    (byte.to_ascii_lowercase() - b'a') as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_are_found_in_one_pass() {
+        let dict = Dictionary::new(["horse", "staple", "sheath", "heat", "abcde", "bcdx", "ox"], 4);
+
+        assert_eq!(dict.find("x9!HoRsE7"), Some(3..8));
+        assert!(dict.find("Tr0ub4dor&3").is_none());
+
+        // words shorter than the minimum are ignored
+        assert!(dict.find("box").is_none());
+
+        // a match that starts inside a longer partial match
+        assert_eq!(dict.find("abcdx"), Some(1..5));
+
+        // a match that is a suffix of a longer partial match
+        assert_eq!(dict.find("sheat"), Some(1..5));
+
+        // anything but an ascii letter breaks a word
+        assert!(dict.find("hor-se stäple").is_none());
+    }
+}
@@ -0,0 +1,111 @@
+/* -------------------- *
+ *       ENTROPY        *
+ * -------------------- */
+/// Upper bounds in bits for each strength label, in ascending order.
+pub const STRENGTH_LABELS: &[(f64, &str)] = &[
+    (40.0, "weak"),
+    (64.0, "fair"),
+    (90.0, "strong"),
+    (f64::INFINITY, "excellent"),
+];
+
+/// Size of the printable ASCII alphabet.
+pub(crate) const PRINTABLE_ASCII: f64 = 95.0;
+
+/// Size of the alphanumeric alphabet.
+pub(crate) const ALPHANUMERIC: f64 = 62.0;
+
+/// Bits of entropy of `count` tokens drawn uniformly from a pool of `pool_size`.
+pub fn token_bits(pool_size: usize, count: u32) -> f64 {
+    (pool_size as f64).log2() * count as f64
+}
+
+/// Seconds needed to search `fraction` of a keyspace of `bits` at `rate` guesses per second.
+pub(crate) fn crack_time(bits: f64, rate: f64, fraction: f64) -> f64 {
+    fraction * bits.exp2() / rate
+}
+
+/// Probability that `attempts` guesses at a keyspace of `bits` hit any of `valid` codes in it.
+pub(crate) fn guess_probability(bits: f64, valid: f64, attempts: f64) -> f64 {
+    // 1 - (1 - p)^k, without losing a tiny p to rounding
+    -(attempts * (-valid / bits.exp2()).ln_1p()).exp_m1()
+}
+
+/// Length of a uniformly random password from an alphabet of `size` with the same entropy.
+pub(crate) fn equivalent_length(bits: f64, size: f64) -> f64 {
+    (bits / size.log2()).ceil()
+}
+
+/// Qualitative label for a total entropy in bits.
+pub(crate) fn strength_label(bits: f64) -> &'static str {
+    STRENGTH_LABELS
+        .iter()
+        .find(|&&(limit, _)| bits < limit)
+        .map_or("excellent", |&(_, label)| label)
+}
+
+/// Characters an [`entropy_bar`] is drawn with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BarCharset {
+    /// Full and light shade blocks, for terminals with a UTF-8 locale.
+    Unicode,
+    /// `#` and `-`, for everything else.
+    Ascii,
+}
+
+/// A bar of `width` cells between brackets, filled in proportion to `bits` out of `target`.
+///
+/// The share is rounded to the nearest cell and capped at a full bar, any positive entropy
+/// fills at least one cell and an infinite one fills them all.
+pub(crate) fn entropy_bar(bits: f64, target: f64, width: usize, charset: BarCharset) -> String {
+    let (full, empty) = match charset {
+        BarCharset::Unicode => ('█', '░'),
+        BarCharset::Ascii => ('#', '-'),
+    };
+
+    let share = if target > 0.0 { bits / target } else { 1.0 };
+    let mut filled = (share.clamp(0.0, 1.0) * width as f64).round() as usize;
+
+    if bits > 0.0 && filled == 0 {
+        filled = width.min(1);
+    }
+
+    let mut bar = String::with_capacity(width * full.len_utf8() + 2);
+
+    bar.push('[');
+    bar.extend(std::iter::repeat_n(full, filled));
+    bar.extend(std::iter::repeat_n(empty, width - filled));
+    bar.push(']');
+    bar
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guess_probability_counts_every_code() {
+        assert_eq!(guess_probability(10.0, 1.0, 1.0), 1.0 / 1024.0);
+        assert!((guess_probability(10.0, 8.0, 1.0) - 8.0 / 1024.0).abs() < 1e-15);
+
+        // each valid code is another way to be found
+        let (one, ten) = (guess_probability(40.0, 1.0, 1000.0), guess_probability(40.0, 10.0, 1000.0));
+        assert!((ten / one - 10.0).abs() < 1e-6);
+
+        // and without enough entropy the codes are found for sure
+        assert!(guess_probability(8.0, 10.0, 1e6) > 0.999999);
+    }
+
+    #[test]
+    fn bar_fills_toward_the_target() {
+        assert_eq!(entropy_bar(71.0, 128.0, 20, BarCharset::Unicode), "[███████████░░░░░░░░░]");
+        assert_eq!(entropy_bar(71.0, 128.0, 20, BarCharset::Ascii), "[###########---------]");
+        assert_eq!(entropy_bar(64.0, 128.0, 10, BarCharset::Ascii), "[#####-----]");
+
+        // beyond the target the bar is full, and a little entropy still shows
+        assert_eq!(entropy_bar(300.0, 128.0, 8, BarCharset::Ascii), "[########]");
+        assert_eq!(entropy_bar(f64::INFINITY, 128.0, 4, BarCharset::Ascii), "[####]");
+        assert_eq!(entropy_bar(1.0, 128.0, 10, BarCharset::Ascii), "[#---------]");
+        assert_eq!(entropy_bar(0.0, 128.0, 5, BarCharset::Unicode), "[░░░░░]");
+    }
+}
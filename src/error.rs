@@ -0,0 +1,66 @@
+use std::{error::Error, fmt, io};
+
+/* -------------------- *
+ *        ERRORS        *
+ * -------------------- */
+/// Everything that can go wrong while configuring or generating passwords.
+#[derive(Debug)]
+pub enum PassGenError {
+    /// Invalid flags or arguments.
+    Usage(String),
+    /// A file or stream could not be opened or read.
+    Io {
+        action: &'static str,
+        path: Option<String>,
+        source: io::Error,
+    },
+    /// Token data that cannot be used.
+    InvalidData(String),
+    /// The configuration cannot satisfy a requested constraint.
+    Unsatisfiable(String),
+    /// The total entropy is below the requested minimum.
+    Weak { total: f64, min: f64, missing: f64 },
+    /// Writing the generated output failed.
+    Output(io::Error),
+    /// An error on one line of an input file, like a spec file.
+    Line {
+        path: String,
+        line: usize,
+        source: Box<PassGenError>,
+    },
+}
+
+impl fmt::Display for PassGenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PassGenError::Usage(msg) => write!(f, "{}", msg),
+            PassGenError::Io { action, source, .. } => write!(f, "error while {}: {}", action, source),
+            PassGenError::InvalidData(msg) => write!(f, "{}", msg),
+            PassGenError::Unsatisfiable(msg) => write!(f, "{}", msg),
+            PassGenError::Weak { total, min, missing } => write!(
+                f,
+                "total entropy of {:.1} bits is below the minimum of {} bits, \
+                {} more token(s) would be needed",
+                total, min, missing
+            ),
+            PassGenError::Output(source) => write!(f, "error while writing output: {}", source),
+            PassGenError::Line { path, line, source } => write!(f, "{} line {}: {}", path, line, source),
+        }
+    }
+}
+
+impl Error for PassGenError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PassGenError::Io { source, .. } | PassGenError::Output(source) => Some(source),
+            PassGenError::Line { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for PassGenError {
+    fn from(err: io::Error) -> Self {
+        PassGenError::Output(err)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{entropy_bits, generate, wipe_string, PassGenError};
+use std::{
+    cell::RefCell,
+    ffi::{c_char, c_int, CStr, CString},
+    panic::{catch_unwind, UnwindSafe},
+    ptr,
+};
+
+/* -------------------- *
+ *        C ABI         *
+ * -------------------- */
+// declared in ffi/passgen.h, tests/ffi.rs checks that the two agree
+pub const PASSGEN_OK: c_int = 0;
+pub const PASSGEN_INVALID_ARGUMENT: c_int = 1;
+pub const PASSGEN_BUFFER_TOO_SMALL: c_int = 2;
+pub const PASSGEN_INTERNAL_ERROR: c_int = 3;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Writes a NUL terminated password into `out`.
+///
+/// # Safety
+///
+/// `preset` and `sep` must be NUL terminated strings and `out` must point to
+/// `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn passgen_generate(
+    preset: *const c_char,
+    count: u32,
+    sep: *const c_char,
+    out: *mut c_char,
+    out_len: usize,
+) -> c_int {
+    guard(|| {
+        let preset = unsafe { read_str(preset, "preset") }?;
+        let sep = unsafe { read_str(sep, "sep") }?;
+
+        if out.is_null() {
+            return Err((PASSGEN_INVALID_ARGUMENT, String::from("out is null")));
+        }
+
+        let mut password = generate(preset, count, sep).map_err(usage)?;
+
+        // the only copy left is the one in the caller's buffer
+        let res = match password.len() < out_len {
+            // SAFETY: the caller guarantees out_len bytes and the length was checked above
+            true => unsafe {
+                ptr::copy_nonoverlapping(password.as_ptr(), out.cast(), password.len());
+                *out.add(password.len()) = 0;
+                Ok(())
+            },
+            false => {
+                let msg = format!("password needs {} bytes, buffer has {}", password.len() + 1, out_len);
+                Err((PASSGEN_BUFFER_TOO_SMALL, msg))
+            },
+        };
+
+        wipe_string(&mut password);
+        res
+    })
+}
+
+/// Stores the entropy of `count` tokens of `preset` in `out`.
+///
+/// # Safety
+///
+/// `preset` must be a NUL terminated string and `out` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn passgen_entropy_bits(preset: *const c_char, count: u32, out: *mut f64) -> c_int {
+    guard(|| {
+        let preset = unsafe { read_str(preset, "preset") }?;
+
+        if out.is_null() {
+            return Err((PASSGEN_INVALID_ARGUMENT, String::from("out is null")));
+        }
+
+        let bits = entropy_bits(preset, count).map_err(usage)?;
+
+        // SAFETY: checked for null above, validity is up to the caller
+        unsafe { *out = bits };
+
+        Ok(())
+    })
+}
+
+/// Message of the last failed call on this thread, or null.
+///
+/// The pointer stays valid until the next call on the same thread.
+#[no_mangle]
+pub extern "C" fn passgen_last_error() -> *const c_char {
+    LAST_ERROR.with(|err| err.borrow().as_ref().map_or(ptr::null(), |msg| msg.as_ptr()))
+}
+
+// no panic may unwind into C, they are reported as internal errors instead
+fn guard(f: impl FnOnce() -> Result<(), (c_int, String)> + UnwindSafe) -> c_int {
+    let (status, msg) = match catch_unwind(f) {
+        Ok(Ok(())) => (PASSGEN_OK, None),
+        Ok(Err((status, msg))) => (status, Some(msg)),
+        Err(_) => (PASSGEN_INTERNAL_ERROR, Some(String::from("internal error"))),
+    };
+
+    let msg = msg.map(|msg| CString::new(msg.replace('\0', "")).unwrap_or_default());
+    LAST_ERROR.with(|err| *err.borrow_mut() = msg);
+
+    status
+}
+
+unsafe fn read_str<'a>(ptr: *const c_char, name: &str) -> Result<&'a str, (c_int, String)> {
+    if ptr.is_null() {
+        return Err((PASSGEN_INVALID_ARGUMENT, format!("{} is null", name)));
+    }
+
+    // SAFETY: non-null and NUL terminated per the caller's contract
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map_err(|_| (PASSGEN_INVALID_ARGUMENT, format!("{} is not valid utf-8", name)))
+}
+
+fn usage(err: PassGenError) -> (c_int, String) {
+    (PASSGEN_INVALID_ARGUMENT, err.to_string())
+}
@@ -0,0 +1,859 @@
+use crate::{
+    checksum_token, grapheme_len, preset, token::TokenSource, wipe, Acrostic, BitPacking, ClassSet, Join, Leet,
+    PassGenError, Preset, RandomCase, Sentence, Template,
+};
+use rand::{seq::index, Rng};
+use std::ops::Range;
+
+/* -------------------- *
+ *      GENERATOR       *
+ * -------------------- */
+/// Assembles passwords from a token pool.
+///
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let preset = pass_gen::preset("word").unwrap();
+/// let mut gen = pass_gen::Generator::new(&preset.token_data, 4, "-", StdRng::seed_from_u64(1));
+///
+/// assert_eq!(gen.generate().split('-').count(), 4);
+/// ```
+///
+/// ```
+/// use pass_gen::{Generator, TokenData};
+///
+/// let words = TokenData::from_reader("red\ngreen\nblue\n".as_bytes()).unwrap();
+/// let passwords = Generator::new(&words, 3, " ", rand::thread_rng()).generate_many(5);
+///
+/// assert_eq!(passwords.len(), 5);
+/// ```
+pub struct Generator<'a, R> {
+    token_data: &'a dyn TokenSource,
+    token_count: u32,
+    token_sep: &'a str,
+    sep_set: &'a [char],
+    max_token_len: usize,
+    length: Option<Length<'a>>,
+    template: Option<&'a Template>,
+    first: Option<&'a dyn TokenSource>,
+    sources: Option<&'a [Box<dyn TokenSource>]>,
+    leet: Option<&'a Leet>,
+    random_case: Option<&'a RandomCase>,
+    mutate: Option<(usize, &'a dyn TokenSource)>,
+    acrostic: Option<&'a Acrostic>,
+    sentence: Option<&'a Sentence>,
+    packing: Option<&'a BitPacking>,
+    join: Option<&'a Join>,
+    checksum: bool,
+    rng: R,
+}
+
+// tokens sorted by length in characters, so the ones that fit are a prefix
+struct Length<'a> {
+    target: usize,
+    padding: &'a dyn TokenSource,
+    by_length: Vec<usize>,
+    fitting: Vec<usize>,
+}
+
+impl<'a, R: Rng> Generator<'a, R> {
+    pub fn new(token_data: &'a dyn TokenSource, token_count: u32, token_sep: &'a str, rng: R) -> Self {
+        Self {
+            token_data,
+            token_count,
+            token_sep,
+            sep_set: &[],
+            max_token_len: token_data.max_token_len(),
+            length: None,
+            template: None,
+            first: None,
+            sources: None,
+            leet: None,
+            random_case: None,
+            mutate: None,
+            acrostic: None,
+            sentence: None,
+            packing: None,
+            join: None,
+            checksum: false,
+            rng,
+        }
+    }
+
+    /// Picks every separator at random from `sep_set` instead of using a fixed one.
+    pub fn sep_set(mut self, sep_set: &'a [char]) -> Self {
+        self.sep_set = sep_set;
+        self
+    }
+
+    /// Assembles passwords of exactly `length` characters instead of a fixed token count.
+    ///
+    /// Tokens are drawn from those that still fit, separator included, until none does,
+    /// the rest is filled with single character tokens from `padding`. The entropy this
+    /// leaves varies with the tokens drawn.
+    ///
+    /// ```
+    /// use pass_gen::{preset, Generator};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let words = preset("word").unwrap().token_data;
+    /// let digits = preset("number").unwrap().token_data;
+    ///
+    /// for sep in ["", "--"] {
+    ///     let mut gen = Generator::new(&words, 1, sep, StdRng::seed_from_u64(3)).length(24, &digits);
+    ///
+    ///     for _ in 0..1000 {
+    ///         assert_eq!(pass_gen::grapheme_len(&gen.generate()), 24);
+    ///     }
+    /// }
+    /// ```
+    pub fn length(mut self, length: usize, padding: &'a dyn TokenSource) -> Self {
+        let lengths: Vec<usize> = self.token_data.range().map(|idx| grapheme_len(self.token_data.get(idx))).collect();
+
+        let mut by_length: Vec<usize> = self.token_data.range().collect();
+        by_length.sort_by_key(|&idx| lengths[idx]);
+
+        // fitting[n] is the number of tokens at most n characters long
+        let mut fitting = vec![0; length + 1];
+
+        for &len in lengths.iter().filter(|&&len| len <= length) {
+            fitting[len] += 1;
+        }
+
+        for n in 1..fitting.len() {
+            fitting[n] += fitting[n - 1];
+        }
+
+        self.length = Some(Length { target: length, padding, by_length, fitting });
+        self
+    }
+
+    /// Lays passwords out by `template`, drawing its word positions from the token pool.
+    ///
+    /// The token count and separators are ignored.
+    pub fn template(mut self, template: &'a Template) -> Self {
+        self.template = Some(template);
+        self
+    }
+
+    /// Draws the first token of each password from `first`, a subset of the token pool.
+    ///
+    /// Only the token count mode uses it, templates and [`Generator::length`] draw as usual.
+    ///
+    /// ```
+    /// use pass_gen::{preset, Generator, TokenData, TokenSource};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let ascii = preset("ascii").unwrap().token_data;
+    /// let letters = ascii.range().map(|idx| ascii.get(idx)).filter(|t| t.starts_with(char::is_alphabetic));
+    /// let letters = TokenData::from_strings(letters.map(String::from).collect()).unwrap();
+    ///
+    /// let mut gen = Generator::new(&ascii, 8, "", StdRng::seed_from_u64(1)).first(&letters);
+    ///
+    /// assert!((0..10_000).all(|_| gen.generate().starts_with(char::is_alphabetic)));
+    /// ```
+    pub fn first(mut self, first: &'a dyn TokenSource) -> Self {
+        self.first = Some(first);
+        self
+    }
+
+    /// Draws token `n` from source `n` modulo their number instead of the token pool, so the
+    /// sources take turns in order and the token count should be a multiple of theirs.
+    ///
+    /// ```
+    /// use pass_gen::{preset, Generator, TokenData, TokenSource};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let colors = TokenData::from_reader("red\ngreen\nblue\n".as_bytes()).unwrap();
+    /// let sources: Vec<Box<dyn TokenSource>> = vec![Box::new(colors), Box::new(preset("number").unwrap().token_data)];
+    /// let mut gen = Generator::new(sources[0].as_ref(), 4, "-", StdRng::seed_from_u64(1)).sources(&sources);
+    ///
+    /// for _ in 0..1000 {
+    ///     let password = gen.generate();
+    ///     let tokens: Vec<&str> = password.split('-').collect();
+    ///
+    ///     assert!(["red", "green", "blue"].contains(&tokens[0]) && ["red", "green", "blue"].contains(&tokens[2]));
+    ///     assert!(tokens[1].parse::<u8>().is_ok() && tokens[3].parse::<u8>().is_ok());
+    ///     assert!(password.len() <= gen.max_len());
+    /// }
+    /// ```
+    pub fn sources(mut self, sources: &'a [Box<dyn TokenSource>]) -> Self {
+        self.max_token_len = sources.iter().map(|source| source.max_token_len()).max().unwrap_or(0);
+        self.sources = Some(sources);
+        self
+    }
+
+    /// Substitutes characters of every token with `leet`.
+    ///
+    /// Only the token count mode uses it, [`Generator::tokens`] still yields the tokens as drawn.
+    pub fn leet(mut self, leet: &'a Leet) -> Self {
+        self.leet = Some(leet);
+        self
+    }
+
+    /// Uppercases letters of every token at random with `random_case`, after any mutations.
+    ///
+    /// Only the token count mode uses it.
+    pub fn random_case(mut self, random_case: &'a RandomCase) -> Self {
+        self.random_case = Some(random_case);
+        self
+    }
+
+    /// Replaces `count` characters of the tokens with random characters from `pool`.
+    ///
+    /// Positions are distinct and separators are never replaced. Only the token count mode uses it.
+    ///
+    /// ```
+    /// use pass_gen::{preset, Generator};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let words = preset("word").unwrap().token_data;
+    /// let digits = preset("number").unwrap().token_data;
+    /// let mut gen = Generator::new(&words, 4, "-", StdRng::seed_from_u64(1)).mutate(2, &digits);
+    ///
+    /// for _ in 0..1000 {
+    ///     let password = gen.generate();
+    ///
+    ///     assert_eq!(password.split('-').count(), 4);
+    ///     assert!((1..=2).contains(&password.chars().filter(char::is_ascii_digit).count()));
+    /// }
+    /// ```
+    pub fn mutate(mut self, count: usize, pool: &'a dyn TokenSource) -> Self {
+        self.mutate = Some((count, pool));
+        self
+    }
+
+    /// Draws each token from those starting with the matching letter of `acrostic`.
+    ///
+    /// The acrostic must be built from the same token pool, its length replaces the token count.
+    pub fn acrostic(mut self, acrostic: &'a Acrostic) -> Self {
+        self.token_count = acrostic.len() as u32;
+        self.acrostic = Some(acrostic);
+        self
+    }
+
+    /// Assembles passwords from `sentence` instead of the token pool.
+    ///
+    /// Separators go between the words as usual, the token count is ignored.
+    pub fn sentence(mut self, sentence: &'a Sentence) -> Self {
+        self.sentence = Some(sentence);
+        self
+    }
+
+    /// Encodes random integers of exactly `packing.bits()` bits as tokens instead of drawing each
+    /// token on its own, see [`BitPacking`].
+    ///
+    /// The packing's token count replaces the generator's, the first position pool is ignored.
+    ///
+    /// ```
+    /// use pass_gen::{preset, BitPacking, Generator, TokenSource};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let words = preset("word").unwrap().token_data;
+    /// let packing = BitPacking::new(128, words.len()).unwrap();
+    /// let mut gen = Generator::new(&words, 6, " ", StdRng::seed_from_u64(1)).packing(&packing);
+    ///
+    /// assert_eq!(gen.generate().split(' ').count(), packing.count() as usize);
+    /// ```
+    pub fn packing(mut self, packing: &'a BitPacking) -> Self {
+        self.packing = Some(packing);
+        self.token_count = packing.count();
+        self
+    }
+
+    /// Places the tokens into `join` instead of separating them, see [`Join`].
+    ///
+    /// Only the separators between the tokens of `{*}` are drawn, substitutions, mutations and
+    /// the checksum are left out.
+    pub fn join(mut self, join: &'a Join) -> Self {
+        self.join = Some(join);
+        self
+    }
+
+    /// Appends a checksum token from the pool, see [`verify_checksum`](crate::verify_checksum).
+    ///
+    /// It is computed over the tokens as they end up in the password, after substitutions and
+    /// mutations, and follows a separator like any other token. Only the token count mode uses it.
+    pub fn checksum(mut self) -> Self {
+        self.checksum = true;
+        self
+    }
+
+    /// Draws the tokens of one password without joining them.
+    ///
+    /// Separators are drawn from the same rng, so call [`Tokens::push_separator`]
+    /// between tokens to get the same passwords as [`Generator::generate`].
+    ///
+    /// ```
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let preset = pass_gen::preset("word").unwrap();
+    /// let mut gen = pass_gen::Generator::new(&preset.token_data, 4, "-", StdRng::seed_from_u64(7));
+    /// let tokens: Vec<&str> = gen.tokens().collect();
+    ///
+    /// let mut gen = pass_gen::Generator::new(&preset.token_data, 4, "-", StdRng::seed_from_u64(7));
+    ///
+    /// assert_eq!(tokens.join("-"), gen.generate());
+    /// ```
+    pub fn tokens(&mut self) -> Tokens<'_, 'a, R> {
+        let remaining = self.token_count;
+
+        Tokens { generator: self, remaining }
+    }
+
+    /// Appends one password to `password`.
+    pub fn push_password(&mut self, password: &mut String) {
+        if let Some(template) = self.template {
+            return template.push_password(self.token_data, &mut self.rng, password);
+        }
+
+        if self.length.is_some() {
+            return self.push_fitted(password);
+        }
+
+        if let Some(sentence) = self.sentence {
+            return self.push_sentence(sentence, password);
+        }
+
+        if let Some(packing) = self.packing {
+            return self.push_packed(packing, password);
+        }
+
+        if let Some(join) = self.join {
+            let tokens: Vec<&str> = self.tokens().collect();
+            return join.push(&tokens, password, |password| self.push_separator(password));
+        }
+
+        let leet = self.leet;
+        let mut spans = Vec::new();
+        let mut tokens = self.tokens();
+
+        while let Some(token) = tokens.next() {
+            let start = password.len();
+
+            match leet {
+                Some(leet) => leet.push_token(token, &mut tokens.generator.rng, password),
+                None => password.push_str(token),
+            }
+
+            spans.push(start..password.len());
+
+            if tokens.len() != 0 {
+                tokens.push_separator(password);
+            }
+        }
+
+        if let Some((count, pool)) = self.mutate {
+            self.push_mutations(password, &mut spans, count, pool);
+        }
+
+        if let Some(case) = self.random_case {
+            for span in &spans {
+                case.apply(password, span.clone(), &mut self.rng);
+            }
+        }
+
+        if self.checksum {
+            let token = checksum_token(spans.iter().map(|span| &password[span.clone()]), self.token_data);
+
+            self.push_separator(password);
+            password.push_str(token);
+        }
+    }
+
+    // replaces whole characters from the back, so the offsets in front stay valid
+    fn push_mutations(
+        &mut self,
+        password: &mut String,
+        spans: &mut [Range<usize>],
+        count: usize,
+        pool: &dyn TokenSource,
+    ) {
+        let chars: Vec<Range<usize>> = spans
+            .iter()
+            .flat_map(|span| password[span.clone()].char_indices().map(move |(idx, c)| {
+                span.start + idx..span.start + idx + c.len_utf8()
+            }))
+            .collect();
+
+        let mut positions = index::sample(&mut self.rng, chars.len(), count.min(chars.len())).into_vec();
+        positions.sort_unstable_by(|a, b| b.cmp(a));
+
+        for position in positions {
+            let replacement = pool.get(self.rng.gen_range(pool.range()));
+            let replaced = chars[position].clone();
+
+            // the spans from the replaced character on move with its length
+            let shift = replacement.len() as isize - replaced.len() as isize;
+
+            for span in spans.iter_mut().filter(|span| span.end > replaced.start) {
+                span.end = span.end.wrapping_add_signed(shift);
+
+                if span.start > replaced.start {
+                    span.start = span.start.wrapping_add_signed(shift);
+                }
+            }
+
+            password.replace_range(replaced, replacement);
+        }
+    }
+
+    fn push_sentence(&mut self, sentence: &Sentence, password: &mut String) {
+        for (idx, &pos) in sentence.pick(&mut self.rng).iter().enumerate() {
+            if idx != 0 {
+                self.push_separator(password);
+            }
+
+            let words = sentence.words(pos);
+            password.push_str(words.get(self.rng.gen_range(words.range())));
+        }
+    }
+
+    fn push_packed(&mut self, packing: &BitPacking, password: &mut String) {
+        let mut bytes = vec![0; packing.bytes()];
+        self.rng.fill(&mut bytes[..]);
+
+        for (position, idx) in packing.encode(&bytes).into_iter().enumerate() {
+            if position != 0 {
+                self.push_separator(password);
+            }
+
+            password.push_str(self.token_data.get(idx));
+        }
+
+        wipe(&mut bytes);
+    }
+
+    fn push_fitted(&mut self, password: &mut String) {
+        // taken out for the duration so the rng can be borrowed alongside it
+        let length = match self.length.take() {
+            Some(length) => length,
+            None => return,
+        };
+
+        let sep_len = if self.sep_set.is_empty() { grapheme_len(self.token_sep) } else { 1 };
+        let mut remaining = length.target;
+        let mut first = true;
+
+        loop {
+            let sep = if first { 0 } else { sep_len };
+            let fits = remaining.checked_sub(sep).map_or(0, |room| length.fitting[room]);
+
+            if fits == 0 {
+                break;
+            }
+
+            if !first {
+                self.push_separator(password);
+            }
+
+            let token = self.token_data.get(length.by_length[self.rng.gen_range(0..fits)]);
+
+            password.push_str(token);
+            remaining -= sep + grapheme_len(token);
+            first = false;
+        }
+
+        for _ in 0..remaining {
+            password.push_str(length.padding.get(self.rng.gen_range(length.padding.range())));
+        }
+
+        self.length = Some(length);
+    }
+
+    fn push_separator(&mut self, password: &mut String) {
+        if self.sep_set.is_empty() {
+            password.push_str(self.token_sep);
+        } else {
+            password.push(self.sep_set[self.rng.gen_range(0..self.sep_set.len())]);
+        }
+    }
+
+    /// Upper bound in bytes of a generated password.
+    ///
+    /// Buffers allocated with this capacity never reallocate while a password
+    /// is assembled, so wiping them afterwards leaves no copies behind.
+    ///
+    /// ```
+    /// let preset = pass_gen::preset("word").unwrap();
+    /// let mut gen = pass_gen::Generator::new(&preset.token_data, 6, " ", rand::thread_rng());
+    /// let password = gen.generate();
+    ///
+    /// assert!(password.len() <= gen.max_len());
+    /// assert_eq!(password.capacity(), gen.max_len());
+    /// ```
+    pub fn max_len(&self) -> usize {
+        if let Some(template) = self.template {
+            return template.max_len(self.token_data);
+        }
+
+        let sep_len = match self.sep_set.iter().map(|c| c.len_utf8()).max() {
+            Some(len) => len,
+            None => self.token_sep.len(),
+        };
+
+        if let Some(sentence) = self.sentence {
+            return sentence.max_len(sep_len);
+        }
+
+        // every token, separator and padding covers at least one character
+        if let Some(length) = &self.length {
+            return length.target * self.max_token_len.max(sep_len).max(length.padding.max_token_len());
+        }
+
+        let count = self.token_count as usize;
+
+        // the literals take no more bytes than the template they are written in
+        if let Some(join) = self.join {
+            return self.max_token_len * count + sep_len * join.separators(count) + join.to_string().len();
+        }
+
+        let mutations = self.mutate.map_or(0, |(mutations, pool)| mutations * pool.max_token_len());
+        let checksum = if self.checksum { sep_len + self.max_token_len } else { 0 };
+
+        self.max_token_len * count + sep_len * count.saturating_sub(1) + mutations + checksum
+    }
+
+    pub fn generate(&mut self) -> String {
+        let mut password = String::with_capacity(self.max_len());
+        self.push_password(&mut password);
+        password
+    }
+
+    pub fn generate_many(&mut self, n: usize) -> Vec<String> {
+        (0..n).map(|_| self.generate()).collect()
+    }
+
+    /// The rng passwords are drawn from, for values that should follow them in the same stream.
+    pub fn rng(&mut self) -> &mut R {
+        &mut self.rng
+    }
+}
+
+/// The tokens of one password, see [`Generator::tokens`].
+pub struct Tokens<'g, 'a, R> {
+    generator: &'g mut Generator<'a, R>,
+    remaining: u32,
+}
+
+impl<'g, 'a, R: Rng> Tokens<'g, 'a, R> {
+    /// Appends the separator that goes before the next token.
+    pub fn push_separator(&mut self, password: &mut String) {
+        self.generator.push_separator(password);
+    }
+}
+
+impl<'g, 'a, R: Rng> Iterator for Tokens<'g, 'a, R> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let generator = &mut *self.generator;
+        let position = (generator.token_count - self.remaining) as usize;
+
+        self.remaining -= 1;
+
+        if let Some(acrostic) = generator.acrostic {
+            let indices = acrostic.indices(position);
+            return Some(generator.token_data.get(indices[generator.rng.gen_range(0..indices.len())]));
+        }
+
+        let token_data = match (generator.sources, generator.first) {
+            (Some(sources), _) => sources[position % sources.len()].as_ref(),
+            (None, Some(first)) if position == 0 => first,
+            _ => generator.token_data,
+        };
+
+        let idx = generator.rng.gen_range(token_data.range());
+
+        Some(token_data.get(idx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining as usize, Some(self.remaining as usize))
+    }
+}
+
+impl<'g, 'a, R: Rng> ExactSizeIterator for Tokens<'g, 'a, R> {}
+
+
+/* -------------------- *
+ *     LENGTH MODE      *
+ * -------------------- */
+/// Minimum and average entropy in bits of a password assembled by [`Generator::length`].
+///
+/// `sep_len` is the separator length in characters and `sep_choices` the number of
+/// separators to pick from, `padding` is the size of the padding pool.
+pub(crate) fn length_bits(
+    token_data: &dyn TokenSource,
+    length: usize,
+    sep_len: usize,
+    sep_choices: usize,
+    padding: usize,
+) -> (f64, f64) {
+    let mut counts = vec![0; length + 1];
+
+    for len in token_data.range().map(|idx| grapheme_len(token_data.get(idx))) {
+        if len <= length {
+            counts[len] += 1;
+        }
+    }
+
+    let pad_bits = (padding as f64).log2();
+    let sep_bits = (sep_choices as f64).log2();
+
+    // bits[n] covers the rest of a password with n characters left after a token
+    let mut bits: Vec<(f64, f64)> = Vec::with_capacity(length + 1);
+
+    let step = |bits: &[(f64, f64)], room: usize, sep_bits: f64, left: usize| {
+        let fits: usize = counts[..=room.min(length)].iter().sum();
+
+        if fits == 0 {
+            return (left as f64 * pad_bits, left as f64 * pad_bits);
+        }
+
+        let choice = (fits as f64).log2() + sep_bits;
+        let mut min = f64::INFINITY;
+        let mut avg = 0.0;
+
+        for (len, &count) in counts.iter().enumerate().take(room + 1).skip(1).filter(|(_, &count)| count > 0) {
+            let (next_min, next_avg) = bits[room - len];
+
+            min = min.min(next_min);
+            avg += count as f64 / fits as f64 * next_avg;
+        }
+
+        (choice + min, choice + avg)
+    };
+
+    for left in 0..=length {
+        let next = match left.checked_sub(sep_len) {
+            Some(room) => step(&bits, room, sep_bits, left),
+            None => (left as f64 * pad_bits, left as f64 * pad_bits),
+        };
+
+        bits.push(next);
+    }
+
+    step(&bits, length, 0.0, length)
+}
+
+/// Probability that a password assembled by [`Generator::length`] contains every class in `required`.
+///
+/// Separators are drawn uniformly from `seps`, all of the same length in characters.
+pub(crate) fn length_require_probability(
+    required: ClassSet,
+    token_data: &dyn TokenSource,
+    length: usize,
+    seps: &[&str],
+    padding: &dyn TokenSource,
+) -> f64 {
+    let sep_len = seps.first().map_or(0, |sep| grapheme_len(sep));
+    let sep_sets: Vec<usize> = seps.iter().map(|sep| ClassSet::of(sep).bits()).collect();
+
+    // tokens counted by length and by the set of classes they contain
+    let mut counts = vec![[0usize; 16]; length + 1];
+
+    for token in token_data.range().map(|idx| token_data.get(idx)) {
+        let len = grapheme_len(token);
+
+        if len <= length {
+            counts[len][ClassSet::of(token).bits()] += 1;
+        }
+    }
+
+    let mut pad_counts = [0usize; 16];
+
+    for token in padding.range().map(|idx| padding.get(idx)) {
+        pad_counts[ClassSet::of(token).bits()] += 1;
+    }
+
+    // inclusion-exclusion over the missing classes the padding never draws
+    let pad_covers = |missing: usize, left: usize| {
+        let total = padding.len().max(1) as f64;
+        let mut probability = 0.0;
+        let mut subset = missing;
+
+        loop {
+            let avoiding: usize = (0..16).filter(|set| set & subset == 0).map(|set| pad_counts[set]).sum();
+            let term = (avoiding as f64 / total).powi(left as i32);
+
+            probability += if subset.count_ones().is_multiple_of(2) { term } else { -term };
+
+            if subset == 0 {
+                return probability;
+            }
+
+            subset = (subset - 1) & missing;
+        }
+    };
+
+    let required = required.bits();
+
+    // probability[left][seen] of ending with every class, with left characters after a token
+    let mut probability: Vec<[f64; 16]> = Vec::with_capacity(length + 1);
+
+    let step = |probability: &[[f64; 16]], left: usize, room: usize, seen: usize, seps: &[usize]| {
+        let fits: usize = counts[..=room].iter().flatten().sum();
+
+        if fits == 0 {
+            return pad_covers(required & !seen, left);
+        }
+
+        let mut sum = 0.0;
+
+        for (len, sets) in counts.iter().enumerate().take(room + 1).skip(1) {
+            for (set, &count) in sets.iter().enumerate().filter(|(_, &count)| count > 0) {
+                for &sep in seps {
+                    let next = probability[room - len][(seen | set | sep) & required];
+                    sum += count as f64 / fits as f64 / seps.len() as f64 * next;
+                }
+            }
+        }
+
+        sum
+    };
+
+    for left in 0..=length {
+        let mut row = [0.0; 16];
+
+        for (seen, p) in row.iter_mut().enumerate() {
+            *p = match left.checked_sub(sep_len) {
+                Some(room) => step(&probability, left, room, seen, &sep_sets),
+                None => pad_covers(required & !seen, left),
+            };
+        }
+
+        probability.push(row);
+    }
+
+    step(&probability, length, length, 0, &[0]).clamp(0.0, 1.0)
+}
+
+/// Probability that `count` tokens joined by separators of `sep_len` characters
+/// are at most `max_length` characters long.
+///
+/// Re-drawing longer passwords keeps the rest uniform, so the entropy drops by `-log2` of this.
+pub(crate) fn max_length_probability(
+    token_data: &dyn TokenSource,
+    count: u32,
+    sep_len: usize,
+    max_length: usize,
+) -> f64 {
+    slots_max_length_probability(&vec![token_data; count as usize], sep_len, max_length)
+}
+
+/// Like [`max_length_probability`], with each token drawn from its own source.
+pub(crate) fn slots_max_length_probability(slots: &[&dyn TokenSource], sep_len: usize, max_length: usize) -> f64 {
+    let seps = sep_len * slots.len().saturating_sub(1);
+
+    let budget = match max_length.checked_sub(seps) {
+        Some(budget) => budget,
+        None => return 0.0,
+    };
+
+    // distribution of the token characters so far, dropping everything over budget
+    let mut dist = vec![0.0; budget + 1];
+    dist[0] = 1.0;
+
+    for token_data in slots {
+        let mut counts = vec![0; budget + 1];
+
+        for len in token_data.range().map(|idx| grapheme_len(token_data.get(idx))) {
+            if len <= budget {
+                counts[len] += 1;
+            }
+        }
+
+        let total = token_data.len().max(1) as f64;
+        let mut next = vec![0.0; budget + 1];
+
+        for (sum, &p) in dist.iter().enumerate().filter(|(_, &p)| p > 0.0) {
+            for (len, &n) in counts.iter().enumerate().take(budget - sum + 1).filter(|(_, &n)| n > 0) {
+                next[sum + len] += p * n as f64 / total;
+            }
+        }
+
+        dist = next;
+    }
+
+    dist.iter().sum::<f64>().clamp(0.0, 1.0)
+}
+
+
+/* -------------------- *
+ *       ONE-SHOT       *
+ * -------------------- */
+/// Generates one password from a builtin preset using the thread rng.
+///
+/// This and [`entropy_bits`] are the whole surface needed by bindings.
+///
+/// ```
+/// let password = pass_gen::generate("number", 6, "").unwrap();
+///
+/// assert_eq!(password.len(), 6);
+/// assert!(pass_gen::generate("emoji", 6, "").is_err());
+/// ```
+pub fn generate(preset: &str, count: u32, sep: &str) -> Result<String, PassGenError> {
+    let preset = lookup(preset, count)?;
+
+    Ok(Generator::new(&preset.token_data, count, sep, rand::thread_rng()).generate())
+}
+
+/// Entropy in bits of `count` tokens drawn from a builtin preset.
+pub fn entropy_bits(preset: &str, count: u32) -> Result<f64, PassGenError> {
+    let preset = lookup(preset, count)?;
+
+    Ok(preset.token_data.entropy_bits_per_token() * count as f64)
+}
+
+fn lookup(name: &str, count: u32) -> Result<Preset, PassGenError> {
+    if count == 0 {
+        return Err(PassGenError::Usage(String::from("count must be a positive number")));
+    }
+
+    preset(name).ok_or_else(|| PassGenError::Usage(format!("invalid preset {:?}", name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TokenData;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn length_bits_of_the_word_list() {
+        let words = preset("word").unwrap().token_data;
+        let (min, avg) = length_bits(&words, 24, 1, 1, 41);
+
+        assert!(min < avg && avg < 70.0);
+    }
+
+    #[test]
+    fn require_probability_of_the_padding() {
+        let words = preset("word").unwrap().token_data;
+        let digits = preset("number").unwrap().token_data;
+        let digit = ClassSet::parse("digit").unwrap();
+
+        // only the padding has digits, and there is none when the words fit exactly
+        let p = length_require_probability(digit, &words, 24, &[" "], &digits);
+
+        assert!(p > 0.0 && p < 1.0);
+    }
+
+    #[test]
+    fn max_length_probability_matches_the_draws() {
+        let lines = ["a", "bb", "ccccccccc", "dddddddddddddddddddd"].map(String::from).to_vec();
+        let words = TokenData::from_strings(lines).unwrap();
+        let p = max_length_probability(&words, 3, 1, 16);
+
+        let mut gen = Generator::new(&words, 3, "-", StdRng::seed_from_u64(5));
+        let fit = (0..100_000).filter(|_| gen.generate().chars().count() <= 16).count();
+
+        assert!((p - 20.0 / 64.0).abs() < 1e-9);
+        assert!((fit as f64 / 100_000.0 - p).abs() < 0.01);
+    }
+}
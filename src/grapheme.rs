@@ -0,0 +1,146 @@
+use crate::data::grapheme::GRAPHEME_CLASSES;
+use std::cmp::Ordering;
+use Class::*;
+
+/* -------------------- *
+ *      GRAPHEMES       *
+ * -------------------- */
+/// Splits `str` into user-perceived characters, the unit every length in pass-gen is counted in.
+///
+/// These are the extended grapheme clusters of [UAX #29] with the properties of Unicode 14.0, rules
+/// GB3 to GB13: combining and spacing marks, variation selectors, skin tone modifiers and tags belong
+/// to the character before them, prepended marks to the one after, Hangul jamo form syllables,
+/// pictographs joined by a zero width joiner stay together, regional indicators pair up into flags
+/// and `\r\n` is one character. The Indic conjunct rule GB9c of later versions is not applied.
+///
+/// [UAX #29]: https://www.unicode.org/reports/tr29/
+///
+/// Limits that are defined in bytes, like the 63 bytes of a WPA passphrase, should check
+/// `str::len` explicitly instead.
+///
+/// ```
+/// use pass_gen::{grapheme_len, graphemes};
+///
+/// // plain ascii, every count agrees
+/// let ascii = "Tr0ub4dor&3";
+/// assert_eq!((grapheme_len(ascii), ascii.chars().count(), ascii.len()), (11, 11, 11));
+///
+/// // a decomposed accent is two code points but one character
+/// let decomposed = "cafe\u{301}";
+/// assert_eq!((grapheme_len(decomposed), decomposed.chars().count(), decomposed.len()), (4, 5, 6));
+/// assert_eq!(graphemes(decomposed).last(), Some("e\u{301}"));
+///
+/// // a family, a thumbs up with a skin tone, a flag and a keycap
+/// let emoji = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{1F44D}\u{1F3FD}\u{1F1E9}\u{1F1EA}7\u{FE0F}\u{20E3}";
+/// assert_eq!((grapheme_len(emoji), emoji.chars().count(), emoji.len()), (4, 12, 41));
+///
+/// // three flags in a row pair up from the start
+/// assert_eq!(grapheme_len("\u{1F1E9}\u{1F1EA}\u{1F1EB}\u{1F1F7}\u{1F1EE}\u{1F1F9}"), 3);
+/// assert_eq!(grapheme_len("a\r\nb"), 3);
+///
+/// // jamo make up one syllable, a prepended mark joins what follows
+/// assert_eq!(grapheme_len("\u{1100}\u{1161}\u{11A8}"), 1);
+/// assert_eq!(graphemes("\u{600}1a").collect::<Vec<_>>(), ["\u{600}1", "a"]);
+/// assert_eq!(grapheme_len(""), 0);
+/// ```
+pub fn graphemes(str: &str) -> impl Iterator<Item = &str> {
+    let mut rest = str;
+
+    std::iter::from_fn(move || {
+        let mut chars = rest.char_indices();
+        let (_, first) = chars.next()?;
+
+        let mut prev = class(first);
+        let mut end = rest.len();
+
+        // regional indicators in a row, an odd count waits for its pair
+        let mut regionals = usize::from(prev == RegionalIndicator);
+        // a pictograph and its extends so far, and whether a zwj after them joins the next one
+        let mut pictograph = prev == Pictographic;
+        let mut joining = false;
+
+        for (idx, c) in chars {
+            let next = class(c);
+
+            if breaks(prev, next, regionals % 2 == 1, joining) {
+                end = idx;
+                break;
+            }
+
+            regionals = if next == RegionalIndicator { regionals + 1 } else { 0 };
+            joining = next == Zwj && pictograph;
+            pictograph = next == Pictographic || (next == Extend && pictograph);
+            prev = next;
+        }
+
+        let (grapheme, tail) = rest.split_at(end);
+        rest = tail;
+
+        Some(grapheme)
+    })
+}
+
+/// Length of `str` in user-perceived characters, see [`graphemes`].
+pub fn grapheme_len(str: &str) -> usize {
+    // ascii never combines, except \r\n
+    if str.is_ascii() && !str.contains("\r\n") {
+        return str.len();
+    }
+
+    graphemes(str).count()
+}
+
+/// Grapheme_Cluster_Break of a code point, with Extended_Pictographic as a class of its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Class {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    Pictographic,
+}
+
+fn class(c: char) -> Class {
+    // printable ascii is the common case
+    if (' '..='~').contains(&c) {
+        return Other;
+    }
+
+    let cp = c as u32;
+    let found = GRAPHEME_CLASSES.binary_search_by(|&(start, end, _)| match (start > cp, end < cp) {
+        (true, _) => Ordering::Greater,
+        (_, true) => Ordering::Less,
+        _ => Ordering::Equal,
+    });
+
+    found.map_or(Other, |idx| GRAPHEME_CLASSES[idx].2)
+}
+
+// the rules between two code points, given the regional indicators and pictograph before them
+fn breaks(prev: Class, next: Class, odd_regionals: bool, joining: bool) -> bool {
+    match (prev, next) {
+        // GB3, GB4, GB5
+        (Cr, Lf) => false,
+        (Cr | Lf | Control, _) | (_, Cr | Lf | Control) => true,
+        // GB6, GB7, GB8
+        (L, L | V | Lv | Lvt) | (Lv | V, V | T) | (Lvt | T, T) => false,
+        // GB9, GB9a, GB9b
+        (_, Extend | Zwj | SpacingMark) | (Prepend, _) => false,
+        // GB11
+        (Zwj, Pictographic) => !joining,
+        // GB12, GB13
+        (RegionalIndicator, RegionalIndicator) => !odd_regionals,
+        // GB999
+        _ => true,
+    }
+}
@@ -0,0 +1,143 @@
+use crate::PassGenError;
+use std::fmt;
+
+/// Hardware the guess rates of [`HASH_RATES`] are for.
+pub(crate) const HASH_HARDWARE: &str = "8x RTX 4090 running hashcat";
+
+/// A cost parameter of a hash: its name, how the work grows with it, the value the rate of
+/// [`HASH_RATES`] is for and the value assumed when none is given.
+pub type HashParam = (&'static str, Cost, u32, u32);
+
+/// Guesses per second of 8x RTX 4090 running hashcat against each verifier hash, at the
+/// reference values of its cost parameters.
+///
+/// The fast hashes are benchmarks, the memory hard ones estimates from memory bandwidth.
+pub const HASH_RATES: &[(&str, f64, &[HashParam])] = &[
+    ("md5", 1.3e12, &[]),
+    ("sha1", 4.0e11, &[]),
+    ("sha256", 1.8e11, &[]),
+    ("bcrypt", 1.5e6, &[("cost", Cost::Doubling, 5, 12)]),
+    ("scrypt", 5.6e4, &[("N", Cost::Linear, 16384, 16384), ("r", Cost::Linear, 8, 8), ("p", Cost::Linear, 1, 1)]),
+    ("argon2id", 2.0e4, &[("m", Cost::Linear, 65536, 19456), ("t", Cost::Linear, 3, 2), ("p", Cost::Free, 1, 1)]),
+];
+
+/* -------------------- *
+ *      HASH RATES      *
+ * -------------------- */
+/// How the work of a hash grows with one of its cost parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cost {
+    /// In proportion to the value.
+    Linear,
+    /// Twice the work for each step, like the cost of bcrypt.
+    Doubling,
+    /// Not at all, like lanes sharing the memory of argon2.
+    Free,
+}
+
+/// A verifier hash with its cost parameters, written `alg` or `alg:value,value...` with
+/// the parameters in the order of [`HASH_RATES`].
+///
+/// ```
+/// use pass_gen::TargetHash;
+///
+/// let md5 = TargetHash::parse("md5").unwrap();
+/// let bcrypt = TargetHash::parse("bcrypt:10").unwrap();
+///
+/// assert_eq!(md5.guesses_per_second(), 1.3e12);
+/// assert_eq!(bcrypt.guesses_per_second(), 1.5e6 / 32.0);
+/// assert_eq!(TargetHash::parse("bcrypt:11").unwrap().guesses_per_second(), 1.5e6 / 64.0);
+///
+/// // missing parameters are assumed and shown
+/// assert_eq!(TargetHash::parse("bcrypt").unwrap().to_string(), "bcrypt:12");
+/// assert_eq!(TargetHash::parse("argon2id").unwrap().to_string(), "argon2id:19456,2,1");
+///
+/// // twice the memory halves the rate, lanes share it
+/// let argon = |spec| TargetHash::parse(spec).unwrap().guesses_per_second();
+///
+/// assert_eq!(argon("argon2id:65536,3,1"), 2.0e4);
+/// assert_eq!(argon("argon2id:131072,3,4"), 1.0e4);
+///
+/// let error = |spec| TargetHash::parse(spec).unwrap_err().to_string();
+///
+/// assert!(error("crc32").contains("md5, sha1, sha256, bcrypt, scrypt, argon2id"));
+/// assert!(error("md5:3").contains("takes no parameters"));
+/// assert!(error("scrypt:16384").contains("N,r,p"));
+/// assert!(error("bcrypt:0").contains("cost"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TargetHash {
+    name: &'static str,
+    rate: f64,
+    params: Vec<HashParam>,
+}
+
+impl TargetHash {
+    pub fn parse(spec: &str) -> Result<TargetHash, PassGenError> {
+        let (name, values) = match spec.split_once(':') {
+            Some((name, values)) => (name, Some(values)),
+            None => (spec, None),
+        };
+
+        let (name, rate, params) = match HASH_RATES.iter().find(|(alg, _, _)| alg.eq_ignore_ascii_case(name)) {
+            Some(&row) => row,
+            None => {
+                let names: Vec<&str> = HASH_RATES.iter().map(|(alg, _, _)| *alg).collect();
+                let msg = format!("unknown hash {:?}, expected one of {}", name, names.join(", "));
+                return Err(PassGenError::Usage(msg));
+            },
+        };
+
+        let values: Vec<&str> = match values {
+            Some(_) if params.is_empty() => {
+                return Err(PassGenError::Usage(format!("{} takes no parameters", name)));
+            },
+            Some(values) => values.split(',').collect(),
+            None => params.iter().map(|_| "").collect(),
+        };
+
+        let names: Vec<&str> = params.iter().map(|(param, ..)| *param).collect();
+
+        if values.len() != params.len() {
+            let msg = format!("{} takes {} parameter(s) as {}:{}", name, params.len(), name, names.join(","));
+            return Err(PassGenError::Usage(msg));
+        }
+
+        let params = params
+            .iter()
+            .zip(values)
+            .map(|(&(param, cost, reference, default), value)| match value {
+                "" => Ok((param, cost, reference, default)),
+                value => match value.trim().parse::<u32>() {
+                    Ok(value) if value > 0 => Ok((param, cost, reference, value)),
+                    _ => {
+                        let msg = format!("invalid {} {:?} of {}, expected positive number", param, value, name);
+                        Err(PassGenError::Usage(msg))
+                    },
+                },
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(TargetHash { name, rate, params })
+    }
+
+    /// Guesses per second of the reference hardware, the rate scaled to the parameters.
+    pub fn guesses_per_second(&self) -> f64 {
+        self.params.iter().fold(self.rate, |rate, &(_, cost, reference, value)| match cost {
+            Cost::Linear => rate * reference as f64 / value as f64,
+            Cost::Doubling => rate * 2f64.powf(reference as f64 - value as f64),
+            Cost::Free => rate,
+        })
+    }
+}
+
+impl fmt::Display for TargetHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let values: Vec<String> = self.params.iter().map(|(.., value)| value.to_string()).collect();
+
+        match values.is_empty() {
+            true => write!(f, "{}", self.name),
+            false => write!(f, "{}:{}", self.name, values.join(",")),
+        }
+    }
+}
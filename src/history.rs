@@ -0,0 +1,361 @@
+use crate::{config_dir, derive::hmac_sha256, PassGenError};
+use rand::{rngs::OsRng, Rng};
+use std::{
+    collections::HashSet,
+    fmt,
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, ErrorKind, Seek, SeekFrom, Write},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// First line of every history file.
+pub(crate) const HISTORY_HEADER: &str = "# pass-gen history v2";
+
+/// Name of the history key in the config directory.
+pub const HISTORY_KEY_FILE: &str = "history-key";
+
+const ALGORITHM: &str = "hmac-sha256";
+const V1_HEADER: &str = "# pass-gen history v1";
+
+/* -------------------- *
+ *       HISTORY        *
+ * -------------------- */
+/// Keyed hashes of every password issued so far, never the passwords themselves.
+///
+/// The file starts with [`HISTORY_HEADER`], the hash algorithm and a fingerprint of the key,
+/// followed by one line per password with the unix time it was issued at and its HMAC. The key
+/// is kept apart from the file, so the file alone cannot be used to test guesses offline.
+pub(crate) struct History {
+    key: [u8; 32],
+    // issue times and hashes in file order
+    entries: Vec<(u64, [u8; 32])>,
+    hashes: HashSet<[u8; 32]>,
+}
+
+// the key stays out of debug output
+impl fmt::Debug for History {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("History").field("entries", &self.entries.len()).finish_non_exhaustive()
+    }
+}
+
+impl History {
+    pub fn new(key: [u8; 32]) -> Self {
+        History { key, entries: Vec::new(), hashes: HashSet::new() }
+    }
+
+    /// Reads a history written with `key`, a file of another key is an error.
+    pub fn read_from(reader: impl BufRead, key: [u8; 32]) -> Result<Self, PassGenError> {
+        let mut lines = reader.lines().enumerate();
+        let mut next = || match lines.next() {
+            Some((idx, Ok(line))) => Ok(Some((idx + 1, line))),
+            Some((_, Err(source))) => Err(PassGenError::Io { action: "reading history file", path: None, source }),
+            None => Ok(None),
+        };
+
+        match next()? {
+            Some((_, line)) if line == HISTORY_HEADER => {},
+            Some((_, line)) if line == V1_HEADER => {
+                return Err(invalid(1, "a v1 history holds unkeyed hashes, move it away to start a keyed one"));
+            },
+            _ => return Err(invalid(1, "missing header, this is not a pass-gen history file")),
+        }
+
+        match next()? {
+            Some((_, line)) if line == format!("algorithm {}", ALGORITHM) => {},
+            _ => return Err(invalid(2, &format!("unsupported hash algorithm, expected {}", ALGORITHM))),
+        }
+
+        let mut history = History::new(key);
+
+        match next()? {
+            Some((_, line)) if line.strip_prefix("key ") == Some(&history.fingerprint()) => {},
+            Some((_, line)) if line.starts_with("key ") => {
+                return Err(invalid(3, "written with another history key"));
+            },
+            _ => return Err(invalid(3, "expected the key fingerprint")),
+        }
+
+        while let Some((line_no, line)) = next()? {
+            let entry = line
+                .split_once(' ')
+                .and_then(|(time, hash)| Some((time.parse().ok()?, parse_hex::<32>(hash)?)));
+
+            match entry {
+                Some((time, hash)) => history.push(time, hash),
+                None => return Err(invalid(line_no, "expected a unix time and a hash")),
+            };
+        }
+
+        Ok(history)
+    }
+
+    /// Writes the whole file, header included.
+    pub fn write_to(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "{}", HISTORY_HEADER)?;
+        writeln!(out, "algorithm {}", ALGORITHM)?;
+        writeln!(out, "key {}", self.fingerprint())?;
+
+        for &(time, hash) in &self.entries {
+            Self::write_entry(out, time, &hash)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a single entry line, to append newly issued passwords to an existing file.
+    pub fn write_entry(out: &mut impl Write, time: u64, hash: &[u8; 32]) -> io::Result<()> {
+        writeln!(out, "{} {}", time, to_hex(hash))
+    }
+
+    /// HMAC of `password` under the history key.
+    pub fn hash(&self, password: &str) -> [u8; 32] {
+        hmac_sha256(&self.key, &[password.as_bytes()])
+    }
+
+    // tells which key a file was written with, without revealing it
+    fn fingerprint(&self) -> String {
+        to_hex(&hmac_sha256(&self.key, &[b"pass-gen history key"])[..8])
+    }
+
+    pub fn contains(&self, password: &str) -> bool {
+        self.contains_hash(&self.hash(password))
+    }
+
+    pub fn contains_hash(&self, hash: &[u8; 32]) -> bool {
+        self.hashes.contains(hash)
+    }
+
+    fn push(&mut self, time: u64, hash: [u8; 32]) -> bool {
+        let new = self.hashes.insert(hash);
+
+        if new {
+            self.entries.push((time, hash));
+        }
+
+        new
+    }
+
+    /// Forgets every password issued before unix time `before`, returns how many.
+    pub fn prune(&mut self, before: u64) -> usize {
+        let len = self.entries.len();
+
+        self.entries.retain(|(time, _)| *time >= before);
+        self.hashes = self.entries.iter().map(|&(_, hash)| hash).collect();
+
+        len - self.entries.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+fn invalid(line: usize, msg: &str) -> PassGenError {
+    PassGenError::InvalidData(format!("invalid history file, line {}: {}", line, msg))
+}
+
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub(crate) fn parse_hex<const N: usize>(str: &str) -> Option<[u8; N]> {
+    let mut bytes = [0; N];
+
+    if str.len() != N * 2 {
+        return None;
+    }
+
+    for (byte, pair) in bytes.iter_mut().zip(str.as_bytes().chunks_exact(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+
+    Some(bytes)
+}
+
+
+/* -------------------- *
+ *     HISTORY FILE     *
+ * -------------------- */
+/// A history of issued passwords on disk, locked until the process exits so concurrent runs take turns.
+#[derive(Debug)]
+pub struct HistoryFile {
+    pub(crate) path: String,
+    pub(crate) file: File,
+    pub(crate) history: History,
+}
+
+impl HistoryFile {
+    /// Opens or creates the history at `path` and locks it, blocking while another run holds it.
+    ///
+    /// The key is read from `history-key` in the config directory and created on first use.
+    pub fn open(path: String) -> Result<Self, PassGenError> {
+        let key = load_key()?;
+        let io_error = |action, source| PassGenError::Io { action, path: Some(path.clone()), source };
+
+        let mut options = OpenOptions::new();
+        options.read(true).write(true).create(true).truncate(false);
+
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let mut file = options.open(&path).map_err(|e| io_error("opening history file", e))?;
+
+        // blocks while another invocation holds the lock
+        file.lock().map_err(|e| io_error("locking history file", e))?;
+
+        let empty = file.metadata().map_err(|e| io_error("reading history file", e))?.len() == 0;
+
+        let history = if empty {
+            let history = History::new(key);
+
+            history
+                .write_to(&mut file)
+                .and_then(|_| file.sync_data())
+                .map_err(|e| io_error("creating history file", e))?;
+
+            history
+        } else {
+            match History::read_from(BufReader::new(&file), key) {
+                Err(PassGenError::Io { action, source, .. }) => return Err(io_error(action, source)),
+                Err(PassGenError::InvalidData(msg)) => {
+                    return Err(PassGenError::InvalidData(format!("{}: {}", path, msg)));
+                },
+                loaded => loaded?,
+            }
+        };
+
+        Ok(HistoryFile { path, file, history })
+    }
+
+    /// Appends the hashes of newly issued passwords and syncs the file.
+    pub fn append(&self, hashes: &[[u8; 32]]) -> io::Result<()> {
+        let time = unix_time();
+        let mut out = BufWriter::new(&self.file);
+
+        out.seek(SeekFrom::End(0))?;
+
+        for hash in hashes {
+            History::write_entry(&mut out, time, hash)?;
+        }
+
+        out.flush()?;
+        self.file.sync_data()
+    }
+
+    /// Drops entries older than `days` and rewrites the file in place, the lock is held throughout.
+    pub fn prune(&mut self, days: u32) -> Result<usize, PassGenError> {
+        let removed = self.history.prune(unix_time().saturating_sub(days as u64 * 86400));
+
+        let mut out = BufWriter::new(&self.file);
+        let path = Some(self.path.clone());
+        let io_error = |source| PassGenError::Io { action: "pruning history file", path, source };
+
+        self.file
+            .set_len(0)
+            .and_then(|_| out.seek(SeekFrom::Start(0)))
+            .and_then(|_| self.history.write_to(&mut out))
+            .and_then(|_| out.flush())
+            .and_then(|_| self.file.sync_data())
+            .map_err(io_error)?;
+
+        Ok(removed)
+    }
+
+    /// Passwords in the history.
+    pub fn len(&self) -> usize {
+        self.history.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.history.is_empty()
+    }
+}
+
+// one key per user, created on first use and linked into place so concurrent runs agree on it
+fn load_key() -> Result<[u8; 32], PassGenError> {
+    let dir = config_dir().ok_or_else(|| {
+        PassGenError::Usage(String::from("cannot find a place for the history key, set HOME or XDG_CONFIG_HOME"))
+    })?;
+
+    let path = dir.join(HISTORY_KEY_FILE);
+    let io_error = |action, source| PassGenError::Io { action, path: Some(path.display().to_string()), source };
+    let read = || match fs::read_to_string(&path) {
+        Ok(text) => parse_hex::<32>(text.trim())
+            .map(Some)
+            .ok_or_else(|| PassGenError::InvalidData(format!("{}: expected 64 hex digits", path.display()))),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(io_error("reading history key", e)),
+    };
+
+    if let Some(key) = read()? {
+        return Ok(key);
+    }
+
+    let key: [u8; 32] = OsRng.gen();
+    let temp = dir.join(format!("{}.{}", HISTORY_KEY_FILE, std::process::id()));
+
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let written = fs::create_dir_all(&dir)
+        .and_then(|_| options.open(&temp))
+        .and_then(|mut file| writeln!(file, "{}", to_hex(&key)).and_then(|_| file.sync_data()))
+        .and_then(|_| fs::hard_link(&temp, &path));
+
+    let _ = fs::remove_file(&temp);
+
+    match written {
+        Ok(()) => {
+            eprintln!("pass-gen: created history key {:?}, the history cannot be checked without it", path);
+            Ok(key)
+        },
+        // another run was first
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => read()?.ok_or_else(|| io_error("reading history key", e)),
+        Err(e) => Err(io_error("creating history key", e)),
+    }
+}
+
+/// Seconds since the unix epoch, 0 if the clock is set before it.
+pub(crate) fn unix_time() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hashes_are_keyed() {
+        let mut history = History::new([7; 32]);
+
+        assert!(history.push(100, history.hash("correct horse")));
+        assert!(!history.push(200, history.hash("correct horse")));
+        history.push(300, history.hash("battery staple"));
+
+        let mut file = Vec::new();
+        history.write_to(&mut file).unwrap();
+
+        // neither the plaintext nor the key end up in the file
+        let text = String::from_utf8(file.clone()).unwrap();
+        assert!(text.starts_with("# pass-gen history v2\nalgorithm hmac-sha256\nkey ") && !text.contains("horse"));
+        assert!(!text.contains(&"07".repeat(32)));
+
+        let mut history = History::read_from(file.as_slice(), [7; 32]).unwrap();
+
+        assert!(history.contains("correct horse") && !history.contains("Correct horse"));
+        assert_eq!(history.prune(200), 1);
+        assert!(!history.contains("correct horse") && history.contains("battery staple"));
+
+        // another key gives other hashes and cannot read the file
+        assert_ne!(History::new([8; 32]).hash("battery staple"), history.hash("battery staple"));
+        assert!(History::read_from(file.as_slice(), [8; 32]).is_err());
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{history::to_hex, ApiToken, LicenseKey, PassGenError};
+use rand::Rng;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Alphabet of ULIDs, Crockford's base32 without I, L, O and U.
+pub(crate) const CROCKFORD_BASE32: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Milliseconds since the unix epoch a ULID timestamp can hold.
+pub(crate) const ULID_MAX_TIME: u64 = (1 << 48) - 1;
+
+/* -------------------- *
+ *      IDENTIFIER      *
+ * -------------------- */
+/// An identifier generated in place of a password.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Identifier {
+    /// A random version 4 UUID.
+    Uuid,
+    /// A ULID, a millisecond timestamp followed by random bits.
+    Ulid,
+    /// An API token with a prefix and checksum.
+    ApiToken(ApiToken),
+    /// A license key in groups, ending in a check character.
+    LicenseKey(LicenseKey),
+}
+
+impl Identifier {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Identifier::Uuid => "uuid",
+            Identifier::Ulid => "ulid",
+            Identifier::ApiToken(_) => "api-token",
+            Identifier::LicenseKey(_) => "license-key",
+        }
+    }
+
+    /// Random bits of one identifier, the version, variant, timestamp and checksum are not secret.
+    pub fn bits(&self) -> f64 {
+        match self {
+            Identifier::Uuid => 122.0,
+            Identifier::Ulid => 80.0,
+            Identifier::ApiToken(token) => token.bits(),
+            Identifier::LicenseKey(key) => key.bits(),
+        }
+    }
+
+    /// Characters of one identifier.
+    pub fn length(&self) -> usize {
+        match self {
+            Identifier::Uuid => 36,
+            Identifier::Ulid => 26,
+            Identifier::ApiToken(token) => token.length(),
+            Identifier::LicenseKey(key) => key.length(),
+        }
+    }
+
+    /// Draws one identifier, ULIDs from the current time through `ulids` so a batch stays in
+    /// order within the millisecond.
+    ///
+    /// ```
+    /// use pass_gen::{Identifier, Ulids};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(1);
+    /// let mut ulids = Ulids::default();
+    ///
+    /// let first = Identifier::Ulid.generate(&mut ulids, &mut rng).unwrap();
+    /// let second = Identifier::Ulid.generate(&mut ulids, &mut rng).unwrap();
+    ///
+    /// assert!(first < second);
+    /// assert_eq!(Identifier::Uuid.generate(&mut ulids, &mut rng).unwrap().len(), Identifier::Uuid.length());
+    /// ```
+    pub fn generate(&self, ulids: &mut Ulids, rng: &mut impl Rng) -> Result<String, PassGenError> {
+        match self {
+            Identifier::Uuid => Ok(uuid_v4(rng)),
+            Identifier::ApiToken(token) => Ok(token.generate(rng)),
+            Identifier::LicenseKey(key) => Ok(key.generate(rng)),
+            Identifier::Ulid => {
+                let time = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_millis());
+                ulids.next(time as u64, rng)
+            },
+        }
+    }
+}
+
+/// A random version 4 UUID, hyphenated and in lower case.
+pub(crate) fn uuid_v4(rng: &mut impl Rng) -> String {
+    let mut bytes: [u8; 16] = rng.gen();
+
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let hex = to_hex(&bytes);
+
+    format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+}
+
+/// The 26 character ULID of a millisecond timestamp and 80 random bits.
+pub(crate) fn encode_ulid(time: u64, random: u128) -> String {
+    let value = ((time as u128) << 80) | (random & ((1 << 80) - 1));
+
+    (0..26).rev().map(|idx| CROCKFORD_BASE32[(value >> (idx * 5)) as usize & 31] as char).collect()
+}
+
+/// Generates ULIDs that sort in the order they were generated.
+///
+/// Within the same millisecond, or when the clock goes back, the random bits of the previous
+/// ULID are incremented instead of drawn again, as the specification asks.
+///
+/// ```
+/// use pass_gen::Ulids;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let mut ulids = Ulids::default();
+///
+/// let first = ulids.next(1469918176385, &mut rng).unwrap();
+/// let second = ulids.next(1469918176385, &mut rng).unwrap();
+/// let earlier = ulids.next(1469918176000, &mut rng).unwrap();
+/// let later = ulids.next(1469918176386, &mut rng).unwrap();
+///
+/// assert!(first < second && second < earlier && earlier < later);
+/// assert_eq!(first[..10], second[..10]);
+/// assert!(later.starts_with("01ARYZ6S42"));
+///
+/// assert!(ulids.next(1 << 48, &mut rng).is_err());
+/// ```
+#[derive(Debug, Default)]
+pub struct Ulids {
+    last: Option<(u64, u128)>,
+}
+
+impl Ulids {
+    pub fn next(&mut self, time: u64, rng: &mut impl Rng) -> Result<String, PassGenError> {
+        if time > ULID_MAX_TIME {
+            return Err(PassGenError::Unsatisfiable(format!("time {} ms is beyond the range of a ulid", time)));
+        }
+
+        let (time, random) = match self.last {
+            Some((last, random)) if time <= last => match random.checked_add(1).filter(|random| *random < 1 << 80) {
+                Some(random) => (last, random),
+                None => {
+                    let msg = String::from("the random bits of a ulid overflowed within one millisecond");
+                    return Err(PassGenError::Unsatisfiable(msg));
+                },
+            },
+            _ => (time, rng.gen::<u128>() >> 48),
+        };
+
+        self.last = Some((time, random));
+
+        Ok(encode_ulid(time, random))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn uuids_are_version_4() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        for _ in 0..1000 {
+            let uuid = uuid_v4(&mut rng);
+            let groups: Vec<&str> = uuid.split('-').collect();
+
+            assert_eq!(groups.iter().map(|group| group.len()).collect::<Vec<_>>(), [8, 4, 4, 4, 12]);
+            assert!(uuid.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f' | '-')));
+
+            // the version and the variant of RFC 4122
+            assert!(groups[2].starts_with('4'));
+            assert!(groups[3].starts_with(['8', '9', 'a', 'b']));
+        }
+    }
+
+    #[test]
+    fn ulid_encoding() {
+        // the timestamp of the example of the specification
+        assert!(encode_ulid(1469918176385, 0).starts_with("01ARYZ6S41"));
+
+        assert_eq!(encode_ulid(0, 1), "00000000000000000000000001");
+        assert_eq!(encode_ulid((1 << 48) - 1, (1 << 80) - 1), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+    }
+}
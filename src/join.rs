@@ -0,0 +1,233 @@
+use crate::{grapheme_len, PassGenError};
+use std::fmt;
+
+/* -------------------- *
+ *         JOIN         *
+ * -------------------- */
+#[derive(Debug)]
+enum Piece {
+    Literal(String),
+    // the 0-based token and the column of its placeholder
+    Token(usize, usize),
+    Rest,
+}
+
+/// Places the tokens of a password into a layout.
+///
+/// `{n}` is token `n`, counting from 1, `{*}` is every token without a placeholder of its own
+/// joined by the separator, and `{{` and `}}` are literal braces. A token can only be placed once.
+///
+/// ```
+/// use pass_gen::{preset, Generator, Join};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let join = Join::parse("{1}-{2}.{3}_{4}").unwrap();
+/// let words = preset("word").unwrap().token_data;
+/// let mut gen = Generator::new(&words, 4, " ", StdRng::seed_from_u64(1)).join(&join);
+///
+/// // the tokens are those of the plain password, in their places
+/// let plain = Generator::new(&words, 4, " ", StdRng::seed_from_u64(1)).generate();
+/// let tokens: Vec<&str> = plain.split(' ').collect();
+///
+/// assert_eq!(gen.generate(), format!("{}-{}.{}_{}", tokens[0], tokens[1], tokens[2], tokens[3]));
+/// assert_eq!(join.highest(), 4);
+///
+/// // the rest keeps the order it was drawn in
+/// let rest = Join::parse("{{{3}}}:{*}").unwrap();
+/// let mut gen = Generator::new(&words, 5, " ", StdRng::seed_from_u64(1)).join(&rest);
+/// let plain = Generator::new(&words, 5, " ", StdRng::seed_from_u64(1)).generate();
+/// let tokens: Vec<&str> = plain.split(' ').collect();
+///
+/// assert_eq!(gen.generate(), format!("{{{}}}:{} {} {} {}", tokens[2], tokens[0], tokens[1], tokens[3], tokens[4]));
+/// assert_eq!(rest.separators(5), 3);
+/// assert_eq!(rest.literal_len(), 3);
+///
+/// // every placeholder has to have a token, and every token a place
+/// assert!(rest.check_count(2).unwrap_err().to_string().contains("{3} at column 3"));
+/// assert!(join.check_count(5).unwrap_err().to_string().contains("token 5 has no placeholder"));
+/// assert!(rest.check_count(3).is_ok());
+///
+/// // repeated, unknown and unbalanced placeholders are caught where they are
+/// let error = |text| Join::parse(text).unwrap_err().to_string();
+///
+/// assert!(error("{1}-{1}").contains("column 5: {1} is already placed at column 1"));
+/// assert!(error("{*}{2}{*}").contains("column 7: {*} is already placed at column 1"));
+/// assert!(error("{0}").contains("column 1"));
+/// assert!(error("{x}").contains("column 1"));
+/// assert!(error("a{1").contains("column 2: unclosed placeholder"));
+/// assert!(error("a}{1}").contains("column 2"));
+/// assert!(error("{{1}}").contains("no placeholder"));
+/// assert!(error("").contains("empty"));
+/// ```
+#[derive(Debug)]
+pub struct Join {
+    source: String,
+    pieces: Vec<Piece>,
+}
+
+impl Join {
+    pub fn parse(text: &str) -> Result<Join, PassGenError> {
+        let invalid = |column: usize, msg: &str| {
+            PassGenError::Usage(format!("invalid join template {:?} at column {}: {}", text, column, msg))
+        };
+
+        if text.is_empty() {
+            return Err(PassGenError::Usage(String::from("join template is empty")));
+        }
+
+        let mut pieces = Vec::new();
+        let mut literal = String::new();
+        let mut placed: Vec<(Option<usize>, usize)> = Vec::new();
+        let mut chars = text.chars().enumerate().peekable();
+
+        while let Some((idx, c)) = chars.next() {
+            let column = idx + 1;
+
+            match c {
+                '{' if chars.next_if(|&(_, c)| c == '{').is_some() => literal.push('{'),
+                '}' if chars.next_if(|&(_, c)| c == '}').is_some() => literal.push('}'),
+                '}' => return Err(invalid(column, "unmatched }, write }} for a literal brace")),
+                '{' => {
+                    let mut name = String::new();
+
+                    loop {
+                        match chars.next() {
+                            Some((_, '}')) => break,
+                            Some((_, c)) => name.push(c),
+                            None => return Err(invalid(column, "unclosed placeholder, write {{ for a literal brace")),
+                        }
+                    }
+
+                    let token = match name.as_str() {
+                        "*" => None,
+                        _ => match name.parse::<usize>() {
+                            Ok(n) if n > 0 && name.bytes().all(|b| b.is_ascii_digit()) => Some(n - 1),
+                            _ => {
+                                let msg = format!("invalid placeholder {{{}}}, expected {{n}} from 1 or {{*}}", name);
+                                return Err(invalid(column, &msg));
+                            },
+                        },
+                    };
+
+                    if let Some((_, first)) = placed.iter().find(|(placed, _)| *placed == token) {
+                        let msg = format!("{{{}}} is already placed at column {}", name, first);
+                        return Err(invalid(column, &msg));
+                    }
+
+                    placed.push((token, column));
+
+                    if !literal.is_empty() {
+                        pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                    }
+
+                    pieces.push(match token {
+                        Some(token) => Piece::Token(token, column),
+                        None => Piece::Rest,
+                    });
+                },
+                c => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(literal));
+        }
+
+        if placed.is_empty() {
+            return Err(PassGenError::Usage(format!("join template {:?} has no placeholder", text)));
+        }
+
+        Ok(Join { source: text.to_string(), pieces })
+    }
+
+    /// The highest token placed on its own, counting from 1, or 0 if there is none.
+    pub fn highest(&self) -> usize {
+        self.tokens().map(|(token, _)| token + 1).max().unwrap_or(0)
+    }
+
+    /// Whether the layout has a place for the tokens without a placeholder.
+    pub fn has_rest(&self) -> bool {
+        self.pieces.iter().any(|piece| matches!(piece, Piece::Rest))
+    }
+
+    /// Checks that `count` tokens fill every placeholder and have a place each.
+    pub fn check_count(&self, count: u32) -> Result<(), PassGenError> {
+        let count = count as usize;
+
+        if let Some((token, column)) = self.tokens().find(|&(token, _)| token >= count) {
+            return Err(PassGenError::Usage(format!(
+                "placeholder {{{}}} at column {} of join template {:?} is beyond the {} token(s) drawn",
+                token + 1,
+                column,
+                self.source,
+                count
+            )));
+        }
+
+        if !self.has_rest() && self.highest() < count {
+            return Err(PassGenError::Usage(format!(
+                "token {} has no placeholder in join template {:?}, add {{*}} to place the rest",
+                self.highest() + 1,
+                self.source
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Separators between the tokens of the rest, out of `count` tokens.
+    pub fn separators(&self, count: usize) -> usize {
+        match self.has_rest() {
+            true => count.saturating_sub(self.tokens().count()).saturating_sub(1),
+            false => 0,
+        }
+    }
+
+    /// Characters of the layout itself, without the tokens.
+    pub fn literal_len(&self) -> usize {
+        self.literals().map(grapheme_len).sum()
+    }
+
+    /// Appends the layout with `tokens` in their places, `push_separator` appends
+    /// the separator between two tokens of the rest.
+    pub fn push(&self, tokens: &[&str], password: &mut String, mut push_separator: impl FnMut(&mut String)) {
+        for piece in &self.pieces {
+            match piece {
+                Piece::Literal(text) => password.push_str(text),
+                Piece::Token(token, _) => password.push_str(tokens[*token]),
+                Piece::Rest => {
+                    let mut rest = (0..tokens.len()).filter(|idx| self.tokens().all(|(token, _)| token != *idx));
+
+                    if let Some(first) = rest.next() {
+                        password.push_str(tokens[first]);
+                    }
+
+                    for idx in rest {
+                        push_separator(password);
+                        password.push_str(tokens[idx]);
+                    }
+                },
+            }
+        }
+    }
+
+    fn tokens(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.pieces.iter().filter_map(|piece| match piece {
+            Piece::Token(token, column) => Some((*token, *column)),
+            _ => None,
+        })
+    }
+
+    fn literals(&self) -> impl Iterator<Item = &str> {
+        self.pieces.iter().filter_map(|piece| match piece {
+            Piece::Literal(text) => Some(text.as_str()),
+            _ => None,
+        })
+    }
+}
+
+impl fmt::Display for Join {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
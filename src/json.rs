@@ -0,0 +1,48 @@
+/* -------------------- *
+ *         JSON         *
+ * -------------------- */
+/// `x` as a JSON number, `null` for infinities and NaN which JSON has no literal for.
+///
+/// ```
+/// use pass_gen::json_number;
+///
+/// assert_eq!(json_number(42.0), "42");
+/// assert_eq!(json_number(0.5), "0.5");
+/// assert_eq!(json_number(1e21), "1e21");
+/// assert_eq!(json_number(f64::INFINITY), "null");
+/// ```
+pub fn json_number(x: f64) -> String {
+    match () {
+        _ if !x.is_finite() => String::from("null"),
+        _ if x == 0.0 || (1e-6..1e15).contains(&x.abs()) => x.to_string(),
+        _ => format!("{:e}", x),
+    }
+}
+
+/// `str` as a JSON string, in quotes and with control characters escaped.
+///
+/// ```
+/// use pass_gen::json_quote;
+///
+/// assert_eq!(json_quote("say \"hi\"\n"), "\"say \\\"hi\\\"\\n\"");
+/// assert_eq!(json_quote("\u{7}"), "\"\\u0007\"");
+/// ```
+pub fn json_quote(str: &str) -> String {
+    let mut quoted = String::with_capacity(str.len() * 6 + 2);
+    quoted.push('"');
+
+    for c in str.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
@@ -0,0 +1,97 @@
+use crate::grapheme_len;
+use std::io::{self, Write};
+
+/// Spaces between two columns.
+pub(crate) const COLUMN_GAP: usize = 2;
+
+/* -------------------- *
+ *        LAYOUT        *
+ * -------------------- */
+/// Lays out items of the given display `widths` in columns, like `ls`, filled top to bottom.
+///
+/// Returns the number of rows and the width of each column, using as few rows as fit into
+/// `term_width` with [`COLUMN_GAP`] spaces between columns. Item `idx` goes to column
+/// `idx / rows` and row `idx % rows`. Items wider than the terminal get a row each.
+pub(crate) fn columns(widths: &[usize], term_width: usize) -> (usize, Vec<usize>) {
+    let count = widths.len();
+
+    if count == 0 {
+        return (0, Vec::new());
+    }
+
+    // no layout has more columns than the narrowest items allow
+    let narrowest = widths.iter().copied().min().unwrap_or(0);
+    let max_cols = ((term_width + COLUMN_GAP) / (narrowest + COLUMN_GAP)).clamp(1, count);
+
+    for rows in count.div_ceil(max_cols)..count {
+        let widths: Vec<usize> = widths.chunks(rows).map(|column| column.iter().copied().max().unwrap_or(0)).collect();
+
+        if widths.iter().sum::<usize>() + COLUMN_GAP * (widths.len() - 1) <= term_width {
+            return (rows, widths);
+        }
+    }
+
+    (count, vec![widths.iter().copied().max().unwrap_or(0)])
+}
+
+/// Writes `passwords` in the [`columns`] that fit into `term_width`, without a newline after
+/// the last row.
+pub(crate) fn write_columns(out: &mut impl Write, passwords: &[String], term_width: usize) -> io::Result<()> {
+    let widths: Vec<usize> = passwords.iter().map(|password| grapheme_len(password)).collect();
+    let (rows, layout) = columns(&widths, term_width);
+
+    for row in 0..rows {
+        if row != 0 {
+            out.write_all(b"\n")?;
+        }
+
+        for col in 0..layout.len() {
+            let idx = col * rows + row;
+
+            if idx >= passwords.len() {
+                break;
+            }
+
+            // pad the previous password of the row to its column width
+            if col != 0 {
+                let prev = idx - rows;
+                write!(out, "{:1$}", "", layout[col - 1] - widths[prev] + COLUMN_GAP)?;
+            }
+
+            out.write_all(passwords[idx].as_bytes())?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns_fill_top_to_bottom() {
+        // everything fits on one row
+        assert_eq!(columns(&[4, 4, 4], 80), (1, vec![4, 4, 4]));
+
+        // 4 + 2 + 6 + 2 + 5 = 19 columns do not fit into 18
+        assert_eq!(columns(&[4, 6, 5], 18), (2, vec![6, 5]));
+
+        // each column is as wide as its own longest item
+        assert_eq!(columns(&[3, 9, 2, 2, 4], 19), (2, vec![9, 2, 4]));
+        assert_eq!(columns(&[3, 9, 2, 2, 4], 16), (3, vec![9, 4]));
+
+        // too wide for any columns
+        assert_eq!(columns(&[30, 10], 20), (2, vec![30]));
+        assert_eq!(columns(&[], 80), (0, vec![]));
+    }
+
+    #[test]
+    fn columns_are_padded() {
+        let passwords: Vec<String> = ["alpha", "be", "gamma", "delta"].map(String::from).to_vec();
+        let mut out = Vec::new();
+
+        write_columns(&mut out, &passwords, 14).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "alpha  gamma\nbe     delta");
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{class_weights, draws_require_probability, ClassSet, PassGenError, TokenSource};
+use rand::Rng;
+
+/// Characters leet speak replaces, with what they can turn into.
+pub(crate) const LEET_TABLE: &[(char, &[char])] = &[
+    ('a', &['4', '@']),
+    ('e', &['3']),
+    ('i', &['1', '!']),
+    ('o', &['0']),
+    ('s', &['5', '$']),
+    ('t', &['7']),
+];
+
+/* -------------------- *
+ *         LEET         *
+ * -------------------- */
+/// Substitutes characters of tokens with look-alikes, each one independently.
+///
+/// The substitutions are well known to attackers, so they only add the entropy of the
+/// choices made, see [`Leet::bits`].
+///
+/// ```
+/// use pass_gen::{preset, ClassSet, Generator, Leet};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let words = preset("word").unwrap().token_data;
+/// let leet = Leet::new(0.5).unwrap();
+/// let mut gen = Generator::new(&words, 4, " ", StdRng::seed_from_u64(1)).leet(&leet);
+///
+/// assert_eq!(gen.generate(), "wa5h3s l171ga7i0n d!5rupt5 p3rf0rmanc3s");
+/// assert_eq!(gen.generate(), "t@nn!ng pew73r 3vinc3 h1lariou$");
+///
+/// // words alone never contain digits, substituted ones usually do
+/// let digit = ClassSet::parse("digit").unwrap();
+///
+/// assert!(leet.require_probability(digit, &words, 4, &[" "]) > 0.9);
+/// assert!(Leet::new(0.0).is_err());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Leet {
+    probability: f64,
+}
+
+impl Leet {
+    pub const DEFAULT_PROBABILITY: f64 = 0.3;
+
+    /// `probability` is the chance of substituting each eligible character.
+    pub fn new(probability: f64) -> Result<Leet, PassGenError> {
+        if !(probability > 0.0 && probability <= 1.0) {
+            let msg = format!("leet probability must be above 0 and at most 1, got {}", probability);
+            return Err(PassGenError::Usage(msg));
+        }
+
+        Ok(Leet { probability })
+    }
+
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+
+    /// Appends `token` to `password`, substituting characters at random.
+    pub fn push_token<R: Rng>(&self, token: &str, rng: &mut R, password: &mut String) {
+        for c in token.chars() {
+            match substitutes(c) {
+                Some(subs) if rng.gen_bool(self.probability) => password.push(subs[rng.gen_range(0..subs.len())]),
+                _ => password.push(c),
+            }
+        }
+    }
+
+    /// Average entropy in bits the substitutions add to a token of `source`.
+    pub fn bits(&self, source: &dyn TokenSource) -> f64 {
+        let p = self.probability;
+
+        // keeping the character, or one of its substitutes
+        let choice = |subs: usize| {
+            let keep = if p < 1.0 { -(1.0 - p) * (1.0 - p).log2() } else { 0.0 };
+            keep - p * (p / subs as f64).log2()
+        };
+
+        let total: f64 = source
+            .range()
+            .map(|idx| source.get(idx).chars().filter_map(substitutes).map(|subs| choice(subs.len())).sum::<f64>())
+            .sum();
+
+        total / source.len().max(1) as f64
+    }
+
+    /// Like [`require_probability`](crate::require_probability), with the tokens substituted.
+    pub fn require_probability(&self, required: ClassSet, source: &dyn TokenSource, count: u32, seps: &[&str]) -> f64 {
+        let tokens = self.source_weights(source);
+        let separators = class_weights(seps.iter().copied());
+
+        draws_require_probability(required, &[(tokens, count), (separators, count.saturating_sub(1))])
+    }
+
+    /// The class weights of the tokens, with the tokens substituted.
+    pub fn source_weights(&self, source: &dyn TokenSource) -> [f64; 16] {
+        let mut tokens = [0.0; 16];
+
+        for idx in source.range() {
+            for (mask, weight) in self.mask_weights(source.get(idx)).into_iter().enumerate() {
+                tokens[mask] += weight;
+            }
+        }
+
+        tokens
+    }
+
+    // probability of each set of classes a substituted token ends up with
+    fn mask_weights(&self, token: &str) -> [f64; 16] {
+        let mut weights = [0.0; 16];
+        weights[0] = 1.0;
+
+        for c in token.chars() {
+            let outcomes: Vec<(f64, usize)> = match substitutes(c) {
+                Some(subs) => {
+                    let share = self.probability / subs.len() as f64;
+                    let kept = (1.0 - self.probability, ClassSet::of(&c.to_string()).bits());
+
+                    subs.iter().map(|s| (share, ClassSet::of(&s.to_string()).bits())).chain([kept]).collect()
+                },
+                None => vec![(1.0, ClassSet::of(&c.to_string()).bits())],
+            };
+
+            let mut next = [0.0; 16];
+
+            for (mask, &weight) in weights.iter().enumerate().filter(|(_, &weight)| weight > 0.0) {
+                for &(probability, class) in &outcomes {
+                    next[mask | class] += weight * probability;
+                }
+            }
+
+            weights = next;
+        }
+
+        weights
+    }
+}
+
+fn substitutes(c: char) -> Option<&'static [char]> {
+    LEET_TABLE.iter().find(|(from, _)| *from == c).map(|(_, subs)| *subs)
+}
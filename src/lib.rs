@@ -0,0 +1,118 @@
+mod acrostic;
+#[cfg(feature = "age")]
+mod age;
+mod api_token;
+mod audit;
+mod batch;
+mod breach;
+mod case;
+mod charset;
+mod checksum;
+mod class;
+mod composition;
+mod config;
+mod config_file;
+mod data;
+mod derive;
+mod dictionary;
+mod entropy;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod generator;
+mod grapheme;
+mod hash_rate;
+mod history;
+mod identifier;
+mod join;
+mod json;
+mod layout;
+mod leet;
+mod license_key;
+mod locale;
+mod memorability;
+mod output;
+mod packing;
+mod policy;
+mod report;
+mod retry;
+mod secret;
+mod sentence;
+mod sharing;
+mod template;
+mod token;
+mod username;
+mod verbose;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod wordlist_hash;
+
+pub use acrostic::{Acrostic, Bucket};
+#[cfg(feature = "age")]
+pub use age::{age_encrypt, AgeIdentity, AgeRecipient};
+pub use api_token::{token_checksum, verify_api_token, ApiToken, TokenAlphabet, API_TOKEN_BITS, API_TOKEN_CHECKSUM_LEN};
+pub use audit::AuditLog;
+#[cfg(feature = "age")]
+pub use batch::write_age;
+pub use batch::{write_entry, write_passwords};
+pub use breach::{sha1, BreachFilter};
+pub use case::RandomCase;
+pub use checksum::verify_checksum;
+pub use class::{require_probability, CharClass, ClassSet};
+pub use composition::Composition;
+pub use config::{
+    Config, ConfigBuilder, CountSource, Derivation, Format, PoolFilters, PoolSource, Quote, CHARSET_BITS,
+    LICENSE_GROUPS, MAX_TOKENS,
+};
+pub use config_file::{config_dir, CONFIG_KEYS};
+pub use derive::{derive_seed, KdfParams};
+pub use entropy::{token_bits, STRENGTH_LABELS};
+pub use error::PassGenError;
+pub use generator::{entropy_bits, generate, Generator, Tokens};
+pub use grapheme::{grapheme_len, graphemes};
+pub use hash_rate::{Cost, HashParam, TargetHash, HASH_RATES};
+pub use history::HistoryFile;
+pub use identifier::{Identifier, Ulids};
+pub use join::Join;
+pub use json::{json_number, json_quote};
+pub use leet::Leet;
+pub use license_key::{verify_license_key, LicenseKey, LICENSE_KEY_ALPHABET};
+pub use locale::LOCALE_VARS;
+pub use memorability::{Memorability, MEMORABILITY_LABELS};
+pub use packing::BitPacking;
+pub use policy::{Policy, Rule};
+pub use report::{
+    write_comparison, write_estimate, EntropyReport, GuessTime, Pool, ReportFormat, Targets, Terminal, BAR_TARGET,
+    DEFAULT_RATES, TIME_UNITS,
+};
+pub use secret::{lock_buffers, wipe, wipe_string, Secrets, WipingWriter};
+pub use sentence::{PartOfSpeech, Sentence};
+pub use sharing::{combine_shares, SecretSharing, Share};
+pub use template::Template;
+pub use token::{confusable_sets, preset, Preset, TokenData, TokenSource, Whitespace, PRESETS};
+pub use username::{Username, UsernameStyle};
+pub use verbose::write_verbose;
+pub use wordlist_hash::{WordlistHash, WORDLIST_HASHES};
+
+pub(crate) use charset::parse_charset;
+pub(crate) use checksum::checksum_token;
+pub(crate) use class::{class_weights, draws_require_probability, source_weights};
+pub(crate) use config::TTY_PATH;
+pub(crate) use config_file::{layer, ConfigFile, Layer, PresetSection};
+pub(crate) use derive::sha256;
+pub(crate) use dictionary::Dictionary;
+pub(crate) use entropy::{
+    crack_time, entropy_bar, equivalent_length, guess_probability, strength_label, BarCharset, ALPHANUMERIC,
+    PRINTABLE_ASCII,
+};
+pub(crate) use generator::{
+    length_bits, length_require_probability, max_length_probability, slots_max_length_probability,
+};
+pub(crate) use hash_rate::HASH_HARDWARE;
+pub(crate) use identifier::CROCKFORD_BASE32;
+pub(crate) use layout::write_columns;
+pub(crate) use leet::LEET_TABLE;
+pub(crate) use locale::locale_preset;
+pub(crate) use output::{write_footer, write_header, write_password, write_stats};
+pub(crate) use report::format_unit;
+pub(crate) use retry::{Rejections, Retry};
@@ -0,0 +1,176 @@
+use crate::PassGenError;
+use rand::Rng;
+
+/// Alphabet of license keys, digits and upper case letters without 0, O, 1 and I.
+pub const LICENSE_KEY_ALPHABET: &[u8; 32] = b"23456789ABCDEFGHJKLMNPQRSTUVWXYZ";
+
+/* -------------------- *
+ *      CHECK CHAR      *
+ * -------------------- */
+/// The Luhn mod N check character of `payload`, with N the size of [`LICENSE_KEY_ALPHABET`],
+/// or `None` if a character is not in it.
+///
+/// Every single changed character and every swap of two neighbours is caught, except a swap of
+/// the first and the last character of the alphabet, `2Z` and `Z2`.
+pub(crate) fn luhn_check_char(payload: &str) -> Option<char> {
+    let n = LICENSE_KEY_ALPHABET.len();
+    let sum = luhn_sum(payload, 2)?;
+
+    Some(LICENSE_KEY_ALPHABET[(n - sum % n) % n] as char)
+}
+
+// the sum over code points from the right, every other one doubled and its digits in base N added
+fn luhn_sum(chars: &str, mut factor: usize) -> Option<usize> {
+    let n = LICENSE_KEY_ALPHABET.len();
+    let mut sum = 0;
+
+    for c in chars.bytes().rev() {
+        let addend = factor * LICENSE_KEY_ALPHABET.iter().position(|&valid| valid == c)?;
+
+        sum += addend / n + addend % n;
+        factor = 3 - factor;
+    }
+
+    Some(sum)
+}
+
+
+/* -------------------- *
+ *     LICENSE KEY      *
+ * -------------------- */
+/// The shape of a license key, `groups` groups of `group` characters joined by `-`.
+///
+/// The last character is a luhn mod 32 check character of the others, so a mistyped key is
+/// rejected offline before it is ever looked up.
+///
+/// ```
+/// use pass_gen::{verify_license_key, LicenseKey};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let shape = LicenseKey::new(4, 4).unwrap();
+/// let mut rng = StdRng::seed_from_u64(1);
+///
+/// // the check character adds no entropy
+/// assert_eq!(shape.bits(), 75.0);
+/// assert_eq!(shape.length(), 19);
+///
+/// for _ in 0..1000 {
+///     let key = shape.generate(&mut rng);
+///
+///     assert_eq!(key.split('-').map(str::len).collect::<Vec<_>>(), [4, 4, 4, 4]);
+///     assert_eq!(verify_license_key(&key).unwrap(), shape);
+/// }
+///
+/// assert!(LicenseKey::new(1, 1).unwrap_err().to_string().contains("at least 2 characters"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LicenseKey {
+    group: usize,
+    groups: usize,
+}
+
+impl LicenseKey {
+    pub fn new(group: usize, groups: usize) -> Result<LicenseKey, PassGenError> {
+        if group * groups < 2 {
+            let msg = String::from("a license key needs at least 2 characters, one of them is the check character");
+            return Err(PassGenError::Usage(msg));
+        }
+
+        Ok(LicenseKey { group, groups })
+    }
+
+    /// Characters per group.
+    pub fn group(&self) -> usize {
+        self.group
+    }
+
+    pub fn groups(&self) -> usize {
+        self.groups
+    }
+
+    /// Random bits of a key, all characters but the check character.
+    pub fn bits(&self) -> f64 {
+        (self.group * self.groups - 1) as f64 * (LICENSE_KEY_ALPHABET.len() as f64).log2()
+    }
+
+    /// Characters of a whole key, with the dashes.
+    pub fn length(&self) -> usize {
+        self.group * self.groups + self.groups - 1
+    }
+
+    pub fn generate(&self, rng: &mut impl Rng) -> String {
+        let mut chars: String = (1..self.group * self.groups)
+            .map(|_| LICENSE_KEY_ALPHABET[rng.gen_range(0..LICENSE_KEY_ALPHABET.len())] as char)
+            .collect();
+
+        chars.push(luhn_check_char(&chars).expect("drawn from the alphabet"));
+
+        let groups: Vec<&str> = (0..self.groups).map(|idx| &chars[idx * self.group..(idx + 1) * self.group]).collect();
+
+        groups.join("-")
+    }
+}
+
+/// Checks the structure and check character of a pasted `key`, ignoring case and surrounding
+/// whitespace, and returns its shape.
+///
+/// ```
+/// use pass_gen::verify_license_key;
+///
+/// assert_eq!(verify_license_key(" abcd-2345-6789-abcm \n").unwrap().groups(), 4);
+///
+/// let error = |key| verify_license_key(key).unwrap_err().to_string();
+///
+/// assert!(error("BACD-2345-6789-ABCM").contains("check character"));
+/// assert!(error("ABCD-2345-6789-ABC").contains("groups of 4"));
+/// assert!(error("ABCD--6789-ABCD").contains("empty"));
+/// assert!(error("ABCD-2345-6789-ABC0").contains("'0'"));
+/// ```
+pub fn verify_license_key(key: &str) -> Result<LicenseKey, PassGenError> {
+    let key = key.trim().to_ascii_uppercase();
+    let groups: Vec<&str> = key.split('-').collect();
+    let group = groups[0].len();
+
+    if groups.iter().any(|chars| chars.is_empty()) {
+        return Err(PassGenError::InvalidData(String::from("key has an empty group")));
+    }
+
+    if let Some(c) = key.chars().find(|&c| c != '-' && !(c.is_ascii() && LICENSE_KEY_ALPHABET.contains(&(c as u8)))) {
+        return Err(PassGenError::InvalidData(format!("key contains {:?}, which license keys never do", c)));
+    }
+
+    if let Some((idx, chars)) = groups.iter().enumerate().find(|(_, chars)| chars.len() != group) {
+        return Err(PassGenError::InvalidData(format!(
+            "group {} of the key has {} characters, expected groups of {}",
+            idx + 1,
+            chars.len(),
+            group
+        )));
+    }
+
+    let shape = LicenseKey::new(group, groups.len()).map_err(|err| PassGenError::InvalidData(err.to_string()))?;
+
+    match luhn_sum(&groups.concat(), 1).is_some_and(|sum| sum % LICENSE_KEY_ALPHABET.len() == 0) {
+        true => Ok(shape),
+        false => Err(PassGenError::InvalidData(String::from(
+            "check character does not match the key, a character is mistyped or two are swapped",
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn luhn_catches_swapped_neighbours() {
+        assert_eq!(luhn_check_char("ABCD"), Some('8'));
+        assert_eq!(luhn_check_char("23456789ABCDEFG"), Some('Z'));
+
+        // neighbours swapped
+        assert_ne!(luhn_check_char("BACD"), Some('8'));
+        assert_eq!(luhn_check_char("A2ZD"), luhn_check_char("AZ2D"));
+
+        assert_eq!(luhn_check_char("ABC0"), None);
+    }
+}
@@ -0,0 +1,76 @@
+/// Builtin word presets by language code, English first.
+pub(crate) const WORD_LANGUAGES: &[(&str, &str)] = &[("en", "word")];
+
+/// Environment variables naming the locale, in order of precedence.
+pub const LOCALE_VARS: &[&str] = &["LC_ALL", "LC_MESSAGES", "LANG"];
+
+/* -------------------- *
+ *        LOCALE        *
+ * -------------------- */
+/// Language subtag of a locale name like `de_DE.UTF-8@euro`, lowercased.
+///
+/// The `C` and `POSIX` locales name no language.
+pub(crate) fn locale_language(locale: &str) -> Option<String> {
+    let language = locale.split(['_', '-', '.', '@']).next().unwrap_or("");
+
+    match language {
+        "C" | "POSIX" => None,
+        _ if (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_alphabetic()) => {
+            Some(language.to_ascii_lowercase())
+        },
+        _ => None,
+    }
+}
+
+/// The builtin word preset for a language, `None` when no list for it was compiled in.
+pub(crate) fn word_preset(language: &str) -> Option<&'static str> {
+    WORD_LANGUAGES.iter().find(|(code, _)| *code == language).map(|&(_, name)| name)
+}
+
+/// The word preset of the first locale variable that is set, with the reason for `--verbose`.
+pub(crate) fn locale_preset() -> (&'static str, String) {
+    #[cfg(not(target_arch = "wasm32"))]
+    let locale = LOCALE_VARS
+        .iter()
+        .find_map(|&var| std::env::var(var).ok().filter(|value| !value.is_empty()).map(|value| (var, value)));
+
+    #[cfg(target_arch = "wasm32")]
+    let locale: Option<(&str, String)> = None;
+
+    let (var, value) = match locale {
+        Some(locale) => locale,
+        None => return ("word", String::from("not set, using the english word list")),
+    };
+
+    match locale_language(&value) {
+        Some(language) => match word_preset(&language) {
+            Some(name) => (name, format!("{}={} selects the {:?} word list", var, value, language)),
+            None => ("word", format!("{}={}, no {:?} word list built in, using english", var, value, language)),
+        },
+        None => ("word", format!("{}={} names no language, using the english word list", var, value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn language_of_a_locale() {
+        assert_eq!(locale_language("de_DE.UTF-8").as_deref(), Some("de"));
+        assert_eq!(locale_language("pt-BR").as_deref(), Some("pt"));
+        assert_eq!(locale_language("EN").as_deref(), Some("en"));
+        assert_eq!(locale_language("fr_FR@euro").as_deref(), Some("fr"));
+
+        assert_eq!(locale_language("C.UTF-8"), None);
+        assert_eq!(locale_language("POSIX"), None);
+        assert_eq!(locale_language(""), None);
+        assert_eq!(locale_language("1_2"), None);
+    }
+
+    #[test]
+    fn word_preset_of_a_language() {
+        assert_eq!(word_preset("en"), Some("word"));
+        assert_eq!(word_preset("tlh"), None);
+    }
+}
@@ -1,220 +1,2753 @@
-mod data;
-use rand::Rng;
+// the command line needs a process, WebAssembly only gets the library and its bindings
+#![cfg_attr(target_arch = "wasm32", no_main)]
+#![cfg(not(target_arch = "wasm32"))]
+
+#[cfg(windows)]
+mod windows;
+
+use pass_gen::{
+    config_dir, derive_seed, grapheme_len, json_number, json_quote, lock_buffers, verify_api_token, verify_license_key,
+    wipe, wipe_string, write_comparison, write_entry, write_estimate, write_passwords, write_verbose, BitPacking,
+    BreachFilter, CharClass, ClassSet, Config, ConfigBuilder, Derivation, EntropyReport, Format, Identifier, KdfParams,
+    Leet, PassGenError, Policy, PoolSource, Quote, RandomCase, ReportFormat, SecretSharing, Secrets, Share, TargetHash,
+    Targets, Terminal, TokenAlphabet, TokenSource, UsernameStyle, Whitespace, WipingWriter, WordlistHash,
+    API_TOKEN_BITS, API_TOKEN_CHECKSUM_LEN, BAR_TARGET, CHARSET_BITS, CONFIG_KEYS, DEFAULT_RATES, HASH_RATES,
+    LICENSE_GROUPS, LICENSE_KEY_ALPHABET, LOCALE_VARS, MAX_TOKENS, PRESETS, STRENGTH_LABELS, TIME_UNITS,
+    WORDLIST_HASHES,
+};
+#[cfg(feature = "age")]
+use pass_gen::{write_age, AgeRecipient};
+use rand::{
+    rngs::{OsRng, StdRng},
+    Rng, SeedableRng,
+};
 use std::{
     env::args,
-    fs::File,
-    io::{BufRead, BufReader},
-    ops::Range,
+    fs::{File, OpenOptions},
+    hint,
+    io::{self, BufRead, BufReader, BufWriter, ErrorKind, IsTerminal, Write},
+    path::Path,
     process::{exit, Command, Stdio},
+    thread,
+    time::{Duration, Instant},
 };
 
 /* -------------------- *
  *        UTILS         *
  * -------------------- */
-const MINUTE: f64  = 60.0;
-const HOUR: f64    = MINUTE * 60.0;
-const DAY: f64     = HOUR * 24.0;
-const YEAR: f64    = DAY * 365.25;
-const CENTURY: f64 = YEAR * 100.0;
+const EXIT_SUCCESS: i32 = 0;
+const EXIT_FAILED: i32 = 1;
+const EXIT_WEAK: i32 = 3;
+const EXIT_INTERRUPTED: i32 = 130;
+const FP_RATE: f64 = 0.001;
+const PWGEN_AMBIGUOUS: &str = "B8G6I1l0OQDS5Z2";
+const PWGEN_SYMBOLS: &str = "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+const HELP_INDENT: usize = 31;
+const HELP_WIDTH: usize = 100;
+const XKCDPASS_DELIMITERS: &str = "!$%^&*-_+=:|~?/.;";
+const AGENT_IDLE_TIMEOUT: u32 = 600;
+const AGENT_MAX_REQUEST: u64 = 64 * 1024;
+const PEPPER_FD: u32 = 3;
+const BENCH_SECONDS: f64 = 3.0;
+const RECOVERY_CODES: u32 = 10;
+
+
+macro_rules! usage {
+    ($($x:expr),*) => {
+        PassGenError::Usage(format!($($x,)*))
+    }
+}
+
+fn use_color() -> bool {
+    std::env::var_os("NO_COLOR").is_none() && io::stderr().is_terminal()
+}
+
+// keeps secrets out of swap and core files, failures only warn
+fn harden() {
+    #[cfg(unix)]
+    {
+        let no_core = libc::rlimit { rlim_cur: 0, rlim_max: 0 };
+
+        // SAFETY: plain syscalls on our own process with valid arguments
+        unsafe {
+            if libc::setrlimit(libc::RLIMIT_CORE, &no_core) != 0 {
+                eprintln!("pass-gen: warning: could not disable core dumps: {}", io::Error::last_os_error());
+            }
+
+            #[cfg(target_os = "linux")]
+            if libc::prctl(libc::PR_SET_DUMPABLE, 0, 0, 0, 0) != 0 {
+                eprintln!("pass-gen: warning: could not mark the process undumpable: {}", io::Error::last_os_error());
+            }
+
+            // with a finite limit future allocations would fail once it is reached,
+            // so only the output buffers are locked, which every password passes through
+            let mut limit: libc::rlimit = std::mem::zeroed();
+            let unlimited = libc::getrlimit(libc::RLIMIT_MEMLOCK, &mut limit) == 0
+                && limit.rlim_cur == libc::RLIM_INFINITY;
+
+            if !unlimited && libc::geteuid() != 0 {
+                let locked = match lock_buffers(limit.rlim_cur as usize) {
+                    0 => "nothing is locked",
+                    _ => "only the output buffer is locked, not the passwords as they are generated",
+                };
+
+                eprintln!(
+                    "pass-gen: warning: RLIMIT_MEMLOCK is {} KiB, {} (try ulimit -l unlimited)",
+                    limit.rlim_cur / 1024,
+                    locked
+                );
+            } else if libc::mlockall(libc::MCL_CURRENT | libc::MCL_FUTURE) != 0 {
+                eprintln!("pass-gen: warning: could not lock memory: {}", io::Error::last_os_error());
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    eprintln!("pass-gen: warning: --paranoid is not supported on this platform");
+}
+
+// reads a line from stdin, disabling echo while a terminal is attached
+fn read_secret(prompt: &str) -> io::Result<String> {
+    let stdin = io::stdin();
+    let tty = stdin.is_terminal();
+
+    #[cfg(unix)]
+    let saved = if tty {
+        let mut term: libc::termios = unsafe { std::mem::zeroed() };
+
+        // SAFETY: tcgetattr/tcsetattr only touch the termios struct we own
+        unsafe {
+            if libc::tcgetattr(libc::STDIN_FILENO, &mut term) == 0 {
+                let saved = term;
+                term.c_lflag &= !libc::ECHO;
+                libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &term);
+                Some(saved)
+            } else {
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    #[cfg(windows)]
+    let saved = if tty { windows::disable_echo() } else { None };
+
+    if tty {
+        eprint!("{}", prompt);
+    }
+
+    let mut line = String::new();
+    let res = stdin.lock().read_line(&mut line);
+
+    #[cfg(unix)]
+    if let Some(saved) = saved {
+        // SAFETY: restores the attributes read above
+        unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &saved) };
+        eprintln!();
+    }
+
+    #[cfg(windows)]
+    if let Some(saved) = saved {
+        windows::restore_echo(saved);
+        eprintln!();
+    }
+
+    res?;
+
+    let len = line.trim_end_matches(['\r', '\n']).len();
+    line.truncate(len);
+
+    Ok(line)
+}
+
+// waits for enter on the terminal, ctrl-c arrives as a key so the terminal is restored before exiting
+#[cfg(unix)]
+const TERMINAL: &str = "/dev/tty";
+
+#[cfg(unix)]
+fn confirm_reveal() -> Result<(), PassGenError> {
+    use std::os::unix::io::AsRawFd;
+
+    let tty_error = |source| PassGenError::Io { action: "waiting to reveal", path: Some(TERMINAL.to_string()), source };
+
+    let mut tty = OpenOptions::new().read(true).write(true).open(TERMINAL).map_err(tty_error)?;
+    let fd = tty.as_raw_fd();
+    let mut term: libc::termios = unsafe { std::mem::zeroed() };
+
+    // SAFETY: tcgetattr/tcsetattr only touch the termios struct we own
+    if unsafe { libc::tcgetattr(fd, &mut term) } != 0 {
+        return Err(tty_error(io::Error::last_os_error()));
+    }
+
+    let saved = term;
+    term.c_lflag &= !(libc::ICANON | libc::ECHO | libc::ISIG);
+    term.c_cc[libc::VMIN] = 1;
+    term.c_cc[libc::VTIME] = 0;
+
+    // SAFETY: as above
+    unsafe { libc::tcsetattr(fd, libc::TCSANOW, &term) };
+
+    let res = tty.write_all(b"[press Enter to reveal]").and_then(|_| wait_for_enter(&mut tty));
+
+    // SAFETY: restores the attributes read above
+    unsafe { libc::tcsetattr(fd, libc::TCSANOW, &saved) };
+    let _ = tty.write_all(b"\n");
+
+    if !res.map_err(tty_error)? {
+        return Err(tty_error(io::Error::new(ErrorKind::Interrupted, "interrupted, nothing was revealed")));
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+const CONSOLE_INPUT: &str = "CONIN$";
+#[cfg(not(unix))]
+const CONSOLE_OUTPUT: &str = "CONOUT$";
+
+#[cfg(not(unix))]
+fn confirm_reveal() -> Result<(), PassGenError> {
+    let tty_error =
+        |source| PassGenError::Io { action: "waiting to reveal", path: Some(CONSOLE_OUTPUT.to_string()), source };
+
+    let mut tty = OpenOptions::new().write(true).open(CONSOLE_OUTPUT).map_err(tty_error)?;
+    tty.write_all(b"[press Enter to reveal]").map_err(tty_error)?;
+
+    // the console itself, like /dev/tty, so piped stdin cannot answer in place of the user
+    let console = OpenOptions::new().read(true).open(CONSOLE_INPUT).map_err(|source| PassGenError::Io {
+        action: "waiting to reveal",
+        path: Some(CONSOLE_INPUT.to_string()),
+        source,
+    })?;
+
+    let mut line = String::new();
+
+    if BufReader::new(console).read_line(&mut line).map_err(tty_error)? == 0 {
+        return Err(tty_error(io::Error::new(ErrorKind::Interrupted, "interrupted, nothing was revealed")));
+    }
+
+    Ok(())
+}
+
+// true on enter, false on ctrl-c, ctrl-d or the end of input
+#[cfg(unix)]
+fn wait_for_enter(tty: &mut File) -> io::Result<bool> {
+    let mut byte = [0];
+
+    loop {
+        match io::Read::read(tty, &mut byte) {
+            Ok(0) => return Ok(false),
+            Ok(_) if matches!(byte[0], b'\r' | b'\n') => return Ok(true),
+            Ok(_) if matches!(byte[0], 0x03 | 0x04) => return Ok(false),
+            Ok(_) => {},
+            Err(err) if err.kind() == ErrorKind::Interrupted => {},
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+fn exit_code(err: &PassGenError) -> i32 {
+    match err {
+        PassGenError::Weak { .. } => EXIT_WEAK,
+        PassGenError::Io { source, .. } if source.kind() == ErrorKind::Interrupted => EXIT_INTERRUPTED,
+        PassGenError::Line { source, .. } => exit_code(source),
+        PassGenError::Output(e) => e.raw_os_error().unwrap_or(EXIT_FAILED),
+        _ => EXIT_FAILED,
+    }
+}
+
+// a check that ran to the end but failed is no error, only its status tells
+fn status(passed: bool) -> i32 {
+    if passed { EXIT_SUCCESS } else { EXIT_FAILED }
+}
+
+
+/* -------------------- *
+ *       OPTIONS        *
+ * -------------------- */
+// one flag of the main command line, the parser, --help and --generate-man all read the same table
+struct Opt {
+    short: Option<&'static str>,
+    long: &'static str,
+    arg: Option<&'static str>,
+    help: String,
+    default: Option<String>,
+}
+
+impl Opt {
+    fn new(short: &'static str, long: &'static str, arg: &'static str, help: impl Into<String>) -> Self {
+        Opt {
+            short: Some(short).filter(|short| !short.is_empty()),
+            long,
+            arg: Some(arg).filter(|arg| !arg.is_empty()),
+            help: help.into(),
+            default: None,
+        }
+    }
+
+    fn default(mut self, default: impl Into<String>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    // an optional argument is attached with =, like --leet=0.5
+    fn matches(&self, flag: &str) -> bool {
+        let attached = self.arg.is_some_and(|arg| arg.starts_with("[="));
+
+        flag == self.long
+            || self.short == Some(flag)
+            || (attached && flag.strip_prefix(self.long).is_some_and(|rest| rest.starts_with('=')))
+    }
+
+    fn usage(&self) -> String {
+        let flags = match self.short {
+            Some(short) => format!("{}, {}", short, self.long),
+            None => format!("    {}", self.long),
+        };
+
+        match self.arg {
+            Some(arg) if arg.starts_with('[') => format!("{}{}", flags, arg),
+            Some(arg) => format!("{} {}", flags, arg),
+            None => flags,
+        }
+    }
+}
+
+fn options() -> Vec<Opt> {
+    let kdf = KdfParams::default();
+    let rates: Vec<&str> = DEFAULT_RATES.iter().map(|(label, _)| *label).collect();
+
+    vec![
+        Opt::new("-p", "--preset", "<name>", format!("use a builtin token preset: {}", PRESETS.join(", ")))
+            .default("the word list of the locale, english when it has none, -p word is always english"),
+        Opt::new("", "--no-locale", "", "ignore the locale and use the english word list"),
+        Opt::new("", "--no-config", "", "ignore the [preset.<name>] sections of the config file"),
+        Opt::new("-f", "--file", "<path>", "read tokens from a file, one per line, # starts a comment, - reads stdin"),
+        Opt::new("", "--wordlist-hash", "<algo:hex>", format!("refuse a word list whose raw bytes do not hash to hex, \
+            repeat it for every --file or --from file in order, algo is one of {}", WORDLIST_HASHES.join(", "))),
+        Opt::new("", "--from", "<spec>", "draw one token from each source in turn, a comma list of files and \
+            preset:<name> like \"adjectives.txt,nouns.txt,preset:number\", --count repeats the sequence"),
+        Opt::new("", "--whitespace", "<policy>", "what to do with lines of --file and --from files with \
+            whitespace inside like \"new york\": keep, skip with a warning, split into tokens or join the parts")
+            .default("skip"),
+        Opt::new("", "--whitespace-join", "<str>", "string to join the parts with, with --whitespace join")
+            .default("nothing, giving \"newyork\""),
+        Opt::new("", "--charset", "<chars>", "draw single characters from a list like 'a-zA-Z0-9!@#', \\ escapes"),
+        Opt::new("", "--allow-unicode", "", "allow characters outside ascii in --charset"),
+        Opt::new("", "--exclude-chars", "<chars>", "remove characters from a pool of single characters"),
+        Opt::new("", "--min-word-length", "<n>", "keep only tokens of at least n characters"),
+        Opt::new("", "--max-word-length", "<n>", "keep only tokens of at most n characters"),
+        Opt::new("", "--no-confusable", "", "keep at most one word of every set of homophones and easily confused \
+            words, like to/too/two"),
+        Opt::new("-c", "--count", "<n>", "number of tokens per password")
+            .default(format!("the preset's, enough for {} bits with --charset", CHARSET_BITS)),
+        Opt::new("", "--force", "", format!("allow more than {} tokens per password", MAX_TOKENS)),
+        Opt::new("-b", "--bits", "<n>", "choose the token count to reach n bits of entropy"),
+        Opt::new("", "--exact-bits", "<n>", "encode n random bits across the fewest tokens that hold them, \
+            instead of drawing each token on its own"),
+        Opt::new("", "--decode-bits", "<n>", "recover the n bit integer of an --exact-bits passphrase from stdin, \
+            with the pool and separator it was generated with"),
+        Opt::new("", "--length", "<n>", "fit as many tokens as possible into exactly n characters and pad the rest \
+            with digits and symbols"),
+        Opt::new("", "--max-length", "<n>", "re-draw passwords longer than n characters, never truncate"),
+        Opt::new("", "--retry-limit", "<n>", "give up after n rejected candidates for one password (default 1000)"),
+        Opt::new("", "--acrostic", "<text>", "one word per letter of text, each starting with that letter"),
+        Opt::new("", "--template", "<pattern>", "lay out each password, e.g. \"Wwdd-ss\": W capitalized word, \
+            w word, d digit, s symbol, a/A lower/upper letter, x any ascii, \\ escapes a literal, words come from \
+            --preset or --file"),
+        Opt::new("", "--join", "<layout>", "place the tokens into a layout like \"{1}-{2}.{3}_{4}\": {n} is token n, \
+            {*} the tokens without a placeholder separated as usual, {{ and }} are braces, the placeholders choose \
+            the count unless there is a {*}"),
+        Opt::new("", "--sentence", "", "words that read like a sentence, e.g. adjective noun adverb verb noun, \
+            from builtin lists, separated by \"-\" unless --sep is given"),
+        Opt::new("", "--sentence-template", "<pattern>", "one sentence layout instead of a random one, \
+            e.g. ADJ-NOUN-VERB-NOUN"),
+        Opt::new("", "--crack-time", "<duration>", "choose the token count to resist guessing for a duration"),
+        Opt::new("", "--min-entropy", "<n>", format!("fail with exit status {} if entropy is below n bits", EXIT_WEAK)),
+        Opt::new("-s", "--sep", "<str>", "separator between tokens").default("the preset's"),
+        Opt::new("", "--sep-set", "<chars>", "pick each separator at random from these characters"),
+        Opt::new("-n", "--number", "<n>", "number of passwords to generate").default("1"),
+        Opt::new("-u", "--unique", "", "never repeat a password within a batch"),
+        Opt::new("", "--recovery-codes", "[=<n>]", "generate n unique, numbered recovery codes of 8 characters from \
+            23456789abcdefghjkmnpqrstuvwxyz, -c sets the characters per code").default(RECOVERY_CODES.to_string()),
+        Opt::new("", "--uuid", "", "print random version 4 uuids instead of passwords, 122 random bits each"),
+        Opt::new("", "--ulid", "", "print ulids instead of passwords, a millisecond timestamp and 80 random bits, \
+            in ascending order within a run"),
+        Opt::new("", "--api-token", "", format!(
+            "print api tokens instead of passwords, the --token-prefix, an underscore, a random payload of --bits, \
+            default {}, and a {} character crc32 checksum of the payload",
+            API_TOKEN_BITS, API_TOKEN_CHECKSUM_LEN
+        )),
+        Opt::new("", "--token-prefix", "<str>", "prefix of --api-token, letters, digits and _"),
+        Opt::new("", "--token-alphabet", "<name>", "alphabet of the payload and checksum of --api-token, base62 or \
+            crockford32").default("base62"),
+        Opt::new("", "--license-key", "", format!(
+            "print license keys instead of passwords, --key-groups groups of --group characters from {} joined by -, \
+            the last character a luhn mod {} check character of the others",
+            std::str::from_utf8(LICENSE_KEY_ALPHABET).expect("alphabet is ascii"),
+            LICENSE_KEY_ALPHABET.len()
+        )),
+        Opt::new("", "--key-groups", "<n>", "groups of a --license-key").default(LICENSE_GROUPS.to_string()),
+        Opt::new("", "--group", "<n>", "characters per group of a recovery code, joined by the separator, - unless -s \
+            is given, or of a --license-key").default("4"),
+        Opt::new("", "--require", "<classes>", "re-draw until every class is present: lower,upper,digit,symbol"),
+        Opt::new("", "--start-with-letter", "", "make the first character a letter"),
+        Opt::new("", "--mutate", "<n>", "replace n random characters of the tokens, never separators"),
+        Opt::new("", "--mutate-pool", "<chars>", "characters to replace them with, in --charset syntax")
+            .default("digits and symbols"),
+        Opt::new("", "--leet", "[=<p>]", "substitute a→4/@, e→3, i→1/!, o→0, s→5/$, t→7 in tokens, \
+            each with probability p")
+            .default(Leet::DEFAULT_PROBABILITY.to_string()),
+        Opt::new("", "--random-case", "[=<p>]", "uppercase each letter of the tokens with probability p, \
+            separators, digits and symbols stay as they are, conflicts with --leet")
+            .default(RandomCase::DEFAULT_PROBABILITY.to_string()),
+        Opt::new("", "--checksum", "", "append a word derived from the others to catch typos, no entropy"),
+        Opt::new("", "--policy", "<path>", "satisfy a password policy file, a charset in it becomes the pool"),
+        Opt::new("-j", "--jobs", "<n>", "number of threads for bulk generation").default("1"),
+        Opt::new("", "--seed", "<n>", "seed the random number generator"),
+        Opt::new("", "--csv", "", "write passwords as csv"),
+        Opt::new("", "--json", "", "write passwords as json"),
+        Opt::new("", "--no-header", "", "omit the csv header row"),
+        Opt::new("", "--keepass-csv", "", "write passwords as csv for the KeePassXC importer"),
+        Opt::new("", "--title-template", "<text>", "entry titles for --keepass-csv, {i} is the password number"),
+        Opt::new("", "--username-template", "<text>", "entry usernames for --keepass-csv, {i} is the password number"),
+        Opt::new("", "--keepass-group", "<name>", "group of the --keepass-csv entries"),
+        Opt::new("", "--with-username", "", "generate a username with every password, printed as username:password"),
+        Opt::new("", "--username-style", "<style>", "words for two words joined by a dot or pronounceable for 8 \
+            letters [default: words]"),
+        Opt::new("", "--username-max-len", "<n>", "maximum length of the usernames [default: 32]"),
+        Opt::new("", "--one-per-line", "", "never lay out passwords in columns on a terminal"),
+        Opt::new("", "--vertical", "", "print each token on its own numbered line and leave the separators out, \
+            for writing a passphrase down"),
+        Opt::new("", "--no-numbers", "", "leave the numbers out of --vertical"),
+        Opt::new("", "--split", "<k/n>", "print n shares of the password instead of the password, any k of them \
+            reconstruct it with pass-gen combine and fewer reveal nothing"),
+        Opt::new("", "--also-plain", "", "print the password above its --split shares or encrypted output"),
+        Opt::new("", "--encrypt-to", "<keyid>", "print the output encrypted by gpg --armor for keyid instead of in \
+            plain, repeat to add recipients"),
+        Opt::new("", "--age-recipient", "<age1...>", "print the output encrypted with age to the recipient instead of \
+            in plain, repeat to add recipients"),
+        Opt::new("", "--age-recipients-file", "<path>", "encrypt with age to every recipient in path, one per line"),
+        Opt::new("", "--tty-echo", "", "also write every password to the terminal, even when stdout is captured, \
+            skipped without a controlling terminal"),
+        Opt::new("", "--confirm-reveal", "", "hold the passwords back until enter is pressed on the terminal, \
+            skipped when stdout is not a terminal"),
+        Opt::new("-o", "--output", "<path>", "write passwords to a new file instead of stdout"),
+        Opt::new("", "--quote", "<style>", "quote passwords printed to stdout for pasting, shell wraps them in \
+            single quotes, env quotes them for a .env file, none prints them as they are")
+        .default("none"),
+        Opt::new("", "--export", "<name>", "print the password as a line export name=..., quoted like --quote env \
+            unless --quote says otherwise"),
+        Opt::new("", "--paranoid", "", "lock memory and disable core dumps before generating"),
+        Opt::new("", "--check-offline", "<path>", "reject passwords found in a filter made by build-filter"),
+        Opt::new("", "--no-dictionary-substrings", "", "re-draw character passwords containing an english word of \
+            4+ letters"),
+        Opt::new("", "--history", "<path>", "never issue a password twice, keyed hashes of issued ones are kept \
+            in path, the key in the config directory"),
+        Opt::new("", "--history-prune", "<days>", "forget passwords issued more than days ago and exit"),
+        Opt::new("", "--audit-log", "<path>", "append a json line per run to path with the time, user, source, \
+            token count and entropy, never the passwords or the seed"),
+        Opt::new("", "--derive", "<label>", "derive the password for label from a master passphrase"),
+        Opt::new("", "--salt", "<str>", "argon2 salt for --derive").default("a stored random salt"),
+        Opt::new("", "--master-fd", "<fd>", "read the master passphrase from a file descriptor"),
+        Opt::new("", "--pepper", "", format!(
+            "append a secret only you know to every password after the separator, prompted for without echo, \
+            read from descriptor {} when stdin is not a terminal, never counted or reported",
+            PEPPER_FD
+        )),
+        Opt::new("", "--pepper-fd", "<fd>", "read the --pepper from a file descriptor"),
+        Opt::new("", "--kdf-memory", "<KiB>", "argon2 memory for --derive").default(kdf.memory.to_string()),
+        Opt::new("", "--kdf-iterations", "<n>", "argon2 iterations for --derive").default(kdf.iterations.to_string()),
+        Opt::new("", "--kdf-lanes", "<n>", "argon2 lanes for --derive").default(kdf.lanes.to_string()),
+        Opt::new("", "--estimate", "<phrase>", "estimate the entropy of a passphrase, - reads stdin"),
+        Opt::new("", "--compare", "<spec>", "compare configurations, e.g. \"word:5,ascii:12,file=words.txt:6\""),
+        Opt::new("", "--batch", "", "read one request per line of stdin in the flags of this command line and print \
+            one password per line, the other flags given here apply to every line"),
+        Opt::new("", "--batch-strict", "", "stop --batch at the first line that fails instead of printing an empty \
+            line for it"),
+        Opt::new("", "--spec", "<file>", "generate one password per \"label: <flags>\" line of file in file order and \
+            print label=password lines, or a json object with --json, the other flags given here apply to every line"),
+        Opt::new("-r", "--report", "", "print an entropy report to stderr"),
+        Opt::new("", "--report-only", "", "print the report to stdout without generating"),
+        Opt::new("", "--pool-size", "<n>", "report on tokens drawn from a hypothetical pool of n, without any token \
+            data, implies --report-only"),
+        Opt::new("", "--report-format", "<fmt>", "report format: human, json, kv").default("human"),
+        Opt::new("", "--guesses-per-second", "<r>", "attacker guess rate for the report, e.g. 10k, 1e12")
+            .default(rates.join(", ")),
+        Opt::new("", "--target-hash", "<alg>", format!(
+            "attacker guess rate for the report from the verifier hash, one of {}, with costs like bcrypt:12 or \
+            argon2id:m,t,p, estimated for 8x RTX 4090 running hashcat",
+            HASH_RATES.iter().map(|(name, ..)| *name).collect::<Vec<_>>().join(", ")
+        )),
+        Opt::new("", "--bar-target", "<n>", "bits that fill the entropy bar of the report")
+            .default(BAR_TARGET.to_string()),
+        Opt::new("-v", "--verbose", "", "print the effective configuration to stderr"),
+        Opt::new("", "--stats", "", "print the composition of each password to stderr, or with --json as a stats \
+            object of each entry"),
+        Opt::new("", "--compat", "<mode>", "behave like pwgen or xkcdpass, must be the first argument"),
+        Opt::new("", "--generate-man", "", "write this manual as a roff man page to stdout"),
+        Opt::new("-h", "--help", "", "print this help"),
+    ]
+}
+
+// usage and description of every subcommand and compatibility mode
+fn commands() -> Vec<(&'static str, &'static str, String)> {
+    vec![
+        ("agent", "--socket <path> [--max-requests <n>] [--idle-timeout <secs>]", format!(
+            "serve passwords on a unix socket created 0600, each line sent is a request in the flags of the main \
+            command line and is answered with the passwords and a newline, or a line starting with \"error: \", \
+            exits after n requests or when idle for secs, default {} seconds",
+            AGENT_IDLE_TIMEOUT
+        )),
+        ("build-filter", "[--fp-rate <p>] <hash list> <output>", format!(
+            "build a breach filter from a sha-1 hash list, one hash per line like the ordered HIBP download, \
+            default --fp-rate {}",
+            FP_RATE
+        )),
+        ("check", "--policy <path> [--quiet]", String::from(
+            "check a password from stdin against a policy file, exit status is 0 only if every rule passes, \
+            --quiet prints nothing",
+        )),
+        ("verify-checksum", "[-p <name> | -f <path> [--whitespace <policy>]] [-s <str>] [--quiet]", String::from(
+            "check the last word of a passphrase from stdin against the others, with the pool, separator and \
+            --whitespace and --whitespace-join it was generated with",
+        )),
+        ("verify-token", "[--token-prefix <str>] [--quiet] <token>", String::from(
+            "check the structure and checksum of an --api-token, and its prefix if --token-prefix is given, \
+            without any network access",
+        )),
+        ("verify-key", "[--group <n>] [--key-groups <n>] [--quiet] <key>", String::from(
+            "check the groups and check character of a --license-key, ignoring case, and its shape if --group or \
+            --key-groups is given",
+        )),
+        ("combine", "< <shares>", String::from(
+            "reconstruct a password from its --split shares on stdin, one per line, the share headers and empty \
+            lines are skipped",
+        )),
+        ("bench", "[--seconds <s>] [--json] [options]", format!(
+            "generate passwords for s seconds, default {}, and print the throughput and where the time goes, \
+            the options of the main command line choose the configuration and --jobs the threads, --json prints \
+            the results as json",
+            BENCH_SECONDS
+        )),
+        ("words", "[--prefix <str>] [--contains <str>] [--of-length <n>] [--index <n>] [options]", String::from(
+            "print the tokens of the pool that match every query, one per line, the pool options of the main \
+            command line choose the pool and its filters, --index counts from 1 in pool order, exit status is 1 \
+            if no token matches",
+        )),
+    ]
+}
+
+fn compat_modes() -> Vec<(&'static str, &'static str, &'static str)> {
+    vec![
+        ("pwgen", "[-sncAy0B1] [-N <n>] [-r <chars>] [length [count]]",
+            "must come first, also enabled when called as pwgen. Only fully random passwords (-s) are supported, \
+            with capitals and numerals unless -A or -0, in columns on a terminal unless -1"),
+        ("xkcdpass", "[-n <n>] [-d <str>] [-w <path>] [--min <n>] [--max <n>] [-c <n>] [-V]",
+            "must come first, also enabled when called as xkcdpass. Words come from the word preset unless -w \
+            names a file, xkcdpass's bundled lists are not included"),
+    ]
+}
+
+const EXIT_CODES: &[(i32, &str)] = &[
+    (EXIT_SUCCESS, "success, or a password that passes check, verify-checksum, verify-token and verify-key"),
+    (EXIT_FAILED, "invalid usage, unusable input, an unsatisfiable configuration, or a password that fails check, \
+        verify-checksum, verify-token and verify-key"),
+    (EXIT_WEAK, "the entropy is below --min-entropy"),
+    (EXIT_INTERRUPTED, "--confirm-reveal was interrupted, nothing was revealed"),
+];
+
+const ENVIRONMENT: &[(&str, &str)] = &[
+    ("XDG_CONFIG_HOME", "directory of the pass-gen config file, salt and history key, instead of ~/.config"),
+    ("HOME", "locates ~/.config/pass-gen"),
+    ("APPDATA", "directory of the config file, salt and history key on windows"),
+    ("NO_COLOR", "disables colored output"),
+    ("COLUMNS", "terminal width when it cannot be queried"),
+];
+
+fn strength_ratings() -> String {
+    let mut lower = 0.0;
+    let mut strength = Vec::new();
+
+    for &(limit, label) in STRENGTH_LABELS {
+        if limit.is_finite() {
+            strength.push(format!("{} < {} bits", label, limit));
+        } else {
+            strength.push(format!("{} >= {} bits", label, lower));
+        }
+
+        lower = limit;
+    }
+
+    strength.join(", ")
+}
+
+// breaks text into lines of at most width characters, at spaces
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = vec![String::new()];
+
+    for word in text.split(' ') {
+        let line = lines.last_mut().expect("lines start with one");
+
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(word.to_string());
+        } else {
+            if !line.is_empty() {
+                line.push(' ');
+            }
+
+            line.push_str(word);
+        }
+    }
+
+    lines
+}
+
+fn print_help(out: &mut impl Write) -> io::Result<()> {
+    let indent = " ".repeat(HELP_INDENT);
+    let print_entry = |out: &mut dyn Write, head: String, text: &str| -> io::Result<()> {
+        let mut lines = wrap(text, HELP_WIDTH - HELP_INDENT).into_iter();
+
+        match head.chars().count() < HELP_INDENT {
+            true => writeln!(out, "{:<width$}{}", head, lines.next().unwrap_or_default(), width = HELP_INDENT)?,
+            false => writeln!(out, "{}", head)?,
+        }
+
+        for line in lines {
+            writeln!(out, "{}{}", indent, line)?;
+        }
+
+        Ok(())
+    };
+
+    writeln!(out, "Usage: pass-gen [OPTIONS]")?;
+
+    for (name, usage, _) in commands() {
+        writeln!(out, "       pass-gen {} {}", name, usage)?;
+    }
+
+    for (name, usage, _) in compat_modes() {
+        writeln!(out, "       pass-gen --compat {} {}", name, usage)?;
+    }
+
+    writeln!(out, "\nOptions:")?;
+
+    for opt in options() {
+        let help = match &opt.default {
+            Some(default) => format!("{} (default: {})", opt.help, default),
+            None => opt.help.clone(),
+        };
+
+        print_entry(out, format!("  {}", opt.usage()), &help)?;
+    }
+
+    writeln!(out, "\nCommands:")?;
+
+    for (name, _, help) in commands() {
+        print_entry(out, format!("  {}", name), &help)?;
+    }
+
+    writeln!(out, "\nCompatibility:")?;
+
+    for (name, _, help) in compat_modes() {
+        print_entry(out, format!("  --compat {}", name), help)?;
+    }
+
+    writeln!(out, "\nStrength ratings:")?;
+    writeln!(out, "  {}", strength_ratings())
+}
+
+
+/* -------------------- *
+ *       MAN PAGE       *
+ * -------------------- */
+// escapes text for roff, a leading dot or quote would start a request
+fn roff(text: &str) -> String {
+    let escaped = text.replace('\\', "\\e").replace('-', "\\-");
+
+    match escaped.starts_with(['.', '\'']) {
+        true => format!("\\&{}", escaped),
+        false => escaped,
+    }
+}
+
+fn print_man(out: &mut impl Write) -> io::Result<()> {
+    // the presets are a table, preprocessed by tbl
+    writeln!(out, "'\\\" t")?;
+    writeln!(out, ".TH PASS-GEN 1 \"\" \"pass-gen {}\" \"User Commands\"", env!("CARGO_PKG_VERSION"))?;
+    writeln!(out, ".SH NAME")?;
+    writeln!(out, "pass\\-gen \\- generate passwords and passphrases from token pools")?;
+
+    writeln!(out, ".SH SYNOPSIS")?;
+    writeln!(out, ".B pass\\-gen\n[OPTIONS]")?;
+
+    for (name, usage, _) in commands() {
+        writeln!(out, ".br\n.B pass\\-gen {}\n{}", roff(name), roff(usage))?;
+    }
+
+    for (name, usage, _) in compat_modes() {
+        writeln!(out, ".br\n.B pass\\-gen \\-\\-compat {}\n{}", roff(name), roff(usage))?;
+    }
+
+    writeln!(out, ".SH DESCRIPTION")?;
+    writeln!(
+        out,
+        "{}",
+        roff(
+            "pass-gen draws tokens, words or single characters, uniformly at random from a pool and joins them \
+            with a separator. The entropy of every configuration is known exactly and can be reported, targeted \
+            and enforced."
+        )
+    )?;
+
+    writeln!(out, ".SH OPTIONS")?;
+
+    for opt in options() {
+        let flags = match opt.short {
+            Some(short) => format!("\\fB{}\\fR, \\fB{}\\fR", roff(short), roff(opt.long)),
+            None => format!("\\fB{}\\fR", roff(opt.long)),
+        };
+
+        match opt.arg {
+            Some(arg) if arg.starts_with('[') => writeln!(out, ".TP\n{}\\fI{}\\fR", flags, roff(arg))?,
+            Some(arg) => writeln!(out, ".TP\n{} \\fI{}\\fR", flags, roff(arg))?,
+            None => writeln!(out, ".TP\n{}", flags)?,
+        }
+
+        writeln!(out, "{}", roff(&opt.help))?;
+
+        if let Some(default) = &opt.default {
+            writeln!(out, ".br\nDefault: {}", roff(default))?;
+        }
+    }
+
+    writeln!(out, ".SH COMMANDS")?;
+
+    for (name, _, help) in commands() {
+        writeln!(out, ".TP\n\\fB{}\\fR\n{}", roff(name), roff(&help))?;
+    }
+
+    writeln!(out, ".SH COMPATIBILITY")?;
+
+    for (name, _, help) in compat_modes() {
+        writeln!(out, ".TP\n\\fB\\-\\-compat {}\\fR\n{}", roff(name), roff(help))?;
+    }
+
+    writeln!(out, ".SH PRESETS")?;
+    writeln!(out, ".TS\nl r r r l.\nName\tTokens\tBits per token\tCount\tSeparator")?;
+
+    for &name in PRESETS {
+        if let Some(preset) = pass_gen::preset(name) {
+            writeln!(
+                out,
+                "{}\t{}\t{:.1}\t{}\t{:?}",
+                name,
+                preset.token_data.len(),
+                preset.token_data.entropy_bits_per_token(),
+                preset.token_count,
+                preset.token_sep
+            )?;
+        }
+    }
+
+    writeln!(out, ".TE")?;
+
+    writeln!(out, ".SH CONFIGURATION")?;
+    writeln!(
+        out,
+        "{}",
+        roff(
+            "Settings per preset are read from $XDG_CONFIG_HOME/pass-gen/config.toml, one [preset.<name>] section \
+            each. Flags override them, --no-config ignores the file. The keys are:"
+        )
+    )?;
+
+    for (key, help) in CONFIG_KEYS {
+        writeln!(out, ".TP\n\\fB{}\\fR\n{}", roff(key), roff(help))?;
+    }
+
+    writeln!(out, ".SH ENVIRONMENT")?;
+
+    for var in LOCALE_VARS {
+        writeln!(out, ".TP\n\\fB{}\\fR\n{}", var, roff("the first one set selects the word list, see --no-locale"))?;
+    }
+
+    for (var, help) in ENVIRONMENT {
+        writeln!(out, ".TP\n\\fB{}\\fR\n{}", var, roff(help))?;
+    }
+
+    writeln!(out, ".SH EXIT STATUS")?;
+
+    for (code, help) in EXIT_CODES {
+        writeln!(out, ".TP\n\\fB{}\\fR\n{}", code, roff(help))?;
+    }
+
+    writeln!(out, ".PP\n{}", roff("A failed write exits with the error number of the operating system."))?;
+
+    writeln!(out, ".SH STRENGTH RATINGS")?;
+    writeln!(out, "{}", roff(&strength_ratings()))
+}
+
+
+/* -------------------- *
+ *      ARGUMENTS       *
+ * -------------------- */
+// the command line is only parsed here, all validation happens in the builder
+// None once --help or --generate-man has been printed, there is nothing left to do
+fn parse_config(args: &[String]) -> Result<Option<Config>, PassGenError> {
+    parse_args(args)?.map(ConfigBuilder::build).transpose()
+}
+
+// the builder of the command line, for callers that still have settings of their own to add
+fn parse_args(args: &[String]) -> Result<Option<ConfigBuilder>, PassGenError> {
+    let mut builder = ConfigBuilder::new();
+    let mut format = Format::Plain;
+    let mut identifier = None;
+
+    let options = options();
+
+    let mut idx = 1;
+    while let Some(flag) = args.get(idx).map(String::as_str) {
+        idx += 1;
+
+        // only flags of the option table are accepted, so --help and the man page list every one
+        let long = match options.iter().find(|opt| opt.matches(flag)) {
+            Some(opt) => opt.long,
+            None => return Err(usage!("invalid option {:?}", flag)),
+        };
+
+        builder = match long {
+            "--verbose" => builder.verbose(true),
+            "--stats" => builder.stats(true),
+            "--help" => {
+                print_help(&mut io::stdout().lock())?;
+                return Ok(None);
+            },
+            "--report" => builder.report(true),
+            "--report-only" => builder.report_only(true),
+            "--report-format" => {
+                let format = get_string(flag, args, &mut idx)?;
+
+                builder.report_format(match format {
+                    "human" => ReportFormat::Human,
+                    "json" => ReportFormat::Json,
+                    "kv" => ReportFormat::Kv,
+                    _ => return Err(usage!("invalid report format {:?}", format)),
+                })
+            }
+            "--guesses-per-second" => {
+                let rate = get_string(flag, args, &mut idx)?;
+
+                builder.guess_rate(rate, parse_rate(flag, rate)?)
+            }
+            "--target-hash" => builder.target_hash(TargetHash::parse(get_string(flag, args, &mut idx)?)?),
+            "--csv" | "--json" | "--keepass-csv" => {
+                let next = match long {
+                    "--csv" => Format::Csv,
+                    "--json" => Format::Json,
+                    _ => Format::KeepassCsv,
+                };
+
+                if format != Format::Plain && format != next {
+                    return Err(usage!("--csv, --json and --keepass-csv are mutually exclusive"));
+                }
+
+                format = next;
+                builder.format(next)
+            }
+            "--no-header" => builder.header(false),
+            "--title-template" => builder.title_template(get_string(flag, args, &mut idx)?),
+            "--username-template" => builder.username_template(get_string(flag, args, &mut idx)?),
+            "--keepass-group" => builder.keepass_group(get_string(flag, args, &mut idx)?),
+            "--with-username" => builder.with_username(true),
+            "--username-style" => {
+                let style = get_string(flag, args, &mut idx)?;
+
+                match UsernameStyle::parse(style) {
+                    Some(style) => builder.username_style(style),
+                    None => {
+                        return Err(usage!("invalid username style {:?}, expected words or pronounceable", style));
+                    },
+                }
+            }
+            "--username-max-len" => builder.username_max_len(get_number(flag, args, &mut idx)?),
+            "--one-per-line" => builder.one_per_line(true),
+            "--vertical" => builder.vertical(true),
+            "--no-numbers" => builder.numbers(false),
+            "--split" => {
+                let spec = get_string(flag, args, &mut idx)?;
+
+                let (threshold, shares) = match spec.split_once('/') {
+                    Some((k, n)) => (parse_number(flag, k)?, parse_number(flag, n)?),
+                    None => return Err(usage!("invalid argument to {:?}, expected k/n got {:?}", flag, spec)),
+                };
+
+                builder.split(SecretSharing::new(threshold, shares)?)
+            }
+            "--also-plain" => builder.also_plain(true),
+            "--encrypt-to" => builder.encrypt_to(get_string(flag, args, &mut idx)?),
+            #[cfg(feature = "age")]
+            "--age-recipient" => {
+                let recipient = AgeRecipient::parse(get_string(flag, args, &mut idx)?)?;
+                builder.age_recipients(vec![recipient])
+            }
+            #[cfg(feature = "age")]
+            "--age-recipients-file" => {
+                builder.age_recipients(load_age_recipients(get_string(flag, args, &mut idx)?)?)
+            }
+            #[cfg(not(feature = "age"))]
+            "--age-recipient" | "--age-recipients-file" => {
+                return Err(usage!("{} is not available, pass-gen was built without the age feature", flag));
+            }
+            "--tty-echo" => builder.tty_echo(true),
+            "--confirm-reveal" => builder.confirm_reveal(true),
+            "--output" => builder.output(get_string(flag, args, &mut idx)?),
+            "--quote" => {
+                let style = get_string(flag, args, &mut idx)?;
+
+                builder.quote(match style {
+                    "none" => Quote::None,
+                    "shell" => Quote::Shell,
+                    "env" => Quote::Env,
+                    _ => return Err(usage!("invalid quote style {:?}, expected shell, env or none", style)),
+                })
+            }
+            "--export" => builder.export(get_string(flag, args, &mut idx)?),
+            "--unique" => builder.unique(true),
+            "--recovery-codes" => {
+                let codes = match flag.split_once('=') {
+                    Some((_, value)) => match value.parse() {
+                        Ok(codes) if codes > 0 => codes,
+                        _ => {
+                            return Err(usage!(
+                                "invalid argument to {:?}, expected positive number got {:?}",
+                                long,
+                                value
+                            ));
+                        },
+                    },
+                    None => RECOVERY_CODES,
+                };
+
+                builder.recovery_codes(codes)
+            },
+            "--group" => builder.group(get_number(flag, args, &mut idx)?),
+            "--uuid" | "--ulid" => {
+                let next = if long == "--uuid" { Identifier::Uuid } else { Identifier::Ulid };
+
+                // the first of them is kept to tell which one conflicts
+                if identifier.is_some_and(|seen| seen != long) {
+                    return Err(usage!("--uuid conflicts with --ulid"));
+                }
+
+                identifier = Some(long);
+                builder.identifier(next)
+            },
+            "--api-token" => builder.api_token(true),
+            "--license-key" => builder.license_key(true),
+            "--key-groups" => builder.key_groups(get_number(flag, args, &mut idx)?),
+            "--token-prefix" => builder.token_prefix(get_string(flag, args, &mut idx)?),
+            "--token-alphabet" => {
+                let name = get_string(flag, args, &mut idx)?;
+                builder.token_alphabet(TokenAlphabet::parse(name)?)
+            },
+            "--start-with-letter" => builder.start_with_letter(true),
+            "--checksum" => builder.checksum(true),
+            "--mutate" => builder.mutate(get_number(flag, args, &mut idx)?),
+            "--mutate-pool" => builder.mutate_pool(get_string(flag, args, &mut idx)?),
+            "--leet" => {
+                let probability = match flag.split_once('=') {
+                    Some((_, value)) => value.parse().map_err(|_| {
+                        usage!("invalid argument to {:?}, expected a probability got {:?}", "--leet", value)
+                    })?,
+                    None => Leet::DEFAULT_PROBABILITY,
+                };
+
+                builder.leet(Leet::new(probability)?)
+            },
+            "--random-case" => {
+                let probability = match flag.split_once('=') {
+                    Some((_, value)) => value.parse().map_err(|_| {
+                        usage!("invalid argument to {:?}, expected a probability got {:?}", "--random-case", value)
+                    })?,
+                    None => RandomCase::DEFAULT_PROBABILITY,
+                };
+
+                builder.random_case(RandomCase::new(probability)?)
+            },
+            "--require" => {
+                let list = get_string(flag, args, &mut idx)?;
+
+                match ClassSet::parse(list) {
+                    Some(classes) => builder.require(classes),
+                    None => {
+                        return Err(usage!(
+                            "invalid argument to {:?}, expected classes from lower, upper, digit, symbol",
+                            flag
+                        ))
+                    },
+                }
+            }
+            "--paranoid" => builder.paranoid(true),
+            "--derive" => builder.derive(get_string(flag, args, &mut idx)?),
+            "--salt" => builder.salt(get_string(flag, args, &mut idx)?),
+            "--master-fd" => builder.master_fd(get_number(flag, args, &mut idx)?),
+            "--pepper" => builder.pepper(true),
+            "--pepper-fd" => builder.pepper_fd(get_number(flag, args, &mut idx)?),
+            "--kdf-memory" => builder.kdf_memory(get_number(flag, args, &mut idx)?),
+            "--kdf-iterations" => builder.kdf_iterations(get_number(flag, args, &mut idx)?),
+            "--kdf-lanes" => builder.kdf_lanes(get_number(flag, args, &mut idx)?),
+            "--number" => builder.number(get_number(flag, args, &mut idx)?),
+            "--jobs" => builder.jobs(get_number(flag, args, &mut idx)?),
+            "--seed" => builder.seed(get_seed(flag, args, &mut idx)?),
+            "--count" => builder.count(get_number(flag, args, &mut idx)?),
+            "--force" => builder.force(true),
+            "--length" => builder.length(get_number(flag, args, &mut idx)?),
+            "--max-length" => builder.max_length(get_number(flag, args, &mut idx)?),
+            "--retry-limit" => builder.retry_limit(get_number(flag, args, &mut idx)?),
+            "--acrostic" => builder.acrostic(get_string(flag, args, &mut idx)?),
+            "--template" => builder.template(get_string(flag, args, &mut idx)?),
+            "--join" => builder.join(get_string(flag, args, &mut idx)?),
+            "--sentence" => builder.sentence(true),
+            "--sentence-template" => builder.sentence_template(get_string(flag, args, &mut idx)?),
+            "--bits" => builder.bits(get_number(flag, args, &mut idx)? as f64),
+            "--pool-size" => builder.pool_size(get_number(flag, args, &mut idx)?),
+            "--exact-bits" => builder.exact_bits(get_number(flag, args, &mut idx)?),
+            "--decode-bits" => builder.decode_bits(get_number(flag, args, &mut idx)?),
+            "--min-entropy" => builder.min_entropy(get_number(flag, args, &mut idx)? as f64),
+            "--bar-target" => builder.bar_target(get_number(flag, args, &mut idx)? as f64),
+            "--crack-time" => builder.crack_time(get_duration(flag, args, &mut idx)?),
+            "--sep" => builder.separator(get_string(flag, args, &mut idx)?),
+            "--sep-set" => builder.sep_set(get_string(flag, args, &mut idx)?),
+            "--file" => builder.wordlist_file(get_string(flag, args, &mut idx)?),
+            "--wordlist-hash" => {
+                let hash = get_string(flag, args, &mut idx)?;
+
+                builder.wordlist_hash(WordlistHash::parse(hash)?)
+            }
+            "--from" => builder.from(get_string(flag, args, &mut idx)?),
+            "--whitespace" => builder.whitespace(parse_whitespace(get_string(flag, args, &mut idx)?)?),
+            "--whitespace-join" => builder.whitespace_join(get_string(flag, args, &mut idx)?),
+            "--preset" => builder.preset(get_string(flag, args, &mut idx)?),
+            "--estimate" => builder.estimate(get_string(flag, args, &mut idx)?),
+            "--check-offline" => builder.check_offline(get_string(flag, args, &mut idx)?),
+            "--no-dictionary-substrings" => builder.no_dictionary(true),
+            "--no-confusable" => builder.no_confusable(true),
+            "--no-locale" => builder.no_locale(true),
+            "--no-config" => builder.no_config(true),
+            "--history" => builder.history(get_string(flag, args, &mut idx)?),
+            "--history-prune" => builder.history_prune(get_number(flag, args, &mut idx)?),
+            "--audit-log" => builder.audit_log(get_string(flag, args, &mut idx)?),
+            "--charset" => builder.charset(get_string(flag, args, &mut idx)?),
+            "--allow-unicode" => builder.allow_unicode(true),
+            "--exclude-chars" => builder.exclude_chars(get_string(flag, args, &mut idx)?),
+            "--min-word-length" => builder.min_word_length(get_number(flag, args, &mut idx)?),
+            "--max-word-length" => builder.max_word_length(get_number(flag, args, &mut idx)?),
+            "--policy" => builder.policy(get_string(flag, args, &mut idx)?),
+            "--compat" => return Err(usage!("--compat must be the first argument")),
+            "--compare" => builder.compare(get_string(flag, args, &mut idx)?),
+            "--batch" => builder.batch(true),
+            "--batch-strict" => builder.batch_strict(true),
+            "--spec" => builder.spec(get_string(flag, args, &mut idx)?),
+            "--generate-man" => {
+                print_man(&mut io::stdout().lock())?;
+                return Ok(None);
+            },
+            _ => return Err(usage!("invalid option {:?}", flag)),
+        };
+    }
+
+    Ok(Some(builder))
+}
+
+fn parse_whitespace(name: &str) -> Result<Whitespace, PassGenError> {
+    match Whitespace::parse(name) {
+        Some(whitespace) => Ok(whitespace),
+        None => Err(usage!("invalid whitespace policy {:?}, expected keep, skip, split or join", name)),
+    }
+}
+
+#[cfg(feature = "age")]
+fn load_age_recipients(path: &str) -> Result<Vec<AgeRecipient>, PassGenError> {
+    let text = std::fs::read_to_string(path).map_err(|source| PassGenError::Io {
+        action: "reading age recipients",
+        path: Some(path.to_string()),
+        source,
+    })?;
+
+    AgeRecipient::parse_file(&text).map_err(|err| usage!("{}: {}", path, err))
+}
+
+fn get_string<'a>(flag: &str, args: &'a [String], idx: &mut usize) -> Result<&'a str, PassGenError> {
+    if let Some(str) = args.get(*idx) {
+        *idx += 1;
+        Ok(str)
+    } else {
+        Err(usage!("missing argument to {}", flag))
+    }
+}
+
+fn get_number(flag: &str, args: &[String], idx: &mut usize) -> Result<u32, PassGenError> {
+    parse_number(flag, get_string(flag, args, idx)?)
+}
+
+fn parse_number(flag: &str, str: &str) -> Result<u32, PassGenError> {
+    match str.parse() {
+        Ok(int) if int > 0 => Ok(int),
+        _ => Err(usage!("invalid argument to {:?}, expected positve number got {:?}", flag, str)),
+    }
+}
+
+fn parse_rate(flag: &str, str: &str) -> Result<f64, PassGenError> {
+    let lower = str.to_ascii_lowercase();
+
+    let (num, mult) = match lower.char_indices().last() {
+        Some((i, 'k')) => (&lower[..i], 1e3),
+        Some((i, 'm')) => (&lower[..i], 1e6),
+        Some((i, 'b' | 'g')) => (&lower[..i], 1e9),
+        Some((i, 't')) => (&lower[..i], 1e12),
+        Some((i, 'p')) => (&lower[..i], 1e15),
+        _ => (lower.as_str(), 1.0),
+    };
+
+    match num.parse::<f64>().map(|x| x * mult) {
+        Ok(rate) if rate.is_finite() && rate > 0.0 => Ok(rate),
+        _ => Err(usage!("invalid argument to {:?}, expected positive rate got {:?}", flag, str)),
+    }
+}
+
+fn get_duration(flag: &str, args: &[String], idx: &mut usize) -> Result<f64, PassGenError> {
+    let str = get_string(flag, args, idx)?;
+    let trimmed = str.trim();
+
+    let split = trimmed.find(|c: char| c.is_alphabetic()).unwrap_or(trimmed.len());
+    let (num, unit) = trimmed.split_at(split);
+
+    let scale = TIME_UNITS
+        .iter()
+        .find(|(one, many, _)| unit == *one || unit == *many)
+        .map(|&(_, _, scale)| scale);
+
+    match (num.trim().parse::<f64>(), scale) {
+        (Ok(x), Some(scale)) if x.is_finite() && x > 0.0 => Ok(x * scale),
+        _ => Err(usage!("invalid argument to {:?}, expected positive duration like \"100 years\" got {:?}", flag, str)),
+    }
+}
+
+fn get_seed(flag: &str, args: &[String], idx: &mut usize) -> Result<u64, PassGenError> {
+    let str = get_string(flag, args, idx)?;
+
+    match str.parse() {
+        Ok(seed) => Ok(seed),
+        Err(_) => Err(usage!("invalid argument to {:?}, expected unsigned integer got {:?}", flag, str)),
+    }
+}
+
+
+/* -------------------- *
+ *     ENVIRONMENT      *
+ * -------------------- */
+// what the terminal the report goes to can show
+fn terminal() -> Terminal {
+    Terminal { width: get_term_width(), color: use_color(), unicode: unicode_locale() }
+}
+
+// whether the locale in effect encodes characters as utf-8
+fn unicode_locale() -> bool {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+        .map(|value| value.to_ascii_lowercase())
+        .is_some_and(|value| value.contains("utf-8") || value.contains("utf8"))
+}
+
+fn get_term_width() -> usize {
+    #[cfg(unix)]
+    {
+        let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+
+        // SAFETY: TIOCGWINSZ only writes into the winsize struct we pass
+        let res = unsafe { libc::ioctl(libc::STDERR_FILENO, libc::TIOCGWINSZ, &mut size) };
+
+        if res == 0 && size.ws_col > 0 {
+            return size.ws_col as usize;
+        }
+    }
+
+    #[cfg(windows)]
+    if let Some(width) = windows::term_width() {
+        return width;
+    }
+
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|cols| cols.trim().parse().ok())
+        .filter(|&cols| cols > 0)
+        .unwrap_or(80)
+}
+
+
+/* -------------------- *
+ *     DECODE BITS      *
+ * -------------------- */
+fn decode_packed(config: &Config, packing: &BitPacking, out: &mut impl Write) -> Result<(), PassGenError> {
+    let mut phrase = read_secret("passphrase: ")
+        .map_err(|source| PassGenError::Io { action: "reading passphrase", path: None, source })?;
+
+    let decoded = packing.unpack(config.token_data(), config.token_sep(), &phrase);
+    wipe_string(&mut phrase);
+
+    let mut bytes = decoded?;
+    let mut hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+
+    // as many hex digits as the bits need
+    let digits = (packing.bits() as usize).div_ceil(4);
+    let res = writeln!(out, "{}", &hex[hex.len() - digits..]);
+
+    wipe(&mut bytes);
+    wipe_string(&mut hex);
+
+    Ok(res?)
+}
+
+
+/* -------------------- *
+ *        OUTPUT        *
+ * -------------------- */
+fn open_output(config: &Config) -> Result<Box<dyn Write>, PassGenError> {
+    let path = match config.output() {
+        Some(path) => path,
+        None => return Ok(Box::new(io::stdout().lock())),
+    };
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    match options.open(path) {
+        Ok(file) => Ok(Box::new(file)),
+        Err(source) => Err(PassGenError::Io {
+            action: "opening output file",
+            path: Some(path.to_string()),
+            source,
+        }),
+    }
+}
+
+
+/* -------------------- *
+ *        DERIVE        *
+ * -------------------- */
+// the seed of --derive, from the master passphrase and the salt
+fn derived_seed(derivation: &Derivation) -> Result<[u8; 32], PassGenError> {
+    let salt = load_salt(derivation)?;
+
+    let master = match derivation.master_fd {
+        Some(fd) => read_fd(fd),
+        None => read_secret("master passphrase: "),
+    };
+
+    let mut master =
+        master.map_err(|source| PassGenError::Io { action: "reading master passphrase", path: None, source })?;
+
+    if master.is_empty() {
+        return Err(usage!("the master passphrase is empty"));
+    }
+
+    let seed = derive_seed(master.as_bytes(), salt.as_bytes(), &derivation.label, &derivation.params);
+    wipe_string(&mut master);
+
+    seed
+}
+
+// the salt is created once and has to be kept, every derived password depends on it
+fn load_salt(derivation: &Derivation) -> Result<String, PassGenError> {
+    if let Some(salt) = &derivation.salt {
+        return Ok(salt.clone());
+    }
+
+    let dir = match config_dir() {
+        Some(dir) => dir,
+        None => return Err(usage!("cannot find a place for the salt file, pass --salt")),
+    };
+
+    let path = dir.join("salt");
+    let io_error = |action, source| PassGenError::Io { action, path: Some(path.display().to_string()), source };
+
+    match std::fs::read_to_string(&path) {
+        Ok(salt) => return Ok(salt.trim().to_string()),
+        Err(e) if e.kind() == ErrorKind::NotFound => {},
+        Err(e) => return Err(io_error("reading salt file", e)),
+    }
+
+    let salt: String = OsRng.gen::<[u8; 16]>().iter().map(|b| format!("{:02x}", b)).collect();
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    std::fs::create_dir_all(&dir)
+        .and_then(|_| options.open(&path))
+        .and_then(|mut file| writeln!(file, "{}", salt))
+        .map_err(|e| io_error("creating salt file", e))?;
+
+    eprintln!("pass-gen: created salt file {:?}, back it up, derived passwords depend on it", path);
+
+    Ok(salt)
+}
+
+fn read_fd(fd: u32) -> io::Result<String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::io::FromRawFd;
+
+        // SAFETY: fcntl only reads the flags, a descriptor that is not open must not be owned below
+        if unsafe { libc::fcntl(fd as i32, libc::F_GETFD) } == -1 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // SAFETY: the descriptor was handed to us for reading and is not used elsewhere
+        let file = unsafe { File::from_raw_fd(fd as i32) };
+        let mut line = String::new();
+
+        BufReader::new(file).read_line(&mut line)?;
+
+        let len = line.trim_end_matches(['\r', '\n']).len();
+        line.truncate(len);
+
+        Ok(line)
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = fd;
+        Err(io::Error::new(ErrorKind::Unsupported, "reading from a descriptor is only supported on unix"))
+    }
+}
+
+// from the terminal, or a descriptor when stdin is something else, never from the command line
+fn read_pepper(config: &Config) -> Result<String, PassGenError> {
+    let pepper = match config.pepper_fd() {
+        Some(fd) => read_fd(fd),
+        None if io::stdin().is_terminal() => read_secret("pepper (will be appended, not shown): "),
+        None => read_fd(PEPPER_FD).map_err(|err| match err.raw_os_error() {
+            #[cfg(unix)]
+            Some(libc::EBADF) => io::Error::other(format!(
+                "stdin is not a terminal and descriptor {} is not open, pass the pepper there or with --pepper-fd",
+                PEPPER_FD
+            )),
+            _ => err,
+        }),
+    };
+
+    let pepper = pepper.map_err(|source| PassGenError::Io { action: "reading pepper", path: None, source })?;
+
+    if pepper.is_empty() {
+        return Err(usage!("the pepper is empty"));
+    }
+
+    Ok(pepper)
+}
+
+
+/* -------------------- *
+ *     PWGEN COMPAT     *
+ * -------------------- */
+// pwgen [options] [length [count]] mapped onto a charset pool, anything else is rejected
+fn pwgen_config(args: &[String]) -> Result<Option<Config>, PassGenError> {
+    let mut secure = false;
+    let (mut uppers, mut digits, mut symbols) = (true, true, false);
+    let mut columns = io::stdout().is_terminal();
+    let mut count = None;
+    let mut remove = String::new();
+    let mut positionals = Vec::new();
+
+    let unsupported = |option: &str| usage!("pwgen option {} is not supported in compat mode", option);
+    let number = parse_number;
+
+    let mut idx = 0;
+    while let Some(arg) = args.get(idx).map(String::as_str) {
+        idx += 1;
+
+        if let Some(long) = arg.strip_prefix("--") {
+            let (name, inline) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value)),
+                None => (long, None),
+            };
+
+            let value = match (name, inline) {
+                ("remove-chars" | "num-passwords", None) => get_string(arg, args, &mut idx)?,
+                (_, value) => value.unwrap_or(""),
+            };
+
+            match name {
+                "secure" => secure = true,
+                "numerals" => digits = true,
+                "no-numerals" => digits = false,
+                "capitalize" => uppers = true,
+                "no-capitalize" => uppers = false,
+                "symbols" => symbols = true,
+                "ambiguous" => remove.push_str(PWGEN_AMBIGUOUS),
+                "remove-chars" => remove.push_str(value),
+                "num-passwords" => count = Some(number(arg, value)?),
+                _ => return Err(unsupported(arg)),
+            }
+
+            continue;
+        }
+
+        let flags = match arg.strip_prefix('-') {
+            Some(flags) if !flags.is_empty() => flags,
+            _ => {
+                positionals.push(arg);
+                continue;
+            },
+        };
+
+        // short options cluster like -sy1, a value takes the rest of the cluster or the next argument
+        for (pos, flag) in flags.char_indices() {
+            match flag {
+                's' => secure = true,
+                'n' => digits = true,
+                '0' => digits = false,
+                'c' => uppers = true,
+                'A' => uppers = false,
+                'y' => symbols = true,
+                'B' => remove.push_str(PWGEN_AMBIGUOUS),
+                '1' => columns = false,
+                'N' | 'r' => {
+                    let value = match &flags[pos + 1..] {
+                        "" => get_string(arg, args, &mut idx)?,
+                        rest => rest,
+                    };
+
+                    match flag {
+                        'N' => count = Some(number("-N", value)?),
+                        _ => remove.push_str(value),
+                    }
+
+                    break;
+                },
+                'h' => {
+                    print_help(&mut io::stdout().lock())?;
+                    return Ok(None);
+                },
+                _ => return Err(unsupported(&format!("-{}", flag))),
+            }
+        }
+    }
+
+    if !secure {
+        return Err(usage!("pronounceable passwords are not supported in compat mode, pass -s for fully random ones"));
+    }
+
+    let (length, count) = match positionals[..] {
+        [] => (8, count),
+        [length] => (number("length", length)?, count),
+        [length, num] => (number("length", length)?, Some(number("count", num)?)),
+        _ => return Err(usage!("usage: pwgen [options] [length [count]]")),
+    };
+
+    // pwgen fills the terminal when it prints columns
+    let count = count.unwrap_or(match columns {
+        true => (get_term_width() as u32 / (length + 1)).max(1) * 20,
+        false => 1,
+    });
+
+    // every class beyond lowercase is also required, like pwgen does
+    let mut spec = String::from("a-z");
+    let mut required = ClassSet::default();
+
+    for (enabled, range, class) in [(uppers, "A-Z", CharClass::Upper), (digits, "0-9", CharClass::Digit)] {
+        if enabled {
+            spec.push_str(range);
+            required.insert(class);
+        }
+    }
+
+    if symbols {
+        spec.extend(PWGEN_SYMBOLS.chars().flat_map(|c| ['\\', c]));
+        required.insert(CharClass::Symbol);
+    }
+
+    let mut builder = ConfigBuilder::new()
+        .no_config(true)
+        .charset(&spec)
+        .count(length)
+        .number(count)
+        .require(required)
+        .one_per_line(!columns);
+
+    if !remove.is_empty() {
+        builder = builder.exclude_chars(&remove);
+    }
+
+    builder.build().map(Some)
+}
+
+
+/* -------------------- *
+ *   XKCDPASS COMPAT    *
+ * -------------------- */
+// xkcdpass [options] mapped onto the word pool, anything without an equivalent is rejected
+fn xkcdpass_config(args: &[String]) -> Result<Option<Config>, PassGenError> {
+    let mut builder = ConfigBuilder::new().separator(" ").one_per_line(true).no_locale(true).no_config(true);
+    let (mut words, mut acrostic) = (None, false);
+    let (mut min, mut max) = (5, 9);
+    let (mut random, mut delimiters) = (false, XKCDPASS_DELIMITERS);
+
+    let unsupported = |option: &str| usage!("xkcdpass option {} is not supported in compat mode", option);
+
+    let mut idx = 0;
+    while let Some(arg) = args.get(idx).map(String::as_str) {
+        idx += 1;
+
+        // --option=value is split up like argparse does
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value)),
+            _ => (arg, None),
+        };
+
+        let mut value = || match inline {
+            Some(value) => Ok(value),
+            None => get_string(flag, args, &mut idx),
+        };
+
+        builder = match flag {
+            "-n" | "--numwords" => {
+                words = Some(parse_number(flag, value()?)?);
+                builder
+            },
+            "-c" | "--count" => builder.number(parse_number(flag, value()?)?),
+            "-d" | "--delimiter" => builder.separator(value()?),
+            "--min" => {
+                min = parse_number(flag, value()?)?;
+                builder
+            },
+            "--max" => {
+                max = parse_number(flag, value()?)?;
+                builder
+            },
+            "-w" | "--wordfile" => {
+                let path = value()?;
+
+                if !Path::new(path).exists() {
+                    return Err(usage!(
+                        "word file {:?} not found, the word lists bundled with xkcdpass are not available in \
+                        compat mode, pass the path of a file",
+                        path
+                    ));
+                }
+
+                builder.wordlist_file(path)
+            },
+            "-a" | "--acrostic" => {
+                acrostic = true;
+                builder.acrostic(value()?)
+            },
+            "-R" | "--random-delimiters" => {
+                random = true;
+                builder
+            },
+            "-D" | "--valid-delimiters" => {
+                delimiters = value()?;
+                builder
+            },
+            "-V" | "--verbose" => builder.report(true),
+            "-h" | "--help" => {
+                print_help(&mut io::stdout().lock())?;
+                return Ok(None);
+            },
+            _ => return Err(unsupported(flag)),
+        };
+    }
+
+    // xkcdpass only uses the valid delimiters together with -R, and ignores the word count for an acrostic
+    if random {
+        builder = builder.sep_set(delimiters);
+    }
+
+    if let Some(words) = words.filter(|_| !acrostic) {
+        builder = builder.count(words);
+    }
+
+    builder.min_word_length(min).max_word_length(max).build().map(Some)
+}
+
+
+/* -------------------- *
+ *     BUILD FILTER     *
+ * -------------------- */
+fn build_filter(args: &[String]) -> Result<(), PassGenError> {
+    let mut fp_rate = FP_RATE;
+    let mut paths = Vec::new();
+
+    let mut idx = 0;
+    while let Some(arg) = args.get(idx).map(String::as_str) {
+        idx += 1;
+
+        match arg {
+            "--fp-rate" => {
+                let str = get_string(arg, args, &mut idx)?;
+
+                fp_rate = match str.parse::<f64>() {
+                    Ok(rate) if rate > 0.0 && rate < 1.0 => rate,
+                    _ => return Err(usage!("invalid argument to {:?}, expected a rate between 0 and 1 got {:?}", arg, str)),
+                };
+            },
+            _ if arg.starts_with('-') => return Err(usage!("invalid option {:?}", arg)),
+            _ => paths.push(arg),
+        }
+    }
+
+    let (input, output) = match paths[..] {
+        [input, output] => (input, output),
+        _ => return Err(usage!("usage: pass-gen build-filter [--fp-rate <p>] <hash list> <output>")),
+    };
+
+    let filter = BreachFilter::from_hash_list(|| File::open(input).map(BufReader::new), fp_rate)?;
+
+    let write_error = |source| PassGenError::Io { action: "writing filter file", path: Some(output.to_string()), source };
+    let mut out = BufWriter::new(File::create(output).map_err(write_error)?);
+
+    filter.write_to(&mut out).and_then(|_| out.flush()).map_err(write_error)?;
+
+    writeln!(io::stdout().lock(), "{} hashes, false positive rate {}", filter.items(), filter.fp_rate())?;
+
+    Ok(())
+}
+
+
+/* -------------------- *
+ *        CHECK         *
+ * -------------------- */
+// returns whether the password passes every rule
+fn check_policy(args: &[String]) -> Result<bool, PassGenError> {
+    let mut path = None;
+    let mut quiet = false;
+
+    let mut idx = 0;
+    while let Some(arg) = args.get(idx).map(String::as_str) {
+        idx += 1;
+
+        match arg {
+            "--policy" => path = Some(get_string(arg, args, &mut idx)?),
+            "-q" | "--quiet" => quiet = true,
+            _ => return Err(usage!("invalid option {:?}", arg)),
+        }
+    }
+
+    let path = match path {
+        Some(path) => path,
+        None => return Err(usage!("usage: pass-gen check --policy <path> [--quiet]")),
+    };
+
+    let policy = Policy::from_path(Path::new(path))?;
+
+    let mut password = read_secret("password: ")
+        .map_err(|source| PassGenError::Io { action: "reading password", path: None, source })?;
+
+    // the same rules generation re-draws against
+    let results: Vec<(String, bool)> =
+        policy.rules().iter().map(|rule| (rule.to_string(), rule.check(&password))).collect();
+
+    wipe_string(&mut password);
+
+    if !quiet {
+        let mut out = io::stdout().lock();
+
+        for (rule, passed) in &results {
+            writeln!(out, "{}  {}", if *passed { "pass" } else { "FAIL" }, rule)?;
+        }
+
+        let failed = results.iter().filter(|(_, passed)| !passed).count();
+        writeln!(out, "{} of {} rules passed", results.len() - failed, results.len())?;
+    }
+
+    Ok(results.iter().all(|(_, passed)| *passed))
+}
+
+
+/* -------------------- *
+ *   VERIFY CHECKSUM    *
+ * -------------------- */
+// returns whether the last word matches the checksum of the others
+fn verify_checksum(args: &[String]) -> Result<bool, PassGenError> {
+    let mut preset = None;
+    let mut file = None;
+    let mut sep = None;
+    let mut whitespace = None;
+    let mut join = None;
+    let mut quiet = false;
+
+    let mut idx = 0;
+    while let Some(arg) = args.get(idx).map(String::as_str) {
+        idx += 1;
+
+        match arg {
+            "-p" | "--preset" => preset = Some(get_string(arg, args, &mut idx)?),
+            "-f" | "--file" => file = Some(get_string(arg, args, &mut idx)?),
+            "-s" | "--sep" => sep = Some(get_string(arg, args, &mut idx)?),
+            "--whitespace" => whitespace = Some(parse_whitespace(get_string(arg, args, &mut idx)?)?),
+            "--whitespace-join" => join = Some(get_string(arg, args, &mut idx)?.to_string()),
+            "-q" | "--quiet" => quiet = true,
+            _ => return Err(usage!("invalid option {:?}", arg)),
+        }
+    }
+
+    let name = preset.unwrap_or("word");
+    let preset = match pass_gen::preset(name) {
+        Some(preset) => preset,
+        None => return Err(usage!("invalid preset {:?}, expected one of {}", name, PRESETS.join(", "))),
+    };
+
+    let sep = sep.unwrap_or(preset.token_sep);
+
+    let whitespace = Config::whitespace_policy(whitespace, join)?;
+
+    let words = match file {
+        Some(path) => Config::load_file(path, &whitespace.unwrap_or_default(), None)?.0,
+        None if whitespace.is_some() => return Err(usage!("--whitespace and --whitespace-join only apply to --file")),
+        None => preset.token_data,
+    };
+
+    if sep.is_empty() {
+        return Err(usage!("the passphrase cannot be split without a separator, pass the one it was generated with"));
+    }
+
+    let mut passphrase = read_secret("passphrase: ")
+        .map_err(|source| PassGenError::Io { action: "reading passphrase", path: None, source })?;
+
+    // hand copies may differ in case and spacing, the checksum ignores both
+    let valid = {
+        let tokens: Vec<&str> = match sep.trim().is_empty() {
+            true => passphrase.split_whitespace().collect(),
+            false => passphrase.trim().split(sep).map(str::trim).collect(),
+        };
+
+        pass_gen::verify_checksum(&tokens, &words)
+    };
+
+    wipe_string(&mut passphrase);
+
+    if !quiet {
+        let result = match valid {
+            true => "checksum ok",
+            false => "checksum mismatch, a word is mistyped, missing or out of order",
+        };
+
+        writeln!(io::stdout().lock(), "{}", result)?;
+    }
+
+    Ok(valid)
+}
+
+
+/* -------------------- *
+ *     VERIFY TOKEN     *
+ * -------------------- */
+// returns whether the token is well formed and its checksum matches
+fn verify_token(args: &[String]) -> Result<bool, PassGenError> {
+    let mut prefix = None;
+    let mut token = None;
+    let mut quiet = false;
+
+    let mut idx = 0;
+    while let Some(arg) = args.get(idx).map(String::as_str) {
+        idx += 1;
+
+        match arg {
+            "--token-prefix" => prefix = Some(get_string(arg, args, &mut idx)?),
+            "-q" | "--quiet" => quiet = true,
+            _ if arg.starts_with('-') => return Err(usage!("invalid option {:?}", arg)),
+            _ if token.is_some() => return Err(usage!("verify-token takes one token")),
+            _ => token = Some(arg),
+        }
+    }
+
+    let token = match token {
+        Some(token) => token,
+        None => return Err(usage!("verify-token needs the token to check")),
+    };
+
+    let verified = verify_api_token(token).and_then(|shape| match prefix {
+        Some(prefix) if shape.prefix() != prefix => Err(PassGenError::InvalidData(format!(
+            "token prefix {:?} is not {:?}",
+            shape.prefix(),
+            prefix
+        ))),
+        _ => Ok(shape),
+    });
+
+    if !quiet {
+        let mut out = io::stdout().lock();
+
+        match &verified {
+            Ok(shape) => writeln!(
+                out,
+                "token ok: prefix {}, {} {} characters of payload",
+                shape.prefix(),
+                shape.payload_len(),
+                shape.alphabet().name()
+            )?,
+            Err(err) => writeln!(out, "token invalid: {}", err)?,
+        }
+    }
+
+    Ok(verified.is_ok())
+}
+
+
+/* -------------------- *
+ *      VERIFY KEY      *
+ * -------------------- */
+// returns whether the key is well formed and its check character matches
+fn verify_key(args: &[String]) -> Result<bool, PassGenError> {
+    let mut group = None;
+    let mut groups = None;
+    let mut key = None;
+    let mut quiet = false;
+
+    let mut idx = 0;
+    while let Some(arg) = args.get(idx).map(String::as_str) {
+        idx += 1;
+
+        match arg {
+            "--group" => group = Some(get_number(arg, args, &mut idx)? as usize),
+            "--key-groups" => groups = Some(get_number(arg, args, &mut idx)? as usize),
+            "-q" | "--quiet" => quiet = true,
+            _ if arg.starts_with('-') => return Err(usage!("invalid option {:?}", arg)),
+            _ if key.is_some() => return Err(usage!("verify-key takes one key")),
+            _ => key = Some(arg),
+        }
+    }
+
+    let key = match key {
+        Some(key) => key,
+        None => return Err(usage!("verify-key needs the key to check")),
+    };
+
+    let verified = verify_license_key(key).and_then(|shape| {
+        let expected = (group.unwrap_or(shape.group()), groups.unwrap_or(shape.groups()));
+
+        match (shape.group(), shape.groups()) == expected {
+            true => Ok(shape),
+            false => Err(PassGenError::InvalidData(format!(
+                "key has {} groups of {} characters, expected {} of {}",
+                shape.groups(),
+                shape.group(),
+                expected.1,
+                expected.0
+            ))),
+        }
+    });
+
+    if !quiet {
+        let mut out = io::stdout().lock();
+
+        match &verified {
+            Ok(shape) => writeln!(out, "key ok: {} groups of {} characters", shape.groups(), shape.group())?,
+            Err(err) => writeln!(out, "key invalid: {}", err)?,
+        }
+    }
+
+    Ok(verified.is_ok())
+}
+
+
+/* -------------------- *
+ *        SHARES        *
+ * -------------------- */
+fn combine(args: &[String]) -> Result<(), PassGenError> {
+    if let Some(arg) = args.first() {
+        return Err(usage!("invalid argument {:?} for combine, the shares are read from stdin", arg));
+    }
+
+    let mut input = String::new();
+    io::Read::read_to_string(&mut io::stdin(), &mut input)
+        .map_err(|source| PassGenError::Io { action: "reading shares", path: None, source })?;
+
+    let shares: Result<Vec<Share>, PassGenError> = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("share "))
+        .map(Share::decode)
+        .collect();
+
+    wipe_string(&mut input);
+
+    let mut secret = pass_gen::combine_shares(&shares?)?;
+    let mut out = io::stdout().lock();
+    let res = out.write_all(&secret).and_then(|_| out.write_all(b"\n"));
+
+    wipe(&mut secret);
+    Ok(res?)
+}
+
+
+/* -------------------- *
+ *      ENCRYPTION      *
+ * -------------------- */
+// the output as gpg armors it for the recipients, the plaintext only reaches gpg's stdin
+fn write_encrypted(config: &Config, secrets: &Secrets, out: &mut impl Write) -> Result<(), PassGenError> {
+    let gpg_error = |source| PassGenError::Io { action: "running gpg", path: None, source };
+
+    let mut gpg = Command::new("gpg");
+    gpg.args(["--batch", "--encrypt", "--armor"]);
+
+    for recipient in config.encrypt_to() {
+        gpg.args(["--recipient", recipient]);
+    }
+
+    let mut child = gpg.stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped()).spawn().map_err(gpg_error)?;
+
+    let stdin = child.stdin.take().expect("stdin is piped");
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let mut stderr = child.stderr.take().expect("stderr is piped");
+
+    // gpg writes while it reads, so its output is drained alongside
+    let (written, armor, messages) = thread::scope(|scope| {
+        let armor = scope.spawn(move || {
+            let mut armor = Vec::new();
+            io::Read::read_to_end(&mut stdout, &mut armor).map(|_| armor)
+        });
+        let messages = scope.spawn(move || {
+            let mut messages = String::new();
+            io::Read::read_to_string(&mut stderr, &mut messages).map(|_| messages)
+        });
+
+        let mut tee = Tee { gpg: WipingWriter::new(stdin), plain: config.also_plain().then_some(&mut *out) };
+        let written = write_passwords(config, secrets, get_term_width(), &mut tee).and_then(|_| Ok(tee.flush()?));
+
+        // closes gpg's stdin
+        drop(tee);
+
+        (written, armor.join().unwrap(), messages.join().unwrap())
+    });
+
+    let status = child.wait().map_err(gpg_error)?;
+
+    // a failing gpg closes its stdin early, its own message says why
+    if !status.success() {
+        let messages = messages.unwrap_or_default();
+        let msg = format!("gpg failed with {}: {}", status, messages.trim().replace('\n', "; "));
+
+        return Err(PassGenError::Io { action: "encrypting", path: None, source: io::Error::other(msg) });
+    }
+
+    written?;
+
+    let armor = armor.map_err(gpg_error)?;
+
+    if config.also_plain() {
+        out.write_all(b"\n\n")?;
+    }
+
+    Ok(out.write_all(&armor)?)
+}
+
+// everything written goes to gpg, and to the output as well with --also-plain
+struct Tee<'a, A: Write, B: Write> {
+    gpg: A,
+    plain: Option<&'a mut B>,
+}
+
+impl<A: Write, B: Write> Write for Tee<'_, A, B> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.gpg.write_all(data)?;
+
+        if let Some(plain) = &mut self.plain {
+            plain.write_all(data)?;
+        }
+
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.gpg.flush()?;
+
+        match &mut self.plain {
+            Some(plain) => plain.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+
+/* -------------------- *
+ *       REQUESTS       *
+ * -------------------- */
+// flags that read stdin or descriptors, write anywhere but the answer or end the process
+const REQUEST_UNAVAILABLE: &[&str] = &[
+    "--help", "--generate-man", "--output", "--paranoid", "--derive", "--master-fd", "--estimate", "--compare",
+    "--history-prune", "--decode-bits", "--report", "--report-only", "--verbose", "--stats", "--batch",
+    "--batch-strict", "--vertical", "--no-numbers", "--split", "--also-plain", "--tty-echo", "--pepper",
+    "--pepper-fd", "--confirm-reveal", "--encrypt-to", "--age-recipient", "--age-recipients-file", "--spec",
+];
+
+// a request line of the agent or of --batch, parsed after the base arguments like the command line
+fn request_config(base: &[String], request: &str, mode: &str) -> Result<Config, PassGenError> {
+    let mut args = base.to_vec();
+    args.extend(split_request(request)?);
+
+    let options = options();
+
+    let mut idx = 1;
+    while let Some(flag) = args.get(idx) {
+        idx += 1;
+
+        if let Some(opt) = options.iter().find(|opt| opt.matches(flag)) {
+            if REQUEST_UNAVAILABLE.contains(&opt.long) {
+                return Err(usage!("{} is not available in {} requests", opt.long, mode));
+            }
+
+            if opt.long == "--file" && args.get(idx).is_some_and(|path| path == "-") {
+                return Err(usage!("--file - is not available in {} requests, stdin is not theirs to read", mode));
+            }
+
+            // skip the argument, a separator like "-v" is not a flag
+            if opt.arg.is_some_and(|arg| !arg.starts_with('[')) {
+                idx += 1;
+            }
+        }
+    }
+
+    let builder = parse_args(&args)?.expect("--help and --generate-man are not available in requests");
+
+    // the answer is read as lines, never laid out in columns for our own terminal
+    builder.one_per_line(true).build()
+}
+
+// writes the passwords of a request and a newline
+fn answer_request(config: &Config, out: impl Write) -> Result<(), PassGenError> {
+    config.check_min_entropy()?;
+
+    // nothing is sent unless the whole request succeeds, short of filling the buffer
+    let mut out = WipingWriter::new(out);
+
+    write_passwords(config, &Secrets::default(), get_term_width(), &mut out)?;
+
+    if config.format() == Format::Plain {
+        out.write_all(b"\n")?;
+    }
+
+    out.flush()?;
+
+    match config.audit_log() {
+        Some(log) => log.record(config),
+        None => Ok(()),
+    }
+}
+
+// splits a request into arguments like a shell, with '' and "" quotes and \ escapes
+fn split_request(request: &str) -> Result<Vec<String>, PassGenError> {
+    let mut args = Vec::new();
+    let mut arg: Option<String> = None;
+    let mut quote = None;
+    let mut chars = request.chars();
+
+    while let Some(ch) = chars.next() {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (None | Some('"'), '\\') => match chars.next() {
+                Some(next) => arg.get_or_insert_with(String::new).push(next),
+                None => return Err(usage!("request ends in an escape")),
+            },
+            (Some(_), _) => arg.get_or_insert_with(String::new).push(ch),
+            (None, '\'' | '"') => {
+                // "" is an empty argument
+                arg.get_or_insert_with(String::new);
+                quote = Some(ch);
+            },
+            (None, _) if ch.is_whitespace() => args.extend(arg.take()),
+            (None, _) => arg.get_or_insert_with(String::new).push(ch),
+        }
+    }
+
+    if quote.is_some() {
+        return Err(usage!("unterminated quote in request"));
+    }
 
-macro_rules! error {
-    ($($x:expr),*) => {{
-        eprintln!("pass-gen: {}", format!($($x,)*));
-        exit(1);
-    }}
+    args.extend(arg);
+    Ok(args)
 }
 
 
+
 /* -------------------- *
- *      TOKEN DATA      *
+ *        AGENT         *
  * -------------------- */
-#[derive(Debug)]
-enum TokenData {
-    Static(&'static [&'static str]),
-    Owned(Vec<String>),
+
+#[cfg(not(unix))]
+fn agent(_args: &[String]) -> Result<(), PassGenError> {
+    Err(usage!("agent mode needs unix domain sockets, which this platform lacks"))
 }
 
-impl TokenData {
-    fn get(&self, idx: usize) -> &str {
-        match self {
-            TokenData::Static(x) => x[idx],
-            TokenData::Owned(x) => &x[idx],
+#[cfg(unix)]
+fn agent(args: &[String]) -> Result<(), PassGenError> {
+    use std::os::unix::{fs::FileTypeExt, net::{UnixListener, UnixStream}};
+
+    let mut socket = None;
+    let mut max_requests = None;
+    let mut idle_timeout = AGENT_IDLE_TIMEOUT;
+
+    let mut idx = 0;
+    while let Some(arg) = args.get(idx).map(String::as_str) {
+        idx += 1;
+
+        match arg {
+            "--socket" => socket = Some(get_string(arg, args, &mut idx)?),
+            "--max-requests" => max_requests = Some(get_number(arg, args, &mut idx)?),
+            "--idle-timeout" => idle_timeout = get_number(arg, args, &mut idx)?,
+            _ => return Err(usage!("invalid option {:?}", arg)),
+        }
+    }
+
+    let path = match socket {
+        Some(path) => path,
+        None => {
+            return Err(usage!("usage: pass-gen agent --socket <path> [--max-requests <n>] [--idle-timeout <secs>]"));
+        },
+    };
+
+    let io_error = |action| move |source| PassGenError::Io { action, path: Some(path.to_string()), source };
+
+    // a socket left behind by an agent that died is replaced, a live one or any other file is not
+    if let Ok(meta) = std::fs::symlink_metadata(path) {
+        if !meta.file_type().is_socket() {
+            return Err(usage!("{:?} exists and is not a socket", path));
+        }
+
+        if UnixStream::connect(path).is_ok() {
+            return Err(usage!("another agent is listening on {:?}", path));
         }
+
+        std::fs::remove_file(path).map_err(io_error("removing stale agent socket"))?;
     }
 
-    fn len(&self) -> usize {
-        match self {
-            TokenData::Static(x) => x.len(),
-            TokenData::Owned(x) => x.len(),
+    // the socket is created 0600 rather than changed after, so no other user can connect in between
+    // SAFETY: umask only swaps the file mode mask of our single threaded process and is restored right away
+    let mask = unsafe { libc::umask(0o177) };
+    let listener = UnixListener::bind(path);
+    unsafe { libc::umask(mask) };
+
+    let listener = listener.map_err(io_error("binding agent socket"))?;
+    let idle = Duration::from_secs(idle_timeout as u64);
+    let mut served = 0;
+
+    let res = loop {
+        if max_requests.is_some_and(|max| served >= max) {
+            break Ok(());
+        }
+
+        match wait_for_client(&listener, idle) {
+            Ok(true) => {},
+            Ok(false) => break Ok(()),
+            Err(source) => break Err(io_error("waiting for agent clients")(source)),
+        }
+
+        let stream = match listener.accept() {
+            Ok((stream, _)) => stream,
+            Err(source) => break Err(io_error("accepting agent client")(source)),
+        };
+
+        // clients are served one at a time, one that stops talking is dropped after the idle timeout
+        if stream.set_read_timeout(Some(idle)).is_ok() {
+            served += serve_client(&stream, max_requests.map(|max| max - served));
+        }
+    };
+
+    let _ = std::fs::remove_file(path);
+    res
+}
+
+// waits for the next connection, false once the timeout passes without one
+#[cfg(unix)]
+fn wait_for_client(listener: &std::os::unix::net::UnixListener, timeout: Duration) -> io::Result<bool> {
+    use std::os::unix::io::AsRawFd;
+
+    let mut fd = libc::pollfd { fd: listener.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+    let millis = timeout.as_millis().min(i32::MAX as u128) as i32;
+
+    loop {
+        // SAFETY: fd is a single valid pollfd for the duration of the call
+        match unsafe { libc::poll(&mut fd, 1, millis) } {
+            -1 if io::Error::last_os_error().kind() == ErrorKind::Interrupted => continue,
+            -1 => return Err(io::Error::last_os_error()),
+            ready => return Ok(ready > 0),
         }
     }
+}
+
+// answers requests until the client hangs up, returns how many were served
+#[cfg(unix)]
+fn serve_client(stream: &std::os::unix::net::UnixStream, max_requests: Option<u32>) -> u32 {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    let mut served = 0;
+
+    while max_requests.is_none_or(|max| served < max) {
+        line.clear();
+
+        let len = match io::Read::take(&mut reader, AGENT_MAX_REQUEST).read_line(&mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(len) => len,
+        };
+
+        served += 1;
+
+        let too_long = len as u64 == AGENT_MAX_REQUEST && !line.ends_with('\n');
+
+        let res = match too_long {
+            true => Err(usage!("requests are limited to {} bytes", AGENT_MAX_REQUEST)),
+            false => agent_request(line.trim_end_matches(['\r', '\n']), stream),
+        };
+
+        let mut out = stream;
+        let reply = match res {
+            Ok(()) => Ok(()),
+            Err(err) => writeln!(out, "error: {}", err),
+        };
 
-    fn range(&self) -> Range<usize> {
-        0..self.len()
+        // the rest of an oversized request would be read as requests of its own
+        if reply.is_err() || too_long {
+            break;
+        }
     }
+
+    served
 }
 
+// one request, generated exactly like the same flags on the command line
+#[cfg(unix)]
+fn agent_request(request: &str, stream: &std::os::unix::net::UnixStream) -> Result<(), PassGenError> {
+    let config = request_config(&[String::from("pass-gen")], request, "agent")?;
+
+    answer_request(&config, stream)
+}
 
 /* -------------------- *
- *        CONFIG        *
+ *        BATCH         *
  * -------------------- */
-#[derive(Debug)]
-struct Config<'a> {
-    report: bool,
-    token_count: u32,
-    token_sep: &'a str,
-    token_data: TokenData,
-}
+// every line is answered by exactly one line, empty when the request fails
+fn batch(args: &[String], strict: bool) -> Result<i32, PassGenError> {
+    let base: Vec<String> =
+        args.iter().filter(|arg| !matches!(arg.as_str(), "--batch" | "--batch-strict")).cloned().collect();
+
+    // flags of the command line that cannot make a line of their own fail before any input is read
+    batch_config(&base, "")?;
+
+    let mut out = io::stdout().lock();
+    let mut failed = None;
+
+    for (nr, line) in io::stdin().lock().lines().enumerate() {
+        let line = line.map_err(|source| PassGenError::Io { action: "reading batch requests", path: None, source })?;
+
+        let err = match batch_config(&base, &line).and_then(|config| answer_request(&config, &mut out)) {
+            Ok(()) => continue,
+            Err(err @ PassGenError::Output(_)) => return Err(err),
+            Err(err) => err,
+        };
+
+        eprintln!("pass-gen: line {}: {}", nr + 1, err);
 
-impl<'a> Default for Config<'a> {
-    fn default() -> Self {
-        Config {
-            report: false,
-            token_count: data::word::TOKEN_COUNT,
-            token_sep: data::word::TOKEN_SEP,
-            token_data: TokenData::Static(&data::word::TOKEN_DATA),
+        if strict {
+            return Ok(exit_code(&err));
         }
+
+        out.write_all(b"\n")?;
+        out.flush()?;
+        failed = failed.or(Some(exit_code(&err)));
+    }
+
+    // the status of the first failed line, once every line is answered
+    Ok(failed.unwrap_or(EXIT_SUCCESS))
+}
+
+fn batch_config(base: &[String], line: &str) -> Result<Config, PassGenError> {
+    let config = request_config(base, line, "batch")?;
+
+    if config.number() != 1 || config.format() != Format::Plain {
+        return Err(usage!("a batch line makes exactly one password, without --number, --csv or --json"));
     }
+
+    Ok(config)
 }
 
-impl<'a> Config<'a> {
-    fn new(args: &'a [String]) -> Self {
-        let mut config = Config::default();
 
-        let mut idx = 1;
-        while let Some(flag) = args.get(idx).map(String::as_str) {
+/* -------------------- *
+ *         SPEC         *
+ * -------------------- */
+// flags of the command line that shape the whole set rather than each entry
+const SPEC_OWN: &[&str] = &["--spec", "--output", "--json"];
+
+// every entry is generated before anything is written, so a failing one leaves no partial set behind
+fn spec(args: &[String], path: &str, config: &Config) -> Result<(), PassGenError> {
+    let options = options();
+    let mut base = Vec::new();
+
+    let mut idx = 0;
+    while let Some(arg) = args.get(idx) {
+        idx += 1;
+
+        let opt = options.iter().find(|opt| idx > 1 && opt.matches(arg));
+        let takes_arg = opt.is_some_and(|opt| opt.arg.is_some_and(|arg| !arg.starts_with('[')));
+
+        match opt {
+            Some(opt) if SPEC_OWN.contains(&opt.long) => {},
+            _ => {
+                base.push(arg.clone());
+                base.extend(args.get(idx).filter(|_| takes_arg).cloned());
+            },
+        }
+
+        // the argument stays with its flag, even one like "--json"
+        if takes_arg {
             idx += 1;
+        }
+    }
 
-            match flag {
-                "-r" | "--report" => {
-                    config.report = true;
-                }
-                "-c" | "--count" => {
-                    config.token_count = Self::get_number(flag, args, &mut idx);
-                }
-                "-s" | "--sep" => {
-                    config.token_sep = Self::get_string(flag, args, &mut idx);
-                }
-                "-f" | "--file" => {
-                    let path = Self::get_string(flag, args, &mut idx);
+    let text = std::fs::read_to_string(path).map_err(|source| PassGenError::Io {
+        action: "reading spec file",
+        path: Some(path.to_string()),
+        source,
+    })?;
 
-                    config.token_data = match File::open(path) {
-                        Ok(f) => {
-                            TokenData::Owned(BufReader::new(f).lines().map(Result::unwrap).collect())
-                        },
-                        Err(e) => {
-                            error!("error while reading token file: {}", e)
-                        },
-                    }
-                }
-                "-p" | "--preset" => {
-                    let preset = Self::get_string(flag, args, &mut idx);
-
-                    config = match preset {
-                        "ascii" => Self {
-                            report: config.report,
-                            token_count: data::ascii::TOKEN_COUNT,
-                            token_sep: data::ascii::TOKEN_SEP,
-                            token_data: TokenData::Static(&data::ascii::TOKEN_DATA),
-                        },
-                        "number" => Self {
-                            report: config.report,
-                            token_count: data::number::TOKEN_COUNT,
-                            token_sep: data::number::TOKEN_SEP,
-                            token_data: TokenData::Static(&data::number::TOKEN_DATA),
-                        },
-                        "word" => Self {
-                            report: config.report,
-                            token_count: data::word::TOKEN_COUNT,
-                            token_sep: data::word::TOKEN_SEP,
-                            token_data: TokenData::Static(&data::word::TOKEN_DATA),
-                        },
-                        _ => error!("invalid preset {:?}", preset),
-                    }
-                },
-                _ => error!("invalid option {:?}", flag),
-            }
+    let mut entries: Vec<(&str, usize, Config)> = Vec::new();
+
+    for (nr, line) in text.lines().enumerate().map(|(idx, line)| (idx + 1, line.trim())) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (label, flags) = match line.split_once(':') {
+            Some((label, flags)) => (label.trim(), flags),
+            None => return Err(spec_failed(path, nr, usage!("expected \"label: <flags>\", got {:?}", line))),
+        };
+
+        if label.is_empty() || !label.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')) {
+            let err = usage!("invalid label {:?}, expected letters, digits, '_', '-' and '.'", label);
+            return Err(spec_failed(path, nr, err));
+        }
+
+        if let Some((_, first, _)) = entries.iter().find(|(seen, ..)| *seen == label) {
+            return Err(spec_failed(path, nr, usage!("duplicate label {:?}, first used on line {}", label, first)));
         }
 
-        config
+        // a --seed of the command line would draw the same stream for every entry, one of the entry still wins
+        let mut args = base.clone();
+
+        if let Some(seed) = config.seed() {
+            args.extend([String::from("--seed"), seed.wrapping_add(entries.len() as u64).to_string()]);
+        }
+
+        let entry = spec_config(&args, flags)
+            .and_then(|entry| entry.check_min_entropy().map(|_| entry))
+            .map_err(|err| spec_failed(path, nr, err))?;
+
+        entries.push((label, nr, entry));
     }
 
-    fn get_string(flag: &str, args: &'a [String], idx: &mut usize) -> &'a str {
-        if let Some(str) = args.get(*idx) {
-            *idx += 1;
-            str
-        } else {
-            error!("missing argument to {}", flag)
+    if entries.is_empty() {
+        return Err(usage!("spec file {:?} has no entries", path));
+    }
+
+    let mut passwords = Vec::new();
+
+    for (label, nr, entry) in &entries {
+        let mut password = Vec::new();
+
+        if let Err(err) = write_passwords(entry, &Secrets::default(), get_term_width(), &mut password) {
+            for (_, mut password) in passwords {
+                wipe_string(&mut password);
+            }
+
+            return Err(spec_failed(path, *nr, err));
         }
+
+        passwords.push((*label, String::from_utf8(password).expect("passwords are utf-8")));
     }
 
-    fn get_number(flag: &str, args: &'a [String], idx: &mut usize) -> u32 {
-        let str = Self::get_string(flag, args, idx);
-        let int = str.parse();
+    let mut out = WipingWriter::new(open_output(config)?);
+    let json = config.format() == Format::Json;
 
-        if matches!(int, Ok(i) if i > 0) {
-            int.unwrap()
-        } else {
-            error!("invalid argument to {:?}, expected positve number got {:?}", flag, str);
+    if json {
+        out.write_all(b"{")?;
+    }
+
+    for (idx, (label, password)) in passwords.iter_mut().enumerate() {
+        let res = match json {
+            true => {
+                let mut field = json_quote(password);
+                let res = write!(out, "{}\n  {}: {}", if idx != 0 { "," } else { "" }, json_quote(label), field);
+
+                wipe_string(&mut field);
+                res
+            },
+            false => writeln!(out, "{}={}", label, password),
+        };
+
+        wipe_string(password);
+        res?;
+    }
+
+    if json {
+        out.write_all(b"\n}\n")?;
+    }
+
+    out.flush()?;
+
+    for (_, _, entry) in &entries {
+        if let Some(log) = entry.audit_log() {
+            log.record(entry)?;
         }
     }
+
+    Ok(())
+}
+
+// an entry that fails is reported at its line with the status it would have on its own
+fn spec_failed(path: &str, nr: usize, err: PassGenError) -> PassGenError {
+    PassGenError::Line { path: path.to_string(), line: nr, source: Box::new(err) }
+}
+
+fn spec_config(base: &[String], flags: &str) -> Result<Config, PassGenError> {
+    let config = request_config(base, flags, "spec")?;
+
+    if config.number() != 1 || config.format() != Format::Plain {
+        return Err(usage!("a spec entry makes exactly one password, without --number, --csv or --json"));
+    }
+
+    Ok(config)
 }
 
 
 /* -------------------- *
- *       REPORTER       *
+ *        BENCH         *
  * -------------------- */
-struct Reporter {
-    pool_size: f64,
-    token_count: f64,
+// flags that make no sense without output, or print something on every round
+const BENCH_UNAVAILABLE: &[&str] =
+    &["--number", "--history", "--audit-log", "--check-offline", "--no-dictionary-substrings"];
+
+// passwords per job and round, the clock is checked between rounds
+const BENCH_ROUND: u32 = 1024;
+
+// counts what would have been written, black_box keeps the formatting from being optimized away
+#[derive(Default)]
+struct Discard {
+    bytes: u64,
+}
+
+impl Write for Discard {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        hint::black_box(data);
+        self.bytes += data.len() as u64;
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+struct BenchResult {
+    parameters: String,
+    jobs: u32,
+    setup: Duration,
+    elapsed: Duration,
+    passwords: u64,
+    bytes: u64,
+    // nanoseconds per password on one thread, the randomness only where it can be drawn on its own
+    rng: Option<f64>,
+    assembly: f64,
+    formatting: f64,
 }
 
-impl Reporter {
-    fn new(pool_size: f64, token_count: f64) -> Self {
-        Self { pool_size, token_count }
+fn bench(args: &[String]) -> Result<(), PassGenError> {
+    let mut seconds = BENCH_SECONDS;
+    let mut jobs = 1;
+    let mut json = false;
+    let mut rest = vec![String::from("pass-gen")];
+
+    let options = options();
+
+    let mut idx = 0;
+    while let Some(arg) = args.get(idx) {
+        idx += 1;
+
+        match arg.as_str() {
+            "--seconds" => {
+                let str = get_string(arg, args, &mut idx)?;
+
+                seconds = match str.parse::<f64>() {
+                    Ok(seconds) if seconds > 0.0 && seconds.is_finite() => seconds,
+                    _ => return Err(usage!("invalid argument to {:?}, expected positive number got {:?}", arg, str)),
+                };
+            },
+            "--json" => json = true,
+            flag => {
+                rest.push(arg.clone());
+
+                let opt = match options.iter().find(|opt| opt.matches(flag)) {
+                    Some(opt) => opt,
+                    None => continue,
+                };
+
+                if REQUEST_UNAVAILABLE.contains(&opt.long) || BENCH_UNAVAILABLE.contains(&opt.long) {
+                    return Err(usage!("{} is not available in bench", opt.long));
+                }
+
+                // every thread gets a round of its own
+                if let ("--jobs", Some(str)) = (opt.long, args.get(idx)) {
+                    jobs = parse_number(flag, str)?;
+                }
+
+                // the argument goes along, a separator like "--json" is not a flag
+                if opt.arg.is_some_and(|arg| !arg.starts_with('[')) && !flag.contains('=') {
+                    rest.extend(args.get(idx).cloned());
+                    idx += 1;
+                }
+            },
+        }
+    }
+
+    // loading word files is timed on its own
+    let start = Instant::now();
+    let builder = parse_args(&rest)?.expect("--help and --generate-man are not available in bench");
+    let config = builder.number(BENCH_ROUND * jobs).one_per_line(true).build()?;
+    let setup = start.elapsed();
+
+    let result = run_bench(&config, setup, Duration::from_secs_f64(seconds))?;
+    let mut out = io::stdout().lock();
+
+    match json {
+        true => print_bench_json(&result, &mut out)?,
+        false => print_bench(&result, &mut out)?,
     }
 
-    fn print_report(&self) {
-        let entropy = self.pool_size.log2();
-        let total_entropy = entropy * self.token_count;
+    Ok(())
+}
+
+fn run_bench(config: &Config, setup: Duration, budget: Duration) -> Result<BenchResult, PassGenError> {
+    let mut sink = Discard::default();
+    let mut passwords = 0;
+    let start = Instant::now();
 
-        eprintln!("entropy per word:           {:.1} bits", entropy);
-        eprintln!("total entropy:              {:.0} bits", total_entropy);
-        eprintln!("guess times:");
-        eprintln!("  1 billion / second:       {}", Self::format_time((total_entropy - 31.0).exp2()));
-        eprintln!("  1 quadrillion / second:   {}", Self::format_time((total_entropy - 51.0).exp2()));
-        eprintln!("  1 sextillion / second:    {}", Self::format_time((total_entropy - 71.0).exp2()));
-        eprintln!("{}", "-".repeat(Self::get_term_width()));
+    // the same path as a batch of passwords, threads and re-draws included
+    while passwords == 0 || start.elapsed() < budget {
+        write_passwords(config, &Secrets::default(), get_term_width(), &mut sink)?;
+        passwords += config.number() as u64;
     }
 
-    fn format_time(t: f64) -> String {
-        match () {
-            _ if t < 1.0     => String::from("less than a second"),
-            _ if t < MINUTE  => Self::format_unit(t, "seconds"),
-            _ if t < HOUR    => Self::format_unit(t / MINUTE, "minutes"),
-            _ if t < DAY     => Self::format_unit(t / HOUR, "hours"),
-            _ if t < YEAR    => Self::format_unit(t / DAY, "days"),
-            _ if t < CENTURY => Self::format_unit(t / YEAR, "years"),
-            _ => Self::format_unit(t / CENTURY, "centuries"),
+    let elapsed = start.elapsed();
+
+    // a tenth of the time again for each step of the breakdown
+    let step = budget / 10;
+    let mut draws = StdRng::from_entropy();
+
+    let rng_only = config.template().is_none()
+        && config.fixed_length().is_none()
+        && config.sentence().is_none()
+        && config.packing().is_none()
+        && config.acrostic().is_none()
+        && config.leet().is_none()
+        && config.random_case().is_none()
+        && config.mutate().is_none();
+
+    let rng = match rng_only {
+        true => Some(time_per_call(step, || {
+            let seps = if config.sep_set().is_empty() { 0 } else { config.token_count() - 1 };
+
+            for _ in 0..config.token_count() {
+                hint::black_box(draws.gen_range(0..config.token_data().len()));
+            }
+
+            for _ in 0..seps {
+                hint::black_box(draws.gen_range(0..config.sep_set().len()));
+            }
+
+            Ok(())
+        })?),
+        false => None,
+    };
+
+    let mut gen = config.generator(StdRng::from_entropy());
+    let generated = time_per_call(step, || {
+        let mut password = gen.generate();
+
+        hint::black_box(&password);
+        wipe_string(&mut password);
+        Ok(())
+    })?;
+
+    let written = time_per_call(step, || {
+        let mut password = gen.generate();
+        let res = write_entry(config, &Secrets::default(), &mut sink, 1, &password, None);
+
+        wipe_string(&mut password);
+        res
+    })?;
+
+    Ok(BenchResult {
+        parameters: config.parameters(),
+        jobs: config.jobs(),
+        setup,
+        elapsed,
+        passwords,
+        bytes: sink.bytes,
+        rng,
+        assembly: generated - rng.unwrap_or(0.0),
+        formatting: (written - generated).max(0.0),
+    })
+}
+
+// nanoseconds per call of step, called in small batches until the budget runs out
+fn time_per_call(budget: Duration, mut step: impl FnMut() -> io::Result<()>) -> io::Result<f64> {
+    let start = Instant::now();
+    let mut calls = 0;
+
+    while calls == 0 || start.elapsed() < budget {
+        for _ in 0..16 {
+            step()?;
         }
+
+        calls += 16;
     }
 
-    fn format_unit(x: f64, unit: &str) -> String {
-        if x < 1e6 {
-            format!("{:.0} {}", x, unit)
-        } else {
-            format!("{} {}", format!("{:.0e}", x).replace('e', "e+"), unit)
+    Ok(start.elapsed().as_nanos() as f64 / calls as f64)
+}
+
+fn print_bench(result: &BenchResult, out: &mut impl Write) -> io::Result<()> {
+    let seconds = result.elapsed.as_secs_f64();
+
+    writeln!(out, "configuration: {}", result.parameters)?;
+    writeln!(out, "setup:         {:.3} ms loading the configuration", result.setup.as_secs_f64() * 1e3)?;
+    writeln!(out, "generated:     {} passwords in {:.2} s with {} job(s)", result.passwords, seconds, result.jobs)?;
+    writeln!(
+        out,
+        "throughput:    {:.0} passwords/s, {:.2} MB/s",
+        result.passwords as f64 / seconds,
+        result.bytes as f64 / seconds / 1e6
+    )?;
+
+    match result.rng {
+        Some(rng) => writeln!(
+            out,
+            "per password:  {:.0} ns drawing random numbers, {:.0} ns putting them together, {:.0} ns formatting \
+            (one thread)",
+            rng, result.assembly, result.formatting
+        ),
+        None => writeln!(
+            out,
+            "per password:  {:.0} ns generating, {:.0} ns formatting (one thread)",
+            result.assembly, result.formatting
+        ),
+    }
+}
+
+fn print_bench_json(result: &BenchResult, out: &mut impl Write) -> io::Result<()> {
+    let seconds = result.elapsed.as_secs_f64();
+
+    writeln!(
+        out,
+        "{{\"configuration\": {}, \"jobs\": {}, \"setup_seconds\": {}, \"seconds\": {}, \"passwords\": {}, \
+        \"bytes\": {}, \"passwords_per_second\": {}, \"mb_per_second\": {}, \"per_password_ns\": \
+        {{\"rng\": {}, \"assembly\": {}, \"formatting\": {}}}}}",
+        json_quote(&result.parameters),
+        result.jobs,
+        json_number(result.setup.as_secs_f64()),
+        json_number(seconds),
+        result.passwords,
+        result.bytes,
+        json_number(result.passwords as f64 / seconds),
+        json_number(result.bytes as f64 / seconds / 1e6),
+        result.rng.map_or(String::from("null"), json_number),
+        json_number(result.assembly),
+        json_number(result.formatting)
+    )
+}
+
+
+/* -------------------- *
+ *        WORDS         *
+ * -------------------- */
+// prints the tokens of the pool that pass every query, false if none does
+fn words(args: &[String]) -> Result<bool, PassGenError> {
+    let mut prefix = None;
+    let mut contains = None;
+    let mut length = None;
+    let mut index = None;
+    let mut rest = vec![String::from("pass-gen")];
+
+    let options = options();
+
+    let mut idx = 0;
+    while let Some(arg) = args.get(idx) {
+        idx += 1;
+
+        match arg.as_str() {
+            "--prefix" => prefix = Some(get_string(arg, args, &mut idx)?),
+            "--contains" => contains = Some(get_string(arg, args, &mut idx)?),
+            "--of-length" => length = Some(get_number(arg, args, &mut idx)? as usize),
+            "--index" => index = Some(get_number(arg, args, &mut idx)? as usize),
+            flag => {
+                rest.push(arg.clone());
+
+                let opt = match options.iter().find(|opt| opt.matches(flag)) {
+                    Some(opt) => opt,
+                    None => continue,
+                };
+
+                if opt.arg.is_some_and(|arg| !arg.starts_with('[')) && !flag.contains('=') {
+                    rest.extend(args.get(idx).cloned());
+                    idx += 1;
+                }
+            },
         }
     }
 
-    fn get_term_width() -> usize {
-        Command::new("tput")
-            .arg("cols")
-            .stderr(Stdio::inherit())
-            .output()
-            .map(|o| String::from_utf8(o.stdout).unwrap().trim().parse().unwrap())
-            .unwrap_or(80)
+    // the pool goes through the same files, filters and dedup as for generating
+    let config = match parse_config(&rest)? {
+        Some(config) => config,
+        None => return Ok(true),
+    };
+
+    let pool = config.token_data();
+
+    if let PoolSource::Size(_) = config.pool_source() {
+        return Err(usage!("--pool-size has no tokens to list"));
+    }
+
+    let range = match index {
+        Some(index) if index > pool.len() => {
+            return Err(usage!("index {} is beyond the pool of {} tokens", index, pool.len()));
+        },
+        Some(index) => index - 1..index,
+        None => pool.range(),
+    };
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    let mut found = false;
+
+    for token in range.map(|idx| pool.get(idx)) {
+        let matches = prefix.is_none_or(|prefix| token.starts_with(prefix))
+            && contains.is_none_or(|contains| token.contains(contains))
+            && length.is_none_or(|length| grapheme_len(token) == length);
+
+        if matches {
+            writeln!(out, "{}", token)?;
+            found = true;
+        }
     }
+
+    out.flush()?;
+    Ok(found)
 }
 
 
@@ -222,30 +2755,248 @@ impl Reporter {
  *         MAIN         *
  * -------------------- */
 fn main() {
-    // parse config
     let args: Vec<String> = args().collect();
-    let config = Config::new(&args);
+
+    match run(&args) {
+        Ok(EXIT_SUCCESS) => {},
+        Ok(code) => exit(code),
+        // behave like standard unix tools when the reader goes away
+        Err(PassGenError::Output(e)) if e.kind() == ErrorKind::BrokenPipe => exit(EXIT_SUCCESS),
+        Err(err) => {
+            eprintln!("pass-gen: {}", err);
+            exit(exit_code(&err));
+        },
+    }
+}
+
+// the exit status, errors are left to main
+fn run(args: &[String]) -> Result<i32, PassGenError> {
+    // subcommands
+    match args.get(1).map(String::as_str) {
+        Some("agent") => return agent(&args[2..]).map(|_| EXIT_SUCCESS),
+        Some("build-filter") => return build_filter(&args[2..]).map(|_| EXIT_SUCCESS),
+        Some("check") => return check_policy(&args[2..]).map(status),
+        Some("verify-checksum") => return verify_checksum(&args[2..]).map(status),
+        Some("verify-token") => return verify_token(&args[2..]).map(status),
+        Some("verify-key") => return verify_key(&args[2..]).map(status),
+        Some("combine") => return combine(&args[2..]).map(|_| EXIT_SUCCESS),
+        Some("bench") => return bench(&args[2..]).map(|_| EXIT_SUCCESS),
+        Some("words") => return words(&args[2..]).map(status),
+        _ => {},
+    }
+
+    // parse config, pwgen style when asked to or installed under its name
+    let invoked = args.first().and_then(|arg0| Path::new(arg0).file_name()).and_then(|name| name.to_str());
+
+    let parsed = match (invoked, args.get(1).map(String::as_str)) {
+        (Some("pwgen"), _) => pwgen_config(&args[1..])?,
+        (Some("xkcdpass"), _) => xkcdpass_config(&args[1..])?,
+        (_, Some("--compat")) => match args.get(2).map(String::as_str) {
+            Some("pwgen") => pwgen_config(&args[3..])?,
+            Some("xkcdpass") => xkcdpass_config(&args[3..])?,
+            mode => {
+                let mode = mode.unwrap_or("");
+                return Err(usage!("invalid compatibility mode {:?}, expected pwgen or xkcdpass", mode));
+            },
+        },
+        _ => parse_config(args)?,
+    };
+
+    // --help and --generate-man are done once printed
+    let config = match parsed {
+        Some(config) => config,
+        None => return Ok(EXIT_SUCCESS),
+    };
+
+    // one password per line of stdin
+    if config.batch() {
+        return batch(args, config.batch_strict());
+    }
+
+    // one labeled password per line of a file
+    if let Some(path) = config.spec() {
+        return spec(args, path, &config).map(|_| EXIT_SUCCESS);
+    }
+
+    run_config(config).map(|_| EXIT_SUCCESS)
+}
+
+// everything a single configuration does, from the report to the passwords
+fn run_config(config: Config) -> Result<(), PassGenError> {
+    // harden the process before any secret exists
+    if config.paranoid() {
+        harden();
+    }
+
+    // explain the effective configuration
+    if config.verbose() {
+        write_verbose(&config, &mut io::stderr())?;
+    }
+
+    // history maintenance
+    if let Some(days) = config.history_prune() {
+        let mut history = config.into_history().expect("--history-prune requires --history");
+        let removed = history.prune(days)?;
+
+        eprintln!(
+            "pass-gen: pruned {} password(s) issued more than {} day(s) ago, {} left",
+            removed,
+            days,
+            history.len()
+        );
+
+        return Ok(());
+    }
+
+    // compare presets
+    if let Some(spec) = config.compare() {
+        return write_comparison(spec, get_term_width(), &mut io::stdout());
+    }
+
+    // score an existing passphrase
+    if let Some(phrase) = config.estimate() {
+        let phrase = match phrase {
+            "-" => read_secret("passphrase: ")
+                .map_err(|source| PassGenError::Io { action: "reading passphrase", path: None, source })?,
+            _ => phrase.to_string(),
+        };
+
+        return Ok(write_estimate(&config, &phrase, &terminal(), &mut io::stdout())?);
+    }
+
+    // recover the integer behind a packed passphrase
+    if let (true, Some(packing)) = (config.decode(), config.packing()) {
+        let mut out = WipingWriter::new(open_output(&config)?);
+
+        decode_packed(&config, &packing, &mut out)?;
+
+        return Ok(out.flush()?);
+    }
 
     // print report
-    if config.report {
-        let reporter = Reporter::new(
-            config.token_data.len() as f64,
-            config.token_count as f64,
+    if config.report() {
+        let mut report = EntropyReport::new(
+            config.pool(),
+            config.token_count() as f64,
+            Targets {
+                bits: config.bits().or(config.packing().map(|packing| packing.bits() as f64)),
+                time: config.crack_time().map(|t| (t, config.attack_rate())),
+            },
+            &config.rates(),
         );
 
-        reporter.print_report();
+        report.min_bits = config.min_bits();
+        report.max_length = config.max_length().map(|max| (max, config.max_length_probability()));
+        report.leet = config.leet().map(|leet| leet.probability());
+        report.memorability = match (config.pool_source(), config.identifier()) {
+            (PoolSource::Size(_), _) | (_, Some(_)) => None,
+            _ => Some(config.memorability()),
+        };
+        report.derivation = config.derive().as_ref().map(|d| (d.label.clone(), d.params));
+        report.pepper = config.pepper();
+        report.bar_target = config.bar_target();
+        report.target_hash = config.target_hash().map(TargetHash::to_string);
+        report.username = config.username().as_ref().map(|username| (username.style().name(), username.bits()));
+        if config.recovery_codes() {
+            report.set_recovery_codes(config.number());
+        }
+
+        // the report is the only output in report-only mode
+        if config.report_only() {
+            return Ok(report.write(config.report_format(), &terminal(), &mut io::stdout())?);
+        }
+
+        report.write(config.report_format(), &terminal(), &mut io::stderr())?;
+    }
+
+    // refuse to generate weak passwords
+    config.check_min_entropy()?;
+
+    // the master passphrase is only read once everything else checks out, and the pepper after it
+    let mut secrets = Secrets::default();
+
+    if let Some(derivation) = config.derive() {
+        secrets.seed = Some(derived_seed(derivation)?);
+    }
+
+    if config.pepper() {
+        secrets.pepper = Some(read_pepper(&config)?);
+    }
+
+    // nothing is shown on a terminal until asked for, scripts reading a pipe never wait
+    if config.confirm_reveal() && config.output().is_none() && io::stdout().is_terminal() {
+        confirm_reveal()?;
+    }
+
+    // generate passwords
+    let mut out = WipingWriter::new(open_output(&config)?);
+
+    let res = match (config.encrypt_to().is_empty(), config.age()) {
+        (false, _) => write_encrypted(&config, &secrets, &mut out),
+        #[cfg(feature = "age")]
+        (true, true) => write_age(&config, &secrets, get_term_width(), &mut out),
+        _ => write_passwords(&config, &secrets, get_term_width(), &mut out),
+    };
+
+    res.and_then(|_| Ok(out.flush()?))?;
+
+    // wiped as soon as the passwords are out
+    drop(secrets);
+
+    match config.audit_log() {
+        Some(log) => log.record(&config),
+        None => Ok(()),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(msg: &str) -> PassGenError {
+        PassGenError::Usage(msg.to_string())
     }
 
-    // generate password
-    let mut rng = rand::thread_rng();
+    #[test]
+    fn exit_code_of_every_error() {
+        // ENOENT on unix and windows alike
+        let io_error = || io::Error::from_raw_os_error(2);
+
+        assert_eq!(exit_code(&usage("invalid argument")), EXIT_FAILED);
+        assert_eq!(exit_code(&PassGenError::Io { action: "reading", path: None, source: io_error() }), EXIT_FAILED);
+        assert_eq!(exit_code(&PassGenError::InvalidData(String::from("empty wordlist"))), EXIT_FAILED);
+        assert_eq!(exit_code(&PassGenError::Unsatisfiable(String::from("no candidate"))), EXIT_FAILED);
+        assert_eq!(exit_code(&PassGenError::Weak { total: 40.0, min: 60.0, missing: 2.0 }), EXIT_WEAK);
+
+        // an error at a line of a spec file keeps the status of the error itself
+        let line = |source| PassGenError::Line { path: String::from("spec"), line: 2, source: Box::new(source) };
+
+        assert_eq!(exit_code(&line(PassGenError::Weak { total: 40.0, min: 60.0, missing: 2.0 })), EXIT_WEAK);
+        assert_eq!(exit_code(&line(usage("invalid label"))), EXIT_FAILED);
 
-    for i in 1..=config.token_count {
-        let idx = rng.gen_range(config.token_data.range());
+        let source = io::Error::new(ErrorKind::Interrupted, "interrupted");
+        assert_eq!(exit_code(&PassGenError::Io { action: "waiting", path: None, source }), EXIT_INTERRUPTED);
 
-        print!("{}", config.token_data.get(idx));
+        // output errors keep the status of the os error, if there is one
+        assert_eq!(exit_code(&PassGenError::Output(io_error())), 2);
+        assert_eq!(exit_code(&PassGenError::Output(io::Error::other("closed"))), EXIT_FAILED);
+    }
+
+    #[test]
+    fn failed_checks_are_a_status() {
+        assert_eq!(status(true), EXIT_SUCCESS);
+        assert_eq!(status(false), EXIT_FAILED);
 
-        if i != config.token_count {
-            print!("{}", config.token_sep);
+        let args = |rest: &[&str]| -> Vec<String> {
+            ["pass-gen"].iter().chain(rest).map(|arg| arg.to_string()).collect()
         };
+
+        assert_eq!(run(&args(&["verify-key", "--quiet", "ABCD-2345-6789-ABCD"])).unwrap(), EXIT_FAILED);
+        assert_eq!(run(&args(&["verify-key", "--quiet", "ABCD-2345-6789-ABCM"])).unwrap(), EXIT_SUCCESS);
+        assert_eq!(run(&args(&["verify-token", "--quiet", "pg_abc"])).unwrap(), EXIT_FAILED);
+
+        // a missing argument is still an error
+        assert!(matches!(run(&args(&["verify-key"])), Err(PassGenError::Usage(_))));
     }
 }
@@ -0,0 +1,133 @@
+use crate::{grapheme_len, ClassSet};
+
+/// Upper bounds of the memorability score for each label, in ascending order.
+pub const MEMORABILITY_LABELS: &[(f64, &str)] = &[
+    (40.0, "very hard"),
+    (60.0, "hard"),
+    (80.0, "moderate"),
+    (f64::INFINITY, "easy"),
+];
+
+// characters typed with shift on a US keyboard, besides capital letters
+const SHIFTED_SYMBOLS: &str = "~!@#$%^&*()_+{}|:\"<>?";
+
+/* -------------------- *
+ *     MEMORABILITY     *
+ * -------------------- */
+/// How hard a password is to remember and type, see [`Memorability::score`].
+///
+/// The factors are plain numbers so the factors of many passwords can be averaged.
+///
+/// ```
+/// use pass_gen::Memorability;
+///
+/// let easy = Memorability::of("correct horse battery staple");
+///
+/// assert_eq!((easy.length, easy.classes, easy.symbol_clusters, easy.shift_changes), (28.0, 1.0, 0.0, 0.0));
+/// assert_eq!(easy.score(), 84.0);
+/// assert_eq!(easy.label(), "easy");
+///
+/// let mixed = Memorability::of("x9#Lq!2@vR$t&8Zp");
+///
+/// assert_eq!((mixed.classes, mixed.symbol_clusters, mixed.shift_changes), (4.0, 0.0, 0.8));
+/// assert_eq!(mixed.score(), 40.0);
+/// assert_eq!(mixed.label(), "hard");
+///
+/// let awkward = Memorability::of("Q}a#!R%b^S&&c*T(d)9[=");
+///
+/// assert_eq!(awkward.symbol_clusters, 3.0);
+/// assert!((awkward.score() - 31.0).abs() < 1e-9);
+/// assert_eq!(awkward.label(), "very hard");
+///
+/// // common words are easier than rare ones
+/// let rare = easy.with_word_rank(0.9);
+///
+/// assert!(rare.score() < easy.score());
+/// assert_eq!(Memorability::mean(&[easy, mixed]).length, 22.0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Memorability {
+    /// Length in characters.
+    pub length: f64,
+    /// Number of distinct character classes.
+    pub classes: f64,
+    /// Runs of two or more symbols in a row.
+    pub symbol_clusters: f64,
+    /// Share of adjacent characters where the shift key goes down or up, on a US keyboard.
+    pub shift_changes: f64,
+    /// Mean frequency rank of the words, from 0 for the most common to 1 for the rarest.
+    ///
+    /// Only known for pools with a frequency table, none of the presets has one.
+    pub word_rank: Option<f64>,
+}
+
+impl Memorability {
+    pub fn of(password: &str) -> Self {
+        let chars: Vec<char> = password.chars().collect();
+        let symbol = |c: &char| !c.is_alphanumeric() && !c.is_whitespace() && !c.is_control();
+        let shifted = |c: &char| c.is_uppercase() || SHIFTED_SYMBOLS.contains(*c);
+
+        // a cluster starts wherever a symbol follows a symbol that did not follow one itself
+        let symbol_clusters = chars
+            .windows(2)
+            .enumerate()
+            .filter(|(idx, pair)| symbol(&pair[0]) && symbol(&pair[1]) && (*idx == 0 || !symbol(&chars[idx - 1])))
+            .count();
+
+        let changes = chars.windows(2).filter(|pair| shifted(&pair[0]) != shifted(&pair[1])).count();
+
+        Memorability {
+            length: grapheme_len(password) as f64,
+            classes: ClassSet::of(password).iter().count() as f64,
+            symbol_clusters: symbol_clusters as f64,
+            shift_changes: changes as f64 / chars.len().saturating_sub(1).max(1) as f64,
+            word_rank: None,
+        }
+    }
+
+    pub fn with_word_rank(mut self, rank: f64) -> Self {
+        self.word_rank = Some(rank);
+        self
+    }
+
+    /// Factors averaged over `items`, the word rank only if every item has one.
+    pub fn mean(items: &[Memorability]) -> Self {
+        let count = items.len().max(1) as f64;
+        let mean = |factor: fn(&Memorability) -> f64| items.iter().map(factor).sum::<f64>() / count;
+
+        let ranks: Option<Vec<f64>> = items.iter().map(|item| item.word_rank).collect();
+
+        Memorability {
+            length: mean(|item| item.length),
+            classes: mean(|item| item.classes),
+            symbol_clusters: mean(|item| item.symbol_clusters),
+            shift_changes: mean(|item| item.shift_changes),
+            word_rank: ranks.filter(|ranks| !ranks.is_empty()).map(|ranks| ranks.iter().sum::<f64>() / count),
+        }
+    }
+
+    /// Score from 0, hardest, to 100, easiest.
+    ///
+    /// Starting from 100 it takes away 1 per character beyond 12, at most 40, 8 per character
+    /// class beyond the first, 6 per symbol cluster, 40 times the share of shift changes and
+    /// 20 times the word rank, clamped to 0.
+    pub fn score(&self) -> f64 {
+        let length = (self.length - 12.0).clamp(0.0, 40.0);
+        let classes = 8.0 * (self.classes - 1.0).max(0.0);
+        let clusters = 6.0 * self.symbol_clusters;
+        let shift = 40.0 * self.shift_changes;
+        let rank = 20.0 * self.word_rank.unwrap_or(0.0);
+
+        (100.0 - length - classes - clusters - shift - rank).clamp(0.0, 100.0)
+    }
+
+    /// Qualitative label of the score.
+    pub fn label(&self) -> &'static str {
+        let score = self.score();
+
+        MEMORABILITY_LABELS
+            .iter()
+            .find(|&&(limit, _)| score < limit)
+            .map_or("easy", |&(_, label)| label)
+    }
+}
@@ -0,0 +1,341 @@
+use crate::{grapheme_len, json_quote, wipe_string, ClassSet, Composition, Config, Format, Quote};
+use std::io::{self, Write};
+
+/* -------------------- *
+ *        OUTPUT        *
+ * -------------------- */
+/// Writes what comes before the first password in the output format of `config`, the csv
+/// header or the opening of the json array.
+pub(crate) fn write_header(config: &Config, out: &mut impl Write) -> io::Result<()> {
+    match config.format {
+        Format::Plain => Ok(()),
+        Format::Csv if config.header && config.username.is_some() => {
+            out.write_all(b"index,username,password,length,entropy\n")
+        },
+        Format::Csv if config.header => out.write_all(b"index,password,length,entropy\n"),
+        Format::KeepassCsv if config.header => out.write_all(b"Group,Title,Username,Password,URL,Notes\n"),
+        Format::Csv | Format::KeepassCsv => Ok(()),
+        Format::Json if config.recovery_codes => out.write_all(b"{\"recovery_codes\": ["),
+        Format::Json => out.write_all(b"["),
+    }
+}
+
+/// Writes the password number `index` of a batch in the output format of `config`.
+///
+/// `index` counts from 0, the separators between entries are written before every entry but
+/// the first. The pepper of `--pepper` has to be part of `password` already.
+pub(crate) fn write_password(
+    config: &Config,
+    out: &mut impl Write,
+    index: u32,
+    password: &str,
+    username: Option<&str>,
+) -> io::Result<()> {
+    match config.format {
+        Format::Plain if config.vertical => {
+            if index != 0 {
+                out.write_all(b"\n\n")?;
+            }
+
+            if config.number > 1 {
+                writeln!(out, "password {}", index + 1)?;
+            }
+
+            write_vertical(config, out, password)
+        },
+        Format::Plain => {
+            if index != 0 {
+                out.write_all(b"\n")?;
+            }
+
+            if let Some(username) = username {
+                write!(out, "{}:", username)?;
+            }
+
+            if config.recovery_codes {
+                write!(out, "{:>width$}. ", index + 1, width = config.number.to_string().len())?;
+            }
+
+            if config.quote == Quote::None {
+                return out.write_all(password.as_bytes());
+            }
+
+            let mut field = shell_quote(config.quote, password);
+            let res = match &config.export {
+                Some(name) => write!(out, "export {}={}", name, field),
+                None => out.write_all(field.as_bytes()),
+            };
+
+            wipe_string(&mut field);
+            res
+        },
+        Format::Csv => {
+            let (number, length) = ((index + 1).to_string(), grapheme_len(password).to_string());
+            let entropy = format!("{:.1}", config.entropy());
+            let mut fields = vec![number.as_str(), password, &length, &entropy];
+
+            if let Some(username) = username {
+                fields.insert(1, username);
+            }
+
+            write_csv_row(out, &fields)
+        },
+        Format::KeepassCsv => {
+            let entry = |template: &Option<String>, default: &str| {
+                template.as_deref().unwrap_or(default).replace("{i}", &(index + 1).to_string())
+            };
+
+            let (parameters, entropy) = (config.parameters(), config.entropy());
+            let notes = format!("generated by pass-gen from {}, {:.1} bits of entropy", parameters, entropy);
+            let fields = [
+                config.keepass_group.as_deref().unwrap_or("Root"),
+                &entry(&config.title_template, "password {i}"),
+                &username.map_or_else(|| entry(&config.username_template, ""), String::from),
+                password,
+                "",
+                &notes,
+            ];
+
+            write_csv_row(out, &fields)
+        },
+        Format::Json if config.recovery_codes => {
+            let mut field = json_quote(password);
+            let res = write!(out, "{}\n  {}", if index != 0 { "," } else { "" }, field);
+
+            wipe_string(&mut field);
+            res
+        },
+        Format::Json => {
+            let mut field = json_quote(password);
+            let res = write!(
+                out,
+                "{}\n  {{\"index\": {}, {}\"password\": {}, \"length\": {}, \"entropy\": {:.1}{}}}",
+                if index != 0 { "," } else { "" },
+                index + 1,
+                username.map_or(String::new(), |username| format!("\"username\": {}, ", json_quote(username))),
+                field,
+                grapheme_len(password),
+                config.entropy(),
+                if config.stats { format!(", \"stats\": {}", stats_json(config, password)) } else { String::new() },
+            );
+
+            wipe_string(&mut field);
+            res
+        },
+    }
+}
+
+// one token per line, numbered from 1 with the numbers right aligned
+fn write_vertical(config: &Config, out: &mut impl Write, password: &str) -> io::Result<()> {
+    let tokens: Vec<&str> = password.split('\n').collect();
+    let width = tokens.len().to_string().len();
+
+    for (pos, token) in tokens.iter().enumerate() {
+        if pos != 0 {
+            out.write_all(b"\n")?;
+        }
+
+        if config.numbers {
+            write!(out, "{:>1$}. ", pos + 1, width)?;
+        }
+
+        out.write_all(token.as_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// Writes the composition of `password` for `--stats`, nothing unless it is asked for.
+pub(crate) fn write_stats(config: &Config, index: u32, password: &str, out: &mut impl Write) -> io::Result<()> {
+    if !config.stats {
+        return Ok(());
+    }
+
+    let stats = Composition::of(password);
+    let classes = stats.classes();
+
+    if config.number > 1 {
+        writeln!(out, "password:     {} of {}", index + 1, config.number)?;
+    }
+
+    writeln!(out, "characters:   {}", stats.characters)?;
+    writeln!(out, "lowercase:    {}", stats.lower)?;
+    writeln!(out, "uppercase:    {}", stats.upper)?;
+    writeln!(out, "digits:       {}", stats.digits)?;
+    writeln!(out, "symbols:      {}", stats.symbols)?;
+    writeln!(out, "other:        {}", stats.other)?;
+
+    if let Some(tokens) = config.tokens_in(password) {
+        writeln!(out, "tokens:       {}", tokens)?;
+    }
+
+    writeln!(out, "classes:      {} of 4 ({})", classes.iter().count(), classes)?;
+
+    for (idx, (name, met)) in requirements(config, classes).into_iter().enumerate() {
+        let label = if idx == 0 { "satisfies:" } else { "" };
+        writeln!(out, "{:<14}{} {}", label, name, if met { "yes" } else { "no" })?;
+    }
+
+    if index + 1 < config.number {
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+/// The composition of `password` as a json object, as written into the entries of `--json`.
+pub(crate) fn stats_json(config: &Config, password: &str) -> String {
+    let stats = Composition::of(password);
+    let classes = stats.classes();
+
+    let names: Vec<String> = classes.iter().map(|class| format!("\"{}\"", class.name())).collect();
+    let satisfies: Vec<String> = requirements(config, classes)
+        .into_iter()
+        .map(|(name, met)| format!("{}: {}", json_quote(&name), met))
+        .collect();
+
+    format!(
+        "{{\"characters\": {}, \"lowercase\": {}, \"uppercase\": {}, \"digits\": {}, \"symbols\": {}, \"other\": {}, \
+        \"tokens\": {}, \"classes\": [{}], \"satisfies\": {{{}}}}}",
+        stats.characters,
+        stats.lower,
+        stats.upper,
+        stats.digits,
+        stats.symbols,
+        stats.other,
+        config.tokens_in(password).map_or(String::from("null"), |tokens| tokens.to_string()),
+        names.join(", "),
+        satisfies.join(", "),
+    )
+}
+
+// requirements sites commonly ask for, besides --require
+fn requirements(config: &Config, classes: ClassSet) -> Vec<(String, bool)> {
+    let count = classes.iter().count();
+    let mut requirements = vec![
+        (String::from("3 of 4 classes"), count >= 3),
+        (String::from("all 4 classes"), count == 4),
+    ];
+
+    if !config.require.is_empty() {
+        requirements.push((format!("--require {}", config.require), classes.contains_all(config.require)));
+    }
+
+    requirements
+}
+
+/// Writes what comes after the last password in the output format of `config`.
+pub(crate) fn write_footer(config: &Config, out: &mut impl Write) -> io::Result<()> {
+    match config.format {
+        Format::Plain | Format::Csv | Format::KeepassCsv => Ok(()),
+        Format::Json if config.recovery_codes => out.write_all(b"\n]}\n"),
+        Format::Json => out.write_all(b"\n]\n"),
+    }
+}
+
+// one line of both csv layouts, every field quoted as needed and wiped after
+fn write_csv_row(out: &mut impl Write, fields: &[&str]) -> io::Result<()> {
+    let mut quoted: Vec<String> = fields.iter().map(|field| csv_quote(field)).collect();
+    let res = quoted.iter().enumerate().try_for_each(|(pos, field)| {
+        if pos != 0 {
+            out.write_all(b",")?;
+        }
+
+        out.write_all(field.as_bytes())
+    });
+
+    quoted.iter_mut().for_each(wipe_string);
+    res.and_then(|_| out.write_all(b"\n"))
+}
+
+/// `field` quoted for csv where needed.
+///
+/// The result is preallocated for the worst case, so it can be wiped without leaving copies.
+pub(crate) fn csv_quote(field: &str) -> String {
+    let mut quoted = String::with_capacity(field.len() * 2 + 2);
+
+    if !field.contains([',', '"', '\n', '\r']) {
+        quoted.push_str(field);
+        return quoted;
+    }
+
+    quoted.push('"');
+
+    for c in field.chars() {
+        if c == '"' {
+            quoted.push('"');
+        }
+
+        quoted.push(c);
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+/// `str` quoted for a posix shell or an .env file.
+///
+/// Env only leaves single quotes when `str` has none, .env parsers take single quoted values
+/// literally but know no escape inside them.
+pub(crate) fn shell_quote(quote: Quote, str: &str) -> String {
+    let mut quoted = String::with_capacity(str.len() * 4 + 2);
+
+    match quote {
+        Quote::None => quoted.push_str(str),
+        Quote::Env if str.contains('\'') => {
+            quoted.push('"');
+
+            for c in str.chars() {
+                if matches!(c, '"' | '\\' | '$' | '`') {
+                    quoted.push('\\');
+                }
+
+                quoted.push(c);
+            }
+
+            quoted.push('"');
+        },
+        Quote::Shell | Quote::Env => {
+            quoted.push('\'');
+
+            for c in str.chars() {
+                match c {
+                    '\'' => quoted.push_str("'\\''"),
+                    c => quoted.push(c),
+                }
+            }
+
+            quoted.push('\'');
+        },
+    }
+
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConfigBuilder;
+
+    #[test]
+    fn csv_row_with_a_username() {
+        let config = ConfigBuilder::new().format(Format::Csv).header(true).no_config(true).build().unwrap();
+        let mut out = Vec::new();
+
+        write_password(&config, &mut out, 0, "a,b", Some("alice")).unwrap();
+        assert!(String::from_utf8(out).unwrap().starts_with("1,alice,\"a,b\",3,"));
+    }
+
+    #[test]
+    fn csv_fields_are_quoted() {
+        assert_eq!(csv_quote("plain"), "plain");
+        assert_eq!(csv_quote("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+
+    #[test]
+    fn shell_quoting() {
+        assert_eq!(shell_quote(Quote::Shell, "it's"), "'it'\\''s'");
+        assert_eq!(shell_quote(Quote::Env, "a$b"), "'a$b'");
+        assert_eq!(shell_quote(Quote::Env, "it's $5"), "\"it's \\$5\"");
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{graphemes, PassGenError, TokenSource};
+
+/* -------------------- *
+ *       PACKING        *
+ * -------------------- */
+/// Encodes integers of exactly `bits` bits as tokens of a pool, in mixed radix.
+///
+/// Drawing every token on its own wastes whatever fraction of a bit the last token carries
+/// beyond the target. Packing instead reads `bits` random bits as one integer and writes it
+/// in base `pool_size`, most significant token first, with the fewest tokens that can hold
+/// any such integer. Every integer maps to its own passphrase and back, so a passphrase
+/// carries exactly `bits` bits.
+///
+/// ```
+/// use pass_gen::BitPacking;
+///
+/// // 10 independent words would be 129.2 bits
+/// let packing = BitPacking::new(128, 7776).unwrap();
+/// assert_eq!((packing.count(), packing.bytes()), (10, 16));
+///
+/// // the largest integer uses the most significant token too
+/// let indices = packing.encode(&[0xff; 16]);
+/// assert_eq!(indices.len(), 10);
+/// assert!(indices[0] > 0);
+/// assert_eq!(packing.decode(&indices).unwrap(), [0xff; 16]);
+///
+/// // a pool of two writes the integer in binary
+/// let binary = BitPacking::new(12, 2).unwrap();
+/// assert_eq!(binary.encode(&[0xfa, 0x5c]), [1, 0, 1, 0, 0, 1, 0, 1, 1, 1, 0, 0]);
+/// ```
+///
+/// Round trips, including the bits of the first byte that are masked off:
+///
+/// ```
+/// use pass_gen::BitPacking;
+/// use rand::{rngs::StdRng, Rng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(1);
+///
+/// for _ in 0..2000 {
+///     let bits = rng.gen_range(1..300);
+///     let pool_size = match rng.gen_range(0..3) {
+///         0 => rng.gen_range(2..10),
+///         1 => rng.gen_range(2..100_000),
+///         _ => 1 << rng.gen_range(1..32),
+///     };
+///
+///     let packing = BitPacking::new(bits, pool_size).unwrap();
+///     let mut bytes = vec![0; packing.bytes()];
+///     rng.fill(&mut bytes[..]);
+///
+///     let indices = packing.encode(&bytes);
+///     assert_eq!(indices.len(), packing.count() as usize);
+///     assert!(indices.iter().all(|&idx| idx < pool_size));
+///
+///     bytes[0] &= 0xff >> (bytes.len() * 8 - bits as usize);
+///     assert_eq!(packing.decode(&indices).unwrap(), bytes);
+///
+///     // one token less could not hold every integer
+///     assert!(((pool_size as f64).log2() * (packing.count() - 1) as f64) < bits as f64);
+/// }
+/// ```
+///
+/// Distinct integers get distinct passphrases and passphrases above the largest integer are
+/// rejected:
+///
+/// ```
+/// use pass_gen::BitPacking;
+/// use std::collections::HashSet;
+///
+/// let packing = BitPacking::new(4, 3).unwrap();
+/// let encoded: HashSet<Vec<usize>> = (0..16).map(|n| packing.encode(&[n])).collect();
+///
+/// assert_eq!((packing.count(), encoded.len()), (3, 16));
+/// assert_eq!(packing.decode(&[1, 2, 0]).unwrap(), [15]);
+/// assert!(packing.decode(&[1, 2, 1]).is_err());
+/// assert!(packing.decode(&[0, 3, 0]).is_err());
+/// assert!(packing.decode(&[0, 0]).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitPacking {
+    bits: u32,
+    pool_size: u32,
+    count: u32,
+}
+
+impl BitPacking {
+    pub fn new(bits: u32, pool_size: usize) -> Result<BitPacking, PassGenError> {
+        if bits == 0 {
+            return Err(PassGenError::Usage(String::from("exact bits must be a positive number")));
+        }
+
+        let pool_size = match u32::try_from(pool_size) {
+            Ok(size) if size >= 2 => size,
+            _ => {
+                let msg = format!("cannot pack bits into a pool of {} tokens", pool_size);
+                return Err(PassGenError::Unsatisfiable(msg));
+            },
+        };
+
+        // the first power of the pool size that exceeds every integer of the given bits
+        let mut power = vec![1];
+        let mut count = 0;
+
+        while bit_len(&power) <= bits as usize {
+            mul_add(&mut power, pool_size, 0);
+            count += 1;
+        }
+
+        Ok(BitPacking { bits, pool_size, count })
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Tokens per passphrase.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Bytes of random input per passphrase, the high bits of the first beyond `bits` are ignored.
+    pub fn bytes(&self) -> usize {
+        (self.bits as usize).div_ceil(8)
+    }
+
+    /// Token indices of the big-endian integer in `bytes`, most significant first.
+    ///
+    /// # Panics
+    ///
+    /// If `bytes` is not [`BitPacking::bytes`] long.
+    pub fn encode(&self, bytes: &[u8]) -> Vec<usize> {
+        assert_eq!(bytes.len(), self.bytes(), "expected {} bytes to pack", self.bytes());
+
+        let mut limbs = vec![0; bytes.len().div_ceil(4)];
+
+        for (idx, &byte) in bytes.iter().rev().enumerate() {
+            let byte = if idx == bytes.len() - 1 { byte & (0xff >> (bytes.len() * 8 - self.bits as usize)) } else { byte };
+            limbs[idx / 4] |= (byte as u32) << (8 * (idx % 4));
+        }
+
+        let mut indices = vec![0; self.count as usize];
+
+        for idx in indices.iter_mut().rev() {
+            *idx = div_rem(&mut limbs, self.pool_size) as usize;
+        }
+
+        limbs.fill(0);
+        indices
+    }
+
+    /// The big-endian integer behind token indices, see [`BitPacking::encode`].
+    pub fn decode(&self, indices: &[usize]) -> Result<Vec<u8>, PassGenError> {
+        if indices.len() != self.count as usize {
+            return Err(PassGenError::InvalidData(format!(
+                "expected {} tokens to unpack {} bits, got {}",
+                self.count,
+                self.bits,
+                indices.len()
+            )));
+        }
+
+        let mut limbs = vec![0];
+
+        for (position, &idx) in indices.iter().enumerate() {
+            if idx >= self.pool_size as usize {
+                let msg = format!("token {} is outside the pool of {} tokens", position + 1, self.pool_size);
+                return Err(PassGenError::InvalidData(msg));
+            }
+
+            mul_add(&mut limbs, self.pool_size, idx as u32);
+        }
+
+        if bit_len(&limbs) > self.bits as usize {
+            return Err(PassGenError::InvalidData(format!(
+                "the tokens encode an integer of more than {} bits, they were not packed from {} bits",
+                self.bits, self.bits
+            )));
+        }
+
+        let bytes = (0..self.bytes())
+            .rev()
+            .map(|idx| limbs.get(idx / 4).map_or(0, |limb| (limb >> (8 * (idx % 4))) as u8))
+            .collect();
+
+        limbs.fill(0);
+        Ok(bytes)
+    }
+
+    /// The big-endian integer behind a passphrase of tokens from `words` joined by `sep`.
+    ///
+    /// Without a separator the passphrase can only be split if every token is one character.
+    ///
+    /// ```
+    /// use pass_gen::{preset, BitPacking};
+    ///
+    /// let words = preset("number").unwrap().token_data;
+    /// let packing = BitPacking::new(16, 10).unwrap();
+    ///
+    /// assert_eq!(packing.unpack(&words, "", "65535").unwrap(), [0xff, 0xff]);
+    /// assert_eq!(packing.unpack(&words, " ", " 0 1 2 3 4 ").unwrap(), [0x04, 0xd2]);
+    /// assert!(packing.unpack(&words, "-", "0-1-2-3-x").unwrap_err().to_string().contains("token 5"));
+    /// ```
+    pub fn unpack(&self, words: &dyn TokenSource, sep: &str, phrase: &str) -> Result<Vec<u8>, PassGenError> {
+        let phrase = phrase.trim();
+
+        let tokens: Vec<&str> = match sep {
+            "" if words.length_stats().2 == 1 => graphemes(phrase).collect(),
+            "" => {
+                let msg = "a passphrase of words cannot be split without a separator, pass it with --sep";
+                return Err(PassGenError::Usage(String::from(msg)));
+            },
+            sep if sep.trim().is_empty() => phrase.split_whitespace().collect(),
+            sep => phrase.split(sep).collect(),
+        };
+
+        let mut indices = Vec::with_capacity(tokens.len());
+
+        for (position, token) in tokens.iter().enumerate() {
+            match words.range().find(|&idx| words.get(idx) == *token) {
+                Some(idx) => indices.push(idx),
+                None => {
+                    return Err(PassGenError::InvalidData(format!(
+                        "token {} of the passphrase is not in the pool it was generated from",
+                        position + 1
+                    )));
+                },
+            }
+        }
+
+        self.decode(&indices)
+    }
+}
+
+// little-endian limbs, only ever multiplied and divided by single limbs
+fn mul_add(limbs: &mut Vec<u32>, factor: u32, addend: u32) {
+    let mut carry = addend as u64;
+
+    for limb in limbs.iter_mut() {
+        let product = *limb as u64 * factor as u64 + carry;
+        *limb = product as u32;
+        carry = product >> 32;
+    }
+
+    if carry > 0 {
+        limbs.push(carry as u32);
+    }
+}
+
+fn div_rem(limbs: &mut [u32], divisor: u32) -> u32 {
+    let mut rem = 0;
+
+    for limb in limbs.iter_mut().rev() {
+        let value = (rem << 32) | *limb as u64;
+        *limb = (value / divisor as u64) as u32;
+        rem = value % divisor as u64;
+    }
+
+    rem as u32
+}
+
+fn bit_len(limbs: &[u32]) -> usize {
+    match limbs.iter().rposition(|&limb| limb != 0) {
+        Some(idx) => idx * 32 + 32 - limbs[idx].leading_zeros() as usize,
+        None => 0,
+    }
+}
@@ -0,0 +1,344 @@
+use crate::{grapheme_len, CharClass, ClassSet, PassGenError, TokenData, TokenSource};
+use std::{fmt, fs, path::Path};
+
+/* -------------------- *
+ *         RULE         *
+ * -------------------- */
+/// A single constraint of a [`Policy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    MinLength(usize),
+    MaxLength(usize),
+    Require(CharClass),
+    Forbidden(String),
+    Charset(String),
+    NoSpaces,
+}
+
+impl Rule {
+    /// Whether `password` satisfies the rule, lengths are counted in characters.
+    pub fn check(&self, password: &str) -> bool {
+        match self {
+            Rule::MinLength(min) => grapheme_len(password) >= *min,
+            Rule::MaxLength(max) => grapheme_len(password) <= *max,
+            Rule::Require(class) => password.chars().any(|c| class.matches(c)),
+            _ => password.chars().all(|c| self.allows_char(c)),
+        }
+    }
+
+    /// Whether `c` may appear anywhere in a password, length and class rules allow everything.
+    pub fn allows_char(&self, c: char) -> bool {
+        match self {
+            Rule::Forbidden(chars) => !chars.contains(c),
+            Rule::Charset(chars) => chars.contains(c),
+            Rule::NoSpaces => !c.is_whitespace(),
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::MinLength(min) => write!(f, "at least {} characters", min),
+            Rule::MaxLength(max) => write!(f, "at most {} characters", max),
+            Rule::Require(class) => write!(f, "at least one {} character", class.name()),
+            Rule::Forbidden(chars) => write!(f, "none of {:?}", chars),
+            Rule::Charset(chars) => write!(f, "only characters from {:?}", chars),
+            Rule::NoSpaces => write!(f, "no spaces"),
+        }
+    }
+}
+
+
+/* -------------------- *
+ *        POLICY        *
+ * -------------------- */
+/// A site's password policy, read from a small TOML file.
+///
+/// Every key is optional:
+///
+/// ```toml
+/// min_length = 12
+/// max_length = 64
+/// require = ["upper", "digit", "symbol"]
+/// forbidden = "\"'\\"
+/// charset = "abcdefghijklmnopqrstuvwxyz0123456789"
+/// spaces = false
+/// ```
+///
+/// Generating and checking share [`Policy::allows`], so they cannot disagree.
+///
+/// ```
+/// use pass_gen::{preset, Generator, Policy, TokenSource};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// for text in [include_str!("../policies/corp.toml"), include_str!("../policies/bank.toml")] {
+///     let policy = Policy::parse(text).unwrap();
+///     let (tokens, _) = policy.restrict(&preset("ascii").unwrap().token_data).unwrap();
+///     let mut gen = Generator::new(&tokens, 14, "", StdRng::seed_from_u64(1));
+///
+///     for _ in 0..10_000 {
+///         let password = (0..1000).map(|_| gen.generate()).find(|p| policy.allows(p)).unwrap();
+///
+///         assert!(policy.rules().iter().all(|rule| rule.check(&password)));
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Policy {
+    rules: Vec<Rule>,
+}
+
+impl Policy {
+    pub fn parse(text: &str) -> Result<Policy, PassGenError> {
+        let mut rules = Vec::new();
+        let mut seen = Vec::new();
+
+        for (nr, line) in text.lines().enumerate() {
+            let invalid = |msg: &str| PassGenError::InvalidData(format!("invalid policy on line {}: {}", nr + 1, msg));
+
+            let line = strip_comment(line).trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None if line.starts_with('[') => return Err(invalid("tables are not supported")),
+                None => return Err(invalid("expected key = value")),
+            };
+
+            if seen.contains(&key) {
+                return Err(invalid(&format!("duplicate key {:?}", key)));
+            }
+
+            seen.push(key);
+
+            let value = Value::parse(value).ok_or_else(|| invalid("expected a number, boolean, string or array"))?;
+
+            match (key, value) {
+                ("min_length", Value::Int(min)) => rules.push(Rule::MinLength(min)),
+                ("max_length", Value::Int(max)) => rules.push(Rule::MaxLength(max)),
+                ("require", Value::Array(names)) => {
+                    for name in names {
+                        let class = CharClass::from_name(&name).ok_or_else(|| {
+                            invalid(&format!("unknown class {:?}, expected lower, upper, digit or symbol", name))
+                        })?;
+
+                        rules.push(Rule::Require(class));
+                    }
+                },
+                ("forbidden", Value::Str(chars)) => rules.push(Rule::Forbidden(chars)),
+                ("charset", Value::Str(chars)) if chars.is_empty() => return Err(invalid("charset is empty")),
+                ("charset", Value::Str(chars)) => rules.push(Rule::Charset(chars)),
+                ("spaces", Value::Bool(spaces)) => {
+                    if !spaces {
+                        rules.push(Rule::NoSpaces);
+                    }
+                },
+                ("min_length" | "max_length", _) => return Err(invalid(&format!("{} must be a number", key))),
+                ("require", _) => return Err(invalid("require must be an array of class names")),
+                ("forbidden" | "charset", _) => return Err(invalid(&format!("{} must be a string", key))),
+                ("spaces", _) => return Err(invalid("spaces must be true or false")),
+                _ => return Err(invalid(&format!("unknown key {:?}", key))),
+            }
+        }
+
+        let policy = Policy { rules };
+
+        if let (min, Some(max)) = policy.length_bounds() {
+            if min > max {
+                let msg = format!("invalid policy: min_length {} is larger than max_length {}", min, max);
+                return Err(PassGenError::InvalidData(msg));
+            }
+        }
+
+        Ok(policy)
+    }
+
+    pub fn from_path(path: &Path) -> Result<Policy, PassGenError> {
+        let text = fs::read_to_string(path).map_err(|source| PassGenError::Io {
+            action: "reading policy file",
+            path: Some(path.display().to_string()),
+            source,
+        })?;
+
+        Self::parse(&text)
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    pub fn allows(&self, password: &str) -> bool {
+        self.rules.iter().all(|rule| rule.check(password))
+    }
+
+    pub fn allows_char(&self, c: char) -> bool {
+        self.rules.iter().all(|rule| rule.allows_char(c))
+    }
+
+    /// Minimum and maximum length in characters.
+    pub fn length_bounds(&self) -> (usize, Option<usize>) {
+        let mut bounds = (0, None);
+
+        for rule in &self.rules {
+            match *rule {
+                Rule::MinLength(min) => bounds.0 = min,
+                Rule::MaxLength(max) => bounds.1 = Some(max),
+                _ => {},
+            }
+        }
+
+        bounds
+    }
+
+    pub fn require(&self) -> ClassSet {
+        self.rules.iter().fold(ClassSet::default(), |set, rule| match rule {
+            Rule::Require(class) => set.with(*class),
+            _ => set,
+        })
+    }
+
+    /// The allowed characters, minus the forbidden ones.
+    pub fn charset(&self) -> Option<String> {
+        self.rules.iter().find_map(|rule| match rule {
+            Rule::Charset(chars) => Some(chars.chars().filter(|&c| self.allows_char(c)).collect()),
+            _ => None,
+        })
+    }
+
+    /// Keeps the tokens made only of allowed characters, returning them with the number removed.
+    pub fn restrict(&self, source: &dyn TokenSource) -> Result<(TokenData, usize), PassGenError> {
+        let kept: Vec<String> = source
+            .range()
+            .map(|idx| source.get(idx))
+            .filter(|token| token.chars().all(|c| self.allows_char(c)))
+            .map(str::to_string)
+            .collect();
+
+        let removed = source.len() - kept.len();
+
+        Ok((TokenData::from_strings(kept)?, removed))
+    }
+}
+
+impl fmt::Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rules: Vec<String> = self.rules.iter().map(Rule::to_string).collect();
+
+        write!(f, "{}", rules.join(", "))
+    }
+}
+
+
+/* -------------------- *
+ *        VALUE         *
+ * -------------------- */
+// the subset of toml values a policy needs
+pub(crate) enum Value {
+    Int(usize),
+    Bool(bool),
+    Str(String),
+    Array(Vec<String>),
+}
+
+impl Value {
+    pub(crate) fn parse(str: &str) -> Option<Value> {
+        match str {
+            "true" => return Some(Value::Bool(true)),
+            "false" => return Some(Value::Bool(false)),
+            _ => {},
+        }
+
+        if let Some(items) = str.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            let mut array = Vec::new();
+            let mut rest = items.trim();
+
+            while !rest.is_empty() {
+                let (item, tail) = parse_string(rest)?;
+                array.push(item);
+
+                rest = tail.trim_start();
+                rest = match rest.strip_prefix(',') {
+                    Some(tail) => tail.trim_start(),
+                    None if rest.is_empty() => rest,
+                    None => return None,
+                };
+            }
+
+            return Some(Value::Array(array));
+        }
+
+        if str.starts_with(['"', '\'']) {
+            return match parse_string(str)? {
+                (value, "") => Some(Value::Str(value)),
+                _ => None,
+            };
+        }
+
+        str.replace('_', "").parse().ok().map(Value::Int)
+    }
+}
+
+// parses a basic or literal string at the start of str, returning it and the rest
+fn parse_string(str: &str) -> Option<(String, &str)> {
+    let mut chars = str.char_indices();
+    let quote = chars.next()?.1;
+
+    if quote != '"' && quote != '\'' {
+        return None;
+    }
+
+    let mut value = String::new();
+
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            _ if c == quote => return Some((value, &str[idx + 1..])),
+            '\\' if quote == '"' => {
+                let escaped = match chars.next()?.1 {
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    '"' => '"',
+                    '\\' => '\\',
+                    'u' => {
+                        let hex: String = (0..4).map(|_| chars.next().map(|(_, c)| c)).collect::<Option<_>>()?;
+                        char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+                    },
+                    _ => return None,
+                };
+
+                value.push(escaped);
+            },
+            _ => value.push(c),
+        }
+    }
+
+    None
+}
+
+// a # only starts a comment outside of strings
+pub(crate) fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut escaped = false;
+
+    for (idx, c) in line.char_indices() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                continue;
+            },
+            (Some(q), _) if c == q && !escaped => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') => return &line[..idx],
+            _ => {},
+        }
+
+        escaped = false;
+    }
+
+    line
+}
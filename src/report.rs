@@ -0,0 +1,1054 @@
+use crate::{
+    crack_time, entropy_bar, equivalent_length, grapheme_len, guess_probability, json_number, json_quote,
+    strength_label, BarCharset, Config, ConfigBuilder, KdfParams, Memorability, PassGenError, TargetHash,
+    ALPHANUMERIC, HASH_HARDWARE, PRINTABLE_ASCII,
+};
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+};
+
+const MINUTE: f64        = 60.0;
+const HOUR: f64          = MINUTE * 60.0;
+const DAY: f64           = HOUR * 24.0;
+const WEEK: f64          = DAY * 7.0;
+const YEAR: f64          = DAY * 365.25;
+const MONTH: f64         = YEAR / 12.0;
+const CENTURY: f64       = YEAR * 100.0;
+const MILLENNIUM: f64    = YEAR * 1e3;
+const MILLION_YEARS: f64 = YEAR * 1e6;
+const BILLION_YEARS: f64 = YEAR * 1e9;
+const FOREVER: f64       = YEAR * 1e15;
+
+/// Units of the crack times with their singular, plural and length in seconds, smallest first.
+pub const TIME_UNITS: &[(&str, &str, f64)] = &[
+    ("second", "seconds", 1.0),
+    ("minute", "minutes", MINUTE),
+    ("hour", "hours", HOUR),
+    ("day", "days", DAY),
+    ("week", "weeks", WEEK),
+    ("month", "months", MONTH),
+    ("year", "years", YEAR),
+    ("century", "centuries", CENTURY),
+    ("millennium", "millennia", MILLENNIUM),
+    ("million years", "million years", MILLION_YEARS),
+    ("billion years", "billion years", BILLION_YEARS),
+];
+
+/// Guess rates of the report when none are asked for, from a single gpu to a nation state.
+pub const DEFAULT_RATES: &[(&str, f64)] = &[
+    ("1 billion", 1e9),
+    ("1 quadrillion", 1e15),
+    ("1 sextillion", 1e21),
+];
+
+/// Bits that fill the entropy bar of the report when no other target is asked for.
+pub const BAR_TARGET: f64 = 128.0;
+
+const BAR_MIN_WIDTH: usize = 10;
+const BAR_MAX_WIDTH: usize = 40;
+const SMALL_POOL: usize = 16;
+
+// attempts the odds of guessing any recovery code are given for
+const RECOVERY_ATTEMPTS: &[f64] = &[10.0, 1000.0, 1e6];
+
+/* -------------------- *
+ *       TERMINAL       *
+ * -------------------- */
+/// What the terminal a human report goes to can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Terminal {
+    /// Columns, the report ends with a rule across all of them.
+    pub width: usize,
+    /// Whether ANSI colors are shown.
+    pub color: bool,
+    /// Whether the locale encodes characters as UTF-8, for the block characters of the bar.
+    pub unicode: bool,
+}
+
+
+/* -------------------- *
+ *        REPORT        *
+ * -------------------- */
+/// Format of the entropy report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Human,
+    Json,
+    Kv,
+}
+
+/// Time to crack a password at one guess rate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuessTime {
+    pub label: String,
+    /// Guesses per second.
+    pub rate: f64,
+    /// Seconds to search half the keyspace.
+    pub average: f64,
+    /// Seconds to search all of it.
+    pub worst: f64,
+}
+
+impl GuessTime {
+    /// The times to search a keyspace of `bits` at `rate` guesses per second.
+    pub fn new(label: String, rate: f64, bits: f64) -> Self {
+        // on average the password is found after searching half the keyspace
+        Self { label, rate, average: crack_time(bits, rate, 0.5), worst: crack_time(bits, rate, 1.0) }
+    }
+}
+
+/// The tokens a password is drawn from, as far as the report is concerned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pool {
+    pub size: usize,
+    pub per_token_bits: f64,
+    /// Filters with the number of tokens each removed.
+    pub filters: Vec<(&'static str, usize)>,
+    /// Minimum, mean and maximum length in characters, `None` for a pool that only has a size.
+    pub length: Option<(usize, f64, usize)>,
+    /// Named parts of the entropy, they add up to the total.
+    pub components: Vec<(&'static str, f64)>,
+}
+
+/// Strength the password was asked to reach.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Targets {
+    pub bits: Option<f64>,
+    /// Seconds to crack at the rate of guesses per second.
+    pub time: Option<(f64, f64)>,
+}
+
+/// The entropy of a password and what it means to an attacker, rendered by [`EntropyReport::write`].
+///
+/// ```
+/// use pass_gen::{EntropyReport, Pool, ReportFormat, Targets, Terminal};
+///
+/// let pool = Pool {
+///     size: 7776,
+///     per_token_bits: 7776f64.log2(),
+///     filters: Vec::new(),
+///     length: Some((20, 34.5, 54)),
+///     components: vec![("tokens", 7776f64.log2() * 6.0)],
+/// };
+/// let report = EntropyReport::new(pool, 6.0, Targets { bits: None, time: None }, &[]);
+///
+/// assert_eq!(report.total_bits.round(), 78.0);
+/// assert_eq!(report.guess_times.len(), 3);
+///
+/// let mut kv = Vec::new();
+/// report.write(ReportFormat::Kv, &Terminal { width: 80, color: false, unicode: false }, &mut kv).unwrap();
+///
+/// assert!(String::from_utf8(kv).unwrap().contains("\nstrength=strong\n"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntropyReport {
+    pub pool_size: f64,
+    pub filters: Vec<(&'static str, usize)>,
+    pub length: Option<(usize, f64, usize)>,
+    pub components: Vec<(&'static str, f64)>,
+    pub token_count: f64,
+    pub target_bits: Option<f64>,
+    pub target_time: Option<(f64, f64)>,
+    pub per_token_bits: f64,
+    pub total_bits: f64,
+    /// Bits that fill the entropy bar.
+    pub bar_target: f64,
+    /// The verifier hash the guess rates are for.
+    pub target_hash: Option<String>,
+    pub guess_times: Vec<GuessTime>,
+    /// The least entropy of a single password, when it differs from the average.
+    pub min_bits: Option<f64>,
+    /// The length limit with the fraction of candidates that fit it.
+    pub max_length: Option<(usize, f64)>,
+    /// Probability of each leet substitution.
+    pub leet: Option<f64>,
+    pub memorability: Option<Memorability>,
+    /// Label and parameters of a derived password.
+    pub derivation: Option<(String, KdfParams)>,
+    /// Whether a pepper the report can't count is appended.
+    pub pepper: bool,
+    /// Style and entropy of the usernames, never part of the total.
+    pub username: Option<(&'static str, f64)>,
+    /// The number of valid recovery codes, any of which lets an attacker in.
+    pub recovery_codes: Option<u32>,
+}
+
+impl EntropyReport {
+    /// The report of `token_count` tokens from `pool`, with crack times at `rates` or [`DEFAULT_RATES`]
+    /// if there are none.
+    pub fn new(pool: Pool, token_count: f64, targets: Targets, rates: &[(&str, f64)]) -> Self {
+        let pool_size = pool.size as f64;
+        let per_token_bits = pool.per_token_bits;
+        let total_bits = pool.components.iter().map(|(_, bits)| bits).sum();
+
+        let rates = if rates.is_empty() { DEFAULT_RATES } else { rates };
+
+        let guess_times = rates
+            .iter()
+            .map(|&(label, rate)| GuessTime::new(label.to_string(), rate, total_bits))
+            .collect();
+
+        Self {
+            pool_size,
+            filters: pool.filters,
+            length: pool.length,
+            components: pool.components,
+            token_count,
+            target_bits: targets.bits,
+            target_time: targets.time,
+            per_token_bits,
+            total_bits,
+            bar_target: BAR_TARGET,
+            target_hash: None,
+            guess_times,
+            min_bits: None,
+            max_length: None,
+            leet: None,
+            memorability: None,
+            derivation: None,
+            pepper: false,
+            username: None,
+            recovery_codes: None,
+        }
+    }
+
+    /// Average seconds to crack at `rate` guesses per second.
+    pub fn crack_time(&self, rate: f64) -> f64 {
+        crack_time(self.attack_bits(), rate, 0.5)
+    }
+
+    /// Sets the number of valid recovery codes, the crack times are then those of guessing any one of them.
+    pub fn set_recovery_codes(&mut self, codes: u32) {
+        self.recovery_codes = Some(codes);
+
+        let bits = self.attack_bits();
+
+        for guess in self.guess_times.iter_mut() {
+            *guess = GuessTime::new(std::mem::take(&mut guess.label), guess.rate, bits);
+        }
+    }
+
+    // every valid code is another target, n of them take log2(n) bits off the search
+    fn attack_bits(&self) -> f64 {
+        self.total_bits - self.recovery_codes.map_or(0.0, |codes| (codes as f64).log2())
+    }
+
+    /// Writes the report in `format`, only the human one looks at `term`.
+    pub fn write(&self, format: ReportFormat, term: &Terminal, out: &mut impl Write) -> io::Result<()> {
+        match format {
+            ReportFormat::Human => self.write_human(term, out),
+            ReportFormat::Json => self.write_json(out),
+            ReportFormat::Kv => self.write_kv(out),
+        }
+    }
+
+    /// Aligned lines for a terminal, ending in a rule across it.
+    pub fn write_human(&self, term: &Terminal, out: &mut impl Write) -> io::Result<()> {
+        if !self.filters.is_empty() {
+            let filters: Vec<String> = self
+                .filters
+                .iter()
+                .map(|(name, removed)| format!("{} (-{})", name, removed))
+                .collect();
+
+            writeln!(out, "pool filters:               {}", filters.join(", "))?;
+        }
+
+        // a hypothetical pool is as small as it was asked to be
+        if (self.pool_size as usize) < SMALL_POOL && self.length.is_some() {
+            writeln!(
+                out,
+                "warning: the token pool only has {} tokens, check the token file and filters",
+                self.pool_size
+            )?;
+        }
+
+        match self.length {
+            Some((min, _, max)) if min == max => writeln!(out, "length:                     {} chars", min)?,
+            Some((min, avg, max)) => {
+                writeln!(out, "length:                     {}–{} chars (avg {:.0})", min, max, avg)?
+            },
+            None => {},
+        }
+
+        writeln!(out, "entropy per word:           {:.1} bits", self.per_token_bits)?;
+        let total = format!("total entropy:              {:.0} bits", self.total_bits);
+        let target = format!("target {:.0}", self.bar_target);
+        writeln!(out, "{} {} {}", total, self.bar(term, grapheme_len(&total) + grapheme_len(&target) + 4), target)?;
+
+        if self.pepper {
+            writeln!(out, "                            + user pepper (uncounted)")?;
+        }
+
+        if let Some((style, bits)) = self.username {
+            let username = format!("{} style, {:.1} bits of its own (not in the total)", style, bits);
+            writeln!(out, "username:                   {}", username)?;
+        }
+
+        if let Some(codes) = self.recovery_codes {
+            let (bits, any) = (self.total_bits, self.attack_bits());
+            let valid = format!("{} valid, {:.1} bits each, {:.1} to guess any one", codes, bits, any);
+
+            writeln!(out, "recovery codes:             {}", valid)?;
+
+            for &attempts in RECOVERY_ATTEMPTS {
+                let probability = guess_probability(self.total_bits, codes as f64, attempts);
+                let label = format!("in {} attempts:", format_unit(attempts, "").trim_end());
+
+                writeln!(out, "  {:<26}1 in {}", label, format_unit(1.0 / probability, "").trim_end())?;
+            }
+        }
+
+        if let Some(bits) = self.min_bits {
+            writeln!(out, "  {:<26}{:.1} bits, the total is an average over the tokens drawn", "minimum:", bits)?;
+        }
+
+        if self.components.len() > 1 {
+            for (name, bits) in &self.components {
+                writeln!(out, "  {:<26}{:.1} bits", format!("{}:", name), bits)?;
+            }
+        }
+
+        writeln!(
+            out,
+            "equivalent to:              {} random ascii or {} alphanumeric chars",
+            equivalent_length(self.total_bits, PRINTABLE_ASCII),
+            equivalent_length(self.total_bits, ALPHANUMERIC),
+        )?;
+
+        if let Some((max, fit)) = self.max_length {
+            writeln!(out, "max length:                 {} chars, {:.1}% of candidates fit", max, fit * 100.0)?;
+        }
+
+        if let Some(probability) = self.leet {
+            writeln!(
+                out,
+                "leet:                       p = {}, counted only as the choice of substitutions, attackers try \
+                 them all",
+                probability
+            )?;
+        }
+
+        if let Some(memorability) = &self.memorability {
+            writeln!(out, "memorability:               {:.0}/100 ({})", memorability.score(), memorability.label())?;
+        }
+
+        if let Some(bits) = self.target_bits {
+            writeln!(out, "target entropy:             {:.1} bits ({} tokens)", bits, self.token_count)?;
+        }
+
+        if let Some((seconds, rate)) = self.target_time {
+            let achieved = self.crack_time(rate);
+
+            writeln!(
+                out,
+                "target crack time:          {} at {:e} / second (margin {:.1}x)",
+                format_time(seconds),
+                rate,
+                achieved / seconds,
+            )?;
+        }
+
+        if let Some(hash) = &self.target_hash {
+            writeln!(out, "target hash:                {}, guess rate estimated for {}", hash, HASH_HARDWARE)?;
+        }
+
+        writeln!(out, "average time to crack:")?;
+
+        for guess in &self.guess_times {
+            let label = format!("{} / second:", guess.label);
+            writeln!(out, "  {:<26}{}", label, format_time(guess.average))?;
+        }
+
+        writeln!(out, "worst case:")?;
+
+        for guess in &self.guess_times {
+            let label = format!("{} / second:", guess.label);
+            writeln!(out, "  {:<26}{}", label, format_time(guess.worst))?;
+        }
+
+        if let Some((label, kdf)) = &self.derivation {
+            writeln!(
+                out,
+                "derivation:                 argon2id (m={} KiB, t={}, p={}) + hkdf-sha256, label {:?}",
+                kdf.memory, kdf.iterations, kdf.lanes, label
+            )?;
+        }
+
+        let strength = strength_label(self.total_bits);
+
+        if strength == "weak" && term.color {
+            writeln!(out, "strength:                   \x1b[31m{}\x1b[0m", strength)?;
+        } else {
+            writeln!(out, "strength:                   {}", strength)?;
+        }
+
+        writeln!(out, "{}", "-".repeat(term.width))
+    }
+
+    /// A single line JSON object.
+    pub fn write_json(&self, out: &mut impl Write) -> io::Result<()> {
+        let guess_times: Vec<String> = self
+            .guess_times
+            .iter()
+            .map(|g| {
+                format!(
+                    "{{\"rate\": {}, \"average_seconds\": {}, \"worst_seconds\": {}}}",
+                    json_number(g.rate),
+                    json_number(g.average),
+                    json_number(g.worst),
+                )
+            })
+            .collect();
+
+        let filters: Vec<String> = self
+            .filters
+            .iter()
+            .map(|(name, removed)| format!("{{\"name\": {}, \"removed\": {}}}", json_quote(name), removed))
+            .collect();
+
+        let components: Vec<String> = self
+            .components
+            .iter()
+            .map(|(name, bits)| format!("{{\"name\": {}, \"bits\": {}}}", json_quote(name), json_number(*bits)))
+            .collect();
+
+        let memorability = match &self.memorability {
+            Some(m) => format!(
+                "\"memorability\": {{\"score\": {}, \"label\": {}, \"length\": {}, \"classes\": {}, \
+                \"symbol_clusters\": {}, \"shift_changes\": {}, \"word_rank\": {}}}, ",
+                json_number(m.score()),
+                json_quote(m.label()),
+                json_number(m.length),
+                json_number(m.classes),
+                json_number(m.symbol_clusters),
+                json_number(m.shift_changes),
+                m.word_rank.map_or(String::from("null"), json_number),
+            ),
+            None => String::new(),
+        };
+
+        let derivation = match &self.derivation {
+            Some((label, kdf)) => format!(
+                "\"derivation\": {{\"label\": {}, \"kdf\": \"argon2id\", \"memory_kib\": {}, \"iterations\": {}, \
+                \"lanes\": {}}}, ",
+                json_quote(label),
+                kdf.memory,
+                kdf.iterations,
+                kdf.lanes
+            ),
+            None => String::new(),
+        };
+
+        let pepper = if self.pepper { "\"user_pepper\": \"uncounted\", " } else { "" };
+        let username = match self.username {
+            Some((style, bits)) => {
+                format!("\"username\": {{\"style\": \"{}\", \"bits\": {}}}, ", style, json_number(bits))
+            },
+            None => String::new(),
+        };
+        let recovery_codes = match self.recovery_codes {
+            Some(codes) => {
+                let guessed: Vec<String> = RECOVERY_ATTEMPTS
+                    .iter()
+                    .map(|&attempts| {
+                        let probability = guess_probability(self.total_bits, codes as f64, attempts);
+                        let (attempts, probability) = (json_number(attempts), json_number(probability));
+
+                        format!("{{\"attempts\": {}, \"probability\": {}}}", attempts, probability)
+                    })
+                    .collect();
+
+                format!(
+                    "\"recovery_codes\": {{\"valid\": {}, \"bits\": {}, \"guessed\": [{}]}}, ",
+                    codes,
+                    json_number(self.attack_bits()),
+                    guessed.join(", ")
+                )
+            },
+            None => String::new(),
+        };
+        let target_hash = match &self.target_hash {
+            Some(hash) => format!(
+                "\"target_hash\": {{\"hash\": {}, \"hardware\": {}}}, ",
+                json_quote(hash),
+                json_quote(HASH_HARDWARE)
+            ),
+            None => String::new(),
+        };
+
+        writeln!(
+            out,
+            "{{\"pool_size\": {}, \"filters\": [{}], \"length\": {}, \"components\": [{}], \"token_count\": {}, \
+            \"target_bits\": {}, \"target_seconds\": {}, \"per_token_bits\": {}, \"total_bits\": {}, \"min_bits\": {}, \
+            \"max_length\": {}, \"leet_probability\": {}, \"ascii_equivalent\": {}, \"alnum_equivalent\": {}, \
+            \"guess_times\": [{}], {}{}{}{}{}{}\"strength\": {}}}",
+            json_number(self.pool_size),
+            filters.join(", "),
+            self.length.map_or(String::from("null"), |(min, avg, max)| {
+                format!("{{\"min\": {}, \"avg\": {}, \"max\": {}}}", min, json_number(avg), max)
+            }),
+            components.join(", "),
+            json_number(self.token_count),
+            self.target_bits.map_or(String::from("null"), json_number),
+            self.target_time.map_or(String::from("null"), |(t, _)| json_number(t)),
+            json_number(self.per_token_bits),
+            json_number(self.total_bits),
+            self.min_bits.map_or(String::from("null"), json_number),
+            self.max_length.map_or(String::from("null"), |(max, fit)| {
+                format!("{{\"chars\": {}, \"fit\": {}}}", max, json_number(fit))
+            }),
+            self.leet.map_or(String::from("null"), json_number),
+            json_number(equivalent_length(self.total_bits, PRINTABLE_ASCII)),
+            json_number(equivalent_length(self.total_bits, ALPHANUMERIC)),
+            guess_times.join(", "),
+            memorability,
+            derivation,
+            pepper,
+            username,
+            recovery_codes,
+            target_hash,
+            json_quote(strength_label(self.total_bits)),
+        )
+    }
+
+    /// One `key=value` line per figure, for shell scripts.
+    pub fn write_kv(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "pool_size={}", json_number(self.pool_size))?;
+
+        for (name, removed) in &self.filters {
+            writeln!(out, "filter_{}={}", name, removed)?;
+        }
+
+        if let Some((min, avg, max)) = self.length {
+            writeln!(out, "length_min={}", min)?;
+            writeln!(out, "length_avg={}", json_number(avg))?;
+            writeln!(out, "length_max={}", max)?;
+        }
+        writeln!(out, "token_count={}", json_number(self.token_count))?;
+
+        if let Some(bits) = self.target_bits {
+            writeln!(out, "target_bits={}", json_number(bits))?;
+        }
+
+        if let Some((seconds, _)) = self.target_time {
+            writeln!(out, "target_seconds={}", json_number(seconds))?;
+        }
+
+        writeln!(out, "per_token_bits={}", json_number(self.per_token_bits))?;
+        writeln!(out, "total_bits={}", json_number(self.total_bits))?;
+
+        if let Some(bits) = self.min_bits {
+            writeln!(out, "min_bits={}", json_number(bits))?;
+        }
+
+        if let Some((max, fit)) = self.max_length {
+            writeln!(out, "max_length={}", max)?;
+            writeln!(out, "max_length_fit={}", json_number(fit))?;
+        }
+
+        if let Some(probability) = self.leet {
+            writeln!(out, "leet_probability={}", json_number(probability))?;
+        }
+
+        if let Some(memorability) = &self.memorability {
+            writeln!(out, "memorability={}", json_number(memorability.score()))?;
+            writeln!(out, "memorability_label={}", memorability.label())?;
+        }
+
+
+        for (name, bits) in &self.components {
+            writeln!(out, "component_{}={}", name, json_number(*bits))?;
+        }
+
+        writeln!(out, "ascii_equivalent={}", equivalent_length(self.total_bits, PRINTABLE_ASCII))?;
+        writeln!(out, "alnum_equivalent={}", equivalent_length(self.total_bits, ALPHANUMERIC))?;
+
+        if let Some(hash) = &self.target_hash {
+            writeln!(out, "target_hash={}", hash)?;
+            writeln!(out, "target_hash_hardware={}", HASH_HARDWARE)?;
+        }
+
+        for guess in &self.guess_times {
+            writeln!(out, "average_time_{:e}={}", guess.rate, json_number(guess.average))?;
+            writeln!(out, "worst_time_{:e}={}", guess.rate, json_number(guess.worst))?;
+        }
+
+        if let Some((label, kdf)) = &self.derivation {
+            writeln!(out, "derive_label={}", label)?;
+            writeln!(out, "kdf=argon2id")?;
+            writeln!(out, "kdf_memory_kib={}", kdf.memory)?;
+            writeln!(out, "kdf_iterations={}", kdf.iterations)?;
+            writeln!(out, "kdf_lanes={}", kdf.lanes)?;
+        }
+
+        if self.pepper {
+            writeln!(out, "user_pepper=uncounted")?;
+        }
+
+        if let Some((style, bits)) = self.username {
+            writeln!(out, "username_style={}", style)?;
+            writeln!(out, "username_bits={}", json_number(bits))?;
+        }
+
+        if let Some(codes) = self.recovery_codes {
+            writeln!(out, "recovery_codes={}", codes)?;
+            writeln!(out, "recovery_bits={}", json_number(self.attack_bits()))?;
+
+            for &attempts in RECOVERY_ATTEMPTS {
+                let probability = guess_probability(self.total_bits, codes as f64, attempts);
+                writeln!(out, "recovery_guessed_{:e}={}", attempts, json_number(probability))?;
+            }
+        }
+
+        writeln!(out, "strength={}", strength_label(self.total_bits))?;
+
+        Ok(())
+    }
+
+    // the entropy bar, sized to what is left of the terminal after `used` columns
+    fn bar(&self, term: &Terminal, used: usize) -> String {
+        let width = term.width.saturating_sub(used).clamp(BAR_MIN_WIDTH, BAR_MAX_WIDTH);
+        let charset = if term.unicode { BarCharset::Unicode } else { BarCharset::Ascii };
+        let bar = entropy_bar(self.total_bits, self.bar_target, width, charset);
+
+        if !term.color {
+            return bar;
+        }
+
+        let color = match strength_label(self.total_bits) {
+            "weak" => 31,
+            "fair" => 33,
+            _ => 32,
+        };
+
+        format!("\x1b[{}m{}\x1b[0m", color, bar)
+    }
+}
+
+
+/* -------------------- *
+ *      FORMATTING      *
+ * -------------------- */
+/// `t` seconds in words, in the largest unit that shows as at least one.
+pub(crate) fn format_time(t: f64) -> String {
+    // overflowed or absurdly large times carry no meaning beyond this point
+    if t.is_nan() || t >= FOREVER {
+        return String::from("effectively forever (> 10^15 years)");
+    }
+
+    if t < 1.0 {
+        return String::from("less than a second");
+    }
+
+    // largest unit that still displays as at least one
+    let mut idx = TIME_UNITS
+        .iter()
+        .rposition(|&(_, _, scale)| t >= scale)
+        .unwrap_or(0);
+
+    // rounding may reach the next unit, e.g. 59.7 seconds is "1 minute"
+    loop {
+        let (shown, text) = time_in_unit(t.max(TIME_UNITS[idx].2), idx);
+
+        let next = TIME_UNITS.get(idx + 1).map_or(FOREVER, |&(_, _, scale)| scale);
+
+        if shown < next {
+            return text;
+        }
+
+        if idx + 1 == TIME_UNITS.len() {
+            return format_time(FOREVER);
+        }
+
+        idx += 1;
+    }
+}
+
+// `t` in unit `idx` of TIME_UNITS, with the seconds it shows as
+fn time_in_unit(t: f64, idx: usize) -> (f64, String) {
+    let (one, many, scale) = TIME_UNITS[idx];
+
+    // magnitude units keep a decimal below ten, e.g. "4.2 billion years"
+    if scale >= MILLION_YEARS {
+        let x = t / scale;
+        let x = if x < 10.0 { (x * 10.0).round() / 10.0 } else { x.round() };
+
+        let text = match x.fract() {
+            0.0 => format_unit(x, many),
+            _ => format!("{:.1} {}", x, many),
+        };
+
+        return (x * scale, text);
+    }
+
+    // below a century the next smaller unit still carries useful precision
+    if idx > 0 && scale < CENTURY {
+        let (minor_one, minor_many, minor_scale) = TIME_UNITS[idx - 1];
+
+        let mut major = (t / scale).floor();
+        let mut minor = ((t - major * scale) / minor_scale).round();
+
+        if minor * minor_scale >= scale {
+            major += 1.0;
+            minor = 0.0;
+        }
+
+        let shown = major * scale + minor * minor_scale;
+        let major = format!("{} {}", major, if major == 1.0 { one } else { many });
+
+        let text = match minor {
+            0.0 => major,
+            1.0 => format!("{} 1 {}", major, minor_one),
+            m => format!("{} {} {}", major, m, minor_many),
+        };
+
+        return (shown, text);
+    }
+
+    let x = (t / scale).round();
+    (x * scale, format_unit(x, if x == 1.0 { one } else { many }))
+}
+
+/// `x` of `unit` without decimals, grouped by thousands and in magnitude words from a million.
+pub(crate) fn format_unit(x: f64, unit: &str) -> String {
+    const MAGNITUDES: &[(f64, &str)] = &[
+        (1e6, "million"),
+        (1e9, "billion"),
+        (1e12, "trillion"),
+        (1e15, "quadrillion"),
+        (1e18, "quintillion"),
+    ];
+
+    // rounded before the unit is chosen, so 999999.7 carries into "1 million"
+    let rounded = x.round();
+
+    if rounded < 1e4 {
+        return format!("{:.0} {}", rounded, unit);
+    }
+
+    if rounded < 1e6 {
+        let digits = format!("{:.0}", rounded);
+        let (high, low) = digits.split_at(digits.len() - 3);
+
+        return format!("{},{} {}", high, low, unit);
+    }
+
+    for &(scale, name) in MAGNITUDES {
+        let v = x / scale;
+        let shown = if v < 10.0 { (v * 10.0).round() / 10.0 } else { v.round() };
+
+        if shown < 1000.0 {
+            return match shown.fract() {
+                0.0 => format!("{:.0} {} {}", shown, name, unit),
+                _ => format!("{:.1} {} {}", shown, name, unit),
+            };
+        }
+    }
+
+    format!("{} {}", format!("{:.0e}", x).replace('e', "e+"), unit)
+}
+
+
+/* -------------------- *
+ *       COMPARE        *
+ * -------------------- */
+/// Writes a table of the pool size, entropy, length, crack time and memorability of every
+/// configuration of `--compare`, a comma separated list of presets or `file=<path>`, each
+/// optionally followed by `:<count>`.
+///
+/// The columns are aligned if they fit into `term_width` and tab separated otherwise.
+///
+/// ```
+/// use pass_gen::write_comparison;
+///
+/// let mut out = Vec::new();
+/// write_comparison("number:6, ascii:10", 200, &mut out).unwrap();
+///
+/// let table = String::from_utf8(out).unwrap();
+/// let rows: Vec<&str> = table.lines().collect();
+///
+/// assert_eq!(rows.len(), 3);
+/// assert!(rows[1].starts_with("number:6  10         20    6       less than a second"));
+///
+/// // too narrow for the table
+/// let mut out = Vec::new();
+/// write_comparison("number:6", 40, &mut out).unwrap();
+/// assert!(String::from_utf8(out).unwrap().ends_with("number:6\t10\t20\t6\tless than a second\t100/100\n"));
+/// assert!(write_comparison("emoji", 200, &mut Vec::new()).is_err());
+/// ```
+pub fn write_comparison(spec: &str, term_width: usize, out: &mut impl Write) -> Result<(), PassGenError> {
+    let mut rows = vec![[
+        String::from("config"),
+        String::from("pool size"),
+        String::from("bits"),
+        String::from("length"),
+        String::from("average crack time at 1e12 / second"),
+        String::from("memorability"),
+    ]];
+
+    for entry in spec.split(',').map(str::trim) {
+        let (source, count) = match entry.rsplit_once(':') {
+            Some((source, count)) => (source, Some(count)),
+            None => (entry, None),
+        };
+
+        let mut builder = ConfigBuilder::new();
+
+        if let Some(path) = source.strip_prefix("file=") {
+            builder = builder.wordlist_file(path);
+        } else if crate::preset(source).is_some() {
+            builder = builder.preset(source);
+        } else {
+            return Err(PassGenError::Usage(format!("invalid preset {:?} in --compare", source)));
+        }
+
+        if let Some(count) = count {
+            builder = match count.parse() {
+                Ok(n) if n > 0 => builder.count(n),
+                _ => {
+                    let msg = format!("invalid count {:?} in --compare, expected positive number", count);
+                    return Err(PassGenError::Usage(msg));
+                },
+            };
+        }
+
+        let config = builder.build()?;
+
+        let report = EntropyReport::new(
+            config.pool(),
+            config.token_count as f64,
+            Targets { bits: None, time: None },
+            &[("1e12", 1e12)],
+        );
+
+        let (min, avg, max) = config.length();
+
+        rows.push([
+            entry.to_string(),
+            report.pool_size.to_string(),
+            format!("{:.0}", report.total_bits),
+            if min == max { min.to_string() } else { format!("{}–{} (avg {:.0})", min, max, avg) },
+            format_time(report.guess_times[0].average),
+            format!("{:.0}/100", config.memorability().score()),
+        ]);
+    }
+
+    let mut widths = [0; 6];
+
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    // fall back to tab separated columns when the table doesn't fit
+    let aligned = widths.iter().sum::<usize>() + 2 * (widths.len() - 1) <= term_width;
+
+    for row in &rows {
+        let line: Vec<String> = if aligned {
+            row.iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{}{}", cell, " ".repeat(width - cell.chars().count())))
+                .collect()
+        } else {
+            row.to_vec()
+        };
+
+        writeln!(out, "{}", line.join(if aligned { "  " } else { "\t" }).trim_end())?;
+    }
+
+    Ok(())
+}
+
+
+/* -------------------- *
+ *       ESTIMATE       *
+ * -------------------- */
+/// Writes the [`EntropyReport`] of a `phrase` that was drawn from the pool of `config`, split
+/// into tokens by its separator, with a warning if tokens are missing from the pool.
+pub fn write_estimate(config: &Config, phrase: &str, terminal: &Terminal, out: &mut impl Write) -> io::Result<()> {
+    let pool: HashSet<&str> = config.token_data.range().map(|i| config.token_data.get(i)).collect();
+
+    let tokens: Vec<&str> = match config.token_sep.as_str() {
+        "" => phrase.char_indices().map(|(i, c)| &phrase[i..i + c.len_utf8()]).collect(),
+        sep if sep.trim().is_empty() => phrase.split_whitespace().collect(),
+        sep => phrase.split(sep).collect(),
+    };
+
+    let found = tokens.iter().filter(|t| pool.contains(*t)).count();
+
+    match config.report_format {
+        ReportFormat::Human => {
+            writeln!(out, "tokens:                     {}", tokens.len())?;
+            writeln!(out, "found in pool:              {}", found)?;
+        },
+        ReportFormat::Kv => {
+            writeln!(out, "estimate_tokens={}", tokens.len())?;
+            writeln!(out, "estimate_found={}", found)?;
+        },
+        ReportFormat::Json => {
+            writeln!(out, "{{\"estimate_tokens\": {}, \"estimate_found\": {}}}", tokens.len(), found)?;
+        },
+    }
+
+    if found < tokens.len() && matches!(config.report_format, ReportFormat::Human) {
+        writeln!(
+            out,
+            "warning: {} token(s) are not in the pool, the estimate below is unreliable",
+            tokens.len() - found
+        )?;
+    }
+
+    let mut report = EntropyReport::new(
+        Pool {
+            size: config.token_data.len(),
+            per_token_bits: config.token_data.entropy_bits_per_token(),
+            filters: config.pool_filters.clone(),
+            length: Some((grapheme_len(phrase), grapheme_len(phrase) as f64, grapheme_len(phrase))),
+            components: vec![("tokens", config.token_data.entropy_bits_per_token() * tokens.len() as f64)],
+        },
+        tokens.len() as f64,
+        Targets { bits: None, time: None },
+        &config.rates(),
+    );
+
+    report.bar_target = config.bar_target;
+    report.target_hash = config.target_hash.as_ref().map(TargetHash::to_string);
+
+    report.write(config.report_format, terminal, out)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_time_picks_the_unit() {
+        assert_eq!(format_time(0.5), "less than a second");
+        assert_eq!(format_time(90.0), "1 minute 30 seconds");
+        assert_eq!(format_time(3.0 * 31557600.0), "3 years");
+        assert_eq!(format_time(4.2e9 * 31557600.0), "4.2 billion years");
+        assert_eq!(format_time(f64::INFINITY), "effectively forever (> 10^15 years)");
+    }
+
+    #[test]
+    fn format_unit_groups_digits() {
+        assert_eq!(format_unit(12.3, "days"), "12 days");
+        assert_eq!(format_unit(12345.0, "days"), "12,345 days");
+        assert_eq!(format_unit(4.2e9, "years"), "4.2 billion years");
+    }
+
+    #[test]
+    fn format_unit_carries_into_the_next_magnitude() {
+        let cases = [
+            (0.4, "0 days"),
+            (9999.4, "9999 days"),
+            (9999.6, "10,000 days"),
+            (999999.4, "999,999 days"),
+            (999999.7, "1 million days"),
+            (1.04e6, "1 million days"),
+            (1.05e6, "1.1 million days"),
+            (9.94e6, "9.9 million days"),
+            (9.96e6, "10 million days"),
+            (999.4e6, "999 million days"),
+            (999.97e6, "1 billion days"),
+            (999.6e9, "1 trillion days"),
+            (999.6e12, "1 quadrillion days"),
+            (999.6e15, "1 quintillion days"),
+            (999.4e18, "999 quintillion days"),
+            (999.6e18, "1e+21 days"),
+        ];
+
+        for (x, expected) in cases {
+            assert_eq!(format_unit(x, "days"), expected, "{}", x);
+        }
+    }
+
+    #[test]
+    fn format_time_carries_into_the_next_unit() {
+        let cases = [
+            (59.4, "59 seconds"),
+            (59.7, "1 minute"),
+            (MINUTE * 59.0 + 59.4, "59 minutes 59 seconds"),
+            (MINUTE * 59.0 + 59.7, "1 hour"),
+            (HOUR * 23.0 + MINUTE * 59.7, "1 day"),
+            (DAY * 6.0 + HOUR * 23.7, "1 week"),
+            (WEEK * 4.0 + DAY * 2.4, "4 weeks 2 days"),
+            (MONTH * 11.0 + WEEK * 4.4, "1 year"),
+            (YEAR * 99.0 + MONTH * 11.7, "1 century"),
+            (CENTURY * 9.4, "9 centuries"),
+            (CENTURY * 9.6, "1 millennium"),
+            (MILLENNIUM * 999.4, "999 millennia"),
+            (MILLENNIUM * 999.6, "1 million years"),
+            (MILLION_YEARS * 999.97, "1 billion years"),
+        ];
+
+        for (t, expected) in cases {
+            assert_eq!(format_time(t), expected, "{}", t);
+        }
+    }
+
+    #[test]
+    fn format_time_in_million_and_billion_years() {
+        let cases = [
+            (MILLION_YEARS * 1.04, "1 million years"),
+            (MILLION_YEARS * 4.24, "4.2 million years"),
+            (MILLION_YEARS * 9.96, "10 million years"),
+            (MILLION_YEARS * 35.4, "35 million years"),
+            (BILLION_YEARS * 4.2, "4.2 billion years"),
+            (BILLION_YEARS * 38300.0, "38,300 billion years"),
+            (BILLION_YEARS * 999999.4, "999,999 billion years"),
+            (BILLION_YEARS * 999999.6, "effectively forever (> 10^15 years)"),
+        ];
+
+        for (t, expected) in cases {
+            assert_eq!(format_time(t), expected, "{}", t);
+        }
+    }
+
+    #[test]
+    fn format_time_saturates_at_both_ends() {
+        let forever = "effectively forever (> 10^15 years)";
+        let cases = [
+            (f64::NAN, forever),
+            (f64::INFINITY, forever),
+            (f64::MAX, forever),
+            (FOREVER, forever),
+            (FOREVER * 0.99, "990,000 billion years"),
+            (0.0, "less than a second"),
+            (-1.0, "less than a second"),
+            (f64::NEG_INFINITY, "less than a second"),
+            (0.999, "less than a second"),
+            (1.0, "1 second"),
+            (1.4, "1 second"),
+            (61.4, "1 minute 1 second"),
+            (DAY + HOUR * 2.0, "1 day 2 hours"),
+        ];
+
+        for (t, expected) in cases {
+            assert_eq!(format_time(t), expected, "{}", t);
+        }
+    }
+
+    #[test]
+    fn format_unit_groups_thousands_and_keeps_one_decimal() {
+        let cases = [
+            (1234.0, "1234 guesses"),
+            (12345.0, "12,345 guesses"),
+            (123456.4, "123,456 guesses"),
+            (1.24e6, "1.2 million guesses"),
+            (1.26e6, "1.3 million guesses"),
+            (2e9, "2 billion guesses"),
+            (12.34e9, "12 billion guesses"),
+            (f64::INFINITY, "inf guesses"),
+        ];
+
+        for (x, expected) in cases {
+            assert_eq!(format_unit(x, "guesses"), expected, "{}", x);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::{borrow::Cow, cmp::Reverse, fmt};
+
+/* -------------------- *
+ *        RETRY         *
+ * -------------------- */
+/// Draws candidates again until every registered check accepts them.
+///
+/// Checks run in the order they were added and a candidate counts as rejected by the first
+/// one refusing it. After `limit` rejected candidates it gives up with a breakdown of why.
+pub(crate) struct Retry<'a, T> {
+    limit: u32,
+    checks: Vec<Check<'a, T>>,
+}
+
+// a reason and the check counting under it
+type Check<'a, T> = (Cow<'a, str>, Box<dyn Fn(&T) -> bool + 'a>);
+
+impl<'a, T> Retry<'a, T> {
+    /// Gives up once `limit` candidates have been rejected.
+    pub fn new(limit: u32) -> Self {
+        Retry { limit, checks: Vec::new() }
+    }
+
+    /// Adds a check, candidates it refuses are counted under `reason`.
+    pub fn check(&mut self, reason: impl Into<Cow<'a, str>>, accepts: impl Fn(&T) -> bool + 'a) -> &mut Self {
+        self.checks.push((reason.into(), Box::new(accepts)));
+        self
+    }
+
+    /// The reason of the first check refusing `candidate`, if any does.
+    pub fn rejection(&self, candidate: &T) -> Option<&str> {
+        self.checks.iter().find(|(_, accepts)| !accepts(candidate)).map(|(reason, _)| reason.as_ref())
+    }
+
+    /// Replaces `candidate` with `redraw` until every check accepts it, the rejections are
+    /// added to `tally` either way and returned on their own when the limit is reached.
+    pub fn until_accepted(
+        &self,
+        candidate: &mut T,
+        mut redraw: impl FnMut(&mut T),
+        tally: &mut Rejections,
+    ) -> Result<(), Rejections> {
+        let mut rejected = Rejections::default();
+
+        while let Some(reason) = self.rejection(candidate) {
+            rejected.add(reason, 1);
+            tally.add(reason, 1);
+
+            if rejected.total() >= self.limit as u64 {
+                return Err(rejected);
+            }
+
+            redraw(candidate);
+        }
+
+        Ok(())
+    }
+}
+
+/// Rejected candidates counted by reason, see [`Retry`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Rejections {
+    counts: Vec<(String, u64)>,
+}
+
+impl Rejections {
+    pub fn add(&mut self, reason: &str, count: u64) {
+        match self.counts.iter_mut().find(|(seen, _)| seen == reason) {
+            Some((_, total)) => *total += count,
+            None => self.counts.push((reason.to_string(), count)),
+        }
+    }
+
+    pub fn count(&self, reason: &str) -> u64 {
+        self.counts.iter().find(|(seen, _)| seen == reason).map_or(0, |(_, count)| *count)
+    }
+
+    pub fn total(&self) -> u64 {
+        self.counts.iter().map(|(_, count)| count).sum()
+    }
+
+    /// The reason most candidates were rejected for.
+    pub fn most_common(&self) -> Option<&str> {
+        self.sorted().first().map(|(reason, _)| reason.as_str())
+    }
+
+    // largest share first, ties in the order they were first seen
+    fn sorted(&self) -> Vec<&(String, u64)> {
+        let mut counts: Vec<&(String, u64)> = self.counts.iter().collect();
+        counts.sort_by_key(|(_, count)| Reverse(*count));
+        counts
+    }
+}
+
+impl fmt::Display for Rejections {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let counts: Vec<String> = self.sorted().iter().map(|(reason, count)| format!("{} {}", count, reason)).collect();
+
+        write!(f, "rejected {} candidates: {}", self.total(), counts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejections_are_counted_by_reason() {
+        // a check that always fails exhausts the limit
+        let mut retry = Retry::new(1000);
+        retry.check("missing symbol", |_: &u32| false);
+
+        let mut tally = Rejections::default();
+        let rejected = retry.until_accepted(&mut 0, |n| *n += 1, &mut tally).unwrap_err();
+
+        assert_eq!(rejected.total(), 1000);
+        assert_eq!(rejected.to_string(), "rejected 1000 candidates: 1000 missing symbol");
+        assert_eq!(tally.count("missing symbol"), 1000);
+
+        // the first check to refuse counts, largest share first
+        let mut retry = Retry::new(100);
+        retry.check("missing symbol", |n: &u32| n.is_multiple_of(3)).check("HIBP hit", |_| false);
+
+        let rejected = retry.until_accepted(&mut 1, |n| *n += 1, &mut Rejections::default()).unwrap_err();
+        assert_eq!(rejected.to_string(), "rejected 100 candidates: 67 missing symbol, 33 HIBP hit");
+        assert_eq!(rejected.most_common(), Some("missing symbol"));
+
+        // a check that fails a fixed number of times lets the next candidate through
+        let mut retry = Retry::new(10);
+        retry.check("too short", |n: &u32| *n >= 9);
+
+        let mut candidate = 0;
+        let mut tally = Rejections::default();
+
+        assert!(retry.until_accepted(&mut candidate, |n| *n += 1, &mut tally).is_ok());
+        assert_eq!((candidate, tally.total()), (9, 9));
+
+        // one rejection less allowed and it gives up
+        let mut retry = Retry::new(9);
+        retry.check("too short", |n: &u32| *n >= 9);
+
+        assert!(retry.until_accepted(&mut 0, |n| *n += 1, &mut tally).is_err());
+        assert_eq!(tally.count("too short"), 18);
+
+        // without checks every candidate is accepted
+        let retry: Retry<u32> = Retry::new(1);
+
+        assert!(retry.rejection(&0).is_none() && retry.until_accepted(&mut 0, |_| {}, &mut tally).is_ok());
+    }
+}
@@ -0,0 +1,195 @@
+use crate::Config;
+use std::{
+    io::{self, Write},
+    ptr,
+    sync::atomic::{compiler_fence, AtomicUsize, Ordering},
+};
+
+// bytes the buffers of new writers may still lock, none until lock_buffers is called
+static LOCK_BUDGET: AtomicUsize = AtomicUsize::new(0);
+
+/* -------------------- *
+ *        WIPING        *
+ * -------------------- */
+/// Overwrites the whole allocation of `buf` with zeros and empties it.
+///
+/// Only memory the buffer currently owns is wiped, copies left behind by
+/// earlier reallocations are not, so secrets should be built in buffers
+/// allocated with enough capacity up front.
+pub fn wipe(buf: &mut Vec<u8>) {
+    let ptr = buf.as_mut_ptr();
+
+    for i in 0..buf.capacity() {
+        // SAFETY: every byte up to the capacity belongs to the allocation,
+        // volatile keeps the writes from being optimized away
+        unsafe { ptr::write_volatile(ptr.add(i), 0) };
+    }
+
+    buf.clear();
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// [`wipe`] for strings.
+pub fn wipe_string(str: &mut String) {
+    // SAFETY: the string is empty afterwards, so no invalid utf-8 is observable
+    wipe(unsafe { str.as_mut_vec() });
+}
+
+/// Locks the buffer of every [`WipingWriter`] created from now on into memory, `limit` bytes in total.
+///
+/// A fallback for when the whole process cannot be locked, every password passes through these
+/// buffers but is generated in memory that stays unlocked. Returns the number of buffers that fit
+/// the limit, 0 where locking is not supported.
+pub fn lock_buffers(limit: usize) -> usize {
+    if !cfg!(unix) {
+        return 0;
+    }
+
+    LOCK_BUDGET.store(limit, Ordering::SeqCst);
+    limit / WIPING_CAPACITY
+}
+
+const WIPING_CAPACITY: usize = 64 * 1024;
+
+/// A buffered writer that wipes its buffer after every flush and on drop.
+pub struct WipingWriter<W: Write> {
+    inner: W,
+    buf: Vec<u8>,
+    locked: bool,
+}
+
+impl<W: Write> WipingWriter<W> {
+    pub fn new(inner: W) -> Self {
+        let mut writer = Self { inner, buf: Vec::with_capacity(WIPING_CAPACITY), locked: false };
+
+        if LOCK_BUDGET.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |b| b.checked_sub(WIPING_CAPACITY)).is_ok() {
+            writer.locked = writer.lock();
+
+            if !writer.locked {
+                LOCK_BUDGET.fetch_add(WIPING_CAPACITY, Ordering::SeqCst);
+            }
+        }
+
+        writer
+    }
+
+    // the buffer never grows, so its allocation stays the one that is locked
+    fn lock(&mut self) -> bool {
+        #[cfg(unix)]
+        // SAFETY: the range is the allocation of the buffer, which outlives the lock
+        return unsafe { libc::mlock(self.buf.as_ptr().cast(), self.buf.capacity()) } == 0;
+
+        #[cfg(not(unix))]
+        false
+    }
+
+    fn flush_buf(&mut self) -> io::Result<()> {
+        let res = self.inner.write_all(&self.buf);
+        wipe(&mut self.buf);
+        res
+    }
+}
+
+impl<W: Write> Write for WipingWriter<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        // never let the buffer grow, a reallocation would leave a copy behind
+        if self.buf.len() + data.len() > self.buf.capacity() {
+            self.flush_buf()?;
+        }
+
+        if data.len() >= self.buf.capacity() {
+            return self.inner.write(data);
+        }
+
+        self.buf.extend_from_slice(data);
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.flush_buf()?;
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> Drop for WipingWriter<W> {
+    fn drop(&mut self) {
+        wipe(&mut self.buf);
+
+        #[cfg(unix)]
+        if self.locked {
+            // SAFETY: unlocks the range locked in new, before the buffer is freed
+            unsafe { libc::munlock(self.buf.as_ptr().cast(), self.buf.capacity()) };
+            LOCK_BUDGET.fetch_add(WIPING_CAPACITY, Ordering::SeqCst);
+        }
+    }
+}
+
+
+/* -------------------- *
+ *       SECRETS        *
+ * -------------------- */
+/// What is read from the user once the [`Config`] checks out, kept out of it and wiped when dropped.
+#[derive(Default)]
+pub struct Secrets {
+    /// The seed of `--derive`, takes precedence over `--seed`.
+    pub seed: Option<[u8; 32]>,
+    /// The pepper of `--pepper`.
+    pub pepper: Option<String>,
+}
+
+impl Secrets {
+    /// The password as written, the pepper follows the separator of `config`.
+    pub fn peppered(&self, config: &Config, password: &str) -> Option<String> {
+        let pepper = self.pepper.as_ref()?;
+        let mut peppered = String::with_capacity(password.len() + config.token_sep.len() + pepper.len());
+
+        peppered.push_str(password);
+        peppered.push_str(&config.token_sep);
+        peppered.push_str(pepper);
+
+        Some(peppered)
+    }
+}
+
+impl Drop for Secrets {
+    fn drop(&mut self) {
+        for byte in self.seed.iter_mut().flatten() {
+            // SAFETY: a valid reference, volatile keeps the write from being optimized away
+            unsafe { ptr::write_volatile(byte, 0) };
+        }
+
+        if let Some(pepper) = &mut self.pepper {
+            wipe_string(pepper);
+        }
+
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(unix)]
+    fn buffers_are_locked_within_the_limit() {
+        assert_eq!(lock_buffers(2 * WIPING_CAPACITY + 1), 2);
+
+        // a RLIMIT_MEMLOCK below the two buffers fails their lock, which gives the budget back
+        let first = WipingWriter::new(io::sink());
+        let second = WipingWriter::new(io::sink());
+        let unlocked = [&first, &second].iter().filter(|writer| !writer.locked).count();
+
+        assert!(!WipingWriter::new(io::sink()).locked);
+        assert_eq!(LOCK_BUDGET.load(Ordering::SeqCst), 1 + unlocked * WIPING_CAPACITY);
+
+        // dropping a locked buffer returns its share
+        let locked = first.locked;
+        drop(first);
+
+        assert_eq!(WipingWriter::new(io::sink()).locked, locked);
+        assert_eq!(lock_buffers(0), 0);
+        assert!(!WipingWriter::new(io::sink()).locked);
+    }
+}
@@ -0,0 +1,308 @@
+use crate::{class_weights, draws_require_probability, slots_max_length_probability, source_weights};
+use crate::{ClassSet, PassGenError, TokenData, TokenSource};
+use rand::Rng;
+use std::fmt;
+
+/* -------------------- *
+ *    PART OF SPEECH    *
+ * -------------------- */
+/// A word class of a sentence, each with its own embedded word list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartOfSpeech {
+    Adjective,
+    Noun,
+    Adverb,
+    Verb,
+}
+
+impl PartOfSpeech {
+    pub const ALL: [PartOfSpeech; 4] =
+        [PartOfSpeech::Adjective, PartOfSpeech::Noun, PartOfSpeech::Adverb, PartOfSpeech::Verb];
+
+    /// The tag used in sentence templates.
+    pub fn tag(self) -> &'static str {
+        match self {
+            PartOfSpeech::Adjective => "ADJ",
+            PartOfSpeech::Noun => "NOUN",
+            PartOfSpeech::Adverb => "ADV",
+            PartOfSpeech::Verb => "VERB",
+        }
+    }
+
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|pos| pos.tag().eq_ignore_ascii_case(tag))
+    }
+
+    /// Plural name, for reports.
+    pub fn name(self) -> &'static str {
+        match self {
+            PartOfSpeech::Adjective => "adjectives",
+            PartOfSpeech::Noun => "nouns",
+            PartOfSpeech::Adverb => "adverbs",
+            PartOfSpeech::Verb => "verbs",
+        }
+    }
+
+    /// The word list, empty when built without the `sentence` feature.
+    pub fn words(self) -> TokenData {
+        macro_rules! list {
+            ($name:ident) => {{
+                #[cfg(feature = "sentence")]
+                let list = TokenData::StaticPacked {
+                    text: crate::data::$name::TOKEN_TEXT,
+                    offsets: &crate::data::$name::TOKEN_OFFSETS,
+                };
+
+                #[cfg(not(feature = "sentence"))]
+                let list = TokenData::StaticPacked { text: "", offsets: &[0] };
+
+                list
+            }};
+        }
+
+        match self {
+            PartOfSpeech::Adjective => list!(adjective),
+            PartOfSpeech::Noun => list!(noun),
+            PartOfSpeech::Adverb => list!(adverb),
+            PartOfSpeech::Verb => list!(verb),
+        }
+    }
+}
+
+/// The builtin sentence templates, one is picked at random for every password.
+pub(crate) const SENTENCE_TEMPLATES: &[&[PartOfSpeech]] = {
+    use PartOfSpeech::*;
+
+    &[
+        &[Adjective, Noun, Adverb, Verb, Noun],
+        &[Adjective, Noun, Verb, Adjective, Noun],
+        &[Noun, Adverb, Verb, Adjective, Noun],
+        &[Adjective, Adjective, Noun, Verb, Noun],
+    ]
+};
+
+
+/* -------------------- *
+ *       SENTENCE       *
+ * -------------------- */
+/// Passphrases that read like a sentence, one word list per part of speech.
+///
+/// No word is in two lists, so the parts of speech, and with them the template, can be
+/// read back from a passphrase. The template choice then adds `log2` of the number of
+/// templates, as long as the separator keeps the words apart.
+///
+/// ```
+/// use pass_gen::{Generator, PartOfSpeech, Sentence, TokenSource};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let sentence = Sentence::parse("adj-noun-adv-verb-noun").unwrap();
+/// let nouns = PartOfSpeech::Noun.words();
+/// let mut gen = Generator::new(&nouns, 1, "-", StdRng::seed_from_u64(1)).sentence(&sentence);
+///
+/// let password = gen.generate();
+/// let words: Vec<&str> = password.split('-').collect();
+///
+/// assert_eq!(words.len(), 5);
+/// assert!(nouns.range().any(|idx| nouns.get(idx) == words[4]));
+///
+/// // every slot adds the entropy of its own list
+/// let bits: f64 = sentence.components().iter().map(|(_, bits)| bits).sum();
+/// assert!((bits - sentence.bits()).abs() < 1e-9 && bits < 5.0 * 10.0);
+///
+/// // the lists are disjoint
+/// let all = PartOfSpeech::ALL.map(|pos| pos.words());
+/// let mut words: Vec<&str> = all.iter().flat_map(|list| list.range().map(|idx| list.get(idx))).collect();
+/// let total = words.len();
+///
+/// words.sort_unstable();
+/// words.dedup();
+/// assert_eq!(words.len(), total);
+///
+/// assert!(Sentence::parse("ADJ-NOUN-PRONOUN").unwrap_err().to_string().contains("\"PRONOUN\""));
+/// ```
+#[derive(Debug)]
+pub struct Sentence {
+    templates: Vec<Vec<PartOfSpeech>>,
+    lists: [TokenData; 4],
+}
+
+impl Sentence {
+    /// Picks one of the sentence templates for every password.
+    pub fn new() -> Result<Sentence, PassGenError> {
+        Self::with_templates(SENTENCE_TEMPLATES.iter().map(|template| template.to_vec()).collect())
+    }
+
+    /// Uses a single template of tags separated by dashes, like `ADJ-NOUN-VERB-NOUN`.
+    pub fn parse(template: &str) -> Result<Sentence, PassGenError> {
+        let slots = template
+            .split('-')
+            .map(|tag| {
+                PartOfSpeech::from_tag(tag.trim()).ok_or_else(|| {
+                    PassGenError::Usage(format!(
+                        "invalid sentence template {:?}: unknown part of speech {:?}, \
+                        expected ADJ, NOUN, ADV or VERB",
+                        template, tag
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::with_templates(vec![slots])
+    }
+
+    fn with_templates(templates: Vec<Vec<PartOfSpeech>>) -> Result<Sentence, PassGenError> {
+        let lists = PartOfSpeech::ALL.map(PartOfSpeech::words);
+
+        if lists.iter().any(TokenSource::is_empty) {
+            let msg = "sentence word lists are missing, pass-gen was built without the \"sentence\" feature";
+            return Err(PassGenError::InvalidData(msg.to_string()));
+        }
+
+        Ok(Sentence { templates, lists })
+    }
+
+    pub fn templates(&self) -> &[Vec<PartOfSpeech>] {
+        &self.templates
+    }
+
+    pub fn words(&self, pos: PartOfSpeech) -> &TokenData {
+        &self.lists[pos as usize]
+    }
+
+    /// Picks the template of the next password.
+    pub fn pick<R: Rng>(&self, rng: &mut R) -> &[PartOfSpeech] {
+        match self.templates.len() {
+            1 => &self.templates[0],
+            len => &self.templates[rng.gen_range(0..len)],
+        }
+    }
+
+    /// Entropy in bits of the template choice and of each part of speech, averaged over the templates.
+    pub fn components(&self) -> Vec<(&'static str, f64)> {
+        let count = self.templates.len() as f64;
+        let mut components = Vec::new();
+
+        if self.templates.len() > 1 {
+            components.push(("sentence templates", count.log2()));
+        }
+
+        for pos in PartOfSpeech::ALL {
+            let slots = self.templates.iter().flatten().filter(|&&slot| slot == pos).count() as f64 / count;
+
+            if slots > 0.0 {
+                components.push((pos.name(), self.words(pos).entropy_bits_per_token() * slots));
+            }
+        }
+
+        components
+    }
+
+    pub fn bits(&self) -> f64 {
+        self.components().iter().map(|(_, bits)| bits).sum()
+    }
+
+    /// Entropy in bits of the weakest template, the template choice included.
+    pub fn min_bits(&self) -> f64 {
+        let choice = (self.templates.len() as f64).log2();
+
+        self.templates
+            .iter()
+            .map(|template| template.iter().map(|&pos| self.words(pos).entropy_bits_per_token()).sum::<f64>())
+            .fold(f64::INFINITY, f64::min)
+            + choice
+    }
+
+    /// Number of distinct passphrases.
+    pub fn combinations(&self) -> f64 {
+        self.templates
+            .iter()
+            .map(|template| template.iter().map(|&pos| self.words(pos).len() as f64).product::<f64>())
+            .sum()
+    }
+
+    /// Mean number of words.
+    pub fn avg_words(&self) -> f64 {
+        self.templates.iter().map(Vec::len).sum::<usize>() as f64 / self.templates.len() as f64
+    }
+
+    /// Minimum, mean and maximum length in characters with separators of `sep_len` characters.
+    pub fn length_stats(&self, sep_len: usize) -> (usize, f64, usize) {
+        let stats: Vec<(usize, f64, usize)> = self
+            .templates
+            .iter()
+            .map(|template| {
+                let seps = sep_len * (template.len() - 1);
+
+                template.iter().map(|&pos| self.words(pos).length_stats()).fold(
+                    (seps, seps as f64, seps),
+                    |(min, avg, max), (lmin, lavg, lmax)| (min + lmin, avg + lavg, max + lmax),
+                )
+            })
+            .collect();
+
+        let min = stats.iter().map(|s| s.0).min().unwrap_or(0);
+        let max = stats.iter().map(|s| s.2).max().unwrap_or(0);
+        let avg = stats.iter().map(|s| s.1).sum::<f64>() / stats.len().max(1) as f64;
+
+        (min, avg, max)
+    }
+
+    /// Upper bound in bytes with separators of at most `sep_len` bytes.
+    pub fn max_len(&self, sep_len: usize) -> usize {
+        self.templates
+            .iter()
+            .map(|template| {
+                let words: usize = template.iter().map(|&pos| self.words(pos).max_token_len()).sum();
+                words + sep_len * (template.len() - 1)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Probability that a passphrase contains every class in `required`, separators drawn from `seps`.
+    pub fn require_probability(&self, required: ClassSet, seps: &[&str]) -> f64 {
+        let separators = class_weights(seps.iter().copied());
+
+        let total: f64 = self
+            .templates
+            .iter()
+            .map(|template| {
+                let mut draws: Vec<([f64; 16], u32)> =
+                    template.iter().map(|&pos| (source_weights(self.words(pos)), 1)).collect();
+
+                draws.push((separators, template.len() as u32 - 1));
+                draws_require_probability(required, &draws)
+            })
+            .sum();
+
+        total / self.templates.len() as f64
+    }
+
+    /// Probability that a passphrase is at most `max_length` characters long.
+    pub fn max_length_probability(&self, sep_len: usize, max_length: usize) -> f64 {
+        let total: f64 = self
+            .templates
+            .iter()
+            .map(|template| {
+                let slots: Vec<&dyn TokenSource> =
+                    template.iter().map(|&pos| self.words(pos) as &dyn TokenSource).collect();
+
+                slots_max_length_probability(&slots, sep_len, max_length)
+            })
+            .sum();
+
+        total / self.templates.len() as f64
+    }
+}
+
+impl fmt::Display for Sentence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let templates: Vec<String> = self
+            .templates
+            .iter()
+            .map(|template| template.iter().map(|pos| pos.tag()).collect::<Vec<_>>().join("-"))
+            .collect();
+
+        write!(f, "{}", templates.join(", "))
+    }
+}
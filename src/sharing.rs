@@ -0,0 +1,253 @@
+use crate::{wipe, PassGenError};
+use rand::{CryptoRng, RngCore};
+
+/* -------------------- *
+ *    SECRET SHARING    *
+ * -------------------- */
+/// Splits a secret into `shares` shares any `threshold` of which reconstruct it, with Shamir's
+/// scheme over GF(256).
+///
+/// Every byte of the secret is the constant term of its own random polynomial of degree
+/// `threshold - 1`, share `x` holds the value of each polynomial at `x`. Fewer shares than the
+/// threshold leave every value of the secret equally likely.
+///
+/// ```
+/// use pass_gen::{combine_shares, SecretSharing, Share};
+/// use rand::rngs::OsRng;
+///
+/// let sharing = SecretSharing::new(2, 3).unwrap();
+/// let shares = sharing.split(b"correct horse", &mut OsRng);
+///
+/// assert_eq!(shares.len(), 3);
+/// assert_eq!(combine_shares(&shares[1..]).unwrap(), b"correct horse");
+/// assert_eq!(combine_shares(&[shares[2].clone(), shares[0].clone()]).unwrap(), b"correct horse");
+///
+/// // one share is not enough
+/// assert!(combine_shares(&shares[..1]).is_err());
+///
+/// // the encoding keeps the version, threshold and index
+/// let decoded = Share::decode(&shares[1].encode()).unwrap();
+/// assert_eq!((decoded.threshold(), decoded.index()), (2, 2));
+/// assert_eq!(decoded, shares[1]);
+/// ```
+///
+/// Round trips for any subset of at least the threshold:
+///
+/// ```
+/// use pass_gen::{combine_shares, SecretSharing};
+/// use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(1);
+///
+/// for (threshold, count) in [(2, 2), (2, 5), (3, 5), (5, 5), (4, 9), (10, 255)] {
+///     let sharing = SecretSharing::new(threshold, count).unwrap();
+///     let secret: Vec<u8> = (0..rng.gen_range(1..64)).map(|_| rng.gen()).collect();
+///     let mut shares = sharing.split(&secret, &mut rng);
+///
+///     for _ in 0..20 {
+///         shares.shuffle(&mut rng);
+///         let used = rng.gen_range(threshold..=count) as usize;
+///
+///         assert_eq!(combine_shares(&shares[..used]).unwrap(), secret);
+///         assert!(combine_shares(&shares[..threshold as usize - 1]).is_err());
+///     }
+/// }
+///
+/// assert!(SecretSharing::new(1, 3).is_err() && SecretSharing::new(4, 3).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SecretSharing {
+    threshold: u8,
+    shares: u8,
+}
+
+impl SecretSharing {
+    pub fn new(threshold: u32, shares: u32) -> Result<SecretSharing, PassGenError> {
+        if threshold < 2 {
+            return Err(PassGenError::Usage(String::from("a threshold below 2 would make every share the secret")));
+        }
+
+        if threshold > shares || shares > 255 {
+            let msg = format!("cannot split into {} shares with a threshold of {}, at most 255", shares, threshold);
+            return Err(PassGenError::Usage(msg));
+        }
+
+        Ok(SecretSharing { threshold: threshold as u8, shares: shares as u8 })
+    }
+
+    /// Shares needed to reconstruct the secret.
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    pub fn shares(&self) -> u8 {
+        self.shares
+    }
+
+    /// Shares of `secret` with indices 1 to [`SecretSharing::shares`], the coefficients come from `rng`.
+    pub fn split<R: RngCore + CryptoRng>(&self, secret: &[u8], rng: &mut R) -> Vec<Share> {
+        let mut shares: Vec<Share> = (1..=self.shares)
+            .map(|index| Share { threshold: self.threshold, index, data: Vec::with_capacity(secret.len()) })
+            .collect();
+
+        let mut coefficients = vec![0; self.threshold as usize];
+
+        for &byte in secret {
+            coefficients[0] = byte;
+            rng.fill_bytes(&mut coefficients[1..]);
+
+            for share in &mut shares {
+                // horner's rule from the highest coefficient
+                let value = coefficients.iter().rev().fold(0, |acc, &coef| gf_mul(acc, share.index) ^ coef);
+                share.data.push(value);
+            }
+        }
+
+        wipe(&mut coefficients);
+        shares
+    }
+}
+
+/// Reconstructs the secret from at least as many shares as their threshold, in any order.
+pub fn combine_shares(shares: &[Share]) -> Result<Vec<u8>, PassGenError> {
+    let first = match shares.first() {
+        Some(first) => first,
+        None => return Err(PassGenError::InvalidData(String::from("no shares to combine"))),
+    };
+
+    for (pos, share) in shares.iter().enumerate() {
+        if share.threshold != first.threshold || share.data.len() != first.data.len() {
+            return Err(PassGenError::InvalidData(String::from("the shares come from different secrets")));
+        }
+
+        if shares[..pos].iter().any(|other| other.index == share.index) {
+            return Err(PassGenError::InvalidData(format!("share {} is given twice", share.index)));
+        }
+    }
+
+    if shares.len() < first.threshold as usize {
+        return Err(PassGenError::InvalidData(format!(
+            "{} shares are needed to reconstruct the secret, got {}",
+            first.threshold,
+            shares.len()
+        )));
+    }
+
+    // lagrange interpolation at 0, where subtraction is xor
+    let shares = &shares[..first.threshold as usize];
+    let weights: Vec<u8> = shares
+        .iter()
+        .map(|share| {
+            shares.iter().filter(|other| other.index != share.index).fold(1, |weight, other| {
+                gf_mul(weight, gf_mul(other.index, gf_inv(other.index ^ share.index)))
+            })
+        })
+        .collect();
+
+    let secret = (0..first.data.len())
+        .map(|pos| shares.iter().zip(&weights).fold(0, |acc, (share, &weight)| acc ^ gf_mul(share.data[pos], weight)))
+        .collect();
+
+    Ok(secret)
+}
+
+/// One share of a secret, see [`SecretSharing`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct Share {
+    threshold: u8,
+    index: u8,
+    data: Vec<u8>,
+}
+
+impl Share {
+    /// Version byte of the encoding.
+    pub const VERSION: u8 = 1;
+
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// The x coordinate of the share, from 1.
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Lowercase hex of the version, threshold, index and data bytes.
+    pub fn encode(&self) -> String {
+        let mut hex = String::with_capacity((self.data.len() + 3) * 2);
+
+        for byte in [Self::VERSION, self.threshold, self.index].iter().chain(&self.data) {
+            hex.push(char::from_digit((byte >> 4) as u32, 16).unwrap());
+            hex.push(char::from_digit((byte & 0xf) as u32, 16).unwrap());
+        }
+
+        hex
+    }
+
+    /// Parses [`Share::encode`], surrounding whitespace and uppercase digits are accepted.
+    pub fn decode(hex: &str) -> Result<Share, PassGenError> {
+        let invalid = |msg: &str| PassGenError::InvalidData(format!("invalid share: {}", msg));
+        let hex = hex.trim();
+
+        if !hex.len().is_multiple_of(2) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid("expected an even number of hex digits"));
+        }
+
+        let mut bytes: Vec<u8> =
+            (0..hex.len()).step_by(2).map(|idx| u8::from_str_radix(&hex[idx..idx + 2], 16).unwrap()).collect();
+
+        let res = match bytes[..] {
+            [version, ..] if version != Self::VERSION => Err(invalid(&format!("unknown version {}", version))),
+            [_, threshold, index, _, ..] if threshold >= 2 && index != 0 => {
+                Ok(Share { threshold, index, data: bytes[3..].to_vec() })
+            },
+            _ => Err(invalid("too short or a zero index")),
+        };
+
+        wipe(&mut bytes);
+        res
+    }
+}
+
+impl std::fmt::Debug for Share {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Share").field("threshold", &self.threshold).field("index", &self.index).finish()
+    }
+}
+
+impl Drop for Share {
+    fn drop(&mut self) {
+        wipe(&mut self.data);
+    }
+}
+
+// multiplication in GF(256) modulo x^8 + x^4 + x^3 + x + 1, without branches on the operands
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+
+    for _ in 0..8 {
+        product ^= a & 0u8.wrapping_sub(b & 1);
+        a = (a << 1) ^ (0x1b & 0u8.wrapping_sub(a >> 7));
+        b >>= 1;
+    }
+
+    product
+}
+
+// a^254 is the inverse of a non-zero a
+fn gf_inv(a: u8) -> u8 {
+    let mut result = 1;
+    let mut base = a;
+    let mut exp = 254u8;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = gf_mul(result, base);
+        }
+
+        base = gf_mul(base, base);
+        exp >>= 1;
+    }
+
+    result
+}
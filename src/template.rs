@@ -0,0 +1,301 @@
+use crate::{max_length_probability, ClassSet, PassGenError, TokenSource};
+use rand::Rng;
+use std::fmt;
+
+const DIGITS: CharPool = CharPool("0123456789");
+const LOWER: CharPool = CharPool("abcdefghijklmnopqrstuvwxyz");
+const UPPER: CharPool = CharPool("ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+const SYMBOLS: CharPool = CharPool("!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~");
+const PRINTABLE: CharPool = CharPool(concat!(
+    "0123456789",
+    "abcdefghijklmnopqrstuvwxyz",
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+    "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~",
+));
+
+/* -------------------- *
+ *     SLOT CLASSES     *
+ * -------------------- */
+/// Where a template position draws from.
+#[derive(Debug)]
+pub(crate) enum SlotPool {
+    /// The word pool passed to the generator, a preset or token file.
+    Words,
+    /// One character out of these.
+    Chars(&'static CharPool),
+}
+
+/// A character that stands for a random position in a [`Template`].
+#[derive(Debug)]
+pub(crate) struct SlotClass {
+    pub symbol: char,
+    pub name: &'static str,
+    pub pool: SlotPool,
+    pub capitalize: bool,
+}
+
+/// Every template class, new ones only need a row here.
+pub(crate) const SLOT_CLASSES: &[SlotClass] = &[
+    SlotClass { symbol: 'W', name: "capitalized words", pool: SlotPool::Words, capitalize: true },
+    SlotClass { symbol: 'w', name: "words", pool: SlotPool::Words, capitalize: false },
+    SlotClass { symbol: 'd', name: "digits", pool: SlotPool::Chars(&DIGITS), capitalize: false },
+    SlotClass { symbol: 's', name: "symbols", pool: SlotPool::Chars(&SYMBOLS), capitalize: false },
+    SlotClass { symbol: 'a', name: "lowercase letters", pool: SlotPool::Chars(&LOWER), capitalize: false },
+    SlotClass { symbol: 'A', name: "uppercase letters", pool: SlotPool::Chars(&UPPER), capitalize: false },
+    SlotClass { symbol: 'x', name: "ascii characters", pool: SlotPool::Chars(&PRINTABLE), capitalize: false },
+];
+
+/// A string of ascii characters used as a pool of one character tokens.
+#[derive(Debug)]
+pub(crate) struct CharPool(pub &'static str);
+
+impl TokenSource for CharPool {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get(&self, idx: usize) -> &str {
+        &self.0[idx..idx + 1]
+    }
+}
+
+
+/* -------------------- *
+ *       TEMPLATE       *
+ * -------------------- */
+#[derive(Debug)]
+enum Slot {
+    Literal(char),
+    Class(&'static SlotClass),
+}
+
+/// A password layout, one position per character.
+///
+/// Class characters are drawn at random, everything else is copied, `\` makes the next
+/// character literal. Unknown letters are rejected so typos are caught.
+///
+/// ```
+/// use pass_gen::{preset, Generator, Template};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let template = Template::parse("Wwdd-ss").unwrap();
+/// let words = preset("word").unwrap().token_data;
+/// let mut gen = Generator::new(&words, 1, "", StdRng::seed_from_u64(1)).template(&template);
+///
+/// let password = gen.generate();
+/// let (head, tail) = password.split_at(password.len() - 3);
+///
+/// assert!(head.chars().next().unwrap().is_uppercase());
+/// assert!(head[head.len() - 2..].chars().all(|c| c.is_ascii_digit()));
+/// assert!(tail.starts_with('-') && tail[1..].chars().all(|c| c.is_ascii_punctuation()));
+///
+/// assert!(Template::parse("wwq").unwrap_err().to_string().contains("column 3"));
+/// ```
+#[derive(Debug)]
+pub struct Template {
+    source: String,
+    slots: Vec<Slot>,
+}
+
+impl Template {
+    pub fn parse(template: &str) -> Result<Template, PassGenError> {
+        let invalid = |column: usize, msg: &str| {
+            PassGenError::Usage(format!("invalid template {:?} at column {}: {}", template, column, msg))
+        };
+
+        let mut slots = Vec::new();
+        let mut chars = template.chars().enumerate();
+
+        while let Some((idx, c)) = chars.next() {
+            let slot = match c {
+                '\\' => match chars.next() {
+                    Some((_, escaped)) => Slot::Literal(escaped),
+                    None => return Err(invalid(idx + 1, "nothing left to escape")),
+                },
+                _ => match SLOT_CLASSES.iter().find(|class| class.symbol == c) {
+                    Some(class) => Slot::Class(class),
+                    None if c.is_ascii_alphabetic() => {
+                        let msg = format!("unknown class {:?}, escape a literal letter with \\", c);
+                        return Err(invalid(idx + 1, &msg));
+                    },
+                    None => Slot::Literal(c),
+                },
+            };
+
+            slots.push(slot);
+        }
+
+        if slots.is_empty() {
+            return Err(PassGenError::Usage(String::from("template is empty")));
+        }
+
+        Ok(Template { source: template.to_string(), slots })
+    }
+
+    /// Appends one password to `password`, drawing words from `words`.
+    pub fn push_password<R: Rng>(&self, words: &dyn TokenSource, rng: &mut R, password: &mut String) {
+        for slot in &self.slots {
+            let class = match slot {
+                Slot::Literal(c) => {
+                    password.push(*c);
+                    continue;
+                },
+                Slot::Class(class) => class,
+            };
+
+            let pool = pool(class, words);
+            let token = pool.get(rng.gen_range(pool.range()));
+
+            if class.capitalize {
+                password.extend(capitalize(token));
+            } else {
+                password.push_str(token);
+            }
+        }
+    }
+
+    /// Entropy in bits summed per class, one entry per slot class.
+    pub fn components(&self, words: &dyn TokenSource) -> Vec<(&'static str, f64)> {
+        SLOT_CLASSES
+            .iter()
+            .filter_map(|class| {
+                let is_class = |slot: &&Slot| matches!(slot, Slot::Class(c) if c.symbol == class.symbol);
+                let count = self.slots.iter().filter(is_class).count();
+
+                (count > 0).then(|| (class.name, pool(class, words).entropy_bits_per_token() * count as f64))
+            })
+            .collect()
+    }
+
+    pub fn bits(&self, words: &dyn TokenSource) -> f64 {
+        self.components(words).iter().map(|(_, bits)| bits).sum()
+    }
+
+    /// Minimum, mean and maximum length in characters.
+    pub fn length_stats(&self, words: &dyn TokenSource) -> (usize, f64, usize) {
+        let (min, avg, max) = words.length_stats();
+        let (fixed, count) = self.shape();
+
+        (fixed + min * count, fixed as f64 + avg * count as f64, fixed + max * count)
+    }
+
+    /// Upper bound in bytes, capitalizing can grow a character to a few bytes.
+    pub fn max_len(&self, words: &dyn TokenSource) -> usize {
+        self.slots
+            .iter()
+            .map(|slot| match slot {
+                Slot::Literal(c) => c.len_utf8(),
+                Slot::Class(class) => pool(class, words).max_token_len() + if class.capitalize { 4 } else { 0 },
+            })
+            .sum()
+    }
+
+    /// Probability that a password contains every class in `required`.
+    pub fn require_probability(&self, required: ClassSet, words: &dyn TokenSource) -> f64 {
+        // the classes each position can produce, by how many of its tokens produce them
+        let positions: Vec<[usize; 16]> = self
+            .slots
+            .iter()
+            .map(|slot| {
+                let mut counts = [0; 16];
+
+                match slot {
+                    Slot::Literal(c) => counts[ClassSet::of(&c.to_string()).bits()] += 1,
+                    Slot::Class(class) => {
+                        let pool = pool(class, words);
+
+                        for token in pool.range().map(|idx| pool.get(idx)) {
+                            let classes = match class.capitalize {
+                                true => ClassSet::of(&capitalize(token).collect::<String>()),
+                                false => ClassSet::of(token),
+                            };
+
+                            counts[classes.bits()] += 1;
+                        }
+                    },
+                }
+
+                counts
+            })
+            .collect();
+
+        // inclusion-exclusion over the classes no position produces
+        let required = required.bits();
+        let mut probability = 0.0;
+        let mut subset = required;
+
+        loop {
+            let term: f64 = positions
+                .iter()
+                .map(|counts| {
+                    let total: usize = counts.iter().sum();
+                    let avoiding: usize = (0..16).filter(|set| set & subset == 0).map(|set| counts[set]).sum();
+
+                    avoiding as f64 / total.max(1) as f64
+                })
+                .product();
+
+            probability += if subset.count_ones().is_multiple_of(2) { term } else { -term };
+
+            if subset == 0 {
+                break;
+            }
+
+            subset = (subset - 1) & required;
+        }
+
+        probability.clamp(0.0, 1.0)
+    }
+
+    /// Probability that the first character of a password matches `pred`.
+    pub fn starts_with_probability(&self, words: &dyn TokenSource, pred: impl Fn(char) -> bool) -> f64 {
+        match self.slots.first() {
+            Some(Slot::Literal(c)) => pred(*c) as u8 as f64,
+            Some(Slot::Class(class)) => {
+                let pool = pool(class, words);
+                let matching = pool.range().filter(|&idx| capitalize(pool.get(idx)).next().is_some_and(&pred)).count();
+
+                matching as f64 / pool.len().max(1) as f64
+            },
+            None => 0.0,
+        }
+    }
+
+    /// Probability that a password is at most `max_length` characters long.
+    pub fn max_length_probability(&self, words: &dyn TokenSource, max_length: usize) -> f64 {
+        let (fixed, count) = self.shape();
+
+        match max_length.checked_sub(fixed) {
+            Some(budget) => max_length_probability(words, count as u32, 0, budget),
+            None => 0.0,
+        }
+    }
+
+    // characters that are always one long, and the number of word positions
+    fn shape(&self) -> (usize, usize) {
+        let is_word = |slot: &&Slot| matches!(slot, Slot::Class(class) if matches!(class.pool, SlotPool::Words));
+        let count = self.slots.iter().filter(is_word).count();
+
+        (self.slots.len() - count, count)
+    }
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+fn pool<'t>(class: &SlotClass, words: &'t dyn TokenSource) -> &'t dyn TokenSource {
+    match class.pool {
+        SlotPool::Words => words,
+        SlotPool::Chars(chars) => chars,
+    }
+}
+
+fn capitalize(token: &str) -> impl Iterator<Item = char> + '_ {
+    let mut chars = token.chars();
+    let first = chars.next().into_iter().flat_map(char::to_uppercase);
+
+    first.chain(chars)
+}
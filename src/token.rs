@@ -0,0 +1,574 @@
+use crate::{data, grapheme_len, wipe_string, PassGenError};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    fs::File,
+    io::{self, BufRead, BufReader, ErrorKind},
+    ops::Range,
+    path::Path,
+};
+
+const MAX_TOKEN_LEN: usize = 1024;
+
+/* -------------------- *
+ *     TOKEN SOURCE     *
+ * -------------------- */
+/// Anything passwords can draw tokens from.
+///
+/// Sources are shared between generator threads, hence the `Send + Sync` bound.
+///
+/// ```
+/// use pass_gen::{Generator, TokenSource};
+///
+/// struct Digits;
+///
+/// impl TokenSource for Digits {
+///     fn len(&self) -> usize {
+///         10
+///     }
+///
+///     fn get(&self, idx: usize) -> &str {
+///         &"0123456789"[idx..idx + 1]
+///     }
+/// }
+///
+/// let pin = Generator::new(&Digits, 6, "", rand::thread_rng()).generate();
+///
+/// assert_eq!(pin.len(), 6);
+/// ```
+pub trait TokenSource: Send + Sync {
+    /// Number of tokens, indices passed to the other methods are below this.
+    fn len(&self) -> usize;
+
+    fn get(&self, idx: usize) -> &str;
+
+    /// Override for pools that are not drawn uniformly.
+    fn entropy_bits_per_token(&self) -> f64 {
+        (self.len() as f64).log2()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn range(&self) -> Range<usize> {
+        0..self.len()
+    }
+
+    /// Length in bytes of the longest token.
+    fn max_token_len(&self) -> usize {
+        self.range().map(|idx| self.get(idx).len()).max().unwrap_or(0)
+    }
+
+    /// Minimum, mean and maximum token length in characters.
+    fn length_stats(&self) -> (usize, f64, usize) {
+        let mut min = usize::MAX;
+        let mut max = 0;
+        let mut sum = 0;
+
+        for idx in self.range() {
+            let len = grapheme_len(self.get(idx));
+
+            min = min.min(len);
+            max = max.max(len);
+            sum += len;
+        }
+
+        (min.min(max), sum as f64 / self.len().max(1) as f64, max)
+    }
+}
+
+impl fmt::Debug for dyn TokenSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TokenSource").field("len", &self.len()).finish()
+    }
+}
+
+
+/* -------------------- *
+ *      TOKEN DATA      *
+ * -------------------- */
+/// What happens to tokens with whitespace inside, like `new york`, when a file is loaded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Whitespace {
+    /// Keep them as they are.
+    Keep,
+    /// Leave them out of the pool.
+    #[default]
+    Skip,
+    /// Make every part a token of its own.
+    Split,
+    /// Join the parts with a string, `newyork` with an empty one.
+    Join(String),
+}
+
+impl Whitespace {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Whitespace::Keep => "keep",
+            Whitespace::Skip => "skip",
+            Whitespace::Split => "split",
+            Whitespace::Join(_) => "join",
+        }
+    }
+
+    /// The policy called `name`, parts are joined without anything in between.
+    pub fn parse(name: &str) -> Option<Whitespace> {
+        match name {
+            "keep" => Some(Whitespace::Keep),
+            "skip" => Some(Whitespace::Skip),
+            "split" => Some(Whitespace::Split),
+            "join" => Some(Whitespace::Join(String::new())),
+            _ => None,
+        }
+    }
+}
+
+/// A pool of tokens that passwords are assembled from.
+#[derive(Debug)]
+pub enum TokenData {
+    StaticPacked {
+        text: &'static str,
+        offsets: &'static [u32],
+    },
+    Owned {
+        text: String,
+        spans: Vec<Range<u32>>,
+    },
+}
+
+impl TokenSource for TokenData {
+    fn get(&self, idx: usize) -> &str {
+        match self {
+            TokenData::StaticPacked { text, offsets } => {
+                &text[offsets[idx] as usize..offsets[idx + 1] as usize]
+            },
+            TokenData::Owned { text, spans } => {
+                &text[spans[idx].start as usize..spans[idx].end as usize]
+            },
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            TokenData::StaticPacked { offsets, .. } => offsets.len() - 1,
+            TokenData::Owned { spans, .. } => spans.len(),
+        }
+    }
+}
+
+// custom token files can be private, don't leave them behind in memory
+impl Drop for TokenData {
+    fn drop(&mut self) {
+        if let TokenData::Owned { text, .. } = self {
+            wipe_string(text);
+        }
+    }
+}
+
+impl TokenData {
+    /// Removes repeated tokens, returning how many were removed.
+    pub fn dedup(&mut self) -> usize {
+        match self {
+            TokenData::StaticPacked { .. } => 0,
+            TokenData::Owned { text, spans } => {
+                let mut seen = HashSet::new();
+                let before = spans.len();
+
+                spans.retain(|s| seen.insert(&text[s.start as usize..s.end as usize]));
+
+                before - spans.len()
+            },
+        }
+    }
+
+    /// Keeps the tokens of a pool of single characters that are not in `excluded`,
+    /// returning them with the number removed. Word pools are rejected.
+    ///
+    /// ```
+    /// use pass_gen::{preset, Generator, TokenData};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let excluded = "\"'\\`";
+    /// let (tokens, removed) = TokenData::without_chars(&preset("ascii").unwrap().token_data, excluded).unwrap();
+    /// let mut gen = Generator::new(&tokens, 16, "", StdRng::seed_from_u64(1));
+    ///
+    /// assert_eq!(removed, 4);
+    /// assert!((0..10_000).map(|_| gen.generate()).all(|p| !p.contains(|c| excluded.contains(c))));
+    ///
+    /// assert!(TokenData::without_chars(&preset("word").unwrap().token_data, "a").is_err());
+    /// ```
+    pub fn without_chars(source: &dyn TokenSource, excluded: &str) -> Result<(TokenData, usize), PassGenError> {
+        let (_, _, longest) = source.length_stats();
+
+        if longest > 1 {
+            return Err(PassGenError::Usage(String::from("only pools of single characters can exclude characters")));
+        }
+
+        let kept: Vec<String> = source
+            .range()
+            .map(|idx| source.get(idx))
+            .filter(|token| !token.chars().any(|c| excluded.contains(c)))
+            .map(str::to_string)
+            .collect();
+
+        let removed = source.len() - kept.len();
+
+        Ok((TokenData::from_strings(kept)?, removed))
+    }
+
+    /// Keeps the tokens of `source` that are `min` to `max` characters long, returning them
+    /// with the number removed.
+    ///
+    /// ```
+    /// use pass_gen::{preset, TokenData, TokenSource};
+    ///
+    /// let words = preset("word").unwrap().token_data;
+    /// let (kept, removed) = TokenData::with_lengths(&words, 5, Some(9)).unwrap();
+    ///
+    /// assert_eq!(kept.len() + removed, words.len());
+    /// assert!(kept.range().all(|idx| (5..=9).contains(&kept.get(idx).chars().count())));
+    ///
+    /// assert!(TokenData::with_lengths(&words, 9, Some(5)).is_err());
+    /// ```
+    pub fn with_lengths(
+        source: &dyn TokenSource,
+        min: usize,
+        max: Option<usize>,
+    ) -> Result<(TokenData, usize), PassGenError> {
+        if max.is_some_and(|max| max < min) {
+            let msg = format!("the minimum token length {} is above the maximum {}", min, max.unwrap_or(0));
+            return Err(PassGenError::Usage(msg));
+        }
+
+        let kept: Vec<String> = source
+            .range()
+            .map(|idx| source.get(idx))
+            .filter(|token| {
+                let len = grapheme_len(token);
+                len >= min && max.is_none_or(|max| len <= max)
+            })
+            .map(str::to_string)
+            .collect();
+
+        let removed = source.len() - kept.len();
+
+        Ok((TokenData::from_strings(kept)?, removed))
+    }
+
+    /// Applies a [`Whitespace`] policy to the tokens with whitespace inside, returning the pool
+    /// with the number of such tokens. Tokens are already trimmed when loaded, so only whitespace
+    /// between other characters counts.
+    ///
+    /// ```
+    /// use pass_gen::{TokenData, TokenSource, Whitespace};
+    ///
+    /// let text = "new york\nice\tcream\n  leading\ntwo   spaces\nplain\n";
+    /// let words = TokenData::from_reader(text.as_bytes()).unwrap();
+    /// let pool = |policy| {
+    ///     let (kept, found) = TokenData::with_whitespace(&words, &policy).unwrap();
+    ///     (kept.range().map(|idx| kept.get(idx).to_string()).collect::<Vec<_>>(), found)
+    /// };
+    ///
+    /// assert_eq!(pool(Whitespace::Keep).0.len(), 5);
+    /// assert_eq!(pool(Whitespace::Skip), (vec!["leading".to_string(), "plain".to_string()], 3));
+    /// assert_eq!(pool(Whitespace::Split).0, ["new", "york", "ice", "cream", "leading", "two", "spaces", "plain"]);
+    /// assert_eq!(pool(Whitespace::Join(String::new())).0, ["newyork", "icecream", "leading", "twospaces", "plain"]);
+    /// assert_eq!(pool(Whitespace::Join("_".to_string())).0[1], "ice_cream");
+    /// ```
+    pub fn with_whitespace(source: &dyn TokenSource, policy: &Whitespace) -> Result<(TokenData, usize), PassGenError> {
+        let spaced = |token: &str| token.contains(char::is_whitespace);
+        let found = source.range().filter(|&idx| spaced(source.get(idx))).count();
+
+        let kept: Vec<String> = source
+            .range()
+            .map(|idx| source.get(idx))
+            .flat_map(|token| match policy {
+                _ if !spaced(token) => vec![token.to_string()],
+                Whitespace::Keep => vec![token.to_string()],
+                Whitespace::Skip => Vec::new(),
+                Whitespace::Split => token.split_whitespace().map(str::to_string).collect(),
+                Whitespace::Join(join) => vec![token.split_whitespace().collect::<Vec<_>>().join(join)],
+            })
+            .collect();
+
+        Ok((TokenData::from_strings(kept)?, found))
+    }
+
+    /// Keeps at most one token of every set of [`confusable_sets`], returning the tokens with
+    /// the number removed. Case is ignored and the first word of a set that is in the pool stays.
+    ///
+    /// ```
+    /// use pass_gen::{confusable_sets, preset, TokenData, TokenSource};
+    ///
+    /// let text = "two\ntoo\nto\ntoast\nwhere\nwear\nThere\nquite\nquiet\n";
+    /// let (kept, removed) = TokenData::without_confusable(&TokenData::from_reader(text.as_bytes()).unwrap()).unwrap();
+    ///
+    /// let pool: Vec<&str> = kept.range().map(|idx| kept.get(idx)).collect();
+    ///
+    /// assert_eq!(removed, 4);
+    /// assert_eq!(pool, ["to", "toast", "wear", "There", "quiet"]);
+    ///
+    /// // no two words of the builtin list share a set afterwards
+    /// let (kept, removed) = TokenData::without_confusable(&preset("word").unwrap().token_data).unwrap();
+    /// let pool: Vec<&str> = kept.range().map(|idx| kept.get(idx)).collect();
+    ///
+    /// assert!(removed > 100);
+    ///
+    /// for set in confusable_sets() {
+    ///     assert!(set.len() >= 2);
+    ///     assert!(set.iter().filter(|word| pool.contains(word)).count() <= 1, "{:?}", set);
+    /// }
+    /// ```
+    pub fn without_confusable(source: &dyn TokenSource) -> Result<(TokenData, usize), PassGenError> {
+        if confusable_sets().is_empty() {
+            let msg = "the confusable word table is missing, pass-gen was built without the \"confusable\" feature";
+            return Err(PassGenError::Usage(msg.to_string()));
+        }
+
+        let mut positions: HashMap<String, Vec<usize>> = HashMap::new();
+
+        for idx in source.range() {
+            positions.entry(source.get(idx).to_lowercase()).or_default().push(idx);
+        }
+
+        let mut removed = vec![false; source.len()];
+        let mut kept = HashSet::new();
+
+        // a word can be in several sets, one kept in an earlier set is kept in later ones too
+        for set in confusable_sets() {
+            let members: Vec<usize> = set
+                .iter()
+                .flat_map(|word| positions.get(*word).into_iter().flatten().copied())
+                .filter(|&idx| !removed[idx])
+                .collect();
+
+            let keep = members.iter().copied().find(|idx| kept.contains(idx)).or(members.first().copied());
+
+            for idx in members {
+                match Some(idx) == keep {
+                    true => kept.insert(idx),
+                    false => kept.remove(&idx),
+                };
+
+                removed[idx] = Some(idx) != keep;
+            }
+        }
+
+        let pool: Vec<String> =
+            source.range().filter(|&idx| !removed[idx]).map(|idx| source.get(idx).to_string()).collect();
+        let removed = source.len() - pool.len();
+
+        Ok((TokenData::from_strings(pool)?, removed))
+    }
+
+    /// Reads one token per line, see [`TokenData::from_strings`] for how lines are handled.
+    ///
+    /// ```
+    /// use pass_gen::{TokenData, TokenSource};
+    ///
+    /// let tokens = TokenData::from_reader("# colors\n red \n\ngreen\n#\n".as_bytes()).unwrap();
+    ///
+    /// assert_eq!(tokens.len(), 3);
+    /// assert_eq!(tokens.get(0), "red");
+    /// assert_eq!(tokens.get(2), "#");
+    /// ```
+    pub fn from_reader(reader: impl BufRead) -> Result<TokenData, PassGenError> {
+        let mut reader = reader;
+        let mut pool = PoolBuilder::default();
+        let mut line = Vec::new();
+
+        loop {
+            line.clear();
+
+            if reader.read_until(b'\n', &mut line).map_err(read_error)? == 0 {
+                break;
+            }
+
+            match std::str::from_utf8(&line) {
+                Ok(str) => pool.push_line(str)?,
+                Err(_) => {
+                    let msg = format!("invalid utf-8 on line {}", pool.line_nr + 1);
+                    return Err(read_error(io::Error::new(ErrorKind::InvalidData, msg)));
+                },
+            }
+        }
+
+        Ok(pool.finish())
+    }
+
+    /// Reads one token per line from a file.
+    pub fn from_path(path: &Path) -> Result<TokenData, PassGenError> {
+        let loaded = File::open(path)
+            .map_err(read_error)
+            .and_then(|file| Self::from_reader(BufReader::new(file)));
+
+        match loaded {
+            Err(PassGenError::Io { action, source, .. }) => {
+                Err(PassGenError::Io { action, path: Some(path.display().to_string()), source })
+            },
+            loaded => loaded,
+        }
+    }
+
+    /// Builds a pool from lines of text.
+    ///
+    /// Lines are trimmed, blank lines and comments (`#` followed by more text)
+    /// are skipped and lines longer than 1024 bytes are rejected.
+    pub fn from_strings(lines: Vec<String>) -> Result<TokenData, PassGenError> {
+        let mut pool = PoolBuilder::default();
+
+        for line in &lines {
+            pool.push_line(line)?;
+        }
+
+        Ok(pool.finish())
+    }
+}
+
+// the line policy shared by every way of loading tokens
+#[derive(Default)]
+struct PoolBuilder {
+    text: String,
+    spans: Vec<Range<u32>>,
+    line_nr: usize,
+}
+
+impl PoolBuilder {
+    fn push_line(&mut self, line: &str) -> Result<(), PassGenError> {
+        self.line_nr += 1;
+
+        let token = line.trim();
+
+        // a lone "#" is still a valid token for symbol pools
+        if token.is_empty() || (token.starts_with('#') && token.len() > 1) {
+            return Ok(());
+        }
+
+        if token.len() > MAX_TOKEN_LEN {
+            let msg = format!("line {} is longer than {} bytes", self.line_nr, MAX_TOKEN_LEN);
+            return Err(read_error(io::Error::new(ErrorKind::InvalidData, msg)));
+        }
+
+        let start = self.text.len();
+        self.text.push_str(token);
+
+        match (u32::try_from(start), u32::try_from(self.text.len())) {
+            (Ok(start), Ok(end)) => self.spans.push(start..end),
+            _ => {
+                let err = io::Error::new(ErrorKind::InvalidData, "token file is too large");
+                return Err(read_error(err));
+            },
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> TokenData {
+        TokenData::Owned { text: self.text, spans: self.spans }
+    }
+}
+
+
+/* -------------------- *
+ *       PRESETS        *
+ * -------------------- */
+/// Names of the builtin presets.
+pub const PRESETS: &[&str] = &["word", "ascii", "number", "base58", "zbase32"];
+
+/// A builtin token pool together with its default count and separator.
+pub struct Preset {
+    pub token_count: u32,
+    pub token_sep: &'static str,
+    pub token_data: TokenData,
+}
+
+/// Sets of homophones and easily confused words, empty when built without the `confusable` feature.
+pub fn confusable_sets() -> &'static [&'static [&'static str]] {
+    #[cfg(feature = "confusable")]
+    let sets = data::confusable::CONFUSABLE_SETS;
+
+    #[cfg(not(feature = "confusable"))]
+    let sets = &[];
+
+    sets
+}
+
+/// Looks up a builtin preset by name.
+pub fn preset(name: &str) -> Option<Preset> {
+    macro_rules! preset {
+        ($name:ident) => {
+            Some(Preset {
+                token_count: data::$name::TOKEN_COUNT,
+                token_sep: data::$name::TOKEN_SEP,
+                token_data: TokenData::StaticPacked {
+                    text: data::$name::TOKEN_TEXT,
+                    offsets: &data::$name::TOKEN_OFFSETS,
+                },
+            })
+        };
+    }
+
+    match name {
+        "ascii" => preset!(ascii),
+        "number" => preset!(number),
+        "base58" => preset!(base58),
+        "zbase32" => preset!(zbase32),
+        "word" => preset!(word),
+        _ => None,
+    }
+}
+
+fn read_error(source: io::Error) -> PassGenError {
+    PassGenError::Io { action: "reading token file", path: None, source }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::{size_of, size_of_val};
+
+    // bytes of the tokens and their index, on the heap for owned pools and in the binary for packed ones
+    fn footprint(data: &TokenData) -> usize {
+        match data {
+            TokenData::StaticPacked { text, offsets } => text.len() + size_of_val(*offsets),
+            TokenData::Owned { text, spans } => text.capacity() + spans.capacity() * size_of::<Range<u32>>(),
+        }
+    }
+
+    // the same tokens loaded the way a token file is
+    fn owned(data: &TokenData) -> TokenData {
+        TokenData::from_strings(data.range().map(|idx| data.get(idx).to_string()).collect()).unwrap()
+    }
+
+    #[test]
+    fn packed_presets_are_smaller_than_owned() {
+        // the first, middle and last token of each source list
+        let lists = [
+            ("word", 26578, ["aback", "lander", "zucchini"]),
+            ("ascii", 93, ["0", "K", "~"]),
+            ("number", 10, ["0", "5", "9"]),
+            ("base58", 58, ["1", "W", "z"]),
+            ("zbase32", 32, ["y", "o", "9"]),
+        ];
+
+        for (name, len, [first, middle, last]) in lists {
+            let packed = preset(name).unwrap().token_data;
+            let owned = owned(&packed);
+
+            assert_eq!((packed.len(), owned.len()), (len, len), "{}", name);
+            assert_eq!([packed.get(0), packed.get(len / 2), packed.get(len - 1)], [first, middle, last], "{}", name);
+            assert_eq!([owned.get(0), owned.get(len / 2), owned.get(len - 1)], [first, middle, last], "{}", name);
+
+            // an offset of 4 bytes per token against a span of 8 and whatever capacity is spare
+            assert!(footprint(&packed) < footprint(&owned), "{}", name);
+        }
+
+        // the word list saves at least 4 bytes per word, over 100 kB
+        let packed = preset("word").unwrap().token_data;
+        let saved = footprint(&owned(&packed)) - footprint(&packed);
+
+        assert!(saved >= 4 * packed.len(), "{} bytes saved for {} words", saved, packed.len());
+    }
+}
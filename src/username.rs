@@ -0,0 +1,160 @@
+use crate::{preset, PassGenError, TokenSource};
+use rand::Rng;
+
+const CONSONANTS: &[u8] = b"bcdfghjklmnprstvz";
+const VOWELS: &[u8] = b"aeiou";
+
+/* -------------------- *
+ *      USERNAMES       *
+ * -------------------- */
+/// How usernames are put together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsernameStyle {
+    /// Two words of the builtin word list joined by a dot.
+    Words,
+    /// Consonants and vowels taking turns, [`Username::PRONOUNCEABLE_LEN`] letters.
+    Pronounceable,
+}
+
+impl UsernameStyle {
+    pub const ALL: [UsernameStyle; 2] = [UsernameStyle::Words, UsernameStyle::Pronounceable];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            UsernameStyle::Words => "words",
+            UsernameStyle::Pronounceable => "pronounceable",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<UsernameStyle> {
+        Self::ALL.into_iter().find(|style| style.name() == name)
+    }
+}
+
+/// Generates lowercase usernames that are safe as a DNS label, only `a-z`, `0-9`, `.`, `_`
+/// and `-` ever appear.
+///
+/// ```
+/// use pass_gen::{Username, UsernameStyle};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let words = Username::new(UsernameStyle::Words, 12).unwrap();
+/// let mut rng = StdRng::seed_from_u64(1);
+///
+/// for _ in 0..100 {
+///     let username = words.generate(&mut rng);
+///     let (first, second) = username.split_once('.').unwrap();
+///
+///     assert!(username.len() <= 12 && !first.is_empty() && !second.is_empty());
+///     assert!(username.bytes().all(|b| b.is_ascii_lowercase() || b == b'.'));
+/// }
+///
+/// // the same seed gives the same usernames
+/// let pronounceable = Username::new(UsernameStyle::Pronounceable, 32).unwrap();
+/// let draw = |seed| pronounceable.generate(&mut StdRng::seed_from_u64(seed));
+///
+/// assert_eq!(draw(7), draw(7));
+/// assert_eq!(draw(7).len(), Username::PRONOUNCEABLE_LEN);
+/// assert!((pronounceable.bits() - 4.0 * (17f64.log2() + 5f64.log2())).abs() < 1e-9);
+///
+/// // a shorter limit leaves fewer pairs of words
+/// assert!(words.bits() < Username::new(UsernameStyle::Words, 32).unwrap().bits());
+/// assert!(Username::new(UsernameStyle::Pronounceable, 7).is_err());
+/// assert!(Username::new(UsernameStyle::Words, 4).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Username {
+    style: UsernameStyle,
+    max_len: usize,
+    // words of only lowercase ascii letters
+    words: Vec<String>,
+}
+
+impl Username {
+    pub const DEFAULT_MAX_LEN: usize = 32;
+    pub const PRONOUNCEABLE_LEN: usize = 8;
+
+    pub fn new(style: UsernameStyle, max_len: usize) -> Result<Username, PassGenError> {
+        let words: Vec<String> = match style {
+            UsernameStyle::Words => {
+                let list = preset("word").expect("the word preset is builtin").token_data;
+
+                list.range()
+                    .map(|idx| list.get(idx))
+                    .filter(|word| !word.is_empty() && word.bytes().all(|b| b.is_ascii_lowercase()))
+                    .map(String::from)
+                    .collect()
+            },
+            UsernameStyle::Pronounceable => Vec::new(),
+        };
+
+        let shortest = match style {
+            UsernameStyle::Words => 2 * words.iter().map(String::len).min().unwrap_or(usize::MAX / 4) + 1,
+            UsernameStyle::Pronounceable => Self::PRONOUNCEABLE_LEN,
+        };
+
+        if max_len < shortest {
+            return Err(PassGenError::Unsatisfiable(format!(
+                "a username of at most {} characters is too short for the {} style, which needs {}",
+                max_len,
+                style.name(),
+                shortest
+            )));
+        }
+
+        Ok(Username { style, max_len, words })
+    }
+
+    pub fn style(&self) -> UsernameStyle {
+        self.style
+    }
+
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        match self.style {
+            UsernameStyle::Words => loop {
+                // pairs that are too long are drawn again, so every pair that fits is equally likely
+                let first = &self.words[rng.gen_range(0..self.words.len())];
+                let second = &self.words[rng.gen_range(0..self.words.len())];
+
+                if first.len() + 1 + second.len() <= self.max_len {
+                    return format!("{}.{}", first, second);
+                }
+            },
+            UsernameStyle::Pronounceable => (0..Self::PRONOUNCEABLE_LEN)
+                .map(|pos| {
+                    let letters = if pos % 2 == 0 { CONSONANTS } else { VOWELS };
+                    letters[rng.gen_range(0..letters.len())] as char
+                })
+                .collect(),
+        }
+    }
+
+    /// Entropy in bits of a generated username.
+    pub fn bits(&self) -> f64 {
+        match self.style {
+            UsernameStyle::Words => {
+                let mut by_length = vec![0f64; self.max_len + 1];
+
+                for word in self.words.iter().filter(|word| word.len() < self.max_len) {
+                    by_length[word.len()] += 1.0;
+                }
+
+                let pairs: f64 = (1..by_length.len())
+                    .flat_map(|first| (1..by_length.len()).map(move |second| (first, second)))
+                    .filter(|(first, second)| first + 1 + second <= self.max_len)
+                    .map(|(first, second)| by_length[first] * by_length[second])
+                    .sum();
+
+                pairs.log2()
+            },
+            UsernameStyle::Pronounceable => {
+                let half = (Self::PRONOUNCEABLE_LEN / 2) as f64;
+                half * ((CONSONANTS.len() as f64).log2() + (VOWELS.len() as f64).log2())
+            },
+        }
+    }
+}
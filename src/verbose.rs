@@ -0,0 +1,243 @@
+use crate::{
+    Config, CountSource, Format, Identifier, Layer, PartOfSpeech, PoolSource, Sentence, TokenSource,
+    API_TOKEN_CHECKSUM_LEN, LICENSE_KEY_ALPHABET, TTY_PATH,
+};
+use std::io::{self, Write};
+
+/* -------------------- *
+ *       VERBOSE        *
+ * -------------------- */
+/// Writes how `config` came about for `--verbose`: where the pool and its size, the token
+/// count and the separator come from, the rng and every constraint on the candidates.
+pub fn write_verbose(config: &Config, out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "source:       {}", config.source())?;
+
+    if let (Some(reason), None, None) = (&config.locale, &config.sentence, &config.identifier) {
+        writeln!(out, "locale:       {}", reason)?;
+    }
+
+    if let Some(path) = &config.config_file {
+        writeln!(out, "config file:  {}", path)?;
+    }
+
+    // an identifier has no pool, tokens or separator, only its random bits
+    if let Some(identifier) = &config.identifier {
+        match identifier {
+            Identifier::Uuid => writeln!(out, "identifier:   {} random bits, 6 fixed version bits", identifier.bits())?,
+            Identifier::Ulid => {
+                writeln!(out, "identifier:   {} random bits after a 48 bit millisecond timestamp", identifier.bits())?
+            },
+            Identifier::ApiToken(token) => writeln!(
+                out,
+                "identifier:   {}_, {} {} characters ({:.1} random bits) and a {} character crc32 checksum",
+                token.prefix(),
+                token.payload_len(),
+                token.alphabet().name(),
+                token.bits(),
+                API_TOKEN_CHECKSUM_LEN
+            )?,
+            Identifier::LicenseKey(key) => writeln!(
+                out,
+                "identifier:   {} groups of {} characters, {:.1} random bits and a luhn mod {} check character",
+                key.groups(),
+                key.group(),
+                key.bits(),
+                LICENSE_KEY_ALPHABET.len()
+            )?,
+        }
+
+        writeln!(out, "rng:          {}", verbose_rng(config))?;
+
+        return write_output(config, out);
+    }
+
+    if let Some(sentence) = &config.sentence {
+        for (idx, pos) in PartOfSpeech::ALL.into_iter().enumerate() {
+            let label = if idx == 0 { "pool size:" } else { "" };
+            writeln!(out, "{:<14}{} {}", label, sentence.words(pos).len(), pos.name())?;
+        }
+    } else if let PoolSource::From(spec) = &config.pool_source {
+        for (idx, (source, pool)) in spec.split(',').zip(&config.sources).enumerate() {
+            let label = if idx == 0 { "pool size:" } else { "" };
+            writeln!(out, "{:<14}{} from {}", label, pool.len(), source.trim())?;
+        }
+    } else if !matches!(config.pool_source, PoolSource::Size(_)) {
+        let mut size = config.token_data.len() + config.pool_filters.iter().map(|(_, n)| n).sum::<usize>();
+        writeln!(out, "pool size:    {} loaded", size)?;
+
+        for (name, removed) in &config.pool_filters {
+            size -= removed;
+            writeln!(out, "              {} after {} filter (-{})", size, name, removed)?;
+        }
+    }
+
+    let count_source = match config.count_source {
+        CountSource::Default => "default",
+        CountSource::Config => "config file",
+        CountSource::Flag => "--count",
+        CountSource::Bits => "--bits",
+        CountSource::CrackTime => "--crack-time",
+        CountSource::Length => "--length",
+        CountSource::Template => "--template",
+        CountSource::Acrostic => "--acrostic",
+        CountSource::Sentence => "--sentence",
+        CountSource::ExactBits if config.decode => "--decode-bits",
+        CountSource::ExactBits => "--exact-bits",
+        CountSource::Join => "--join",
+        CountSource::From => "--from",
+    };
+
+    match (&config.template, &config.length) {
+        _ if config.sentence.is_some() => {
+            let sentence = config.sentence.as_ref().map(Sentence::to_string).unwrap_or_default();
+            writeln!(out, "sentence:     {} (from {})", sentence, count_source)?
+        },
+        (Some(template), _) => writeln!(out, "template:     {:?}", template.to_string())?,
+        (None, Some((length, _))) => {
+            writeln!(out, "token count:  as many as fit in {} characters (from --length)", length)?
+        },
+        (None, None) => writeln!(out, "token count:  {} (from {})", config.token_count, count_source)?,
+    }
+
+    if let Some(join) = &config.join {
+        writeln!(out, "join:         {:?}", join.to_string())?;
+    }
+
+    // nothing is drawn from a hypothetical pool
+    if let PoolSource::Size(_) = config.pool_source {
+        return Ok(());
+    }
+
+    let sep_source = match config.sep_layer {
+        Layer::Flag => "--sep",
+        layer => layer.name(),
+    };
+
+    if config.template.is_some() {
+        // the template has its own literals
+    } else if config.vertical {
+        writeln!(out, "separator:    none, one token per line (from --vertical)")?;
+    } else if config.sep_set.is_empty() {
+        writeln!(out, "separator:    {:?} (from {})", config.token_sep, sep_source)?;
+    } else {
+        writeln!(out, "separator:    random from {:?}", config.sep_set.iter().collect::<String>())?;
+    }
+
+    if config.checksum {
+        writeln!(out, "checksum:     one more token derived from the others, it adds no entropy")?;
+    }
+
+    if config.pepper {
+        writeln!(out, "pepper:       a secret of yours after the separator, it adds no counted entropy")?;
+    }
+
+    if let Some(username) = &config.username {
+        let (style, max_len) = (username.style().name(), username.max_len());
+        writeln!(out, "username:     {} style, at most {} characters, drawn after each password", style, max_len)?;
+    }
+
+    let memorability = config.memorability();
+
+    writeln!(
+        out,
+        "memorability: {:.0}/100 ({}) over {} samples: {:.1} chars, {:.1} classes, {:.2} symbol clusters, \
+        {:.2} shift changes per char",
+        memorability.score(),
+        memorability.label(),
+        config.memorability_samples(),
+        memorability.length,
+        memorability.classes,
+        memorability.symbol_clusters,
+        memorability.shift_changes
+    )?;
+
+    if let Some(rank) = memorability.word_rank {
+        writeln!(out, "              {:.2} mean word frequency rank", rank)?;
+    }
+
+    let rng = verbose_rng(config);
+
+    writeln!(out, "rng:          {}", rng)?;
+    if let Some(max) = config.max_length {
+        writeln!(out, "max length:   {} ({:.1}% of candidates fit)", max, config.max_length_probability() * 100.0)?;
+    }
+
+    if let Some((path, policy)) = &config.policy {
+        writeln!(out, "policy:       {:?}", path)?;
+
+        for rule in policy.rules() {
+            writeln!(out, "              {}", rule)?;
+        }
+    }
+
+    if !config.require.is_empty() {
+        let probability = config.require_probability(config.require);
+        writeln!(out, "require:      {} ({:.1}% of candidates pass)", config.require, probability * 100.0)?;
+    }
+
+    if let Some(leet) = &config.leet {
+        writeln!(out, "leet:         p = {}", leet.probability())?;
+    }
+
+    if let Some(case) = &config.random_case {
+        let bits = case.bits(config.token_data.as_ref());
+        writeln!(out, "random case:  p = {}, {:.2} bits per token", case.probability(), bits)?;
+    }
+
+    if let Some((count, pool)) = &config.mutate {
+        writeln!(out, "mutations:    {} from {} characters", count, pool.len())?;
+    }
+
+    if config.start_with_letter {
+        writeln!(out, "first char:   letter ({:.1}% of tokens start with one)", config.letter_probability() * 100.0)?;
+    }
+
+    write_output(config, out)
+}
+
+fn verbose_rng(config: &Config) -> &'static str {
+    match (config.seed, config.number) {
+        _ if config.derive.is_some() => "ChaCha20 seeded from --derive (argon2id + hkdf-sha256)",
+        (Some(_), _) => "StdRng (ChaCha12) seeded from --seed",
+        (None, 1) => "thread_rng (ChaCha12, seeded by the os)",
+        (None, _) => "StdRng (ChaCha12) per chunk, seeded by the os",
+    }
+}
+
+// what is generated and where it goes
+fn write_output(config: &Config, out: &mut impl Write) -> io::Result<()> {
+    let unique = if config.unique { ", unique" } else { "" };
+    writeln!(out, "passwords:    {} ({} job(s){})", config.number, config.jobs, unique)?;
+
+    if let Some((path, filter)) = &config.breach_filter {
+        writeln!(
+            out,
+            "breach check: {:?} ({} hashes, {} false positive rate)",
+            path,
+            filter.items(),
+            filter.fp_rate()
+        )?;
+    }
+
+    if let Some(history) = &config.history {
+        writeln!(out, "history:      {:?} ({} issued)", history.path, history.history.len())?;
+    }
+
+    let format = match config.format {
+        Format::Plain => "plain",
+        Format::Csv => "csv",
+        Format::Json => "json",
+        Format::KeepassCsv => "keepass csv",
+    };
+
+    match &config.output {
+        Some(path) => writeln!(out, "output:       {} to file {:?}", format, path)?,
+        None => writeln!(out, "output:       {} to stdout", format)?,
+    }
+
+    match (config.tty_echo, &config.tty) {
+        (true, Some(_)) => writeln!(out, "tty echo:     a copy of every password to {}", TTY_PATH),
+        (true, None) => writeln!(out, "tty echo:     skipped, there is no controlling terminal"),
+        (false, _) => Ok(()),
+    }
+}
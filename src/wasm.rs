@@ -0,0 +1,18 @@
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+/* -------------------- *
+ *     WASM BINDGEN     *
+ * -------------------- */
+/// A password of `count` tokens from `preset` joined by `sep`, see [`crate::generate`].
+///
+/// An invalid preset or count throws an `Error` with the message of the usage error.
+#[wasm_bindgen]
+pub fn generate(preset: &str, count: u32, sep: &str) -> Result<String, JsError> {
+    crate::generate(preset, count, sep).map_err(|err| JsError::new(&err.to_string()))
+}
+
+/// Entropy in bits of `count` tokens from `preset`, see [`crate::entropy_bits`].
+#[wasm_bindgen]
+pub fn entropy_bits(preset: &str, count: u32) -> Result<f64, JsError> {
+    crate::entropy_bits(preset, count).map_err(|err| JsError::new(&err.to_string()))
+}
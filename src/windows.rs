@@ -0,0 +1,97 @@
+// console calls for windows, the counterparts of the termios and ioctl calls in main
+use std::{
+    ffi::c_void,
+    fs::OpenOptions,
+    os::windows::io::{AsRawHandle, RawHandle},
+};
+
+const STD_INPUT_HANDLE: u32 = -10i32 as u32;
+const STD_ERROR_HANDLE: u32 = -12i32 as u32;
+const ENABLE_ECHO_INPUT: u32 = 0x0004;
+
+#[repr(C)]
+#[derive(Default)]
+#[allow(dead_code)]
+struct Coord {
+    x: i16,
+    y: i16,
+}
+
+#[repr(C)]
+#[derive(Default)]
+#[allow(dead_code)]
+struct SmallRect {
+    left: i16,
+    top: i16,
+    right: i16,
+    bottom: i16,
+}
+
+#[repr(C)]
+#[derive(Default)]
+#[allow(dead_code)]
+struct ScreenBufferInfo {
+    size: Coord,
+    cursor_position: Coord,
+    attributes: u16,
+    window: SmallRect,
+    maximum_window_size: Coord,
+}
+
+#[link(name = "kernel32")]
+extern "system" {
+    fn GetStdHandle(std_handle: u32) -> *mut c_void;
+    fn GetConsoleScreenBufferInfo(console: *mut c_void, info: *mut ScreenBufferInfo) -> i32;
+    fn GetConsoleMode(console: *mut c_void, mode: *mut u32) -> i32;
+    fn SetConsoleMode(console: *mut c_void, mode: u32) -> i32;
+}
+
+
+/* -------------------- *
+ *        WIDTH         *
+ * -------------------- */
+fn window_width(console: RawHandle) -> Option<usize> {
+    let mut info = ScreenBufferInfo::default();
+
+    // SAFETY: only writes into the struct we pass, invalid handles make it fail
+    if unsafe { GetConsoleScreenBufferInfo(console, &mut info) } == 0 {
+        return None;
+    }
+
+    let width = info.window.right - info.window.left + 1;
+    (width > 0).then_some(width as usize)
+}
+
+/// Width of the console window, through CONOUT$ when stderr is redirected.
+pub fn term_width() -> Option<usize> {
+    // SAFETY: GetStdHandle has no preconditions
+    window_width(unsafe { GetStdHandle(STD_ERROR_HANDLE) }).or_else(|| {
+        let console = OpenOptions::new().read(true).write(true).open("CONOUT$").ok()?;
+        window_width(console.as_raw_handle())
+    })
+}
+
+
+/* -------------------- *
+ *         ECHO         *
+ * -------------------- */
+/// Turns off echo on the console input, returns the mode to restore.
+pub fn disable_echo() -> Option<u32> {
+    let mut mode = 0;
+
+    // SAFETY: the mode calls only read and set flags of our own console handle
+    unsafe {
+        let input = GetStdHandle(STD_INPUT_HANDLE);
+
+        if GetConsoleMode(input, &mut mode) == 0 || SetConsoleMode(input, mode & !ENABLE_ECHO_INPUT) == 0 {
+            return None;
+        }
+    }
+
+    Some(mode)
+}
+
+pub fn restore_echo(mode: u32) {
+    // SAFETY: restores the mode read in disable_echo
+    unsafe { SetConsoleMode(GetStdHandle(STD_INPUT_HANDLE), mode) };
+}
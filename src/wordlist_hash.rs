@@ -0,0 +1,89 @@
+use crate::{
+    history::{parse_hex, to_hex},
+    sha256, PassGenError,
+};
+use std::fmt;
+
+/// Digest algorithms a [`WordlistHash`] can be written in.
+pub const WORDLIST_HASHES: &[&str] = &["sha256"];
+
+/* -------------------- *
+ *    WORDLIST HASH     *
+ * -------------------- */
+/// The expected digest of the raw bytes of a word list, written `algo:hex`.
+///
+/// ```
+/// use pass_gen::WordlistHash;
+///
+/// let empty = "sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+/// let hash = WordlistHash::parse(empty).unwrap();
+///
+/// assert!(hash.check("empty.txt", b"").is_ok());
+/// assert_eq!(hash.to_string(), empty);
+///
+/// // upper case digits are read, lower case ones shown
+/// let upper = format!("SHA256:{}", &empty[7..].to_uppercase());
+/// assert_eq!(WordlistHash::parse(&upper).unwrap().to_string(), empty);
+///
+/// // a mismatch names both digests
+/// let error = hash.check("words.txt", b"tampered\n").unwrap_err().to_string();
+///
+/// assert!(error.contains("words.txt"));
+/// assert!(error.contains(&format!("expected {}", empty)));
+/// assert!(error.contains("got sha256:92e78d0b"));
+///
+/// let error = |spec| WordlistHash::parse(spec).unwrap_err().to_string();
+///
+/// assert!(error("md5:d41d8cd98f00b204e9800998ecf8427e").contains("expected one of sha256"));
+/// assert!(error("sha256:e3b0c442").contains("64 hex digits"));
+/// assert!(error("e3b0c442").contains("<algo>:<hex>"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordlistHash {
+    algo: &'static str,
+    digest: [u8; 32],
+}
+
+impl WordlistHash {
+    pub fn parse(spec: &str) -> Result<WordlistHash, PassGenError> {
+        let (algo, hex) = match spec.split_once(':') {
+            Some((algo, hex)) => (algo, hex.trim()),
+            None => {
+                let msg = format!("invalid word list hash {:?}, expected <algo>:<hex>", spec);
+                return Err(PassGenError::Usage(msg));
+            },
+        };
+
+        let algo = match WORDLIST_HASHES.iter().find(|name| name.eq_ignore_ascii_case(algo)) {
+            Some(algo) => *algo,
+            None => {
+                let msg = format!("unknown hash {:?}, expected one of {}", algo, WORDLIST_HASHES.join(", "));
+                return Err(PassGenError::Usage(msg));
+            },
+        };
+
+        match parse_hex(hex) {
+            Some(digest) => Ok(WordlistHash { algo, digest }),
+            None => Err(PassGenError::Usage(format!("invalid {} digest {:?}, expected 64 hex digits", algo, hex))),
+        }
+    }
+
+    /// Checks the raw bytes `data` of the word list `name` against the digest.
+    pub fn check(&self, name: &str, data: &[u8]) -> Result<(), PassGenError> {
+        let actual = WordlistHash { algo: self.algo, digest: sha256(data) };
+
+        match actual == *self {
+            true => Ok(()),
+            false => Err(PassGenError::InvalidData(format!(
+                "word list {} does not match its hash, expected {}, got {}",
+                name, self, actual
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for WordlistHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.algo, to_hex(&self.digest))
+    }
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "age")]
+
+mod common;
+
+use common::{stderr, stdout};
+use pass_gen::AgeIdentity;
+use rand::rngs::OsRng;
+use std::fs;
+
+fn decrypt(identity: &AgeIdentity, armor: &str) -> String {
+    String::from_utf8(identity.decrypt(armor).unwrap()).unwrap()
+}
+
+#[test]
+fn decrypts_to_the_password() {
+    let (alice, bob) = (AgeIdentity::generate(&mut OsRng), AgeIdentity::generate(&mut OsRng));
+    let (alice_key, bob_key) = (alice.to_recipient().to_string(), bob.to_recipient().to_string());
+
+    // exactly what would have been printed, without a trailing newline
+    let password = stdout(&["--seed", "1"]);
+    let armor = stdout(&["--seed", "1", "--age-recipient", &alice_key, "--age-recipient", &bob_key]);
+
+    assert!(armor.starts_with("-----BEGIN AGE ENCRYPTED FILE-----\n") && !armor.contains("washes"), "{}", armor);
+    assert!(armor.ends_with("-----END AGE ENCRYPTED FILE-----\n"), "{}", armor);
+    assert_eq!(decrypt(&alice, &armor), password);
+    assert_eq!(decrypt(&bob, &armor), password);
+    assert!(AgeIdentity::generate(&mut OsRng).decrypt(&armor).is_err());
+
+    // a recipients file, with batches encrypted as a whole
+    let path = std::env::temp_dir().join(format!("pass-gen-age-{}.txt", std::process::id()));
+    fs::write(&path, format!("# bob\n{}\n\n", bob_key)).unwrap();
+
+    let file = path.to_str().unwrap();
+    let batch = stdout(&["--seed", "2", "-n", "3"]);
+    assert_eq!(decrypt(&bob, &stdout(&["--seed", "2", "-n", "3", "--age-recipients-file", file])), batch);
+
+    // --also-plain prints the password above the ciphertext
+    let both = stdout(&["--seed", "1", "--age-recipient", &alice_key, "--also-plain"]);
+    let (plain, armor) = both.split_once("\n\n").unwrap();
+
+    assert_eq!(plain, password);
+    assert_eq!(decrypt(&alice, armor), password);
+
+    // the ciphertext goes to the output file as well
+    let out = format!("{}.age", file);
+    stdout(&["--seed", "1", "--age-recipients-file", file, "-o", &out]);
+    assert_eq!(decrypt(&bob, &fs::read_to_string(&out).unwrap()), password);
+
+    fs::remove_file(path).unwrap();
+    fs::remove_file(out).unwrap();
+}
+
+#[test]
+fn rejects() {
+    let key = "age1ql3z7hjy54pw3hyww5ayyfg7zqgvc7w3j2elw8zmrj2kg5sfn9aqmcac8p";
+
+    assert!(stderr(&["--age-recipient", &key.replace("ql3z", "ql3y")]).contains("invalid age recipient \"age1ql3y"));
+    assert!(stderr(&["--age-recipient", &key.replace("age1", "agf1")]).contains("expected a key starting with age1"));
+    assert!(stderr(&["--age-recipient", "age1"]).contains("invalid checksum"));
+    assert!(stderr(&["--age-recipients-file", "/nonexistent"]).contains("error while reading age recipients"));
+    assert!(stderr(&["--age-recipient", key, "--split", "2/3"]).contains("--age-recipient conflicts with --split"));
+    assert!(stderr(&["--age-recipient", key, "--report-only"]).contains("--report-only cannot be combined"));
+
+    let error = stderr(&["--age-recipient", key, "--encrypt-to", "bob"]);
+    assert!(error.contains("--age-recipient conflicts with --encrypt-to"), "{}", error);
+}
@@ -0,0 +1,113 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    os::unix::{fs::PermissionsExt, net::UnixStream},
+    path::{Path, PathBuf},
+    process::{Child, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+fn spawn_agent(name: &str, args: &[&str]) -> (Child, PathBuf) {
+    let path = std::env::temp_dir().join(format!("pass-gen-agent-{}-{}.sock", name, std::process::id()));
+    let child = common::command()
+        .args(["agent", "--socket", path.to_str().unwrap()])
+        .args(args)
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("pass-gen runs");
+
+    let start = Instant::now();
+
+    while !path.exists() {
+        assert!(start.elapsed() < Duration::from_secs(10), "the agent never bound its socket");
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    (child, path)
+}
+
+fn request(reader: &mut BufReader<UnixStream>, line: &str, lines: usize) -> Vec<String> {
+    reader.get_mut().write_all(line.as_bytes()).unwrap();
+
+    (0..lines)
+        .map(|_| {
+            let mut reply = String::new();
+            reader.read_line(&mut reply).unwrap();
+            reply.trim_end_matches('\n').to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn serves_requests() {
+    let (agent, path) = spawn_agent("serve", &["--max-requests", "6"]);
+
+    assert_eq!(path.metadata().unwrap().permissions().mode() & 0o777, 0o600);
+
+    // several requests on one connection, parsed like the command line
+    let mut client = BufReader::new(UnixStream::connect(&path).unwrap());
+
+    let ascii = request(&mut client, "-p ascii -c 24\n", 1);
+    assert_eq!(ascii[0].chars().count(), 24);
+
+    let numbers = request(&mut client, "-p number -c 2 -n 3 -s 'a b'\n", 3);
+    assert!(numbers.iter().all(|line| line.len() == 5 && &line[1..4] == "a b"), "{:?}", numbers);
+
+    let seeded = request(&mut client, "--seed 1\n", 1);
+    assert_eq!(seeded[0], "washes pseudonym hilly completely directed videotape");
+
+    // errors are replies, the agent keeps serving, clients are served one at a time
+    drop(client);
+    let mut client = BufReader::new(UnixStream::connect(&path).unwrap());
+
+    assert_eq!(request(&mut client, "--not-a-flag\n", 1), ["error: invalid option \"--not-a-flag\""]);
+    assert_eq!(request(&mut client, "-o out.txt\n", 1), ["error: --output is not available in agent requests"]);
+
+    // a separator that looks like a flag is still an argument
+    assert_eq!(request(&mut client, "-p number -c 2 -s --help\n", 1)[0].len(), 8);
+
+    // --max-requests ends the agent and removes its socket
+    let output = agent.wait_with_output().unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(!path.exists());
+}
+
+#[test]
+fn idle_timeout() {
+    let (mut agent, path) = spawn_agent("idle", &["--idle-timeout", "1"]);
+    let start = Instant::now();
+
+    // a client that never sends a whole request does not keep the agent alive
+    let mut client = UnixStream::connect(&path).unwrap();
+    client.write_all(b"-p asc").unwrap();
+
+    let mut rest = String::new();
+    client.read_to_string(&mut rest).unwrap();
+
+    assert!(agent.wait().unwrap().success());
+    assert!(start.elapsed() < Duration::from_secs(10));
+    assert!(rest.is_empty() && !Path::new(&path).exists());
+}
+
+#[test]
+fn rejects() {
+    let (agent, path) = spawn_agent("rejects", &["--max-requests", "1"]);
+
+    // a live agent keeps its socket
+    let output = common::command().args(["agent", "--socket", path.to_str().unwrap()]).output().unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("another agent is listening"));
+
+    let mut client = BufReader::new(UnixStream::connect(&path).unwrap());
+    assert_eq!(request(&mut client, "-c \"2\n", 1), ["error: unterminated quote in request"]);
+    assert!(agent.wait_with_output().unwrap().status.success());
+
+    let output = common::command().args(["agent"]).output().unwrap();
+    assert!(String::from_utf8_lossy(&output.stderr).contains("usage: pass-gen agent --socket"));
+}
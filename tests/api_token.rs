@@ -0,0 +1,115 @@
+mod common;
+
+use common::{pass_gen, stderr, success};
+
+const BASE62: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const CROCKFORD: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+fn tokens(args: &[&str]) -> Vec<String> {
+    let args: Vec<&str> = ["--api-token"].iter().chain(args).copied().collect();
+
+    success(pass_gen(&args)).lines().map(str::to_string).collect()
+}
+
+fn verify(args: &[&str]) -> (bool, String) {
+    let output = pass_gen(&[&["verify-token"], args].concat());
+
+    (output.status.success(), String::from_utf8(output.stdout).unwrap())
+}
+
+#[test]
+fn prefix_payload_and_checksum() {
+    for token in tokens(&["--token-prefix", "ghp", "-n", "20"]) {
+        let payload = token.strip_prefix("ghp_").unwrap();
+
+        // 27 base62 characters reach 160 bits
+        assert_eq!(payload.len(), 27 + 6, "{}", token);
+        assert!(payload.chars().all(|c| BASE62.contains(c)), "{}", token);
+    }
+
+    for token in tokens(&["--token-prefix", "acme_live", "--token-alphabet", "crockford32", "--bits", "128"]) {
+        let payload = token.strip_prefix("acme_live_").unwrap();
+
+        assert_eq!(payload.len(), 26 + 6, "{}", token);
+        assert!(payload.chars().all(|c| CROCKFORD.contains(c)), "{}", token);
+    }
+}
+
+#[test]
+fn round_trip() {
+    for alphabet in ["base62", "crockford32"] {
+        for token in tokens(&["--token-prefix", "pg", "--token-alphabet", alphabet, "-n", "10"]) {
+            let (valid, stdout) = verify(&[&token]);
+
+            assert!(valid, "{}: {}", token, stdout);
+            assert!(stdout.contains(&format!("prefix pg, {} {} characters", token.len() - 9, alphabet)), "{}", stdout);
+        }
+    }
+}
+
+#[test]
+fn corruption_is_detected() {
+    let token = tokens(&["--token-prefix", "pg", "--seed", "3"]).remove(0);
+
+    // every single character changed within the alphabet, in the payload and the checksum
+    for idx in 3..token.len() {
+        let mut corrupted = token.clone().into_bytes();
+        corrupted[idx] = if corrupted[idx] == b'0' { b'1' } else { b'0' };
+
+        let corrupted = String::from_utf8(corrupted).unwrap();
+        let (valid, stdout) = verify(&[&corrupted]);
+
+        assert!(!valid, "{} passed", corrupted);
+        assert!(stdout.starts_with("token invalid: token checksum"), "{}", stdout);
+    }
+
+    // a dropped character shifts the checksum
+    assert!(!verify(&[&token[..token.len() - 1]]).0);
+}
+
+#[test]
+fn malformed_tokens() {
+    let invalid = |token: &str| {
+        let (valid, stdout) = verify(&[token]);
+
+        assert!(!valid, "{} passed", token);
+        stdout
+    };
+
+    assert!(invalid("abcdefghijkl").contains("no prefix"));
+    assert!(invalid("pg_abc").contains("payload is missing"));
+    assert!(invalid("pg_abc!defghij").contains("'!'"));
+
+    let token = tokens(&["--token-prefix", "pg"]).remove(0);
+
+    assert!(verify(&["--token-prefix", "pg", &token]).0);
+    assert!(invalid(&format!("pg-{}", &token[3..])).contains("prefix"));
+
+    let (valid, stdout) = verify(&["--token-prefix", "ghp", &token]);
+    assert!(!valid && stdout.contains("is not \"ghp\""), "{}", stdout);
+
+    // nothing is printed with --quiet, the status still tells
+    let (valid, stdout) = verify(&["--quiet", "pg_abc"]);
+    assert!(!valid && stdout.is_empty());
+}
+
+#[test]
+fn follows_the_seed() {
+    let args = ["--token-prefix", "pg", "-n", "3", "--seed", "9"];
+
+    assert_eq!(tokens(&args), tokens(&args));
+}
+
+#[test]
+fn usage_errors() {
+    assert!(stderr(&["--api-token"]).contains("requires --token-prefix"));
+    assert!(stderr(&["--token-prefix", "pg"]).contains("require --api-token"));
+    assert!(stderr(&["--api-token", "--token-prefix", "pg-live"]).contains("invalid token prefix"));
+
+    let alphabet = stderr(&["--api-token", "--token-prefix", "pg", "--token-alphabet", "hex"]);
+    assert!(alphabet.contains("base62, crockford32"));
+
+    assert!(stderr(&["--api-token", "--token-prefix", "pg", "--ulid"]).contains("--api-token conflicts with --ulid"));
+    assert!(stderr(&["--api-token", "--token-prefix", "pg", "-c", "4"]).contains("--api-token conflicts with"));
+    assert!(stderr(&["verify-token"]).contains("needs the token"));
+}
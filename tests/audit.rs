@@ -0,0 +1,57 @@
+mod common;
+
+use std::{fs, thread};
+
+#[test]
+fn concurrent_runs() {
+    let path = std::env::temp_dir().join(format!("pass-gen-audit-{}.log", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let handles: Vec<_> = [["-p", "ascii", "-n", "3"], ["-p", "number", "-c", "8"]]
+        .into_iter()
+        .cycle()
+        .take(16)
+        .map(|args| {
+            let log = path.to_str().unwrap().to_string();
+
+            thread::spawn(move || common::command().args(args).args(["--audit-log", &log]).output().unwrap())
+        })
+        .collect();
+
+    let mut passwords = Vec::new();
+
+    for handle in handles {
+        let output = handle.join().unwrap();
+
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        passwords.extend(String::from_utf8(output.stdout).unwrap().lines().map(String::from));
+    }
+
+    // one intact record per run
+    let log = fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = log.lines().collect();
+
+    assert_eq!(lines.len(), 16);
+
+    for line in &lines {
+        assert!(line.starts_with("{\"time\": ") && line.ends_with('}'), "{}", line);
+        assert!(line.contains("\"user\": \"") && line.contains("\"entropy_bits\": "), "{}", line);
+
+        let record = line.contains("\"source\": \"preset ascii\", \"token_count\": 14,")
+            && line.ends_with("\"passwords\": 3}")
+            || line.contains("\"source\": \"preset number\", \"token_count\": 8,") && line.ends_with("\"passwords\": 1}");
+
+        assert!(record, "{}", line);
+    }
+
+    // metadata only
+    assert!(passwords.iter().all(|password| !log.contains(password.as_str())));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+    }
+
+    fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,47 @@
+mod common;
+
+fn total_line(env: &[(&str, &str)], args: &[&str]) -> String {
+    let output = common::command()
+        .args(["--pool-size", "16", "-c", "16"])
+        .args(args)
+        .env_remove("LC_ALL")
+        .env_remove("LC_CTYPE")
+        .env_remove("NO_COLOR")
+        .envs(env.iter().copied())
+        .output()
+        .expect("pass-gen runs");
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().find(|line| line.starts_with("total entropy:")).unwrap().to_string()
+}
+
+#[test]
+fn ascii_outside_utf8_locales() {
+    let line = total_line(&[("LANG", "C"), ("COLUMNS", "80")], &[]);
+    assert_eq!(line, format!("total entropy:              64 bits [{}{}] target 128", "#".repeat(16), "-".repeat(15)));
+}
+
+#[test]
+fn unicode_in_utf8_locales() {
+    let line = total_line(&[("LANG", "C"), ("LC_CTYPE", "en_US.UTF-8"), ("COLUMNS", "80")], &["--bar-target", "64"]);
+    assert_eq!(line, format!("total entropy:              64 bits [{}] target 64", "█".repeat(32)));
+}
+
+#[test]
+fn width_follows_the_terminal() {
+    let narrow = total_line(&[("LANG", "C"), ("COLUMNS", "20")], &[]);
+    assert_eq!(narrow, "total entropy:              64 bits [#####-----] target 128");
+
+    let wide = total_line(&[("LANG", "C"), ("COLUMNS", "500")], &[]);
+    assert_eq!(wide, format!("total entropy:              64 bits [{}{}] target 128", "#".repeat(20), "-".repeat(20)));
+}
+
+#[test]
+fn bar_target_must_be_positive() {
+    let output = common::command().args(["--bar-target", "0"]).output().unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--bar-target"));
+}
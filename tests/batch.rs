@@ -0,0 +1,68 @@
+mod common;
+
+use common::pass_gen_stdin;
+use std::fs;
+
+#[test]
+fn one_line_per_request() {
+    let path = std::env::temp_dir().join(format!("pass-gen-batch-{}.txt", std::process::id()));
+    fs::write(&path, "alpha\nbravo\ncharlie\n").unwrap();
+
+    let script = [
+        String::from("-p number -c 8"),
+        format!("-f '{}' -c 3 -s -", path.display()),
+        String::from("--not-a-flag"),
+        String::from("--seed 1"),
+        String::from("-r"),
+        String::from("-p ascii -c 12 -n 2"),
+        String::new(),
+        String::from("-p number -c 4"),
+    ];
+
+    let output = pass_gen_stdin(&["--batch"], &(script.join("\n") + "\n"));
+    fs::remove_file(path).unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    // failed lines stay paired with an empty line
+    assert_eq!(lines.len(), script.len(), "{}", stdout);
+    assert!(lines[0].len() == 8 && lines[0].bytes().all(|b| b.is_ascii_digit()));
+    assert!(lines[1].split('-').all(|word| ["alpha", "bravo", "charlie"].contains(&word)));
+    assert_eq!(lines[2], "");
+    assert_eq!(lines[3], "washes pseudonym hilly completely directed videotape");
+    assert_eq!((lines[4], lines[5]), ("", ""));
+    assert_eq!(lines[6].split(' ').count(), 6);
+    assert_eq!(lines[7].len(), 4);
+
+    assert!(stderr.contains("line 3: invalid option \"--not-a-flag\""), "{}", stderr);
+    assert!(stderr.contains("line 5: --report is not available in batch requests"), "{}", stderr);
+    assert!(stderr.contains("line 6: a batch line makes exactly one password"), "{}", stderr);
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn base_flags() {
+    // flags of the command line apply to every line, the line's own come after
+    let output = pass_gen_stdin(&["--batch", "-p", "number", "-c", "4"], "\n-c 6\n-s .\n");
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let lengths: Vec<usize> = String::from_utf8(output.stdout).unwrap().lines().map(str::len).collect();
+    assert_eq!(lengths, [4, 6, 7]);
+
+    let output = pass_gen_stdin(&["--batch", "--json"], "-c 2\n");
+    assert!(!output.status.success() && output.stdout.is_empty());
+}
+
+#[test]
+fn strict() {
+    let output = pass_gen_stdin(&["--batch", "--batch-strict", "-p", "number"], "-c 2\n-c 0\n-c 2\n");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!(String::from_utf8(output.stdout).unwrap().lines().count(), 1);
+    assert!(stderr.contains("line 2: ") && !output.status.success(), "{}", stderr);
+
+    let output = pass_gen_stdin(&["--batch-strict"], "");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--batch-strict requires --batch"));
+}
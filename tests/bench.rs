@@ -0,0 +1,43 @@
+mod common;
+
+use common::{pass_gen, success};
+use std::process::Output;
+
+fn bench(args: &[&str]) -> Output {
+    pass_gen(&[&["bench"], args].concat())
+}
+
+#[test]
+fn human_and_json() {
+    let human = success(bench(&["--seconds", "0.2", "-p", "ascii", "-c", "24"]));
+    let lines: Vec<&str> = human.lines().collect();
+
+    assert_eq!(lines.len(), 5, "{}", human);
+    assert_eq!(lines[0], "configuration: preset ascii, 24 tokens separated by \"\"");
+    assert!(lines[2].starts_with("generated:") && lines[2].ends_with("with 1 job(s)"), "{}", human);
+    assert!(lines[3].contains("passwords/s") && lines[3].ends_with("MB/s"), "{}", human);
+    assert!(lines[4].contains("ns drawing random numbers"), "{}", human);
+
+    // the separator after -s is not taken for the flag of bench
+    let json = success(bench(&["-s", "--json", "--seconds", "0.2", "-j", "2", "--json"]));
+
+    let head = "{\"configuration\": \"preset word, 6 tokens separated by \\\"--json\\\"\", \"jobs\": 2,";
+
+    assert!(json.starts_with(head), "{}", json);
+    assert!(json.contains("\"passwords_per_second\": "), "{}", json);
+    assert!(json.contains("\"per_password_ns\": {\"rng\": "), "{}", json);
+
+    // randomness can't be drawn on its own for a template
+    let template = success(bench(&["--seconds", "0.1", "--template", "Wdd", "--json"]));
+    assert!(template.contains("\"rng\": null"), "{}", template);
+}
+
+#[test]
+fn unavailable_flags() {
+    for args in [&["-n", "3"][..], &["--output", "x"], &["--report"], &["--seconds", "0"]] {
+        let output = bench(args);
+
+        assert_eq!(output.status.code(), Some(1), "{:?}", args);
+        assert!(output.stdout.is_empty());
+    }
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use std::{io::Read, process::Stdio};
+
+#[test]
+fn reader_going_away_is_not_an_error() {
+    let mut child = common::command()
+        .args(["-n", "10000000", "--seed", "1"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("pass-gen runs");
+
+    // like head, a few bytes and the pipe is closed
+    let mut head = [0; 16];
+    child.stdout.take().unwrap().read_exact(&mut head).unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(output.status.success(), "{:?}: {}", output.status, stderr);
+    assert!(!stderr.contains("panicked"), "{}", stderr);
+    assert!(stderr.is_empty(), "{}", stderr);
+}
+
+// stdout is a pipe whose reader is gone before pass-gen writes anything
+#[cfg(unix)]
+fn closed_pipe(args: &[&str], stdin: &str) -> std::process::Output {
+    use std::{
+        io::Write,
+        os::fd::{FromRawFd, OwnedFd},
+    };
+
+    let mut fds = [0; 2];
+    assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+
+    // SAFETY: both ends were just created and are owned here
+    let (reader, writer) = unsafe { (OwnedFd::from_raw_fd(fds[0]), OwnedFd::from_raw_fd(fds[1])) };
+    drop(reader);
+
+    let mut child = common::command()
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::from(writer))
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("pass-gen runs");
+
+    let _ = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    child.wait_with_output().unwrap()
+}
+
+#[cfg(unix)]
+#[test]
+fn every_command_survives_a_closed_pipe() {
+    let dir = std::env::temp_dir();
+    let list = dir.join(format!("pass-gen-pipe-{}.txt", std::process::id()));
+    let filter = dir.join(format!("pass-gen-pipe-{}.filter", std::process::id()));
+    let (list_path, filter_path) = (list.to_str().unwrap(), filter.to_str().unwrap());
+
+    std::fs::write(&list, format!("{}:3\n", "0".repeat(40))).unwrap();
+
+    let cases: &[(&[&str], &str)] = &[
+        (&["--help"], ""),
+        (&["--compat", "pwgen", "-h"], ""),
+        (&["--generate-man"], ""),
+        (&["build-filter", list_path, filter_path], ""),
+        (&["verify-checksum"], "correct horse battery staple"),
+        (&["verify-token", "pg_abc"], ""),
+        (&["verify-key", "ABCD-2345-6789-ABCD"], ""),
+    ];
+
+    for (args, stdin) in cases {
+        let output = closed_pipe(args, stdin);
+        let stderr = String::from_utf8(output.stderr).unwrap();
+
+        assert!(output.status.success() && stderr.is_empty(), "{:?} {:?}: {}", args, output.status, stderr);
+    }
+
+    std::fs::remove_file(list).unwrap();
+    std::fs::remove_file(filter).unwrap();
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use std::{
+    io::Read,
+    process::Stdio,
+    time::{Duration, Instant},
+};
+
+const PASSWORDS: usize = 1_000_000;
+
+// generous, a debug build takes about 4 seconds
+const DEADLINE: Duration = Duration::from_secs(20);
+
+#[test]
+fn million_short_passwords() {
+    let start = Instant::now();
+
+    let mut child = common::command()
+        .args(["-p", "number", "-c", "4", "-n", &PASSWORDS.to_string(), "--seed", "1"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("pass-gen runs");
+
+    let mut stdout = Vec::new();
+    child.stdout.take().unwrap().read_to_end(&mut stdout).unwrap();
+
+    #[cfg(target_os = "linux")]
+    let writes = write_calls(child.id());
+
+    assert!(child.wait().unwrap().success());
+
+    let elapsed = start.elapsed();
+    assert!(elapsed < DEADLINE, "{} passwords took {:?}", PASSWORDS, elapsed);
+
+    // 4 digits each, a newline between them
+    assert_eq!(stdout.len(), PASSWORDS * 5 - 1);
+    assert_eq!(stdout.iter().filter(|&&byte| byte == b'\n').count(), PASSWORDS - 1);
+
+    // buffered, so far fewer writes than passwords
+    #[cfg(target_os = "linux")]
+    assert!(writes < PASSWORDS / 100, "{} write calls for {} passwords", writes, PASSWORDS);
+}
+
+// write system calls of a child that exited but was not waited for yet, its counters stay until then
+#[cfg(target_os = "linux")]
+fn write_calls(pid: u32) -> usize {
+    let proc = format!("/proc/{}", pid);
+
+    while !std::fs::read_to_string(format!("{}/stat", proc)).unwrap().contains(") Z ") {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    let io = std::fs::read_to_string(format!("{}/io", proc)).unwrap();
+    io.lines().find_map(|line| line.strip_prefix("syscw: ")).unwrap().parse().unwrap()
+}
@@ -0,0 +1,79 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use std::{
+    ffi::CStr,
+    fs::{File, OpenOptions},
+    io::Read,
+    os::fd::FromRawFd,
+    process::Stdio,
+};
+
+const WIDTH: usize = 40;
+
+// stdout on a pseudo terminal of unknown size and stderr on a pipe, so only COLUMNS tells the width
+fn on_terminal(args: &[&str]) -> String {
+    // SAFETY: the master fd is checked and owned by the file, ptsname is read before any other call
+    let (mut master, slave) = unsafe {
+        let fd = libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY);
+        assert!(fd >= 0 && libc::grantpt(fd) == 0 && libc::unlockpt(fd) == 0);
+
+        let name = CStr::from_ptr(libc::ptsname(fd)).to_str().unwrap().to_string();
+        (File::from_raw_fd(fd), OpenOptions::new().write(true).open(name).unwrap())
+    };
+
+    let mut child = common::command()
+        .args(args)
+        .env("COLUMNS", WIDTH.to_string())
+        .stdout(Stdio::from(slave))
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("pass-gen runs");
+
+    // the terminal ends with the last writer, a read past it fails with EIO
+    let mut output = Vec::new();
+    let mut buf = [0; 4096];
+
+    while let Ok(n @ 1..) = master.read(&mut buf) {
+        output.extend_from_slice(&buf[..n]);
+    }
+
+    assert!(child.wait().unwrap().success());
+    String::from_utf8(output).unwrap().replace("\r\n", "\n")
+}
+
+fn assert_fits(output: &str, columns: usize) {
+    let rows: Vec<&str> = output.lines().collect();
+
+    assert!(rows.len() > 1, "{}", output);
+
+    for row in &rows {
+        assert!(row.chars().count() <= WIDTH, "{:?} is wider than {}", row, WIDTH);
+        assert_eq!(row.split_whitespace().count(), columns, "{}", output);
+    }
+}
+
+#[test]
+fn pwgen_columns_fit() {
+    let output = on_terminal(&["--compat", "pwgen", "-s", "8"]);
+
+    // 4 columns of 8 with a gap of 2, 20 rows like pwgen
+    assert_fits(&output, 4);
+    assert_eq!(output.lines().count(), 20);
+}
+
+#[test]
+fn batch_columns_fit() {
+    // 3 columns of 12 and 2 gaps fill all 40 columns
+    let output = on_terminal(&["-p", "ascii", "-c", "12", "-n", "30"]);
+
+    assert_fits(&output, 3);
+    assert_eq!(output.lines().count(), 10);
+
+    // one more character leaves room for 2
+    let output = on_terminal(&["-p", "ascii", "-c", "13", "-n", "30"]);
+
+    assert_fits(&output, 2);
+    assert_eq!(output.lines().count(), 15);
+}
@@ -0,0 +1,58 @@
+// helpers shared by the integration tests, each test binary uses only some of them
+#![allow(dead_code)]
+
+use std::{
+    fs,
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+// from the package root, so fixture paths are relative to it, and away from the developer's config file
+pub fn command() -> Command {
+    let home = std::env::temp_dir().join(format!("pass-gen-home-{}", std::process::id()));
+    fs::create_dir_all(&home).unwrap();
+
+    let mut command = Command::new(env!("CARGO_BIN_EXE_pass-gen"));
+    command.current_dir(env!("CARGO_MANIFEST_DIR")).env("XDG_CONFIG_HOME", home).env_remove("HOME");
+    command
+}
+
+pub fn pass_gen(args: &[&str]) -> Output {
+    command().args(args).output().expect("pass-gen runs")
+}
+
+pub fn pass_gen_stdin(args: &[&str], stdin: impl AsRef<[u8]>) -> Output {
+    let mut child = command()
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("pass-gen runs");
+
+    // a run that fails up front exits without reading stdin
+    let _ = child.stdin.take().unwrap().write_all(stdin.as_ref());
+    child.wait_with_output().unwrap()
+}
+
+// stdout of a run that has to succeed
+pub fn success(output: Output) -> String {
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+pub fn stdout(args: &[&str]) -> String {
+    success(pass_gen(args))
+}
+
+pub fn stdout_stdin(args: &[&str], stdin: impl AsRef<[u8]>) -> String {
+    success(pass_gen_stdin(args, stdin))
+}
+
+// stderr of a run rejected as a usage error
+pub fn stderr(args: &[&str]) -> String {
+    let output = pass_gen(args);
+
+    assert_eq!(output.status.code(), Some(1), "{:?}", args);
+    String::from_utf8(output.stderr).unwrap()
+}
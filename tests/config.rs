@@ -0,0 +1,80 @@
+mod common;
+
+use std::{fs, path::PathBuf, process::Output};
+
+const CONFIG: &str = "[preset.word]\ncount = 3\nsep = \"-\"\n\n[preset.ascii]\ncount = 24\n\n[preset.emoji]\ncount = 2\n";
+
+fn config_home(name: &str) -> PathBuf {
+    let home = std::env::temp_dir().join(format!("pass-gen-config-{}-{}", name, std::process::id()));
+
+    fs::create_dir_all(home.join("pass-gen")).unwrap();
+    fs::write(home.join("pass-gen/config.toml"), CONFIG).unwrap();
+
+    home
+}
+
+fn pass_gen(home: &PathBuf, args: &[&str]) -> Output {
+    let output = common::command().env("XDG_CONFIG_HOME", home).args(args).output().expect("pass-gen runs");
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    output
+}
+
+fn stdout(home: &PathBuf, args: &[&str]) -> String {
+    String::from_utf8(pass_gen(home, args).stdout).unwrap()
+}
+
+#[test]
+fn layers() {
+    let home = config_home("layers");
+
+    // preset defaults < config section < flags, for each setting on its own
+    assert_eq!(stdout(&home, &[]).split('-').count(), 3);
+    assert_eq!(stdout(&home, &["-c", "5"]).split('-').count(), 5);
+    assert_eq!(stdout(&home, &["-s", "."]).split('.').count(), 3);
+    assert_eq!(stdout(&home, &["-c", "4", "-s", " "]).split(' ').count(), 4);
+
+    // sections only apply to their own preset
+    assert_eq!(stdout(&home, &["-p", "ascii"]).chars().count(), 24);
+    assert_eq!(stdout(&home, &["-p", "number"]).chars().count(), 6);
+
+    // --no-config keeps the built in defaults
+    assert_eq!(stdout(&home, &["--no-config"]).split(' ').count(), 6);
+
+    fs::remove_dir_all(home).unwrap();
+}
+
+#[test]
+fn verbose_sources() {
+    let home = config_home("verbose");
+    let verbose = |args: &[&str]| String::from_utf8(pass_gen(&home, args).stderr).unwrap();
+
+    let stderr = verbose(&["-v"]);
+    assert!(stderr.contains("token count:  3 (from config file)"), "{}", stderr);
+    assert!(stderr.contains("separator:    \"-\" (from config file)"), "{}", stderr);
+
+    let stderr = verbose(&["-v", "-c", "4", "-s", "."]);
+    assert!(stderr.contains("token count:  4 (from --count)"), "{}", stderr);
+    assert!(stderr.contains("separator:    \".\" (from --sep)"), "{}", stderr);
+
+    let stderr = verbose(&["-v", "-p", "number"]);
+    assert!(stderr.contains("token count:  6 (from default)"), "{}", stderr);
+
+    // sections of presets this build lacks only warn
+    assert!(stderr.contains("warning") && stderr.contains("unknown preset \"emoji\""), "{}", stderr);
+
+    fs::remove_dir_all(home).unwrap();
+}
+
+#[test]
+fn invalid_config() {
+    let home = config_home("invalid");
+    fs::write(home.join("pass-gen/config.toml"), "[preset.word]\ncount = \"five\"\n").unwrap();
+
+    let output = common::command().env("XDG_CONFIG_HOME", &home).output().unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("line 2: count must be a positive number"));
+
+    fs::remove_dir_all(home).unwrap();
+}
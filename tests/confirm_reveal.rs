@@ -0,0 +1,15 @@
+mod common;
+
+use common::pass_gen;
+
+#[test]
+fn piped_output_is_not_held() {
+    for args in [&["--seed", "1"][..], &["--seed", "2", "-n", "5", "-r"], &["--seed", "3", "--json"]] {
+        let plain = pass_gen(args);
+        let held = pass_gen(&[args, &["--confirm-reveal"]].concat());
+
+        // no prompt, the same output
+        assert!(held.status.success(), "{}", String::from_utf8_lossy(&held.stderr));
+        assert_eq!((held.stdout, held.stderr), (plain.stdout, plain.stderr));
+    }
+}
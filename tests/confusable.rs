@@ -0,0 +1,33 @@
+mod common;
+
+use std::fs;
+
+#[test]
+fn report_and_pool() {
+    let path = std::env::temp_dir().join(format!("pass-gen-confusable-{}.txt", std::process::id()));
+    fs::write(&path, "to\ntoo\ntwo\nthere\ntheir\nquiet\nquite\nmaple\ncedar\n").unwrap();
+
+    let run = |args: &[&str]| {
+        let output = common::command()
+            .args(["-f", path.to_str().unwrap(), "--no-confusable"])
+            .args(args)
+            .output()
+            .expect("pass-gen runs");
+
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let report = run(&["--report-only", "--report-format", "json"]);
+    assert!(report.contains("{\"pool_size\": 5, \"filters\": [{\"name\": \"duplicates\", \"removed\": 0}, \
+        {\"name\": \"confusable words\", \"removed\": 4}]"), "{}", report);
+
+    // one word of each set is left to draw
+    let words: Vec<String> = run(&["-n", "500", "-c", "3"]).split_whitespace().map(String::from).collect();
+
+    for set in [&["to", "too", "two"][..], &["there", "their"], &["quiet", "quite"]] {
+        assert_eq!(set.iter().filter(|word| words.iter().any(|w| w == *word)).count(), 1, "{:?}", set);
+    }
+
+    fs::remove_file(path).unwrap();
+}
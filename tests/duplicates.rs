@@ -0,0 +1,44 @@
+mod common;
+
+use common::{pass_gen, stdout};
+use std::fs;
+
+// 16 words, each 64 times, in a file of 1024 lines
+fn duplicate_heavy_file(name: &str) -> (String, Vec<String>) {
+    let words: Vec<String> = (0..16).map(|i| format!("word{:02}", i)).collect();
+    let lines: Vec<&str> = (0..64).flat_map(|_| words.iter().map(String::as_str)).collect();
+
+    let path = std::env::temp_dir().join(format!("pass-gen-{}-{}.txt", name, std::process::id()));
+    fs::write(&path, lines.join("\n")).unwrap();
+
+    (path.to_str().unwrap().to_string(), words)
+}
+
+#[test]
+fn entropy_from_the_deduplicated_pool() {
+    let (path, _) = duplicate_heavy_file("duplicates-report");
+    let output = pass_gen(&["-f", &path, "-c", "5", "--report-only", "--report-format", "kv"]);
+    let verbose = pass_gen(&["-f", &path, "-c", "5", "-v"]);
+    fs::remove_file(&path).unwrap();
+
+    let report = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = report.lines().collect();
+
+    // 4 bits per word of 16, not 10 bits per line of 1024
+    assert!(lines.contains(&"pool_size=16"), "{}", report);
+    assert!(lines.contains(&"filter_duplicates=1008"), "{}", report);
+    assert!(lines.contains(&"per_token_bits=4"), "{}", report);
+    assert!(lines.contains(&"total_bits=20"), "{}", report);
+
+    let stderr = String::from_utf8(verbose.stderr).unwrap();
+    assert!(stderr.contains("pool size:    1024 loaded\n              16 after duplicates filter (-1008)\n"), "{}", stderr);
+}
+
+#[test]
+fn every_word_once() {
+    let (path, words) = duplicate_heavy_file("duplicates-words");
+    let listed = stdout(&["words", "-f", &path]);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(listed.lines().collect::<Vec<_>>(), words);
+}
@@ -0,0 +1,125 @@
+#![cfg(unix)]
+
+mod common;
+
+use common::success;
+use std::{
+    fs,
+    io::Write,
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+    process::{Command, Output, Stdio},
+};
+
+// a throwaway keyring, removed with its agent when dropped
+struct Keyring {
+    home: PathBuf,
+}
+
+impl Keyring {
+    fn new(uids: &[&str]) -> Option<Keyring> {
+        Command::new("gpg").arg("--version").output().ok().filter(|output| output.status.success())?;
+
+        let home = std::env::temp_dir().join(format!("pass-gen-gpg-{}", std::process::id()));
+        fs::create_dir_all(&home).unwrap();
+        fs::set_permissions(&home, fs::Permissions::from_mode(0o700)).unwrap();
+
+        let keyring = Keyring { home };
+
+        for uid in uids {
+            let args = ["--batch", "--passphrase", "", "--quick-gen-key", uid, "future-default", "default", "never"];
+            let output = keyring.gpg(&args, "");
+
+            assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        }
+
+        Some(keyring)
+    }
+
+    fn gpg(&self, args: &[&str], stdin: &str) -> Output {
+        run(Command::new("gpg").env("GNUPGHOME", &self.home).args(args), stdin)
+    }
+
+    fn pass_gen(&self, args: &[&str]) -> Output {
+        run(common::command().env("GNUPGHOME", &self.home).args(args), "")
+    }
+
+    fn decrypt(&self, armor: &str) -> String {
+        let output = self.gpg(&["--batch", "--quiet", "--decrypt"], armor);
+
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    fn home(&self) -> &Path {
+        &self.home
+    }
+}
+
+impl Drop for Keyring {
+    fn drop(&mut self) {
+        let _ = Command::new("gpgconf").env("GNUPGHOME", &self.home).args(["--kill", "gpg-agent"]).output();
+        let _ = fs::remove_dir_all(&self.home);
+    }
+}
+
+fn run(command: &mut Command, stdin: &str) -> Output {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("command runs");
+
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn decrypts_to_the_password() {
+    let keyring = match Keyring::new(&["alice@example.org", "bob@example.org"]) {
+        Some(keyring) => keyring,
+        None => return eprintln!("gpg is not installed, skipping"),
+    };
+
+    let password = success(keyring.pass_gen(&["--seed", "1"]));
+    assert_eq!(password, "washes pseudonym hilly completely directed videotape");
+
+    // no plaintext on stdout, either recipient can decrypt
+    let armor = success(keyring.pass_gen(&["--seed", "1", "--encrypt-to", "alice@example.org", "--encrypt-to", "bob"]));
+
+    assert!(armor.starts_with("-----BEGIN PGP MESSAGE-----") && !armor.contains("washes"), "{}", armor);
+    assert_eq!(keyring.decrypt(&armor), password);
+
+    // batches are encrypted as a whole
+    let batch = success(keyring.pass_gen(&["--seed", "2", "-n", "3"]));
+    assert_eq!(keyring.decrypt(&success(keyring.pass_gen(&["--seed", "2", "-n", "3", "--encrypt-to", "bob"]))), batch);
+
+    // --also-plain prints the password above the ciphertext
+    let both = success(keyring.pass_gen(&["--seed", "1", "--encrypt-to", "alice@example.org", "--also-plain"]));
+    let (plain, armor) = both.split_once("\n\n").unwrap();
+
+    assert_eq!(plain, password);
+    assert!(armor.starts_with("-----BEGIN PGP MESSAGE-----"), "{}", armor);
+    assert_eq!(keyring.decrypt(armor), password);
+
+    // the ciphertext goes to the output file as well
+    let path = keyring.home().join("out.asc");
+    success(keyring.pass_gen(&["--seed", "1", "--encrypt-to", "bob", "-o", path.to_str().unwrap()]));
+    assert_eq!(keyring.decrypt(&fs::read_to_string(&path).unwrap()), password);
+
+    // gpg's status and message are passed on
+    let output = keyring.pass_gen(&["--encrypt-to", "mallory@example.org"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success() && stderr.contains("gpg failed with exit status"), "{}", stderr);
+    assert!(stderr.contains("mallory@example.org") && output.stdout.is_empty(), "{}", stderr);
+}
+
+#[test]
+fn conflicts() {
+    let output = run(common::command().args(["--encrypt-to", "bob", "--split", "2/3"]), "");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success() && stderr.contains("--encrypt-to conflicts with --split"), "{}", stderr);
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::stdout_stdin;
+
+// the passphrase is piped in, --estimate - reads it from stdin when there is no terminal
+fn estimate(args: &[&str], phrase: &str) -> String {
+    let args = [&["--estimate", "-", "--no-config", "--no-locale"], args].concat();
+
+    stdout_stdin(&args, format!("{}\n", phrase))
+}
+
+#[test]
+fn known_passphrase_from_stdin() {
+    let report = estimate(&[], "correct horse battery staple");
+
+    // 4 words of 14.7 bits from the 26578 of the word preset
+    assert!(report.starts_with("tokens:                     4\nfound in pool:              4\n"), "{}", report);
+    assert!(report.contains("length:                     28 chars\n"), "{}", report);
+    assert!(report.contains("entropy per word:           14.7 bits\n"), "{}", report);
+    assert!(report.contains("total entropy:              59 bits"), "{}", report);
+    assert!(!report.contains("warning"), "{}", report);
+
+    let kv = estimate(&["--report-format", "kv"], "correct horse battery staple");
+    assert!(kv.lines().any(|line| line == "total_bits=58.79177970059062"), "{}", kv);
+}
+
+#[test]
+fn split_by_the_separator() {
+    let kv = estimate(&["-p", "number", "-s", "", "--report-format", "kv"], "123456");
+
+    assert!(kv.starts_with("estimate_tokens=6\nestimate_found=6\n"), "{}", kv);
+    assert!(kv.lines().any(|line| line == "total_bits=19.931568569324174"), "{}", kv);
+}
+
+#[test]
+fn tokens_outside_the_pool() {
+    let report = estimate(&[], "correct horse xyzzyq staple");
+
+    assert!(report.contains("found in pool:              3\n"), "{}", report);
+
+    let warning = "warning: 1 token(s) are not in the pool, the estimate below is unreliable\n";
+    assert!(report.contains(warning), "{}", report);
+}
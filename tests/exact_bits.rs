@@ -0,0 +1,65 @@
+mod common;
+
+use common::{pass_gen_stdin, stdout_stdin};
+use std::fs;
+
+#[test]
+fn round_trip() {
+    for pool in [&["-p", "word"][..], &["-p", "number"], &["--charset", "a-z", "-s", "-"], &["--charset", "ab"]] {
+        for bits in ["1", "61", "128", "257"] {
+            let encode = [pool, &["--exact-bits", bits, "-n", "20", "--seed", "7"]].concat();
+            let decode = [pool, &["--decode-bits", bits]].concat();
+
+            let mut integers: Vec<String> = stdout_stdin(&encode, "")
+                .lines()
+                .map(|password| stdout_stdin(&decode, password).trim_end().to_string())
+                .collect();
+
+            let digits = bits.parse::<usize>().unwrap().div_ceil(4);
+            assert!(integers.iter().all(|hex| hex.len() == digits && u8::from_str_radix(&hex[..1], 16).is_ok()));
+
+            // distinct passwords come from distinct integers
+            integers.sort();
+            integers.dedup();
+            assert!(integers.len() > 1 || bits == "1", "{:?} {}", pool, bits);
+        }
+    }
+}
+
+#[test]
+fn fewest_tokens() {
+    // 10 words of a diceware sized list hold 128 bits, drawn on their own they would carry 129.2
+    let path = std::env::temp_dir().join(format!("pass-gen-exact-bits-{}.txt", std::process::id()));
+    fs::write(&path, (0..7776).map(|n| format!("w{}\n", n)).collect::<String>()).unwrap();
+
+    let words = stdout_stdin(&["-f", path.to_str().unwrap(), "--exact-bits", "128"], "");
+    fs::remove_file(path).unwrap();
+
+    assert_eq!(words.split(' ').count(), 10);
+
+    let letters = stdout_stdin(&["--charset", "a-z", "--exact-bits", "128"], "");
+    assert_eq!(letters.len(), 28);
+
+    let output = pass_gen_stdin(&["--charset", "a-z", "--exact-bits", "128", "-r"], "");
+    let report = String::from_utf8(output.stderr).unwrap();
+    assert!(report.contains("total entropy:              128 bits"), "{}", report);
+}
+
+#[test]
+fn rejects() {
+    let fails = |args: &[&str], stdin: &str, msg: &str| {
+        let output = pass_gen_stdin(args, stdin);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(!output.status.success() && stderr.contains(msg), "{}", stderr);
+    };
+
+    // 8 is out of range for 2 bits, nor is d in the pool
+    fails(&["--charset", "abc", "--decode-bits", "2"], "cc", "more than 2 bits");
+    fails(&["--charset", "abc", "--decode-bits", "2"], "ad", "token 2");
+    fails(&["--charset", "abc", "--decode-bits", "2"], "abc", "expected 2 tokens");
+
+    fails(&["--exact-bits", "64", "-c", "3"], "", "conflicts with --count");
+    fails(&["--exact-bits", "64", "--decode-bits", "64"], "", "conflicts with --decode-bits");
+    fails(&["--decode-bits", "64", "--checksum"], "", "--decode-bits conflicts");
+}
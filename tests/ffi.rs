@@ -0,0 +1,164 @@
+#![cfg(feature = "ffi")]
+
+use pass_gen::ffi::{
+    passgen_entropy_bits, passgen_generate, passgen_last_error, PASSGEN_BUFFER_TOO_SMALL, PASSGEN_INTERNAL_ERROR,
+    PASSGEN_INVALID_ARGUMENT, PASSGEN_OK,
+};
+use std::{
+    ffi::{c_char, c_int, CStr},
+    ptr,
+};
+
+// the c string in out and the status of passgen_generate
+fn generate(preset: &CStr, count: u32, sep: &CStr, out_len: usize) -> (c_int, String) {
+    let mut out = vec![1 as c_char; out_len];
+    let status = unsafe { passgen_generate(preset.as_ptr(), count, sep.as_ptr(), out.as_mut_ptr(), out.len()) };
+
+    let password = match status {
+        PASSGEN_OK => unsafe { CStr::from_ptr(out.as_ptr()) }.to_str().unwrap().to_string(),
+        _ => String::new(),
+    };
+
+    (status, password)
+}
+
+fn last_error() -> Option<String> {
+    let msg = passgen_last_error();
+
+    (!msg.is_null()).then(|| unsafe { CStr::from_ptr(msg) }.to_str().unwrap().to_string())
+}
+
+#[test]
+fn generates_into_the_buffer() {
+    let (status, password) = generate(c"number", 6, c"", 256);
+
+    assert_eq!(status, PASSGEN_OK);
+    assert!(password.len() == 6 && password.chars().all(|c| c.is_ascii_digit()), "{}", password);
+    assert_eq!(last_error(), None);
+
+    let (status, password) = generate(c"word", 4, c"-", 256);
+
+    assert_eq!(status, PASSGEN_OK);
+    assert_eq!(password.split('-').count(), 4, "{}", password);
+
+    // exactly the password and its NUL fit
+    let (status, password) = generate(c"number", 6, c"", 7);
+    assert_eq!((status, password.len()), (PASSGEN_OK, 6));
+}
+
+#[test]
+fn entropy_of_the_preset() {
+    let mut bits = 0.0;
+
+    assert_eq!(unsafe { passgen_entropy_bits(c"ascii".as_ptr(), 10, &mut bits) }, PASSGEN_OK);
+    assert!(bits > 65.0 && bits < 66.0, "{}", bits);
+    assert_eq!(last_error(), None);
+
+    assert_eq!(unsafe { passgen_entropy_bits(c"number".as_ptr(), 3, &mut bits) }, PASSGEN_OK);
+    assert!((bits - 1000f64.log2()).abs() < 1e-9, "{}", bits);
+}
+
+#[test]
+fn errors_are_kept_until_the_next_call() {
+    assert_eq!(generate(c"emoji", 6, c"", 256).0, PASSGEN_INVALID_ARGUMENT);
+    assert_eq!(last_error().as_deref(), Some("invalid preset \"emoji\""));
+
+    assert_eq!(generate(c"number", 6, c"", 6).0, PASSGEN_BUFFER_TOO_SMALL);
+    assert_eq!(last_error().as_deref(), Some("password needs 7 bytes, buffer has 6"));
+
+    assert_eq!(generate(c"number", 0, c"", 256).0, PASSGEN_INVALID_ARGUMENT);
+    assert_eq!(generate(c"\xff", 6, c"", 256).0, PASSGEN_INVALID_ARGUMENT);
+    assert_eq!(last_error().as_deref(), Some("preset is not valid utf-8"));
+
+    let mut out = [0 as c_char; 16];
+    let status = unsafe { passgen_generate(ptr::null(), 6, c"".as_ptr(), out.as_mut_ptr(), out.len()) };
+
+    assert_eq!(status, PASSGEN_INVALID_ARGUMENT);
+    assert_eq!(last_error().as_deref(), Some("preset is null"));
+
+    assert_eq!(unsafe { passgen_entropy_bits(c"word".as_ptr(), 1, ptr::null_mut()) }, PASSGEN_INVALID_ARGUMENT);
+    assert_eq!(last_error().as_deref(), Some("out is null"));
+
+    // a successful call clears it
+    assert_eq!(generate(c"number", 6, c"", 256).0, PASSGEN_OK);
+    assert_eq!(last_error(), None);
+}
+
+#[test]
+fn errors_are_per_thread() {
+    assert_eq!(generate(c"emoji", 6, c"", 256).0, PASSGEN_INVALID_ARGUMENT);
+
+    std::thread::spawn(|| assert_eq!(last_error(), None)).join().unwrap();
+    assert!(last_error().is_some());
+}
+
+// the C type spelled in the header for a type of the rust signature
+fn c_type(rust: &str) -> &'static str {
+    match rust {
+        "*const c_char" => "const char *",
+        "*mut c_char" => "char *",
+        "*mut f64" => "double *",
+        "u32" => "uint32_t",
+        "usize" => "size_t",
+        "c_int" => "int",
+        _ => panic!("no C type for {}", rust),
+    }
+}
+
+#[test]
+fn header_matches_the_exports() {
+    let header = include_str!("../ffi/passgen.h");
+    let source = include_str!("../src/ffi.rs");
+
+    // the status codes
+    let defines: Vec<(&str, i32)> = header
+        .lines()
+        .filter_map(|line| line.strip_prefix("#define PASSGEN_"))
+        .filter_map(|line| line.split_once(' '))
+        .map(|(name, value)| (name, value.trim().parse().unwrap()))
+        .collect();
+
+    let codes = [
+        ("OK", PASSGEN_OK),
+        ("INVALID_ARGUMENT", PASSGEN_INVALID_ARGUMENT),
+        ("BUFFER_TOO_SMALL", PASSGEN_BUFFER_TOO_SMALL),
+        ("INTERNAL_ERROR", PASSGEN_INTERNAL_ERROR),
+    ];
+
+    assert_eq!(defines, codes);
+    assert_eq!(source.matches("pub const PASSGEN_").count(), codes.len());
+
+    // every exported function is declared with the same parameters, and nothing else is
+    let _: unsafe extern "C" fn(*const c_char, u32, *const c_char, *mut c_char, usize) -> c_int = passgen_generate;
+    let _: unsafe extern "C" fn(*const c_char, u32, *mut f64) -> c_int = passgen_entropy_bits;
+    let _: extern "C" fn() -> *const c_char = passgen_last_error;
+
+    let exports: Vec<String> = source
+        .split("#[no_mangle]")
+        .skip(1)
+        .map(|item| {
+            let item = &item[item.find("fn ").unwrap() + 3..item.find('{').unwrap()];
+            let (name, rest) = item.split_once('(').unwrap();
+            let (params, ret) = rest.rsplit_once(')').unwrap();
+
+            let params: Vec<String> = params
+                .split(',')
+                .map(str::trim)
+                .filter(|param| !param.is_empty())
+                .map(|param| {
+                    let (name, rust) = param.split_once(": ").unwrap();
+                    format!("{}{}{}", c_type(rust), if rust.starts_with('*') { "" } else { " " }, name)
+                })
+                .collect();
+
+            let ret = c_type(ret.trim().trim_start_matches("-> "));
+            let params = if params.is_empty() { String::from("void") } else { params.join(", ") };
+
+            format!("{}{}{}({});", ret, if ret.ends_with('*') { "" } else { " " }, name.trim(), params)
+        })
+        .collect();
+
+    let declared: Vec<&str> = header.lines().filter(|line| line.contains("passgen_") && line.ends_with(");")).collect();
+
+    assert_eq!(declared, exports);
+}
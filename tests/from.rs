@@ -0,0 +1,66 @@
+mod common;
+
+use common::{pass_gen, stdout};
+
+const ADJECTIVES: &str = "tests/fixtures/from-adjectives.txt";
+const ANIMALS: &str = "tests/fixtures/from-animals.txt";
+
+fn spec() -> String {
+    format!("{},{},preset:number", ADJECTIVES, ANIMALS)
+}
+
+#[test]
+fn one_token_from_each_source_in_order() {
+    let adjectives = ["quick", "silent", "brave", "hollow"];
+    let animals = ["otter", "heron", "badger", "lynx"];
+
+    for count in ["1", "3"] {
+        let passwords = stdout(&["--from", &spec(), "-c", count, "-s", "-", "-n", "200"]);
+
+        for password in passwords.lines() {
+            let tokens: Vec<&str> = password.split('-').collect();
+
+            assert_eq!(tokens.len(), 3 * count.parse::<usize>().unwrap(), "{}", password);
+
+            for triple in tokens.chunks(3) {
+                assert!(adjectives.contains(&triple[0]), "{}", password);
+                assert!(animals.contains(&triple[1]), "{}", password);
+                assert!(triple[2].len() == 1 && triple[2].parse::<u8>().is_ok(), "{}", password);
+            }
+        }
+    }
+}
+
+#[test]
+fn report_sums_each_position() {
+    let kv = stdout(&["--from", &spec(), "-c", "2", "--report-only", "--report-format", "kv"]);
+    let total: f64 = kv.lines().find_map(|line| line.strip_prefix("total_bits=")).unwrap().parse().unwrap();
+
+    // the repeated adjective is dropped, four of each and ten digits
+    assert!((total - 2.0 * (2.0 + 2.0 + 10f64.log2())).abs() < 1e-9, "{}", kv);
+    assert!(kv.contains("filter_duplicates=1"), "{}", kv);
+}
+
+#[test]
+fn filters_apply_per_source() {
+    let output = pass_gen(&["--from", &spec(), "--max-word-length", "5"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let output = pass_gen(&["--from", &format!("{},preset:number", ANIMALS), "--min-word-length", "2"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("source 2 of --from (\"preset:number\")"), "{}", stderr);
+}
+
+#[test]
+fn errors_name_the_source() {
+    let error = |spec: &str| String::from_utf8_lossy(&pass_gen(&["--from", spec]).stderr).to_string();
+
+    assert!(error(&format!("{},missing.txt", ADJECTIVES)).contains("source 2 of --from (\"missing.txt\")"));
+    assert!(error("preset:number,preset:nope").contains("source 2 of --from (\"preset:nope\"): invalid preset"));
+    assert!(error("preset:number,,preset:number").contains("source 2 of --from (\"\"): empty source"));
+
+    let output = pass_gen(&["--from", &spec(), "--template", "w-w"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--from conflicts with"));
+}
@@ -0,0 +1,33 @@
+use pass_gen::{grapheme_len, graphemes};
+
+// "÷ 0061 × 0308 ÷ 0062 ÷" into the string and the characters it splits into
+fn parse(case: &str) -> (String, Vec<String>) {
+    let mut clusters = Vec::new();
+    let mut cluster = String::new();
+
+    for field in case.split_whitespace() {
+        match field {
+            "÷" if !cluster.is_empty() => clusters.push(std::mem::take(&mut cluster)),
+            "÷" | "×" => {},
+            hex => cluster.push(char::from_u32(u32::from_str_radix(hex, 16).unwrap()).unwrap()),
+        }
+    }
+
+    (clusters.concat(), clusters)
+}
+
+#[test]
+fn grapheme_break_test() {
+    let cases = include_str!("fixtures/grapheme-break-test.txt");
+    let mut count = 0;
+
+    for case in cases.lines().filter(|line| !line.is_empty() && !line.starts_with('#')) {
+        let (text, expected) = parse(case);
+
+        assert_eq!(graphemes(&text).collect::<Vec<_>>(), expected, "{}", case);
+        assert_eq!(grapheme_len(&text), expected.len(), "{}", case);
+        count += 1;
+    }
+
+    assert_eq!(count, 602);
+}
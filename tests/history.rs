@@ -0,0 +1,153 @@
+mod common;
+
+use common::{command, pass_gen, stderr};
+use std::{collections::HashSet, fs, path::PathBuf, thread};
+
+fn history_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("pass-gen-history-{}-{}.txt", name, std::process::id()));
+    let _ = fs::remove_file(&path);
+    path
+}
+
+fn entries(path: &PathBuf) -> Vec<String> {
+    fs::read_to_string(path).unwrap().lines().skip(3).map(String::from).collect()
+}
+
+#[test]
+fn never_repeats() {
+    let path = history_path("repeat");
+    let history = path.to_str().unwrap();
+    let mut issued = HashSet::new();
+
+    // a pool of 8 passwords runs out after 4 batches of 2
+    for _ in 0..4 {
+        let output = pass_gen(&["--charset", "ab", "-c", "3", "-n", "2", "--history", history]);
+
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+        for password in String::from_utf8(output.stdout).unwrap().lines() {
+            assert!(issued.insert(password.to_string()), "{} was issued twice", password);
+        }
+    }
+
+    let output = pass_gen(&["--charset", "ab", "-c", "3", "--history", history]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("issued before"));
+    assert!(output.stdout.is_empty());
+
+    // only keyed hashes are stored, the key is kept elsewhere
+    let text = fs::read_to_string(&path).unwrap();
+
+    assert!(text.starts_with("# pass-gen history v2\nalgorithm hmac-sha256\nkey "));
+    assert!(text.split_whitespace().all(|field| !issued.contains(field)));
+    assert_eq!(entries(&path).len(), 8);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+    }
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn concurrent_runs() {
+    let path = history_path("concurrent");
+    let history = path.to_str().unwrap().to_string();
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let history = history.clone();
+            thread::spawn(move || pass_gen(&["-p", "number", "-c", "3", "-n", "50", "--history", &history]))
+        })
+        .collect();
+
+    let mut issued = HashSet::new();
+
+    for handle in handles {
+        let output = handle.join().unwrap();
+
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+        for password in String::from_utf8(output.stdout).unwrap().lines() {
+            assert!(issued.insert(password.to_string()), "{} was issued twice", password);
+        }
+    }
+
+    // every run appended its own lines to a single header
+    let hashes: HashSet<String> = entries(&path).into_iter().collect();
+
+    assert_eq!(issued.len(), 400);
+    assert_eq!(hashes.len(), 400);
+    assert_eq!(fs::read_to_string(&path).unwrap().matches("# pass-gen history").count(), 1);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn prune() {
+    let path = history_path("prune");
+    let history = path.to_str().unwrap();
+
+    assert!(pass_gen(&["-n", "3", "--history", history]).status.success());
+
+    // backdate the first entry to the epoch
+    let text = fs::read_to_string(&path).unwrap();
+    let old = text.lines().nth(3).unwrap();
+    fs::write(&path, text.replacen(old, &format!("0 {}", old.split_once(' ').unwrap().1), 1)).unwrap();
+
+    let output = pass_gen(&["--history", history, "--history-prune", "30"]);
+
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("pruned 1 password(s)"));
+    assert_eq!(entries(&path).len(), 2);
+
+    assert!(!pass_gen(&["--history-prune", "30"]).status.success());
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn report_only_leaves_no_file() {
+    let path = history_path("report");
+    let output = pass_gen(&["--report-only", "--history", path.to_str().unwrap()]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--report-only cannot be combined with --history"));
+    assert!(!path.exists());
+}
+
+#[test]
+fn another_key_cannot_read_it() {
+    let path = history_path("key");
+    let history = path.to_str().unwrap();
+
+    assert!(pass_gen(&["-p", "number", "--history", history]).status.success());
+
+    let home = std::env::temp_dir().join(format!("pass-gen-other-home-{}", std::process::id()));
+    let output = command().env("XDG_CONFIG_HOME", &home).args(["-p", "number", "--history", history]).output().unwrap();
+    let error = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(error.contains("written with another history key"), "{}", error);
+
+    // the new key was created with the file left untouched
+    assert!(home.join("pass-gen/history-key").exists() && entries(&path).len() == 1);
+
+    fs::remove_dir_all(home).unwrap();
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn old_histories_are_rejected() {
+    let path = history_path("v1");
+    fs::write(&path, "# pass-gen history v1\nsalt 00\nalgorithm sha256\n").unwrap();
+
+    let error = stderr(&["-p", "number", "--history", path.to_str().unwrap()]);
+    assert!(error.contains("a v1 history holds unkeyed hashes"), "{}", error);
+
+    fs::remove_file(path).unwrap();
+}
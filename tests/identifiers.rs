@@ -0,0 +1,85 @@
+mod common;
+
+use common::{pass_gen, stderr, success};
+
+const CROCKFORD: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+#[test]
+fn uuids_are_version_4() {
+    let stdout = success(pass_gen(&["--uuid", "-n", "100"]));
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!(lines.len(), 100);
+
+    for uuid in lines {
+        let groups: Vec<&str> = uuid.split('-').collect();
+
+        assert_eq!(groups.iter().map(|group| group.len()).collect::<Vec<_>>(), [8, 4, 4, 4, 12], "{}", uuid);
+        assert!(groups.concat().chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')), "{}", uuid);
+        assert!(groups[2].starts_with('4'), "{}", uuid);
+        assert!(groups[3].starts_with(['8', '9', 'a', 'b']), "{}", uuid);
+    }
+}
+
+#[test]
+fn uuids_follow_the_seed() {
+    let first = success(pass_gen(&["--uuid", "-n", "3", "--seed", "7"]));
+
+    assert_eq!(first, success(pass_gen(&["--uuid", "-n", "3", "--seed", "7"])));
+    assert_ne!(first, success(pass_gen(&["--uuid", "-n", "3", "--seed", "8"])));
+}
+
+#[test]
+fn ulids_sort_in_order() {
+    let stdout = success(pass_gen(&["--ulid", "-n", "1000"]));
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!(lines.len(), 1000);
+
+    for ulid in &lines {
+        assert_eq!(ulid.len(), 26, "{}", ulid);
+        assert!(ulid.chars().all(|c| CROCKFORD.contains(c)), "{}", ulid);
+        assert!(ulid.as_bytes()[0] <= b'7', "{}", ulid);
+    }
+
+    // many share a millisecond, they still come out strictly ascending
+    assert!(lines.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn report_counts_random_bits() {
+    let report = |flag| String::from_utf8(pass_gen(&[flag, "-r"]).stderr).unwrap();
+
+    assert!(report("--uuid").contains("total entropy:              122 bits"));
+    assert!(report("--ulid").contains("total entropy:              80 bits"));
+}
+
+#[test]
+fn verbose_names_the_identifier() {
+    let output = pass_gen(&["--ulid", "-v"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(stderr.contains("source:       random ulid"), "{}", stderr);
+    assert!(stderr.contains("80 random bits after a 48 bit millisecond timestamp"), "{}", stderr);
+    assert!(!stderr.contains("separator:"), "{}", stderr);
+}
+
+#[test]
+fn fixed_strength() {
+    let output = pass_gen(&["--uuid", "--min-entropy", "128"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("a uuid has 122 random bits"));
+    assert!(pass_gen(&["--ulid", "--min-entropy", "80"]).status.success());
+}
+
+#[test]
+fn conflicts() {
+    assert!(stderr(&["--uuid", "--ulid"]).contains("--uuid conflicts with --ulid"));
+
+    for flag in [&["-p", "ascii"][..], &["-c", "5"], &["--json"], &["--unique"], &["--leet"]] {
+        let args: Vec<&str> = ["--uuid"].iter().chain(flag).copied().collect();
+
+        assert!(stderr(&args).contains("--uuid conflicts with"), "{:?}", flag);
+    }
+}
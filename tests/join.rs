@@ -0,0 +1,45 @@
+mod common;
+
+use common::{stderr, stdout};
+
+#[test]
+fn tokens_in_their_places() {
+    let plain = stdout(&["--seed", "1", "-c", "4"]);
+    let tokens: Vec<&str> = plain.split(' ').collect();
+
+    // the placeholders choose the count
+    let joined = stdout(&["--seed", "1", "--join", "{1}-{2}.{3}_{4}"]);
+    assert_eq!(joined, format!("{}-{}.{}_{}", tokens[0], tokens[1], tokens[2], tokens[3]));
+
+    let swapped = stdout(&["--seed", "1", "--join", "{{{4}}}:{3}:{2}:{1}"]);
+    assert_eq!(swapped, format!("{{{}}}:{}:{}:{}", tokens[3], tokens[2], tokens[1], tokens[0]));
+
+    // the rest keeps the usual count and separator
+    let plain = stdout(&["--seed", "1"]);
+    let tokens: Vec<&str> = plain.split(' ').collect();
+
+    let rest = format!("{}/{} {}", tokens[1], tokens[0], tokens[2..].join(" "));
+    assert_eq!(stdout(&["--seed", "1", "--join", "{2}/{*}"]), rest);
+}
+
+#[test]
+fn report_counts_the_drawn_tokens() {
+    let kv = |args: &[&str]| stdout(&[args, &["--report-only", "--report-format", "kv"]].concat());
+    let bits = |report: String| report.lines().find(|line| line.starts_with("total_bits=")).unwrap().to_string();
+
+    assert_eq!(bits(kv(&["--join", "x{1}y{2}z"])), bits(kv(&["-c", "2"])));
+    assert_eq!(bits(kv(&["--join", "{1}+{*}", "-c", "5"])), bits(kv(&["-c", "5"])));
+}
+
+#[test]
+fn mismatches_point_at_the_placeholder() {
+    let beyond = stderr(&["--join", "{1}-{5}", "-c", "3"]);
+    let msg = "placeholder {5} at column 5 of join template \"{1}-{5}\" is beyond the 3 token(s)";
+    assert!(beyond.contains(msg), "{}", beyond);
+
+    assert!(stderr(&["--join", "{1}-{2}", "-c", "3"]).contains("token 3 has no placeholder"));
+    assert!(stderr(&["--join", "{1}-{1}"]).contains("at column 5: {1} is already placed at column 1"));
+    assert!(stderr(&["--join", ""]).contains("join template is empty"));
+    assert!(stderr(&["--join", "{1", "-c", "1"]).contains("column 1: unclosed placeholder"));
+    assert!(stderr(&["--join", "{1}", "--sep-set", "-."]).contains("--join conflicts with"));
+}
@@ -0,0 +1,37 @@
+mod common;
+
+use common::{stderr, stdout};
+
+#[test]
+fn matches_fixture() {
+    // tokens with quotes and commas, joined by a quote
+    let args = [
+        "-f", "tests/fixtures/keepass-words.txt", "--whitespace", "keep", "-c", "3", "-s", "\"", "-n", "3",
+        "--seed", "4", "--keepass-csv", "--title-template", "svc-{i}", "--username-template", "deploy,{i}",
+        "--keepass-group", "Servers/\"Prod\"",
+    ];
+
+    let expected = include_str!("fixtures/keepass.csv");
+    assert_eq!(stdout(&args), expected);
+
+    // the same rows without the header
+    let rows = stdout(&[&args[..], &["--no-header"]].concat());
+    assert_eq!(rows, expected.split_once('\n').unwrap().1);
+
+    // titles are numbered by default, the group is the root
+    let output = stdout(&["--keepass-csv", "-n", "2"]);
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines[0], "Group,Title,Username,Password,URL,Notes");
+    assert!(lines[1].starts_with("Root,password 1,,") && lines[2].starts_with("Root,password 2,,"), "{}", output);
+    let notes = "\"generated by pass-gen from preset word, 6 tokens separated by \"\" \"\", 88.2 bits of entropy\"";
+    assert!(lines[1].ends_with(&format!(",,{}", notes)), "{}", output);
+}
+
+#[test]
+fn conflicts() {
+    assert!(stderr(&["--keepass-csv", "--csv"]).contains("--csv, --json and --keepass-csv are mutually exclusive"));
+    assert!(stderr(&["--json", "--keepass-csv"]).contains("--csv, --json and --keepass-csv are mutually exclusive"));
+    assert!(stderr(&["--title-template", "svc-{i}"]).contains("require --keepass-csv"));
+    assert!(stderr(&["--csv", "--keepass-group", "Prod"]).contains("require --keepass-csv"));
+}
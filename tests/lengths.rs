@@ -0,0 +1,52 @@
+mod common;
+
+use pass_gen::grapheme_len;
+use std::fs;
+
+// tokens of one, two and five code points per character
+const TOKENS: &str = "abc\ncafe\u{301}\n\u{1F44D}\u{1F3FD}\u{1F1E9}\u{1F1EA}\n\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\n";
+
+fn pass_gen(args: &[&str]) -> Vec<String> {
+    let path = std::env::temp_dir().join(format!("pass-gen-lengths-{}-{}.txt", std::process::id(), args.join("")));
+    fs::write(&path, TOKENS).unwrap();
+
+    let output =
+        common::command().args(["-f", path.to_str().unwrap(), "-s", "-"]).args(args).output().expect("pass-gen runs");
+
+    fs::remove_file(path).unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap().lines().map(String::from).collect()
+}
+
+#[test]
+fn fitted_length() {
+    let generated = pass_gen(&["--length", "12", "-n", "200"]);
+
+    assert!(generated.iter().all(|password| grapheme_len(password) == 12));
+
+    // emoji and accents take more code points than characters
+    assert!(generated.iter().any(|password| password.chars().count() > 12));
+}
+
+#[test]
+fn max_length() {
+    let generated = pass_gen(&["-c", "3", "--max-length", "9", "-n", "200"]);
+
+    assert!(generated.iter().all(|password| grapheme_len(password) <= 9));
+
+    // "abc-abc-abc" is too long, three emoji with separators are not
+    assert!(!generated.iter().any(|password| password == "abc-abc-abc"));
+    assert!(generated.iter().any(|password| password.chars().count() > 9));
+}
+
+#[test]
+fn stats_and_json() {
+    let output =
+        common::command().args(["--charset", "a-z", "-c", "8", "--json", "--stats"]).output().expect("pass-gen runs");
+
+    let json = String::from_utf8(output.stdout).unwrap();
+
+    // plain ascii counts the same either way
+    assert!(json.contains("\"length\": 8") && json.contains("\"characters\": 8"), "{}", json);
+}
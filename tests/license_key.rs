@@ -0,0 +1,132 @@
+mod common;
+
+use common::{pass_gen, stderr};
+
+const ALPHABET: &str = "23456789ABCDEFGHJKLMNPQRSTUVWXYZ";
+
+fn keys(args: &[&str]) -> Vec<String> {
+    let args: Vec<&str> = ["--license-key"].iter().chain(args).copied().collect();
+    let output = pass_gen(&args);
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap().lines().map(str::to_string).collect()
+}
+
+fn verify(args: &[&str]) -> (bool, String) {
+    let output = pass_gen(&[&["verify-key"], args].concat());
+
+    (output.status.success(), String::from_utf8(output.stdout).unwrap())
+}
+
+#[test]
+fn four_groups_of_four() {
+    for key in keys(&["-n", "20"]) {
+        let groups: Vec<&str> = key.split('-').collect();
+
+        assert_eq!(groups.iter().map(|group| group.len()).collect::<Vec<_>>(), [4, 4, 4, 4], "{}", key);
+        assert!(groups.concat().chars().all(|c| ALPHABET.contains(c)), "{}", key);
+    }
+}
+
+#[test]
+fn shape_by_flags() {
+    for key in keys(&["--group", "5", "--key-groups", "3", "-n", "5"]) {
+        assert_eq!(key.split('-').map(str::len).collect::<Vec<_>>(), [5, 5, 5], "{}", key);
+
+        let (valid, stdout) = verify(&["--group", "5", "--key-groups", "3", &key]);
+        assert!(valid, "{}: {}", key, stdout);
+
+        let (valid, stdout) = verify(&["--key-groups", "4", &key]);
+        assert!(!valid && stdout.contains("expected 4 of 5"), "{}", stdout);
+    }
+}
+
+#[test]
+fn round_trip() {
+    for key in keys(&["-n", "10"]) {
+        let (valid, stdout) = verify(&[&key]);
+
+        assert!(valid, "{}: {}", key, stdout);
+        assert_eq!(stdout, "key ok: 4 groups of 4 characters\n");
+
+        // pasted in lower case
+        assert!(verify(&[&key.to_lowercase()]).0);
+    }
+}
+
+#[test]
+fn transposed_pairs_are_caught() {
+    let mut caught = 0;
+
+    for key in keys(&["-n", "3", "--seed", "11"]) {
+        let chars: Vec<char> = key.chars().collect();
+
+        // every pair of neighbours, also across a dash
+        for idx in 0..chars.len() - 1 {
+            let (a, b) = match (chars[idx], chars[idx + 1]) {
+                ('-', _) => continue,
+                (_, '-') => (idx, idx + 2),
+                _ => (idx, idx + 1),
+            };
+
+            // the one swap luhn mod n cannot see
+            if chars[a] == chars[b] || matches!((chars[a], chars[b]), ('2', 'Z') | ('Z', '2')) {
+                continue;
+            }
+
+            let mut swapped = chars.clone();
+            swapped.swap(a, b);
+
+            let swapped: String = swapped.into_iter().collect();
+            let (valid, stdout) = verify(&[&swapped]);
+
+            assert!(!valid, "{} from {} passed", swapped, key);
+            assert!(stdout.contains("check character does not match"), "{}", stdout);
+            caught += 1;
+        }
+    }
+
+    assert!(caught > 30);
+}
+
+#[test]
+fn typos_are_caught() {
+    let key = keys(&["--seed", "4"]).remove(0);
+
+    for idx in (0..key.len()).filter(|&idx| key.as_bytes()[idx] != b'-') {
+        let mut typo = key.clone().into_bytes();
+        typo[idx] = if typo[idx] == b'A' { b'B' } else { b'A' };
+
+        let typo = String::from_utf8(typo).unwrap();
+        assert!(!verify(&[&typo]).0, "{} passed", typo);
+    }
+
+    // characters left out of the alphabet and broken groups
+    let (valid, stdout) = verify(&["ABCD-2345-6789-ABC0"]);
+    assert!(!valid && stdout.contains("'0'"), "{}", stdout);
+
+    let (valid, stdout) = verify(&["ABCD-2345-678-ABCM"]);
+    assert!(!valid && stdout.contains("group 3"), "{}", stdout);
+
+    let (valid, stdout) = verify(&["--quiet", "ABCD-2345-6789-ABCD"]);
+    assert!(!valid && stdout.is_empty());
+}
+
+#[test]
+fn report_excludes_the_check_character() {
+    let output = pass_gen(&["--license-key", "-r"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    // 15 random characters of 5 bits
+    assert!(stderr.contains("total entropy:              75 bits"), "{}", stderr);
+    assert!(stderr.contains("check character:          0.0 bits"), "{}", stderr);
+}
+
+#[test]
+fn usage_errors() {
+    assert!(stderr(&["--key-groups", "3"]).contains("requires --license-key"));
+    assert!(stderr(&["--license-key", "--uuid"]).contains("--license-key conflicts with --uuid"));
+    assert!(stderr(&["--license-key", "-c", "4"]).contains("--license-key conflicts with"));
+    assert!(stderr(&["--license-key", "--group", "1", "--key-groups", "1"]).contains("at least 2 characters"));
+    assert!(stderr(&["verify-key"]).contains("needs the key"));
+}
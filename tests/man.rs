@@ -0,0 +1,81 @@
+mod common;
+
+use common::{pass_gen, stdout};
+use std::{collections::BTreeSet, fs, process::Stdio};
+
+// long flags of the OPTIONS section, with whether they take an argument
+fn man_options(man: &str) -> Vec<(String, bool)> {
+    let section = man.split(".SH OPTIONS\n").nth(1).unwrap().split("\n.SH ").next().unwrap();
+
+    section
+        .split(".TP\n")
+        .skip(1)
+        .map(|item| {
+            let head = item.lines().next().unwrap().replace("\\-", "-");
+            let long = head.split("\\fB").filter_map(|part| part.split("\\fR").next()).find(|f| f.starts_with("--"));
+
+            (long.unwrap().to_string(), head.contains("\\fI<"))
+        })
+        .collect()
+}
+
+#[test]
+fn sections() {
+    let man = stdout(&["--generate-man"]);
+
+    assert!(man.starts_with("'\\\" t\n.TH PASS-GEN 1 "));
+
+    for section in ["NAME", "SYNOPSIS", "OPTIONS", "COMMANDS", "PRESETS", "CONFIGURATION", "ENVIRONMENT", "EXIT STATUS"] {
+        assert!(man.contains(&format!("\n.SH {}\n", section)), "{}", section);
+    }
+
+    // every preset with its pool size, every config key
+    assert!(man.contains("\nword\t") && man.contains("\nascii\t93\t") && man.contains("\nnumber\t10\t"));
+    assert!(man.contains("\\fBcount\\fR") && man.contains("\\fBsep\\fR"));
+    assert!(man.contains("\\fB3\\fR\nthe entropy is below \\-\\-min\\-entropy"));
+}
+
+#[test]
+fn every_flag() {
+    let man = man_options(&stdout(&["--generate-man"]));
+    let help = stdout(&["--help"]);
+
+    // --help lists the same flags
+    let man_flags: BTreeSet<&str> = man.iter().map(|(long, _)| long.as_str()).collect();
+    let help_flags: BTreeSet<&str> = help
+        .split("\nCommands:")
+        .next()
+        .unwrap()
+        .lines()
+        .filter(|line| line.trim_start().starts_with('-') && line.len() - line.trim_start().len() < 8)
+        .filter_map(|line| line.split_whitespace().find(|word| word.starts_with("--")))
+        .map(|long| long.split('[').next().unwrap())
+        .collect();
+
+    assert_eq!(man_flags, help_flags);
+    assert!(man_flags.len() > 50);
+
+    // the parser rejects anything outside the table, so a documented flag it rejects is the only way to diverge
+    let dir = std::env::temp_dir().join(format!("pass-gen-man-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    for (long, takes_arg) in &man {
+        let args: &[&str] = if *takes_arg { &[long, "1"] } else { &[long] };
+        let output = common::command()
+            .args(args)
+            .args(["--no-config", "--kdf-memory", "8"])
+            .current_dir(&dir)
+            .stdin(Stdio::null())
+            .output()
+            .unwrap();
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(!stderr.contains("invalid option") && !stderr.contains("panicked"), "{}: {}", long, stderr);
+    }
+
+    fs::remove_dir_all(dir).unwrap();
+
+    let output = pass_gen(&["--not-a-flag"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid option \"--not-a-flag\""));
+}
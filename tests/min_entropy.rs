@@ -0,0 +1,20 @@
+mod common;
+
+use common::{pass_gen, stdout};
+
+#[test]
+fn weak_configuration_exits_with_3() {
+    // 4 words of the default list are 58.8 bits
+    let output = pass_gen(&["-c", "4", "--min-entropy", "60", "-n", "5"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!(output.status.code(), Some(3), "{}", stderr);
+    assert!(output.stdout.is_empty(), "{}", String::from_utf8_lossy(&output.stdout));
+    assert!(stderr.contains("total entropy of 58.8 bits is below the minimum of 60 bits"), "{}", stderr);
+    assert!(stderr.contains("1 more token(s) would be needed"), "{}", stderr);
+}
+
+#[test]
+fn strong_enough_configuration_generates() {
+    assert_eq!(stdout(&["-c", "5", "--min-entropy", "60", "-n", "5"]).lines().count(), 5);
+}
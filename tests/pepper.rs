@@ -0,0 +1,79 @@
+#![cfg(unix)]
+
+mod common;
+
+use common::success;
+use std::{
+    fs::{self, File},
+    os::unix::{io::AsRawFd, process::CommandExt},
+    process::{Output, Stdio},
+};
+
+// runs with stdin closed and the pepper on descriptor fd
+fn pass_gen(args: &[&str], pepper: Option<(&str, i32)>) -> Output {
+    let mut command = common::command();
+    command.args(args).stdin(Stdio::null());
+
+    if let Some((pepper, fd)) = pepper {
+        let path = std::env::temp_dir().join(format!("pass-gen-pepper-{}-{}", std::process::id(), fd));
+        fs::write(&path, format!("{}\n", pepper)).unwrap();
+
+        let file = File::open(&path).unwrap();
+        fs::remove_file(path).unwrap();
+
+        // SAFETY: dup2 and fcntl are async-signal-safe, the file outlives the spawn,
+        // close-on-exec is cleared in case the file already is on fd
+        let raw = file.as_raw_fd();
+        unsafe {
+            command.pre_exec(move || match libc::dup2(raw, fd) == -1 || libc::fcntl(fd, libc::F_SETFD, 0) == -1 {
+                true => Err(std::io::Error::last_os_error()),
+                false => Ok(()),
+            });
+        }
+
+        return command.output().expect("pass-gen runs");
+    }
+
+    command.output().expect("pass-gen runs")
+}
+
+#[test]
+fn appended() {
+    let plain = success(pass_gen(&["--seed", "1", "-s", "-", "--stats"], None));
+
+    // after the separator, on descriptor 3 by default
+    let peppered = pass_gen(&["--seed", "1", "-s", "-", "--stats", "-r", "-v", "--pepper"], Some(("s3cr3t", 3)));
+    assert_eq!(success(peppered.clone()), format!("{}-s3cr3t", plain));
+
+    // the stats, report and verbose output never see it
+    let stderr = String::from_utf8(peppered.stderr).unwrap();
+    assert!(!stderr.contains("s3cr3t"), "{}", stderr);
+    assert!(stderr.contains(&format!("characters:   {}\n", plain.len())), "{}", stderr);
+    assert!(stderr.contains("+ user pepper (uncounted)"), "{}", stderr);
+
+    // every password of a batch and every output, here a file
+    let path = std::env::temp_dir().join(format!("pass-gen-pepper-out-{}", std::process::id()));
+    let output = pass_gen(&["-n", "5", "-o", path.to_str().unwrap(), "--pepper-fd", "7"], Some(("it's", 7)));
+
+    success(output);
+    let written = fs::read_to_string(&path).unwrap();
+    fs::remove_file(path).unwrap();
+
+    assert_eq!(written.lines().count(), 5);
+    assert!(written.lines().all(|line| line.ends_with(" it's")), "{}", written);
+}
+
+#[test]
+fn rejects() {
+    let fails = |args: &[&str], pepper: Option<(&str, i32)>, msg: &str| {
+        let output = pass_gen(args, pepper);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(!output.status.success() && stderr.contains(msg), "{}", stderr);
+    };
+
+    fails(&["--pepper"], None, "descriptor 3 is not open");
+    fails(&["--pepper"], Some(("", 3)), "the pepper is empty");
+    fails(&["--pepper", "--json"], Some(("x", 3)), "--pepper conflicts with --csv, --json and --split");
+    fails(&["--batch", "--pepper"], None, "--pepper is not available in batch requests");
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use common::{stderr, stdout};
+
+// the report is the only output, there is nothing to generate from
+fn report(args: &[&str]) -> String {
+    stdout(args)
+}
+
+#[test]
+fn hypothetical_pool() {
+    // 20 characters of a 72 symbol alphabet
+    let human = report(&["--pool-size", "72", "-c", "20"]);
+    assert!(human.contains("total entropy:              123 bits"), "{}", human);
+    assert!(!human.contains("length:"), "{}", human);
+
+    let json = report(&["--pool-size", "72", "-c", "20", "-r", "--report-format", "json"]);
+    assert!(json.starts_with("{\"pool_size\": 72, \"filters\": [], \"length\": null,"), "{}", json);
+    assert!(json.contains("\"token_count\": 20,") && !json.contains("memorability"), "{}", json);
+
+    // the count can come from a target like for real pools
+    let kv = report(&["--pool-size", "7776", "--bits", "128", "--report-format", "kv"]);
+    assert!(kv.contains("token_count=10\n") && !kv.contains("length_min"), "{}", kv);
+
+    // pools that small are asked for, not a broken token file
+    assert!(!report(&["--pool-size", "10", "-c", "4"]).contains("warning"));
+}
+
+#[test]
+fn conflicts() {
+    let error = stderr(&["--pool-size", "72", "-c", "20", "-p", "ascii"]);
+    assert!(error.contains("--pool-size conflicts with --preset, --file"), "{}", error);
+
+    let error = stderr(&["--pool-size", "72", "-c", "20", "-f", "words.txt"]);
+    assert!(error.contains("--pool-size conflicts with --preset, --file"), "{}", error);
+
+    assert!(stderr(&["--pool-size", "72"]).contains("--pool-size needs --count, --bits or --crack-time"));
+    assert!(stderr(&["--pool-size", "72", "-c", "3", "--bits", "64"]).contains("--bits conflicts with --count"));
+    assert!(stderr(&["--pool-size", "72", "-c", "3", "--template", "wdd"]).contains("--pool-size only goes with"));
+    assert!(stderr(&["--pool-size", "72", "-c", "3", "--json"]).contains("--report-only cannot be combined"));
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use common::{pass_gen, stdout};
+
+// the bitcoin base58 alphabet and the z-base-32 alphabet of Zooko O'Whielacronx, in their published order
+const BASE58: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const ZBASE32: &str = "ybndrfg8ejkmcpqxot1uwisza345h769";
+
+#[test]
+fn alphabets_match_the_specifications() {
+    // words lists the pool in order, one token per line
+    assert_eq!(stdout(&["words", "-p", "base58"]).lines().collect::<String>(), BASE58);
+    assert_eq!(stdout(&["words", "-p", "zbase32"]).lines().collect::<String>(), ZBASE32);
+
+    let man = stdout(&["--generate-man"]);
+
+    assert!(man.contains("\nbase58\t58\t5.9\t22\t\"\"\n"), "{}", man);
+    assert!(man.contains("\nzbase32\t32\t5.0\t26\t\"\"\n"), "{}", man);
+}
+
+#[test]
+fn about_128_bits_without_separators() {
+    for (preset, length) in [("base58", 22), ("zbase32", 26)] {
+        for password in stdout(&["-p", preset, "-n", "20"]).lines() {
+            assert_eq!(password.chars().count(), length, "{}", password);
+        }
+
+        let report = String::from_utf8(pass_gen(&["-p", preset, "-r"]).stderr).unwrap();
+        let total: f64 = report
+            .lines()
+            .find_map(|line| line.strip_prefix("total entropy:"))
+            .and_then(|rest| rest.split_whitespace().next())
+            .and_then(|bits| bits.parse().ok())
+            .unwrap();
+
+        assert!((128.0..=131.0).contains(&total), "{} has {} bits", preset, total);
+    }
+}
+
+#[test]
+fn ambiguous_characters_never_appear() {
+    let base58 = stdout(&["-p", "base58", "-n", "500"]);
+    let zbase32 = stdout(&["-p", "zbase32", "-n", "500"]);
+
+    for (output, alphabet, excluded) in [(&base58, BASE58, "0OIl"), (&zbase32, ZBASE32, "02lv")] {
+        for c in output.chars().filter(|&c| c != '\n') {
+            assert!(alphabet.contains(c) && !excluded.contains(c), "{:?}", c);
+        }
+    }
+
+    // z-base-32 is lower case only
+    assert!(!zbase32.chars().any(|c| c.is_ascii_uppercase()));
+
+    // a large sample covers every character of the alphabet
+    for (output, alphabet) in [(&base58, BASE58), (&zbase32, ZBASE32)] {
+        assert!(alphabet.chars().all(|c| output.contains(c)));
+    }
+}
+
+#[test]
+fn listed_with_the_other_presets() {
+    let help = stdout(&["--help"]);
+    assert!(help.contains("word, ascii, number, base58, zbase32"));
+
+    let output = pass_gen(&["-p", "base64"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("base58, zbase32"));
+}
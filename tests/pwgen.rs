@@ -0,0 +1,62 @@
+mod common;
+
+use common::{pass_gen, success};
+use std::process::Output;
+
+// stdout is a pipe here, so pwgen's non-terminal defaults apply
+fn pwgen(args: &[&str]) -> Output {
+    pass_gen(&[&["--compat", "pwgen"], args].concat())
+}
+
+fn passwords(args: &[&str]) -> Vec<String> {
+    success(pwgen(args)).lines().map(String::from).collect()
+}
+
+#[test]
+fn length_and_count() {
+    let generated = passwords(&["-s", "16", "5"]);
+
+    assert_eq!(generated.len(), 5);
+
+    for password in &generated {
+        assert_eq!(password.chars().count(), 16);
+        assert!(password.chars().all(|c| c.is_ascii_alphanumeric()));
+
+        // capitals and numerals are included and required by default
+        assert!(password.chars().any(|c| c.is_ascii_uppercase()));
+        assert!(password.chars().any(|c| c.is_ascii_digit()));
+    }
+
+    assert_eq!(passwords(&["-s"]).len(), 1);
+    assert_eq!(passwords(&["-s"])[0].len(), 8);
+    assert_eq!(passwords(&["-s1", "-N", "3", "12"]).len(), 3);
+    assert_eq!(passwords(&["-sN4"]).len(), 4);
+}
+
+#[test]
+fn charsets() {
+    for password in passwords(&["-sy", "12", "50"]) {
+        assert!(password.chars().all(|c| c.is_ascii_graphic()));
+        assert!(password.chars().any(|c| c.is_ascii_punctuation()));
+    }
+
+    for password in passwords(&["-s", "-A", "-0", "20", "50"]) {
+        assert!(password.chars().all(|c| c.is_ascii_lowercase()));
+    }
+
+    for password in passwords(&["-sB", "--remove-chars=xyz", "30", "50"]) {
+        assert!(!password.chars().any(|c| "B8G6I1l0OQDS5Z2xyz".contains(c)));
+    }
+}
+
+#[test]
+fn unsupported_options() {
+    for args in [&["-sv"][..], &["-s", "-H", "seed"], &["-s", "--columns"], &["16"]] {
+        let output = pwgen(args);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(!output.status.success());
+        assert!(stderr.contains("not supported in compat mode"), "{}", stderr);
+        assert!(output.stdout.is_empty());
+    }
+}
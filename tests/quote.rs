@@ -0,0 +1,73 @@
+#![cfg(unix)]
+
+mod common;
+
+use common::{stderr, stdout};
+use std::{
+    fs,
+    io::Write,
+    process::{Command, Stdio},
+};
+
+// evaluates every line of input with sh and prints what script leaves in $value
+fn eval(script: &str, input: &str) -> String {
+    let mut child = Command::new("sh")
+        .args(["-c", &format!("while IFS= read -r line; do {}; printf '%s\\n' \"$value\"; done", script)])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("sh runs");
+
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn round_trips_through_sh() {
+    let path = std::env::temp_dir().join(format!("pass-gen-quote-{}.txt", std::process::id()));
+    fs::write(&path, "it's\n\"quoted\"\n$HOME\n`id`\nback\\slash\n!bang\nünïcödé\nsemi;colon\n").unwrap();
+
+    let file = path.to_str().unwrap();
+    let pools = [&["-p", "ascii"][..], &["-f", file, "-s", "'"], &["-f", file, "-s", "$"]];
+
+    for pool in pools {
+        for seed in ["1", "2", "3"] {
+            let args = [pool, &["-n", "300", "--seed", seed]].concat();
+            let raw = stdout(&args);
+
+            for style in ["shell", "env"] {
+                let quoted = stdout(&[&args[..], &["--quote", style]].concat());
+
+                assert_ne!(quoted, raw);
+                assert_eq!(eval("eval \"value=$line\"", &format!("{}\n", quoted)), format!("{}\n", raw), "{}", style);
+            }
+
+            // a password without a single quote keeps them for .env files
+            let single = [pool, &["--seed", seed]].concat();
+            let password = stdout(&single);
+            let export = stdout(&[&single[..], &["--export", "PASS"]].concat());
+
+            assert_eq!(export.starts_with("export PASS='"), !password.contains('\''), "{}", export);
+            assert_eq!(eval("eval \"$line\"; value=$PASS", &format!("{}\n", export)), format!("{}\n", password));
+        }
+    }
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn conflicts() {
+    // the output file gets the raw password, so there is nothing to quote
+    assert!(stderr(&["--quote", "shell", "-o", "/dev/null"]).contains("only apply to plain output on stdout"));
+    assert!(stderr(&["--quote", "env", "--json"]).contains("only apply to plain output on stdout"));
+    assert!(stderr(&["--quote", "csv"]).contains("invalid quote style \"csv\""));
+    assert!(stderr(&["--quote", "none", "--export", "PASS"]).contains("--export needs --quote shell or env"));
+    assert!(stderr(&["--export", "1PASS"]).contains("invalid variable name \"1PASS\""));
+    assert!(stderr(&["--export", "PASS", "-n", "2"]).contains("conflicts with --number"));
+
+    let export = stdout(&["--charset", "ab", "-c", "1", "--quote", "shell", "--export", "A_1"]);
+    assert!(["export A_1='a'", "export A_1='b'"].contains(&export.as_str()), "{}", export);
+}
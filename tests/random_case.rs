@@ -0,0 +1,44 @@
+mod common;
+
+use common::{pass_gen, stderr, stdout};
+use std::fs;
+
+#[test]
+fn flips_token_letters() {
+    let path = std::env::temp_dir().join(format!("pass-gen-random-case-{}.txt", std::process::id()));
+    fs::write(&path, "alpha\nbravo7\ncharlie\n").unwrap();
+
+    let file = path.to_str().unwrap();
+    let args = ["-f", file, "-c", "4", "-s", "x", "-n", "200", "--random-case", "--seed", "3"];
+    let passwords = stdout(&args);
+
+    // same seed, same passwords
+    assert_eq!(passwords, stdout(&args));
+
+    for password in passwords.lines() {
+        let tokens: Vec<&str> = password.split('x').collect();
+
+        // lowercase separators stay, so the tokens still split apart
+        assert_eq!(tokens.len(), 4, "{}", password);
+        assert!(tokens.iter().all(|token| ["alpha", "bravo7", "charlie"].contains(&token.to_lowercase().as_str())));
+    }
+
+    assert!(passwords.contains(char::is_uppercase) && passwords.contains(char::is_lowercase));
+
+    // one bit per letter of the average token
+    let report = String::from_utf8(pass_gen(&["-f", file, "-c", "4", "--random-case", "-r"]).stderr).unwrap();
+    assert!(report.contains("random case:              22.7 bits"), "{}", report);
+
+    let report = String::from_utf8(pass_gen(&["-f", file, "-c", "4", "--random-case=0.1", "-r"]).stderr).unwrap();
+    assert!(report.contains("random case:              10.6 bits"), "{}", report);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn conflicts() {
+    assert!(stderr(&["--random-case", "--leet"]).contains("--random-case conflicts with"));
+    assert!(stderr(&["--random-case", "--template", "Wwdd"]).contains("--random-case conflicts with"));
+    assert!(stderr(&["--random-case=0", "-c", "3"]).contains("random case probability must be above 0"));
+    assert!(stderr(&["--random-case", "--checksum", "-s", "X"]).contains("'X' is one"));
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use common::{pass_gen, stdout, success};
+use std::{collections::HashSet, fs};
+
+const ALPHABET: &str = "23456789abcdefghjkmnpqrstuvwxyz";
+
+#[test]
+fn ten_numbered_codes() {
+    let stdout = success(pass_gen(&["--recovery-codes"]));
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!(lines.len(), 10);
+
+    let mut codes = HashSet::new();
+
+    for (idx, line) in lines.iter().enumerate() {
+        let (number, code) = line.split_once(". ").unwrap();
+        let groups: Vec<&str> = code.split('-').collect();
+
+        assert_eq!(number.trim_start(), (idx + 1).to_string());
+        assert!(groups.len() == 2 && groups.iter().all(|group| group.len() == 4), "{}", line);
+        assert!(groups.concat().chars().all(|c| ALPHABET.contains(c)), "{}", line);
+        assert!(codes.insert(code.to_string()), "{} repeated", code);
+    }
+
+    // the numbers line up
+    assert!(lines[0].starts_with(" 1. ") && lines[9].starts_with("10. "));
+}
+
+#[test]
+fn grouped_by_flags() {
+    let stdout = success(pass_gen(&["--recovery-codes=3", "-c", "10", "--group", "3", "-s", " "]));
+
+    for line in stdout.lines() {
+        let (_, code) = line.split_once(". ").unwrap();
+        let groups: Vec<usize> = code.split(' ').map(str::len).collect();
+
+        assert_eq!(groups, [3, 3, 3, 1], "{}", line);
+    }
+}
+
+#[test]
+fn unique_within_the_batch() {
+    // every code of a single character is drawn once
+    let stdout = success(pass_gen(&["--recovery-codes=31", "-c", "1", "--seed", "1"]));
+    let codes: HashSet<&str> = stdout.lines().map(|line| line.split_once(". ").unwrap().1).collect();
+
+    assert_eq!(codes.len(), 31);
+
+    let output = pass_gen(&["--recovery-codes=32", "-c", "1"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unique"));
+}
+
+#[test]
+fn json_array_in_a_file() {
+    let path = std::env::temp_dir().join(format!("pass-gen-recovery-{}.json", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let plain = success(pass_gen(&["--recovery-codes=4", "--seed", "2"]));
+    let output = pass_gen(&["--recovery-codes=4", "--seed", "2", "--json", "-o", path.to_str().unwrap()]);
+
+    assert!(success(output).is_empty());
+
+    let json = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let codes: Vec<String> = plain.lines().map(|line| format!("  \"{}\"", line.split_once(". ").unwrap().1)).collect();
+    assert_eq!(json, format!("{{\"recovery_codes\": [\n{}\n]}}\n", codes.join(",\n")));
+}
+
+#[test]
+fn report_gives_the_odds_of_any_code() {
+    let report = success(pass_gen(&["--recovery-codes", "--report-only"]));
+
+    assert!(report.contains("recovery codes:             10 valid, 39.6 bits each"), "{}", report);
+    assert!(report.contains("  in 10 attempts:           1 in 8.5 billion"), "{}", report);
+
+    // twice the codes are twice as likely to be hit
+    let kv = success(pass_gen(&["--recovery-codes=20", "--report-only", "--report-format", "kv"]));
+    let odds = kv.lines().find_map(|line| line.strip_prefix("recovery_guessed_1e3=")).unwrap();
+
+    assert!((odds.parse::<f64>().unwrap() / 2.3449654e-8 - 1.0).abs() < 1e-6, "{}", odds);
+}
+
+#[test]
+fn conflicting_flags() {
+    let error = |args: &[&str]| String::from_utf8_lossy(&pass_gen(args).stderr).to_string();
+
+    assert!(error(&["--recovery-codes", "-p", "word"]).contains("--recovery-codes conflicts with --preset"));
+    assert!(error(&["--recovery-codes", "-n", "3"]).contains("conflicts with --number"));
+    assert!(error(&["--recovery-codes=0"]).contains("expected positive number"));
+    assert!(error(&["--group", "3"]).contains("--group requires --recovery-codes"));
+}
+
+#[test]
+fn crack_times_are_for_any_code() {
+    let kv = |args: &[&str]| {
+        let args = [args, &["--seed", "1", "--report-only", "--report-format", "kv"]].concat();
+        let kv = success(pass_gen(&args));
+        let value = |key: &str| -> f64 {
+            let prefix = format!("{}=", key);
+            kv.lines().find_map(|line| line.strip_prefix(&prefix)).unwrap().parse().unwrap()
+        };
+
+        (value("total_bits"), value("average_time_1e9"), kv.contains("recovery_bits="))
+    };
+
+    // ten codes take log2(10) bits off the same characters as a single password
+    let (total, single, _) = kv(&["--charset", "23456789abcdefghjkmnpqrstuvwxyz", "-c", "8"]);
+    let (codes_total, codes, bits) = kv(&["--recovery-codes=10"]);
+
+    assert!(bits && (total - codes_total).abs() < 1e-9);
+    assert!((single / codes - 10.0).abs() < 1e-9, "{} {}", single, codes);
+
+    let report = success(pass_gen(&["--recovery-codes=10", "--report-only"]));
+
+    assert!(report.contains("10 valid, 39.6 bits each, 36.3 to guess any one"), "{}", report);
+    assert!(report.contains("  1 billion / second:       43 seconds\n"), "{}", report);
+}
+
+#[test]
+fn help_describes_the_codes() {
+    let help = stdout(&["--help"]).split_whitespace().collect::<Vec<_>>().join(" ");
+    let code = success(pass_gen(&["--recovery-codes=1", "-s", ""]));
+    let code = code.trim().split_once(". ").unwrap().1;
+
+    // the length, characters and grouping the help promises
+    assert!(help.contains(&format!("recovery codes of {} characters from {},", code.len(), ALPHABET)), "{}", help);
+    assert!(help.contains("or of a --license-key (default: 4)"), "{}", help);
+
+    let code = success(pass_gen(&["--recovery-codes=1"]));
+    assert!(code.trim().split_once(". ").unwrap().1.split('-').all(|group| group.len() == 4), "{}", code);
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use common::success;
+use pass_gen::{EntropyReport, Pool, ReportFormat, Targets, Terminal};
+
+const TERMINAL: Terminal = Terminal { width: 72, color: false, unicode: false };
+
+const HUMAN: &str = "\
+pool filters:               too long (-24), confusable (-3)
+length:                     23–62 chars (avg 42)
+entropy per word:           12.9 bits
+total entropy:              81 bits [###############--------] target 128
+                            + user pepper (uncounted)
+  tokens:                   77.5 bits
+  separators:               3.3 bits
+equivalent to:              13 random ascii or 14 alphanumeric chars
+target entropy:             80.0 bits (6 tokens)
+average time to crack:
+  1 billion / second:       35 million years
+  1 quadrillion / second:   35 years
+  1 sextillion / second:    18 minutes 25 seconds
+worst case:
+  1 billion / second:       70 million years
+  1 quadrillion / second:   70 years 1 month
+  1 sextillion / second:    36 minutes 51 seconds
+strength:                   strong
+------------------------------------------------------------------------
+";
+
+// what the binary reports for 20 digits on a 60 column terminal
+const NUMBER: &str = "\
+warning: the token pool only has 10 tokens, check the token file and filters
+length:                     20 chars
+entropy per word:           3.3 bits
+total entropy:              66 bits [######-----] target 128
+equivalent to:              11 random ascii or 12 alphanumeric chars
+memorability:               92/100 (easy)
+average time to crack:
+  1 billion / second:       2 millennia
+  1 quadrillion / second:   13 hours 53 minutes
+  1 sextillion / second:    less than a second
+worst case:
+  1 billion / second:       3 millennia
+  1 quadrillion / second:   1 day 4 hours
+  1 sextillion / second:    less than a second
+strength:                   strong
+------------------------------------------------------------
+";
+
+// six words of a diceware list with a random digit between two of them
+fn report() -> EntropyReport {
+    let word_bits = 7776f64.log2();
+    let pool = Pool {
+        size: 7776,
+        per_token_bits: word_bits,
+        filters: vec![("too long", 24), ("confusable", 3)],
+        length: Some((23, 41.7, 62)),
+        components: vec![("tokens", word_bits * 6.0), ("separators", 10f64.log2())],
+    };
+
+    let mut report = EntropyReport::new(pool, 6.0, Targets { bits: Some(80.0), time: None }, &[]);
+    report.pepper = true;
+    report
+}
+
+fn render(report: &EntropyReport, format: ReportFormat, term: &Terminal) -> String {
+    let mut out = Vec::new();
+
+    report.write(format, term, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn human_snapshot() {
+    assert_eq!(render(&report(), ReportFormat::Human, &TERMINAL), HUMAN);
+}
+
+#[test]
+fn binary_snapshot() {
+    let output = common::command()
+        .args(["--report-only", "-p", "number", "-c", "20"])
+        .env("COLUMNS", "60")
+        .env("LANG", "C")
+        .env_remove("LC_ALL")
+        .env_remove("LC_CTYPE")
+        .output()
+        .expect("pass-gen runs");
+
+    assert_eq!(success(output), NUMBER);
+}
+
+#[test]
+fn terminal_shapes_only_the_bar_and_rule() {
+    let term = Terminal { width: 100, color: true, unicode: true };
+    let human = render(&report(), ReportFormat::Human, &term);
+
+    assert!(human.contains("81 bits \x1b[32m[███████████████████████"), "{}", human);
+    assert!(human.ends_with(&format!("\n{}\n", "-".repeat(100))), "{}", human);
+
+    // the machine formats ignore it
+    for format in [ReportFormat::Json, ReportFormat::Kv] {
+        assert_eq!(render(&report(), format, &term), render(&report(), format, &TERMINAL));
+    }
+
+    let kv = render(&report(), ReportFormat::Kv, &term);
+    assert!(kv.contains("\ncomponent_separators=3.32192809488736"), "{}", kv);
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use common::pass_gen;
+use std::{fs, path::PathBuf};
+
+// half the tokens start with a letter and a single one has an upper case letter
+fn pool(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("pass-gen-retry-{}-{}.txt", name, std::process::id()));
+    let mut tokens: Vec<String> = (0..999).map(|i| format!("w{:03}", i)).collect();
+
+    tokens.push(String::from("Zed"));
+    tokens.extend((0..999).map(|i| format!("{:03}", i)));
+    fs::write(&path, tokens.join("\n")).unwrap();
+    path
+}
+
+#[test]
+fn gives_up_with_a_breakdown() {
+    let path = pool("breakdown");
+    let file = path.to_str().unwrap();
+
+    let output = pass_gen(&["-f", file, "--require", "upper", "--retry-limit", "5", "--seed", "1"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr.contains("rejected 5 candidates: 5 missing upper, raise --retry-limit"), "{}", stderr);
+
+    // the first check to refuse a candidate counts, largest share first
+    let args = ["-f", file, "--require", "upper", "--start-with-letter", "--retry-limit", "50", "--seed", "1"];
+    let output = pass_gen(&args);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    fs::remove_file(&path).unwrap();
+
+    assert!(stderr.contains("rejected 50 candidates: 28 missing upper, 22 not starting with a letter"), "{}", stderr);
+}
+
+#[test]
+fn higher_limit_succeeds() {
+    let path = pool("succeeds");
+    let file = path.to_str().unwrap();
+    let output = pass_gen(&["-f", file, "--require", "upper", "--retry-limit", "5000", "--seed", "1"]);
+
+    fs::remove_file(&path).unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8(output.stdout).unwrap().contains("Zed"));
+}
+
+#[test]
+fn limit_must_be_positive() {
+    let output = pass_gen(&["--retry-limit", "0"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--retry-limit"));
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use common::stdout;
+
+// the kv report of the word preset, without a config file changing it
+fn report(args: &[&str]) -> Vec<(String, f64)> {
+    let args = [&["--report-only", "--report-format", "kv", "--no-config", "--no-locale"], args].concat();
+
+    stdout(&args)
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .filter_map(|(key, value)| Some((key.to_string(), value.parse().ok()?)))
+        .collect()
+}
+
+fn value(report: &[(String, f64)], key: &str) -> f64 {
+    report.iter().find(|(found, _)| found == key).map(|(_, value)| *value).unwrap()
+}
+
+#[test]
+fn adds_log2_of_the_set_per_separator() {
+    for (set, bits) in [("!@", 1.0), ("!@#$", 2.0), ("0123456789abcdef", 4.0)] {
+        for words in [2, 5, 8] {
+            let count = words.to_string();
+            let plain = report(&["-c", &count]);
+            let with_set = report(&["-c", &count, "--sep-set", set]);
+
+            let added = value(&with_set, "total_bits") - value(&plain, "total_bits");
+            let expected = bits * (words - 1) as f64;
+
+            assert!((added - expected).abs() < 1e-9, "{} on {} words added {} bits", set, words, added);
+            assert_eq!(value(&with_set, "component_separators"), expected);
+        }
+    }
+}
+
+#[test]
+fn five_words_with_four_separators() {
+    // 4 separators of 2 bits each
+    let plain = report(&["-c", "5"]);
+    let with_set = report(&["-c", "5", "--sep-set", "!@#$"]);
+
+    assert_eq!(value(&with_set, "total_bits") - value(&plain, "total_bits"), 8.0);
+}
+
+#[test]
+fn repeated_characters_count_once() {
+    assert_eq!(value(&report(&["-c", "5", "--sep-set", "!!@@"]), "component_separators"), 4.0);
+}
+
+#[test]
+fn separators_come_from_the_set() {
+    for password in stdout(&["-c", "5", "--sep-set", "!@#$", "-n", "50", "--no-locale"]).lines() {
+        // no word of the list has any of them
+        let words: Vec<&str> = password.split(['!', '@', '#', '$']).collect();
+
+        assert_eq!(words.len(), 5, "{}", password);
+        assert!(words.iter().all(|word| !word.is_empty()), "{}", password);
+    }
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use common::{pass_gen, stderr, stdout};
+use std::fs;
+
+const PREDICTABLE: &str =
+    "pass-gen: token pool has 1 tokens, generated passwords would be predictable; check the token file and filters\n";
+
+#[test]
+fn one_token_is_an_error() {
+    assert_eq!(stderr(&["--charset", "a", "-c", "8"]), PREDICTABLE);
+
+    // also when filters leave only one
+    assert_eq!(stderr(&["--charset", "ab", "--exclude-chars", "b", "-c", "8"]), PREDICTABLE);
+
+    let path = std::env::temp_dir().join(format!("pass-gen-small-pool-{}.txt", std::process::id()));
+    fs::write(&path, "same\nsame\nsame\n").unwrap();
+
+    let output = pass_gen(&["-f", path.to_str().unwrap()]);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert_eq!(String::from_utf8(output.stderr).unwrap(), PREDICTABLE);
+}
+
+#[test]
+fn two_tokens_are_a_warning() {
+    let output = pass_gen(&["--charset", "ab", "-c", "8", "-r"]);
+    let report = String::from_utf8(output.stderr).unwrap();
+
+    assert!(output.status.success(), "{}", report);
+    assert!(report.starts_with("warning: the token pool only has 2 tokens, check the token file and filters\n"));
+    assert!(report.contains("entropy per word:           1.0 bits\n"), "{}", report);
+    assert!(report.contains("total entropy:              8 bits"), "{}", report);
+
+    // the passwords are still generated
+    let password = stdout(&["--charset", "ab", "-c", "8"]);
+    assert!(password.len() == 8 && password.chars().all(|c| c == 'a' || c == 'b'), "{}", password);
+}
+
+#[test]
+fn only_the_human_report_warns() {
+    let output = pass_gen(&["--charset", "ab", "-c", "8", "--report-only", "--report-format", "kv"]);
+    let report = String::from_utf8(output.stdout).unwrap();
+
+    assert!(report.starts_with("pool_size=2\n"), "{}", report);
+    assert!(!report.contains("warning"), "{}", report);
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use common::pass_gen;
+use std::fs;
+
+const MIXED: &str = "tests/fixtures/spec-mixed.txt";
+
+fn entries(stdout: &[u8]) -> Vec<(String, String)> {
+    let stdout = String::from_utf8(stdout.to_vec()).unwrap();
+
+    stdout.lines().map(|line| line.split_once('=').unwrap()).map(|(l, p)| (l.to_string(), p.to_string())).collect()
+}
+
+#[test]
+fn one_line_per_entry_in_file_order() {
+    let output = pass_gen(&["--spec", MIXED]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let entries = entries(&output.stdout);
+    let labels: Vec<&str> = entries.iter().map(|(label, _)| label.as_str()).collect();
+    assert_eq!(labels, ["db_root", "api_key", "wifi", "admin.passphrase", "pin"]);
+
+    // each entry has the shape of its own flags
+    let passwords: Vec<&str> = entries.iter().map(|(_, password)| password.as_str()).collect();
+    let [db_root, api_key, wifi, admin, pin] = passwords[..] else { panic!("{:?}", passwords) };
+
+    assert_eq!(db_root.chars().count(), 32);
+    assert!(db_root.chars().all(|c| c.is_ascii_graphic()));
+    assert_eq!(api_key.chars().count(), 40);
+    assert!(api_key.chars().any(|c| c.is_ascii_digit()) && api_key.chars().any(|c| c.is_ascii_punctuation()));
+    assert_eq!(wifi.split('-').count(), 5);
+    assert!(wifi.chars().all(|c| c.is_ascii_lowercase() || c == '-'));
+    assert_eq!(admin.split("1 ").count(), 6);
+    assert!(pin.len() == 6 && pin.chars().all(|c| c.is_ascii_digit()));
+}
+
+#[test]
+fn command_line_flags_apply_to_every_entry() {
+    let first = pass_gen(&["--spec", MIXED, "--seed", "3"]);
+    let again = pass_gen(&["--seed", "3", "--spec", MIXED]);
+
+    assert!(first.status.success(), "{}", String::from_utf8_lossy(&first.stderr));
+    assert_eq!(first.stdout, again.stdout);
+
+    // but every entry draws its own stream
+    let entries = entries(&first.stdout);
+    assert!(!entries[1].1.starts_with(&entries[0].1));
+}
+
+#[test]
+fn json_object() {
+    let output = pass_gen(&["--spec", MIXED, "--json", "--seed", "3"]);
+    let plain = entries(&pass_gen(&["--spec", MIXED, "--seed", "3"]).stdout);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(stdout.starts_with("{\n  \"db_root\": ") && stdout.ends_with("\n}\n"), "{}", stdout);
+    assert_eq!(stdout.lines().count(), plain.len() + 2);
+
+    for (line, (label, password)) in stdout.lines().skip(1).zip(&plain) {
+        let value = password.replace('\\', "\\\\").replace('"', "\\\"");
+        assert_eq!(line.trim_end_matches(','), format!("  \"{}\": \"{}\"", label, value));
+    }
+}
+
+#[test]
+fn whole_set_in_one_file() {
+    let path = std::env::temp_dir().join(format!("pass-gen-spec-{}.txt", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let output = pass_gen(&["--spec", MIXED, "-o", path.to_str().unwrap()]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(output.stdout.is_empty());
+
+    let written = fs::read(&path).unwrap();
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+    }
+
+    fs::remove_file(&path).unwrap();
+    assert_eq!(entries(&written).len(), 5);
+}
+
+#[test]
+fn errors_name_the_line() {
+    let output = pass_gen(&["--spec", "tests/fixtures/spec-duplicate.txt"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    let expected = "spec-duplicate.txt line 4: duplicate label \"db_root\", first used on line 1";
+    assert!(stderr.contains(expected), "{}", stderr);
+
+    // nothing is generated when any entry is invalid
+    let output = pass_gen(&["--spec", "tests/fixtures/spec-invalid.txt"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert!(stderr.contains("spec-invalid.txt line 2: "), "{}", stderr);
+
+    // an entry makes one password
+    let path = std::env::temp_dir().join(format!("pass-gen-spec-number-{}.txt", std::process::id()));
+    fs::write(&path, "pin: -p number -n 2\n").unwrap();
+
+    let output = pass_gen(&["--spec", path.to_str().unwrap()]);
+    fs::remove_file(&path).unwrap();
+
+    assert!(String::from_utf8_lossy(&output.stderr).contains("line 1: a spec entry makes exactly one password"));
+}
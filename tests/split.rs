@@ -0,0 +1,75 @@
+mod common;
+
+use common::{pass_gen_stdin, stdout, stdout_stdin};
+
+#[test]
+fn split_then_combine() {
+    for (threshold, count) in [(2, 2), (2, 3), (3, 5), (4, 4), (5, 12)] {
+        for pool in [&["-p", "word"][..], &["-p", "ascii"], &["--charset", "äöü", "--allow-unicode"]] {
+            let spec = format!("{}/{}", threshold, count);
+            let output = stdout_stdin(&[pool, &["--split", &spec, "--also-plain"]].concat(), "");
+
+            // the password, then every share under its header
+            let mut parts = output.split("\n\n");
+            let password = parts.next().unwrap();
+            let shares: Vec<&str> = parts.collect();
+
+            assert_eq!(shares.len(), count);
+
+            for (idx, share) in shares.iter().enumerate() {
+                let header = format!("share {} of {}, any {} reconstruct the password\n", idx + 1, count, threshold);
+                assert!(share.starts_with(&header), "{}", share);
+            }
+
+            // any threshold of the shares in any order, headers included or not
+            for start in 0..count {
+                let used: Vec<&str> = (0..threshold).rev().map(|n| shares[(start + n) % count]).collect();
+                let bare: Vec<&str> = used.iter().map(|share| share.lines().nth(1).unwrap()).collect();
+
+                assert_eq!(stdout_stdin(&["combine"], used.join("\n\n")), format!("{}\n", password));
+                assert_eq!(stdout_stdin(&["combine"], bare.join("\n")), format!("{}\n", password));
+            }
+
+            // one short of the threshold reveals nothing
+            let output = pass_gen_stdin(&["combine"], shares[..threshold - 1].join("\n"));
+            assert!(String::from_utf8_lossy(&output.stderr).contains("shares are needed"));
+        }
+    }
+
+    // without --also-plain only the shares are printed
+    let output = stdout_stdin(&["--split", "2/2"], "");
+    assert!(output.starts_with("share 1 of 2") && output.lines().count() == 5, "{}", output);
+}
+
+#[test]
+fn rejects() {
+    let fails = |args: &[&str], stdin: &str, msg: &str| {
+        let output = pass_gen_stdin(args, stdin);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(!output.status.success() && stderr.contains(msg), "{}", stderr);
+    };
+
+    fails(&["--split", "1/3"], "", "a threshold below 2");
+    fails(&["--split", "4/3"], "", "cannot split into 3 shares");
+    fails(&["--split", "2"], "", "expected k/n");
+    fails(&["--split", "2/3", "-n", "2"], "", "conflicts with --number");
+    fails(&["--split", "2/3", "--json"], "", "--split conflicts with --csv");
+    fails(&["--also-plain"], "", "--also-plain requires --split, --encrypt-to or --age-recipient");
+
+    // the plain password is not a share, nor is a share of another version
+    fails(&["combine"], "correct horse", "invalid share");
+    fails(&["combine"], "020201aa\n020202bb", "unknown version 2");
+    fails(&["combine"], "010201aa\n010201bb", "share 1 is given twice");
+}
+
+#[test]
+fn combine_synopsis() {
+    let help = stdout(&["--help"]);
+
+    assert!(help.contains("       pass-gen combine < <shares>\n"), "{}", help);
+    assert!(help.lines().all(|line| !line.ends_with(' ')), "{}", help);
+
+    let man = stdout(&["--generate-man"]);
+    assert!(man.contains(".B pass\\-gen combine\n< <shares>\n"), "{}", man);
+}
@@ -0,0 +1,64 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use common::pass_gen;
+use std::{collections::HashSet, fs};
+
+const TOKEN_LEN: usize = 64;
+const COUNT: usize = 1_000_000;
+
+// the largest resident set of any child waited for so far, in bytes
+fn children_max_rss() -> usize {
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+
+    assert_eq!(unsafe { libc::getrusage(libc::RUSAGE_CHILDREN, &mut usage) }, 0);
+    usage.ru_maxrss as usize * 1024
+}
+
+#[test]
+fn huge_passwords_are_streamed() {
+    let path = std::env::temp_dir().join(format!("pass-gen-stream-{}.txt", std::process::id()));
+    let tokens: Vec<String> = (0..256).map(|i| format!("{}{:02x}", "t".repeat(TOKEN_LEN - 2), i)).collect();
+    fs::write(&path, tokens.join("\n")).unwrap();
+
+    let count = COUNT.to_string();
+    let output = pass_gen(&["-f", path.to_str().unwrap(), "-c", &count, "-s", "-", "--seed", "1"]);
+    fs::remove_file(&path).unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let password = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(password.len(), COUNT * TOKEN_LEN + COUNT - 1);
+
+    let pool: HashSet<&str> = tokens.iter().map(String::as_str).collect();
+    assert!(password.split('-').all(|token| pool.contains(token)));
+
+    // a password put together in memory would take at least its own length
+    let rss = children_max_rss();
+    assert!(rss < password.len() / 2, "{} bytes resident for a {} byte password", rss, password.len());
+}
+
+#[test]
+fn same_password_as_buffered() {
+    // the csv path never streams
+    let args = ["-c", "150000", "--seed", "4"];
+    let streamed = pass_gen(&args).stdout;
+    let csv = pass_gen(&[&args[..], &["--csv", "--no-header"]].concat()).stdout;
+
+    let csv = String::from_utf8(csv).unwrap();
+    assert_eq!(String::from_utf8(streamed).unwrap(), csv.split(',').nth(1).unwrap());
+}
+
+#[test]
+fn token_ceiling() {
+    let output = pass_gen(&["-p", "number", "-c", "4000000000"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("exceed the limit of 1000000, pass --force"), "{}", stderr);
+
+    let output = pass_gen(&["-p", "number", "-c", "1000001", "--force"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(output.stdout.len(), 1_000_001);
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use common::{pass_gen, stdout};
+
+fn report(args: &[&str]) -> String {
+    stdout(&[&["--report-only", "-p", "number", "-c", "20"], args].concat())
+}
+
+#[test]
+fn rate_flows_into_the_rows() {
+    let human = report(&["--target-hash", "bcrypt:10"]);
+
+    assert!(human.contains("target hash:                bcrypt:10, guess rate estimated for 8x RTX 4090"), "{}", human);
+    assert!(human.contains("  46,875 / second:"), "{}", human);
+    assert!(!human.contains("1 billion / second:"), "{}", human);
+
+    let kv = report(&["--target-hash", "md5", "--report-format", "kv"]);
+
+    assert!(kv.contains("target_hash=md5\n"), "{}", kv);
+    assert!(kv.contains("average_time_1.3e12="), "{}", kv);
+    assert_eq!(kv.matches("average_time_").count(), 1);
+
+    let json = report(&["--target-hash", "argon2id:131072,3,4", "--report-format", "json"]);
+
+    assert!(json.contains("\"guess_times\": [{\"rate\": 10000,"), "{}", json);
+    assert!(json.contains("\"target_hash\": {\"hash\": \"argon2id:131072,3,4\""), "{}", json);
+}
+
+#[test]
+fn crack_time_uses_the_hash() {
+    let count = |hash| {
+        let output = pass_gen(&["-p", "number", "--crack-time", "100 years", "--target-hash", hash]);
+        String::from_utf8(output.stdout).unwrap().trim().len()
+    };
+
+    // md5 is some 10^8 times faster than bcrypt at cost 12, a digit is a factor of ten
+    assert_eq!(count("md5") - count("bcrypt"), 8);
+}
+
+#[test]
+fn conflicts_and_errors() {
+    let output = pass_gen(&["--target-hash", "md5", "--guesses-per-second", "1k"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--target-hash conflicts with --guesses-per-second"));
+
+    let output = pass_gen(&["--target-hash", "rot13"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown hash \"rot13\""));
+}
+
+#[test]
+fn help_names_the_hardware() {
+    let help = stdout(&["--help"]).split_whitespace().collect::<Vec<_>>().join(" ");
+    let kv = report(&["--target-hash", "md5", "--report-format", "kv"]);
+    let hardware = kv.lines().find_map(|line| line.strip_prefix("target_hash_hardware=")).unwrap();
+
+    assert!(help.contains(&format!("estimated for {} --bar-target", hardware)), "{}", help);
+}
@@ -0,0 +1,55 @@
+#![cfg(unix)]
+
+mod common;
+
+use common::pass_gen;
+use std::{os::unix::process::CommandExt, process::Output};
+
+// in a session of its own, so without a controlling terminal
+fn detached(args: &[&str]) -> Output {
+    let mut command = common::command();
+    command.args(args);
+
+    // SAFETY: setsid is async-signal-safe
+    unsafe {
+        command.pre_exec(|| {
+            libc::setsid();
+            Ok(())
+        });
+    }
+
+    command.output().expect("pass-gen runs")
+}
+
+#[test]
+fn stdout_unchanged() {
+    let runs = [
+        &["--seed", "1"][..],
+        &["--seed", "2", "-n", "20"],
+        &["--seed", "3", "-n", "3", "--json", "--stats"],
+        &["--seed", "4", "-n", "3", "--csv", "-p", "ascii"],
+        &["--seed", "5", "--quote", "shell"],
+    ];
+
+    for args in runs {
+        let plain = pass_gen(args);
+        let echoed = pass_gen(&[args, &["--tty-echo"]].concat());
+
+        assert!(echoed.status.success(), "{}", String::from_utf8_lossy(&echoed.stderr));
+        assert_eq!(echoed.stdout, plain.stdout, "{:?}", args);
+        assert_eq!(echoed.stderr, plain.stderr, "{:?}", args);
+    }
+}
+
+#[test]
+fn no_terminal() {
+    let plain = detached(&["--seed", "1"]);
+    let echoed = detached(&["--seed", "1", "--tty-echo", "--verbose"]);
+
+    assert!(echoed.status.success());
+    assert_eq!(echoed.stdout, plain.stdout);
+    assert!(String::from_utf8_lossy(&echoed.stderr).contains("tty echo:     skipped, there is no controlling terminal"));
+
+    let output = pass_gen(&["--tty-echo", "--split", "2/2"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--tty-echo conflicts with --split"));
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use common::pass_gen;
+use std::{
+    collections::HashSet,
+    process::{Output, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+// fails the test instead of waiting forever on a run that hangs
+fn within_deadline(args: &[&str]) -> Output {
+    let mut child =
+        common::command().args(args).stdout(Stdio::piped()).stderr(Stdio::piped()).spawn().expect("pass-gen runs");
+
+    let start = Instant::now();
+
+    while child.try_wait().unwrap().is_none() {
+        if start.elapsed() > Duration::from_secs(10) {
+            child.kill().unwrap();
+            panic!("{:?} still running after 10 seconds", args);
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn more_than_the_pool_supports() {
+    // a pool of 2 characters has 8 passwords of 3
+    let output = within_deadline(&["--charset", "ab", "-c", "3", "-n", "1000000", "--unique"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!(output.status.code(), Some(1), "{}", stderr);
+    assert!(output.stdout.is_empty());
+    assert_eq!(stderr, "pass-gen: cannot generate 1000000 unique passwords, the configuration only supports 8\n");
+
+    assert_eq!(within_deadline(&["--charset", "ab", "-c", "3", "-n", "9", "--unique"]).status.code(), Some(1));
+}
+
+#[test]
+fn exactly_what_the_pool_supports() {
+    let output = pass_gen(&["--charset", "ab", "-c", "3", "-n", "8", "--unique", "--seed", "1"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let passwords: HashSet<&str> = stdout.lines().collect();
+
+    assert_eq!(passwords.len(), 8, "{}", stdout);
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use common::{stderr, stdout};
+
+fn safe(username: &str) -> bool {
+    username.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b".-_".contains(&b))
+}
+
+#[test]
+fn drawn_after_the_password() {
+    for style in ["words", "pronounceable"] {
+        for number in ["1", "300"] {
+            let args = ["--seed", "3", "-n", number];
+            let passwords = stdout(&args);
+            let output = stdout(&[&args[..], &["--with-username", "--username-style", style]].concat());
+
+            // the same seed gives the same output, and the passwords stay those of the seed
+            assert_eq!(output, stdout(&[&args[..], &["--with-username", "--username-style", style]].concat()));
+
+            let (usernames, rest): (Vec<&str>, Vec<&str>) =
+                output.lines().map(|line| line.split_once(':').unwrap()).unzip();
+
+            assert_eq!(rest.join("\n"), passwords);
+            assert!(usernames.iter().all(|username| safe(username) && username.len() <= 32), "{:?}", usernames);
+
+            match style {
+                "words" => assert!(usernames.iter().all(|username| username.split('.').count() == 2)),
+                _ => assert!(usernames.iter().all(|username| username.len() == 8)),
+            }
+        }
+    }
+
+    let short = stdout(&["-n", "200", "--with-username", "--username-max-len", "9"]);
+    assert!(short.lines().all(|line| line.split_once(':').unwrap().0.len() <= 9), "{}", short);
+}
+
+#[test]
+fn fields_and_report() {
+    let json = stdout(&["--seed", "1", "--with-username", "--json"]);
+    let entry = "{\"index\": 1, \"username\": \"giving.rates\", \"password\": \"washes pseudonym";
+    assert!(json.contains(entry), "{}", json);
+
+    let csv = stdout(&["--seed", "1", "--with-username", "--csv"]);
+    assert!(csv.starts_with("index,username,password,length,entropy\n1,giving.rates,washes pseudonym"), "{}", csv);
+
+    // the total only counts the password
+    let kv = |args: &[&str]| stdout(&[args, &["--report-only", "--report-format", "kv"]].concat());
+    let plain = kv(&[]);
+    let with_username = kv(&["--with-username"]);
+
+    assert!(with_username.starts_with(&plain[..plain.find("strength=").unwrap()]), "{}", with_username);
+    assert!(with_username.contains("username_style=words\nusername_bits="), "{}", with_username);
+}
+
+#[test]
+fn rejects() {
+    let error = stderr(&["--with-username", "--username-style", "pronounceable", "--username-max-len", "7"]);
+    assert!(error.contains("too short"), "{}", error);
+
+    assert!(stderr(&["--with-username", "--username-style", "handle"]).contains("invalid username style \"handle\""));
+    assert!(stderr(&["--username-max-len", "12"]).contains("require --with-username"));
+    assert!(stderr(&["--with-username", "--quote", "shell"]).contains("--with-username conflicts with --quote"));
+    assert!(stderr(&["--with-username", "--split", "2/3"]).contains("--with-username conflicts with --quote"));
+
+    let error = stderr(&["--with-username", "--keepass-csv", "--username-template", "u{i}"]);
+    assert!(error.contains("conflicts with --username-template"), "{}", error);
+}
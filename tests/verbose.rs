@@ -0,0 +1,32 @@
+mod common;
+
+use common::pass_gen;
+
+// the whole of a seeded -v run, the configuration on stderr and the passwords on stdout
+const CONFIGURATION: &str = "\
+source:       preset word
+pool size:    26578 loaded
+token count:  5 (from --count)
+separator:    \"-\" (from --sep)
+memorability: 63/100 (moderate) over 200 samples: 40.8 chars, 2.0 classes, 0.00 symbol clusters, \
+0.00 shift changes per char
+rng:          StdRng (ChaCha12) seeded from --seed
+max length:   40 (47.6% of candidates fit)
+passwords:    3 (1 job(s), unique)
+output:       plain to stdout
+";
+
+const PASSWORDS: &str = "\
+volume-quart-parlance-third-idiots
+lieu-carrots-mob-voiced-gradient
+muscle-exist-fruiting-forefathers-seduce";
+
+#[test]
+fn seeded_run() {
+    let args = ["-v", "--seed", "1", "-n", "3", "-c", "5", "-s", "-", "--max-length", "40", "--unique"];
+    let output = pass_gen(&[&args[..], &["--no-config", "--no-locale"]].concat());
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stderr).unwrap(), CONFIGURATION);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), PASSWORDS);
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use common::{stderr, stdout};
+
+#[test]
+fn numbered_lines() {
+    let phrase = stdout(&["--seed", "1"]);
+    let vertical = stdout(&["--seed", "1", "--vertical"]);
+
+    // same tokens, the separators left out
+    let numbered: Vec<String> =
+        phrase.split(' ').enumerate().map(|(pos, word)| format!("{}. {}", pos + 1, word)).collect();
+    assert_eq!(vertical, numbered.join("\n"));
+    assert_eq!(stdout(&["--seed", "1", "--vertical", "--no-numbers"]), phrase.replace(' ', "\n"));
+
+    // a separator the tokens contain no longer matters
+    assert_eq!(stdout(&["--seed", "1", "--vertical", "-s", "-"]), vertical);
+
+    // numbers are aligned, passwords get a header each
+    let batch = stdout(&["--seed", "2", "-c", "10", "-n", "2", "--vertical"]);
+    let passwords: Vec<&str> = batch.split("\n\n").collect();
+
+    assert_eq!(passwords.len(), 2);
+
+    for (idx, password) in passwords.iter().enumerate() {
+        let lines: Vec<&str> = password.lines().collect();
+
+        assert_eq!(lines[0], format!("password {}", idx + 1));
+        assert!(lines[1].starts_with(" 1. ") && lines[10].starts_with("10. "), "{}", password);
+    }
+
+    // characters are tokens of their own
+    let chars = stdout(&["--charset", "ab", "-c", "3", "--vertical", "--no-numbers"]);
+    assert!(chars.split('\n').all(|line| line == "a" || line == "b") && chars.len() == 5, "{}", chars);
+}
+
+#[test]
+fn conflicts() {
+    assert!(stderr(&["--vertical", "--json"]).contains("--vertical conflicts with --csv, --json"));
+    assert!(stderr(&["--vertical", "--quote", "shell"]).contains("--vertical conflicts with --csv, --json"));
+    assert!(stderr(&["--vertical", "--template", "WW"]).contains("--vertical conflicts with --template"));
+    assert!(stderr(&["--vertical", "--sep-set", "-_"]).contains("--vertical conflicts with --template"));
+    assert!(stderr(&["--no-numbers"]).contains("--no-numbers requires --vertical"));
+    assert!(stderr(&["--batch", "--vertical"]).contains("--vertical is not available in batch requests"));
+}
@@ -0,0 +1,16 @@
+#![cfg(feature = "wasm")]
+
+// the bindings outside of a wasm runtime, where a thrown error cannot be built
+use pass_gen::wasm::{entropy_bits, generate};
+
+#[test]
+fn same_as_the_library() {
+    let password = generate("word", 4, "-").unwrap();
+    assert_eq!(password.split('-').count(), 4, "{}", password);
+
+    let password = generate("number", 6, "").unwrap();
+    assert!(password.len() == 6 && password.chars().all(|c| c.is_ascii_digit()), "{}", password);
+
+    assert_eq!(entropy_bits("number", 3).unwrap(), pass_gen::entropy_bits("number", 3).unwrap());
+    assert!((entropy_bits("ascii", 10).unwrap() - 65.5).abs() < 0.5);
+}
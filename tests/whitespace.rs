@@ -0,0 +1,70 @@
+mod common;
+
+use common::{pass_gen, success};
+use std::process::Output;
+
+const WORDS: &str = "tests/fixtures/whitespace-words.txt";
+
+fn from_words(args: &[&str]) -> Output {
+    pass_gen(&[&["-f", WORDS], args].concat())
+}
+
+// every token drawn in a long password, separated by commas
+fn drawn(args: &[&str]) -> Vec<String> {
+    let password = success(from_words(&[args, &["-c", "200", "-s", ",", "--seed", "1"]].concat()));
+    let mut tokens: Vec<String> = password.split(',').map(String::from).collect();
+
+    tokens.sort();
+    tokens.dedup();
+    tokens
+}
+
+fn pool_size(args: &[&str]) -> String {
+    let kv = success(from_words(&[args, &["--report-only", "--report-format", "kv"]].concat()));
+    kv.lines().find_map(|line| line.strip_prefix("pool_size=")).unwrap().to_string()
+}
+
+#[test]
+fn skipped_by_default() {
+    let output = from_words(&["-c", "200", "-s", ","]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // tabs and runs of spaces count, whitespace around a line does not
+    assert!(stderr.contains("warning: skipped 4 line(s) of tests/fixtures/whitespace-words.txt"), "{}", stderr);
+    assert_eq!(drawn(&[]), ["leading", "plain", "trailing"]);
+    assert_eq!(pool_size(&[]), "3");
+    assert_eq!(drawn(&["--whitespace", "skip"]), drawn(&[]));
+}
+
+#[test]
+fn keep_split_and_join() {
+    let keep = ["--whitespace", "keep"];
+    assert_eq!(drawn(&keep), ["ice\tcream", "leading", "new york", "plain", "trailing", "two   spaces"]);
+    assert_eq!(pool_size(&keep), "6");
+    assert!(from_words(&keep).stderr.is_empty());
+
+    let split = ["--whitespace", "split"];
+    let words = ["cream", "ice", "leading", "new", "plain", "spaces", "trailing", "two", "york"];
+    assert_eq!(drawn(&split), words);
+    assert_eq!(pool_size(&split), "9");
+
+    let join = ["--whitespace", "join"];
+    assert_eq!(drawn(&join), ["icecream", "leading", "newyork", "plain", "trailing", "twospaces"]);
+    assert_eq!(pool_size(&join), "6");
+
+    let underscore = ["--whitespace", "join", "--whitespace-join", "_"];
+    assert_eq!(drawn(&underscore), ["ice_cream", "leading", "new_york", "plain", "trailing", "two_spaces"]);
+}
+
+#[test]
+fn invalid_combinations() {
+    let split_join = ["--whitespace", "split", "--whitespace-join", "_"];
+
+    for args in [&["--whitespace", "squash"][..], &["--whitespace-join", "_"], &split_join] {
+        let output = from_words(args);
+        assert_eq!(output.status.code(), Some(1), "{:?}", args);
+    }
+
+    let output = common::command().args(["--whitespace", "keep"]).output().unwrap();
+    assert!(String::from_utf8_lossy(&output.stderr).contains("only apply to --file"));
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use common::pass_gen_stdin;
+
+const WORDS: &str = "tests/fixtures/wordlist-hash.txt";
+const DIGEST: &str = "sha256:3eca7ea48b0da0ad30bee679c92c7b68d487547068b6914d10a64e8cedb03f51";
+const OTHER: &str = "sha256:0000000000000000000000000000000000000000000000000000000000000000";
+
+#[test]
+fn matching_list_is_used() {
+    let output = pass_gen_stdin(&["-f", WORDS, "--wordlist-hash", DIGEST, "-c", "8", "--seed", "1"], b"");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(stdout.split(' ').all(|token| ["alpha", "bravo", "charlie"].contains(&token)), "{}", stdout);
+
+    // the digest is of the raw bytes whatever case it is written in
+    let upper = DIGEST.to_uppercase();
+    assert!(pass_gen_stdin(&["-f", WORDS, "--wordlist-hash", &upper], b"").status.success());
+}
+
+#[test]
+fn mismatch_names_both_digests() {
+    let output = pass_gen_stdin(&["-f", WORDS, "--wordlist-hash", OTHER], b"");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert!(stderr.contains(&format!("expected {}, got {}", OTHER, DIGEST)), "{}", stderr);
+}
+
+#[test]
+fn stdin_is_hashed() {
+    let words = std::fs::read(WORDS).unwrap();
+
+    let output = pass_gen_stdin(&["-f", "-", "--wordlist-hash", DIGEST, "-c", "4"], &words);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    // a single byte more is a different list
+    let output = pass_gen_stdin(&["-f", "-", "--wordlist-hash", DIGEST], [&words[..], b"\n"].concat());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr.contains("word list <stdin> does not match"), "{}", stderr);
+}
+
+#[test]
+fn matched_positionally_with_from() {
+    let from = format!("{},preset:number,tests/fixtures/from-animals.txt", WORDS);
+
+    // the second hash is checked against the second file, presets have none
+    let output = pass_gen_stdin(&["--from", &from, "--wordlist-hash", DIGEST, "--wordlist-hash", DIGEST], b"");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr.contains("source 3 of --from"), "{}", stderr);
+    assert!(stderr.contains(&format!("expected {}", DIGEST)), "{}", stderr);
+
+    // one hash for each file
+    let output = pass_gen_stdin(&["--from", &from, "--wordlist-hash", DIGEST], b"");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(stderr.contains("given 1 time(s) for 2 word list file(s)"), "{}", stderr);
+}
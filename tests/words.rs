@@ -0,0 +1,46 @@
+mod common;
+
+use common::{pass_gen, success};
+use std::process::Output;
+
+fn words(args: &[&str]) -> Output {
+    pass_gen(&[&["words"], args].concat())
+}
+
+fn lines(args: &[&str]) -> Vec<String> {
+    success(words(args)).lines().map(String::from).collect()
+}
+
+#[test]
+fn queries() {
+    let all = lines(&[]);
+    let car = lines(&["--prefix", "car"]);
+
+    assert!(!car.is_empty());
+    assert_eq!(car, all.iter().filter(|word| word.starts_with("car")).cloned().collect::<Vec<_>>());
+
+    let both = lines(&["--prefix", "car", "--contains", "o", "--of-length", "6"]);
+    assert!(both.iter().all(|word| word.starts_with("car") && word.contains('o') && word.chars().count() == 6));
+    assert_eq!(both.len(), car.iter().filter(|word| word.contains('o') && word.chars().count() == 6).count());
+
+    assert_eq!(lines(&["-p", "number", "--contains", "7"]), ["7"]);
+}
+
+#[test]
+fn index_counts_from_one_in_pool_order() {
+    let all = lines(&["--max-word-length", "5"]);
+
+    assert_eq!(lines(&["--max-word-length", "5", "--index", "1"]), [all[0].clone()]);
+    assert_eq!(lines(&["--max-word-length", "5", "--index", &all.len().to_string()]), [all[all.len() - 1].clone()]);
+
+    let output = words(&["--max-word-length", "5", "--index", &(all.len() + 1).to_string()]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("beyond the pool"));
+}
+
+#[test]
+fn nothing_found() {
+    let output = words(&["--prefix", "car", "--of-length", "1"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use common::{pass_gen, success};
+use std::{
+    fs,
+    process::Output,
+};
+
+fn xkcdpass(args: &[&str]) -> Output {
+    pass_gen(&[&["--compat", "xkcdpass"], args].concat())
+}
+
+fn passphrases(args: &[&str]) -> Vec<String> {
+    success(xkcdpass(args)).lines().map(String::from).collect()
+}
+
+#[test]
+fn defaults() {
+    let generated = passphrases(&["-c", "20"]);
+
+    assert_eq!(generated.len(), 20);
+
+    // six words of 5 to 9 letters, separated by spaces
+    for passphrase in &generated {
+        let words: Vec<&str> = passphrase.split(' ').collect();
+
+        assert_eq!(words.len(), 6);
+        assert!(words.iter().all(|word| (5..=9).contains(&word.chars().count())));
+    }
+}
+
+#[test]
+fn count_delimiter_and_lengths() {
+    for args in [
+        &["-n", "4", "-d", "-", "--min", "3", "--max", "4", "-c", "10"][..],
+        &["--numwords=4", "--delimiter=-", "--min=3", "--max=4", "--count=10"],
+    ] {
+        let generated = passphrases(args);
+
+        assert_eq!(generated.len(), 10);
+
+        for passphrase in &generated {
+            let words: Vec<&str> = passphrase.split('-').collect();
+
+            assert_eq!(words.len(), 4);
+            assert!(words.iter().all(|word| (3..=4).contains(&word.chars().count())));
+        }
+    }
+
+    // an empty delimiter joins the words
+    assert!(!passphrases(&["-d", "", "-c", "5"]).iter().any(|passphrase| passphrase.contains(' ')));
+}
+
+#[test]
+fn wordfile() {
+    let path = std::env::temp_dir().join(format!("pass-gen-xkcdpass-{}.txt", std::process::id()));
+    fs::write(&path, "alpha\nbravo\ncharlie\ndelta\nalpha\nfoxtrot\nzulu\n").unwrap();
+
+    let output = xkcdpass(&["-w", path.to_str().unwrap(), "-n", "3", "-c", "50", "-V"]);
+    let report = String::from_utf8_lossy(&output.stderr);
+
+    // duplicates and words outside 5 to 9 letters are dropped like xkcdpass does
+    assert!(report.contains("duplicates (-1)") && report.contains("word length (-1)"), "{}", report);
+
+    for passphrase in String::from_utf8(output.stdout).unwrap().lines() {
+        let words: Vec<&str> = passphrase.split(' ').collect();
+
+        assert_eq!(words.len(), 3);
+        assert!(words.iter().all(|word| ["alpha", "bravo", "charlie", "delta", "foxtrot"].contains(word)));
+    }
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn random_delimiters() {
+    for passphrase in passphrases(&["-R", "-D", "#@", "-n", "5", "-c", "20"]) {
+        assert_eq!(passphrase.chars().filter(|&c| c == '#' || c == '@').count(), 4);
+        assert!(!passphrase.contains(' '));
+    }
+}
+
+#[test]
+fn acrostic() {
+    for passphrase in passphrases(&["-a", "cat", "-n", "6", "-c", "10"]) {
+        let initials: String = passphrase.split(' ').map(|word| &word[..1]).collect();
+        assert_eq!(initials, "cat");
+    }
+}
+
+#[test]
+fn unsupported_options() {
+    for args in [&["-i"][..], &["-C", "upper"], &["--valid-chars", "[a-z]"], &["-w", "eff-long"]] {
+        let output = xkcdpass(args);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(!output.status.success());
+        assert!(stderr.contains("not supported in compat mode") || stderr.contains("not available"), "{}", stderr);
+        assert!(output.stdout.is_empty());
+    }
+}